@@ -1,4 +1,4 @@
-use api::{create_app, AppState};
+use api::{serve, Config};
 use std::net::SocketAddr;
 use tokio;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
@@ -11,19 +11,24 @@ async fn main() -> anyhow::Result<()> {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    // Load and validate the configuration before touching the database
+    let config = Config::from_env().map_err(|e| anyhow::anyhow!("Invalid configuration: {}", e))?;
+    tracing::info!("Configuration loaded: {:?}", config);
+
     // Initialize database connection
     tracing::info!("Connecting to database...");
-    let db_pool = flextide_core::database::create_pool()
+    let db_pool = config
+        .connect_database()
         .await
         .map_err(|e| anyhow::anyhow!("Failed to create database pool: {}", e))?;
-    tracing::info!("Database connection established (type: {:?})", db_pool.database_type());
+    tracing::info!("Database connection established (type: {:?})", db_pool.write().database_type());
 
     // Run database migrations
     // Try both paths: ./migrations (when running from backend/) and ./backend/migrations (when running from project root)
     tracing::info!("Running database migrations...");
-    let migration_result = db_pool.run_migrations("./migrations").await;
+    let migration_result = db_pool.write().run_migrations("./migrations").await;
     let migration_result = if migration_result.is_err() {
-        db_pool.run_migrations("./backend/migrations").await
+        db_pool.write().run_migrations("./backend/migrations").await
     } else {
         migration_result
     };
@@ -32,7 +37,7 @@ async fn main() -> anyhow::Result<()> {
 
     // Ensure default admin user exists
     tracing::info!("Checking for default admin user...");
-    flextide_core::user::ensure_default_admin_user(&db_pool)
+    flextide_core::user::ensure_default_admin_user(db_pool.write())
         .await
         .map_err(|e| anyhow::anyhow!("Failed to ensure default admin user: {}", e))?;
     tracing::info!("Default admin user ensured (admin@example.com / admin)");
@@ -42,20 +47,12 @@ async fn main() -> anyhow::Result<()> {
     let event_dispatcher = flextide_core::events::EventDispatcher::new();
     
     // Load database-backed event subscriptions
-    flextide_core::events::initialize(&event_dispatcher, &db_pool)
+    flextide_core::events::initialize(&event_dispatcher, db_pool.write())
         .await
         .map_err(|e| anyhow::anyhow!("Failed to initialize event system: {}", e))?;
     tracing::info!("Event system initialized");
 
-    // JWT secret (in production, use environment variable)
-    let jwt_secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "your-secret-key-change-in-production".to_string());
-
-    let app_state = AppState {
-        jwt_secret,
-        db_pool,
-        event_dispatcher,
-    };
-    let app = create_app(app_state);
+    let app_state = config.into_app_state(db_pool, event_dispatcher);
 
     // Start server
     let addr = SocketAddr::from(([0, 0, 0, 0], 8080));
@@ -63,8 +60,42 @@ async fn main() -> anyhow::Result<()> {
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
     tracing::info!("API server is running and ready to accept connections on {}", addr);
-    axum::serve(listener, app).await?;
+    serve(listener, app_state, shutdown_signal()).await?;
+    tracing::info!("API server shut down gracefully");
 
     Ok(())
 }
 
+/// Resolve when the process receives Ctrl+C or SIGTERM
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    tracing::info!("Shutdown signal received, stopping API server...");
+}
+
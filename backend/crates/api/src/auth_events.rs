@@ -0,0 +1,91 @@
+//! Authentication audit log API endpoints
+//!
+//! Lets server administrators review logins and logouts, e.g. to spot repeated failed
+//! logins for one email address.
+
+use axum::{
+    extract::{Extension, Query, State},
+    http::StatusCode,
+    response::Json,
+    routing::get,
+    Router,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use flextide_core::pagination::{PaginatedResource, Pagination};
+use flextide_core::user::{
+    list_auth_events, record_auth_event, AuthEventFilter, AuthEventOutcome, CreateAuthEventRequest,
+    PaginatedAuthEvents,
+};
+
+use crate::{AppState, Claims};
+
+#[derive(Debug, Deserialize)]
+pub struct ListAuthEventsQuery {
+    #[serde(default = "crate::default_page")]
+    pub page: u32,
+    pub limit: Option<u32>,
+    pub user_uuid: Option<String>,
+    pub email: Option<String>,
+    pub outcome: Option<AuthEventOutcome>,
+}
+
+/// Record an authentication event without failing the request
+///
+/// Logins and logouts must keep working if the audit log cannot be written, so errors
+/// are only logged.
+pub(crate) async fn record_event(state: &AppState, request: CreateAuthEventRequest) {
+    if let Err(e) = record_auth_event(&state.db_pool, &request).await {
+        tracing::warn!(
+            "Failed to record {} {} authentication event: {}",
+            request.event_type.as_str(),
+            request.outcome.as_str(),
+            e
+        );
+    }
+}
+
+/// List authentication events, newest first
+///
+/// GET /api/admin/auth-events
+pub async fn list_auth_events_endpoint(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<ListAuthEventsQuery>,
+) -> Result<Json<PaginatedAuthEvents>, (StatusCode, Json<Value>)> {
+    // Check if user is server admin
+    if !claims.is_server_admin {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "Server admin access required" })),
+        ));
+    }
+
+    let Pagination { page, limit, .. } =
+        state.pagination.clamp(PaginatedResource::AuthEvents, query.page, query.limit);
+
+    // Emails are stored normalized, so the filter has to be normalized as well
+    let filter = AuthEventFilter {
+        user_uuid: query.user_uuid,
+        email: query.email.as_deref().map(flextide_core::user::normalize_email),
+        outcome: query.outcome,
+    };
+
+    let events = list_auth_events(&state.db_pool, &filter, page, limit)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list authentication events: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to list authentication events" })),
+            )
+        })?;
+
+    Ok(Json(events))
+}
+
+/// Create the authentication audit log router
+pub fn create_router() -> Router<AppState> {
+    Router::new().route("/admin/auth-events", get(list_auth_events_endpoint))
+}
@@ -10,7 +10,7 @@ use axum::{
     extract::{Extension, Path, Query, State},
     http::{header, StatusCode},
     response::{Json, Response},
-    routing::{delete, get, post},
+    routing::{get, post},
     Router,
 };
 use axum::body::Body;
@@ -20,14 +20,15 @@ use serde_json::{json, Value};
 use std::str::FromStr;
 use cron::Schedule;
 
+use flextide_core::pagination::{PaginatedResource, Pagination};
+
 use crate::{AppState, Claims};
 
 #[derive(Debug, Deserialize)]
 pub struct ListBackupsQuery {
     #[serde(default = "crate::default_page")]
     pub page: u32,
-    #[serde(default = "crate::default_limit")]
-    pub limit: u32,
+    pub limit: Option<u32>,
 }
 
 /// Get backup statistics
@@ -102,7 +103,9 @@ pub async fn list_backups(
 
     use flextide_core::backup::database::list_backups;
 
-    let result = list_backups(&state.db_pool, &claims.user_uuid, query.page, query.limit)
+    let Pagination { page, limit, .. } =
+        state.pagination.clamp(PaginatedResource::Backups, query.page, query.limit);
+    let result = list_backups(&state.db_pool, &claims.user_uuid, page, limit)
         .await
         .map_err(|e| {
             tracing::error!("Failed to list backups: {}", e);
@@ -306,6 +309,41 @@ pub async fn restore_backup(
     Ok(Json(json!({ "message": "Backup restore initiated successfully" })))
 }
 
+/// Get a single backup
+///
+/// GET /api/admin/backups/{uuid}
+pub async fn get_backup(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(uuid): Path<String>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    // Check if user is server admin
+    if !claims.is_server_admin {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "Server admin access required" })),
+        ));
+    }
+
+    let backup = flextide_core::backup::get_backup(&state.db_pool, &uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get backup: {}", e);
+            match e {
+                flextide_core::backup::BackupError::BackupNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Backup not found" })),
+                ),
+                _ => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to get backup" })),
+                ),
+            }
+        })?;
+
+    Ok(Json(json!(backup)))
+}
+
 /// Download a backup file
 ///
 /// GET /api/admin/backups/{uuid}/download
@@ -940,7 +978,7 @@ pub fn create_router() -> Router<AppState> {
     Router::new()
         .route("/admin/backups/statistics", get(get_backup_statistics))
         .route("/admin/backups", get(list_backups).post(create_backup))
-        .route("/admin/backups/{uuid}", delete(delete_backup))
+        .route("/admin/backups/{uuid}", get(get_backup).delete(delete_backup))
         .route("/admin/backups/{uuid}/restore", post(restore_backup))
         .route("/admin/backups/{uuid}/download", get(download_backup))
         .route("/admin/backup-jobs", get(list_backup_jobs).post(create_backup_job))
@@ -0,0 +1,172 @@
+//! Client IP address extraction
+//!
+//! Behind a reverse proxy the peer address of the connection is the proxy, the real
+//! client is only known from the `X-Forwarded-For` / `X-Real-IP` headers the proxy sets.
+//! Anyone can send these headers, so they are only honored for connections coming from
+//! a configured trusted proxy (`TRUSTED_PROXIES`).
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::extract::{ConnectInfo, FromRequestParts};
+use axum::http::{request::Parts, HeaderMap};
+
+use crate::AppState;
+
+/// A single trusted proxy address or CIDR range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ProxyNetwork {
+    address: IpAddr,
+    prefix_len: u8,
+}
+
+impl ProxyNetwork {
+    /// Parse an IP address (`10.0.0.1`) or CIDR range (`10.0.0.0/8`, `fd00::/8`)
+    fn parse(value: &str) -> Option<Self> {
+        let (address, prefix_len) = match value.split_once('/') {
+            Some((address, prefix_len)) => {
+                (address.parse::<IpAddr>().ok()?, Some(prefix_len.parse::<u8>().ok()?))
+            }
+            None => (value.parse::<IpAddr>().ok()?, None),
+        };
+
+        let max_prefix_len = match address {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        let prefix_len = prefix_len.unwrap_or(max_prefix_len);
+        if prefix_len > max_prefix_len {
+            return None;
+        }
+
+        Some(Self { address, prefix_len })
+    }
+
+    fn contains(&self, ip: IpAddr) -> bool {
+        // IPv4 clients reached over an IPv6 socket show up as IPv4-mapped addresses
+        let ip = match ip {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(ip),
+            IpAddr::V4(_) => ip,
+        };
+
+        match (self.address, ip) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - u32::from(self.prefix_len)).unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - u32::from(self.prefix_len)).unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Reverse proxies whose `X-Forwarded-For` / `X-Real-IP` headers are trusted
+///
+/// Empty by default, so forwarded headers are ignored unless proxies are configured.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrustedProxies {
+    networks: Vec<ProxyNetwork>,
+}
+
+impl TrustedProxies {
+    /// Parse a comma-separated list of IP addresses and CIDR ranges
+    ///
+    /// # Arguments
+    /// * `value` - e.g. `10.0.0.0/8, 192.168.1.10, fd00::/8`
+    ///
+    /// # Errors
+    /// Returns the first entry that is neither an IP address nor a CIDR range
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let networks = value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| ProxyNetwork::parse(entry).ok_or_else(|| entry.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { networks })
+    }
+
+    /// Whether no proxy is trusted
+    pub fn is_empty(&self) -> bool {
+        self.networks.is_empty()
+    }
+
+    /// Whether `ip` belongs to a trusted proxy
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        self.networks.iter().any(|network| network.contains(ip))
+    }
+}
+
+/// Determine the IP address of the client that sent a request
+///
+/// If the connection comes from a trusted proxy, `X-Forwarded-For` is read from right to
+/// left and the first address that is not a trusted proxy is the client (entries further
+/// left were supplied by the client and may be forged). Without `X-Forwarded-For`,
+/// `X-Real-IP` is used. In all other cases the peer address is the client.
+///
+/// # Arguments
+/// * `headers` - Request headers
+/// * `peer` - Address of the connection, `None` if unknown
+/// * `trusted_proxies` - Proxies whose forwarded headers are honored
+///
+/// # Returns
+/// The client IP address, or `None` if the peer address is unknown
+pub fn extract_client_ip(
+    headers: &HeaderMap,
+    peer: Option<IpAddr>,
+    trusted_proxies: &TrustedProxies,
+) -> Option<IpAddr> {
+    let peer = peer?;
+    if !trusted_proxies.contains(peer) {
+        return Some(peer);
+    }
+
+    let forwarded_for: Vec<IpAddr> = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|entry| entry.trim().parse::<IpAddr>().ok())
+        .collect();
+
+    if let Some(&first) = forwarded_for.first() {
+        // Every hop is a trusted proxy, the leftmost entry is the best guess
+        let client = forwarded_for
+            .iter()
+            .rev()
+            .find(|ip| !trusted_proxies.contains(**ip))
+            .copied()
+            .unwrap_or(first);
+        return Some(client);
+    }
+
+    let real_ip = headers
+        .get("x-real-ip")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<IpAddr>().ok());
+
+    Some(real_ip.unwrap_or(peer))
+}
+
+/// IP address of the client that sent a request, see [`extract_client_ip`]
+///
+/// The peer address is only known when the app is served through [`crate::serve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientIp(pub Option<String>);
+
+impl FromRequestParts<AppState> for ClientIp {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|info| info.0.ip());
+
+        let ip = extract_client_ip(&parts.headers, peer, &state.trusted_proxies);
+        Ok(ClientIp(ip.map(|ip| ip.to_string())))
+    }
+}
@@ -0,0 +1,247 @@
+//! Runtime configuration of the API server
+//!
+//! Loads and validates all settings from the environment at startup, so a misconfigured
+//! server fails before it accepts requests instead of on the first request that needs a
+//! setting.
+
+use flextide_core::database::{
+    create_pool_with_config, DatabaseError, DatabasePoolConfig, DatabaseType, ReplicatedPool,
+};
+use flextide_core::events::EventDispatcher;
+use flextide_core::jwt::JwtConfig;
+use flextide_core::pagination::PaginationConfig;
+use thiserror::Error;
+
+use crate::{AppState, TrustedProxies};
+
+/// Minimum length of `JWT_SECRET` in bytes
+pub const MIN_JWT_SECRET_LENGTH: usize = 32;
+
+/// Errors of loading the configuration
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Required setting {0} is not set")]
+    Missing(&'static str),
+
+    #[error("JWT_SECRET must be at least {min_length} bytes long, got {length}")]
+    JwtSecretTooShort { length: usize, min_length: usize },
+
+    #[error("Invalid value for {name}: {value}")]
+    InvalidValue { name: &'static str, value: String },
+
+    #[error("{0} must start with mysql://, postgres:// or sqlite:")]
+    UnsupportedDatabaseUrl(&'static str),
+
+    #[error("DATABASE_MIN_CONNECTIONS ({min}) must not exceed DATABASE_MAX_CONNECTIONS ({max})")]
+    InvalidPoolSize { min: u32, max: u32 },
+
+    #[error("Database error: {0}")]
+    Database(#[from] DatabaseError),
+}
+
+/// Runtime settings of the API server
+#[derive(Clone)]
+pub struct Config {
+    /// Secret used to sign and verify login tokens (`JWT_SECRET`)
+    pub jwt_secret: String,
+    /// Token lifetime and clock-skew leeway (`JWT_LIFETIME_SECONDS`, `JWT_LEEWAY_SECONDS`)
+    pub jwt_config: JwtConfig,
+    /// URL of the primary database (`DATABASE_URL`)
+    pub database_url: String,
+    /// URL of an optional read replica (`DATABASE_REPLICA_URL`)
+    pub database_replica_url: Option<String>,
+    /// Pool size limits (`DATABASE_MAX_CONNECTIONS`, `DATABASE_MIN_CONNECTIONS`)
+    pub database_pool: DatabasePoolConfig,
+    /// Origins allowed to call the API from a browser (`CORS_ALLOWED_ORIGINS`), empty allows any origin
+    pub cors_allowed_origins: Vec<String>,
+    /// Reverse proxies allowed to set `X-Forwarded-For` / `X-Real-IP` (`TRUSTED_PROXIES`), empty trusts none
+    pub trusted_proxies: TrustedProxies,
+    /// Page size limits of the list endpoints (`PAGINATION_*`)
+    pub pagination: PaginationConfig,
+}
+
+impl std::fmt::Debug for Config {
+    // The secret and the database URLs (which may contain passwords) are never logged
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Config")
+            .field("jwt_secret", &"<redacted>")
+            .field("jwt_config", &self.jwt_config)
+            .field("database_url", &"<redacted>")
+            .field("database_replica_url", &self.database_replica_url.as_ref().map(|_| "<redacted>"))
+            .field("database_pool", &self.database_pool)
+            .field("cors_allowed_origins", &self.cors_allowed_origins)
+            .field("trusted_proxies", &self.trusted_proxies)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Config {
+    /// Load the configuration from the environment
+    ///
+    /// Variables missing from the environment are read from `backend/.env` or `.env`,
+    /// like `DATABASE_URL` always was.
+    ///
+    /// # Errors
+    /// Returns `ConfigError` if a required setting is missing or a setting is invalid
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let _ = dotenvy::from_filename("backend/.env");
+        let _ = dotenvy::dotenv();
+
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Load the configuration from a lookup function returning the value of a setting
+    ///
+    /// `JWT_LIFETIME_SECONDS`, `JWT_LEEWAY_SECONDS` and the `PAGINATION_*` settings are
+    /// always read from the environment and fall back to their defaults if invalid.
+    ///
+    /// # Errors
+    /// Returns `ConfigError` if a required setting is missing or a setting is invalid
+    pub fn from_lookup<F>(lookup: F) -> Result<Self, ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        // Blank values are treated like missing ones
+        let get = |name: &str| lookup(name).map(|value| value.trim().to_string()).filter(|value| !value.is_empty());
+
+        let jwt_secret = get("JWT_SECRET").ok_or(ConfigError::Missing("JWT_SECRET"))?;
+        if jwt_secret.len() < MIN_JWT_SECRET_LENGTH {
+            return Err(ConfigError::JwtSecretTooShort {
+                length: jwt_secret.len(),
+                min_length: MIN_JWT_SECRET_LENGTH,
+            });
+        }
+
+        let database_url = get("DATABASE_URL").ok_or(ConfigError::Missing("DATABASE_URL"))?;
+        validate_database_url("DATABASE_URL", &database_url)?;
+
+        let database_replica_url = get("DATABASE_REPLICA_URL");
+        if let Some(url) = &database_replica_url {
+            validate_database_url("DATABASE_REPLICA_URL", url)?;
+        }
+
+        let defaults = DatabasePoolConfig::default();
+        let max_connections = match get("DATABASE_MAX_CONNECTIONS") {
+            Some(value) => match value.parse::<u32>() {
+                Ok(max) if max > 0 => max,
+                _ => {
+                    return Err(ConfigError::InvalidValue {
+                        name: "DATABASE_MAX_CONNECTIONS",
+                        value,
+                    });
+                }
+            },
+            None => defaults.max_connections,
+        };
+        let min_connections = match get("DATABASE_MIN_CONNECTIONS") {
+            Some(value) => value.parse::<u32>().map_err(|_| ConfigError::InvalidValue {
+                name: "DATABASE_MIN_CONNECTIONS",
+                value,
+            })?,
+            None => defaults.min_connections,
+        };
+        if min_connections > max_connections {
+            return Err(ConfigError::InvalidPoolSize {
+                min: min_connections,
+                max: max_connections,
+            });
+        }
+
+        let cors_allowed_origins = match get("CORS_ALLOWED_ORIGINS") {
+            Some(value) => parse_cors_origins(&value)?,
+            None => Vec::new(),
+        };
+
+        let trusted_proxies = match get("TRUSTED_PROXIES") {
+            Some(value) => TrustedProxies::parse(&value).map_err(|entry| ConfigError::InvalidValue {
+                name: "TRUSTED_PROXIES",
+                value: entry,
+            })?,
+            None => TrustedProxies::default(),
+        };
+
+        Ok(Self {
+            jwt_secret,
+            jwt_config: JwtConfig::from_env(),
+            database_url,
+            database_replica_url,
+            database_pool: DatabasePoolConfig {
+                max_connections,
+                min_connections,
+            },
+            cors_allowed_origins,
+            trusted_proxies,
+            pagination: PaginationConfig::from_env(),
+        })
+    }
+
+    /// Connect to the configured database (and read replica, if any)
+    ///
+    /// # Errors
+    /// Returns `ConfigError::Database` if a connection cannot be established
+    pub async fn connect_database(&self) -> Result<ReplicatedPool, ConfigError> {
+        Ok(create_pool_with_config(
+            &self.database_url,
+            self.database_replica_url.as_deref(),
+            self.database_pool,
+        )
+        .await?)
+    }
+
+    /// Build the application state from this configuration
+    ///
+    /// # Arguments
+    /// * `db_pool` - Pool created with `connect_database`, after migrations ran
+    /// * `event_dispatcher` - Initialized event dispatcher
+    pub fn into_app_state(self, db_pool: ReplicatedPool, event_dispatcher: EventDispatcher) -> AppState {
+        // Docs area trees are cached, the cache must see every mutation of this dispatcher
+        flextide_modules_docs::AreaTreeCache::global().subscribe(&event_dispatcher);
+        // Setting values are cached until this dispatcher reports a change
+        flextide_core::settings::SettingsCache::global().subscribe(&event_dispatcher);
+
+        AppState {
+            jwt_secret: self.jwt_secret,
+            jwt_config: self.jwt_config,
+            db_pool: db_pool.write().clone(),
+            db_read_pool: db_pool.read().clone(),
+            event_dispatcher,
+            node_registry: std::sync::Arc::new(crate::default_node_registry()),
+            pagination: std::sync::Arc::new(self.pagination),
+            cors_allowed_origins: self.cors_allowed_origins,
+            trusted_proxies: self.trusted_proxies,
+        }
+    }
+}
+
+fn validate_database_url(name: &'static str, url: &str) -> Result<(), ConfigError> {
+    // The URL itself is not part of the error, it may contain a password
+    DatabaseType::from_url(url)
+        .map(|_| ())
+        .map_err(|_| ConfigError::UnsupportedDatabaseUrl(name))
+}
+
+/// Parse the comma-separated `CORS_ALLOWED_ORIGINS`, `*` allows any origin
+fn parse_cors_origins(value: &str) -> Result<Vec<String>, ConfigError> {
+    if value == "*" {
+        return Ok(Vec::new());
+    }
+
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|origin| !origin.is_empty())
+        .map(|origin| {
+            let valid = (origin.starts_with("http://") || origin.starts_with("https://"))
+                && !origin.ends_with('/')
+                && axum::http::HeaderValue::from_str(origin).is_ok();
+            if valid {
+                Ok(origin.to_string())
+            } else {
+                Err(ConfigError::InvalidValue {
+                    name: "CORS_ALLOWED_ORIGINS",
+                    value: origin.to_string(),
+                })
+            }
+        })
+        .collect()
+}
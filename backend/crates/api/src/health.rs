@@ -0,0 +1,161 @@
+//! Health API endpoints
+//!
+//! Reports the status of the subsystems the API depends on, for load balancers and monitoring.
+//!
+//! `/livez` and `/readyz` are meant for liveness and readiness probes of orchestrators
+//! like Kubernetes: a failing liveness probe restarts the process, a failing readiness
+//! probe only takes it out of the load balancer.
+
+use axum::{extract::State, http::StatusCode, response::Json, routing::get, Router};
+use flextide_core::credentials::CredentialsManager;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+
+use crate::AppState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    /// Only non-critical subsystems are down
+    Degraded,
+    /// At least one critical subsystem is down
+    Down,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SubsystemHealth {
+    pub status: HealthStatus,
+    /// Whether the API cannot serve requests while this subsystem is down
+    pub critical: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FullHealthResponse {
+    pub status: HealthStatus,
+    pub subsystems: BTreeMap<&'static str, SubsystemHealth>,
+}
+
+impl FullHealthResponse {
+    /// Build the response from `(name, critical, healthy)` checks
+    fn from_checks(checks: impl IntoIterator<Item = (&'static str, bool, bool)>) -> Self {
+        let mut status = HealthStatus::Ok;
+        let mut subsystems = BTreeMap::new();
+
+        for (name, critical, healthy) in checks {
+            if !healthy && critical {
+                status = HealthStatus::Down;
+            } else if !healthy && status == HealthStatus::Ok {
+                status = HealthStatus::Degraded;
+            }
+            subsystems.insert(
+                name,
+                SubsystemHealth {
+                    status: if healthy { HealthStatus::Ok } else { HealthStatus::Down },
+                    critical,
+                },
+            );
+        }
+
+        Self { status, subsystems }
+    }
+}
+
+/// Check that the database answers a ping
+async fn database_is_healthy(state: &AppState) -> bool {
+    match state.db_pool.ping().await {
+        Ok(()) => true,
+        Err(e) => {
+            tracing::error!("Health check: database ping failed: {}", e);
+            false
+        }
+    }
+}
+
+/// Check that the event dispatcher has not been shut down
+fn event_dispatcher_is_healthy(state: &AppState) -> bool {
+    let ready = state.event_dispatcher.is_ready();
+    if !ready {
+        tracing::error!("Health check: event dispatcher has been shut down");
+    }
+    ready
+}
+
+/// 503 if a critical subsystem is down, 200 otherwise
+fn status_code_for(response: &FullHealthResponse) -> StatusCode {
+    if response.status == HealthStatus::Down {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    }
+}
+
+/// Get the health of all subsystems
+///
+/// GET /api/health/full
+///
+/// - `database` (critical): the database answers a ping
+/// - `event_dispatcher` (critical): the event dispatcher has not been shut down
+/// - `integration_credentials`: the credentials master key is configured
+///
+/// Responds with 503 if a critical subsystem is down. Error details are only logged.
+pub async fn get_full_health(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<FullHealthResponse>) {
+    let database = database_is_healthy(&state).await;
+    let event_dispatcher = event_dispatcher_is_healthy(&state);
+
+    let integration_credentials = match CredentialsManager::new() {
+        Ok(_) => true,
+        Err(e) => {
+            tracing::warn!("Health check: integration credentials unavailable: {}", e);
+            false
+        }
+    };
+
+    let response = FullHealthResponse::from_checks([
+        ("database", true, database),
+        ("event_dispatcher", true, event_dispatcher),
+        ("integration_credentials", false, integration_credentials),
+    ]);
+
+    (status_code_for(&response), Json(response))
+}
+
+/// Liveness probe
+///
+/// GET /api/livez
+///
+/// Always responds with 200 while the process is able to serve requests. Does not check
+/// any dependency, so an unavailable database does not cause restarts.
+pub async fn get_liveness() -> Json<Value> {
+    Json(json!({ "status": "ok" }))
+}
+
+/// Readiness probe
+///
+/// GET /api/readyz
+///
+/// Checks the critical subsystems (`database` and `event_dispatcher`) and responds with
+/// 503 if one of them is down.
+pub async fn get_readiness(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<FullHealthResponse>) {
+    let database = database_is_healthy(&state).await;
+    let event_dispatcher = event_dispatcher_is_healthy(&state);
+
+    let response = FullHealthResponse::from_checks([
+        ("database", true, database),
+        ("event_dispatcher", true, event_dispatcher),
+    ]);
+
+    (status_code_for(&response), Json(response))
+}
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/health/full", get(get_full_health))
+        .route("/livez", get(get_liveness))
+        .route("/readyz", get(get_readiness))
+}
@@ -7,40 +7,76 @@ use axum::{
     routing::{delete, get, post, put}, // delete and put are used in route definitions
     Router,
 };
-use chrono::{Duration, Utc};
+use chrono::Utc;
+use flextide_core::pagination::{PaginatedResource, Pagination, PaginationConfig};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use sqlx::Row;
 use tower::ServiceBuilder;
 use tower_http::{
-    cors::{Any, CorsLayer},
+    cors::{AllowOrigin, Any, CorsLayer},
     trace::TraceLayer,
 };
 
 #[derive(Clone)]
 pub struct AppState {
     pub jwt_secret: String,
+    /// Token lifetime and clock-skew leeway
+    pub jwt_config: flextide_core::jwt::JwtConfig,
     pub db_pool: flextide_core::database::DatabasePool,
+    /// Pool for read-only queries that tolerate replication lag: the read replica
+    /// if one is configured, otherwise the same pool as `db_pool`
+    pub db_read_pool: flextide_core::database::DatabasePool,
     pub event_dispatcher: flextide_core::events::EventDispatcher,
+    /// Node types workflows may use, e.g. to validate imported workflows
+    pub node_registry: std::sync::Arc<flextide_node_registry::NodeRegistry>,
+    /// Default and maximum page size of the list endpoints
+    pub pagination: std::sync::Arc<PaginationConfig>,
+    /// Origins allowed to call the API from a browser, empty allows any origin
+    pub cors_allowed_origins: Vec<String>,
+    /// Reverse proxies whose forwarded client IP headers are trusted
+    pub trusted_proxies: TrustedProxies,
+}
+
+/// Create a node registry with the built-in nodes
+pub fn default_node_registry() -> flextide_node_registry::NodeRegistry {
+    let mut registry = flextide_node_registry::NodeRegistry::new();
+    registry.register_group(flextide_node_registry::NodeGroup {
+        name: "http".to_string(),
+        title: "HTTP".to_string(),
+        description: "Send requests to web services".to_string(),
+    });
+    registry
+        .register_node(integrations::HttpRequestNode::definition())
+        .expect("HTTP group is registered");
+    registry
 }
 
 // Re-export Claims from flextide-core for convenience
 pub use flextide_core::jwt::Claims;
 
+mod auth_events;
 mod backup;
 mod chroma;
+mod client_ip;
+mod config;
 mod credentials;
+mod health;
+mod metrics;
+mod queue;
+mod usage;
+mod workflows;
+
+pub use client_ip::{extract_client_ip, ClientIp, TrustedProxies};
+pub use config::{Config, ConfigError, MIN_JWT_SECRET_LENGTH};
+pub use metrics::{metrics_middleware, MetricsRegistry};
 
 // Export helper functions for use in other modules
 pub fn default_page() -> u32 {
     1
 }
 
-pub fn default_limit() -> u32 {
-    30
-}
-
 #[derive(Debug, Deserialize)]
 pub struct LoginRequest {
     pub email: String,
@@ -73,6 +109,86 @@ fn error_response(status: StatusCode, error: Value) -> Response {
     response
 }
 
+/// Response for a resource that does not exist
+///
+/// `404 {"error": "<resource> not found"}`, also used for resources of other
+/// organizations, see `ensure_resource_in_organization`.
+pub fn resource_not_found(resource: &str) -> (StatusCode, Json<Value>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(json!({ "error": format!("{} not found", resource) })),
+    )
+}
+
+/// Ensure a resource belongs to the organization of the request
+///
+/// Resources of other organizations are reported exactly like missing resources
+/// (`resource_not_found`) instead of `403`, so responses never reveal that a
+/// resource exists in another organization.
+pub fn ensure_resource_in_organization(
+    resource_org_uuid: &str,
+    org_uuid: &str,
+    resource: &str,
+) -> Result<(), (StatusCode, Json<Value>)> {
+    if resource_org_uuid != org_uuid {
+        tracing::warn!("{} of another organization requested from organization {}", resource, org_uuid);
+        return Err(resource_not_found(resource));
+    }
+
+    Ok(())
+}
+
+/// Header used to propagate the request id between clients, the API and logs
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Stable identifier of a single API request
+///
+/// Inserted into the request extensions by `request_id_middleware`, so handlers
+/// can use `Extension(request_id): Extension<RequestId>` to reference it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+impl std::fmt::Display for RequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Check whether a client supplied request id can be reused as-is
+///
+/// Only short, visible ASCII values are accepted to keep logs and headers clean.
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty() && value.len() <= 128 && value.chars().all(|c| c.is_ascii_graphic())
+}
+
+/// Request id middleware - reads or generates the `X-Request-Id` header
+///
+/// Reuses a valid `X-Request-Id` sent by the client, otherwise generates a new UUID.
+/// The id is stored in the request extensions, attached to the tracing span of
+/// the request and echoed in the response headers (including error responses).
+pub async fn request_id_middleware(mut request: Request, next: Next) -> Response {
+    use tracing::Instrument;
+
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|h| h.to_str().ok())
+        .filter(|id| is_valid_request_id(id))
+        .map(|id| id.to_string())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    request.extensions_mut().insert(RequestId(request_id.clone()));
+
+    let span = tracing::info_span!("request", request_id = %request_id);
+    let mut response = next.run(request).instrument(span).await;
+
+    if let Ok(value) = header::HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    response
+}
+
 /// Authentication middleware - validates JWT token
 pub async fn auth_middleware(
     State(state): State<AppState>,
@@ -88,8 +204,14 @@ pub async fn auth_middleware(
         return next.run(request).await;
     }
 
-    // Skip auth for login and register endpoints
-    if path == "/api/login" || path == "/api/register" || path == "/api/health" {
+    // Skip auth for login and register endpoints and the health probes
+    if path == "/api/login"
+        || path == "/api/register"
+        || path == "/api/health"
+        || path == "/api/health/full"
+        || path == "/api/livez"
+        || path == "/api/readyz"
+    {
         tracing::debug!("[Auth] Skipping authentication for endpoint: {}", path);
         return next.run(request).await;
     }
@@ -127,11 +249,13 @@ pub async fn auth_middleware(
         }
     };
 
-    // Decode and validate token
+    // Decode and validate token, tolerating the configured clock skew
+    let mut validation = Validation::default();
+    validation.leeway = state.jwt_config.leeway_seconds;
     let token_data = match decode::<Claims>(
         token,
         &DecodingKey::from_secret(state.jwt_secret.as_ref()),
-        &Validation::default(),
+        &validation,
     ) {
         Ok(data) => {
             tracing::debug!("[Auth] Token decoded successfully for user: {}", data.claims.sub);
@@ -148,7 +272,7 @@ pub async fn auth_middleware(
 
     // Check if token is expired
     let now = Utc::now().timestamp() as usize;
-    if token_data.claims.exp < now {
+    if state.jwt_config.is_expired(token_data.claims.exp, now) {
         tracing::warn!(
             "[Auth] Token expired for user {} (exp: {}, now: {})",
             token_data.claims.sub,
@@ -187,13 +311,18 @@ pub async fn organization_middleware(
         return next.run(request).await;
     }
 
-    // Skip for login, register, health, logout, organizations/list-own, and organizations/create endpoints
+    // Skip for login, register, health, logout, organizations/list-own, organizations/create,
+    // and the server-wide metrics endpoints
     if path == "/api/login"
         || path == "/api/register"
         || path == "/api/health"
+        || path == "/api/health/full"
+        || path == "/api/livez"
+        || path == "/api/readyz"
         || path == "/api/logout"
         || path == "/api/organizations/list-own"
         || path == "/api/organizations/create"
+        || path == "/api/metrics"
     {
         tracing::debug!("[Org] Skipping organization check for endpoint: {}", path);
         return next.run(request).await;
@@ -231,6 +360,18 @@ pub async fn organization_middleware(
         }
     };
 
+    // Reject malformed organization UUIDs before any database work
+    if uuid::Uuid::parse_str(org_uuid).is_err() {
+        tracing::warn!("[Org] Malformed X-Organization-UUID header for {} {}: {}", method, path, org_uuid);
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            json!({
+                "error": "X-Organization-UUID header is not a valid UUID",
+                "code": "INVALID_ORG_UUID"
+            }),
+        );
+    }
+
     // Get user claims from request extensions (set by auth_middleware)
     let claims = match request.extensions().get::<Claims>() {
         Some(c) => {
@@ -281,25 +422,46 @@ pub async fn organization_middleware(
     // Attach event dispatcher to request extensions for use in handlers
     request.extensions_mut().insert(state.event_dispatcher.clone());
 
+    // Attach page size limits to request extensions for the list endpoints of modules
+    request.extensions_mut().insert(state.pagination.clone());
+
     next.run(request).await
 }
 
 /// Create the API router with all routes
 pub fn create_app(state: AppState) -> Router {
     // CORS configuration
+    let allow_origin = if state.cors_allowed_origins.is_empty() {
+        AllowOrigin::any()
+    } else {
+        // Origins are validated by Config, invalid ones cannot occur here
+        AllowOrigin::list(
+            state
+                .cors_allowed_origins
+                .iter()
+                .filter_map(|origin| header::HeaderValue::from_str(origin).ok()),
+        )
+    };
     let cors = CorsLayer::new()
-        .allow_origin(Any)
+        .allow_origin(allow_origin)
         .allow_methods(Any)
-        .allow_headers(Any);
+        .allow_headers(Any)
+        .expose_headers([header::HeaderName::from_static(REQUEST_ID_HEADER)]);
 
     // Request logging layer
     let trace_layer = TraceLayer::new_for_http()
         .make_span_with(|request: &axum::http::Request<_>| {
+            let request_id = request
+                .extensions()
+                .get::<RequestId>()
+                .map(|id| id.0.as_str())
+                .unwrap_or("-");
             tracing::info_span!(
                 "http_request",
                 method = %request.method(),
                 uri = %request.uri(),
                 version = ?request.version(),
+                request_id = %request_id,
             )
         })
         .on_request(|request: &axum::http::Request<_>, _span: &tracing::Span| {
@@ -333,6 +495,9 @@ pub fn create_app(state: AppState) -> Router {
             );
         });
 
+    // Per-route request metrics, exposed via GET /api/metrics
+    let metrics = MetricsRegistry::new();
+
     // Build router
     Router::new()
         .route("/api/health", get(health_check))
@@ -342,20 +507,38 @@ pub fn create_app(state: AppState) -> Router {
         .route("/api/organizations/list-own", get(list_own_organizations))
         .route("/api/organizations/create", post(create_organization))
         .route("/api/permissions", get(get_permissions))
+        .route(
+            "/api/organizations/{organization_uuid}/users/{user_uuid}/permissions",
+            get(get_user_assignable_permissions),
+        )
         .route("/api/workflows/{workflow_uuid}/edit-title", post(edit_workflow_title))
         .route("/api/executions/last-executions", get(get_last_executions))
+        .route("/api/executions/{execution_uuid}", get(get_execution))
         .route("/api/integrations", get(get_integrations))
         .route("/api/integrations/list", get(list_integrations))
         .route("/api/integrations/search", get(search_integrations))
         .route("/api/webhooks", get(list_webhooks).post(create_webhook))
         .route("/api/webhooks/{id}", get(get_webhook).put(update_webhook).delete(delete_webhook))
+        .route("/api/webhooks/{id}/deliveries", get(list_webhook_deliveries))
+        .nest("/api", auth_events::create_router())
         .nest("/api", backup::create_router())
         .nest("/api", chroma::create_router())
         .nest("/api", credentials::create_router())
+        .nest("/api", health::create_router())
+        .nest("/api", metrics::create_router())
+        .nest("/api", queue::create_router())
+        .nest("/api", usage::create_router())
+        .nest("/api", workflows::create_router())
         .nest("/api", flextide_modules_crm::create_router())
         .nest("/api", flextide_modules_docs::create_router())
         .layer(
             ServiceBuilder::new()
+                .layer(axum::middleware::from_fn(request_id_middleware))
+                .layer(axum::middleware::from_fn_with_state(
+                    metrics.clone(),
+                    metrics_middleware,
+                ))
+                .layer(axum::Extension(metrics))
                 .layer(cors)
                 .layer(axum::middleware::from_fn_with_state(
                     state.clone(),
@@ -370,18 +553,74 @@ pub fn create_app(state: AppState) -> Router {
         .with_state(state)
 }
 
+/// Maximum time to wait for pending event deliveries after the server stopped
+pub const SHUTDOWN_FLUSH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Serve the API until the shutdown signal resolves
+///
+/// Stops accepting new connections once `shutdown` completes, waits for in-flight
+/// requests to finish and then flushes pending event deliveries (e.g. webhooks)
+/// for up to [`SHUTDOWN_FLUSH_TIMEOUT`].
+///
+/// Pass any future as shutdown signal, e.g. `tokio::signal::ctrl_c()` or
+/// `CancellationToken::cancelled_owned()`.
+pub async fn serve<F>(
+    listener: tokio::net::TcpListener,
+    state: AppState,
+    shutdown: F,
+) -> std::io::Result<()>
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    let event_dispatcher = state.event_dispatcher.clone();
+    let app = create_app(state);
+
+    axum::serve(listener, app.into_make_service_with_connect_info::<std::net::SocketAddr>())
+        .with_graceful_shutdown(shutdown)
+        .await?;
+
+    tracing::info!("API server stopped accepting requests, flushing pending event deliveries...");
+    if !event_dispatcher.shutdown(SHUTDOWN_FLUSH_TIMEOUT).await {
+        tracing::warn!("Some event deliveries did not finish before shutdown");
+    }
+
+    Ok(())
+}
+
 async fn health_check() -> Json<Value> {
     Json(json!({ "status": "ok" }))
 }
 
 pub async fn login(
     State(state): State<AppState>,
+    ClientIp(ip_address): ClientIp,
     Json(payload): Json<LoginRequest>,
 ) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    use flextide_core::user::{AuthEventOutcome, AuthEventType, CreateAuthEventRequest};
+
+    let email = flextide_core::user::normalize_email(&payload.email);
+    // Events of known users store the email of the account, not the spelling that was sent
+    let login_event = |outcome, user: Option<&flextide_core::user::User>, failure_reason: Option<&str>| {
+        CreateAuthEventRequest {
+            event_type: AuthEventType::Login,
+            outcome,
+            user_uuid: user.map(|user| user.uuid.clone()),
+            email: Some(user.map_or_else(|| email.clone(), |user| user.email.clone())),
+            ip_address: ip_address.clone(),
+            failure_reason: failure_reason.map(str::to_string),
+        }
+    };
+
     // Get user from database by email
-    let user = match flextide_core::user::get_user_by_email(&state.db_pool, &payload.email).await {
+    let user = match flextide_core::user::get_user_by_email(&state.db_pool, &email).await {
         Ok(user) => user,
         Err(flextide_core::user::UserDatabaseError::Sql(sqlx::Error::RowNotFound)) => {
+            auth_events::record_event(
+                &state,
+                login_event(AuthEventOutcome::Failure, None, Some("unknown_email")),
+            )
+            .await;
+
             // User not found - return generic error to avoid email enumeration
             return Err((
                 StatusCode::UNAUTHORIZED,
@@ -408,6 +647,12 @@ pub async fn login(
         })?;
 
     if !password_valid {
+        auth_events::record_event(
+            &state,
+            login_event(AuthEventOutcome::Failure, Some(&user), Some("invalid_password")),
+        )
+        .await;
+
         return Err((
             StatusCode::UNAUTHORIZED,
             Json(json!({ "error": "Invalid email or password" })),
@@ -416,6 +661,12 @@ pub async fn login(
 
     // Check if account is activated
     if !user.activated {
+        auth_events::record_event(
+            &state,
+            login_event(AuthEventOutcome::Failure, Some(&user), Some("account_not_activated")),
+        )
+        .await;
+
         return Err((
             StatusCode::FORBIDDEN,
             Json(json!({ "error": "Account is not activated" })),
@@ -424,14 +675,14 @@ pub async fn login(
 
     // Generate JWT token
     let now = Utc::now();
-    let exp = (now + Duration::hours(24)).timestamp() as usize;
+    let exp = state.jwt_config.expiration(now);
     let iat = now.timestamp() as usize;
 
-    // Set server admin status (admin@example.com is server admin)
-    let is_server_admin = payload.email == "admin@example.com";
+    // Server admin status is stored on the user
+    let is_server_admin = user.is_server_admin;
 
     let claims = Claims {
-        sub: payload.email.clone(),
+        sub: user.email.clone(),
         user_uuid: user.uuid.clone(),
         exp,
         iat,
@@ -450,9 +701,11 @@ pub async fn login(
         )
     })?;
 
+    auth_events::record_event(&state, login_event(AuthEventOutcome::Success, Some(&user), None)).await;
+
     Ok(Json(json!({
         "token": token,
-        "email": payload.email
+        "email": user.email
     })))
 }
 
@@ -469,18 +722,20 @@ pub async fn register(
 
     // Generate JWT token
     let now = Utc::now();
-    let exp = (now + Duration::hours(24)).timestamp() as usize;
+    let exp = state.jwt_config.expiration(now);
     let iat = now.timestamp() as usize;
 
+    let email = flextide_core::user::normalize_email(&payload.email);
+
     // Generate a UUID for the user (in production, get from database)
-    // For now, use a deterministic UUID based on email hash
-    let user_uuid = uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_DNS, payload.email.as_bytes()).to_string();
+    // For now, use a deterministic UUID based on the normalized email hash
+    let user_uuid = uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_DNS, email.as_bytes()).to_string();
 
-    // Set server admin status (admin@example.com is server admin)
-    let is_server_admin = payload.email == "admin@example.com";
+    // Newly registered users are never server admins
+    let is_server_admin = false;
 
     let claims = Claims {
-        sub: payload.email.clone(),
+        sub: email.clone(),
         user_uuid: user_uuid.clone(),
         exp,
         iat,
@@ -501,7 +756,7 @@ pub async fn register(
 
     Ok(Json(json!({
         "token": token,
-        "email": payload.email
+        "email": email
     })))
 }
 
@@ -511,11 +766,27 @@ pub struct LogoutRequest {
 }
 
 pub async fn logout(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ClientIp(ip_address): ClientIp,
     Json(payload): Json<LogoutRequest>,
 ) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
     tracing::info!("User with userUUID {} has logged out", payload.user_uuid);
-    
+
+    // The token identifies the user, the payload is only logged
+    auth_events::record_event(
+        &state,
+        flextide_core::user::CreateAuthEventRequest {
+            event_type: flextide_core::user::AuthEventType::Logout,
+            outcome: flextide_core::user::AuthEventOutcome::Success,
+            user_uuid: Some(claims.user_uuid),
+            email: Some(claims.sub),
+            ip_address,
+            failure_reason: None,
+        },
+    )
+    .await;
+
     Ok(Json(json!({ "message": "Logged out successfully" })))
 }
 
@@ -942,15 +1213,17 @@ pub async fn create_organization(
 /// Get all permissions for the current user in the current organization
 ///
 /// GET /api/permissions
-/// Returns a list of permission strings that the user has for the current organization
+/// Returns the effective permissions of the user in the current organization, with
+/// `super_admin` and wildcard grants expanded
 pub async fn get_permissions(
     State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Extension(org_uuid): Extension<String>,
 ) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
-    use flextide_core::permissions::list_user_permissions;
+    use flextide_core::permissions::resolve_effective_permissions;
     
-    let user_permissions = list_user_permissions(&state.db_pool, &claims.user_uuid, &org_uuid)
+    // super_admin and wildcard grants are expanded, so clients can check names directly
+    let permissions = resolve_effective_permissions(&state.db_pool, &claims.user_uuid, &org_uuid)
         .await
         .map_err(|e| {
             tracing::error!("Failed to fetch user permissions: {}", e);
@@ -960,12 +1233,6 @@ pub async fn get_permissions(
             )
         })?;
     
-    // Extract just the permission names
-    let permissions: Vec<String> = user_permissions
-        .into_iter()
-        .map(|up| up.permission_name)
-        .collect();
-    
     Ok(Json(json!({
         "permissions": permissions,
         "user_uuid": claims.user_uuid,
@@ -973,12 +1240,109 @@ pub async fn get_permissions(
     })))
 }
 
+/// List all visible permissions with a flag whether the given user has been granted each of them
+///
+/// Only administrators (owner or admin role) of the organization may list the permissions of its members.
+pub async fn get_user_assignable_permissions(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((organization_uuid, user_uuid)): Path<(String, String)>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    use flextide_core::permissions::{list_permissions, list_user_permissions};
+    use flextide_core::user::{user_belongs_to_organization, user_is_organization_admin};
+
+    let is_admin = user_is_organization_admin(&state.db_pool, &claims.user_uuid, &organization_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check organization admin role: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to fetch permissions" })),
+            )
+        })?;
+
+    if !is_admin {
+        tracing::warn!(
+            "User {} tried to list permissions of user {} in organization {} without being an organization admin",
+            claims.user_uuid,
+            user_uuid,
+            organization_uuid
+        );
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "Only organization admins can list user permissions" })),
+        ));
+    }
+
+    let is_member = user_belongs_to_organization(&state.db_pool, &user_uuid, &organization_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check organization membership: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to fetch permissions" })),
+            )
+        })?;
+
+    if !is_member {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(json!({ "error": "User not found in organization" })),
+        ));
+    }
+
+    let permissions = list_permissions(&state.db_pool).await.map_err(|e| {
+        tracing::error!("Failed to fetch permissions: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "Failed to fetch permissions" })),
+        )
+    })?;
+
+    let granted: std::collections::HashSet<String> =
+        list_user_permissions(&state.db_pool, &user_uuid, &organization_uuid)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to fetch user permissions: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch permissions" })),
+                )
+            })?
+            .into_iter()
+            .map(|up| up.permission_name)
+            .collect();
+
+    let permissions: Vec<Value> = permissions
+        .into_iter()
+        .filter(|p| p.visible)
+        .map(|p| {
+            json!({
+                "permission_group_name": p.permission_group_name,
+                "granted": granted.contains(&p.name),
+                "name": p.name,
+                "title": p.title,
+                "description": p.description,
+                "sort_order": p.sort_order,
+            })
+        })
+        .collect();
+
+    Ok(Json(json!({
+        "permissions": permissions,
+        "user_uuid": user_uuid,
+        "organization_uuid": organization_uuid
+    })))
+}
+
 #[derive(Debug, Deserialize)]
 pub struct LastExecutionsQuery {
     #[serde(default = "crate::default_page")]
     pub page: u32,
-    #[serde(default = "crate::default_limit")]
-    pub limit: u32,
+    /// Number of executions per page, defaults to the configured default page size
+    pub limit: Option<u32>,
+    /// Include the (potentially large) metadata of each execution, defaults to false
+    pub include_metadata: Option<bool>,
 }
 
 /// Helper function to extract execution data from a database row
@@ -1057,16 +1421,41 @@ pub struct LastExecutionsResponse {
     pub total_pages: u32,
 }
 
+/// Statement timeout of the queries listing executions
+pub const EXECUTIONS_QUERY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Map an error of the execution list queries to a response
+fn executions_query_error(e: flextide_core::database::DatabaseError) -> (StatusCode, Json<Value>) {
+    match e {
+        flextide_core::database::DatabaseError::QueryTimeout(timeout) => {
+            tracing::warn!("Fetching executions timed out after {:?}", timeout);
+            (
+                StatusCode::GATEWAY_TIMEOUT,
+                Json(json!({ "error": "Fetching executions timed out" })),
+            )
+        }
+        e => {
+            tracing::error!("Failed to fetch executions: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to fetch executions" })),
+            )
+        }
+    }
+}
+
 /// Get last executions for the organization
 ///
-/// GET /api/executions/last-executions?page=1&limit=30
+/// GET /api/executions/last-executions?page=1&limit=30&include_metadata=false
+///
+/// Metadata is only loaded if `include_metadata` is true, otherwise it is `null`.
 pub async fn get_last_executions(
     State(state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Extension(org_uuid): Extension<String>,
     Query(query): Query<LastExecutionsQuery>,
 ) -> Result<Json<LastExecutionsResponse>, (StatusCode, Json<Value>)> {
-    use flextide_core::database::DatabasePool;
+    use flextide_core::database::{begin_with_statement_timeout, with_statement_timeout, DatabasePool};
     use flextide_core::user::{user_belongs_to_organization, user_has_permission};
 
     // Check if user belongs to organization
@@ -1112,51 +1501,57 @@ pub async fn get_last_executions(
         ));
     }
 
-    // Validate limit (max 50)
-    let limit = query.limit.min(50).max(1);
-    let page = query.page.max(1);
-    let offset = (page - 1) * limit;
+    // Validate limit against the configured maximum
+    let Pagination { page, limit, offset } =
+        state.pagination.clamp(PaginatedResource::Executions, query.page, query.limit);
 
-    // Get total count
-    let total = match &state.db_pool {
+    // Get total count (read-only, may be served by the read replica)
+    let total = match &state.db_read_pool {
         DatabasePool::MySql(p) => {
-            sqlx::query_scalar::<_, i64>(
-                "SELECT COUNT(*) FROM runs WHERE organization_uuid = ?"
+            with_statement_timeout(
+                EXECUTIONS_QUERY_TIMEOUT,
+                sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM runs WHERE organization_uuid = ?")
+                    .bind(&org_uuid)
+                    .fetch_one(p),
             )
-            .bind(&org_uuid)
-            .fetch_one(p)
             .await
         }
         DatabasePool::Postgres(p) => {
-            sqlx::query_scalar::<_, i64>(
-                "SELECT COUNT(*) FROM runs WHERE organization_uuid = $1"
-            )
-            .bind(&org_uuid)
-            .fetch_one(p)
+            with_statement_timeout(EXECUTIONS_QUERY_TIMEOUT, async {
+                let mut tx = begin_with_statement_timeout(p, EXECUTIONS_QUERY_TIMEOUT).await?;
+                let total = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM runs WHERE organization_uuid = $1")
+                    .bind(&org_uuid)
+                    .fetch_one(&mut *tx)
+                    .await?;
+                tx.commit().await?;
+                Ok(total)
+            })
             .await
         }
         DatabasePool::Sqlite(p) => {
-            sqlx::query_scalar::<_, i64>(
-                "SELECT COUNT(*) FROM runs WHERE organization_uuid = ?1"
+            with_statement_timeout(
+                EXECUTIONS_QUERY_TIMEOUT,
+                sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM runs WHERE organization_uuid = ?1")
+                    .bind(&org_uuid)
+                    .fetch_one(p),
             )
-            .bind(&org_uuid)
-            .fetch_one(p)
             .await
         }
     }
-    .map_err(|e| {
-        tracing::error!("Failed to count executions: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({ "error": "Failed to fetch executions" })),
-        )
-    })?;
+    .map_err(executions_query_error)?;
+
+    // Select NULL instead of the metadata if not requested, keeping the column indexes
+    let metadata_column = if query.include_metadata.unwrap_or(false) {
+        "r.metadata"
+    } else {
+        "NULL"
+    };
 
     // Fetch executions with workflow name
     // Using a helper function to handle different database types
-    let execution_responses: Vec<ExecutionResponse> = match &state.db_pool {
+    let execution_responses: Vec<ExecutionResponse> = match &state.db_read_pool {
         DatabasePool::MySql(p) => {
-            let rows = sqlx::query(
+            let sql = format!(
                 "SELECT 
                     r.uuid,
                     r.status,
@@ -1165,32 +1560,30 @@ pub async fn get_last_executions(
                     DATE_FORMAT(r.started_at, '%Y-%m-%d %H:%i:%s') as started_at,
                     DATE_FORMAT(r.finished_at, '%Y-%m-%d %H:%i:%s') as finished_at,
                     r.trigger_type,
-                    r.metadata
+                    {metadata_column} as metadata
                  FROM runs r
                  LEFT JOIN workflows w ON r.workflow_id = w.uuid
                  WHERE r.organization_uuid = ?
                  ORDER BY r.created_at DESC
                  LIMIT ? OFFSET ?"
+            );
+            let rows = with_statement_timeout(
+                EXECUTIONS_QUERY_TIMEOUT,
+                sqlx::query(&sql)
+                    .bind(&org_uuid)
+                    .bind(limit as i64)
+                    .bind(offset as i64)
+                    .fetch_all(p),
             )
-            .bind(&org_uuid)
-            .bind(limit as i64)
-            .bind(offset as i64)
-            .fetch_all(p)
             .await
-            .map_err(|e| {
-                tracing::error!("Failed to fetch executions: {}", e);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(json!({ "error": "Failed to fetch executions" })),
-                )
-            })?;
+            .map_err(executions_query_error)?;
 
             rows.into_iter()
                 .map(|row| extract_execution_from_row(row))
                 .collect()
         }
         DatabasePool::Postgres(p) => {
-            let rows = sqlx::query(
+            let sql = format!(
                 "SELECT 
                     r.uuid,
                     r.status,
@@ -1199,32 +1592,33 @@ pub async fn get_last_executions(
                     TO_CHAR(r.started_at, 'YYYY-MM-DD HH24:MI:SS') as started_at,
                     TO_CHAR(r.finished_at, 'YYYY-MM-DD HH24:MI:SS') as finished_at,
                     r.trigger_type,
-                    r.metadata
+                    {metadata_column} as metadata
                  FROM runs r
                  LEFT JOIN workflows w ON r.workflow_id = w.uuid
                  WHERE r.organization_uuid = $1
                  ORDER BY r.created_at DESC
                  LIMIT $2 OFFSET $3"
-            )
-            .bind(&org_uuid)
-            .bind(limit as i64)
-            .bind(offset as i64)
-            .fetch_all(p)
+            );
+            let rows = with_statement_timeout(EXECUTIONS_QUERY_TIMEOUT, async {
+                let mut tx = begin_with_statement_timeout(p, EXECUTIONS_QUERY_TIMEOUT).await?;
+                let rows = sqlx::query(&sql)
+                    .bind(&org_uuid)
+                    .bind(limit as i64)
+                    .bind(offset as i64)
+                    .fetch_all(&mut *tx)
+                    .await?;
+                tx.commit().await?;
+                Ok(rows)
+            })
             .await
-            .map_err(|e| {
-                tracing::error!("Failed to fetch executions: {}", e);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(json!({ "error": "Failed to fetch executions" })),
-                )
-            })?;
+            .map_err(executions_query_error)?;
 
             rows.into_iter()
                 .map(|row| extract_execution_from_row(row))
                 .collect()
         }
         DatabasePool::Sqlite(p) => {
-            let rows = sqlx::query(
+            let sql = format!(
                 "SELECT 
                     r.uuid,
                     r.status,
@@ -1233,25 +1627,23 @@ pub async fn get_last_executions(
                     strftime('%Y-%m-%d %H:%M:%S', r.started_at) as started_at,
                     strftime('%Y-%m-%d %H:%M:%S', r.finished_at) as finished_at,
                     r.trigger_type,
-                    r.metadata
+                    {metadata_column} as metadata
                  FROM runs r
                  LEFT JOIN workflows w ON r.workflow_id = w.uuid
                  WHERE r.organization_uuid = ?1
                  ORDER BY r.created_at DESC
                  LIMIT ?2 OFFSET ?3"
+            );
+            let rows = with_statement_timeout(
+                EXECUTIONS_QUERY_TIMEOUT,
+                sqlx::query(&sql)
+                    .bind(&org_uuid)
+                    .bind(limit as i64)
+                    .bind(offset as i64)
+                    .fetch_all(p),
             )
-            .bind(&org_uuid)
-            .bind(limit as i64)
-            .bind(offset as i64)
-            .fetch_all(p)
             .await
-            .map_err(|e| {
-                tracing::error!("Failed to fetch executions: {}", e);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(json!({ "error": "Failed to fetch executions" })),
-                )
-            })?;
+            .map_err(executions_query_error)?;
 
             rows.into_iter()
                 .map(|row| extract_execution_from_row(row))
@@ -1270,6 +1662,171 @@ pub async fn get_last_executions(
     }))
 }
 
+/// Get a single execution
+///
+/// GET /api/executions/{execution_uuid}
+/// Executions of other organizations are reported as not found.
+pub async fn get_execution(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(org_uuid): Extension<String>,
+    Path(execution_uuid): Path<String>,
+) -> Result<Json<ExecutionResponse>, (StatusCode, Json<Value>)> {
+    use flextide_core::database::DatabasePool;
+    use flextide_core::user::{user_belongs_to_organization, user_has_permission};
+
+    // Check if user belongs to organization
+    let belongs = user_belongs_to_organization(&state.db_pool, &claims.user_uuid, &org_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !belongs {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not belong to this organization" })),
+        ));
+    }
+
+    // Check permission
+    let has_permission = user_has_permission(
+        &state.db_pool,
+        &claims.user_uuid,
+        &org_uuid,
+        "can_see_last_executions",
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error checking permission: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "Database error" })),
+        )
+    })?;
+
+    if !has_permission {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({
+                "error": "User does not have permission to see last executions"
+            })),
+        ));
+    }
+
+    let map_error = |e: sqlx::Error| {
+        tracing::error!("Failed to fetch execution {}: {}", execution_uuid, e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "Failed to fetch execution" })),
+        )
+    };
+
+    // Read-only, may be served by the read replica
+    let execution: Option<(String, ExecutionResponse)> = match &state.db_read_pool {
+        DatabasePool::MySql(p) => sqlx::query(
+            "SELECT 
+                r.uuid,
+                r.status,
+                COALESCE(SUBSTRING(w.name, 1, 50), 'Unknown') as workflow_name,
+                r.workflow_id,
+                DATE_FORMAT(r.started_at, '%Y-%m-%d %H:%i:%s') as started_at,
+                DATE_FORMAT(r.finished_at, '%Y-%m-%d %H:%i:%s') as finished_at,
+                r.trigger_type,
+                r.metadata,
+                r.organization_uuid
+             FROM runs r
+             LEFT JOIN workflows w ON r.workflow_id = w.uuid
+             WHERE r.uuid = ?"
+        )
+        .bind(&execution_uuid)
+        .fetch_optional(p)
+        .await
+        .map_err(map_error)?
+        .map(|row| (row.get(8usize), extract_execution_from_row(row))),
+        DatabasePool::Postgres(p) => sqlx::query(
+            "SELECT 
+                r.uuid,
+                r.status,
+                COALESCE(SUBSTRING(w.name, 1, 50), 'Unknown') as workflow_name,
+                r.workflow_id,
+                TO_CHAR(r.started_at, 'YYYY-MM-DD HH24:MI:SS') as started_at,
+                TO_CHAR(r.finished_at, 'YYYY-MM-DD HH24:MI:SS') as finished_at,
+                r.trigger_type,
+                r.metadata,
+                r.organization_uuid
+             FROM runs r
+             LEFT JOIN workflows w ON r.workflow_id = w.uuid
+             WHERE r.uuid = $1"
+        )
+        .bind(&execution_uuid)
+        .fetch_optional(p)
+        .await
+        .map_err(map_error)?
+        .map(|row| (row.get(8usize), extract_execution_from_row(row))),
+        DatabasePool::Sqlite(p) => sqlx::query(
+            "SELECT 
+                r.uuid,
+                r.status,
+                COALESCE(SUBSTRING(w.name, 1, 50), 'Unknown') as workflow_name,
+                r.workflow_id,
+                strftime('%Y-%m-%d %H:%M:%S', r.started_at) as started_at,
+                strftime('%Y-%m-%d %H:%M:%S', r.finished_at) as finished_at,
+                r.trigger_type,
+                r.metadata,
+                r.organization_uuid
+             FROM runs r
+             LEFT JOIN workflows w ON r.workflow_id = w.uuid
+             WHERE r.uuid = ?1"
+        )
+        .bind(&execution_uuid)
+        .fetch_optional(p)
+        .await
+        .map_err(map_error)?
+        .map(|row| (row.get(8usize), extract_execution_from_row(row))),
+    };
+
+    let Some((execution_org_uuid, execution)) = execution else {
+        return Err(resource_not_found("Execution"));
+    };
+    ensure_resource_in_organization(&execution_org_uuid, &org_uuid, "Execution")?;
+
+    Ok(Json(execution))
+}
+
+/// Load the organization UUID of a workflow, `None` if the workflow does not exist
+async fn load_workflow_organization_uuid(
+    pool: &flextide_core::database::DatabasePool,
+    workflow_uuid: &str,
+) -> Result<Option<String>, sqlx::Error> {
+    use flextide_core::database::DatabasePool;
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query_scalar("SELECT organization_uuid FROM workflows WHERE uuid = ?")
+                .bind(workflow_uuid)
+                .fetch_optional(p)
+                .await
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query_scalar("SELECT organization_uuid FROM workflows WHERE uuid = $1")
+                .bind(workflow_uuid)
+                .fetch_optional(p)
+                .await
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query_scalar("SELECT organization_uuid FROM workflows WHERE uuid = ?1")
+                .bind(workflow_uuid)
+                .fetch_optional(p)
+                .await
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct EditWorkflowTitleRequest {
     pub title: String,
@@ -1277,61 +1834,29 @@ pub struct EditWorkflowTitleRequest {
 
 pub async fn edit_workflow_title(
     Path(workflow_uuid): Path<String>,
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
+    Extension(org_uuid): Extension<String>,
     Json(payload): Json<EditWorkflowTitleRequest>,
 ) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
-    // Validate title length
-    if payload.title.trim().is_empty() {
-        tracing::warn!(
-            "Workflow {} title update failed: Title cannot be empty",
-            workflow_uuid
-        );
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(json!({ "error": "Title cannot be empty" })),
-        ));
-    }
-
-    if payload.title.len() > 50 {
-        tracing::warn!(
-            "Workflow {} title update failed: Title length {} exceeds maximum of 50 characters",
-            workflow_uuid,
-            payload.title.len()
-        );
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(json!({ "error": "Title cannot exceed 50 characters" })),
-        ));
+    if let Err(error) = workflows::validate_workflow_title(&payload.title) {
+        tracing::warn!("Workflow {} title update failed: {}", workflow_uuid, error);
+        return Err((StatusCode::BAD_REQUEST, Json(json!({ "error": error }))));
     }
 
-    // Validate for invalid characters (control characters, invisible characters)
-    // Check for control characters (except normal whitespace like space, tab, newline)
-    // and invisible Unicode characters
-    if payload.title.chars().any(|c| {
-        // Control characters (except common whitespace)
-        (c.is_control() && !matches!(c, '\t' | '\n' | '\r')) ||
-        // Zero-width characters
-        matches!(c, 
-            '\u{200B}' | // Zero Width Space
-            '\u{200C}' | // Zero Width Non-Joiner
-            '\u{200D}' | // Zero Width Joiner
-            '\u{FEFF}' | // Zero Width No-Break Space
-            '\u{00AD}'   // Soft Hyphen
-        ) ||
-        // Bidirectional formatting characters
-        matches!(c, '\u{200E}'..='\u{200F}' | '\u{202A}'..='\u{202E}') ||
-        // Other invisible formatting characters
-        matches!(c, '\u{2060}'..='\u{206F}')
-    }) {
-        tracing::warn!(
-            "Workflow {} title update failed: Title contains invalid characters (control or invisible characters)",
-            workflow_uuid
-        );
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(json!({ "error": "Title contains invalid characters (control or invisible characters are not allowed)" })),
-        ));
-    }
+    // Workflows of other organizations are reported as not found
+    let workflow_org_uuid = load_workflow_organization_uuid(&state.db_pool, &workflow_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to load workflow {}: {}", workflow_uuid, e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to load workflow" })),
+            )
+        })?;
+    let Some(workflow_org_uuid) = workflow_org_uuid else {
+        return Err(resource_not_found("Workflow"));
+    };
+    ensure_resource_in_organization(&workflow_org_uuid, &org_uuid, "Workflow")?;
 
     // Mock: Log the title change (in production, save to database)
     tracing::info!(
@@ -1383,8 +1908,7 @@ pub async fn get_integrations(
 pub struct ListIntegrationsQuery {
     #[serde(default = "default_page")]
     pub page: u32,
-    #[serde(default = "default_limit")]
-    pub limit: u32,
+    pub limit: Option<u32>,
 }
 
 /// List all available integrations with pagination
@@ -1393,13 +1917,12 @@ pub struct ListIntegrationsQuery {
 /// Returns a paginated list of all integrations (activated and not activated)
 pub async fn list_integrations(
     Query(query): Query<ListIntegrationsQuery>,
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Extension(_claims): Extension<Claims>,
     Extension(_org_uuid): Extension<String>,
 ) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
-    let page = query.page.max(1);
-    let limit = query.limit.min(100).max(1);
-    let offset = (page - 1) * limit;
+    let Pagination { page, limit, offset } =
+        state.pagination.clamp(PaginatedResource::Integrations, query.page, query.limit);
 
     // Mock data - in production, this would come from database
     // This includes all available integrations
@@ -1484,7 +2007,7 @@ pub async fn list_integrations(
 
     let total = all_integrations.len() as u32;
     let start = offset as usize;
-    let end = (offset + limit) as usize;
+    let end = start + limit as usize;
     let paginated_integrations: Vec<Value> = all_integrations
         .into_iter()
         .skip(start)
@@ -1507,8 +2030,7 @@ pub struct SearchIntegrationsQuery {
     pub q: String,
     #[serde(default = "default_page")]
     pub page: u32,
-    #[serde(default = "default_limit")]
-    pub limit: u32,
+    pub limit: Option<u32>,
 }
 
 /// Search integrations
@@ -1517,7 +2039,7 @@ pub struct SearchIntegrationsQuery {
 /// Returns a paginated list of integrations matching the search query
 pub async fn search_integrations(
     Query(query): Query<SearchIntegrationsQuery>,
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Extension(_claims): Extension<Claims>,
     Extension(_org_uuid): Extension<String>,
 ) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
@@ -1530,9 +2052,8 @@ pub async fn search_integrations(
         ));
     }
 
-    let page = query.page.max(1);
-    let limit = query.limit.min(100).max(1);
-    let offset = (page - 1) * limit;
+    let Pagination { page, limit, offset } =
+        state.pagination.clamp(PaginatedResource::Integrations, query.page, query.limit);
 
     // Mock data - same as list_integrations
     let all_integrations = vec![
@@ -1632,7 +2153,7 @@ pub async fn search_integrations(
 
     let total = filtered.len() as u32;
     let start = offset as usize;
-    let end = (offset + limit) as usize;
+    let end = start + limit as usize;
     let paginated_integrations: Vec<Value> = filtered
         .into_iter()
         .skip(start)
@@ -1728,6 +2249,8 @@ pub async fn list_webhooks(
                 "url": w.url,
                 "secret": w.secret.is_some(),
                 "headers": w.headers,
+                "payload_template": w.payload_template,
+                "content_type": w.content_type,
                 "active": w.active,
                 "created_by": w.created_by,
                 "created_at": w.created_at.to_rfc3339(),
@@ -1794,6 +2317,15 @@ pub async fn create_webhook(
         ));
     }
 
+    if let Some(template) = &payload.payload_template {
+        flextide_core::events::validate_payload_template(template).map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": e.to_string() })),
+            )
+        })?;
+    }
+
     let webhook_id = flextide_core::events::create_webhook(
         &state.db_pool,
         &org_uuid,
@@ -1850,6 +2382,8 @@ pub async fn get_webhook(
             "url": w.url,
             "secret": w.secret.is_some(),
             "headers": w.headers,
+            "payload_template": w.payload_template,
+            "content_type": w.content_type,
             "active": w.active,
             "created_by": w.created_by,
             "created_at": w.created_at.to_rfc3339(),
@@ -1918,6 +2452,15 @@ pub async fn update_webhook(
         ));
     }
 
+    if let Some(template) = &payload.payload_template {
+        flextide_core::events::validate_payload_template(template).map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": e.to_string() })),
+            )
+        })?;
+    }
+
     flextide_core::events::update_webhook(&state.db_pool, &webhook_id, &org_uuid, &payload)
         .await
         .map_err(|e| {
@@ -1940,6 +2483,102 @@ pub async fn update_webhook(
     })))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ListWebhookDeliveriesQuery {
+    /// Number of delivery attempts to return, defaults to the configured default page size
+    pub limit: Option<u32>,
+}
+
+/// List recent delivery attempts of a webhook
+///
+/// GET /api/webhooks/{id}/deliveries
+/// Returns the newest delivery attempts (including retries) first
+pub async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(org_uuid): Extension<String>,
+    Path(webhook_id): Path<String>,
+    Query(query): Query<ListWebhookDeliveriesQuery>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    use flextide_core::user::{user_belongs_to_organization, user_has_permission};
+
+    // Check if user belongs to organization
+    let belongs = user_belongs_to_organization(&state.db_pool, &claims.user_uuid, &org_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !belongs {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not belong to this organization" })),
+        ));
+    }
+
+    // Check permission
+    let has_permission = user_has_permission(
+        &state.db_pool,
+        &claims.user_uuid,
+        &org_uuid,
+        "organization_can_see_event_webhooks",
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error checking permission: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "Database error" })),
+        )
+    })?;
+
+    if !has_permission {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({
+                "error": "User does not have permission to see event webhooks"
+            })),
+        ));
+    }
+
+    // Only webhooks of the current organization
+    let webhook = flextide_core::events::get_webhook(&state.db_pool, &webhook_id, &org_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get webhook: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to get webhook" })),
+            )
+        })?;
+
+    if webhook.is_none() {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(json!({ "error": "Webhook not found" })),
+        ));
+    }
+
+    let Pagination { limit, .. } =
+        state.pagination.clamp(PaginatedResource::WebhookDeliveries, 1, query.limit);
+
+    let deliveries = flextide_core::events::list_webhook_deliveries(&state.db_pool, &webhook_id, limit)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list webhook deliveries: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to list webhook deliveries" })),
+            )
+        })?;
+
+    Ok(Json(json!(deliveries)))
+}
+
 /// Delete a webhook
 ///
 /// DELETE /api/webhooks/{id}
@@ -0,0 +1,181 @@
+//! Request metrics
+//!
+//! Collects per-route request counts, error counts and latency histograms and
+//! exposes them to server admins in the Prometheus text format.
+
+use axum::{
+    extract::{Extension, MatchedPath, Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+    routing::get,
+    Router,
+};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::{AppState, Claims};
+
+/// Upper bounds (in seconds) of the latency histogram buckets
+pub const LATENCY_BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Route label of requests that did not match any route
+///
+/// Unmatched paths are grouped to keep the number of series bounded.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Metrics of a single route and method
+#[derive(Debug, Clone, Default)]
+struct RouteMetrics {
+    requests: u64,
+    errors: u64,
+    /// Cumulative counts per bucket of `LATENCY_BUCKETS`
+    latency_buckets: Vec<u64>,
+    latency_sum: f64,
+}
+
+/// Shared registry of request metrics
+///
+/// Cheap to clone; all clones share the same metrics.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsRegistry {
+    routes: Arc<Mutex<BTreeMap<(String, String), RouteMetrics>>>,
+}
+
+impl MetricsRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a completed request
+    ///
+    /// Responses with a status of 400 or above count as errors.
+    pub fn record(&self, method: &str, route: &str, status: StatusCode, latency: Duration) {
+        let seconds = latency.as_secs_f64();
+        let mut routes = self.routes.lock().unwrap();
+        let metrics = routes.entry((method.to_string(), route.to_string())).or_default();
+
+        if metrics.latency_buckets.is_empty() {
+            metrics.latency_buckets = vec![0; LATENCY_BUCKETS.len()];
+        }
+        metrics.requests += 1;
+        if status.is_client_error() || status.is_server_error() {
+            metrics.errors += 1;
+        }
+        metrics.latency_sum += seconds;
+        for (count, bound) in metrics.latency_buckets.iter_mut().zip(LATENCY_BUCKETS) {
+            if seconds <= *bound {
+                *count += 1;
+            }
+        }
+    }
+
+    /// Render all metrics in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let routes = self.routes.lock().unwrap();
+        let mut output = String::new();
+
+        output.push_str("# HELP flextide_http_requests_total Total number of HTTP requests.\n");
+        output.push_str("# TYPE flextide_http_requests_total counter\n");
+        for ((method, route), metrics) in routes.iter() {
+            let _ = writeln!(output, "flextide_http_requests_total{{{}}} {}", labels(method, route), metrics.requests);
+        }
+
+        output.push_str("# HELP flextide_http_request_errors_total Total number of HTTP requests answered with status >= 400.\n");
+        output.push_str("# TYPE flextide_http_request_errors_total counter\n");
+        for ((method, route), metrics) in routes.iter() {
+            let _ = writeln!(
+                output,
+                "flextide_http_request_errors_total{{{}}} {}",
+                labels(method, route),
+                metrics.errors
+            );
+        }
+
+        output.push_str("# HELP flextide_http_request_duration_seconds HTTP request latency in seconds.\n");
+        output.push_str("# TYPE flextide_http_request_duration_seconds histogram\n");
+        for ((method, route), metrics) in routes.iter() {
+            let labels = labels(method, route);
+            for (count, bound) in metrics.latency_buckets.iter().zip(LATENCY_BUCKETS) {
+                let _ = writeln!(
+                    output,
+                    "flextide_http_request_duration_seconds_bucket{{{},le=\"{}\"}} {}",
+                    labels, bound, count
+                );
+            }
+            let _ = writeln!(
+                output,
+                "flextide_http_request_duration_seconds_bucket{{{},le=\"+Inf\"}} {}",
+                labels, metrics.requests
+            );
+            let _ = writeln!(
+                output,
+                "flextide_http_request_duration_seconds_sum{{{}}} {}",
+                labels, metrics.latency_sum
+            );
+            let _ = writeln!(
+                output,
+                "flextide_http_request_duration_seconds_count{{{}}} {}",
+                labels, metrics.requests
+            );
+        }
+
+        output
+    }
+}
+
+/// Label set of a route, with label values escaped
+fn labels(method: &str, route: &str) -> String {
+    let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+    format!("method=\"{}\",route=\"{}\"", escape(method), escape(route))
+}
+
+/// Metrics middleware - records count, errors and latency of every request
+///
+/// Requests are grouped by method and route template (e.g. `/api/webhooks/{id}`),
+/// not by the concrete path.
+pub async fn metrics_middleware(State(registry): State<MetricsRegistry>, request: Request, next: Next) -> Response {
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+
+    let started = Instant::now();
+    let response = next.run(request).await;
+    registry.record(&method, &route, response.status(), started.elapsed());
+
+    response
+}
+
+/// Get the request metrics in the Prometheus text format
+///
+/// GET /api/metrics
+pub async fn get_metrics(
+    Extension(claims): Extension<Claims>,
+    Extension(registry): Extension<MetricsRegistry>,
+) -> Response {
+    // Check if user is server admin
+    if !claims.is_server_admin {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "Server admin access required" })),
+        )
+            .into_response();
+    }
+
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")],
+        registry.render(),
+    )
+        .into_response()
+}
+
+pub fn create_router() -> Router<AppState> {
+    Router::new().route("/metrics", get(get_metrics))
+}
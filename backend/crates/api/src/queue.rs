@@ -0,0 +1,49 @@
+//! Queue API endpoints
+//!
+//! Gives server admins visibility into the workflow execution queue.
+
+use axum::{
+    extract::{Extension, State},
+    http::StatusCode,
+    response::Json,
+    routing::get,
+    Router,
+};
+use serde_json::{json, Value};
+
+use crate::{AppState, Claims};
+
+/// Get the queue depth per organization
+///
+/// GET /api/admin/queue/snapshot
+pub async fn get_queue_snapshot(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    // Check if user is server admin
+    if !claims.is_server_admin {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "Server admin access required" })),
+        ));
+    }
+
+    let organizations = flextide_core::queue::snapshot(&state.db_pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to take queue snapshot: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to take queue snapshot" })),
+            )
+        })?;
+
+    Ok(Json(json!({
+        "organizations": organizations
+    })))
+}
+
+/// Create router for queue endpoints
+pub fn create_router() -> Router<AppState> {
+    Router::new().route("/admin/queue/snapshot", get(get_queue_snapshot))
+}
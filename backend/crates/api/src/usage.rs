@@ -0,0 +1,139 @@
+//! Organization usage API endpoints
+//!
+//! Reports resource counts of an organization for billing and quotas.
+
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    response::Json,
+    routing::get,
+    Router,
+};
+use chrono::{Duration, Utc};
+use flextide_core::database::DatabasePool;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::{AppState, Claims};
+
+/// Number of days counted as recent for executions
+pub const RECENT_EXECUTIONS_DAYS: i64 = 30;
+
+#[derive(Debug, Serialize)]
+pub struct OrganizationUsageResponse {
+    pub organization_uuid: String,
+    pub customers: u32,
+    pub docs_pages: i64,
+    /// Active webhooks
+    pub webhooks: usize,
+    /// Executions started within the last `RECENT_EXECUTIONS_DAYS` days
+    pub recent_executions: i64,
+    pub recent_execution_credits: i64, // TODO: Add credits tracking later, defaulting to 0
+}
+
+/// Count the runs of an organization created since the cutoff
+async fn count_runs_since(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    cutoff: chrono::DateTime<Utc>,
+) -> Result<i64, sqlx::Error> {
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM runs WHERE organization_uuid = ? AND created_at >= ?")
+                .bind(organization_uuid)
+                .bind(cutoff)
+                .fetch_one(p)
+                .await
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM runs WHERE organization_uuid = $1 AND created_at >= $2",
+            )
+            .bind(organization_uuid)
+            .bind(cutoff)
+            .fetch_one(p)
+            .await
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM runs WHERE organization_uuid = ?1 AND datetime(created_at) >= datetime(?2)",
+            )
+            .bind(organization_uuid)
+            .bind(cutoff.format("%Y-%m-%d %H:%M:%S").to_string())
+            .fetch_one(p)
+            .await
+        }
+    }
+}
+
+/// Get the resource usage of an organization
+///
+/// GET /api/organizations/{organization_uuid}/usage
+///
+/// Only administrators (owner or admin role) of the organization may see its usage.
+pub async fn get_organization_usage(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(organization_uuid): Path<String>,
+) -> Result<Json<OrganizationUsageResponse>, (StatusCode, Json<Value>)> {
+    use flextide_core::user::user_is_organization_admin;
+
+    let internal_error = |what: &str, e: &dyn std::fmt::Display| {
+        tracing::error!("Failed to {} for organization {}: {}", what, organization_uuid, e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "Failed to fetch organization usage" })),
+        )
+    };
+
+    let is_admin = user_is_organization_admin(&state.db_pool, &claims.user_uuid, &organization_uuid)
+        .await
+        .map_err(|e| internal_error("check organization admin role", &e))?;
+
+    if !is_admin {
+        tracing::warn!(
+            "User {} tried to see the usage of organization {} without being an organization admin",
+            claims.user_uuid,
+            organization_uuid
+        );
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "Only organization admins can see the organization usage" })),
+        ));
+    }
+
+    // Read-only, may be served by the read replica
+    let pool = &state.db_read_pool;
+
+    let customers = flextide_modules_crm::CrmCustomer::count_customers(pool, &organization_uuid)
+        .await
+        .map_err(|e| internal_error("count customers", &e))?;
+    let docs_pages = flextide_modules_docs::docs_stats(pool, &organization_uuid)
+        .await
+        .map_err(|e| internal_error("count docs pages", &e))?
+        .total_pages;
+    let webhooks = flextide_core::events::load_webhooks_by_organization(pool, &organization_uuid)
+        .await
+        .map_err(|e| internal_error("count webhooks", &e))?
+        .len();
+    let recent_executions = count_runs_since(
+        pool,
+        &organization_uuid,
+        Utc::now() - Duration::days(RECENT_EXECUTIONS_DAYS),
+    )
+    .await
+    .map_err(|e| internal_error("count recent executions", &e))?;
+
+    Ok(Json(OrganizationUsageResponse {
+        organization_uuid: organization_uuid.clone(),
+        customers,
+        docs_pages,
+        webhooks,
+        recent_executions,
+        recent_execution_credits: 0,
+    }))
+}
+
+pub fn create_router() -> Router<AppState> {
+    Router::new().route("/organizations/{organization_uuid}/usage", get(get_organization_usage))
+}
@@ -0,0 +1,764 @@
+//! Workflow API endpoints
+//!
+//! Lists, creates, archives, exports and imports the workflows of an organization.
+
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    routing::{delete, get, post},
+    Router,
+};
+use chrono::{DateTime, Utc};
+use flextide_core::database::DatabasePool;
+use flextide_core::jwt::Claims;
+use flextide_core::pagination::{PaginatedResource, Pagination};
+use flextide_core::user::{user_belongs_to_organization, user_has_permission};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::Row;
+
+use crate::{ensure_resource_in_organization, load_workflow_organization_uuid, resource_not_found, AppState};
+
+/// Maximum length of a workflow title in bytes
+pub const MAX_WORKFLOW_TITLE_LENGTH: usize = 50;
+
+/// Version of the workflow export document, increased on incompatible changes
+pub const WORKFLOW_EXPORT_VERSION: u32 = 1;
+
+/// Definition of a newly created workflow, without nodes and edges
+const EMPTY_WORKFLOW_DEFINITION: &str = r#"{"nodes":[],"edges":[]}"#;
+
+/// Validate a workflow title
+///
+/// Returns the error message for the client if the title is empty, too long or contains
+/// control or invisible characters.
+pub fn validate_workflow_title(title: &str) -> Result<(), &'static str> {
+    if title.trim().is_empty() {
+        return Err("Title cannot be empty");
+    }
+
+    if title.len() > MAX_WORKFLOW_TITLE_LENGTH {
+        return Err("Title cannot exceed 50 characters");
+    }
+
+    // Check for control characters (except normal whitespace like space, tab, newline)
+    // and invisible Unicode characters
+    if title.chars().any(|c| {
+        // Control characters (except common whitespace)
+        (c.is_control() && !matches!(c, '\t' | '\n' | '\r')) ||
+        // Zero-width characters
+        matches!(c,
+            '\u{200B}' | // Zero Width Space
+            '\u{200C}' | // Zero Width Non-Joiner
+            '\u{200D}' | // Zero Width Joiner
+            '\u{FEFF}' | // Zero Width No-Break Space
+            '\u{00AD}'   // Soft Hyphen
+        ) ||
+        // Bidirectional formatting characters
+        matches!(c, '\u{200E}'..='\u{200F}' | '\u{202A}'..='\u{202E}') ||
+        // Other invisible formatting characters
+        matches!(c, '\u{2060}'..='\u{206F}')
+    }) {
+        return Err("Title contains invalid characters (control or invisible characters are not allowed)");
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListWorkflowsQuery {
+    #[serde(default = "crate::default_page")]
+    pub page: u32,
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateWorkflowRequest {
+    pub title: String,
+}
+
+/// Workflow document as returned by the export endpoint
+///
+/// Fields of the export that are not needed for the import (e.g. `uuid`) are ignored.
+#[derive(Debug, Deserialize)]
+pub struct WorkflowImportRequest {
+    pub export_version: u32,
+    pub workflow: ImportedWorkflow,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportedWorkflow {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub nodes: Vec<Value>,
+    #[serde(default)]
+    pub edges: Vec<Value>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WorkflowListItem {
+    pub uuid: String,
+    pub name: String,
+    pub created_at: String,
+    /// Status of the most recently created run, `None` if the workflow never ran
+    pub last_run_status: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListWorkflowsResponse {
+    pub workflows: Vec<WorkflowListItem>,
+    pub total: i64,
+    pub page: u32,
+    pub limit: u32,
+    pub total_pages: u32,
+}
+
+/// Versioned document of an exported workflow
+#[derive(Debug, Serialize)]
+pub struct WorkflowExport {
+    pub export_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub workflow: ExportedWorkflow,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportedWorkflow {
+    pub uuid: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub status: String,
+    pub nodes: Vec<Value>,
+    pub edges: Vec<Value>,
+}
+
+/// Helper function to extract a workflow list item from a database row
+fn extract_workflow_from_row<R: Row>(row: R) -> WorkflowListItem
+where
+    for<'r> &'r str: sqlx::ColumnIndex<R>,
+    for<'r> String: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> Option<String>: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+{
+    WorkflowListItem {
+        uuid: row.get("uuid"),
+        name: row.get("name"),
+        created_at: row.get("created_at"),
+        last_run_status: row.get("last_run_status"),
+    }
+}
+
+/// Count the workflows of an organization, without archived workflows
+async fn count_workflows(pool: &DatabasePool, organization_uuid: &str) -> Result<i64, sqlx::Error> {
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM workflows WHERE organization_uuid = ? AND archived_at IS NULL")
+                .bind(organization_uuid)
+                .fetch_one(p)
+                .await
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM workflows WHERE organization_uuid = $1 AND archived_at IS NULL")
+                .bind(organization_uuid)
+                .fetch_one(p)
+                .await
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM workflows WHERE organization_uuid = ?1 AND archived_at IS NULL")
+                .bind(organization_uuid)
+                .fetch_one(p)
+                .await
+        }
+    }
+}
+
+/// Load a page of workflows of an organization, newest first, with the status of their latest run
+///
+/// Archived workflows are skipped.
+async fn load_workflows(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    limit: u32,
+    offset: u32,
+) -> Result<Vec<WorkflowListItem>, sqlx::Error> {
+    let workflows = match pool {
+        DatabasePool::MySql(p) => sqlx::query(
+            "SELECT
+                w.uuid,
+                w.name,
+                DATE_FORMAT(w.created_at, '%Y-%m-%d %H:%i:%s') as created_at,
+                (SELECT r.status FROM runs r
+                 WHERE r.workflow_id = w.uuid
+                 ORDER BY r.created_at DESC
+                 LIMIT 1) as last_run_status
+             FROM workflows w
+             WHERE w.organization_uuid = ? AND w.archived_at IS NULL
+             ORDER BY w.created_at DESC, w.uuid
+             LIMIT ? OFFSET ?",
+        )
+        .bind(organization_uuid)
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(p)
+        .await?
+        .into_iter()
+        .map(extract_workflow_from_row)
+        .collect(),
+        DatabasePool::Postgres(p) => sqlx::query(
+            "SELECT
+                w.uuid,
+                w.name,
+                TO_CHAR(w.created_at, 'YYYY-MM-DD HH24:MI:SS') as created_at,
+                (SELECT r.status FROM runs r
+                 WHERE r.workflow_id = w.uuid
+                 ORDER BY r.created_at DESC
+                 LIMIT 1) as last_run_status
+             FROM workflows w
+             WHERE w.organization_uuid = $1 AND w.archived_at IS NULL
+             ORDER BY w.created_at DESC, w.uuid
+             LIMIT $2 OFFSET $3",
+        )
+        .bind(organization_uuid)
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(p)
+        .await?
+        .into_iter()
+        .map(extract_workflow_from_row)
+        .collect(),
+        DatabasePool::Sqlite(p) => sqlx::query(
+            "SELECT
+                w.uuid,
+                w.name,
+                strftime('%Y-%m-%d %H:%M:%S', w.created_at) as created_at,
+                (SELECT r.status FROM runs r
+                 WHERE r.workflow_id = w.uuid
+                 ORDER BY r.created_at DESC
+                 LIMIT 1) as last_run_status
+             FROM workflows w
+             WHERE w.organization_uuid = ?1 AND w.archived_at IS NULL
+             ORDER BY w.created_at DESC, w.uuid
+             LIMIT ?2 OFFSET ?3",
+        )
+        .bind(organization_uuid)
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(p)
+        .await?
+        .into_iter()
+        .map(extract_workflow_from_row)
+        .collect(),
+    };
+
+    Ok(workflows)
+}
+
+/// Helper function to extract the organization UUID and the exported workflow from a database row
+fn extract_exported_workflow_from_row<R: Row>(row: R) -> (String, ExportedWorkflow)
+where
+    for<'r> &'r str: sqlx::ColumnIndex<R>,
+    for<'r> String: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> Option<String>: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> Value: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+{
+    // JSON column on MySQL and PostgreSQL, text on SQLite
+    let definition: Value = row
+        .try_get::<Value, _>("definition")
+        .ok()
+        .or_else(|| {
+            row.try_get::<String, _>("definition")
+                .ok()
+                .and_then(|s| serde_json::from_str::<Value>(&s).ok())
+        })
+        .unwrap_or(Value::Null);
+    let graph_part = |key: &str| {
+        definition
+            .get(key)
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default()
+    };
+
+    (
+        row.get("organization_uuid"),
+        ExportedWorkflow {
+            uuid: row.get("uuid"),
+            name: row.get("name"),
+            description: row.get("description"),
+            status: row.get("status"),
+            nodes: graph_part("nodes"),
+            edges: graph_part("edges"),
+        },
+    )
+}
+
+/// Load a workflow for export together with its organization UUID, `None` if it does not exist
+async fn load_exported_workflow(
+    pool: &DatabasePool,
+    workflow_uuid: &str,
+) -> Result<Option<(String, ExportedWorkflow)>, sqlx::Error> {
+    let workflow = match pool {
+        DatabasePool::MySql(p) => sqlx::query(
+            "SELECT uuid, organization_uuid, name, description, status, definition
+             FROM workflows WHERE uuid = ?",
+        )
+        .bind(workflow_uuid)
+        .fetch_optional(p)
+        .await?
+        .map(extract_exported_workflow_from_row),
+        DatabasePool::Postgres(p) => sqlx::query(
+            "SELECT uuid, organization_uuid, name, description, status, definition
+             FROM workflows WHERE uuid = $1",
+        )
+        .bind(workflow_uuid)
+        .fetch_optional(p)
+        .await?
+        .map(extract_exported_workflow_from_row),
+        DatabasePool::Sqlite(p) => sqlx::query(
+            "SELECT uuid, organization_uuid, name, description, status, definition
+             FROM workflows WHERE uuid = ?1",
+        )
+        .bind(workflow_uuid)
+        .fetch_optional(p)
+        .await?
+        .map(extract_exported_workflow_from_row),
+    };
+
+    Ok(workflow)
+}
+
+/// Check an imported workflow against the node types of the registry
+///
+/// Every node needs an `id` and a `type` known to the registry, and every edge must
+/// connect nodes of the workflow. Returns a descriptive error message otherwise.
+fn validate_imported_workflow(
+    registry: &flextide_node_registry::NodeRegistry,
+    workflow: &ImportedWorkflow,
+) -> Result<(), String> {
+    let mut node_ids = Vec::with_capacity(workflow.nodes.len());
+    for node in &workflow.nodes {
+        let Some(id) = node.get("id").and_then(Value::as_str) else {
+            return Err("Every node needs an 'id'".to_string());
+        };
+        let Some(node_type) = node.get("type").and_then(Value::as_str) else {
+            return Err(format!("Node '{}' has no 'type'", id));
+        };
+        if registry.get_node(node_type).is_none() {
+            return Err(format!("Node '{}' has unknown type '{}'", id, node_type));
+        }
+        node_ids.push(id);
+    }
+
+    for edge in &workflow.edges {
+        let edge_id = edge.get("id").and_then(Value::as_str).unwrap_or("<unnamed>");
+        for end in ["source", "target"] {
+            let node_id = edge.get(end).and_then(Value::as_str).unwrap_or_default();
+            if !node_ids.contains(&node_id) {
+                return Err(format!("Edge '{}' references unknown {} node '{}'", edge_id, end, node_id));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Insert a new draft workflow, returns its UUID
+async fn insert_workflow(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    name: &str,
+    description: Option<&str>,
+    definition: &str,
+    created_by: &str,
+) -> Result<String, sqlx::Error> {
+    let uuid = uuid::Uuid::new_v4().to_string();
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query(
+                "INSERT INTO workflows (uuid, organization_uuid, name, description, definition, status, created_by)
+                 VALUES (?, ?, ?, ?, ?, 'draft', ?)",
+            )
+            .bind(&uuid)
+            .bind(organization_uuid)
+            .bind(name)
+            .bind(description)
+            .bind(definition)
+            .bind(created_by)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query(
+                "INSERT INTO workflows (uuid, organization_uuid, name, description, definition, status, created_by)
+                 VALUES ($1, $2, $3, $4, $5::json, 'draft', $6)",
+            )
+            .bind(&uuid)
+            .bind(organization_uuid)
+            .bind(name)
+            .bind(description)
+            .bind(definition)
+            .bind(created_by)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query(
+                "INSERT INTO workflows (uuid, organization_uuid, name, description, definition, status, created_by)
+                 VALUES (?1, ?2, ?3, ?4, ?5, 'draft', ?6)",
+            )
+            .bind(&uuid)
+            .bind(organization_uuid)
+            .bind(name)
+            .bind(description)
+            .bind(definition)
+            .bind(created_by)
+            .execute(p)
+            .await?;
+        }
+    }
+
+    Ok(uuid)
+}
+
+/// Archive a workflow, archiving an already archived workflow is a no-op
+async fn archive_workflow(pool: &DatabasePool, workflow_uuid: &str) -> Result<(), sqlx::Error> {
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query(
+                "UPDATE workflows SET archived_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP
+                 WHERE uuid = ? AND archived_at IS NULL",
+            )
+            .bind(workflow_uuid)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query(
+                "UPDATE workflows SET archived_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP
+                 WHERE uuid = $1 AND archived_at IS NULL",
+            )
+            .bind(workflow_uuid)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query(
+                "UPDATE workflows SET archived_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP
+                 WHERE uuid = ?1 AND archived_at IS NULL",
+            )
+            .bind(workflow_uuid)
+            .execute(p)
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the user belongs to the organization and has the permission
+///
+/// `denied_message` is returned as error with 403 if the user lacks the permission.
+async fn require_workflow_permission(
+    state: &AppState,
+    claims: &Claims,
+    org_uuid: &str,
+    permission: &str,
+    denied_message: &str,
+) -> Result<(), (StatusCode, Json<Value>)> {
+    // Check if user belongs to organization
+    let belongs = user_belongs_to_organization(&state.db_pool, &claims.user_uuid, org_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !belongs {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not belong to this organization" })),
+        ));
+    }
+
+    // Check permission
+    let has_permission = user_has_permission(&state.db_pool, &claims.user_uuid, org_uuid, permission)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking permission: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !has_permission {
+        return Err((StatusCode::FORBIDDEN, Json(json!({ "error": denied_message }))));
+    }
+
+    Ok(())
+}
+
+/// List the workflows of the organization
+///
+/// GET /api/workflows?page=1&limit=30
+/// Requires the `can_see_workflows` permission. The page size limits are configured for
+/// `PaginatedResource::Workflows`.
+pub async fn list_workflows(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(org_uuid): Extension<String>,
+    Query(query): Query<ListWorkflowsQuery>,
+) -> Result<Json<ListWorkflowsResponse>, (StatusCode, Json<Value>)> {
+    require_workflow_permission(
+        &state,
+        &claims,
+        &org_uuid,
+        "can_see_workflows",
+        "User does not have permission to see workflows",
+    )
+    .await?;
+
+    let Pagination { page, limit, offset } =
+        state.pagination.clamp(PaginatedResource::Workflows, query.page, query.limit);
+
+    let map_error = |e: sqlx::Error| {
+        tracing::error!("Failed to fetch workflows of organization {}: {}", org_uuid, e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "Failed to fetch workflows" })),
+        )
+    };
+
+    // Read-only, may be served by the read replica
+    let pool = &state.db_read_pool;
+    let total = count_workflows(pool, &org_uuid).await.map_err(map_error)?;
+    let workflows = load_workflows(pool, &org_uuid, limit, offset)
+        .await
+        .map_err(map_error)?;
+
+    let total_pages = ((total as f64) / (limit as f64)).ceil() as u32;
+
+    Ok(Json(ListWorkflowsResponse {
+        workflows,
+        total,
+        page,
+        limit,
+        total_pages,
+    }))
+}
+
+/// Create a new workflow
+///
+/// POST /api/workflows
+/// Creates an empty draft workflow with the given title in the organization.
+/// Requires the `can_create_workflows` permission.
+pub async fn create_workflow(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(org_uuid): Extension<String>,
+    Json(payload): Json<CreateWorkflowRequest>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    require_workflow_permission(
+        &state,
+        &claims,
+        &org_uuid,
+        "can_create_workflows",
+        "User does not have permission to create workflows",
+    )
+    .await?;
+
+    if let Err(error) = validate_workflow_title(&payload.title) {
+        tracing::warn!("Workflow creation in organization {} failed: {}", org_uuid, error);
+        return Err((StatusCode::BAD_REQUEST, Json(json!({ "error": error }))));
+    }
+
+    let workflow_uuid = insert_workflow(
+        &state.db_pool,
+        &org_uuid,
+        &payload.title,
+        None,
+        EMPTY_WORKFLOW_DEFINITION,
+        &claims.user_uuid,
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to create workflow in organization {}: {}", org_uuid, e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "Failed to create workflow" })),
+        )
+    })?;
+
+    tracing::info!("Workflow {} created in organization {}", workflow_uuid, org_uuid);
+
+    Ok(Json(json!({
+        "uuid": workflow_uuid,
+        "message": "Workflow created successfully"
+    })))
+}
+
+/// Delete a workflow
+///
+/// DELETE /api/workflows/{workflow_uuid}
+/// Archives the workflow instead of deleting it: it is hidden from the listing, but its
+/// runs remain available as execution history. Workflows of other organizations are
+/// reported as not found. Requires the `can_delete_workflows` permission.
+pub async fn delete_workflow(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(org_uuid): Extension<String>,
+    Path(workflow_uuid): Path<String>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    require_workflow_permission(
+        &state,
+        &claims,
+        &org_uuid,
+        "can_delete_workflows",
+        "User does not have permission to delete workflows",
+    )
+    .await?;
+
+    let map_error = |e: sqlx::Error| {
+        tracing::error!("Failed to delete workflow {}: {}", workflow_uuid, e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "Failed to delete workflow" })),
+        )
+    };
+
+    let Some(workflow_org_uuid) = load_workflow_organization_uuid(&state.db_pool, &workflow_uuid)
+        .await
+        .map_err(map_error)?
+    else {
+        return Err(resource_not_found("Workflow"));
+    };
+    ensure_resource_in_organization(&workflow_org_uuid, &org_uuid, "Workflow")?;
+
+    archive_workflow(&state.db_pool, &workflow_uuid)
+        .await
+        .map_err(map_error)?;
+
+    tracing::info!("Workflow {} archived by user {}", workflow_uuid, claims.user_uuid);
+
+    Ok(Json(json!({
+        "message": "Workflow deleted successfully"
+    })))
+}
+
+/// Export a workflow definition
+///
+/// GET /api/workflows/{workflow_uuid}/export
+/// Returns a versioned JSON document with the workflow and its nodes and edges, see
+/// [`WORKFLOW_EXPORT_VERSION`]. Workflows of other organizations are reported as not found.
+/// Requires the `can_see_workflows` permission.
+pub async fn export_workflow(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(org_uuid): Extension<String>,
+    Path(workflow_uuid): Path<String>,
+) -> Result<Json<WorkflowExport>, (StatusCode, Json<Value>)> {
+    require_workflow_permission(
+        &state,
+        &claims,
+        &org_uuid,
+        "can_see_workflows",
+        "User does not have permission to see workflows",
+    )
+    .await?;
+
+    // Read-only, may be served by the read replica
+    let workflow = load_exported_workflow(&state.db_read_pool, &workflow_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to export workflow {}: {}", workflow_uuid, e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to export workflow" })),
+            )
+        })?;
+    let Some((workflow_org_uuid, workflow)) = workflow else {
+        return Err(resource_not_found("Workflow"));
+    };
+    ensure_resource_in_organization(&workflow_org_uuid, &org_uuid, "Workflow")?;
+
+    Ok(Json(WorkflowExport {
+        export_version: WORKFLOW_EXPORT_VERSION,
+        exported_at: Utc::now(),
+        workflow,
+    }))
+}
+
+/// Import a workflow
+///
+/// POST /api/workflows/import
+/// Accepts a document as returned by the export endpoint and creates a new draft workflow
+/// with a fresh UUID from it. Rejects unsupported export versions, unknown node types and
+/// edges between unknown nodes with 400. Requires the `can_create_workflows` permission.
+pub async fn import_workflow(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(org_uuid): Extension<String>,
+    Json(payload): Json<WorkflowImportRequest>,
+) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    require_workflow_permission(
+        &state,
+        &claims,
+        &org_uuid,
+        "can_create_workflows",
+        "User does not have permission to create workflows",
+    )
+    .await?;
+
+    let bad_request = |error: String| {
+        tracing::warn!("Workflow import in organization {} failed: {}", org_uuid, error);
+        (StatusCode::BAD_REQUEST, Json(json!({ "error": error })))
+    };
+
+    if payload.export_version != WORKFLOW_EXPORT_VERSION {
+        return Err(bad_request(format!(
+            "Unsupported export_version {}, expected {}",
+            payload.export_version, WORKFLOW_EXPORT_VERSION
+        )));
+    }
+
+    let workflow = payload.workflow;
+    validate_workflow_title(&workflow.name).map_err(|e| bad_request(e.to_string()))?;
+    validate_imported_workflow(&state.node_registry, &workflow).map_err(bad_request)?;
+
+    let definition = json!({ "nodes": workflow.nodes, "edges": workflow.edges }).to_string();
+    let workflow_uuid = insert_workflow(
+        &state.db_pool,
+        &org_uuid,
+        &workflow.name,
+        workflow.description.as_deref(),
+        &definition,
+        &claims.user_uuid,
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to import workflow in organization {}: {}", org_uuid, e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "Failed to import workflow" })),
+        )
+    })?;
+
+    tracing::info!("Workflow {} imported in organization {}", workflow_uuid, org_uuid);
+
+    Ok(Json(json!({
+        "uuid": workflow_uuid,
+        "message": "Workflow imported successfully"
+    })))
+}
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/workflows", get(list_workflows).post(create_workflow))
+        .route("/workflows/import", post(import_workflow))
+        .route("/workflows/{workflow_uuid}", delete(delete_workflow))
+        .route("/workflows/{workflow_uuid}/export", get(export_workflow))
+}
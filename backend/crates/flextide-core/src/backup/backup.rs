@@ -57,6 +57,9 @@ pub struct Backup {
     /// Whether the backup file still exists on the file system
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file_exists: Option<bool>,
+    /// Size of the backup file in bytes, if it still exists
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
 }
 
 /// Backup job record
@@ -74,6 +77,14 @@ pub struct BackupJob {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Upcoming executions of a backup job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupSchedulePreview {
+    pub job_uuid: String,
+    pub job_title: String,
+    pub next_executions: Vec<DateTime<Utc>>,
+}
+
 /// Paginated backup list response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedBackups {
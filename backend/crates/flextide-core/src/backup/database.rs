@@ -78,7 +78,7 @@ pub async fn list_backups(
 
             for row in rows {
                 let full_path: String = row.get("full_path");
-                let file_exists = Path::new(&full_path).exists();
+                let (file_exists, size_bytes) = backup_file_metadata(&full_path);
 
                 let error_json: Option<String> = row.get("error_json");
                 let error_value = error_json
@@ -101,6 +101,7 @@ pub async fn list_backups(
                     start_timestamp: row.get("start_timestamp"),
                     created_at: row.get("created_at"),
                     file_exists: Some(file_exists),
+                    size_bytes,
                 };
                 backups.push(backup);
             }
@@ -121,7 +122,7 @@ pub async fn list_backups(
 
             for row in rows {
                 let full_path: String = row.get("full_path");
-                let file_exists = Path::new(&full_path).exists();
+                let (file_exists, size_bytes) = backup_file_metadata(&full_path);
 
                 let error_json: Option<String> = row.get("error_json");
                 let error_value = error_json
@@ -144,6 +145,7 @@ pub async fn list_backups(
                     start_timestamp: row.get("start_timestamp"),
                     created_at: row.get("created_at"),
                     file_exists: Some(file_exists),
+                    size_bytes,
                 };
                 backups.push(backup);
             }
@@ -164,7 +166,7 @@ pub async fn list_backups(
 
             for row in rows {
                 let full_path: String = row.get("full_path");
-                let file_exists = Path::new(&full_path).exists();
+                let (file_exists, size_bytes) = backup_file_metadata(&full_path);
 
                 let error_json: Option<String> = row.get("error_json");
                 let error_value = error_json
@@ -187,6 +189,7 @@ pub async fn list_backups(
                     start_timestamp: row.get("start_timestamp"),
                     created_at: row.get("created_at"),
                     file_exists: Some(file_exists),
+                    size_bytes,
                 };
                 backups.push(backup);
             }
@@ -202,6 +205,37 @@ pub async fn list_backups(
     })
 }
 
+/// Get a single backup with the state of its file
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `backup_uuid` - UUID of the backup
+///
+/// # Returns
+/// The backup, including whether its file still exists and its size
+///
+/// # Errors
+/// Returns `BackupError` if:
+/// - Backup not found
+/// - Database operation fails
+pub async fn get_backup(pool: &DatabasePool, backup_uuid: &str) -> Result<Backup, BackupError> {
+    let mut backup = crate::backup::execution::get_backup_by_uuid(pool, backup_uuid).await?;
+
+    let (file_exists, size_bytes) = backup_file_metadata(&backup.full_path);
+    backup.file_exists = Some(file_exists);
+    backup.size_bytes = size_bytes;
+
+    Ok(backup)
+}
+
+/// Check whether a backup file exists and get its size
+fn backup_file_metadata(full_path: &str) -> (bool, Option<u64>) {
+    match std::fs::metadata(full_path) {
+        Ok(metadata) => (true, Some(metadata.len())),
+        Err(_) => (Path::new(full_path).exists(), None),
+    }
+}
+
 /// Create a new backup (permission check only, actual backup creation is mocked)
 ///
 /// # Arguments
@@ -369,9 +403,47 @@ pub async fn restore_backup(
         return Err(BackupError::BackupNotFound);
     }
 
+    // Mark the backup as being restored from, so retention pruning keeps it
+    set_restore_started_at(pool, backup_uuid, Some(Utc::now())).await?;
+
     // Mock restore - actual restore implementation will be added later
     tracing::info!("Mock restore backup: {} by user: {}", backup_uuid, user_uuid);
 
+    set_restore_started_at(pool, backup_uuid, None).await?;
+
+    Ok(())
+}
+
+/// Set or clear the time a restore from a backup started
+async fn set_restore_started_at(
+    pool: &DatabasePool,
+    backup_uuid: &str,
+    restore_started_at: Option<DateTime<Utc>>,
+) -> Result<(), BackupError> {
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query("UPDATE backups SET restore_started_at = ? WHERE uuid = ?")
+                .bind(restore_started_at)
+                .bind(backup_uuid)
+                .execute(p)
+                .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query("UPDATE backups SET restore_started_at = $1 WHERE uuid = $2")
+                .bind(restore_started_at)
+                .bind(backup_uuid)
+                .execute(p)
+                .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query("UPDATE backups SET restore_started_at = ?1 WHERE uuid = ?2")
+                .bind(restore_started_at)
+                .bind(backup_uuid)
+                .execute(p)
+                .await?;
+        }
+    }
+
     Ok(())
 }
 
@@ -1080,3 +1152,154 @@ pub async fn update_backup_path(
     Ok(())
 }
 
+/// Update backup checksum (hex encoded SHA-256 of the backup file)
+pub async fn update_backup_checksum(
+    pool: &DatabasePool,
+    backup_uuid: &str,
+    checksum: &str,
+) -> Result<(), BackupError> {
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query("UPDATE backups SET backup_hash_checksum = ? WHERE uuid = ?")
+                .bind(checksum)
+                .bind(backup_uuid)
+                .execute(p)
+                .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query("UPDATE backups SET backup_hash_checksum = $1 WHERE uuid = $2")
+                .bind(checksum)
+                .bind(backup_uuid)
+                .execute(p)
+                .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query("UPDATE backups SET backup_hash_checksum = ?1 WHERE uuid = ?2")
+                .bind(checksum)
+                .bind(backup_uuid)
+                .execute(p)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::database::create_test_pool;
+    use chrono::Duration;
+
+    /// Set up test database with the backups table
+    pub(crate) async fn setup_test_db() -> DatabasePool {
+        let pool = create_test_pool().await.expect("Failed to create test pool");
+
+        if let DatabasePool::Sqlite(p) = &pool {
+            sqlx::query(
+                "CREATE TABLE backups (
+                    uuid CHAR(36) NOT NULL PRIMARY KEY,
+                    filename VARCHAR(500) NOT NULL,
+                    full_path TEXT NOT NULL,
+                    creator_user_uuid CHAR(36) NOT NULL,
+                    target_location VARCHAR(100) NOT NULL DEFAULT 'local_filesystem',
+                    job_type VARCHAR(50),
+                    backup_status VARCHAR(50) NOT NULL DEFAULT 'COMPLETED',
+                    backup_hash_checksum VARCHAR(128),
+                    is_encrypted INTEGER NOT NULL DEFAULT 0,
+                    encryption_algorithm VARCHAR(50),
+                    encryption_master_key_name VARCHAR(255),
+                    error_json TEXT,
+                    start_timestamp TIMESTAMP,
+                    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                    restore_started_at TIMESTAMP NULL
+                )",
+            )
+            .execute(p)
+            .await
+            .expect("Failed to create backups table");
+        }
+
+        pool
+    }
+
+    /// Insert a backup record, returns its UUID
+    pub(crate) async fn insert_backup(
+        pool: &DatabasePool,
+        filename: &str,
+        full_path: &str,
+        job_type: Option<&str>,
+        status: BackupStatus,
+        created_at: DateTime<Utc>,
+    ) -> String {
+        let backup_uuid = Uuid::new_v4().to_string();
+        if let DatabasePool::Sqlite(p) = pool {
+            sqlx::query(
+                "INSERT INTO backups (uuid, filename, full_path, creator_user_uuid, job_type, backup_status, start_timestamp, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)",
+            )
+            .bind(&backup_uuid)
+            .bind(filename)
+            .bind(full_path)
+            .bind(Uuid::new_v4().to_string())
+            .bind(job_type)
+            .bind(String::from(status))
+            .bind(created_at)
+            .execute(p)
+            .await
+            .expect("Failed to insert backup");
+        }
+        backup_uuid
+    }
+
+    #[tokio::test]
+    async fn test_list_backups_newest_first() {
+        let pool = setup_test_db().await;
+        let now = Utc::now();
+
+        let oldest = insert_backup(&pool, "a.json.bkp", "/nonexistent/a.json.bkp", None, BackupStatus::Completed, now - Duration::days(2)).await;
+        let newest = insert_backup(&pool, "c.json.bkp", "/nonexistent/c.json.bkp", None, BackupStatus::InProgress, now).await;
+        let middle = insert_backup(&pool, "b.json.bkp", "/nonexistent/b.json.bkp", Some("full_backup"), BackupStatus::Failed, now - Duration::days(1)).await;
+
+        let page = list_backups(&pool, "admin", 1, 10).await.expect("Failed to list backups");
+        assert_eq!(page.total, 3);
+        let uuids: Vec<&str> = page.backups.iter().map(|b| b.uuid.as_str()).collect();
+        assert_eq!(uuids, vec![newest.as_str(), middle.as_str(), oldest.as_str()]);
+
+        let middle_backup = &page.backups[1];
+        assert_eq!(middle_backup.job_type.as_deref(), Some("full_backup"));
+        assert_eq!(middle_backup.backup_status, BackupStatus::Failed);
+        assert_eq!(middle_backup.file_exists, Some(false));
+        assert_eq!(middle_backup.size_bytes, None);
+
+        let second_page = list_backups(&pool, "admin", 2, 2).await.expect("Failed to list backups");
+        assert_eq!(second_page.total_pages, 2);
+        assert_eq!(second_page.backups.len(), 1);
+        assert_eq!(second_page.backups[0].uuid, oldest);
+    }
+
+    #[tokio::test]
+    async fn test_get_backup_details() {
+        let pool = setup_test_db().await;
+
+        let full_path = std::env::temp_dir().join(format!("{}.json.bkp", Uuid::new_v4()));
+        std::fs::write(&full_path, b"{\"version\":\"1.0\"}").expect("Failed to write backup file");
+        let full_path_str = full_path.to_string_lossy().to_string();
+
+        let backup_uuid = insert_backup(&pool, "details.json.bkp", &full_path_str, Some("full_backup"), BackupStatus::Completed, Utc::now()).await;
+
+        let backup = get_backup(&pool, &backup_uuid).await.expect("Failed to get backup");
+        std::fs::remove_file(&full_path).expect("Failed to remove backup file");
+
+        assert_eq!(backup.filename, "details.json.bkp");
+        assert_eq!(backup.full_path, full_path_str);
+        assert_eq!(backup.backup_status, BackupStatus::Completed);
+        assert_eq!(backup.file_exists, Some(true));
+        assert_eq!(backup.size_bytes, Some(17));
+
+        assert!(matches!(
+            get_backup(&pool, &Uuid::new_v4().to_string()).await,
+            Err(BackupError::BackupNotFound)
+        ));
+    }
+}
@@ -36,5 +36,11 @@ pub enum BackupError {
 
     #[error("User not found: {0}")]
     UserNotFound(String),
+
+    #[error("Backup has no stored checksum")]
+    MissingChecksum,
+
+    #[error("Backup checksum mismatch: expected {expected}, got {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
 }
 
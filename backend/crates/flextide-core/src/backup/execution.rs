@@ -533,6 +533,7 @@ pub async fn execute_backup(
         })?;
     
     let file_size = json_content.len();
+    let checksum = crate::backup::integrity::backup_checksum(json_content.as_bytes());
     tracing::info!("Backup JSON size: {} bytes, writing to file: {}", file_size, file_path.display());
     
     fs::write(&file_path, json_content)
@@ -547,6 +548,7 @@ pub async fn execute_backup(
     let full_path_str = file_path.to_string_lossy().to_string();
     tracing::debug!("Updating backup path in database: {}", full_path_str);
     database::update_backup_path(pool, backup_uuid, &full_path_str).await?;
+    database::update_backup_checksum(pool, backup_uuid, &checksum).await?;
     
     tracing::info!("Updating backup status to COMPLETED");
     database::update_backup_status(pool, backup_uuid, crate::backup::backup::BackupStatus::Completed).await?;
@@ -595,6 +597,7 @@ pub async fn get_backup_by_uuid(
                 start_timestamp: row.get("start_timestamp"),
                 created_at: row.get("created_at"),
                 file_exists: None,
+                size_bytes: None,
             })
         }
         DatabasePool::Postgres(p) => {
@@ -630,6 +633,7 @@ pub async fn get_backup_by_uuid(
                 start_timestamp: row.get("start_timestamp"),
                 created_at: row.get("created_at"),
                 file_exists: None,
+                size_bytes: None,
             })
         }
         DatabasePool::Sqlite(p) => {
@@ -665,6 +669,7 @@ pub async fn get_backup_by_uuid(
                 start_timestamp: row.get("start_timestamp"),
                 created_at: row.get("created_at"),
                 file_exists: None,
+                size_bytes: None,
             })
         }
     }
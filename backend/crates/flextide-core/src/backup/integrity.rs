@@ -0,0 +1,108 @@
+//! Backup integrity
+//!
+//! Detects corrupted or tampered backup files by their SHA-256 checksum.
+
+use crate::backup::error::BackupError;
+use crate::backup::execution::get_backup_by_uuid;
+use crate::database::DatabasePool;
+use sha2::{Digest, Sha256};
+
+/// Compute the checksum stored for a backup file (hex encoded SHA-256)
+pub fn backup_checksum(content: &[u8]) -> String {
+    hex::encode(Sha256::digest(content))
+}
+
+/// Verify that the file of a backup still matches the checksum stored at its creation
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `backup_uuid` - UUID of the backup to verify
+///
+/// # Errors
+/// Returns `BackupError` if:
+/// - Backup not found
+/// - No checksum was stored for the backup (`MissingChecksum`)
+/// - The backup file cannot be read
+/// - The file does not match the stored checksum (`ChecksumMismatch`)
+/// - Database operation fails
+pub async fn verify_backup(pool: &DatabasePool, backup_uuid: &str) -> Result<(), BackupError> {
+    let backup = get_backup_by_uuid(pool, backup_uuid).await?;
+
+    let expected = backup.backup_hash_checksum.ok_or(BackupError::MissingChecksum)?;
+    let actual = backup_checksum(&std::fs::read(&backup.full_path)?);
+
+    if !actual.eq_ignore_ascii_case(&expected) {
+        tracing::warn!(
+            "Backup {} failed verification: expected checksum {}, got {}",
+            backup_uuid,
+            expected,
+            actual
+        );
+        return Err(BackupError::ChecksumMismatch { expected, actual });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup::database::tests::{insert_backup, setup_test_db};
+    use crate::backup::{execute_backup, BackupStatus};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_verify_backup_detects_mutated_file() {
+        let pool = setup_test_db().await;
+        let backup_dir = std::env::temp_dir().join(format!("backups-{}", uuid::Uuid::new_v4()));
+
+        let backup_uuid = insert_backup(
+            &pool,
+            "integrity.json.bkp",
+            "",
+            None,
+            BackupStatus::InProgress,
+            Utc::now(),
+        )
+        .await;
+
+        // The checksum is stored when the backup file is written
+        let file_path = execute_backup(&pool, &backup_uuid, &backup_dir.to_string_lossy())
+            .await
+            .expect("Failed to execute backup");
+        let backup = get_backup_by_uuid(&pool, &backup_uuid).await.expect("Failed to get backup");
+        assert_eq!(
+            backup.backup_hash_checksum,
+            Some(backup_checksum(&std::fs::read(&file_path).unwrap()))
+        );
+
+        verify_backup(&pool, &backup_uuid).await.expect("Untampered backup should verify");
+
+        let mut content = std::fs::read(&file_path).unwrap();
+        content.extend_from_slice(b" ");
+        std::fs::write(&file_path, content).unwrap();
+
+        let result = verify_backup(&pool, &backup_uuid).await;
+        std::fs::remove_dir_all(&backup_dir).unwrap();
+        assert!(matches!(result, Err(BackupError::ChecksumMismatch { .. })), "{:?}", result);
+    }
+
+    #[tokio::test]
+    async fn test_verify_backup_without_checksum() {
+        let pool = setup_test_db().await;
+        let backup_uuid = insert_backup(
+            &pool,
+            "legacy.json.bkp",
+            "/nonexistent/legacy.json.bkp",
+            None,
+            BackupStatus::Completed,
+            Utc::now(),
+        )
+        .await;
+
+        assert!(matches!(
+            verify_backup(&pool, &backup_uuid).await,
+            Err(BackupError::MissingChecksum)
+        ));
+    }
+}
@@ -6,6 +6,8 @@ mod backup;
 pub mod database;
 mod error;
 mod execution;
+mod integrity;
+mod retention;
 
 use chrono::{DateTime, Utc};
 use std::str::FromStr;
@@ -14,6 +16,8 @@ pub use backup::*;
 pub use database::*;
 pub use error::BackupError;
 pub use execution::*;
+pub use integrity::{backup_checksum, verify_backup};
+pub use retention::{prune_backups, BackupRetentionPolicy};
 
 /// Normalize cron schedule to 6 fields (add seconds if 5 fields provided)
 /// 
@@ -48,6 +52,18 @@ fn normalize_cron_schedule(schedule: &str) -> String {
 /// # Returns
 /// Next execution timestamp, or None if schedule is invalid or empty
 pub fn calculate_next_execution(schedule: Option<&str>) -> Option<DateTime<Utc>> {
+    calculate_next_execution_after(schedule, Utc::now())
+}
+
+/// Calculate the next execution timestamp of a cron schedule after the given time
+///
+/// # Arguments
+/// * `schedule` - Cron expression (5 or 6 fields)
+/// * `after` - Time after which the next execution is searched (exclusive)
+///
+/// # Returns
+/// Next execution timestamp, or None if schedule is invalid or empty
+pub fn calculate_next_execution_after(schedule: Option<&str>, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
     let schedule_str = schedule?;
     let schedule_str = schedule_str.trim();
     if schedule_str.is_empty() {
@@ -66,8 +82,99 @@ pub fn calculate_next_execution(schedule: Option<&str>) -> Option<DateTime<Utc>>
         }
     };
 
-    // Get the next execution time from now
-    let _now = Utc::now();
-    cron_schedule.upcoming(Utc).next().map(|dt| dt.with_timezone(&Utc))
+    cron_schedule.after(&after).next()
+}
+
+/// Preview the next executions of several backup jobs
+///
+/// # Arguments
+/// * `jobs` - Backup jobs to preview
+/// * `count` - Number of upcoming executions per job
+/// * `from` - Time after which the executions are calculated
+///
+/// # Returns
+/// One preview per job, in the order of `jobs`. Jobs without a valid schedule get an
+/// empty series.
+pub fn preview_next_executions(
+    jobs: &[BackupJob],
+    count: usize,
+    from: DateTime<Utc>,
+) -> Vec<BackupSchedulePreview> {
+    jobs.iter()
+        .map(|job| {
+            let mut next_executions = Vec::with_capacity(count);
+            let mut after = from;
+            while next_executions.len() < count {
+                match calculate_next_execution_after(job.schedule.as_deref(), after) {
+                    Some(next) => {
+                        next_executions.push(next);
+                        after = next;
+                    }
+                    None => break,
+                }
+            }
+
+            BackupSchedulePreview {
+                job_uuid: job.uuid.clone(),
+                job_title: job.job_title.clone(),
+                next_executions,
+            }
+        })
+        .collect()
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn job(uuid: &str, schedule: Option<&str>) -> BackupJob {
+        let now = Utc::now();
+        BackupJob {
+            uuid: uuid.to_string(),
+            job_type: "full_database_backup".to_string(),
+            job_title: format!("Job {}", uuid),
+            json_data: None,
+            schedule: schedule.map(str::to_string),
+            is_active: true,
+            last_execution_timestamp: None,
+            next_execution_timestamp: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_preview_daily_schedule() {
+        let from = Utc.with_ymd_and_hms(2025, 3, 30, 12, 0, 0).unwrap();
+        let jobs = vec![
+            job("daily", Some("0 10 * * *")),
+            job("invalid", Some("not a cron")),
+            job("unscheduled", None),
+        ];
+
+        let previews = preview_next_executions(&jobs, 3, from);
+        assert_eq!(previews.len(), 3);
+
+        assert_eq!(previews[0].job_uuid, "daily");
+        assert_eq!(
+            previews[0].next_executions,
+            vec![
+                Utc.with_ymd_and_hms(2025, 3, 31, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 4, 1, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 4, 2, 10, 0, 0).unwrap(),
+            ]
+        );
+        assert!(previews[1].next_executions.is_empty());
+        assert!(previews[2].next_executions.is_empty());
+    }
+
+    #[test]
+    fn test_next_execution_after_is_exclusive() {
+        let at_run = Utc.with_ymd_and_hms(2025, 3, 30, 10, 0, 0).unwrap();
+        assert_eq!(
+            calculate_next_execution_after(Some("0 10 * * *"), at_run),
+            Some(Utc.with_ymd_and_hms(2025, 3, 31, 10, 0, 0).unwrap())
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! Backup retention
+//!
+//! Deletes old backups according to a retention policy.
+
+use crate::backup::backup::BackupStatus;
+use crate::backup::error::BackupError;
+use crate::database::DatabasePool;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::Row;
+use std::path::Path;
+
+/// Which backups to keep when pruning
+///
+/// A backup is kept if any of the rules keeps it. A policy without rules keeps everything.
+#[derive(Debug, Clone, Default)]
+pub struct BackupRetentionPolicy {
+    /// Keep the newest N backups
+    pub keep_last: Option<usize>,
+    /// Keep backups created within this duration
+    pub keep_newer_than: Option<Duration>,
+}
+
+impl BackupRetentionPolicy {
+    /// Whether the backup at `position` (0 = newest) created at `created_at` is kept
+    fn keeps(&self, position: usize, created_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        if self.keep_last.is_none() && self.keep_newer_than.is_none() {
+            return true;
+        }
+        self.keep_last.is_some_and(|keep_last| position < keep_last)
+            || self.keep_newer_than.is_some_and(|max_age| now - created_at < max_age)
+    }
+}
+
+/// Backup row considered for pruning
+struct PruneCandidate {
+    uuid: String,
+    full_path: String,
+    created_at: DateTime<Utc>,
+    backup_status: BackupStatus,
+    restoring: bool,
+}
+
+/// Delete backups that are not kept by the retention policy
+///
+/// Backups still being created and backups currently being restored from are never
+/// deleted, but count towards `keep_last`. The records are deleted in a single
+/// transaction, the backup files are removed after it was committed.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `policy` - Retention policy
+///
+/// # Returns
+/// UUIDs of the deleted backups
+///
+/// # Errors
+/// Returns `BackupError` if a database operation fails
+pub async fn prune_backups(
+    pool: &DatabasePool,
+    policy: &BackupRetentionPolicy,
+) -> Result<Vec<String>, BackupError> {
+    let query = "SELECT uuid, full_path, created_at, backup_status, restore_started_at IS NOT NULL AS restoring
+                 FROM backups
+                 ORDER BY created_at DESC";
+
+    let candidates: Vec<PruneCandidate> = match pool {
+        DatabasePool::MySql(p) => sqlx::query(query)
+            .fetch_all(p)
+            .await?
+            .into_iter()
+            .map(|row| PruneCandidate {
+                uuid: row.get("uuid"),
+                full_path: row.get("full_path"),
+                created_at: row.get("created_at"),
+                backup_status: BackupStatus::from(row.get::<String, _>("backup_status").as_str()),
+                restoring: row.get::<i64, _>("restoring") != 0,
+            })
+            .collect(),
+        DatabasePool::Postgres(p) => sqlx::query(query)
+            .fetch_all(p)
+            .await?
+            .into_iter()
+            .map(|row| PruneCandidate {
+                uuid: row.get("uuid"),
+                full_path: row.get("full_path"),
+                created_at: row.get("created_at"),
+                backup_status: BackupStatus::from(row.get::<String, _>("backup_status").as_str()),
+                restoring: row.get::<bool, _>("restoring"),
+            })
+            .collect(),
+        DatabasePool::Sqlite(p) => sqlx::query(query)
+            .fetch_all(p)
+            .await?
+            .into_iter()
+            .map(|row| PruneCandidate {
+                uuid: row.get("uuid"),
+                full_path: row.get("full_path"),
+                created_at: row.get("created_at"),
+                backup_status: BackupStatus::from(row.get::<String, _>("backup_status").as_str()),
+                restoring: row.get::<i64, _>("restoring") != 0,
+            })
+            .collect(),
+    };
+
+    let now = Utc::now();
+    let expired: Vec<PruneCandidate> = candidates
+        .into_iter()
+        .enumerate()
+        .filter(|(position, backup)| {
+            !policy.keeps(*position, backup.created_at, now)
+                && !backup.restoring
+                && backup.backup_status != BackupStatus::InProgress
+        })
+        .map(|(_, backup)| backup)
+        .collect();
+
+    if expired.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // A restore may have started since the backups were loaded, so check again on delete
+    let mut deleted = Vec::new();
+    match pool {
+        DatabasePool::MySql(p) => {
+            let mut tx = p.begin().await?;
+            for backup in &expired {
+                let result = sqlx::query("DELETE FROM backups WHERE uuid = ? AND restore_started_at IS NULL")
+                    .bind(&backup.uuid)
+                    .execute(&mut *tx)
+                    .await?;
+                if result.rows_affected() > 0 {
+                    deleted.push(backup);
+                }
+            }
+            tx.commit().await?;
+        }
+        DatabasePool::Postgres(p) => {
+            let mut tx = p.begin().await?;
+            for backup in &expired {
+                let result = sqlx::query("DELETE FROM backups WHERE uuid = $1 AND restore_started_at IS NULL")
+                    .bind(&backup.uuid)
+                    .execute(&mut *tx)
+                    .await?;
+                if result.rows_affected() > 0 {
+                    deleted.push(backup);
+                }
+            }
+            tx.commit().await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            let mut tx = p.begin().await?;
+            for backup in &expired {
+                let result = sqlx::query("DELETE FROM backups WHERE uuid = ?1 AND restore_started_at IS NULL")
+                    .bind(&backup.uuid)
+                    .execute(&mut *tx)
+                    .await?;
+                if result.rows_affected() > 0 {
+                    deleted.push(backup);
+                }
+            }
+            tx.commit().await?;
+        }
+    }
+
+    // Delete backup files (encrypted or not) of the deleted records
+    for backup in &deleted {
+        let file_path = Path::new(&backup.full_path);
+        if file_path.exists()
+            && let Err(e) = std::fs::remove_file(file_path)
+        {
+            tracing::warn!("Failed to delete backup file {}: {}", backup.full_path, e);
+        }
+    }
+
+    tracing::info!("Pruned {} backups", deleted.len());
+
+    Ok(deleted.into_iter().map(|backup| backup.uuid.clone()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup::database::tests::{insert_backup, setup_test_db};
+
+    async fn remaining_backups(pool: &DatabasePool) -> Vec<String> {
+        match pool {
+            DatabasePool::Sqlite(p) => sqlx::query("SELECT uuid FROM backups ORDER BY created_at DESC")
+                .fetch_all(p)
+                .await
+                .expect("Failed to load backups")
+                .into_iter()
+                .map(|row| row.get("uuid"))
+                .collect(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prune_keeps_last_n_backups() {
+        let pool = setup_test_db().await;
+        let now = Utc::now();
+
+        let file_path = std::env::temp_dir().join(format!("{}.json.bkp", uuid::Uuid::new_v4()));
+        std::fs::write(&file_path, b"{}").expect("Failed to write backup file");
+
+        // Newest first
+        let mut uuids = Vec::new();
+        for days in 0..5 {
+            let full_path = if days == 4 {
+                file_path.to_string_lossy().to_string()
+            } else {
+                format!("/nonexistent/{}.json.bkp", days)
+            };
+            uuids.push(
+                insert_backup(&pool, "backup.json.bkp", &full_path, None, BackupStatus::Completed, now - Duration::days(days)).await,
+            );
+        }
+
+        let policy = BackupRetentionPolicy { keep_last: Some(2), keep_newer_than: None };
+        let mut deleted = prune_backups(&pool, &policy).await.expect("Failed to prune backups");
+        deleted.sort();
+        let mut expected = uuids[2..].to_vec();
+        expected.sort();
+
+        assert_eq!(deleted, expected);
+        assert_eq!(remaining_backups(&pool).await, uuids[..2].to_vec());
+        assert!(!file_path.exists(), "Backup file of the pruned backup should be deleted");
+
+        // Pruning again has nothing left to delete
+        assert!(prune_backups(&pool, &policy).await.expect("Failed to prune backups").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prune_never_deletes_restoring_or_recent_backups() {
+        let pool = setup_test_db().await;
+        let now = Utc::now();
+
+        let newest = insert_backup(&pool, "a.json.bkp", "/nonexistent/a", None, BackupStatus::Completed, now).await;
+        let recent = insert_backup(&pool, "b.json.bkp", "/nonexistent/b", None, BackupStatus::Completed, now - Duration::hours(12)).await;
+        let restoring = insert_backup(&pool, "c.json.bkp", "/nonexistent/c", None, BackupStatus::Completed, now - Duration::days(3)).await;
+        let running = insert_backup(&pool, "d.json.bkp", "/nonexistent/d", None, BackupStatus::InProgress, now - Duration::days(4)).await;
+        let old = insert_backup(&pool, "e.json.bkp", "/nonexistent/e", None, BackupStatus::Failed, now - Duration::days(5)).await;
+
+        if let DatabasePool::Sqlite(p) = &pool {
+            sqlx::query("UPDATE backups SET restore_started_at = ?1 WHERE uuid = ?2")
+                .bind(now)
+                .bind(&restoring)
+                .execute(p)
+                .await
+                .expect("Failed to mark backup as restoring");
+        }
+
+        let policy = BackupRetentionPolicy { keep_last: Some(1), keep_newer_than: Some(Duration::days(1)) };
+        let deleted = prune_backups(&pool, &policy).await.expect("Failed to prune backups");
+
+        assert_eq!(deleted, vec![old]);
+        assert_eq!(remaining_backups(&pool).await, vec![newest, recent, restoring, running]);
+
+        // A policy without rules keeps everything
+        assert!(prune_backups(&pool, &BackupRetentionPolicy::default()).await.unwrap().is_empty());
+    }
+}
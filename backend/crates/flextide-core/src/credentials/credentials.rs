@@ -153,7 +153,8 @@ mod tests {
     fn create_test_manager() -> CredentialsManager {
         // Generate a test master key
         let test_key = hex::encode([0u8; 32]);
-        std::env::set_var("CREDENTIALS_MASTER_KEY", test_key);
+        // SAFETY: test-only environment setup
+        unsafe { std::env::set_var("CREDENTIALS_MASTER_KEY", test_key) };
         CredentialsManager::new().unwrap()
     }
 
@@ -203,33 +204,36 @@ mod tests {
 
     #[test]
     fn test_master_key_not_found() {
-        std::env::remove_var("CREDENTIALS_MASTER_KEY");
+        // SAFETY: test-only environment setup
+        unsafe { std::env::remove_var("CREDENTIALS_MASTER_KEY") };
         let result = CredentialsManager::new();
         assert!(result.is_err());
-        match result.unwrap_err() {
-            CredentialsError::MasterKeyNotFound => {}
+        match result {
+            Err(CredentialsError::MasterKeyNotFound) => {}
             _ => panic!("Expected MasterKeyNotFound error"),
         }
     }
 
     #[test]
     fn test_invalid_master_key_format_short() {
-        std::env::set_var("CREDENTIALS_MASTER_KEY", "short");
+        // SAFETY: test-only environment setup
+        unsafe { std::env::set_var("CREDENTIALS_MASTER_KEY", "short") };
         let result = CredentialsManager::new();
         assert!(result.is_err());
-        match result.unwrap_err() {
-            CredentialsError::InvalidMasterKeyFormat => {}
+        match result {
+            Err(CredentialsError::InvalidMasterKeyFormat) => {}
             _ => panic!("Expected InvalidMasterKeyFormat error"),
         }
     }
 
     #[test]
     fn test_invalid_master_key_format_invalid_hex() {
-        std::env::set_var("CREDENTIALS_MASTER_KEY", "x".repeat(64));
+        // SAFETY: test-only environment setup
+        unsafe { std::env::set_var("CREDENTIALS_MASTER_KEY", "x".repeat(64)) };
         let result = CredentialsManager::new();
         assert!(result.is_err());
-        match result.unwrap_err() {
-            CredentialsError::InvalidMasterKeyFormat => {}
+        match result {
+            Err(CredentialsError::InvalidMasterKeyFormat) => {}
             _ => panic!("Expected InvalidMasterKeyFormat error"),
         }
     }
@@ -291,8 +295,8 @@ mod tests {
         
         let result = manager.decrypt(&encrypted);
         assert!(result.is_err());
-        match result.unwrap_err() {
-            CredentialsError::Decryption(_) => {}
+        match result {
+            Err(CredentialsError::Decryption(_)) => {}
             _ => panic!("Expected Decryption error"),
         }
     }
@@ -301,7 +305,8 @@ mod tests {
     fn test_decrypt_wrong_key() {
         // Create manager with one key
         let test_key1 = hex::encode([0u8; 32]);
-        std::env::set_var("CREDENTIALS_MASTER_KEY", test_key1);
+        // SAFETY: test-only environment setup
+        unsafe { std::env::set_var("CREDENTIALS_MASTER_KEY", test_key1) };
         let manager1 = CredentialsManager::new().unwrap();
         
         let original_data = json!({"key": "value"});
@@ -309,7 +314,8 @@ mod tests {
         
         // Create manager with different key
         let test_key2 = hex::encode([1u8; 32]);
-        std::env::set_var("CREDENTIALS_MASTER_KEY", test_key2);
+        // SAFETY: test-only environment setup
+        unsafe { std::env::set_var("CREDENTIALS_MASTER_KEY", test_key2) };
         let manager2 = CredentialsManager::new().unwrap();
         
         // Should fail to decrypt with wrong key
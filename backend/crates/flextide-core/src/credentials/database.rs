@@ -1002,7 +1002,8 @@ mod tests {
                         created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
                         updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
                         mail_verified INTEGER NOT NULL DEFAULT 0,
-                        activated INTEGER NOT NULL DEFAULT 1
+                        activated INTEGER NOT NULL DEFAULT 1,
+                        is_server_admin INTEGER NOT NULL DEFAULT 0
                     )",
                 )
                 .execute(p)
@@ -1229,7 +1230,8 @@ mod tests {
 
     fn create_test_manager() -> CredentialsManager {
         let test_key = hex::encode([0u8; 32]);
-        std::env::set_var("CREDENTIALS_MASTER_KEY", test_key);
+        // SAFETY: test-only environment setup
+        unsafe { std::env::set_var("CREDENTIALS_MASTER_KEY", test_key) };
         CredentialsManager::new().unwrap()
     }
 
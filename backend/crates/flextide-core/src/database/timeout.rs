@@ -0,0 +1,133 @@
+//! Statement timeouts for individual queries
+//!
+//! PostgreSQL enforces the timeout on the server with `SET LOCAL statement_timeout`, see
+//! [`begin_with_statement_timeout`]. For MySQL and SQLite, [`with_statement_timeout`]
+//! stops waiting for the query once the timeout elapsed.
+
+use std::future::Future;
+use std::time::Duration;
+
+use sqlx::postgres::PgPool;
+use sqlx::{Postgres, Transaction};
+
+use super::DatabaseError;
+
+/// Timeout for queries that have no more specific timeout
+pub const DEFAULT_STATEMENT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// SQLSTATE PostgreSQL reports for a statement canceled by `statement_timeout`
+const POSTGRES_QUERY_CANCELED: &str = "57014";
+
+/// Run a query, failing with `QueryTimeout` if it takes longer than `timeout`
+///
+/// The future is dropped when the timeout elapses. A query that was already sent keeps
+/// running on MySQL and SQLite until it finishes, so PostgreSQL queries should also run
+/// in a transaction from [`begin_with_statement_timeout`] to stop them on the server.
+///
+/// # Arguments
+/// * `timeout` - Maximum time to wait for the query
+/// * `query` - The query, including acquiring its connection
+///
+/// # Returns
+/// The result of the query
+///
+/// # Errors
+/// Returns `DatabaseError::QueryTimeout` if the timeout elapsed or PostgreSQL canceled the
+/// statement, or `DatabaseError::PoolCreationFailed` with the error of a failed query
+pub async fn with_statement_timeout<T, F>(timeout: Duration, query: F) -> Result<T, DatabaseError>
+where
+    F: Future<Output = Result<T, sqlx::Error>>,
+{
+    match tokio::time::timeout(timeout, query).await {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) if is_statement_timeout(&e) => Err(DatabaseError::QueryTimeout(timeout)),
+        Ok(Err(e)) => Err(e.into()),
+        Err(_) => Err(DatabaseError::QueryTimeout(timeout)),
+    }
+}
+
+/// Begin a PostgreSQL transaction whose statements are canceled after `timeout`
+///
+/// The timeout is set with `SET LOCAL`, so it ends with the transaction and does not
+/// leak to other users of the pooled connection.
+///
+/// # Arguments
+/// * `pool` - PostgreSQL pool
+/// * `timeout` - Maximum duration of each statement, rounded down to milliseconds
+///
+/// # Errors
+/// Returns `sqlx::Error` if the transaction could not be started
+pub async fn begin_with_statement_timeout(
+    pool: &PgPool,
+    timeout: Duration,
+) -> Result<Transaction<'static, Postgres>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    // SET does not accept bind parameters, the value is a formatted integer
+    sqlx::query(&format!("SET LOCAL statement_timeout = {}", timeout.as_millis().max(1)))
+        .execute(&mut *tx)
+        .await?;
+    Ok(tx)
+}
+
+/// Whether the database canceled a statement because of its statement timeout
+fn is_statement_timeout(error: &sqlx::Error) -> bool {
+    match error {
+        sqlx::Error::Database(e) => e.code().as_deref() == Some(POSTGRES_QUERY_CANCELED),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{create_test_pool, DatabasePool};
+
+    /// Counts to ten million, which takes far longer than the timeouts below
+    const SLOW_QUERY: &str = "WITH RECURSIVE counter(n) AS (
+            SELECT 1 UNION ALL SELECT n + 1 FROM counter WHERE n < 10000000
+        )
+        SELECT COUNT(*) FROM counter";
+
+    fn sqlite(pool: &DatabasePool) -> &sqlx::SqlitePool {
+        match pool {
+            DatabasePool::Sqlite(p) => p,
+            _ => panic!("Expected SQLite test pool"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_query_times_out() {
+        let pool = create_test_pool().await.expect("Failed to create test pool");
+        let timeout = Duration::from_millis(20);
+
+        let started = std::time::Instant::now();
+        let result = with_statement_timeout(
+            timeout,
+            sqlx::query_scalar::<_, i64>(SLOW_QUERY).fetch_one(sqlite(&pool)),
+        )
+        .await;
+
+        assert!(matches!(result, Err(DatabaseError::QueryTimeout(t)) if t == timeout));
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_fast_query_returns_result() {
+        let pool = create_test_pool().await.expect("Failed to create test pool");
+
+        let value = with_statement_timeout(
+            DEFAULT_STATEMENT_TIMEOUT,
+            sqlx::query_scalar::<_, i64>("SELECT 42").fetch_one(sqlite(&pool)),
+        )
+        .await
+        .expect("Query failed");
+        assert_eq!(value, 42);
+
+        let result = with_statement_timeout(
+            DEFAULT_STATEMENT_TIMEOUT,
+            sqlx::query("SELECT * FROM missing_table").execute(sqlite(&pool)),
+        )
+        .await;
+        assert!(matches!(result, Err(DatabaseError::PoolCreationFailed(_))));
+    }
+}
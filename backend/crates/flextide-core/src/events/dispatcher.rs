@@ -4,13 +4,20 @@
 
 use crate::database::DatabasePool;
 use crate::events::database::load_event_subscriptions;
-use crate::events::subscriber::{DatabaseEventSubscription, EventSubscriber};
+use crate::events::email::{EmailConfig, EmailSubscriber};
+use crate::events::slack::{SlackConfig, SlackSubscriber};
+use crate::events::subscriber::{DatabaseEventSubscription, EventHandlerResult, EventSubscriber, FnSubscriber};
 use crate::events::types::Event;
-use crate::events::webhooks::{load_webhooks, send_webhook, Webhook};
+use crate::events::webhook_deliveries::{deliver_webhook, WebhookRetryPolicy};
+use crate::events::webhooks::{load_webhooks, Webhook};
 use dashmap::DashMap;
-use std::sync::Arc;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use thiserror::Error;
-use tracing::{debug, error, warn};
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
 
 /// Event dispatcher that manages subscribers and dispatches events
 #[derive(Clone)]
@@ -21,6 +28,17 @@ pub struct EventDispatcher {
     runtime_subscriptions: Arc<DashMap<String, Vec<Arc<dyn EventSubscriber>>>>,
     /// Webhooks (cached in memory, grouped by event_name)
     webhooks: Arc<DashMap<String, Vec<Webhook>>>,
+    /// Database the webhooks were loaded from, their deliveries are recorded there
+    webhook_delivery_pool: Arc<Mutex<Option<DatabasePool>>>,
+    /// Retry behavior of webhook deliveries
+    webhook_retry_policy: WebhookRetryPolicy,
+    /// Best-effort background work (e.g. webhook deliveries) that is still running
+    ///
+    /// Plain join handles detach their task when dropped, so dropping the
+    /// dispatcher does not cancel deliveries that are still in flight.
+    pending_tasks: Arc<Mutex<Vec<JoinHandle<()>>>>,
+    /// Set once [`EventDispatcher::shutdown`] was called
+    shut_down: Arc<AtomicBool>,
 }
 
 impl EventDispatcher {
@@ -30,9 +48,19 @@ impl EventDispatcher {
             database_subscriptions: Arc::new(DashMap::new()),
             runtime_subscriptions: Arc::new(DashMap::new()),
             webhooks: Arc::new(DashMap::new()),
+            webhook_delivery_pool: Arc::new(Mutex::new(None)),
+            webhook_retry_policy: WebhookRetryPolicy::default(),
+            pending_tasks: Arc::new(Mutex::new(Vec::new())),
+            shut_down: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    /// Use a different retry behavior for webhook deliveries
+    pub fn with_webhook_retry_policy(mut self, policy: WebhookRetryPolicy) -> Self {
+        self.webhook_retry_policy = policy;
+        self
+    }
+
     /// Load all active event subscriptions from the database into memory
     ///
     /// This should be called once at application startup to cache
@@ -73,7 +101,8 @@ impl EventDispatcher {
     /// Load all active webhooks from the database into memory
     ///
     /// This should be called once at application startup to cache
-    /// all webhooks for efficient event dispatching.
+    /// all webhooks for efficient event dispatching. Deliveries of these webhooks
+    /// are recorded in the same database.
     pub async fn load_webhooks(
         &self,
         pool: &DatabasePool,
@@ -85,6 +114,7 @@ impl EventDispatcher {
 
         // Clear existing webhooks
         self.webhooks.clear();
+        *self.webhook_delivery_pool.lock().unwrap_or_else(|e| e.into_inner()) = Some(pool.clone());
 
         // Group webhooks by event name
         for webhook in webhooks {
@@ -136,6 +166,18 @@ impl EventDispatcher {
             .push(Arc::from(subscriber));
     }
 
+    /// Register an async closure as runtime subscriber for `event_name`
+    ///
+    /// Shorthand for subscribing a [`FnSubscriber`]. It is invoked alongside database
+    /// subscriptions and webhooks, and can be removed with [`EventDispatcher::unsubscribe`].
+    pub fn subscribe_fn<F, Fut>(&self, subscriber_id: impl Into<String>, event_name: impl Into<String>, handler: F)
+    where
+        F: Fn(Event) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = EventHandlerResult> + Send + 'static,
+    {
+        self.subscribe(Box::new(FnSubscriber::new(subscriber_id, event_name, handler)));
+    }
+
     /// Unregister a runtime event subscriber by ID
     pub fn unsubscribe(&self, event_name: &str, subscriber_id: &str) -> bool {
         if let Some(mut subscribers) = self.runtime_subscriptions.get_mut(event_name) {
@@ -237,14 +279,18 @@ impl EventDispatcher {
                 .cloned()
                 .collect();
 
+            let delivery_pool = self.webhook_delivery_pool.lock().unwrap_or_else(|e| e.into_inner()).clone();
+
             // Send webhooks asynchronously (non-blocking)
             for webhook in matching_webhooks {
                 let webhook_clone = webhook.clone();
                 let event_clone = event.clone();
+                let delivery_pool = delivery_pool.clone();
+                let retry_policy = self.webhook_retry_policy;
                 
-                // Spawn async task to send webhook (fire and forget)
-                tokio::spawn(async move {
-                    match send_webhook(&webhook_clone, &event_clone).await {
+                // Spawn tracked task to send webhook (flushed on shutdown)
+                self.spawn_background(async move {
+                    match deliver_webhook(delivery_pool.as_ref(), &webhook_clone, &event_clone, retry_policy).await {
                         Ok(_) => {
                             debug!("Webhook delivered: {}", webhook_clone.url);
                         }
@@ -257,6 +303,85 @@ impl EventDispatcher {
         }
     }
 
+    /// Spawn best-effort background work that should be flushed on shutdown
+    ///
+    /// The task runs detached from the caller, but [`EventDispatcher::shutdown`]
+    /// waits for it to complete before the application exits.
+    pub fn spawn_background<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let mut pending = self.pending_tasks.lock().unwrap_or_else(|e| e.into_inner());
+
+        // Reap finished tasks so the list does not grow unbounded
+        pending.retain(|handle| !handle.is_finished());
+
+        pending.push(tokio::spawn(task));
+    }
+
+    /// Get the number of background tasks that have not finished yet
+    pub fn pending_task_count(&self) -> usize {
+        let mut pending = self.pending_tasks.lock().unwrap_or_else(|e| e.into_inner());
+        pending.retain(|handle| !handle.is_finished());
+        pending.len()
+    }
+
+    /// Whether the dispatcher accepts events, i.e. it has not been shut down
+    pub fn is_ready(&self) -> bool {
+        !self.shut_down.load(Ordering::SeqCst)
+    }
+
+    /// Flush pending background work before the application exits
+    ///
+    /// Waits up to `timeout` for pending webhook deliveries and other background
+    /// tasks to finish. Tasks still running after the timeout are aborted.
+    ///
+    /// Returns `true` if all pending tasks completed within the timeout.
+    pub async fn shutdown(&self, timeout: Duration) -> bool {
+        self.shut_down.store(true, Ordering::SeqCst);
+
+        let mut pending = {
+            let mut guard = self.pending_tasks.lock().unwrap_or_else(|e| e.into_inner());
+            std::mem::take(&mut *guard)
+        };
+
+        if pending.is_empty() {
+            return true;
+        }
+
+        info!("Waiting for {} pending event deliveries to finish", pending.len());
+
+        let flushed = tokio::time::timeout(timeout, async {
+            for handle in pending.iter_mut() {
+                let _ = handle.await;
+            }
+        })
+        .await
+        .is_ok();
+
+        if !flushed {
+            pending.retain(|handle| !handle.is_finished());
+            warn!(
+                "Aborting {} event deliveries that did not finish within {:?}",
+                pending.len(),
+                timeout
+            );
+            for handle in pending {
+                handle.abort();
+                let _ = handle.await;
+            }
+        }
+
+        flushed
+    }
+
+    /// Whether a runtime subscriber with `subscriber_id` is registered for `event_name`
+    pub fn has_subscriber(&self, event_name: &str, subscriber_id: &str) -> bool {
+        self.runtime_subscriptions
+            .get(event_name)
+            .is_some_and(|subscribers| subscribers.iter().any(|s| s.subscriber_id() == subscriber_id))
+    }
+
     /// Get the number of subscribers for an event
     pub fn subscriber_count(&self, event_name: &str) -> usize {
         let db_count = self
@@ -285,7 +410,7 @@ impl Default for EventDispatcher {
 /// (webhooks, Kafka, etc.) in the future.
 async fn handle_database_subscription(
     subscription: &DatabaseEventSubscription,
-    _event: &Event,
+    event: &Event,
 ) -> Result<(), EventDispatcherError> {
     match subscription.subscriber_type.as_str() {
         "webhook" => {
@@ -293,6 +418,24 @@ async fn handle_database_subscription(
             warn!("Webhook connector not yet implemented for subscription: {}", subscription.id);
             Ok(())
         }
+        "slack" => {
+            let config: SlackConfig = serde_json::from_value(subscription.config.clone())
+                .map_err(|e| EventDispatcherError::InvalidConfig(e.to_string()))?;
+            let subscriber = SlackSubscriber::new(&subscription.id, &subscription.event_name, config)
+                .map_err(|e| EventDispatcherError::InvalidConfig(e.to_string()))?;
+            subscriber
+                .handle_event(event)
+                .await
+                .map_err(|e| EventDispatcherError::DeliveryError(e.to_string()))
+        }
+        "email" => {
+            let config: EmailConfig = serde_json::from_value(subscription.config.clone())
+                .map_err(|e| EventDispatcherError::InvalidConfig(e.to_string()))?;
+            EmailSubscriber::from_config(&subscription.id, &subscription.event_name, config)
+                .handle_event(event)
+                .await
+                .map_err(|e| EventDispatcherError::DeliveryError(e.to_string()))
+        }
         "kafka" => {
             // TODO: Implement Kafka connector
             warn!("Kafka connector not yet implemented for subscription: {}", subscription.id);
@@ -324,5 +467,8 @@ pub enum EventDispatcherError {
 
     #[error("Invalid subscription configuration: {0}")]
     InvalidConfig(String),
+
+    #[error("Failed to deliver event: {0}")]
+    DeliveryError(String),
 }
 
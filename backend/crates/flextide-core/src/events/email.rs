@@ -0,0 +1,291 @@
+//! Email connector for the event system
+//!
+//! Sends events as plain text emails through an SMTP relay.
+
+use crate::events::subscriber::EventSubscriber;
+use crate::events::types::Event;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tracing::{debug, warn};
+
+/// Plain text email
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub from: String,
+    pub to: Vec<String>,
+    pub subject: String,
+    pub body: String,
+}
+
+impl EmailMessage {
+    /// Format the message as RFC 5322 text (headers and body, CRLF line endings)
+    pub fn to_rfc5322(&self) -> String {
+        let body = self.body.replace("\r\n", "\n").replace('\n', "\r\n");
+        format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\nMIME-Version: 1.0\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}\r\n",
+            self.from,
+            self.to.join(", "),
+            self.subject,
+            body
+        )
+    }
+}
+
+/// Transport used to deliver emails
+#[async_trait]
+pub trait EmailTransport: Send + Sync {
+    /// Deliver an email to all of its recipients
+    async fn send(&self, message: &EmailMessage) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// SMTP relay configuration
+#[derive(Debug, Clone, Deserialize)]
+pub struct SmtpConfig {
+    /// Host name of the relay
+    pub host: String,
+    /// Port of the relay (default: 25)
+    #[serde(default = "default_smtp_port")]
+    pub port: u16,
+    /// Host name announced in `EHLO` (default: "localhost")
+    #[serde(default = "default_helo_name")]
+    pub helo_name: String,
+}
+
+fn default_smtp_port() -> u16 {
+    25
+}
+
+fn default_helo_name() -> String {
+    "localhost".to_string()
+}
+
+/// Email transport that delivers through an SMTP relay without authentication
+///
+/// The relay is expected to accept mail from the application host, e.g. a local
+/// MTA or an internal relay. TLS and authentication are not supported.
+pub struct SmtpTransport {
+    config: SmtpConfig,
+    timeout: Duration,
+}
+
+impl SmtpTransport {
+    /// Create an SMTP transport for a relay
+    pub fn new(config: SmtpConfig) -> Self {
+        Self {
+            config,
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    async fn deliver(&self, message: &EmailMessage) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // These values end up in SMTP commands and headers, a line break would inject new ones
+        reject_line_breaks("HELO name", &self.config.helo_name)?;
+        reject_line_breaks("sender", &message.from)?;
+        for recipient in &message.to {
+            reject_line_breaks("recipient", recipient)?;
+        }
+        reject_line_breaks("subject", &message.subject)?;
+
+        let stream = TcpStream::connect((self.config.host.as_str(), self.config.port)).await?;
+        let (reader, mut writer) = stream.into_split();
+        let mut reader = BufReader::new(reader);
+
+        expect_reply(&mut reader, 220).await?;
+        send_command(&mut writer, &mut reader, &format!("EHLO {}", self.config.helo_name), 250).await?;
+        send_command(&mut writer, &mut reader, &format!("MAIL FROM:<{}>", message.from), 250).await?;
+        for recipient in &message.to {
+            send_command(&mut writer, &mut reader, &format!("RCPT TO:<{}>", recipient), 250).await?;
+        }
+        send_command(&mut writer, &mut reader, "DATA", 354).await?;
+
+        // Dot-stuff lines starting with a dot, then terminate the data with a single dot
+        let data = message.to_rfc5322().replace("\r\n.", "\r\n..");
+        writer.write_all(data.as_bytes()).await?;
+        send_command(&mut writer, &mut reader, ".", 250).await?;
+        send_command(&mut writer, &mut reader, "QUIT", 221).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EmailTransport for SmtpTransport {
+    async fn send(&self, message: &EmailMessage) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        tokio::time::timeout(self.timeout, self.deliver(message))
+            .await
+            .map_err(|_| format!("SMTP delivery to {} timed out", self.config.host))?
+    }
+}
+
+fn reject_line_breaks(name: &str, value: &str) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if value.contains(['\r', '\n']) {
+        return Err(format!("SMTP {} must not contain line breaks", name).into());
+    }
+    Ok(())
+}
+
+async fn send_command<W, R>(
+    writer: &mut W,
+    reader: &mut R,
+    command: &str,
+    expected_code: u16,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    W: AsyncWriteExt + Unpin,
+    R: AsyncBufReadExt + Unpin,
+{
+    writer.write_all(format!("{}\r\n", command).as_bytes()).await?;
+    expect_reply(reader, expected_code).await
+}
+
+/// Read a (possibly multi-line) SMTP reply and check its status code
+async fn expect_reply<R>(reader: &mut R, expected_code: u16) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    R: AsyncBufReadExt + Unpin,
+{
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await? == 0 {
+            return Err("SMTP connection closed unexpectedly".into());
+        }
+
+        let code: u16 = line
+            .get(..3)
+            .and_then(|code| code.parse().ok())
+            .ok_or_else(|| format!("Invalid SMTP reply: {}", line.trim_end()))?;
+        if code != expected_code {
+            return Err(format!("Unexpected SMTP reply: {}", line.trim_end()).into());
+        }
+
+        // "250-" continues a multi-line reply, "250 " ends it
+        if line.as_bytes().get(3) != Some(&b'-') {
+            return Ok(());
+        }
+    }
+}
+
+/// Recipients of event emails
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EmailRecipients {
+    /// Recipients by organization UUID
+    #[serde(default)]
+    pub organizations: HashMap<String, Vec<String>>,
+    /// Recipients for events without an organization or of organizations without own recipients
+    #[serde(default)]
+    pub default: Vec<String>,
+}
+
+impl EmailRecipients {
+    /// Resolve the recipients of an event
+    pub fn resolve(&self, organization_uuid: Option<&str>) -> &[String] {
+        organization_uuid
+            .and_then(|org| self.organizations.get(org))
+            .unwrap_or(&self.default)
+    }
+}
+
+/// Configuration of database subscriptions with the subscriber type `"email"`
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmailConfig {
+    /// SMTP relay used for delivery
+    pub smtp: SmtpConfig,
+    /// Sender address
+    pub from: String,
+    /// Recipients of the emails
+    #[serde(default)]
+    pub recipients: EmailRecipients,
+}
+
+/// Event subscriber that notifies recipients of an event by email
+pub struct EmailSubscriber {
+    subscriber_id: String,
+    event_name: String,
+    from: String,
+    recipients: EmailRecipients,
+    transport: Arc<dyn EmailTransport>,
+}
+
+impl EmailSubscriber {
+    /// Create an email subscriber for an event
+    pub fn new(
+        subscriber_id: impl Into<String>,
+        event_name: impl Into<String>,
+        from: impl Into<String>,
+        recipients: EmailRecipients,
+        transport: Arc<dyn EmailTransport>,
+    ) -> Self {
+        Self {
+            subscriber_id: subscriber_id.into(),
+            event_name: event_name.into(),
+            from: from.into(),
+            recipients,
+            transport,
+        }
+    }
+
+    /// Create an email subscriber delivering through the SMTP relay of the config
+    pub fn from_config(subscriber_id: impl Into<String>, event_name: impl Into<String>, config: EmailConfig) -> Self {
+        Self::new(
+            subscriber_id,
+            event_name,
+            config.from,
+            config.recipients,
+            Arc::new(SmtpTransport::new(config.smtp)),
+        )
+    }
+
+    /// Build the email for an event, `None` if the event has no recipients
+    pub fn format_message(&self, event: &Event) -> Option<EmailMessage> {
+        let to = self.recipients.resolve(event.organization_uuid.as_deref());
+        if to.is_empty() {
+            return None;
+        }
+
+        let payload = serde_json::to_string_pretty(&event.payload.data).unwrap_or_default();
+        let body = format!(
+            "Event: {}\nTime: {}\nOrganization: {}\nUser: {}\n\nPayload:\n{}",
+            event.name,
+            event.timestamp.to_rfc3339(),
+            event.organization_uuid.as_deref().unwrap_or("-"),
+            event.user_uuid.as_deref().unwrap_or("-"),
+            payload
+        );
+
+        Some(EmailMessage {
+            from: self.from.clone(),
+            to: to.to_vec(),
+            subject: format!("[Flextide] {}", event.name),
+            body,
+        })
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for EmailSubscriber {
+    async fn handle_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let Some(message) = self.format_message(event) else {
+            debug!("No email recipients for event {} (subscriber {})", event.name, self.subscriber_id);
+            return Ok(());
+        };
+
+        debug!("Sending email for event {} to {} recipients", event.name, message.to.len());
+
+        // Best-effort delivery: failures are logged and reported, but never retried
+        self.transport.send(&message).await.inspect_err(|e| {
+            warn!("Email delivery failed for subscriber {}: {}", self.subscriber_id, e);
+        })
+    }
+
+    fn event_name(&self) -> &str {
+        &self.event_name
+    }
+
+    fn subscriber_id(&self) -> &str {
+        &self.subscriber_id
+    }
+}
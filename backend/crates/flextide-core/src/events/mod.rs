@@ -3,25 +3,36 @@
 //! Provides a flexible event system for the Flextide platform that supports:
 //! - Event emission with JSON payloads
 //! - Database-backed event subscriptions (cached in memory)
-//! - Runtime event subscriptions
-//! - Extensible architecture for future connectors (webhooks, Kafka, etc.)
+//! - Runtime event subscriptions, including plain async closures ([`FnSubscriber`])
+//! - Extensible architecture for future connectors (webhooks, Slack, email, Kafka, etc.)
 
 mod database;
 mod dispatcher;
+mod email;
+mod slack;
 mod subscriber;
 mod types;
+mod webhook_deliveries;
 mod webhooks;
 
 #[cfg(test)]
 mod tests;
 
 pub use dispatcher::{EventDispatcher, EventDispatcherError};
-pub use subscriber::{EventSubscriber, EventSubscriberType};
-pub use types::{Event, EventPayload};
+pub use email::{
+    EmailConfig, EmailMessage, EmailRecipients, EmailSubscriber, EmailTransport, SmtpConfig, SmtpTransport,
+};
+pub use slack::{SlackConfig, SlackSubscriber};
+pub use subscriber::{EventHandlerResult, EventSubscriber, EventSubscriberType, FnSubscriber};
+pub use types::{field_changes, Event, EventPayload};
+pub use webhook_deliveries::{
+    deliver_webhook, list_webhook_deliveries, WebhookDelivery, WebhookRetryPolicy,
+};
 pub use webhooks::{
-    CreateWebhookRequest, UpdateWebhookRequest, Webhook,
-    create_webhook, delete_webhook, get_webhook, load_webhooks, load_webhooks_by_organization,
-    send_webhook, update_webhook,
+    CreateWebhookRequest, UpdateWebhookRequest, Webhook, WebhookContentType, WebhookTemplateError,
+    build_webhook_payload, create_webhook, delete_webhook, encode_webhook_body, get_webhook,
+    load_webhooks, load_webhooks_by_organization, send_webhook, update_webhook,
+    validate_payload_template,
 };
 
 /// Initialize the event system by loading database-backed subscriptions and webhooks
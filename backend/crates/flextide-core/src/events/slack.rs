@@ -0,0 +1,140 @@
+//! Slack connector for the event system
+//!
+//! Posts events as messages to a Slack incoming webhook.
+
+use crate::events::subscriber::EventSubscriber;
+use crate::events::types::Event;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// Configuration of a Slack subscriber
+///
+/// This is also the `config` of database subscriptions with the subscriber type `"slack"`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SlackConfig {
+    /// URL of the Slack incoming webhook
+    pub webhook_url: String,
+    /// Message templates by event name
+    ///
+    /// Templates may contain the placeholders `{event}`, `{timestamp}`, `{organization_uuid}`,
+    /// `{user_uuid}` and `{payload.<field>}`. Events without a template are posted with a
+    /// default message.
+    #[serde(default)]
+    pub templates: HashMap<String, String>,
+}
+
+/// Event subscriber that posts events to a Slack incoming webhook
+pub struct SlackSubscriber {
+    subscriber_id: String,
+    event_name: String,
+    config: SlackConfig,
+    client: Client,
+}
+
+impl SlackSubscriber {
+    /// Create a Slack subscriber for an event
+    pub fn new(
+        subscriber_id: impl Into<String>,
+        event_name: impl Into<String>,
+        config: SlackConfig,
+    ) -> Result<Self, reqwest::Error> {
+        let client = Client::builder().timeout(Duration::from_secs(30)).build()?;
+
+        Ok(Self {
+            subscriber_id: subscriber_id.into(),
+            event_name: event_name.into(),
+            config,
+            client,
+        })
+    }
+
+    /// Build the Slack message for an event
+    pub fn format_message(&self, event: &Event) -> JsonValue {
+        let text = match self.config.templates.get(&event.name) {
+            Some(template) => render_template(template, event),
+            None => format!("Event `{}` was emitted", event.name),
+        };
+
+        serde_json::json!({ "text": text })
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for SlackSubscriber {
+    async fn handle_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        debug!("Sending Slack notification for event {}", event.name);
+
+        let response = self
+            .client
+            .post(&self.config.webhook_url)
+            .json(&self.format_message(event))
+            .send()
+            .await?;
+        let status = response.status();
+
+        if status.is_success() {
+            Ok(())
+        } else {
+            let error_text = response.text().await.unwrap_or_default();
+            warn!(
+                "Slack notification failed for subscriber {} (status: {}, error: {})",
+                self.subscriber_id, status, error_text
+            );
+            Err(format!("Slack notification failed with status {}: {}", status, error_text).into())
+        }
+    }
+
+    fn event_name(&self) -> &str {
+        &self.event_name
+    }
+
+    fn subscriber_id(&self) -> &str {
+        &self.subscriber_id
+    }
+}
+
+/// Replace the placeholders of a message template with values of the event
+///
+/// Unknown placeholders are kept as they are.
+fn render_template(template: &str, event: &Event) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        let placeholder = &rest[start + 1..start + end];
+
+        match placeholder_value(placeholder, event) {
+            Some(value) => result.push_str(&value),
+            None => result.push_str(&rest[start..=start + end]),
+        }
+        rest = &rest[start + end + 1..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+fn placeholder_value(placeholder: &str, event: &Event) -> Option<String> {
+    match placeholder {
+        "event" => Some(event.name.clone()),
+        "timestamp" => Some(event.timestamp.to_rfc3339()),
+        "organization_uuid" => Some(event.organization_uuid.clone().unwrap_or_default()),
+        "user_uuid" => Some(event.user_uuid.clone().unwrap_or_default()),
+        _ => {
+            let field = placeholder.strip_prefix("payload.")?;
+            match event.payload.data.get(field)? {
+                JsonValue::String(s) => Some(s.clone()),
+                value => Some(value.to_string()),
+            }
+        }
+    }
+}
@@ -6,6 +6,8 @@ use crate::events::types::Event;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
+use std::future::Future;
+use std::pin::Pin;
 
 /// Trait for event subscribers that can handle events
 #[async_trait]
@@ -23,6 +25,59 @@ pub trait EventSubscriber: Send + Sync {
     fn subscriber_id(&self) -> &str;
 }
 
+/// Result returned by event handlers
+pub type EventHandlerResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+type BoxedEventHandler =
+    Box<dyn Fn(Event) -> Pin<Box<dyn Future<Output = EventHandlerResult> + Send>> + Send + Sync>;
+
+/// Event subscriber that calls an async closure
+///
+/// Lets code embedding the event system react to events in-process without
+/// implementing [`EventSubscriber`]. The closure receives its own copy of the event.
+///
+/// ```ignore
+/// dispatcher.subscribe(Box::new(FnSubscriber::new("audit", "user_created", |event| async move {
+///     println!("{} was emitted", event.name);
+///     Ok(())
+/// })));
+/// ```
+pub struct FnSubscriber {
+    subscriber_id: String,
+    event_name: String,
+    handler: BoxedEventHandler,
+}
+
+impl FnSubscriber {
+    /// Create a subscriber calling `handler` for every emitted `event_name` event
+    pub fn new<F, Fut>(subscriber_id: impl Into<String>, event_name: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(Event) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = EventHandlerResult> + Send + 'static,
+    {
+        Self {
+            subscriber_id: subscriber_id.into(),
+            event_name: event_name.into(),
+            handler: Box::new(move |event| Box::pin(handler(event))),
+        }
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for FnSubscriber {
+    async fn handle_event(&self, event: &Event) -> EventHandlerResult {
+        (self.handler)(event.clone()).await
+    }
+
+    fn event_name(&self) -> &str {
+        &self.event_name
+    }
+
+    fn subscriber_id(&self) -> &str {
+        &self.subscriber_id
+    }
+}
+
 /// Type of event subscriber
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum EventSubscriberType {
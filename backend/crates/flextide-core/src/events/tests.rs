@@ -1,7 +1,7 @@
 //! Tests for the event system
 
 use crate::events::{
-    Event, EventDispatcher, EventPayload, EventSubscriber,
+    field_changes, Event, EventDispatcher, EventPayload, EventSubscriber, FnSubscriber,
 };
 use async_trait::async_trait;
 use serde_json::json;
@@ -130,6 +130,23 @@ async fn test_event_payload_empty() {
     assert!(payload.data.as_object().unwrap().is_empty());
 }
 
+#[test]
+fn test_field_changes_lists_only_changed_fields() {
+    let before = json!({ "title": "Old", "summary": "Same", "archived": false, "tag": "x" });
+    let after = json!({ "title": "New", "summary": "Same", "archived": true, "owner": "me" });
+
+    assert_eq!(
+        field_changes(&before, &after),
+        json!({
+            "title": { "old": "Old", "new": "New" },
+            "archived": { "old": false, "new": true },
+            "tag": { "old": "x", "new": null },
+            "owner": { "old": null, "new": "me" }
+        })
+    );
+    assert_eq!(field_changes(&before, &before), json!({}));
+}
+
 #[tokio::test]
 async fn test_runtime_subscriber() {
     let dispatcher = EventDispatcher::new();
@@ -145,6 +162,42 @@ async fn test_runtime_subscriber() {
     assert_eq!(received[0].name, "test.event");
 }
 
+#[tokio::test]
+async fn test_closure_subscriber() {
+    let dispatcher = EventDispatcher::new();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = TestSubscriber::new("test.event", "subscriber-1");
+
+    let closure_received = received.clone();
+    dispatcher.subscribe_fn("closure-subscriber", "test.event", move |event| {
+        let received = closure_received.clone();
+        async move {
+            received.lock().await.push(event);
+            Ok(())
+        }
+    });
+    dispatcher.subscribe(Box::new(subscriber.clone()));
+    dispatcher.subscribe(Box::new(FnSubscriber::new("failing-closure", "test.event", |_| async {
+        Err("Test error".into())
+    })));
+    assert_eq!(dispatcher.subscriber_count("test.event"), 3);
+
+    let event = Event::new("test.event", EventPayload::new(json!({"data": "value"})))
+        .with_organization("org-1");
+    dispatcher.emit(event).await;
+    dispatcher.emit(Event::new("other.event", EventPayload::empty())).await;
+
+    let received = received.lock().await;
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].name, "test.event");
+    assert_eq!(received[0].payload.data["data"], "value");
+    assert_eq!(received[0].organization_uuid.as_deref(), Some("org-1"));
+    // Other subscribers of the event still receive it
+    assert_eq!(subscriber.get_received_events().await.len(), 1);
+
+    assert!(dispatcher.unsubscribe("test.event", "closure-subscriber"));
+}
+
 #[tokio::test]
 async fn test_multiple_subscribers_same_event() {
     let dispatcher = EventDispatcher::new();
@@ -487,3 +540,561 @@ async fn test_multiple_emissions_call_handlers_multiple_times() {
     }
 }
 
+
+/// Test that shutdown gives pending background work a chance to complete
+#[tokio::test]
+async fn test_shutdown_flushes_pending_background_tasks() {
+    let dispatcher = EventDispatcher::new();
+    let completed = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+    let completed_clone = completed.clone();
+    dispatcher.spawn_background(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        completed_clone.store(true, std::sync::atomic::Ordering::SeqCst);
+    });
+
+    assert_eq!(dispatcher.pending_task_count(), 1);
+
+    let flushed = dispatcher.shutdown(std::time::Duration::from_secs(5)).await;
+
+    assert!(flushed, "All pending tasks should finish within the timeout");
+    assert!(
+        completed.load(std::sync::atomic::Ordering::SeqCst),
+        "Pending task should have completed before shutdown returned"
+    );
+    assert_eq!(dispatcher.pending_task_count(), 0);
+}
+
+/// Test that shutdown aborts background work that exceeds the timeout
+#[tokio::test]
+async fn test_shutdown_aborts_tasks_after_timeout() {
+    let dispatcher = EventDispatcher::new();
+    let completed = Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+    let completed_clone = completed.clone();
+    dispatcher.spawn_background(async move {
+        tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+        completed_clone.store(true, std::sync::atomic::Ordering::SeqCst);
+    });
+
+    let flushed = dispatcher.shutdown(std::time::Duration::from_millis(50)).await;
+
+    assert!(!flushed, "Shutdown should report tasks that did not finish");
+    assert!(!completed.load(std::sync::atomic::Ordering::SeqCst));
+    assert_eq!(dispatcher.pending_task_count(), 0);
+}
+
+/// Test that dropping the dispatcher does not cancel background work that is still running
+#[tokio::test]
+async fn test_dropping_dispatcher_keeps_background_tasks_running() {
+    let dispatcher = EventDispatcher::new();
+    let (sender, receiver) = tokio::sync::oneshot::channel();
+
+    dispatcher.spawn_background(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        let _ = sender.send(());
+    });
+    drop(dispatcher);
+
+    tokio::time::timeout(std::time::Duration::from_secs(5), receiver)
+        .await
+        .expect("Background task did not finish")
+        .expect("Background task was aborted when the dispatcher was dropped");
+}
+
+/// Test that shutdown without pending work returns immediately
+#[tokio::test]
+async fn test_shutdown_without_pending_tasks() {
+    let dispatcher = EventDispatcher::new();
+
+    assert!(dispatcher.shutdown(std::time::Duration::from_millis(10)).await);
+}
+
+/// Test that the dispatcher is no longer ready after shutdown
+#[tokio::test]
+async fn test_dispatcher_not_ready_after_shutdown() {
+    let dispatcher = EventDispatcher::new();
+    assert!(dispatcher.is_ready());
+
+    dispatcher.shutdown(std::time::Duration::from_millis(10)).await;
+    assert!(!dispatcher.is_ready());
+    assert!(!dispatcher.clone().is_ready());
+}
+
+/// Accept a single HTTP request and return its head and body, responding with 200 OK
+async fn capture_request(listener: tokio::net::TcpListener) -> (String, String) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let (mut stream, _) = listener.accept().await.expect("Failed to accept connection");
+    let mut request = Vec::new();
+    let mut buffer = [0u8; 4096];
+    loop {
+        let read = stream.read(&mut buffer).await.expect("Failed to read request");
+        request.extend_from_slice(&buffer[..read]);
+
+        let text = String::from_utf8_lossy(&request);
+        if let Some(header_end) = text.find("\r\n\r\n") {
+            let content_length = text[..header_end]
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length").then(|| value.trim().parse::<usize>().ok())?
+                })
+                .unwrap_or(0);
+            if request.len() >= header_end + 4 + content_length || read == 0 {
+                stream
+                    .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+                    .await
+                    .expect("Failed to write response");
+                return (text[..header_end].to_string(), text[header_end + 4..].to_string());
+            }
+        }
+    }
+}
+
+/// Accept a single HTTP request and return its body, responding with 200 OK
+async fn capture_request_body(listener: tokio::net::TcpListener) -> String {
+    capture_request(listener).await.1
+}
+
+/// Webhook posting to `url`, optionally with a payload template
+fn test_webhook(url: String, payload_template: Option<serde_json::Value>) -> crate::events::Webhook {
+    crate::events::Webhook {
+        id: "webhook-1".to_string(),
+        organization_uuid: "org-1".to_string(),
+        event_name: "customer.created".to_string(),
+        url,
+        secret: None,
+        headers: None,
+        payload_template,
+        content_type: Default::default(),
+        active: true,
+        created_by: "user-1".to_string(),
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_templated_webhook_posts_transformed_payload() {
+    use crate::events::send_webhook;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hooks/customers", listener.local_addr().unwrap());
+    let server = tokio::spawn(capture_request_body(listener));
+
+    let template = json!({
+        "type": "{event}",
+        "customer": {"id": "{payload.customer.id}", "tags": "{payload.customer.tags}"},
+        "summary": "{payload.customer.name} joined {organization_uuid}",
+        "missing": "{payload.customer.phone}",
+        "source": "flextide"
+    });
+    let webhook = test_webhook(url, Some(template));
+    let event = Event::new(
+        "customer.created",
+        EventPayload::new(json!({"customer": {"id": 42, "name": "Jane", "tags": ["vip"]}})),
+    )
+    .with_organization("org-1");
+
+    send_webhook(&webhook, &event).await.expect("Webhook delivery should succeed");
+
+    let body: serde_json::Value = serde_json::from_str(&server.await.unwrap()).expect("Payload should be JSON");
+    assert_eq!(
+        body,
+        json!({
+            "type": "customer.created",
+            "customer": {"id": 42, "tags": ["vip"]},
+            "summary": "Jane joined org-1",
+            "missing": null,
+            "source": "flextide"
+        })
+    );
+}
+
+#[test]
+fn test_webhook_without_template_sends_raw_event() {
+    use crate::events::build_webhook_payload;
+
+    let webhook = test_webhook("http://127.0.0.1:9".to_string(), None);
+    let event = Event::new("customer.created", EventPayload::new(json!({"id": 1})));
+
+    let payload = build_webhook_payload(&webhook, &event);
+    assert_eq!(payload["webhook_id"], "webhook-1");
+    assert_eq!(payload["event"]["name"], "customer.created");
+    assert_eq!(payload["event"]["payload"], json!({"id": 1}));
+}
+
+#[test]
+fn test_payload_template_validation() {
+    use crate::events::{validate_payload_template, WebhookTemplateError};
+
+    assert_eq!(
+        validate_payload_template(&json!({
+            "a": "{event} at {timestamp}",
+            "b": ["{webhook_id}", "{user_uuid}", {"c": "{payload}"}],
+            "d": "{payload.customer.id}",
+            "e": 5
+        })),
+        Ok(())
+    );
+    assert_eq!(
+        validate_payload_template(&json!({"a": ["{customer.id}"]})),
+        Err(WebhookTemplateError::UnknownPlaceholder("customer.id".to_string()))
+    );
+    assert_eq!(
+        validate_payload_template(&json!("{payload..id}")),
+        Err(WebhookTemplateError::UnknownPlaceholder("payload..id".to_string()))
+    );
+}
+
+/// Answer one request per status code, in order, then stop accepting connections
+async fn respond_with_statuses(listener: tokio::net::TcpListener, statuses: Vec<u16>) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    for status in statuses {
+        let (mut stream, _) = listener.accept().await.expect("Failed to accept connection");
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 4096];
+        // The test payloads are small, the request is complete once the body arrived
+        while !String::from_utf8_lossy(&request).contains("}") {
+            let read = stream.read(&mut buffer).await.expect("Failed to read request");
+            if read == 0 {
+                break;
+            }
+            request.extend_from_slice(&buffer[..read]);
+        }
+        let response = format!("HTTP/1.1 {} Status\r\nContent-Length: 4\r\nConnection: close\r\n\r\nbody", status);
+        stream.write_all(response.as_bytes()).await.expect("Failed to write response");
+    }
+}
+
+async fn setup_webhook_deliveries_table() -> crate::database::DatabasePool {
+    let pool = crate::database::create_test_pool().await.expect("Failed to create test pool");
+    match &pool {
+        crate::database::DatabasePool::Sqlite(p) => {
+            sqlx::query(
+                "CREATE TABLE IF NOT EXISTS webhook_deliveries (
+                    id CHAR(36) NOT NULL PRIMARY KEY,
+                    webhook_id CHAR(36) NOT NULL,
+                    organization_uuid CHAR(36) NOT NULL,
+                    event_name VARCHAR(255) NOT NULL,
+                    attempt INTEGER NOT NULL,
+                    status_code INTEGER NULL,
+                    success INTEGER NOT NULL DEFAULT 0,
+                    latency_ms BIGINT NOT NULL,
+                    error TEXT NULL,
+                    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+                )",
+            )
+            .execute(p)
+            .await
+            .expect("Failed to create webhook_deliveries table");
+        }
+        _ => unreachable!("Test pool should be SQLite"),
+    }
+    pool
+}
+
+fn fast_retries(max_attempts: u32) -> crate::events::WebhookRetryPolicy {
+    crate::events::WebhookRetryPolicy {
+        max_attempts,
+        base_delay: std::time::Duration::from_millis(10),
+    }
+}
+
+#[tokio::test]
+async fn test_webhook_deliveries_are_recorded_with_retries() {
+    use crate::events::{deliver_webhook, list_webhook_deliveries};
+
+    let pool = setup_webhook_deliveries_table().await;
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let webhook = test_webhook(format!("http://{}/hook", listener.local_addr().unwrap()), None);
+    let server = tokio::spawn(respond_with_statuses(listener, vec![503, 200]));
+
+    let event = Event::new("customer.created", EventPayload::new(json!({"id": 1})));
+    deliver_webhook(Some(&pool), &webhook, &event, fast_retries(3))
+        .await
+        .expect("Second attempt should succeed");
+    server.await.unwrap();
+
+    let deliveries = list_webhook_deliveries(&pool, "webhook-1", 10).await.unwrap();
+    let summary: Vec<_> = deliveries
+        .iter()
+        .map(|d| (d.attempt, d.status_code, d.success, d.event_name.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![(2, Some(200), true, "customer.created"), (1, Some(503), false, "customer.created")]
+    );
+    assert!(deliveries[1].error.as_deref().unwrap().contains("503"));
+    assert_eq!(deliveries[0].error, None);
+
+    assert_eq!(list_webhook_deliveries(&pool, "webhook-1", 1).await.unwrap().len(), 1);
+    assert!(list_webhook_deliveries(&pool, "webhook-2", 10).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_webhook_deliveries_are_recorded() {
+    use crate::events::{deliver_webhook, list_webhook_deliveries};
+
+    let pool = setup_webhook_deliveries_table().await;
+    let event = Event::new("customer.created", EventPayload::empty());
+
+    // Client errors are not retried
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let webhook = test_webhook(format!("http://{}/hook", listener.local_addr().unwrap()), None);
+    let server = tokio::spawn(respond_with_statuses(listener, vec![404]));
+    assert!(deliver_webhook(Some(&pool), &webhook, &event, fast_retries(3)).await.is_err());
+    server.await.unwrap();
+
+    let deliveries = list_webhook_deliveries(&pool, "webhook-1", 10).await.unwrap();
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!((deliveries[0].status_code, deliveries[0].success), (Some(404), false));
+
+    // Unreachable endpoints are retried until the attempts are used up
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let mut unreachable = test_webhook(format!("http://{}/hook", listener.local_addr().unwrap()), None);
+    unreachable.id = "webhook-2".to_string();
+    drop(listener);
+    assert!(deliver_webhook(Some(&pool), &unreachable, &event, fast_retries(2)).await.is_err());
+
+    let deliveries = list_webhook_deliveries(&pool, "webhook-2", 10).await.unwrap();
+    let summary: Vec<_> = deliveries.iter().map(|d| (d.attempt, d.status_code, d.success)).collect();
+    assert_eq!(summary, vec![(2, None, false), (1, None, false)]);
+    assert!(deliveries.iter().all(|d| d.error.is_some()));
+}
+
+#[tokio::test]
+async fn test_webhook_body_encoding_per_content_type() {
+    use crate::events::{send_webhook, WebhookContentType};
+
+    let template = json!({"customer": "{payload.name}", "tags": "{payload.tags}", "note": "a&b=c"});
+    let event = Event::new("customer.created", EventPayload::new(json!({"name": "Jane Doe", "tags": ["vip"]})));
+
+    for (content_type, expected_body) in [
+        (
+            WebhookContentType::Json,
+            r#"{"customer":"Jane Doe","note":"a&b=c","tags":["vip"]}"#,
+        ),
+        (
+            WebhookContentType::FormUrlEncoded,
+            "customer=Jane+Doe&note=a%26b%3Dc&tags=%5B%22vip%22%5D",
+        ),
+    ] {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mut webhook = test_webhook(format!("http://{}/hook", listener.local_addr().unwrap()), Some(template.clone()));
+        webhook.content_type = content_type;
+        let server = tokio::spawn(capture_request(listener));
+
+        send_webhook(&webhook, &event).await.expect("Webhook delivery should succeed");
+
+        let (head, body) = server.await.unwrap();
+        let header = head
+            .lines()
+            .find_map(|line| line.strip_prefix("content-type: "))
+            .expect("Content-Type header should be set");
+        assert_eq!(header, content_type.as_str());
+        assert_eq!(body, expected_body);
+    }
+}
+
+#[test]
+fn test_form_encoding_of_non_object_payload() {
+    use crate::events::{encode_webhook_body, WebhookContentType};
+
+    let body = encode_webhook_body(WebhookContentType::FormUrlEncoded, &json!(["a", 1])).unwrap();
+    assert_eq!(body, "payload=%5B%22a%22%2C1%5D");
+    assert_eq!(
+        serde_json::to_value(WebhookContentType::FormUrlEncoded).unwrap(),
+        json!("application/x-www-form-urlencoded")
+    );
+}
+
+#[tokio::test]
+async fn test_slack_subscriber_posts_formatted_message() {
+    use crate::events::{SlackConfig, SlackSubscriber};
+    use std::collections::HashMap;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let webhook_url = format!("http://{}/services/T000/B000/XXX", listener.local_addr().unwrap());
+    let server = tokio::spawn(capture_request_body(listener));
+
+    let config = SlackConfig {
+        webhook_url,
+        templates: HashMap::from([(
+            "project.created".to_string(),
+            "Project *{payload.name}* ({payload.id}) created in {organization_uuid} {unknown}".to_string(),
+        )]),
+    };
+    let dispatcher = EventDispatcher::new();
+    dispatcher.subscribe(Box::new(
+        SlackSubscriber::new("slack-1", "project.created", config).expect("Failed to create subscriber"),
+    ));
+
+    let event = Event::new("project.created", EventPayload::new(json!({"name": "Apollo", "id": 7})))
+        .with_organization("org-1");
+    dispatcher.emit(event).await;
+
+    let body: serde_json::Value = serde_json::from_str(&server.await.unwrap()).expect("Payload should be JSON");
+    assert_eq!(body, json!({"text": "Project *Apollo* (7) created in org-1 {unknown}"}));
+}
+
+#[tokio::test]
+async fn test_slack_subscriber_default_message() {
+    use crate::events::{SlackConfig, SlackSubscriber};
+
+    let subscriber = SlackSubscriber::new(
+        "slack-2",
+        "user.registered",
+        SlackConfig { webhook_url: "http://127.0.0.1:9".to_string(), templates: Default::default() },
+    )
+    .unwrap();
+
+    let message = subscriber.format_message(&Event::new("user.registered", EventPayload::empty()));
+    assert_eq!(message, json!({"text": "Event `user.registered` was emitted"}));
+}
+
+/// Email transport that records messages instead of sending them
+#[derive(Default)]
+struct MockEmailTransport {
+    sent: Mutex<Vec<crate::events::EmailMessage>>,
+}
+
+#[async_trait]
+impl crate::events::EmailTransport for MockEmailTransport {
+    async fn send(
+        &self,
+        message: &crate::events::EmailMessage,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.sent.lock().await.push(message.clone());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_email_subscriber_sends_to_organization_recipients() {
+    use crate::events::{EmailRecipients, EmailSubscriber};
+    use std::collections::HashMap;
+
+    let transport = Arc::new(MockEmailTransport::default());
+    let recipients = EmailRecipients {
+        organizations: HashMap::from([("org-1".to_string(), vec!["ops@example.com".to_string()])]),
+        default: vec!["admin@example.com".to_string()],
+    };
+    let dispatcher = EventDispatcher::new();
+    dispatcher.subscribe(Box::new(EmailSubscriber::new(
+        "email-1",
+        "backup.failed",
+        "flextide@example.com",
+        recipients,
+        transport.clone(),
+    )));
+
+    dispatcher
+        .emit(Event::new("backup.failed", EventPayload::new(json!({"error": "disk full"}))).with_organization("org-1"))
+        .await;
+    dispatcher.emit(Event::new("backup.completed", EventPayload::empty())).await;
+    dispatcher.emit(Event::new("backup.failed", EventPayload::empty())).await;
+
+    let sent = transport.sent.lock().await;
+    assert_eq!(sent.len(), 2);
+
+    assert_eq!(sent[0].from, "flextide@example.com");
+    assert_eq!(sent[0].to, vec!["ops@example.com"]);
+    assert_eq!(sent[0].subject, "[Flextide] backup.failed");
+    assert!(sent[0].body.contains("Organization: org-1"), "{}", sent[0].body);
+    assert!(sent[0].body.contains("\"error\": \"disk full\""), "{}", sent[0].body);
+
+    // Events without an organization go to the default recipients
+    assert_eq!(sent[1].to, vec!["admin@example.com"]);
+    assert!(sent[1].body.contains("Organization: -"));
+}
+
+#[tokio::test]
+async fn test_smtp_transport_dialog() {
+    use crate::events::{EmailMessage, EmailTransport, SmtpConfig, SmtpTransport};
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        let mut transcript = Vec::new();
+
+        writer.write_all(b"220 mock ESMTP\r\n").await.unwrap();
+        let mut in_data = false;
+        while let Some(line) = lines.next_line().await.unwrap() {
+            let reply: &[u8] = if in_data {
+                if line == "." {
+                    in_data = false;
+                    b"250 queued\r\n"
+                } else {
+                    transcript.push(line);
+                    continue;
+                }
+            } else if line.starts_with("EHLO") {
+                b"250-mock\r\n250 8BITMIME\r\n"
+            } else if line == "DATA" {
+                in_data = true;
+                b"354 go ahead\r\n"
+            } else if line == "QUIT" {
+                writer.write_all(b"221 bye\r\n").await.unwrap();
+                transcript.push(line);
+                break;
+            } else {
+                b"250 ok\r\n"
+            };
+            transcript.push(line);
+            writer.write_all(reply).await.unwrap();
+        }
+        transcript
+    });
+
+    let transport = SmtpTransport::new(SmtpConfig { host: "127.0.0.1".to_string(), port, helo_name: "flextide".to_string() });
+    let message = EmailMessage {
+        from: "flextide@example.com".to_string(),
+        to: vec!["a@example.com".to_string(), "b@example.com".to_string()],
+        subject: "Test".to_string(),
+        body: "Hello\n.hidden".to_string(),
+    };
+    transport.send(&message).await.expect("SMTP delivery should succeed");
+
+    let transcript = server.await.unwrap();
+    assert_eq!(transcript[..4], ["EHLO flextide", "MAIL FROM:<flextide@example.com>", "RCPT TO:<a@example.com>", "RCPT TO:<b@example.com>"]);
+    assert!(transcript.contains(&"Subject: Test".to_string()));
+    assert!(transcript.contains(&"..hidden".to_string()), "{:?}", transcript);
+    assert_eq!(transcript.last().unwrap(), "QUIT");
+}
+
+#[tokio::test]
+async fn test_smtp_transport_rejects_line_breaks() {
+    use crate::events::{EmailMessage, EmailTransport, SmtpConfig, SmtpTransport};
+
+    // Nothing listens on this port, the message must be rejected before connecting
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    drop(listener);
+
+    let message = EmailMessage {
+        from: "flextide@example.com".to_string(),
+        to: vec!["a@example.com".to_string()],
+        subject: "Test".to_string(),
+        body: "Hello".to_string(),
+    };
+    let config = SmtpConfig { host: "127.0.0.1".to_string(), port, helo_name: "flextide".to_string() };
+
+    let injected_from = EmailMessage { from: "a@example.com>\r\nRCPT TO:<victim@example.com".to_string(), ..message.clone() };
+    let error = SmtpTransport::new(config.clone()).send(&injected_from).await.unwrap_err();
+    assert!(error.to_string().contains("sender"), "{}", error);
+
+    let injected_to = EmailMessage { to: vec!["a@example.com\nDATA".to_string()], ..message.clone() };
+    let error = SmtpTransport::new(config.clone()).send(&injected_to).await.unwrap_err();
+    assert!(error.to_string().contains("recipient"), "{}", error);
+
+    let injected_helo = SmtpConfig { helo_name: "flextide\r\nQUIT".to_string(), ..config };
+    let error = SmtpTransport::new(injected_helo).send(&message).await.unwrap_err();
+    assert!(error.to_string().contains("HELO"), "{}", error);
+}
@@ -73,6 +73,27 @@ impl EventPayload {
     }
 }
 
+/// Describe the fields that differ between two states of an entity
+///
+/// Both states are JSON objects of field values. Returns an object with an
+/// `{"old": ..., "new": ...}` entry for every field whose value changed; a field
+/// missing from one state counts as `null`. Used as `changes` in update event payloads.
+pub fn field_changes(before: &JsonValue, after: &JsonValue) -> JsonValue {
+    let empty = serde_json::Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+
+    let mut changes = serde_json::Map::new();
+    for field in before.keys().chain(after.keys().filter(|field| !before.contains_key(*field))) {
+        let old = before.get(field).unwrap_or(&JsonValue::Null);
+        let new = after.get(field).unwrap_or(&JsonValue::Null);
+        if old != new {
+            changes.insert(field.clone(), serde_json::json!({ "old": old, "new": new }));
+        }
+    }
+    JsonValue::Object(changes)
+}
+
 impl From<JsonValue> for EventPayload {
     fn from(value: JsonValue) -> Self {
         Self::new(value)
@@ -0,0 +1,320 @@
+//! Webhook delivery history
+//!
+//! Every attempt to deliver an event to a webhook is recorded, so operators can see
+//! which deliveries failed and why. Failed attempts are retried with a linear backoff.
+
+use crate::database::DatabasePool;
+use crate::events::types::Event;
+use crate::events::webhooks::{post_webhook, Webhook};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::Row;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// Retry behavior of webhook deliveries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookRetryPolicy {
+    /// Number of attempts including the first one
+    pub max_attempts: u32,
+    /// Delay before the second attempt, every further attempt waits one delay longer
+    pub base_delay: Duration,
+}
+
+impl Default for WebhookRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+/// A single recorded webhook delivery attempt
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub webhook_id: String,
+    pub organization_uuid: String,
+    pub event_name: String,
+    /// 1 for the first attempt of a delivery, counting up for retries
+    pub attempt: u32,
+    /// HTTP status code, `None` if no response was received
+    pub status_code: Option<u16>,
+    pub success: bool,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Outcome of a single delivery attempt, before it is recorded
+struct DeliveryAttempt {
+    status_code: Option<u16>,
+    success: bool,
+    latency_ms: u64,
+    error: Option<String>,
+    retryable: bool,
+}
+
+/// Deliver an event to a webhook, retrying failed attempts
+///
+/// Every attempt is recorded in `webhook_deliveries` if a pool is given. Failing to
+/// record an attempt is only logged, it does not affect the delivery. Client errors
+/// (4xx responses except 408 and 429) are not retried since they will fail again.
+///
+/// # Arguments
+/// * `pool` - Database to record the attempts in, `None` to not record them
+/// * `webhook` - Webhook to deliver to
+/// * `event` - Event to deliver
+/// * `policy` - Number of attempts and delay between them
+///
+/// # Errors
+/// Returns the error of the last attempt if no attempt succeeded
+pub async fn deliver_webhook(
+    pool: Option<&DatabasePool>,
+    webhook: &Webhook,
+    event: &Event,
+    policy: WebhookRetryPolicy,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let max_attempts = policy.max_attempts.max(1);
+    let mut last_error = String::new();
+
+    for attempt in 1..=max_attempts {
+        if attempt > 1 {
+            tokio::time::sleep(policy.base_delay * (attempt - 1)).await;
+        }
+
+        let outcome = attempt_delivery(webhook, event).await;
+        if let Some(pool) = pool
+            && let Err(e) = record_webhook_delivery(pool, webhook, &event.name, attempt, &outcome).await
+        {
+            warn!("Failed to record delivery of webhook {}: {}", webhook.id, e);
+        }
+
+        if outcome.success {
+            debug!("Webhook delivered: {} (attempt {})", webhook.url, attempt);
+            return Ok(());
+        }
+
+        last_error = outcome.error.unwrap_or_default();
+        warn!(
+            "Webhook delivery failed: {} (attempt {}/{}, error: {})",
+            webhook.url, attempt, max_attempts, last_error
+        );
+        if !outcome.retryable {
+            break;
+        }
+    }
+
+    Err(last_error.into())
+}
+
+async fn attempt_delivery(webhook: &Webhook, event: &Event) -> DeliveryAttempt {
+    let started = Instant::now();
+    let result = post_webhook(webhook, event).await;
+    let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    match result {
+        Ok(response) if response.status.is_success() => DeliveryAttempt {
+            status_code: Some(response.status.as_u16()),
+            success: true,
+            latency_ms,
+            error: None,
+            retryable: false,
+        },
+        Ok(response) => {
+            let status = response.status;
+            DeliveryAttempt {
+                status_code: Some(status.as_u16()),
+                success: false,
+                latency_ms,
+                error: Some(format!("Webhook delivery failed with status {}: {}", status, response.error_text)),
+                retryable: !status.is_client_error()
+                    || status == reqwest::StatusCode::REQUEST_TIMEOUT
+                    || status == reqwest::StatusCode::TOO_MANY_REQUESTS,
+            }
+        }
+        Err(e) => DeliveryAttempt {
+            status_code: None,
+            success: false,
+            latency_ms,
+            error: Some(e.to_string()),
+            retryable: true,
+        },
+    }
+}
+
+/// Record a delivery attempt
+async fn record_webhook_delivery(
+    pool: &DatabasePool,
+    webhook: &Webhook,
+    event_name: &str,
+    attempt: u32,
+    outcome: &DeliveryAttempt,
+) -> Result<String, sqlx::Error> {
+    let delivery_id = uuid::Uuid::new_v4().to_string();
+    let now = Utc::now();
+    let attempt = i32::try_from(attempt).unwrap_or(i32::MAX);
+    let status_code = outcome.status_code.map(i32::from);
+    let success = i32::from(outcome.success);
+    let latency_ms = i64::try_from(outcome.latency_ms).unwrap_or(i64::MAX);
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query(
+                "INSERT INTO webhook_deliveries
+                 (id, webhook_id, organization_uuid, event_name, attempt, status_code, success, latency_ms, error, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&delivery_id)
+            .bind(&webhook.id)
+            .bind(&webhook.organization_uuid)
+            .bind(event_name)
+            .bind(attempt)
+            .bind(status_code)
+            .bind(success)
+            .bind(latency_ms)
+            .bind(&outcome.error)
+            .bind(now)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query(
+                "INSERT INTO webhook_deliveries
+                 (id, webhook_id, organization_uuid, event_name, attempt, status_code, success, latency_ms, error, created_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+            )
+            .bind(&delivery_id)
+            .bind(&webhook.id)
+            .bind(&webhook.organization_uuid)
+            .bind(event_name)
+            .bind(attempt)
+            .bind(status_code)
+            .bind(success)
+            .bind(latency_ms)
+            .bind(&outcome.error)
+            .bind(now)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query(
+                "INSERT INTO webhook_deliveries
+                 (id, webhook_id, organization_uuid, event_name, attempt, status_code, success, latency_ms, error, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            )
+            .bind(&delivery_id)
+            .bind(&webhook.id)
+            .bind(&webhook.organization_uuid)
+            .bind(event_name)
+            .bind(attempt)
+            .bind(status_code)
+            .bind(success)
+            .bind(latency_ms)
+            .bind(&outcome.error)
+            .bind(now)
+            .execute(p)
+            .await?;
+        }
+    }
+
+    Ok(delivery_id)
+}
+
+/// List the most recent delivery attempts of a webhook, newest first
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `webhook_id` - Webhook to list the deliveries of
+/// * `limit` - Maximum number of attempts to return
+///
+/// # Errors
+/// Returns `sqlx::Error` if the database query fails
+pub async fn list_webhook_deliveries(
+    pool: &DatabasePool,
+    webhook_id: &str,
+    limit: u32,
+) -> Result<Vec<WebhookDelivery>, sqlx::Error> {
+    let limit = i64::from(limit);
+
+    let rows: Vec<WebhookDelivery> = match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query(
+                "SELECT id, webhook_id, organization_uuid, event_name, attempt, status_code, success, latency_ms, error, created_at
+                 FROM webhook_deliveries
+                 WHERE webhook_id = ?
+                 ORDER BY created_at DESC, attempt DESC
+                 LIMIT ?",
+            )
+            .bind(webhook_id)
+            .bind(limit)
+            .fetch_all(p)
+            .await?
+            .iter()
+            .map(delivery_from_row)
+            .collect()
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query(
+                "SELECT id, webhook_id, organization_uuid, event_name, attempt, status_code, success, latency_ms, error, created_at
+                 FROM webhook_deliveries
+                 WHERE webhook_id = $1
+                 ORDER BY created_at DESC, attempt DESC
+                 LIMIT $2",
+            )
+            .bind(webhook_id)
+            .bind(limit)
+            .fetch_all(p)
+            .await?
+            .iter()
+            .map(delivery_from_row)
+            .collect()
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query(
+                "SELECT id, webhook_id, organization_uuid, event_name, attempt, status_code, success, latency_ms, error, created_at
+                 FROM webhook_deliveries
+                 WHERE webhook_id = ?1
+                 ORDER BY created_at DESC, attempt DESC
+                 LIMIT ?2",
+            )
+            .bind(webhook_id)
+            .bind(limit)
+            .fetch_all(p)
+            .await?
+            .iter()
+            .map(delivery_from_row)
+            .collect()
+        }
+    };
+
+    Ok(rows)
+}
+
+fn delivery_from_row<R>(row: &R) -> WebhookDelivery
+where
+    R: Row,
+    for<'r> &'r str: sqlx::ColumnIndex<R>,
+    for<'r> String: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> Option<String>: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> i32: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> Option<i32>: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> i64: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+    for<'r> DateTime<Utc>: sqlx::Decode<'r, R::Database> + sqlx::Type<R::Database>,
+{
+    WebhookDelivery {
+        id: row.get("id"),
+        webhook_id: row.get("webhook_id"),
+        organization_uuid: row.get("organization_uuid"),
+        event_name: row.get("event_name"),
+        attempt: u32::try_from(row.get::<i32, _>("attempt")).unwrap_or_default(),
+        status_code: row
+            .get::<Option<i32>, _>("status_code")
+            .and_then(|code| u16::try_from(code).ok()),
+        success: row.get::<i32, _>("success") != 0,
+        latency_ms: u64::try_from(row.get::<i64, _>("latency_ms")).unwrap_or_default(),
+        error: row.get("error"),
+        created_at: row.get("created_at"),
+    }
+}
@@ -12,10 +12,52 @@ use serde_json::Value as JsonValue;
 use sha2::Sha256;
 use sqlx::Row;
 use std::time::Duration;
+use thiserror::Error;
 use tracing::{debug, warn};
 
 type HmacSha256 = Hmac<Sha256>;
 
+/// Placeholders of webhook payload templates, besides `{payload}` and `{payload.<field>}`
+const TEMPLATE_PLACEHOLDERS: [&str; 5] = ["event", "timestamp", "organization_uuid", "user_uuid", "webhook_id"];
+
+/// Error type for invalid webhook payload templates
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum WebhookTemplateError {
+    #[error("Unknown placeholder in payload template: {{{0}}}")]
+    UnknownPlaceholder(String),
+}
+
+/// Encoding of the body of webhook requests
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WebhookContentType {
+    /// The payload as JSON document
+    #[default]
+    #[serde(rename = "application/json")]
+    Json,
+    /// The fields of the payload as form fields, see [`encode_webhook_body`]
+    #[serde(rename = "application/x-www-form-urlencoded")]
+    FormUrlEncoded,
+}
+
+impl WebhookContentType {
+    /// Get the MIME type, which is also the string stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookContentType::Json => "application/json",
+            WebhookContentType::FormUrlEncoded => "application/x-www-form-urlencoded",
+        }
+    }
+
+    /// Parse a MIME type
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "application/json" => Some(WebhookContentType::Json),
+            "application/x-www-form-urlencoded" => Some(WebhookContentType::FormUrlEncoded),
+            _ => None,
+        }
+    }
+}
+
 /// Webhook configuration stored in the database
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Webhook {
@@ -25,6 +67,12 @@ pub struct Webhook {
     pub url: String,
     pub secret: Option<String>,
     pub headers: Option<JsonValue>,
+    /// JSON template for the request body, see [`build_webhook_payload`]
+    #[serde(default)]
+    pub payload_template: Option<JsonValue>,
+    /// Encoding of the request body
+    #[serde(default)]
+    pub content_type: WebhookContentType,
     pub active: bool,
     pub created_by: String,
     pub created_at: DateTime<Utc>,
@@ -38,6 +86,12 @@ pub struct CreateWebhookRequest {
     pub url: String,
     pub secret: Option<String>,
     pub headers: Option<JsonValue>,
+    /// JSON template for the request body, the raw event is sent if unset
+    #[serde(default)]
+    pub payload_template: Option<JsonValue>,
+    /// Encoding of the request body, JSON if unset
+    #[serde(default)]
+    pub content_type: WebhookContentType,
 }
 
 /// Request structure for updating a webhook
@@ -47,6 +101,10 @@ pub struct UpdateWebhookRequest {
     pub url: Option<String>,
     pub secret: Option<String>,
     pub headers: Option<JsonValue>,
+    #[serde(default)]
+    pub payload_template: Option<JsonValue>,
+    #[serde(default)]
+    pub content_type: Option<WebhookContentType>,
     pub active: Option<bool>,
 }
 
@@ -55,7 +113,7 @@ pub async fn load_webhooks(pool: &DatabasePool) -> Result<Vec<Webhook>, sqlx::Er
     match pool {
         DatabasePool::MySql(p) => {
             let rows = sqlx::query(
-                "SELECT id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at
+                "SELECT id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at
                  FROM event_webhooks 
                  WHERE active = 1 
                  ORDER BY event_name, id"
@@ -67,6 +125,7 @@ pub async fn load_webhooks(pool: &DatabasePool) -> Result<Vec<Webhook>, sqlx::Er
                 .into_iter()
                 .filter_map(|row| {
                     let headers: Option<JsonValue> = row.try_get("headers").ok().flatten();
+                    let payload_template: Option<JsonValue> = row.try_get("payload_template").ok().flatten();
                     
                     Some(Webhook {
                         id: row.get("id"),
@@ -75,6 +134,13 @@ pub async fn load_webhooks(pool: &DatabasePool) -> Result<Vec<Webhook>, sqlx::Er
                         url: row.get("url"),
                         secret: row.try_get("secret").ok().flatten(),
                         headers,
+                        payload_template,
+                        content_type: row
+                            .try_get::<Option<String>, _>("content_type")
+                            .ok()
+                            .flatten()
+                            .and_then(|s| WebhookContentType::parse(&s))
+                            .unwrap_or_default(),
                         active: row.get("active"),
                         created_by: row.get("created_by"),
                         created_at: row.get("created_at"),
@@ -85,7 +151,7 @@ pub async fn load_webhooks(pool: &DatabasePool) -> Result<Vec<Webhook>, sqlx::Er
         }
         DatabasePool::Postgres(p) => {
             let rows = sqlx::query(
-                "SELECT id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at
+                "SELECT id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at
                  FROM event_webhooks 
                  WHERE active = true 
                  ORDER BY event_name, id"
@@ -97,6 +163,7 @@ pub async fn load_webhooks(pool: &DatabasePool) -> Result<Vec<Webhook>, sqlx::Er
                 .into_iter()
                 .filter_map(|row| {
                     let headers: Option<JsonValue> = row.try_get("headers").ok().flatten();
+                    let payload_template: Option<JsonValue> = row.try_get("payload_template").ok().flatten();
                     
                     Some(Webhook {
                         id: row.get("id"),
@@ -105,6 +172,13 @@ pub async fn load_webhooks(pool: &DatabasePool) -> Result<Vec<Webhook>, sqlx::Er
                         url: row.get("url"),
                         secret: row.try_get("secret").ok().flatten(),
                         headers,
+                        payload_template,
+                        content_type: row
+                            .try_get::<Option<String>, _>("content_type")
+                            .ok()
+                            .flatten()
+                            .and_then(|s| WebhookContentType::parse(&s))
+                            .unwrap_or_default(),
                         active: row.get("active"),
                         created_by: row.get("created_by"),
                         created_at: row.get("created_at"),
@@ -115,7 +189,7 @@ pub async fn load_webhooks(pool: &DatabasePool) -> Result<Vec<Webhook>, sqlx::Er
         }
         DatabasePool::Sqlite(p) => {
             let rows = sqlx::query(
-                "SELECT id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at
+                "SELECT id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at
                  FROM event_webhooks 
                  WHERE active = 1 
                  ORDER BY event_name, id"
@@ -128,6 +202,8 @@ pub async fn load_webhooks(pool: &DatabasePool) -> Result<Vec<Webhook>, sqlx::Er
                 .filter_map(|row| {
                     let headers_str: Option<String> = row.try_get("headers").ok().flatten();
                     let headers = headers_str.and_then(|s| serde_json::from_str(&s).ok());
+                    let payload_template_str: Option<String> = row.try_get("payload_template").ok().flatten();
+                    let payload_template = payload_template_str.and_then(|s| serde_json::from_str(&s).ok());
                     
                     Some(Webhook {
                         id: row.get("id"),
@@ -136,6 +212,13 @@ pub async fn load_webhooks(pool: &DatabasePool) -> Result<Vec<Webhook>, sqlx::Er
                         url: row.get("url"),
                         secret: row.try_get("secret").ok().flatten(),
                         headers,
+                        payload_template,
+                        content_type: row
+                            .try_get::<Option<String>, _>("content_type")
+                            .ok()
+                            .flatten()
+                            .and_then(|s| WebhookContentType::parse(&s))
+                            .unwrap_or_default(),
                         active: row.get("active"),
                         created_by: row.get("created_by"),
                         created_at: row.get("created_at"),
@@ -155,7 +238,7 @@ pub async fn load_webhooks_by_organization(
     match pool {
         DatabasePool::MySql(p) => {
             let rows = sqlx::query(
-                "SELECT id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at
+                "SELECT id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at
                  FROM event_webhooks 
                  WHERE organization_uuid = ? AND active = 1
                  ORDER BY event_name, created_at"
@@ -168,6 +251,7 @@ pub async fn load_webhooks_by_organization(
                 .into_iter()
                 .filter_map(|row| {
                     let headers: Option<JsonValue> = row.try_get("headers").ok().flatten();
+                    let payload_template: Option<JsonValue> = row.try_get("payload_template").ok().flatten();
                     
                     Some(Webhook {
                         id: row.get("id"),
@@ -176,6 +260,13 @@ pub async fn load_webhooks_by_organization(
                         url: row.get("url"),
                         secret: row.try_get("secret").ok().flatten(),
                         headers,
+                        payload_template,
+                        content_type: row
+                            .try_get::<Option<String>, _>("content_type")
+                            .ok()
+                            .flatten()
+                            .and_then(|s| WebhookContentType::parse(&s))
+                            .unwrap_or_default(),
                         active: row.get("active"),
                         created_by: row.get("created_by"),
                         created_at: row.get("created_at"),
@@ -186,7 +277,7 @@ pub async fn load_webhooks_by_organization(
         }
         DatabasePool::Postgres(p) => {
             let rows = sqlx::query(
-                "SELECT id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at
+                "SELECT id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at
                  FROM event_webhooks 
                  WHERE organization_uuid = $1 AND active = true
                  ORDER BY event_name, created_at"
@@ -199,6 +290,7 @@ pub async fn load_webhooks_by_organization(
                 .into_iter()
                 .filter_map(|row| {
                     let headers: Option<JsonValue> = row.try_get("headers").ok().flatten();
+                    let payload_template: Option<JsonValue> = row.try_get("payload_template").ok().flatten();
                     
                     Some(Webhook {
                         id: row.get("id"),
@@ -207,6 +299,13 @@ pub async fn load_webhooks_by_organization(
                         url: row.get("url"),
                         secret: row.try_get("secret").ok().flatten(),
                         headers,
+                        payload_template,
+                        content_type: row
+                            .try_get::<Option<String>, _>("content_type")
+                            .ok()
+                            .flatten()
+                            .and_then(|s| WebhookContentType::parse(&s))
+                            .unwrap_or_default(),
                         active: row.get("active"),
                         created_by: row.get("created_by"),
                         created_at: row.get("created_at"),
@@ -217,7 +316,7 @@ pub async fn load_webhooks_by_organization(
         }
         DatabasePool::Sqlite(p) => {
             let rows = sqlx::query(
-                "SELECT id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at
+                "SELECT id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at
                  FROM event_webhooks 
                  WHERE organization_uuid = ?1 AND active = 1
                  ORDER BY event_name, created_at"
@@ -231,6 +330,8 @@ pub async fn load_webhooks_by_organization(
                 .filter_map(|row| {
                     let headers_str: Option<String> = row.try_get("headers").ok().flatten();
                     let headers = headers_str.and_then(|s| serde_json::from_str(&s).ok());
+                    let payload_template_str: Option<String> = row.try_get("payload_template").ok().flatten();
+                    let payload_template = payload_template_str.and_then(|s| serde_json::from_str(&s).ok());
                     
                     Some(Webhook {
                         id: row.get("id"),
@@ -239,6 +340,13 @@ pub async fn load_webhooks_by_organization(
                         url: row.get("url"),
                         secret: row.try_get("secret").ok().flatten(),
                         headers,
+                        payload_template,
+                        content_type: row
+                            .try_get::<Option<String>, _>("content_type")
+                            .ok()
+                            .flatten()
+                            .and_then(|s| WebhookContentType::parse(&s))
+                            .unwrap_or_default(),
                         active: row.get("active"),
                         created_by: row.get("created_by"),
                         created_at: row.get("created_at"),
@@ -264,8 +372,8 @@ pub async fn create_webhook(
         DatabasePool::MySql(p) => {
             sqlx::query(
                 "INSERT INTO event_webhooks 
-                 (id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at) 
-                 VALUES (?, ?, ?, ?, ?, ?, 1, ?, ?, ?)"
+                 (id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at) 
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, 1, ?, ?, ?)"
             )
             .bind(&webhook_id)
             .bind(organization_uuid)
@@ -273,6 +381,8 @@ pub async fn create_webhook(
             .bind(&request.url)
             .bind(&request.secret)
             .bind(&request.headers)
+            .bind(&request.payload_template)
+            .bind(request.content_type.as_str())
             .bind(created_by)
             .bind(now)
             .bind(now)
@@ -282,8 +392,8 @@ pub async fn create_webhook(
         DatabasePool::Postgres(p) => {
             sqlx::query(
                 "INSERT INTO event_webhooks 
-                 (id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at) 
-                 VALUES ($1, $2, $3, $4, $5, $6, true, $7, $8, $9)"
+                 (id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at) 
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, true, $9, $10, $11)"
             )
             .bind(&webhook_id)
             .bind(organization_uuid)
@@ -291,6 +401,8 @@ pub async fn create_webhook(
             .bind(&request.url)
             .bind(&request.secret)
             .bind(&request.headers)
+            .bind(&request.payload_template)
+            .bind(request.content_type.as_str())
             .bind(created_by)
             .bind(now)
             .bind(now)
@@ -300,11 +412,13 @@ pub async fn create_webhook(
         DatabasePool::Sqlite(p) => {
             let headers_json = request.headers.as_ref()
                 .map(|h| serde_json::to_string(h).unwrap_or_default());
+            let payload_template_json = request.payload_template.as_ref()
+                .map(|t| serde_json::to_string(t).unwrap_or_default());
             
             sqlx::query(
                 "INSERT INTO event_webhooks 
-                 (id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at) 
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, 1, ?7, ?8, ?9)"
+                 (id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at) 
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 1, ?9, ?10, ?11)"
             )
             .bind(&webhook_id)
             .bind(organization_uuid)
@@ -312,6 +426,8 @@ pub async fn create_webhook(
             .bind(&request.url)
             .bind(&request.secret)
             .bind(&headers_json)
+            .bind(&payload_template_json)
+            .bind(request.content_type.as_str())
             .bind(created_by)
             .bind(now)
             .bind(now)
@@ -352,6 +468,14 @@ pub async fn update_webhook(
         updates.push(format!("headers = ${}", bind_index));
         bind_index += 1;
     }
+    if request.payload_template.is_some() {
+        updates.push(format!("payload_template = ${}", bind_index));
+        bind_index += 1;
+    }
+    if request.content_type.is_some() {
+        updates.push(format!("content_type = ${}", bind_index));
+        bind_index += 1;
+    }
     if request.active.is_some() {
         updates.push(format!("active = ${}", bind_index));
         bind_index += 1;
@@ -394,6 +518,12 @@ pub async fn update_webhook(
             if let Some(headers) = &request.headers {
                 query_builder = query_builder.bind(headers);
             }
+            if let Some(payload_template) = &request.payload_template {
+                query_builder = query_builder.bind(payload_template);
+            }
+            if let Some(content_type) = request.content_type {
+                query_builder = query_builder.bind(content_type.as_str());
+            }
             if let Some(active) = request.active {
                 query_builder = query_builder.bind(active);
             }
@@ -423,6 +553,12 @@ pub async fn update_webhook(
             if let Some(headers) = &request.headers {
                 query_builder = query_builder.bind(headers);
             }
+            if let Some(payload_template) = &request.payload_template {
+                query_builder = query_builder.bind(payload_template);
+            }
+            if let Some(content_type) = request.content_type {
+                query_builder = query_builder.bind(content_type.as_str());
+            }
             if let Some(active) = request.active {
                 query_builder = query_builder.bind(active);
             }
@@ -454,6 +590,13 @@ pub async fn update_webhook(
                 let headers_json = serde_json::to_string(headers).unwrap_or_default();
                 query_builder = query_builder.bind(headers_json);
             }
+            if let Some(payload_template) = &request.payload_template {
+                let payload_template_json = serde_json::to_string(payload_template).unwrap_or_default();
+                query_builder = query_builder.bind(payload_template_json);
+            }
+            if let Some(content_type) = request.content_type {
+                query_builder = query_builder.bind(content_type.as_str());
+            }
             if let Some(active) = request.active {
                 query_builder = query_builder.bind(active);
             }
@@ -510,7 +653,7 @@ pub async fn get_webhook(
     match pool {
         DatabasePool::MySql(p) => {
             let row = sqlx::query(
-                "SELECT id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at
+                "SELECT id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at
                  FROM event_webhooks 
                  WHERE id = ? AND organization_uuid = ?"
             )
@@ -521,6 +664,7 @@ pub async fn get_webhook(
 
             Ok(row.and_then(|row| {
                 let headers: Option<JsonValue> = row.try_get("headers").ok().flatten();
+                let payload_template: Option<JsonValue> = row.try_get("payload_template").ok().flatten();
                 
                 Some(Webhook {
                     id: row.get("id"),
@@ -529,6 +673,13 @@ pub async fn get_webhook(
                     url: row.get("url"),
                     secret: row.try_get("secret").ok().flatten(),
                     headers,
+                    payload_template,
+                    content_type: row
+                        .try_get::<Option<String>, _>("content_type")
+                        .ok()
+                        .flatten()
+                        .and_then(|s| WebhookContentType::parse(&s))
+                        .unwrap_or_default(),
                     active: row.get("active"),
                     created_by: row.get("created_by"),
                     created_at: row.get("created_at"),
@@ -538,7 +689,7 @@ pub async fn get_webhook(
         }
         DatabasePool::Postgres(p) => {
             let row = sqlx::query(
-                "SELECT id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at
+                "SELECT id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at
                  FROM event_webhooks 
                  WHERE id = $1 AND organization_uuid = $2"
             )
@@ -549,6 +700,7 @@ pub async fn get_webhook(
 
             Ok(row.and_then(|row| {
                 let headers: Option<JsonValue> = row.try_get("headers").ok().flatten();
+                let payload_template: Option<JsonValue> = row.try_get("payload_template").ok().flatten();
                 
                 Some(Webhook {
                     id: row.get("id"),
@@ -557,6 +709,13 @@ pub async fn get_webhook(
                     url: row.get("url"),
                     secret: row.try_get("secret").ok().flatten(),
                     headers,
+                    payload_template,
+                    content_type: row
+                        .try_get::<Option<String>, _>("content_type")
+                        .ok()
+                        .flatten()
+                        .and_then(|s| WebhookContentType::parse(&s))
+                        .unwrap_or_default(),
                     active: row.get("active"),
                     created_by: row.get("created_by"),
                     created_at: row.get("created_at"),
@@ -566,7 +725,7 @@ pub async fn get_webhook(
         }
         DatabasePool::Sqlite(p) => {
             let row = sqlx::query(
-                "SELECT id, organization_uuid, event_name, url, secret, headers, active, created_by, created_at, updated_at
+                "SELECT id, organization_uuid, event_name, url, secret, headers, payload_template, content_type, active, created_by, created_at, updated_at
                  FROM event_webhooks 
                  WHERE id = ?1 AND organization_uuid = ?2"
             )
@@ -578,6 +737,8 @@ pub async fn get_webhook(
             Ok(row.and_then(|row| {
                 let headers_str: Option<String> = row.try_get("headers").ok().flatten();
                 let headers = headers_str.and_then(|s| serde_json::from_str(&s).ok());
+                let payload_template_str: Option<String> = row.try_get("payload_template").ok().flatten();
+                let payload_template = payload_template_str.and_then(|s| serde_json::from_str(&s).ok());
                 
                 Some(Webhook {
                     id: row.get("id"),
@@ -586,6 +747,13 @@ pub async fn get_webhook(
                     url: row.get("url"),
                     secret: row.try_get("secret").ok().flatten(),
                     headers,
+                    payload_template,
+                    content_type: row
+                        .try_get::<Option<String>, _>("content_type")
+                        .ok()
+                        .flatten()
+                        .and_then(|s| WebhookContentType::parse(&s))
+                        .unwrap_or_default(),
                     active: row.get("active"),
                     created_by: row.get("created_by"),
                     created_at: row.get("created_at"),
@@ -596,35 +764,226 @@ pub async fn get_webhook(
     }
 }
 
+/// Check that a webhook payload template only uses known placeholders
+///
+/// Templates are JSON values whose strings may contain the placeholders `{event}`,
+/// `{timestamp}`, `{organization_uuid}`, `{user_uuid}`, `{webhook_id}`, `{payload}` and
+/// `{payload.<field>}` (nested fields separated by dots, e.g. `{payload.customer.email}`).
+/// Payload fields cannot be checked up front since every event has its own payload.
+///
+/// # Errors
+/// Returns `WebhookTemplateError::UnknownPlaceholder` for the first placeholder that is not known
+pub fn validate_payload_template(template: &JsonValue) -> Result<(), WebhookTemplateError> {
+    match template {
+        JsonValue::String(text) => {
+            for placeholder in find_placeholders(text) {
+                if !is_known_placeholder(placeholder) {
+                    return Err(WebhookTemplateError::UnknownPlaceholder(placeholder.to_string()));
+                }
+            }
+            Ok(())
+        }
+        JsonValue::Array(items) => items.iter().try_for_each(validate_payload_template),
+        JsonValue::Object(fields) => fields.values().try_for_each(validate_payload_template),
+        _ => Ok(()),
+    }
+}
+
+/// Build the request body of a webhook delivery
+///
+/// Without a payload template the raw event is sent. With a template, every string that
+/// consists of a single placeholder is replaced by the referenced JSON value (keeping
+/// numbers, objects etc. intact, missing payload fields become `null`), placeholders
+/// inside longer strings are replaced by their text. See [`validate_payload_template`]
+/// for the available placeholders.
+pub fn build_webhook_payload(webhook: &Webhook, event: &Event) -> JsonValue {
+    match &webhook.payload_template {
+        Some(template) => render_payload_template(template, webhook, event),
+        None => serde_json::json!({
+            "event": {
+                "name": event.name,
+                "timestamp": event.timestamp.to_rfc3339(),
+                "organization_uuid": event.organization_uuid,
+                "user_uuid": event.user_uuid,
+                "payload": event.payload.data
+            },
+            "webhook_id": webhook.id
+        }),
+    }
+}
+
+fn render_payload_template(template: &JsonValue, webhook: &Webhook, event: &Event) -> JsonValue {
+    match template {
+        JsonValue::String(text) => render_template_string(text, webhook, event),
+        JsonValue::Array(items) => items
+            .iter()
+            .map(|item| render_payload_template(item, webhook, event))
+            .collect(),
+        JsonValue::Object(fields) => fields
+            .iter()
+            .map(|(key, value)| (key.clone(), render_payload_template(value, webhook, event)))
+            .collect(),
+        value => value.clone(),
+    }
+}
+
+/// Render a template string, unknown placeholders are kept as they are
+fn render_template_string(text: &str, webhook: &Webhook, event: &Event) -> JsonValue {
+    // A string that is just one placeholder takes the type of the referenced value
+    if let Some(placeholder) = text.strip_prefix('{').and_then(|rest| rest.strip_suffix('}'))
+        && !placeholder.contains(['{', '}'])
+        && let Some(value) = placeholder_value(placeholder, webhook, event)
+    {
+        return value;
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        match placeholder_value(&rest[start + 1..start + end], webhook, event) {
+            Some(JsonValue::String(value)) => result.push_str(&value),
+            Some(JsonValue::Null) => {}
+            Some(value) => result.push_str(&value.to_string()),
+            None => result.push_str(&rest[start..=start + end]),
+        }
+        rest = &rest[start + end + 1..];
+    }
+
+    result.push_str(rest);
+    JsonValue::String(result)
+}
+
+/// Names of all `{...}` placeholders in a template string
+fn find_placeholders(text: &str) -> Vec<&str> {
+    let mut placeholders = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        placeholders.push(&rest[start + 1..start + end]);
+        rest = &rest[start + end + 1..];
+    }
+    placeholders
+}
+
+fn is_known_placeholder(placeholder: &str) -> bool {
+    if TEMPLATE_PLACEHOLDERS.contains(&placeholder) || placeholder == "payload" {
+        return true;
+    }
+    placeholder
+        .strip_prefix("payload.")
+        .is_some_and(|path| path.split('.').all(|segment| !segment.is_empty()))
+}
+
+fn placeholder_value(placeholder: &str, webhook: &Webhook, event: &Event) -> Option<JsonValue> {
+    if !is_known_placeholder(placeholder) {
+        return None;
+    }
+
+    let optional = |value: &Option<String>| value.clone().map(JsonValue::String).unwrap_or(JsonValue::Null);
+    let value = match placeholder {
+        "event" => JsonValue::String(event.name.clone()),
+        "timestamp" => JsonValue::String(event.timestamp.to_rfc3339()),
+        "organization_uuid" => optional(&event.organization_uuid),
+        "user_uuid" => optional(&event.user_uuid),
+        "webhook_id" => JsonValue::String(webhook.id.clone()),
+        "payload" => event.payload.data.clone(),
+        _ => {
+            let path = placeholder.strip_prefix("payload.")?;
+            let pointer: String = path
+                .split('.')
+                .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+                .collect();
+            event.payload.data.pointer(&pointer).cloned().unwrap_or(JsonValue::Null)
+        }
+    };
+    Some(value)
+}
+
+/// Encode a webhook payload as request body
+///
+/// Form bodies contain one field per top-level field of the payload: strings as they are,
+/// `null` as empty value and numbers, booleans, objects and arrays as JSON. A payload
+/// that is not a JSON object is sent as a single `payload` field.
+///
+/// # Errors
+/// Returns an error if the payload cannot be serialized
+pub fn encode_webhook_body(
+    content_type: WebhookContentType,
+    payload: &JsonValue,
+) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    match content_type {
+        WebhookContentType::Json => Ok(serde_json::to_string(payload)?),
+        WebhookContentType::FormUrlEncoded => {
+            let form_value = |value: &JsonValue| match value {
+                JsonValue::String(s) => s.clone(),
+                JsonValue::Null => String::new(),
+                value => value.to_string(),
+            };
+            let fields: Vec<(String, String)> = match payload {
+                JsonValue::Object(fields) => fields
+                    .iter()
+                    .map(|(key, value)| (key.clone(), form_value(value)))
+                    .collect(),
+                value => vec![("payload".to_string(), form_value(value))],
+            };
+            Ok(serde_urlencoded::to_string(fields)?)
+        }
+    }
+}
+
+/// Response of a webhook endpoint
+pub(crate) struct WebhookResponse {
+    pub status: reqwest::StatusCode,
+    /// Response body, only read for unsuccessful responses
+    pub error_text: String,
+}
+
 /// Send a webhook HTTP POST request
 ///
 /// This function sends the event data to the webhook URL with optional
-/// HMAC signature for verification.
+/// HMAC signature for verification. The body is built by [`build_webhook_payload`],
+/// the signature covers the body as sent.
 pub async fn send_webhook(webhook: &Webhook, event: &Event) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let response = post_webhook(webhook, event).await?;
+    let status = response.status;
+
+    if status.is_success() {
+        debug!("Webhook delivered successfully: {} (status: {})", webhook.url, status);
+        Ok(())
+    } else {
+        warn!(
+            "Webhook delivery failed: {} (status: {}, error: {})",
+            webhook.url, status, response.error_text
+        );
+        Err(format!("Webhook delivery failed with status {}: {}", status, response.error_text).into())
+    }
+}
+
+/// Post an event to a webhook and return the response, whatever its status
+pub(crate) async fn post_webhook(
+    webhook: &Webhook,
+    event: &Event,
+) -> Result<WebhookResponse, Box<dyn std::error::Error + Send + Sync>> {
     let client = Client::builder()
         .timeout(Duration::from_secs(30))
         .build()?;
 
-    // Build webhook payload
-    let payload = serde_json::json!({
-        "event": {
-            "name": event.name,
-            "timestamp": event.timestamp.to_rfc3339(),
-            "organization_uuid": event.organization_uuid,
-            "user_uuid": event.user_uuid,
-            "payload": event.payload.data
-        },
-        "webhook_id": webhook.id
-    });
+    let payload = build_webhook_payload(webhook, event);
 
-    let payload_json = serde_json::to_string(&payload)?;
+    let body = encode_webhook_body(webhook.content_type, &payload)?;
 
     // Build request
     let mut request = client
         .post(&webhook.url)
-        .header("Content-Type", "application/json")
+        .header("Content-Type", webhook.content_type.as_str())
         .header("User-Agent", "Flextide-Webhook/1.0")
-        .body(payload_json.clone());
+        .body(body.clone());
 
     // Add custom headers if provided
     if let Some(headers) = &webhook.headers {
@@ -641,7 +1000,7 @@ pub async fn send_webhook(webhook: &Webhook, event: &Event) -> Result<(), Box<dy
     if let Some(secret) = &webhook.secret {
         let mut mac = HmacSha256::new_from_slice(secret.as_bytes())
             .map_err(|e| format!("Failed to create HMAC: {}", e))?;
-        mac.update(payload_json.as_bytes());
+        mac.update(body.as_bytes());
         let signature = hex::encode(mac.finalize().into_bytes());
         request = request.header("X-Webhook-Signature", format!("sha256={}", signature));
     }
@@ -651,17 +1010,12 @@ pub async fn send_webhook(webhook: &Webhook, event: &Event) -> Result<(), Box<dy
     
     let response = request.send().await?;
     let status = response.status();
-
-    if status.is_success() {
-        debug!("Webhook delivered successfully: {} (status: {})", webhook.url, status);
-        Ok(())
+    let error_text = if status.is_success() {
+        String::new()
     } else {
-        let error_text = response.text().await.unwrap_or_default();
-        warn!(
-            "Webhook delivery failed: {} (status: {}, error: {})",
-            webhook.url, status, error_text
-        );
-        Err(format!("Webhook delivery failed with status {}: {}", status, error_text).into())
-    }
+        response.text().await.unwrap_or_default()
+    };
+
+    Ok(WebhookResponse { status, error_text })
 }
 
@@ -21,3 +21,119 @@ pub struct Claims {
     pub is_server_admin: bool,
 }
 
+/// Default lifetime of issued tokens (24 hours)
+pub const DEFAULT_TOKEN_LIFETIME_SECONDS: i64 = 24 * 60 * 60;
+
+/// Longest accepted token lifetime (1 year), larger configured values are clamped to it
+pub const MAX_TOKEN_LIFETIME_SECONDS: i64 = 365 * 24 * 60 * 60;
+
+/// Default tolerated clock skew when validating `exp`
+pub const DEFAULT_LEEWAY_SECONDS: u64 = 60;
+
+/// Token lifetime and validation settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JwtConfig {
+    /// Seconds until an issued token expires
+    pub token_lifetime_seconds: i64,
+    /// Seconds a token is still accepted after its `exp`, to tolerate clock skew
+    pub leeway_seconds: u64,
+}
+
+impl Default for JwtConfig {
+    fn default() -> Self {
+        Self {
+            token_lifetime_seconds: DEFAULT_TOKEN_LIFETIME_SECONDS,
+            leeway_seconds: DEFAULT_LEEWAY_SECONDS,
+        }
+    }
+}
+
+impl JwtConfig {
+    /// Load the configuration from the `JWT_LIFETIME_SECONDS` and `JWT_LEEWAY_SECONDS`
+    /// environment variables
+    ///
+    /// Missing or invalid values fall back to the defaults. Lifetimes above
+    /// `MAX_TOKEN_LIFETIME_SECONDS` are clamped to it.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            token_lifetime_seconds: parse_env("JWT_LIFETIME_SECONDS")
+                .filter(|seconds| *seconds > 0)
+                .map(|seconds: i64| seconds.min(MAX_TOKEN_LIFETIME_SECONDS))
+                .unwrap_or(defaults.token_lifetime_seconds),
+            leeway_seconds: parse_env("JWT_LEEWAY_SECONDS").unwrap_or(defaults.leeway_seconds),
+        }
+    }
+
+    /// Expiration timestamp for a token issued at `issued_at`
+    ///
+    /// The lifetime is clamped to `0..=MAX_TOKEN_LIFETIME_SECONDS`.
+    pub fn expiration(&self, issued_at: chrono::DateTime<chrono::Utc>) -> usize {
+        let lifetime = chrono::Duration::seconds(self.token_lifetime_seconds.clamp(0, MAX_TOKEN_LIFETIME_SECONDS));
+        let expires_at = issued_at
+            .checked_add_signed(lifetime)
+            .unwrap_or(chrono::DateTime::<chrono::Utc>::MAX_UTC);
+        usize::try_from(expires_at.timestamp()).unwrap_or(0)
+    }
+
+    /// Whether a token with the given `exp` is expired at `now`, taking the leeway into account
+    pub fn is_expired(&self, exp: usize, now: usize) -> bool {
+        exp.saturating_add(self.leeway_seconds as usize) < now
+    }
+}
+
+fn parse_env<T: std::str::FromStr>(name: &str) -> Option<T> {
+    let value = std::env::var(name).ok()?;
+    match value.trim().parse() {
+        Ok(parsed) => Some(parsed),
+        Err(_) => {
+            tracing::warn!("Ignoring invalid value for {}: {}", name, value);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiration_uses_lifetime() {
+        let config = JwtConfig {
+            token_lifetime_seconds: 3600,
+            leeway_seconds: 0,
+        };
+        let now = chrono::Utc::now();
+        assert_eq!(config.expiration(now), now.timestamp() as usize + 3600);
+    }
+
+    #[test]
+    fn test_expiration_clamps_lifetime() {
+        let now = chrono::Utc::now();
+        let config = JwtConfig {
+            token_lifetime_seconds: i64::MAX,
+            leeway_seconds: 0,
+        };
+        assert_eq!(
+            config.expiration(now),
+            now.timestamp() as usize + MAX_TOKEN_LIFETIME_SECONDS as usize
+        );
+
+        let config = JwtConfig {
+            token_lifetime_seconds: -3600,
+            leeway_seconds: 0,
+        };
+        assert_eq!(config.expiration(now), now.timestamp() as usize);
+    }
+
+    #[test]
+    fn test_is_expired_with_leeway() {
+        let config = JwtConfig {
+            token_lifetime_seconds: 3600,
+            leeway_seconds: 60,
+        };
+        assert!(!config.is_expired(1_000, 1_000));
+        assert!(!config.is_expired(1_000, 1_060));
+        assert!(config.is_expired(1_000, 1_061));
+    }
+}
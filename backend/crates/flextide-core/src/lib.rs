@@ -7,6 +7,7 @@ pub mod credentials;
 pub mod database;
 pub mod events;
 pub mod jwt;
+pub mod pagination;
 pub mod permissions;
 pub mod queue;
 pub mod settings;
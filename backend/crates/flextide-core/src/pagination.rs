@@ -0,0 +1,246 @@
+//! Pagination helpers
+//!
+//! Shared clamping of `page` and `limit` query parameters, so all list endpoints
+//! behave the same for out-of-range input.
+//!
+//! The default and maximum page size of each resource type can be tuned through
+//! environment variables, see [`PaginationConfig::from_env`].
+
+use std::collections::HashMap;
+
+/// Largest page size any resource type can be configured to
+pub const MAX_PAGE_SIZE: u32 = 1000;
+
+/// Page and limit after clamping, with the resulting row offset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pagination {
+    /// Page number, 1-based
+    pub page: u32,
+    /// Number of items per page, between 1 and the maximum limit
+    pub limit: u32,
+    /// Number of items to skip
+    pub offset: u32,
+}
+
+/// Clamp the requested page and limit to valid values
+///
+/// - `page` is at least 1
+/// - `limit` is at least 1 and at most `max_limit`
+///
+/// # Arguments
+/// * `page` - Requested page number (1-based)
+/// * `limit` - Requested number of items per page
+/// * `max_limit` - Largest number of items per page the endpoint allows
+pub fn clamp_pagination(page: u32, limit: u32, max_limit: u32) -> Pagination {
+    let page = page.max(1);
+    let limit = limit.min(max_limit).max(1);
+
+    Pagination {
+        page,
+        limit,
+        offset: (page - 1).saturating_mul(limit),
+    }
+}
+
+/// Resource types served by paginated list endpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaginatedResource {
+    Executions,
+    Workflows,
+    Integrations,
+    Backups,
+    CrmCustomers,
+    AuthEvents,
+    WebhookDeliveries,
+}
+
+impl PaginatedResource {
+    /// All resource types
+    pub const ALL: [PaginatedResource; 7] = [
+        PaginatedResource::Executions,
+        PaginatedResource::Workflows,
+        PaginatedResource::Integrations,
+        PaginatedResource::Backups,
+        PaginatedResource::CrmCustomers,
+        PaginatedResource::AuthEvents,
+        PaginatedResource::WebhookDeliveries,
+    ];
+
+    /// Name used in the environment variables, e.g. `EXECUTIONS` in
+    /// `PAGINATION_EXECUTIONS_DEFAULT_LIMIT`
+    pub fn env_name(&self) -> &'static str {
+        match self {
+            PaginatedResource::Executions => "EXECUTIONS",
+            PaginatedResource::Workflows => "WORKFLOWS",
+            PaginatedResource::Integrations => "INTEGRATIONS",
+            PaginatedResource::Backups => "BACKUPS",
+            PaginatedResource::CrmCustomers => "CRM_CUSTOMERS",
+            PaginatedResource::AuthEvents => "AUTH_EVENTS",
+            PaginatedResource::WebhookDeliveries => "WEBHOOK_DELIVERIES",
+        }
+    }
+
+    /// Page size limits used if nothing is configured
+    pub fn fallback_limits(&self) -> PageSizeLimits {
+        match self {
+            PaginatedResource::Executions => PageSizeLimits { default: 30, max: 50 },
+            PaginatedResource::Workflows => PageSizeLimits { default: 30, max: 50 },
+            PaginatedResource::Integrations => PageSizeLimits { default: 30, max: 100 },
+            PaginatedResource::Backups => PageSizeLimits { default: 30, max: 100 },
+            PaginatedResource::CrmCustomers => PageSizeLimits { default: 50, max: 50 },
+            PaginatedResource::AuthEvents => PageSizeLimits { default: 50, max: 100 },
+            PaginatedResource::WebhookDeliveries => PageSizeLimits { default: 50, max: 200 },
+        }
+    }
+}
+
+/// Page size used if the client omits `limit`, and the largest page size allowed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageSizeLimits {
+    pub default: u32,
+    pub max: u32,
+}
+
+/// Page size limits of all resource types
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaginationConfig {
+    /// Configured limits, resource types without an entry use their fallback limits
+    overrides: HashMap<PaginatedResource, PageSizeLimits>,
+}
+
+impl PaginationConfig {
+    /// Load the configuration from the environment
+    ///
+    /// For every resource type, `PAGINATION_<RESOURCE>_DEFAULT_LIMIT` and
+    /// `PAGINATION_<RESOURCE>_MAX_LIMIT` (e.g. `PAGINATION_CRM_CUSTOMERS_MAX_LIMIT`) override
+    /// the fallback limits. Missing, zero or invalid values keep the fallback.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        for resource in PaginatedResource::ALL {
+            let fallback = resource.fallback_limits();
+            let default = parse_env_limit(&format!("PAGINATION_{}_DEFAULT_LIMIT", resource.env_name()));
+            let max = parse_env_limit(&format!("PAGINATION_{}_MAX_LIMIT", resource.env_name()));
+            if default.is_some() || max.is_some() {
+                config = config.with_limits(
+                    resource,
+                    PageSizeLimits {
+                        default: default.unwrap_or(fallback.default),
+                        max: max.unwrap_or(fallback.max),
+                    },
+                );
+            }
+        }
+        config
+    }
+
+    /// Override the limits of a resource type
+    ///
+    /// A maximum above [`MAX_PAGE_SIZE`] is lowered to it, a default above the maximum
+    /// is lowered to the maximum.
+    pub fn with_limits(mut self, resource: PaginatedResource, limits: PageSizeLimits) -> Self {
+        let max = limits.max.clamp(1, MAX_PAGE_SIZE);
+        self.overrides.insert(
+            resource,
+            PageSizeLimits {
+                default: limits.default.clamp(1, max),
+                max,
+            },
+        );
+        self
+    }
+
+    /// Limits of a resource type
+    pub fn limits(&self, resource: PaginatedResource) -> PageSizeLimits {
+        self.overrides
+            .get(&resource)
+            .copied()
+            .unwrap_or_else(|| resource.fallback_limits())
+    }
+
+    /// Clamp the requested page and limit of a resource type, using its default limit if
+    /// the client omitted `limit`
+    pub fn clamp(&self, resource: PaginatedResource, page: u32, limit: Option<u32>) -> Pagination {
+        let limits = self.limits(resource);
+        clamp_pagination(page, limit.unwrap_or(limits.default), limits.max)
+    }
+}
+
+fn parse_env_limit(name: &str) -> Option<u32> {
+    let value = std::env::var(name).ok()?;
+    match value.trim().parse::<u32>() {
+        Ok(limit) if limit > 0 => Some(limit),
+        _ => {
+            tracing::warn!("Ignoring invalid value for {}: {}", name, value);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_pagination_below_range() {
+        assert_eq!(
+            clamp_pagination(0, 0, 50),
+            Pagination { page: 1, limit: 1, offset: 0 }
+        );
+    }
+
+    #[test]
+    fn test_clamp_pagination_above_range() {
+        assert_eq!(
+            clamp_pagination(3, 500, 50),
+            Pagination { page: 3, limit: 50, offset: 100 }
+        );
+        assert_eq!(clamp_pagination(u32::MAX, 50, 50).offset, u32::MAX);
+    }
+
+    #[test]
+    fn test_clamp_pagination_at_boundaries() {
+        assert_eq!(
+            clamp_pagination(1, 1, 100),
+            Pagination { page: 1, limit: 1, offset: 0 }
+        );
+        assert_eq!(
+            clamp_pagination(2, 100, 100),
+            Pagination { page: 2, limit: 100, offset: 100 }
+        );
+        assert_eq!(clamp_pagination(2, 101, 100).limit, 100);
+    }
+
+    #[test]
+    fn test_config_uses_fallback_defaults() {
+        let config = PaginationConfig::default();
+        assert_eq!(config.clamp(PaginatedResource::Executions, 1, None).limit, 30);
+        assert_eq!(config.clamp(PaginatedResource::CrmCustomers, 1, None).limit, 50);
+        assert_eq!(config.clamp(PaginatedResource::Integrations, 1, Some(500)).limit, 100);
+    }
+
+    #[test]
+    fn test_config_applies_overrides() {
+        let config = PaginationConfig::default()
+            .with_limits(PaginatedResource::Workflows, PageSizeLimits { default: 5, max: 200 })
+            .with_limits(PaginatedResource::Backups, PageSizeLimits { default: 80, max: 20 });
+
+        assert_eq!(
+            config.clamp(PaginatedResource::Workflows, 2, None),
+            Pagination { page: 2, limit: 5, offset: 5 }
+        );
+        assert_eq!(config.clamp(PaginatedResource::Workflows, 1, Some(150)).limit, 150);
+        // A default above the maximum is lowered
+        assert_eq!(config.limits(PaginatedResource::Backups), PageSizeLimits { default: 20, max: 20 });
+        // Other resources keep their fallback
+        assert_eq!(config.limits(PaginatedResource::Executions), PaginatedResource::Executions.fallback_limits());
+    }
+
+    #[test]
+    fn test_config_caps_maximum_page_size() {
+        let config = PaginationConfig::default()
+            .with_limits(PaginatedResource::CrmCustomers, PageSizeLimits { default: 50, max: u32::MAX });
+
+        assert_eq!(config.limits(PaginatedResource::CrmCustomers).max, MAX_PAGE_SIZE);
+        assert_eq!(config.clamp(PaginatedResource::CrmCustomers, 1, Some(u32::MAX)).limit, MAX_PAGE_SIZE);
+    }
+}
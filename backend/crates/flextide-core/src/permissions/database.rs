@@ -2,9 +2,13 @@
 
 use crate::database::{DatabaseError, DatabasePool};
 use crate::permissions::{
-    CreatePermissionGroupRequest, CreatePermissionRequest, Permission, PermissionGroup, UserPermission,
+    CreatePermissionGroupRequest, CreatePermissionRequest, Permission, PermissionAuditAction,
+    PermissionAuditEntry, PermissionGroup, UserPermission,
 };
+use chrono::{DateTime, Utc};
+use std::collections::BTreeSet;
 use sqlx::Row;
+use uuid::Uuid;
 
 /// Error type for permission database operations
 #[derive(Debug, thiserror::Error)]
@@ -20,6 +24,15 @@ pub enum PermissionDatabaseError {
 
     #[error("Permission not found: {0}")]
     PermissionNotFound(String),
+
+    #[error("Unknown permission '{0}': only permissions defined in the permissions table can be granted")]
+    UnknownPermission(String),
+
+    #[error("Permission group {name} is still referenced by {permission_count} permission(s)")]
+    PermissionGroupInUse { name: String, permission_count: i64 },
+
+    #[error("Invalid permission audit action: {0}")]
+    InvalidAuditAction(String),
 }
 
 /// Create a new permission group
@@ -82,40 +95,269 @@ pub async fn create_permission_group(
 }
 
 /// Delete a permission group
+///
+/// Refuses to delete the group with `PermissionGroupInUse` while permissions still
+/// reference it. Use [`delete_permission_group_cascade`] to remove them as well.
 pub async fn delete_permission_group(
     pool: &DatabasePool,
     name: &str,
 ) -> Result<(), PermissionDatabaseError> {
     match pool {
         DatabasePool::MySql(p) => {
+            let mut tx = p.begin().await?;
+
+            let row = sqlx::query("SELECT COUNT(*) as count FROM permissions WHERE permission_group_name = ?")
+                .bind(name)
+                .fetch_one(&mut *tx)
+                .await?;
+            let permission_count: i64 = row.get("count");
+
+            if permission_count > 0 {
+                return Err(PermissionDatabaseError::PermissionGroupInUse {
+                    name: name.to_string(),
+                    permission_count,
+                });
+            }
+
             let result = sqlx::query("DELETE FROM permission_groups WHERE name = ?")
                 .bind(name)
-                .execute(p)
+                .execute(&mut *tx)
                 .await?;
             
             if result.rows_affected() == 0 {
                 return Err(PermissionDatabaseError::PermissionGroupNotFound(name.to_string()));
             }
+
+            tx.commit().await?;
         }
         DatabasePool::Postgres(p) => {
+            let mut tx = p.begin().await?;
+
+            let row = sqlx::query("SELECT COUNT(*) as count FROM permissions WHERE permission_group_name = $1")
+                .bind(name)
+                .fetch_one(&mut *tx)
+                .await?;
+            let permission_count: i64 = row.get("count");
+
+            if permission_count > 0 {
+                return Err(PermissionDatabaseError::PermissionGroupInUse {
+                    name: name.to_string(),
+                    permission_count,
+                });
+            }
+
             let result = sqlx::query("DELETE FROM permission_groups WHERE name = $1")
                 .bind(name)
-                .execute(p)
+                .execute(&mut *tx)
                 .await?;
             
             if result.rows_affected() == 0 {
                 return Err(PermissionDatabaseError::PermissionGroupNotFound(name.to_string()));
             }
+
+            tx.commit().await?;
         }
         DatabasePool::Sqlite(p) => {
+            let mut tx = p.begin().await?;
+
+            let row = sqlx::query("SELECT COUNT(*) as count FROM permissions WHERE permission_group_name = ?1")
+                .bind(name)
+                .fetch_one(&mut *tx)
+                .await?;
+            let permission_count: i64 = row.get("count");
+
+            if permission_count > 0 {
+                return Err(PermissionDatabaseError::PermissionGroupInUse {
+                    name: name.to_string(),
+                    permission_count,
+                });
+            }
+
             let result = sqlx::query("DELETE FROM permission_groups WHERE name = ?1")
                 .bind(name)
-                .execute(p)
+                .execute(&mut *tx)
                 .await?;
             
             if result.rows_affected() == 0 {
                 return Err(PermissionDatabaseError::PermissionGroupNotFound(name.to_string()));
             }
+
+            tx.commit().await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Delete a permission group together with all of its permissions
+///
+/// Also revokes the deleted permissions from all users, writing a `revoke` entry to the
+/// permission audit log for every removed grant in the same transaction.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `actor_user_id` - User deleting the group, recorded in the audit log
+/// * `name` - Name of the permission group
+///
+/// # Errors
+/// Returns `PermissionGroupNotFound` if the group does not exist
+pub async fn delete_permission_group_cascade(
+    pool: &DatabasePool,
+    actor_user_id: &str,
+    name: &str,
+) -> Result<(), PermissionDatabaseError> {
+    let now = Utc::now();
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            let mut tx = p.begin().await?;
+
+            let grants = sqlx::query(
+                "SELECT user_id, organization_uuid, permission_name FROM user_permissions WHERE permission_name IN
+                 (SELECT name FROM permissions WHERE permission_group_name = ?)",
+            )
+            .bind(name)
+            .fetch_all(&mut *tx)
+            .await?;
+
+            sqlx::query(
+                "DELETE FROM user_permissions WHERE permission_name IN
+                 (SELECT name FROM permissions WHERE permission_group_name = ?)",
+            )
+            .bind(name)
+            .execute(&mut *tx)
+            .await?;
+
+            for grant in grants {
+                sqlx::query(
+                    "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+                     VALUES (?, ?, ?, ?, ?, 'revoke', ?)",
+                )
+                .bind(Uuid::new_v4().to_string())
+                .bind(grant.get::<String, _>("organization_uuid"))
+                .bind(actor_user_id)
+                .bind(grant.get::<String, _>("user_id"))
+                .bind(grant.get::<String, _>("permission_name"))
+                .bind(now)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            sqlx::query("DELETE FROM permissions WHERE permission_group_name = ?")
+                .bind(name)
+                .execute(&mut *tx)
+                .await?;
+
+            let result = sqlx::query("DELETE FROM permission_groups WHERE name = ?")
+                .bind(name)
+                .execute(&mut *tx)
+                .await?;
+            
+            if result.rows_affected() == 0 {
+                return Err(PermissionDatabaseError::PermissionGroupNotFound(name.to_string()));
+            }
+
+            tx.commit().await?;
+        }
+        DatabasePool::Postgres(p) => {
+            let mut tx = p.begin().await?;
+
+            let grants = sqlx::query(
+                "SELECT user_id, organization_uuid, permission_name FROM user_permissions WHERE permission_name IN
+                 (SELECT name FROM permissions WHERE permission_group_name = $1)",
+            )
+            .bind(name)
+            .fetch_all(&mut *tx)
+            .await?;
+
+            sqlx::query(
+                "DELETE FROM user_permissions WHERE permission_name IN
+                 (SELECT name FROM permissions WHERE permission_group_name = $1)",
+            )
+            .bind(name)
+            .execute(&mut *tx)
+            .await?;
+
+            for grant in grants {
+                sqlx::query(
+                    "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+                     VALUES ($1, $2, $3, $4, $5, 'revoke', $6)",
+                )
+                .bind(Uuid::new_v4().to_string())
+                .bind(grant.get::<String, _>("organization_uuid"))
+                .bind(actor_user_id)
+                .bind(grant.get::<String, _>("user_id"))
+                .bind(grant.get::<String, _>("permission_name"))
+                .bind(now)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            sqlx::query("DELETE FROM permissions WHERE permission_group_name = $1")
+                .bind(name)
+                .execute(&mut *tx)
+                .await?;
+
+            let result = sqlx::query("DELETE FROM permission_groups WHERE name = $1")
+                .bind(name)
+                .execute(&mut *tx)
+                .await?;
+            
+            if result.rows_affected() == 0 {
+                return Err(PermissionDatabaseError::PermissionGroupNotFound(name.to_string()));
+            }
+
+            tx.commit().await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            let mut tx = p.begin().await?;
+
+            let grants = sqlx::query(
+                "SELECT user_id, organization_uuid, permission_name FROM user_permissions WHERE permission_name IN
+                 (SELECT name FROM permissions WHERE permission_group_name = ?1)",
+            )
+            .bind(name)
+            .fetch_all(&mut *tx)
+            .await?;
+
+            sqlx::query(
+                "DELETE FROM user_permissions WHERE permission_name IN
+                 (SELECT name FROM permissions WHERE permission_group_name = ?1)",
+            )
+            .bind(name)
+            .execute(&mut *tx)
+            .await?;
+
+            for grant in grants {
+                sqlx::query(
+                    "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, 'revoke', ?6)",
+                )
+                .bind(Uuid::new_v4().to_string())
+                .bind(grant.get::<String, _>("organization_uuid"))
+                .bind(actor_user_id)
+                .bind(grant.get::<String, _>("user_id"))
+                .bind(grant.get::<String, _>("permission_name"))
+                .bind(now)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            sqlx::query("DELETE FROM permissions WHERE permission_group_name = ?1")
+                .bind(name)
+                .execute(&mut *tx)
+                .await?;
+
+            let result = sqlx::query("DELETE FROM permission_groups WHERE name = ?1")
+                .bind(name)
+                .execute(&mut *tx)
+                .await?;
+            
+            if result.rows_affected() == 0 {
+                return Err(PermissionDatabaseError::PermissionGroupNotFound(name.to_string()));
+            }
+
+            tx.commit().await?;
         }
     }
 
@@ -464,8 +706,15 @@ pub async fn list_user_permissions(
 }
 
 /// Add a permission to a user for a specific organization
+///
+/// Writes a `grant` entry to the permission audit log if the user did not
+/// have the permission yet. Granting an already granted permission is a no-op.
+///
+/// # Errors
+/// Returns `UnknownPermission` if `permission_name` is not defined in the permissions table
 pub async fn add_user_permission(
     pool: &DatabasePool,
+    actor_user_id: &str,
     user_id: &str,
     organization_uuid: &str,
     permission_name: &str,
@@ -498,25 +747,51 @@ pub async fn add_user_permission(
         }
     };
 
+    // SQLite does not enforce the foreign key unless enabled, so check explicitly
     if !permission_exists {
-        return Err(PermissionDatabaseError::PermissionNotFound(permission_name.to_string()));
+        return Err(PermissionDatabaseError::UnknownPermission(permission_name.to_string()));
     }
 
+    let audit_uuid = Uuid::new_v4().to_string();
+    let now = Utc::now();
+
     match pool {
         DatabasePool::MySql(p) => {
-            sqlx::query(
-                "INSERT INTO user_permissions (user_id, organization_uuid, permission_name)
-                 VALUES (?, ?, ?)
-                 ON DUPLICATE KEY UPDATE permission_name = permission_name",
+            let mut tx = p.begin().await?;
+
+            // Not ON DUPLICATE KEY UPDATE: with CLIENT_FOUND_ROWS it reports an existing
+            // grant as affected, which would audit every repeated grant
+            let result = sqlx::query(
+                "INSERT IGNORE INTO user_permissions (user_id, organization_uuid, permission_name)
+                 VALUES (?, ?, ?)",
             )
             .bind(user_id)
             .bind(organization_uuid)
             .bind(permission_name)
-            .execute(p)
+            .execute(&mut *tx)
             .await?;
+
+            if result.rows_affected() > 0 {
+                sqlx::query(
+                    "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+                     VALUES (?, ?, ?, ?, ?, 'grant', ?)",
+                )
+                .bind(&audit_uuid)
+                .bind(organization_uuid)
+                .bind(actor_user_id)
+                .bind(user_id)
+                .bind(permission_name)
+                .bind(now)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            tx.commit().await?;
         }
         DatabasePool::Postgres(p) => {
-            sqlx::query(
+            let mut tx = p.begin().await?;
+
+            let result = sqlx::query(
                 "INSERT INTO user_permissions (user_id, organization_uuid, permission_name)
                  VALUES ($1, $2, $3)
                  ON CONFLICT (user_id, organization_uuid, permission_name) DO NOTHING",
@@ -524,19 +799,55 @@ pub async fn add_user_permission(
             .bind(user_id)
             .bind(organization_uuid)
             .bind(permission_name)
-            .execute(p)
+            .execute(&mut *tx)
             .await?;
+
+            if result.rows_affected() > 0 {
+                sqlx::query(
+                    "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+                     VALUES ($1, $2, $3, $4, $5, 'grant', $6)",
+                )
+                .bind(&audit_uuid)
+                .bind(organization_uuid)
+                .bind(actor_user_id)
+                .bind(user_id)
+                .bind(permission_name)
+                .bind(now)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            tx.commit().await?;
         }
         DatabasePool::Sqlite(p) => {
-            sqlx::query(
+            let mut tx = p.begin().await?;
+
+            let result = sqlx::query(
                 "INSERT OR IGNORE INTO user_permissions (user_id, organization_uuid, permission_name)
                  VALUES (?1, ?2, ?3)",
             )
             .bind(user_id)
             .bind(organization_uuid)
             .bind(permission_name)
-            .execute(p)
+            .execute(&mut *tx)
             .await?;
+
+            if result.rows_affected() > 0 {
+                sqlx::query(
+                    "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, 'grant', ?6)",
+                )
+                .bind(&audit_uuid)
+                .bind(organization_uuid)
+                .bind(actor_user_id)
+                .bind(user_id)
+                .bind(permission_name)
+                .bind(now)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            tx.commit().await?;
         }
     }
 
@@ -544,45 +855,109 @@ pub async fn add_user_permission(
 }
 
 /// Delete a specific permission from a user for a specific organization
+///
+/// Writes a `revoke` entry to the permission audit log if the user had the
+/// permission. Revoking a permission the user does not have is a no-op.
 pub async fn delete_user_permission(
     pool: &DatabasePool,
+    actor_user_id: &str,
     user_id: &str,
     organization_uuid: &str,
     permission_name: &str,
 ) -> Result<(), PermissionDatabaseError> {
+    let audit_uuid = Uuid::new_v4().to_string();
+    let now = Utc::now();
+
     match pool {
         DatabasePool::MySql(p) => {
-            sqlx::query(
+            let mut tx = p.begin().await?;
+
+            let result = sqlx::query(
                 "DELETE FROM user_permissions
                  WHERE user_id = ? AND organization_uuid = ? AND permission_name = ?",
             )
             .bind(user_id)
             .bind(organization_uuid)
             .bind(permission_name)
-            .execute(p)
+            .execute(&mut *tx)
             .await?;
+
+            if result.rows_affected() > 0 {
+                sqlx::query(
+                    "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+                     VALUES (?, ?, ?, ?, ?, 'revoke', ?)",
+                )
+                .bind(&audit_uuid)
+                .bind(organization_uuid)
+                .bind(actor_user_id)
+                .bind(user_id)
+                .bind(permission_name)
+                .bind(now)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            tx.commit().await?;
         }
         DatabasePool::Postgres(p) => {
-            sqlx::query(
+            let mut tx = p.begin().await?;
+
+            let result = sqlx::query(
                 "DELETE FROM user_permissions
                  WHERE user_id = $1 AND organization_uuid = $2 AND permission_name = $3",
             )
             .bind(user_id)
             .bind(organization_uuid)
             .bind(permission_name)
-            .execute(p)
+            .execute(&mut *tx)
             .await?;
+
+            if result.rows_affected() > 0 {
+                sqlx::query(
+                    "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+                     VALUES ($1, $2, $3, $4, $5, 'revoke', $6)",
+                )
+                .bind(&audit_uuid)
+                .bind(organization_uuid)
+                .bind(actor_user_id)
+                .bind(user_id)
+                .bind(permission_name)
+                .bind(now)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            tx.commit().await?;
         }
         DatabasePool::Sqlite(p) => {
-            sqlx::query(
+            let mut tx = p.begin().await?;
+
+            let result = sqlx::query(
                 "DELETE FROM user_permissions
                  WHERE user_id = ?1 AND organization_uuid = ?2 AND permission_name = ?3",
             )
             .bind(user_id)
             .bind(organization_uuid)
             .bind(permission_name)
-            .execute(p)
+            .execute(&mut *tx)
             .await?;
+
+            if result.rows_affected() > 0 {
+                sqlx::query(
+                    "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, 'revoke', ?6)",
+                )
+                .bind(&audit_uuid)
+                .bind(organization_uuid)
+                .bind(actor_user_id)
+                .bind(user_id)
+                .bind(permission_name)
+                .bind(now)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            tx.commit().await?;
         }
     }
 
@@ -590,44 +965,669 @@ pub async fn delete_user_permission(
 }
 
 /// Delete all permissions for a user in a specific organization
+///
+/// Writes one `revoke` entry to the permission audit log per removed permission.
 pub async fn delete_all_user_permissions(
     pool: &DatabasePool,
+    actor_user_id: &str,
     user_id: &str,
     organization_uuid: &str,
 ) -> Result<(), PermissionDatabaseError> {
     match pool {
         DatabasePool::MySql(p) => {
-            sqlx::query(
-                "DELETE FROM user_permissions
-                 WHERE user_id = ? AND organization_uuid = ?",
+            let mut tx = p.begin().await?;
+            revoke_all_user_permissions_mysql(&mut tx, actor_user_id, user_id, organization_uuid).await?;
+            tx.commit().await?;
+        }
+        DatabasePool::Postgres(p) => {
+            let mut tx = p.begin().await?;
+            revoke_all_user_permissions_postgres(&mut tx, actor_user_id, user_id, organization_uuid).await?;
+            tx.commit().await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            let mut tx = p.begin().await?;
+            revoke_all_user_permissions_sqlite(&mut tx, actor_user_id, user_id, organization_uuid).await?;
+            tx.commit().await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Delete all permissions of a user in an organization within an open MySQL transaction
+///
+/// Shared by [`delete_all_user_permissions`] and callers that revoke the permissions as
+/// part of a larger transaction, e.g. removing a member from an organization.
+pub(crate) async fn revoke_all_user_permissions_mysql(
+    conn: &mut sqlx::MySqlConnection,
+    actor_user_id: &str,
+    user_id: &str,
+    organization_uuid: &str,
+) -> Result<(), sqlx::Error> {
+    let now = Utc::now();
+
+    let permission_names: Vec<String> = sqlx::query_scalar(
+        "SELECT permission_name FROM user_permissions
+         WHERE user_id = ? AND organization_uuid = ?",
+    )
+    .bind(user_id)
+    .bind(organization_uuid)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    sqlx::query(
+        "DELETE FROM user_permissions
+         WHERE user_id = ? AND organization_uuid = ?",
+    )
+    .bind(user_id)
+    .bind(organization_uuid)
+    .execute(&mut *conn)
+    .await?;
+
+    for permission_name in &permission_names {
+        sqlx::query(
+            "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+             VALUES (?, ?, ?, ?, ?, 'revoke', ?)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(organization_uuid)
+        .bind(actor_user_id)
+        .bind(user_id)
+        .bind(permission_name)
+        .bind(now)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// PostgreSQL variant of [`revoke_all_user_permissions_mysql`]
+pub(crate) async fn revoke_all_user_permissions_postgres(
+    conn: &mut sqlx::PgConnection,
+    actor_user_id: &str,
+    user_id: &str,
+    organization_uuid: &str,
+) -> Result<(), sqlx::Error> {
+    let now = Utc::now();
+
+    let permission_names: Vec<String> = sqlx::query_scalar(
+        "SELECT permission_name FROM user_permissions
+         WHERE user_id = $1 AND organization_uuid = $2",
+    )
+    .bind(user_id)
+    .bind(organization_uuid)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    sqlx::query(
+        "DELETE FROM user_permissions
+         WHERE user_id = $1 AND organization_uuid = $2",
+    )
+    .bind(user_id)
+    .bind(organization_uuid)
+    .execute(&mut *conn)
+    .await?;
+
+    for permission_name in &permission_names {
+        sqlx::query(
+            "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+             VALUES ($1, $2, $3, $4, $5, 'revoke', $6)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(organization_uuid)
+        .bind(actor_user_id)
+        .bind(user_id)
+        .bind(permission_name)
+        .bind(now)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// SQLite variant of [`revoke_all_user_permissions_mysql`]
+pub(crate) async fn revoke_all_user_permissions_sqlite(
+    conn: &mut sqlx::SqliteConnection,
+    actor_user_id: &str,
+    user_id: &str,
+    organization_uuid: &str,
+) -> Result<(), sqlx::Error> {
+    let now = Utc::now();
+
+    let permission_names: Vec<String> = sqlx::query_scalar(
+        "SELECT permission_name FROM user_permissions
+         WHERE user_id = ?1 AND organization_uuid = ?2",
+    )
+    .bind(user_id)
+    .bind(organization_uuid)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    sqlx::query(
+        "DELETE FROM user_permissions
+         WHERE user_id = ?1 AND organization_uuid = ?2",
+    )
+    .bind(user_id)
+    .bind(organization_uuid)
+    .execute(&mut *conn)
+    .await?;
+
+    for permission_name in &permission_names {
+        sqlx::query(
+            "INSERT INTO permission_audit_log (uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, 'revoke', ?6)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(organization_uuid)
+        .bind(actor_user_id)
+        .bind(user_id)
+        .bind(permission_name)
+        .bind(now)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Resolve the permissions a user effectively has in an organization
+///
+/// `super_admin` expands to every defined permission. A grant ending in `*` (e.g.
+/// `module_crm_*`, which has to be defined in the permissions table like any other
+/// permission) is a wildcard and expands to all defined permissions starting with
+/// the text before the `*`. All other grants are included as they are.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `user_id` - UUID of the user
+/// * `organization_uuid` - UUID of the organization
+///
+/// # Returns
+/// The names of all effective permissions, sorted
+///
+/// # Errors
+/// Returns `PermissionDatabaseError` if loading the grants or defined permissions fails
+pub async fn resolve_effective_permissions(
+    pool: &DatabasePool,
+    user_id: &str,
+    organization_uuid: &str,
+) -> Result<BTreeSet<String>, PermissionDatabaseError> {
+    let granted: Vec<String> = list_user_permissions(pool, user_id, organization_uuid)
+        .await?
+        .into_iter()
+        .map(|permission| permission.permission_name)
+        .collect();
+
+    // Only super_admin and wildcards need the defined permissions
+    let needs_definitions = granted
+        .iter()
+        .any(|name| name == "super_admin" || name.ends_with('*'));
+    let defined: Vec<String> = if needs_definitions {
+        list_permissions(pool)
+            .await?
+            .into_iter()
+            .map(|permission| permission.name)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    Ok(expand_permission_grants(&granted, &defined))
+}
+
+/// Expand `super_admin` and wildcard grants against the defined permissions
+fn expand_permission_grants(granted: &[String], defined: &[String]) -> BTreeSet<String> {
+    let mut effective = BTreeSet::new();
+
+    for grant in granted {
+        if grant == "super_admin" {
+            effective.insert(grant.clone());
+            effective.extend(defined.iter().filter(|name| !name.ends_with('*')).cloned());
+        } else if grant.ends_with('*') {
+            effective.extend(defined.iter().filter(|name| grant_covers(grant, name)).cloned());
+        } else {
+            effective.insert(grant.clone());
+        }
+    }
+
+    effective
+}
+
+/// Whether a grant gives a permission, not considering `super_admin`
+///
+/// A wildcard grant (`module_crm_*`) covers every permission starting with the text
+/// before the `*`, except other wildcards. Other grants only cover themselves.
+pub(crate) fn grant_covers(grant: &str, permission: &str) -> bool {
+    match grant.strip_suffix('*') {
+        Some(prefix) => permission.starts_with(prefix) && !permission.ends_with('*'),
+        None => grant == permission,
+    }
+}
+
+/// List the permission audit log of an organization, newest entries first
+pub async fn list_permission_audit_log(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+) -> Result<Vec<PermissionAuditEntry>, PermissionDatabaseError> {
+    let mut entries = Vec::new();
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at
+                 FROM permission_audit_log
+                 WHERE organization_uuid = ?
+                 ORDER BY created_at DESC",
             )
-            .bind(user_id)
             .bind(organization_uuid)
-            .execute(p)
+            .fetch_all(p)
             .await?;
+
+            for row in rows {
+                entries.push(PermissionAuditEntry {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    actor_user_id: row.get("actor_user_id"),
+                    target_user_id: row.get("target_user_id"),
+                    permission_name: row.get("permission_name"),
+                    action: parse_audit_action(row.get("action"))?,
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                });
+            }
         }
         DatabasePool::Postgres(p) => {
-            sqlx::query(
-                "DELETE FROM user_permissions
-                 WHERE user_id = $1 AND organization_uuid = $2",
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at
+                 FROM permission_audit_log
+                 WHERE organization_uuid = $1
+                 ORDER BY created_at DESC",
             )
-            .bind(user_id)
             .bind(organization_uuid)
-            .execute(p)
+            .fetch_all(p)
             .await?;
+
+            for row in rows {
+                entries.push(PermissionAuditEntry {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    actor_user_id: row.get("actor_user_id"),
+                    target_user_id: row.get("target_user_id"),
+                    permission_name: row.get("permission_name"),
+                    action: parse_audit_action(row.get("action"))?,
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                });
+            }
         }
         DatabasePool::Sqlite(p) => {
-            sqlx::query(
-                "DELETE FROM user_permissions
-                 WHERE user_id = ?1 AND organization_uuid = ?2",
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, actor_user_id, target_user_id, permission_name, action, created_at
+                 FROM permission_audit_log
+                 WHERE organization_uuid = ?1
+                 ORDER BY created_at DESC",
             )
-            .bind(user_id)
             .bind(organization_uuid)
-            .execute(p)
+            .fetch_all(p)
             .await?;
+
+            for row in rows {
+                entries.push(PermissionAuditEntry {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    actor_user_id: row.get("actor_user_id"),
+                    target_user_id: row.get("target_user_id"),
+                    permission_name: row.get("permission_name"),
+                    action: parse_audit_action(row.get("action"))?,
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                });
+            }
         }
     }
 
-    Ok(())
+    Ok(entries)
 }
 
+/// Parse the stored audit action string
+fn parse_audit_action(action: &str) -> Result<PermissionAuditAction, PermissionDatabaseError> {
+    PermissionAuditAction::parse(action)
+        .ok_or_else(|| PermissionDatabaseError::InvalidAuditAction(action.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::create_test_pool;
+    use crate::user::user_has_permission;
+
+    const ORG_A: &str = "00000000-0000-0000-0000-00000000000a";
+    const ORG_B: &str = "00000000-0000-0000-0000-00000000000b";
+    const ADMIN: &str = "00000000-0000-0000-0000-000000000001";
+    const MEMBER: &str = "00000000-0000-0000-0000-000000000002";
+
+    /// Set up test database with required tables
+    async fn setup_test_db() -> DatabasePool {
+        let pool = create_test_pool().await.expect("Failed to create test pool");
+
+        match &pool {
+            DatabasePool::Sqlite(p) => {
+                sqlx::query(
+                    "CREATE TABLE IF NOT EXISTS permission_groups (
+                        name VARCHAR(255) NOT NULL PRIMARY KEY,
+                        title VARCHAR(255) NOT NULL,
+                        description TEXT,
+                        visible INTEGER NOT NULL DEFAULT 1,
+                        sort_order INTEGER NOT NULL DEFAULT 0
+                    )",
+                )
+                .execute(p)
+                .await
+                .expect("Failed to create permission_groups table");
+
+                sqlx::query(
+                    "CREATE TABLE IF NOT EXISTS permissions (
+                        name VARCHAR(255) NOT NULL PRIMARY KEY,
+                        permission_group_name VARCHAR(255) NOT NULL,
+                        title VARCHAR(255) NOT NULL,
+                        description TEXT,
+                        visible INTEGER NOT NULL DEFAULT 1,
+                        sort_order INTEGER NOT NULL DEFAULT 0
+                    )",
+                )
+                .execute(p)
+                .await
+                .expect("Failed to create permissions table");
+
+                sqlx::query(
+                    "CREATE TABLE IF NOT EXISTS user_permissions (
+                        user_id CHAR(36) NOT NULL,
+                        organization_uuid CHAR(36) NOT NULL,
+                        permission_name VARCHAR(255) NOT NULL,
+                        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                        PRIMARY KEY (user_id, organization_uuid, permission_name)
+                    )",
+                )
+                .execute(p)
+                .await
+                .expect("Failed to create user_permissions table");
+
+                sqlx::query(
+                    "CREATE TABLE IF NOT EXISTS permission_audit_log (
+                        uuid CHAR(36) NOT NULL PRIMARY KEY,
+                        organization_uuid CHAR(36) NOT NULL,
+                        actor_user_id CHAR(36) NOT NULL,
+                        target_user_id CHAR(36) NOT NULL,
+                        permission_name VARCHAR(255) NOT NULL,
+                        action VARCHAR(20) NOT NULL,
+                        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+                    )",
+                )
+                .execute(p)
+                .await
+                .expect("Failed to create permission_audit_log table");
+
+                for name in ["can_read", "can_write"] {
+                    sqlx::query(
+                        "INSERT INTO permissions (name, permission_group_name, title)
+                         VALUES (?1, 'test', ?1)",
+                    )
+                    .bind(name)
+                    .execute(p)
+                    .await
+                    .expect("Failed to insert permission");
+                }
+            }
+            _ => panic!("Test pool must be SQLite"),
+        }
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_grant_writes_audit_entry() {
+        let pool = setup_test_db().await;
+
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_read")
+            .await
+            .expect("Failed to grant permission");
+
+        let entries = list_permission_audit_log(&pool, ORG_A).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].organization_uuid, ORG_A);
+        assert_eq!(entries[0].actor_user_id, ADMIN);
+        assert_eq!(entries[0].target_user_id, MEMBER);
+        assert_eq!(entries[0].permission_name, "can_read");
+        assert_eq!(entries[0].action, PermissionAuditAction::Grant);
+    }
+
+    #[tokio::test]
+    async fn test_grant_requires_defined_permission() {
+        let pool = setup_test_db().await;
+
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_read").await.unwrap();
+        let granted = list_user_permissions(&pool, MEMBER, ORG_A).await.unwrap();
+        assert_eq!(granted.len(), 1);
+        assert_eq!(granted[0].permission_name, "can_read");
+
+        let result = add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_fly").await;
+        assert!(matches!(result, Err(PermissionDatabaseError::UnknownPermission(name)) if name == "can_fly"));
+        assert_eq!(list_user_permissions(&pool, MEMBER, ORG_A).await.unwrap().len(), 1);
+        assert_eq!(list_permission_audit_log(&pool, ORG_A).await.unwrap().len(), 1);
+    }
+
+    async fn define_permission(pool: &DatabasePool, name: &str) {
+        match pool {
+            DatabasePool::Sqlite(p) => {
+                sqlx::query(
+                    "INSERT INTO permissions (name, permission_group_name, title)
+                     VALUES (?1, 'test', ?1)",
+                )
+                .bind(name)
+                .execute(p)
+                .await
+                .expect("Failed to insert permission");
+            }
+            _ => panic!("Test pool must be SQLite"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_super_admin_has_all_defined_permissions() {
+        let pool = setup_test_db().await;
+        define_permission(&pool, "super_admin").await;
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "super_admin").await.unwrap();
+
+        let effective = resolve_effective_permissions(&pool, MEMBER, ORG_A).await.unwrap();
+        let expected: BTreeSet<String> = ["can_read", "can_write", "super_admin"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        assert_eq!(effective, expected);
+
+        // Scoped to the organization of the grant
+        assert!(resolve_effective_permissions(&pool, MEMBER, ORG_B).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wildcard_grant_expands_to_matching_permissions() {
+        let pool = setup_test_db().await;
+        define_permission(&pool, "module_crm_*").await;
+        define_permission(&pool, "module_crm_can_read").await;
+        define_permission(&pool, "module_crm_can_write").await;
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "module_crm_*").await.unwrap();
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_read").await.unwrap();
+
+        let effective = resolve_effective_permissions(&pool, MEMBER, ORG_A).await.unwrap();
+        let expected: BTreeSet<String> = ["can_read", "module_crm_can_read", "module_crm_can_write"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        assert_eq!(effective, expected);
+    }
+
+    #[tokio::test]
+    async fn test_wildcard_grant_passes_permission_check() {
+        let pool = setup_test_db().await;
+        define_permission(&pool, "module_crm_*").await;
+        define_permission(&pool, "module_crm_can_read").await;
+        define_permission(&pool, "module_docs_can_read").await;
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "module_crm_*").await.unwrap();
+
+        // Every permission reported as effective passes the check used by the endpoints
+        for permission in resolve_effective_permissions(&pool, MEMBER, ORG_A).await.unwrap() {
+            assert!(user_has_permission(&pool, MEMBER, ORG_A, &permission).await.unwrap(), "{}", permission);
+        }
+        assert!(user_has_permission(&pool, MEMBER, ORG_A, "module_crm_can_read").await.unwrap());
+        assert!(!user_has_permission(&pool, MEMBER, ORG_A, "module_docs_can_read").await.unwrap());
+        assert!(!user_has_permission(&pool, MEMBER, ORG_B, "module_crm_can_read").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_revoke_writes_audit_entry() {
+        let pool = setup_test_db().await;
+
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_read").await.unwrap();
+        delete_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_read").await.unwrap();
+
+        let entries = list_permission_audit_log(&pool, ORG_A).await.unwrap();
+        assert_eq!(entries.len(), 2);
+        let revokes: Vec<_> = entries
+            .iter()
+            .filter(|e| e.action == PermissionAuditAction::Revoke)
+            .collect();
+        assert_eq!(revokes.len(), 1);
+        assert_eq!(revokes[0].actor_user_id, ADMIN);
+        assert_eq!(revokes[0].target_user_id, MEMBER);
+        assert_eq!(revokes[0].permission_name, "can_read");
+    }
+
+    #[tokio::test]
+    async fn test_noop_changes_are_not_audited() {
+        let pool = setup_test_db().await;
+
+        // Revoking a permission the user does not have
+        delete_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_read").await.unwrap();
+        // Granting the same permission twice
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_read").await.unwrap();
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_read").await.unwrap();
+
+        let entries = list_permission_audit_log(&pool, ORG_A).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, PermissionAuditAction::Grant);
+    }
+
+    #[tokio::test]
+    async fn test_delete_all_writes_revoke_per_permission() {
+        let pool = setup_test_db().await;
+
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_read").await.unwrap();
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_write").await.unwrap();
+        delete_all_user_permissions(&pool, ADMIN, MEMBER, ORG_A).await.unwrap();
+
+        let entries = list_permission_audit_log(&pool, ORG_A).await.unwrap();
+        let mut revoked: Vec<_> = entries
+            .iter()
+            .filter(|e| e.action == PermissionAuditAction::Revoke)
+            .map(|e| e.permission_name.as_str())
+            .collect();
+        revoked.sort();
+        assert_eq!(revoked, vec!["can_read", "can_write"]);
+        assert!(list_user_permissions(&pool, MEMBER, ORG_A).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_is_scoped_to_organization() {
+        let pool = setup_test_db().await;
+
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_read").await.unwrap();
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_B, "can_write").await.unwrap();
+
+        let entries_a = list_permission_audit_log(&pool, ORG_A).await.unwrap();
+        let entries_b = list_permission_audit_log(&pool, ORG_B).await.unwrap();
+        assert_eq!(entries_a.len(), 1);
+        assert_eq!(entries_a[0].permission_name, "can_read");
+        assert_eq!(entries_b.len(), 1);
+        assert_eq!(entries_b[0].permission_name, "can_write");
+    }
+
+    async fn create_group(pool: &DatabasePool, name: &str) {
+        create_permission_group(
+            pool,
+            CreatePermissionGroupRequest {
+                name: name.to_string(),
+                title: name.to_string(),
+                description: None,
+                visible: None,
+                sort_order: None,
+            },
+        )
+        .await
+        .expect("Failed to create permission group");
+    }
+
+    #[tokio::test]
+    async fn test_delete_permission_group_refused_while_referenced() {
+        let pool = setup_test_db().await;
+        // Permissions seeded by setup_test_db reference the "test" group
+        create_group(&pool, "test").await;
+
+        match delete_permission_group(&pool, "test").await {
+            Err(PermissionDatabaseError::PermissionGroupInUse { name, permission_count }) => {
+                assert_eq!(name, "test");
+                assert_eq!(permission_count, 2);
+            }
+            other => panic!("Expected PermissionGroupInUse, got {:?}", other),
+        }
+
+        let groups = list_permission_groups(&pool).await.unwrap();
+        assert!(groups.iter().any(|g| g.name == "test"));
+        assert_eq!(list_permissions(&pool).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_delete_empty_permission_group() {
+        let pool = setup_test_db().await;
+        create_group(&pool, "empty").await;
+
+        delete_permission_group(&pool, "empty")
+            .await
+            .expect("Failed to delete empty permission group");
+
+        let groups = list_permission_groups(&pool).await.unwrap();
+        assert!(!groups.iter().any(|g| g.name == "empty"));
+    }
+
+    #[tokio::test]
+    async fn test_delete_missing_permission_group() {
+        let pool = setup_test_db().await;
+
+        assert!(matches!(
+            delete_permission_group(&pool, "missing").await,
+            Err(PermissionDatabaseError::PermissionGroupNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_permission_group_cascade() {
+        let pool = setup_test_db().await;
+        create_group(&pool, "test").await;
+        add_user_permission(&pool, ADMIN, MEMBER, ORG_A, "can_read").await.unwrap();
+
+        delete_permission_group_cascade(&pool, ADMIN, "test")
+            .await
+            .expect("Failed to delete permission group");
+
+        assert!(list_permission_groups(&pool).await.unwrap().is_empty());
+        assert!(list_permissions(&pool).await.unwrap().is_empty());
+        assert!(list_user_permissions(&pool, MEMBER, ORG_A).await.unwrap().is_empty());
+
+        let entries = list_permission_audit_log(&pool, ORG_A).await.unwrap();
+        let revoke = entries
+            .iter()
+            .find(|e| e.action == PermissionAuditAction::Revoke)
+            .expect("Removed grant should be audited");
+        assert_eq!(revoke.actor_user_id, ADMIN);
+        assert_eq!(revoke.target_user_id, MEMBER);
+        assert_eq!(revoke.permission_name, "can_read");
+    }
+}
@@ -5,10 +5,13 @@
 mod database;
 
 pub use database::{
-    create_permission_group, delete_permission_group, list_permission_groups,
+    create_permission_group, delete_permission_group, delete_permission_group_cascade, list_permission_groups,
     create_permission, delete_permission, list_permissions,
     list_user_permissions, add_user_permission, delete_user_permission, delete_all_user_permissions,
-    PermissionDatabaseError,
+    list_permission_audit_log, resolve_effective_permissions, PermissionDatabaseError,
+};
+pub(crate) use database::{
+    grant_covers, revoke_all_user_permissions_mysql, revoke_all_user_permissions_postgres, revoke_all_user_permissions_sqlite,
 };
 
 use serde::{Deserialize, Serialize};
@@ -43,6 +46,47 @@ pub struct UserPermission {
     pub created_at: String,
 }
 
+/// Kind of change recorded in the permission audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PermissionAuditAction {
+    Grant,
+    Revoke,
+}
+
+impl PermissionAuditAction {
+    /// Get the string stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PermissionAuditAction::Grant => "grant",
+            PermissionAuditAction::Revoke => "revoke",
+        }
+    }
+
+    /// Parse the string stored in the database
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "grant" => Some(PermissionAuditAction::Grant),
+            "revoke" => Some(PermissionAuditAction::Revoke),
+            _ => None,
+        }
+    }
+}
+
+/// Permission audit log entry - who granted or revoked which permission of whom
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermissionAuditEntry {
+    pub uuid: String,
+    pub organization_uuid: String,
+    /// User who performed the change
+    pub actor_user_id: String,
+    /// User whose permissions changed
+    pub target_user_id: String,
+    pub permission_name: String,
+    pub action: PermissionAuditAction,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
 /// Create permission group request
 #[derive(Debug, Deserialize)]
 pub struct CreatePermissionGroupRequest {
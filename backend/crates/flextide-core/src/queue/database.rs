@@ -0,0 +1,567 @@
+//! Database operations on the `queue_messages` table
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::Row;
+
+use crate::database::DatabasePool;
+use crate::queue::{QueueError, QueueMessage};
+
+/// Status of a message waiting to be processed
+pub const STATUS_PENDING: &str = "pending";
+
+/// Status of a message claimed by a worker
+pub const STATUS_PROCESSING: &str = "processing";
+
+/// Status of a message that was processed successfully
+pub const STATUS_COMPLETED: &str = "completed";
+
+/// Status of a message that exhausted its retries
+pub const STATUS_DEAD_LETTER: &str = "dead_letter";
+
+/// Queue depth of a single organization
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct QueueSnapshot {
+    /// Organization owning the runs of the messages
+    pub organization_uuid: String,
+    /// Number of messages waiting to be processed
+    pub pending: i64,
+    /// Number of messages currently claimed by a worker
+    pub in_progress: i64,
+    /// Number of messages that exhausted their retries
+    pub dead_lettered: i64,
+    /// Age in seconds of the oldest pending message, `None` if nothing is pending
+    pub oldest_pending_age_seconds: Option<i64>,
+}
+
+/// Take a snapshot of the queue depth per organization
+///
+/// Messages are assigned to the organization of their run. Organizations without
+/// pending, in-progress or dead-lettered messages are left out.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+///
+/// # Returns
+/// One `QueueSnapshot` per organization, ordered by organization UUID
+///
+/// # Errors
+/// Returns `QueueError::Database` if the database operation fails
+pub async fn snapshot(pool: &DatabasePool) -> Result<Vec<QueueSnapshot>, QueueError> {
+    let query = "SELECT r.organization_uuid, m.status, COUNT(*) AS message_count, MIN(m.created_at) AS oldest_created_at
+                 FROM queue_messages m
+                 INNER JOIN runs r ON r.uuid = m.run_id
+                 WHERE m.status IN ('pending', 'processing', 'dead_letter')
+                 GROUP BY r.organization_uuid, m.status
+                 ORDER BY r.organization_uuid";
+
+    // (organization_uuid, status, count, oldest created_at)
+    let rows: Vec<(String, String, i64, Option<DateTime<Utc>>)> = match pool {
+        DatabasePool::MySql(p) => sqlx::query(query)
+            .fetch_all(p)
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get("organization_uuid"),
+                    row.get("status"),
+                    row.get("message_count"),
+                    row.get("oldest_created_at"),
+                )
+            })
+            .collect(),
+        DatabasePool::Postgres(p) => sqlx::query(query)
+            .fetch_all(p)
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get("organization_uuid"),
+                    row.get("status"),
+                    row.get("message_count"),
+                    row.get::<Option<chrono::NaiveDateTime>, _>("oldest_created_at")
+                        .map(|created_at| created_at.and_utc()),
+                )
+            })
+            .collect(),
+        DatabasePool::Sqlite(p) => sqlx::query(query)
+            .fetch_all(p)
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get("organization_uuid"),
+                    row.get("status"),
+                    row.get("message_count"),
+                    row.get("oldest_created_at"),
+                )
+            })
+            .collect(),
+    };
+
+    let now = Utc::now();
+    let mut snapshots: Vec<QueueSnapshot> = Vec::new();
+
+    for (organization_uuid, status, count, oldest_created_at) in rows {
+        if snapshots.last().map(|s| &s.organization_uuid) != Some(&organization_uuid) {
+            snapshots.push(QueueSnapshot {
+                organization_uuid,
+                pending: 0,
+                in_progress: 0,
+                dead_lettered: 0,
+                oldest_pending_age_seconds: None,
+            });
+        }
+        let snapshot = snapshots.last_mut().expect("snapshot was just pushed");
+
+        match status.as_str() {
+            STATUS_PENDING => {
+                snapshot.pending = count;
+                snapshot.oldest_pending_age_seconds =
+                    oldest_created_at.map(|created_at| (now - created_at).num_seconds().max(0));
+            }
+            STATUS_PROCESSING => snapshot.in_progress = count,
+            STATUS_DEAD_LETTER => snapshot.dead_lettered = count,
+            _ => {}
+        }
+    }
+
+    Ok(snapshots)
+}
+
+/// Dequeue the next visible pending message and lease it to the caller
+///
+/// The message is marked as processing until `lease_duration` has passed. If it is not
+/// acknowledged before then, [`reclaim_expired_leases`] returns it to pending, so every
+/// message is processed at least once even if a worker crashes.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `queue_name` - Name of the queue to dequeue from
+/// * `lease_duration` - How long the caller may process the message
+///
+/// # Returns
+/// The leased message with a fresh receipt handle, or `None` if no message is available
+///
+/// # Errors
+/// Returns `QueueError` if the database operation fails or the lease duration is out of range
+pub async fn dequeue(
+    pool: &DatabasePool,
+    queue_name: &str,
+    lease_duration: Duration,
+) -> Result<Option<QueueMessage>, QueueError> {
+    let lease_duration = chrono::Duration::from_std(lease_duration)
+        .map_err(|e| QueueError::Operation(format!("Invalid lease duration: {}", e)))?;
+
+    // Another worker may claim the selected message first, in that case try the next one
+    loop {
+        let now = Utc::now();
+
+        let candidate: Option<(String, Value)> = match pool {
+            DatabasePool::MySql(p) => sqlx::query(
+                "SELECT id, payload FROM queue_messages
+                 WHERE status = 'pending' AND queue_name = ? AND visible_at <= ?
+                 ORDER BY priority, created_at LIMIT 1",
+            )
+            .bind(queue_name)
+            .bind(now)
+            .fetch_optional(p)
+            .await?
+            .map(|row| (row.get("id"), row.get("payload"))),
+            DatabasePool::Postgres(p) => sqlx::query(
+                "SELECT id, payload FROM queue_messages
+                 WHERE status = 'pending' AND queue_name = $1 AND visible_at <= $2
+                 ORDER BY priority, created_at LIMIT 1",
+            )
+            .bind(queue_name)
+            .bind(now.naive_utc())
+            .fetch_optional(p)
+            .await?
+            .map(|row| (row.get("id"), row.get("payload"))),
+            DatabasePool::Sqlite(p) => sqlx::query(
+                "SELECT id, payload FROM queue_messages
+                 WHERE status = 'pending' AND queue_name = ?1 AND visible_at <= ?2
+                 ORDER BY priority, created_at LIMIT 1",
+            )
+            .bind(queue_name)
+            .bind(now)
+            .fetch_optional(p)
+            .await?
+            .map(|row| (row.get("id"), row.get("payload"))),
+        };
+
+        let Some((id, payload)) = candidate else {
+            return Ok(None);
+        };
+
+        let receipt_handle = uuid::Uuid::new_v4().to_string();
+        let lease_expires_at = now + lease_duration;
+
+        let claimed = match pool {
+            DatabasePool::MySql(p) => sqlx::query(
+                "UPDATE queue_messages SET status = 'processing', receipt_handle = ?, lease_expires_at = ?, updated_at = ?
+                 WHERE id = ? AND status = 'pending'",
+            )
+            .bind(&receipt_handle)
+            .bind(lease_expires_at)
+            .bind(now)
+            .bind(&id)
+            .execute(p)
+            .await?
+            .rows_affected(),
+            DatabasePool::Postgres(p) => sqlx::query(
+                "UPDATE queue_messages SET status = 'processing', receipt_handle = $1, lease_expires_at = $2, updated_at = $3
+                 WHERE id = $4 AND status = 'pending'",
+            )
+            .bind(&receipt_handle)
+            .bind(lease_expires_at.naive_utc())
+            .bind(now.naive_utc())
+            .bind(&id)
+            .execute(p)
+            .await?
+            .rows_affected(),
+            DatabasePool::Sqlite(p) => sqlx::query(
+                "UPDATE queue_messages SET status = 'processing', receipt_handle = ?1, lease_expires_at = ?2, updated_at = ?3
+                 WHERE id = ?4 AND status = 'pending'",
+            )
+            .bind(&receipt_handle)
+            .bind(lease_expires_at)
+            .bind(now)
+            .bind(&id)
+            .execute(p)
+            .await?
+            .rows_affected(),
+        };
+
+        if claimed == 1 {
+            return Ok(Some(QueueMessage {
+                id,
+                payload,
+                receipt_handle: Some(receipt_handle),
+            }));
+        }
+    }
+}
+
+/// Acknowledge a leased message after it was processed successfully
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `receipt_handle` - Receipt handle returned by [`dequeue`]
+///
+/// # Returns
+/// `true` if the message was marked as completed, `false` if the receipt handle does not
+/// belong to a message in processing (e.g. because its lease expired and was reclaimed)
+///
+/// # Errors
+/// Returns `QueueError::Database` if the database operation fails
+pub async fn acknowledge(pool: &DatabasePool, receipt_handle: &str) -> Result<bool, QueueError> {
+    let now = Utc::now();
+
+    let updated = match pool {
+        DatabasePool::MySql(p) => sqlx::query(
+            "UPDATE queue_messages SET status = 'completed', lease_expires_at = NULL, processed_at = ?, updated_at = ?
+             WHERE receipt_handle = ? AND status = 'processing'",
+        )
+        .bind(now)
+        .bind(now)
+        .bind(receipt_handle)
+        .execute(p)
+        .await?
+        .rows_affected(),
+        DatabasePool::Postgres(p) => sqlx::query(
+            "UPDATE queue_messages SET status = 'completed', lease_expires_at = NULL, processed_at = $1, updated_at = $2
+             WHERE receipt_handle = $3 AND status = 'processing'",
+        )
+        .bind(now.naive_utc())
+        .bind(now.naive_utc())
+        .bind(receipt_handle)
+        .execute(p)
+        .await?
+        .rows_affected(),
+        DatabasePool::Sqlite(p) => sqlx::query(
+            "UPDATE queue_messages SET status = 'completed', lease_expires_at = NULL, processed_at = ?1, updated_at = ?2
+             WHERE receipt_handle = ?3 AND status = 'processing'",
+        )
+        .bind(now)
+        .bind(now)
+        .bind(receipt_handle)
+        .execute(p)
+        .await?
+        .rows_affected(),
+    };
+
+    Ok(updated > 0)
+}
+
+/// Return messages whose lease expired to pending, so another worker can dequeue them
+///
+/// The receipt handle of a reclaimed message is cleared, so the worker that held the
+/// lease can no longer acknowledge it. An expired lease counts as a failed attempt:
+/// `retry_count` is incremented, and a message exceeding its `max_retries` (e.g. one
+/// that crashes every worker) is moved to the dead letter status instead.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+///
+/// # Returns
+/// The number of reclaimed messages, including the dead-lettered ones
+///
+/// # Errors
+/// Returns `QueueError::Database` if the database operation fails
+pub async fn reclaim_expired_leases(pool: &DatabasePool) -> Result<u64, QueueError> {
+    let now = Utc::now();
+
+    // MySQL applies the assignments in order, so retry_count is incremented last to
+    // keep the CASE expressions on the old value, as in PostgreSQL and SQLite
+    let reclaimed = match pool {
+        DatabasePool::MySql(p) => sqlx::query(
+            "UPDATE queue_messages SET
+                 status = CASE WHEN retry_count + 1 > max_retries THEN 'dead_letter' ELSE 'pending' END,
+                 error_message = CASE WHEN retry_count + 1 > max_retries THEN 'Lease expired too often' ELSE error_message END,
+                 receipt_handle = NULL, lease_expires_at = NULL, updated_at = ?,
+                 retry_count = retry_count + 1
+             WHERE status = 'processing' AND lease_expires_at <= ?",
+        )
+        .bind(now)
+        .bind(now)
+        .execute(p)
+        .await?
+        .rows_affected(),
+        DatabasePool::Postgres(p) => sqlx::query(
+            "UPDATE queue_messages SET
+                 status = CASE WHEN retry_count + 1 > max_retries THEN 'dead_letter' ELSE 'pending' END,
+                 error_message = CASE WHEN retry_count + 1 > max_retries THEN 'Lease expired too often' ELSE error_message END,
+                 receipt_handle = NULL, lease_expires_at = NULL, updated_at = $1,
+                 retry_count = retry_count + 1
+             WHERE status = 'processing' AND lease_expires_at <= $2",
+        )
+        .bind(now.naive_utc())
+        .bind(now.naive_utc())
+        .execute(p)
+        .await?
+        .rows_affected(),
+        DatabasePool::Sqlite(p) => sqlx::query(
+            "UPDATE queue_messages SET
+                 status = CASE WHEN retry_count + 1 > max_retries THEN 'dead_letter' ELSE 'pending' END,
+                 error_message = CASE WHEN retry_count + 1 > max_retries THEN 'Lease expired too often' ELSE error_message END,
+                 receipt_handle = NULL, lease_expires_at = NULL, updated_at = ?1,
+                 retry_count = retry_count + 1
+             WHERE status = 'processing' AND lease_expires_at <= ?2",
+        )
+        .bind(now)
+        .bind(now)
+        .execute(p)
+        .await?
+        .rows_affected(),
+    };
+
+    if reclaimed > 0 {
+        tracing::warn!("Reclaimed {} queue messages with expired leases", reclaimed);
+    }
+
+    Ok(reclaimed)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::database::create_test_pool;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    /// Set up the runs and queue_messages tables
+    pub(crate) async fn setup_test_db() -> DatabasePool {
+        let pool = create_test_pool().await.expect("Failed to create test pool");
+
+        if let DatabasePool::Sqlite(p) = &pool {
+            sqlx::query(
+                "CREATE TABLE runs (
+                    uuid CHAR(36) NOT NULL PRIMARY KEY,
+                    workflow_id CHAR(36) NOT NULL,
+                    organization_uuid CHAR(36) NOT NULL
+                )",
+            )
+            .execute(p)
+            .await
+            .expect("Failed to create runs table");
+
+            sqlx::query(
+                "CREATE TABLE queue_messages (
+                    id CHAR(36) NOT NULL PRIMARY KEY,
+                    workflow_id CHAR(36) NOT NULL,
+                    run_id CHAR(36) NOT NULL,
+                    payload JSON NOT NULL,
+                    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+                    priority INTEGER NOT NULL DEFAULT 0,
+                    receipt_handle CHAR(36),
+                    visible_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                    retry_count INTEGER NOT NULL DEFAULT 0,
+                    max_retries INTEGER NOT NULL DEFAULT 3,
+                    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                    processed_at TIMESTAMP NULL,
+                    error_message TEXT,
+                    error_code VARCHAR(100),
+                    queue_name VARCHAR(100) NOT NULL DEFAULT 'default',
+                    lease_expires_at TIMESTAMP NULL
+                )",
+            )
+            .execute(p)
+            .await
+            .expect("Failed to create queue_messages table");
+        }
+
+        pool
+    }
+
+    /// Create a run for an organization, returns its UUID
+    pub(crate) async fn create_run(pool: &DatabasePool, organization_uuid: &str) -> String {
+        let run_uuid = Uuid::new_v4().to_string();
+        if let DatabasePool::Sqlite(p) = pool {
+            sqlx::query("INSERT INTO runs (uuid, workflow_id, organization_uuid) VALUES (?1, ?2, ?3)")
+                .bind(&run_uuid)
+                .bind(Uuid::new_v4().to_string())
+                .bind(organization_uuid)
+                .execute(p)
+                .await
+                .expect("Failed to create run");
+        }
+        run_uuid
+    }
+
+    /// Insert a message of a run, returns its id
+    pub(crate) async fn insert_message(
+        pool: &DatabasePool,
+        run_uuid: &str,
+        status: &str,
+        created_at: DateTime<Utc>,
+    ) -> String {
+        let id = Uuid::new_v4().to_string();
+        if let DatabasePool::Sqlite(p) = pool {
+            sqlx::query(
+                "INSERT INTO queue_messages (id, workflow_id, run_id, payload, status, visible_at, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, '{}', ?4, ?5, ?5, ?5)",
+            )
+            .bind(&id)
+            .bind(Uuid::new_v4().to_string())
+            .bind(run_uuid)
+            .bind(status)
+            .bind(created_at)
+            .execute(p)
+            .await
+            .expect("Failed to insert message");
+        }
+        id
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_counts_messages_per_organization() {
+        let pool = setup_test_db().await;
+        let now = Utc::now();
+
+        let first_org = "00000000-0000-0000-0000-000000000001";
+        let second_org = "00000000-0000-0000-0000-000000000002";
+        let first_run = create_run(&pool, first_org).await;
+        let second_run = create_run(&pool, second_org).await;
+
+        insert_message(&pool, &first_run, STATUS_PENDING, now - Duration::minutes(10)).await;
+        insert_message(&pool, &first_run, STATUS_PENDING, now - Duration::minutes(1)).await;
+        insert_message(&pool, &first_run, STATUS_PROCESSING, now - Duration::hours(1)).await;
+        insert_message(&pool, &first_run, STATUS_DEAD_LETTER, now - Duration::hours(2)).await;
+        insert_message(&pool, &first_run, "completed", now - Duration::hours(3)).await;
+        insert_message(&pool, &second_run, STATUS_PROCESSING, now).await;
+        insert_message(&pool, &second_run, "failed", now).await;
+
+        let snapshots = snapshot(&pool).await.expect("Failed to take snapshot");
+        assert_eq!(snapshots.len(), 2);
+
+        let first = &snapshots[0];
+        assert_eq!(first.organization_uuid, first_org);
+        assert_eq!((first.pending, first.in_progress, first.dead_lettered), (2, 1, 1));
+        let age = first.oldest_pending_age_seconds.expect("Pending messages should have an age");
+        assert!((600..610).contains(&age), "unexpected age {}", age);
+
+        assert_eq!(
+            snapshots[1],
+            QueueSnapshot {
+                organization_uuid: second_org.to_string(),
+                pending: 0,
+                in_progress: 1,
+                dead_lettered: 0,
+                oldest_pending_age_seconds: None,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_of_empty_queue() {
+        let pool = setup_test_db().await;
+
+        assert!(snapshot(&pool).await.expect("Failed to take snapshot").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_expired_lease_makes_message_dequeuable_again() {
+        let pool = setup_test_db().await;
+        let run_uuid = create_run(&pool, "00000000-0000-0000-0000-000000000001").await;
+        let id = insert_message(&pool, &run_uuid, STATUS_PENDING, Utc::now() - Duration::seconds(1)).await;
+
+        let first = dequeue(&pool, "default", std::time::Duration::ZERO)
+            .await
+            .unwrap()
+            .expect("Pending message should be dequeued");
+        assert_eq!(first.id, id);
+
+        // The leased message is not handed out twice
+        assert!(dequeue(&pool, "default", std::time::Duration::from_secs(60)).await.unwrap().is_none());
+
+        // The worker "crashed", its lease already expired
+        assert_eq!(reclaim_expired_leases(&pool).await.unwrap(), 1);
+
+        let second = dequeue(&pool, "default", std::time::Duration::from_secs(60))
+            .await
+            .unwrap()
+            .expect("Reclaimed message should be dequeued again");
+        assert_eq!(second.id, id);
+        assert_ne!(second.receipt_handle, first.receipt_handle);
+
+        // The first worker lost its lease, the second one holds a valid one
+        assert!(!acknowledge(&pool, first.receipt_handle.as_deref().unwrap()).await.unwrap());
+        assert_eq!(reclaim_expired_leases(&pool).await.unwrap(), 0);
+        assert!(acknowledge(&pool, second.receipt_handle.as_deref().unwrap()).await.unwrap());
+        assert!(dequeue(&pool, "default", std::time::Duration::from_secs(60)).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_message_is_dead_lettered_after_too_many_expired_leases() {
+        let pool = setup_test_db().await;
+        let run_uuid = create_run(&pool, "00000000-0000-0000-0000-000000000001").await;
+        let id = insert_message(&pool, &run_uuid, STATUS_PENDING, Utc::now() - Duration::seconds(1)).await;
+
+        // max_retries is 3: the message is leased 4 times, then dead-lettered
+        for attempt in 1..=4 {
+            let message = dequeue(&pool, "default", std::time::Duration::ZERO)
+                .await
+                .unwrap()
+                .expect("Reclaimed message should be dequeued again");
+            assert_eq!(message.id, id);
+            assert_eq!(reclaim_expired_leases(&pool).await.unwrap(), 1, "attempt {}", attempt);
+        }
+        assert!(dequeue(&pool, "default", std::time::Duration::ZERO).await.unwrap().is_none());
+
+        if let DatabasePool::Sqlite(p) = &pool {
+            let row = sqlx::query("SELECT status, retry_count, error_message FROM queue_messages WHERE id = ?1")
+                .bind(&id)
+                .fetch_one(p)
+                .await
+                .unwrap();
+            assert_eq!(row.get::<String, _>("status"), STATUS_DEAD_LETTER);
+            assert_eq!(row.get::<i64, _>("retry_count"), 4);
+            assert_eq!(row.get::<String, _>("error_message"), "Lease expired too often");
+        }
+    }
+}
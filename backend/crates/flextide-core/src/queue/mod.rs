@@ -1,4 +1,5 @@
+pub mod database;
 pub mod queue;
 
+pub use database::{acknowledge, dequeue, reclaim_expired_leases, snapshot, QueueSnapshot};
 pub use queue::{QueueError, QueueMessage, QueueProvider};
-
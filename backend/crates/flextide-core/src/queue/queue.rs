@@ -35,6 +35,9 @@ pub enum QueueError {
 
     #[error("Queue provider error: {0}")]
     Provider(String),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
 }
 
 /// Trait for queue providers
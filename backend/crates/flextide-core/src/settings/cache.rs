@@ -0,0 +1,148 @@
+//! In-memory cache of organizational setting values
+//!
+//! Settings such as the docs AI provider are read on many requests but rarely change.
+//! Values are cached per `(organization_uuid, setting_key)` and dropped when the
+//! dispatcher the cache is subscribed to emits `core_setting_updated`.
+//!
+//! Caching only starts once the cache is subscribed to a dispatcher, so processes that
+//! never see the events (e.g. the worker) always read from the database. Changes made
+//! by other processes are picked up after `SETTINGS_CACHE_TTL`.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::events::{Event, EventDispatcher};
+
+/// Name of the event emitted when a setting value changes
+pub const SETTING_UPDATED_EVENT: &str = "core_setting_updated";
+
+/// Subscriber ID the cache registers with the dispatcher
+pub const SETTINGS_CACHE_SUBSCRIBER_ID: &str = "core_settings_cache";
+
+/// How long a loaded value is served from the cache
+pub const SETTINGS_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Cache shared by all setting reads of this process
+static GLOBAL_CACHE: LazyLock<Arc<SettingsCache>> =
+    LazyLock::new(|| Arc::new(SettingsCache::new(SETTINGS_CACHE_TTL)));
+
+#[derive(Debug, Default)]
+struct CacheState {
+    /// Loaded values and when they were loaded, keyed by `(organization_uuid, setting_key)`
+    values: HashMap<(String, String), (Option<String>, Instant)>,
+    /// Incremented on every invalidation, so values loaded during one are not cached
+    generation: u64,
+}
+
+/// Cache of setting values keyed by organization and setting
+#[derive(Debug)]
+pub struct SettingsCache {
+    ttl: Duration,
+    enabled: AtomicBool,
+    state: Mutex<CacheState>,
+}
+
+impl SettingsCache {
+    /// Create a disabled cache serving values for `ttl` after they were loaded
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            enabled: AtomicBool::new(false),
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Cache shared by the whole process
+    pub fn global() -> &'static Arc<SettingsCache> {
+        &GLOBAL_CACHE
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Whether values are cached, see [`SettingsCache::subscribe`]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Acquire)
+    }
+
+    /// Invalidate cached values on `core_setting_updated` events of `dispatcher` and enable the cache
+    ///
+    /// Runtime subscribers are awaited by [`EventDispatcher::emit`], so a changed value is
+    /// dropped before the request that changed it completes.
+    pub fn subscribe(self: &Arc<Self>, dispatcher: &EventDispatcher) {
+        let cache = Arc::clone(self);
+        dispatcher.subscribe_fn(SETTINGS_CACHE_SUBSCRIBER_ID, SETTING_UPDATED_EVENT, move |event: Event| {
+            let cache = Arc::clone(&cache);
+            async move {
+                cache.handle_event(&event);
+                Ok(())
+            }
+        });
+        self.enabled.store(true, Ordering::Release);
+    }
+
+    /// Drop the value changed by a `core_setting_updated` event
+    ///
+    /// Events without organization or setting key drop all cached values.
+    pub fn handle_event(&self, event: &Event) {
+        let setting_key = event.payload.data.get("entity_id").and_then(|v| v.as_str());
+        match (event.organization_uuid.as_deref(), setting_key) {
+            (Some(organization_uuid), Some(setting_key)) => self.invalidate(organization_uuid, setting_key),
+            _ => self.clear(),
+        }
+    }
+
+    /// Cached value of a setting, `None` on a miss
+    ///
+    /// # Returns
+    /// `Some(value)` if a non-expired value is cached, where `value` is `None` for settings without a value
+    pub fn get(&self, organization_uuid: &str, setting_key: &str) -> Option<Option<String>> {
+        let key = (organization_uuid.to_string(), setting_key.to_string());
+        let mut state = self.lock();
+        match state.values.get(&key) {
+            Some((value, loaded_at)) if loaded_at.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                state.values.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Current invalidation generation, to be passed to [`SettingsCache::insert_if_current`]
+    pub fn generation(&self) -> u64 {
+        self.lock().generation
+    }
+
+    /// Cache a loaded value unless an invalidation happened since `generation` was read
+    ///
+    /// A value read from the database while it was changed may already be stale.
+    pub fn insert_if_current(&self, organization_uuid: &str, setting_key: &str, generation: u64, value: Option<String>) {
+        let mut state = self.lock();
+        if state.generation == generation {
+            state.values.insert(
+                (organization_uuid.to_string(), setting_key.to_string()),
+                (value, Instant::now()),
+            );
+        }
+    }
+
+    /// Forget the cached value of a setting
+    pub fn invalidate(&self, organization_uuid: &str, setting_key: &str) {
+        let mut state = self.lock();
+        state.generation += 1;
+        state
+            .values
+            .remove(&(organization_uuid.to_string(), setting_key.to_string()));
+    }
+
+    /// Forget all cached values
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.generation += 1;
+        state.values.clear();
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::database::{DatabaseError, DatabasePool};
 use crate::events::{Event, EventDispatcher, EventPayload};
+use crate::settings::cache::{SettingsCache, SETTING_UPDATED_EVENT};
 use serde_json::json;
 use sqlx::Row;
 use thiserror::Error;
@@ -37,10 +38,43 @@ pub enum SettingsDatabaseError {
 /// Returns `SettingsDatabaseError` if:
 /// - The setting doesn't exist
 /// - Database operation fails
+///
+/// # Note
+/// Values are served from [`SettingsCache::global`] once it is subscribed to a dispatcher.
 pub async fn get_organizational_setting_value(
     pool: &DatabasePool,
     organization_uuid: &str,
     setting_key: &str,
+) -> Result<Option<String>, SettingsDatabaseError> {
+    get_cached_setting_value(SettingsCache::global(), pool, organization_uuid, setting_key).await
+}
+
+/// Get a setting value from `cache`, loading it from the database on a miss
+async fn get_cached_setting_value(
+    cache: &SettingsCache,
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    setting_key: &str,
+) -> Result<Option<String>, SettingsDatabaseError> {
+    if !cache.is_enabled() {
+        return load_organizational_setting_value(pool, organization_uuid, setting_key).await;
+    }
+
+    if let Some(value) = cache.get(organization_uuid, setting_key) {
+        return Ok(value);
+    }
+
+    let generation = cache.generation();
+    let value = load_organizational_setting_value(pool, organization_uuid, setting_key).await?;
+    cache.insert_if_current(organization_uuid, setting_key, generation, value.clone());
+    Ok(value)
+}
+
+/// Load a setting value from the database, bypassing the cache
+async fn load_organizational_setting_value(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    setting_key: &str,
 ) -> Result<Option<String>, SettingsDatabaseError> {
     // First check if the setting exists
     let setting_exists = match pool {
@@ -187,7 +221,7 @@ pub async fn set_organizational_setting_value(
     }
 
     // Get old value before updating (for event payload)
-    let old_value = get_organizational_setting_value(pool, organization_uuid, setting_key).await?;
+    let old_value = load_organizational_setting_value(pool, organization_uuid, setting_key).await?;
 
     // Upsert the setting value
     match pool {
@@ -230,9 +264,12 @@ pub async fn set_organizational_setting_value(
         }
     }
 
+    // The dispatcher may not be the one the cache is subscribed to
+    SettingsCache::global().invalidate(organization_uuid, setting_key);
+
     // Emit event after successful save
     let mut event = Event::new(
-        "core_setting_updated",
+        SETTING_UPDATED_EVENT,
         EventPayload::new(json!({
             "entity_type": "setting",
             "entity_id": setting_key,
@@ -321,7 +358,7 @@ pub async fn set_organizational_setting_values(
     // Get old values before updating (for event payloads)
     let mut old_values = std::collections::HashMap::new();
     for setting_key in settings.keys() {
-        let old_value = get_organizational_setting_value(pool, organization_uuid, setting_key).await?;
+        let old_value = load_organizational_setting_value(pool, organization_uuid, setting_key).await?;
         old_values.insert(setting_key.clone(), old_value);
     }
 
@@ -368,10 +405,13 @@ pub async fn set_organizational_setting_values(
             }
         }
 
+        // The dispatcher may not be the one the cache is subscribed to
+        SettingsCache::global().invalidate(organization_uuid, setting_key);
+
         // Emit event for each setting that was updated
         let old_value = old_values.get(setting_key).and_then(|v| v.as_ref());
         let mut event = Event::new(
-            "core_setting_updated",
+            SETTING_UPDATED_EVENT,
             EventPayload::new(json!({
                 "entity_type": "setting",
                 "entity_id": setting_key,
@@ -398,6 +438,157 @@ pub async fn set_organizational_setting_values(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::database::create_test_pool;
+    use crate::settings::cache::SETTINGS_CACHE_TTL;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    const SETTING: &str = "module_docs_summary_style";
+
+    /// Set up test database with a defined setting
+    async fn setup_test_db() -> DatabasePool {
+        let pool = create_test_pool().await.expect("Failed to create test pool");
+
+        match &pool {
+            DatabasePool::Sqlite(p) => {
+                sqlx::query(
+                    "CREATE TABLE IF NOT EXISTS organizational_settings (
+                        name VARCHAR(255) NOT NULL PRIMARY KEY
+                    )",
+                )
+                .execute(p)
+                .await
+                .expect("Failed to create organizational_settings table");
+
+                sqlx::query(
+                    "CREATE TABLE IF NOT EXISTS organizational_settings_values (
+                        organization_uuid CHAR(36) NOT NULL,
+                        setting_name VARCHAR(255) NOT NULL,
+                        value TEXT,
+                        PRIMARY KEY (organization_uuid, setting_name)
+                    )",
+                )
+                .execute(p)
+                .await
+                .expect("Failed to create organizational_settings_values table");
+
+                sqlx::query("INSERT INTO organizational_settings (name) VALUES (?1)")
+                    .bind(SETTING)
+                    .execute(p)
+                    .await
+                    .expect("Failed to define setting");
+            }
+            _ => panic!("Expected SQLite test pool"),
+        }
+
+        pool
+    }
+
+    /// Change a setting value without emitting an event
+    async fn write_value_directly(pool: &DatabasePool, organization_uuid: &str, value: &str) {
+        if let DatabasePool::Sqlite(p) = pool {
+            sqlx::query(
+                "UPDATE organizational_settings_values SET value = ?1
+                 WHERE organization_uuid = ?2 AND setting_name = ?3",
+            )
+            .bind(value)
+            .bind(organization_uuid)
+            .bind(SETTING)
+            .execute(p)
+            .await
+            .expect("Failed to update setting value");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_after_set_returns_new_value() {
+        let pool = setup_test_db().await;
+        let organization_uuid = uuid::Uuid::new_v4().to_string();
+        let dispatcher = EventDispatcher::new();
+        // A local cache, subscribing the global one would enable it for all other tests
+        let cache = Arc::new(SettingsCache::new(SETTINGS_CACHE_TTL));
+        cache.subscribe(&dispatcher);
+
+        set_organizational_setting_value(&pool, &organization_uuid, SETTING, Some("concise"), &dispatcher, None)
+            .await
+            .expect("Failed to set value");
+        let value = get_cached_setting_value(&cache, &pool, &organization_uuid, SETTING)
+            .await
+            .expect("Failed to get value");
+        assert_eq!(value.as_deref(), Some("concise"));
+
+        set_organizational_setting_value(&pool, &organization_uuid, SETTING, Some("detailed"), &dispatcher, None)
+            .await
+            .expect("Failed to set value");
+        let value = get_cached_setting_value(&cache, &pool, &organization_uuid, SETTING)
+            .await
+            .expect("Failed to get value");
+        assert_eq!(value.as_deref(), Some("detailed"));
+    }
+
+    #[tokio::test]
+    async fn test_cache_reloads_value_after_ttl() {
+        let pool = setup_test_db().await;
+        let organization_uuid = uuid::Uuid::new_v4().to_string();
+        let dispatcher = EventDispatcher::new();
+        let cache = Arc::new(SettingsCache::new(Duration::from_millis(50)));
+        cache.subscribe(&dispatcher);
+
+        set_organizational_setting_value(&pool, &organization_uuid, SETTING, Some("concise"), &dispatcher, None)
+            .await
+            .expect("Failed to set value");
+        get_cached_setting_value(&cache, &pool, &organization_uuid, SETTING)
+            .await
+            .expect("Failed to get value");
+
+        // Written without an event, like a change made by another process
+        write_value_directly(&pool, &organization_uuid, "detailed").await;
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let value = get_cached_setting_value(&cache, &pool, &organization_uuid, SETTING)
+            .await
+            .expect("Failed to get value");
+        assert_eq!(value.as_deref(), Some("detailed"));
+    }
+
+    #[tokio::test]
+    async fn test_cache_serves_value_until_setting_updated() {
+        let pool = setup_test_db().await;
+        let organization_uuid = uuid::Uuid::new_v4().to_string();
+        let dispatcher = EventDispatcher::new();
+        let cache = Arc::new(SettingsCache::new(Duration::from_secs(60)));
+        cache.subscribe(&dispatcher);
+
+        set_organizational_setting_value(&pool, &organization_uuid, SETTING, Some("concise"), &dispatcher, None)
+            .await
+            .expect("Failed to set value");
+        let value = get_cached_setting_value(&cache, &pool, &organization_uuid, SETTING)
+            .await
+            .expect("Failed to get value");
+        assert_eq!(value.as_deref(), Some("concise"));
+
+        // Written without an event, so the second read is served from the cache
+        write_value_directly(&pool, &organization_uuid, "detailed").await;
+        let value = get_cached_setting_value(&cache, &pool, &organization_uuid, SETTING)
+            .await
+            .expect("Failed to get value");
+        assert_eq!(value.as_deref(), Some("concise"));
+
+        let event = Event::new(
+            SETTING_UPDATED_EVENT,
+            EventPayload::new(serde_json::json!({
+                "entity_type": "setting",
+                "entity_id": SETTING,
+                "organization_uuid": organization_uuid,
+            })),
+        )
+        .with_organization(&organization_uuid);
+        dispatcher.emit(event).await;
+
+        let value = get_cached_setting_value(&cache, &pool, &organization_uuid, SETTING)
+            .await
+            .expect("Failed to get value");
+        assert_eq!(value.as_deref(), Some("detailed"));
+    }
 
     #[test]
     fn test_settings_database_error_display() {
@@ -2,8 +2,10 @@
 //!
 //! Provides functionality for managing and retrieving organizational settings.
 
+mod cache;
 mod database;
 
+pub use cache::{SettingsCache, SETTINGS_CACHE_SUBSCRIBER_ID, SETTINGS_CACHE_TTL, SETTING_UPDATED_EVENT};
 pub use database::{
     get_organizational_setting_value, set_organizational_setting_value,
     set_organizational_setting_values, SettingsDatabaseError,
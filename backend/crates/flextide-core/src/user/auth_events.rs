@@ -0,0 +1,479 @@
+//! Authentication audit log
+//!
+//! Persists logins and logouts with their outcome, so server administrators can review
+//! authentication activity (e.g. repeated failed logins for one email address).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::database::{DatabaseError, DatabasePool};
+use crate::pagination::{clamp_pagination, Pagination};
+
+/// Error type for authentication audit log operations
+#[derive(Debug, Error)]
+pub enum AuthEventDatabaseError {
+    #[error("Database error: {0}")]
+    Database(#[from] DatabaseError),
+
+    #[error("SQL execution error: {0}")]
+    Sql(#[from] sqlx::Error),
+
+    #[error("Invalid authentication event value: {0}")]
+    InvalidValue(String),
+}
+
+/// Kind of authentication event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthEventType {
+    Login,
+    Logout,
+}
+
+impl AuthEventType {
+    /// Get the string stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthEventType::Login => "login",
+            AuthEventType::Logout => "logout",
+        }
+    }
+
+    /// Parse the string stored in the database
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "login" => Some(AuthEventType::Login),
+            "logout" => Some(AuthEventType::Logout),
+            _ => None,
+        }
+    }
+}
+
+/// Whether an authentication attempt succeeded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthEventOutcome {
+    Success,
+    Failure,
+}
+
+impl AuthEventOutcome {
+    /// Get the string stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthEventOutcome::Success => "success",
+            AuthEventOutcome::Failure => "failure",
+        }
+    }
+
+    /// Parse the string stored in the database
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "success" => Some(AuthEventOutcome::Success),
+            "failure" => Some(AuthEventOutcome::Failure),
+            _ => None,
+        }
+    }
+}
+
+/// Authentication audit log entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthEvent {
+    pub uuid: String,
+    pub event_type: AuthEventType,
+    pub outcome: AuthEventOutcome,
+    /// User the event belongs to, `None` if the email address is unknown
+    pub user_uuid: Option<String>,
+    /// Normalized email address used to log in
+    pub email: Option<String>,
+    /// Address of the client, see `client_ip` of the API
+    pub ip_address: Option<String>,
+    /// Why the attempt failed, e.g. `invalid_password`
+    pub failure_reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request structure for recording an authentication event
+#[derive(Debug, Clone)]
+pub struct CreateAuthEventRequest {
+    pub event_type: AuthEventType,
+    pub outcome: AuthEventOutcome,
+    pub user_uuid: Option<String>,
+    pub email: Option<String>,
+    pub ip_address: Option<String>,
+    pub failure_reason: Option<String>,
+}
+
+/// Filter for listing authentication events, unset fields match every event
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuthEventFilter {
+    pub user_uuid: Option<String>,
+    pub email: Option<String>,
+    pub outcome: Option<AuthEventOutcome>,
+}
+
+/// One page of authentication events
+#[derive(Debug, Clone, Serialize)]
+pub struct PaginatedAuthEvents {
+    pub events: Vec<AuthEvent>,
+    pub total: u64,
+    pub page: u32,
+    pub limit: u32,
+    pub total_pages: u32,
+}
+
+/// Record an authentication event
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `request` - The event to record
+///
+/// # Returns
+/// Returns the UUID of the new audit log entry
+///
+/// # Errors
+/// Returns `AuthEventDatabaseError` if the database operation fails
+pub async fn record_auth_event(
+    pool: &DatabasePool,
+    request: &CreateAuthEventRequest,
+) -> Result<String, AuthEventDatabaseError> {
+    let event_uuid = Uuid::new_v4().to_string();
+    let now = Utc::now();
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query(
+                "INSERT INTO auth_events (uuid, event_type, outcome, user_uuid, email, ip_address, failure_reason, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&event_uuid)
+            .bind(request.event_type.as_str())
+            .bind(request.outcome.as_str())
+            .bind(&request.user_uuid)
+            .bind(&request.email)
+            .bind(&request.ip_address)
+            .bind(&request.failure_reason)
+            .bind(now)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query(
+                "INSERT INTO auth_events (uuid, event_type, outcome, user_uuid, email, ip_address, failure_reason, created_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+            )
+            .bind(&event_uuid)
+            .bind(request.event_type.as_str())
+            .bind(request.outcome.as_str())
+            .bind(&request.user_uuid)
+            .bind(&request.email)
+            .bind(&request.ip_address)
+            .bind(&request.failure_reason)
+            .bind(now)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query(
+                "INSERT INTO auth_events (uuid, event_type, outcome, user_uuid, email, ip_address, failure_reason, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            )
+            .bind(&event_uuid)
+            .bind(request.event_type.as_str())
+            .bind(request.outcome.as_str())
+            .bind(&request.user_uuid)
+            .bind(&request.email)
+            .bind(&request.ip_address)
+            .bind(&request.failure_reason)
+            .bind(now)
+            .execute(p)
+            .await?;
+        }
+    }
+
+    Ok(event_uuid)
+}
+
+/// List authentication events, newest first
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `filter` - Only events matching all set fields are listed
+/// * `page` - Page number (1-based)
+/// * `limit` - Number of events per page, callers clamp it to the configured maximum
+///
+/// # Errors
+/// Returns `AuthEventDatabaseError` if the database query fails
+pub async fn list_auth_events(
+    pool: &DatabasePool,
+    filter: &AuthEventFilter,
+    page: u32,
+    limit: u32,
+) -> Result<PaginatedAuthEvents, AuthEventDatabaseError> {
+    let Pagination { page, limit, offset } = clamp_pagination(page, limit, u32::MAX);
+    let outcome = filter.outcome.map(|outcome| outcome.as_str());
+
+    // Unset filter values are bound as NULL and match every row
+    let (total, events): (i64, Vec<AuthEvent>) = match pool {
+        DatabasePool::MySql(p) => {
+            let total = sqlx::query(
+                "SELECT COUNT(*) AS count FROM auth_events
+                 WHERE (? IS NULL OR user_uuid = ?) AND (? IS NULL OR email = ?) AND (? IS NULL OR outcome = ?)",
+            )
+            .bind(&filter.user_uuid)
+            .bind(&filter.user_uuid)
+            .bind(&filter.email)
+            .bind(&filter.email)
+            .bind(outcome)
+            .bind(outcome)
+            .fetch_one(p)
+            .await?
+            .get("count");
+
+            let rows = sqlx::query(
+                "SELECT uuid, event_type, outcome, user_uuid, email, ip_address, failure_reason, created_at
+                 FROM auth_events
+                 WHERE (? IS NULL OR user_uuid = ?) AND (? IS NULL OR email = ?) AND (? IS NULL OR outcome = ?)
+                 ORDER BY created_at DESC
+                 LIMIT ? OFFSET ?",
+            )
+            .bind(&filter.user_uuid)
+            .bind(&filter.user_uuid)
+            .bind(&filter.email)
+            .bind(&filter.email)
+            .bind(outcome)
+            .bind(outcome)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(p)
+            .await?;
+
+            let mut events = Vec::new();
+            for row in rows {
+                events.push(AuthEvent {
+                    uuid: row.get("uuid"),
+                    event_type: parse_event_type(row.get("event_type"))?,
+                    outcome: parse_outcome(row.get("outcome"))?,
+                    user_uuid: row.get("user_uuid"),
+                    email: row.get("email"),
+                    ip_address: row.get("ip_address"),
+                    failure_reason: row.get("failure_reason"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                });
+            }
+
+            (total, events)
+        }
+        DatabasePool::Postgres(p) => {
+            let total = sqlx::query(
+                "SELECT COUNT(*) AS count FROM auth_events
+                 WHERE ($1::TEXT IS NULL OR user_uuid = $1) AND ($2::TEXT IS NULL OR email = $2)
+                 AND ($3::TEXT IS NULL OR outcome = $3)",
+            )
+            .bind(&filter.user_uuid)
+            .bind(&filter.email)
+            .bind(outcome)
+            .fetch_one(p)
+            .await?
+            .get("count");
+
+            let rows = sqlx::query(
+                "SELECT uuid, event_type, outcome, user_uuid, email, ip_address, failure_reason, created_at
+                 FROM auth_events
+                 WHERE ($1::TEXT IS NULL OR user_uuid = $1) AND ($2::TEXT IS NULL OR email = $2)
+                 AND ($3::TEXT IS NULL OR outcome = $3)
+                 ORDER BY created_at DESC
+                 LIMIT $4 OFFSET $5",
+            )
+            .bind(&filter.user_uuid)
+            .bind(&filter.email)
+            .bind(outcome)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(p)
+            .await?;
+
+            let mut events = Vec::new();
+            for row in rows {
+                events.push(AuthEvent {
+                    uuid: row.get("uuid"),
+                    event_type: parse_event_type(row.get("event_type"))?,
+                    outcome: parse_outcome(row.get("outcome"))?,
+                    user_uuid: row.get("user_uuid"),
+                    email: row.get("email"),
+                    ip_address: row.get("ip_address"),
+                    failure_reason: row.get("failure_reason"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                });
+            }
+
+            (total, events)
+        }
+        DatabasePool::Sqlite(p) => {
+            let total = sqlx::query(
+                "SELECT COUNT(*) AS count FROM auth_events
+                 WHERE (?1 IS NULL OR user_uuid = ?1) AND (?2 IS NULL OR email = ?2) AND (?3 IS NULL OR outcome = ?3)",
+            )
+            .bind(&filter.user_uuid)
+            .bind(&filter.email)
+            .bind(outcome)
+            .fetch_one(p)
+            .await?
+            .get("count");
+
+            let rows = sqlx::query(
+                "SELECT uuid, event_type, outcome, user_uuid, email, ip_address, failure_reason, created_at
+                 FROM auth_events
+                 WHERE (?1 IS NULL OR user_uuid = ?1) AND (?2 IS NULL OR email = ?2) AND (?3 IS NULL OR outcome = ?3)
+                 ORDER BY created_at DESC
+                 LIMIT ?4 OFFSET ?5",
+            )
+            .bind(&filter.user_uuid)
+            .bind(&filter.email)
+            .bind(outcome)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(p)
+            .await?;
+
+            let mut events = Vec::new();
+            for row in rows {
+                events.push(AuthEvent {
+                    uuid: row.get("uuid"),
+                    event_type: parse_event_type(row.get("event_type"))?,
+                    outcome: parse_outcome(row.get("outcome"))?,
+                    user_uuid: row.get("user_uuid"),
+                    email: row.get("email"),
+                    ip_address: row.get("ip_address"),
+                    failure_reason: row.get("failure_reason"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                });
+            }
+
+            (total, events)
+        }
+    };
+
+    Ok(PaginatedAuthEvents {
+        events,
+        total: total as u64,
+        page,
+        limit,
+        total_pages: ((total as f64) / (limit as f64)).ceil() as u32,
+    })
+}
+
+/// Parse the stored event type string
+fn parse_event_type(event_type: &str) -> Result<AuthEventType, AuthEventDatabaseError> {
+    AuthEventType::parse(event_type).ok_or_else(|| AuthEventDatabaseError::InvalidValue(event_type.to_string()))
+}
+
+/// Parse the stored outcome string
+fn parse_outcome(outcome: &str) -> Result<AuthEventOutcome, AuthEventDatabaseError> {
+    AuthEventOutcome::parse(outcome).ok_or_else(|| AuthEventDatabaseError::InvalidValue(outcome.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::create_test_pool;
+
+    const USER: &str = "00000000-0000-0000-0000-000000000001";
+
+    async fn setup_test_db() -> DatabasePool {
+        let pool = create_test_pool().await.expect("Failed to create test pool");
+
+        match &pool {
+            DatabasePool::Sqlite(p) => {
+                sqlx::query(
+                    "CREATE TABLE IF NOT EXISTS auth_events (
+                        uuid CHAR(36) NOT NULL PRIMARY KEY,
+                        event_type VARCHAR(20) NOT NULL,
+                        outcome VARCHAR(20) NOT NULL,
+                        user_uuid CHAR(36),
+                        email VARCHAR(255),
+                        ip_address VARCHAR(45),
+                        failure_reason VARCHAR(50),
+                        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+                    )",
+                )
+                .execute(p)
+                .await
+                .expect("Failed to create auth_events table");
+            }
+            _ => unreachable!("Test pool should be SQLite"),
+        }
+
+        pool
+    }
+
+    fn login(outcome: AuthEventOutcome, user_uuid: Option<&str>, email: &str) -> CreateAuthEventRequest {
+        CreateAuthEventRequest {
+            event_type: AuthEventType::Login,
+            outcome,
+            user_uuid: user_uuid.map(str::to_string),
+            email: Some(email.to_string()),
+            ip_address: Some("203.0.113.7".to_string()),
+            failure_reason: match outcome {
+                AuthEventOutcome::Failure => Some("invalid_password".to_string()),
+                AuthEventOutcome::Success => None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_auth_events_filters_and_orders() {
+        let pool = setup_test_db().await;
+
+        record_auth_event(&pool, &login(AuthEventOutcome::Failure, None, "unknown@example.com"))
+            .await
+            .unwrap();
+        record_auth_event(&pool, &login(AuthEventOutcome::Failure, Some(USER), "user@example.com"))
+            .await
+            .unwrap();
+        let newest = record_auth_event(&pool, &login(AuthEventOutcome::Success, Some(USER), "user@example.com"))
+            .await
+            .unwrap();
+
+        let all = list_auth_events(&pool, &AuthEventFilter::default(), 1, 50).await.unwrap();
+        assert_eq!(all.total, 3);
+        assert_eq!(all.events[0].uuid, newest);
+        assert_eq!(all.events[0].ip_address.as_deref(), Some("203.0.113.7"));
+
+        let user_failures = list_auth_events(
+            &pool,
+            &AuthEventFilter {
+                user_uuid: Some(USER.to_string()),
+                email: None,
+                outcome: Some(AuthEventOutcome::Failure),
+            },
+            1,
+            50,
+        )
+        .await
+        .unwrap();
+        assert_eq!(user_failures.total, 1);
+        assert_eq!(user_failures.events[0].failure_reason.as_deref(), Some("invalid_password"));
+
+        let by_email = list_auth_events(
+            &pool,
+            &AuthEventFilter {
+                email: Some("unknown@example.com".to_string()),
+                ..AuthEventFilter::default()
+            },
+            1,
+            50,
+        )
+        .await
+        .unwrap();
+        assert_eq!(by_email.total, 1);
+        assert_eq!(by_email.events[0].user_uuid, None);
+
+        let second_page = list_auth_events(&pool, &AuthEventFilter::default(), 2, 2).await.unwrap();
+        assert_eq!(second_page.total_pages, 2);
+        assert_eq!(second_page.events.len(), 1);
+    }
+}
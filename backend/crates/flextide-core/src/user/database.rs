@@ -1,7 +1,11 @@
 //! Database operations for user management
 
 use crate::database::{DatabaseError, DatabasePool};
-use crate::user::{hash_password, User, UserCreationError};
+use crate::permissions::{
+    revoke_all_user_permissions_mysql, revoke_all_user_permissions_postgres, revoke_all_user_permissions_sqlite,
+};
+use crate::permissions::grant_covers;
+use crate::user::{hash_password, MembershipCache, User, UserCreationError};
 use sqlx::Row;
 use uuid::Uuid;
 
@@ -63,6 +67,9 @@ pub async fn has_any_users(pool: &DatabasePool) -> Result<bool, UserDatabaseErro
 ///
 /// # Note
 /// Users with the "super_admin" permission automatically have access to everything.
+/// Wildcard grants such as `module_crm_*` cover every permission with that prefix, the
+/// same way [`resolve_effective_permissions`](crate::permissions::resolve_effective_permissions)
+/// expands them.
 pub async fn user_has_permission(
     pool: &DatabasePool,
     user_uuid: &str,
@@ -113,48 +120,44 @@ pub async fn user_has_permission(
         return Ok(true);
     }
 
-    // Check for the specific permission
-    match pool {
+    // Check for the specific permission or a wildcard grant covering it
+    let grants: Vec<String> = match pool {
         DatabasePool::MySql(p) => {
-            let row = sqlx::query(
-                "SELECT COUNT(*) as count FROM user_permissions
-                 WHERE user_id = ? AND organization_uuid = ? AND permission_name = ?",
+            sqlx::query_scalar(
+                "SELECT permission_name FROM user_permissions
+                 WHERE user_id = ? AND organization_uuid = ? AND (permission_name = ? OR permission_name LIKE '%*')",
             )
             .bind(user_uuid)
             .bind(organization_uuid)
             .bind(permission)
-            .fetch_one(p)
-            .await?;
-            let count: i64 = row.get("count");
-            Ok(count > 0)
+            .fetch_all(p)
+            .await?
         }
         DatabasePool::Postgres(p) => {
-            let row = sqlx::query(
-                "SELECT COUNT(*) as count FROM user_permissions
-                 WHERE user_id = $1 AND organization_uuid = $2 AND permission_name = $3",
+            sqlx::query_scalar(
+                "SELECT permission_name FROM user_permissions
+                 WHERE user_id = $1 AND organization_uuid = $2 AND (permission_name = $3 OR permission_name LIKE '%*')",
             )
             .bind(user_uuid)
             .bind(organization_uuid)
             .bind(permission)
-            .fetch_one(p)
-            .await?;
-            let count: i64 = row.get("count");
-            Ok(count > 0)
+            .fetch_all(p)
+            .await?
         }
         DatabasePool::Sqlite(p) => {
-            let row = sqlx::query(
-                "SELECT COUNT(*) as count FROM user_permissions
-                 WHERE user_id = ?1 AND organization_uuid = ?2 AND permission_name = ?3",
+            sqlx::query_scalar(
+                "SELECT permission_name FROM user_permissions
+                 WHERE user_id = ?1 AND organization_uuid = ?2 AND (permission_name = ?3 OR permission_name LIKE '%*')",
             )
             .bind(user_uuid)
             .bind(organization_uuid)
             .bind(permission)
-            .fetch_one(p)
-            .await?;
-            let count: i64 = row.get("count");
-            Ok(count > 0)
+            .fetch_all(p)
+            .await?
         }
-    }
+    };
+
+    Ok(grants.iter().any(|grant| grant_covers(grant, permission)))
 }
 
 /// Check if a user exists by UUID
@@ -198,13 +201,17 @@ pub async fn user_exists_by_uuid(pool: &DatabasePool, user_uuid: &str) -> Result
 
 /// Get a user by email from the database
 ///
+/// Emails are stored normalized (see [`normalize_email`](super::normalize_email)) and
+/// `email` is normalized before the lookup, so `Admin@Example.com` finds `admin@example.com`.
+///
 /// # Errors
 /// Returns `UserDatabaseError` if the database query fails or user is not found
 pub async fn get_user_by_email(pool: &DatabasePool, email: &str) -> Result<User, UserDatabaseError> {
+    let email = super::normalize_email(email);
     match pool {
         DatabasePool::MySql(p) => {
             let row = sqlx::query(
-                "SELECT uuid, email, password_hash, salt, prename, lastname, mail_verified, activated 
+                "SELECT uuid, email, password_hash, salt, prename, lastname, mail_verified, activated, is_server_admin 
                  FROM users WHERE email = ?"
             )
             .bind(email)
@@ -215,6 +222,7 @@ pub async fn get_user_by_email(pool: &DatabasePool, email: &str) -> Result<User,
                 Some(row) => {
                     let mail_verified_int: i32 = row.get("mail_verified");
                     let activated_int: i32 = row.get("activated");
+                    let is_server_admin_int: i32 = row.get("is_server_admin");
                     
                     Ok(User {
                         uuid: row.get("uuid"),
@@ -225,6 +233,7 @@ pub async fn get_user_by_email(pool: &DatabasePool, email: &str) -> Result<User,
                         lastname: row.get::<Option<String>, _>("lastname"),
                         mail_verified: mail_verified_int != 0,
                         activated: activated_int != 0,
+                        is_server_admin: is_server_admin_int != 0,
                     })
                 }
                 None => Err(UserDatabaseError::Sql(sqlx::Error::RowNotFound)),
@@ -232,7 +241,7 @@ pub async fn get_user_by_email(pool: &DatabasePool, email: &str) -> Result<User,
         }
         DatabasePool::Postgres(p) => {
             let row = sqlx::query(
-                "SELECT uuid, email, password_hash, salt, prename, lastname, mail_verified, activated 
+                "SELECT uuid, email, password_hash, salt, prename, lastname, mail_verified, activated, is_server_admin 
                  FROM users WHERE email = $1"
             )
             .bind(email)
@@ -243,6 +252,7 @@ pub async fn get_user_by_email(pool: &DatabasePool, email: &str) -> Result<User,
                 Some(row) => {
                     let mail_verified_int: i32 = row.get("mail_verified");
                     let activated_int: i32 = row.get("activated");
+                    let is_server_admin_int: i32 = row.get("is_server_admin");
                     
                     Ok(User {
                         uuid: row.get("uuid"),
@@ -253,6 +263,7 @@ pub async fn get_user_by_email(pool: &DatabasePool, email: &str) -> Result<User,
                         lastname: row.get::<Option<String>, _>("lastname"),
                         mail_verified: mail_verified_int != 0,
                         activated: activated_int != 0,
+                        is_server_admin: is_server_admin_int != 0,
                     })
                 }
                 None => Err(UserDatabaseError::Sql(sqlx::Error::RowNotFound)),
@@ -260,7 +271,7 @@ pub async fn get_user_by_email(pool: &DatabasePool, email: &str) -> Result<User,
         }
         DatabasePool::Sqlite(p) => {
             let row = sqlx::query(
-                "SELECT uuid, email, password_hash, salt, prename, lastname, mail_verified, activated 
+                "SELECT uuid, email, password_hash, salt, prename, lastname, mail_verified, activated, is_server_admin 
                  FROM users WHERE email = ?1"
             )
             .bind(email)
@@ -271,6 +282,7 @@ pub async fn get_user_by_email(pool: &DatabasePool, email: &str) -> Result<User,
                 Some(row) => {
                     let mail_verified_int: i32 = row.get("mail_verified");
                     let activated_int: i32 = row.get("activated");
+                    let is_server_admin_int: i32 = row.get("is_server_admin");
                     
                     Ok(User {
                         uuid: row.get("uuid"),
@@ -281,6 +293,7 @@ pub async fn get_user_by_email(pool: &DatabasePool, email: &str) -> Result<User,
                         lastname: row.get::<Option<String>, _>("lastname"),
                         mail_verified: mail_verified_int != 0,
                         activated: activated_int != 0,
+                        is_server_admin: is_server_admin_int != 0,
                     })
                 }
                 None => Err(UserDatabaseError::Sql(sqlx::Error::RowNotFound)),
@@ -325,12 +338,13 @@ pub async fn ensure_default_admin_user(pool: &DatabasePool) -> Result<(), UserDa
         let prename = "Admin";
         let mail_verified = 1; // true for admin
         let activated = 1; // true
+        let is_server_admin = 1; // true for admin
 
         match pool {
             DatabasePool::MySql(p) => {
                 sqlx::query(
-                    "INSERT INTO users (uuid, email, password_hash, salt, prename, lastname, mail_verified, activated) 
-                     VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                    "INSERT INTO users (uuid, email, password_hash, salt, prename, lastname, mail_verified, activated, is_server_admin) 
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
                 )
                 .bind(&uuid)
                 .bind(email)
@@ -340,13 +354,14 @@ pub async fn ensure_default_admin_user(pool: &DatabasePool) -> Result<(), UserDa
                 .bind::<Option<String>>(None)
                 .bind(mail_verified)
                 .bind(activated)
+                .bind(is_server_admin)
                 .execute(p)
                 .await?;
             }
             DatabasePool::Postgres(p) => {
                 sqlx::query(
-                    "INSERT INTO users (uuid, email, password_hash, salt, prename, lastname, mail_verified, activated) 
-                     VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+                    "INSERT INTO users (uuid, email, password_hash, salt, prename, lastname, mail_verified, activated, is_server_admin) 
+                     VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
                 )
                 .bind(&uuid)
                 .bind(email)
@@ -356,13 +371,14 @@ pub async fn ensure_default_admin_user(pool: &DatabasePool) -> Result<(), UserDa
                 .bind::<Option<String>>(None)
                 .bind(mail_verified)
                 .bind(activated)
+                .bind(is_server_admin)
                 .execute(p)
                 .await?;
             }
             DatabasePool::Sqlite(p) => {
                 sqlx::query(
-                    "INSERT INTO users (uuid, email, password_hash, salt, prename, lastname, mail_verified, activated) 
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                    "INSERT INTO users (uuid, email, password_hash, salt, prename, lastname, mail_verified, activated, is_server_admin) 
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
                 )
                 .bind(&uuid)
                 .bind(email)
@@ -372,6 +388,7 @@ pub async fn ensure_default_admin_user(pool: &DatabasePool) -> Result<(), UserDa
                 .bind::<Option<String>>(None)
                 .bind(mail_verified)
                 .bind(activated)
+                .bind(is_server_admin)
                 .execute(p)
                 .await?;
             }
@@ -470,40 +487,16 @@ pub async fn ensure_default_admin_user(pool: &DatabasePool) -> Result<(), UserDa
         }
 
         // Grant super_admin permission to admin user for the organization
-        match pool {
-            DatabasePool::MySql(p) => {
-                sqlx::query(
-                    "INSERT INTO user_permissions (user_id, organization_uuid, permission_name)
-                     VALUES (?, ?, 'super_admin')
-                     ON DUPLICATE KEY UPDATE permission_name = permission_name",
-                )
-                .bind(&admin_user_uuid)
-                .bind(&org_uuid)
-                .execute(p)
-                .await?;
-            }
-            DatabasePool::Postgres(p) => {
-                sqlx::query(
-                    "INSERT INTO user_permissions (user_id, organization_uuid, permission_name)
-                     VALUES ($1, $2, 'super_admin')
-                     ON CONFLICT (user_id, organization_uuid, permission_name) DO NOTHING",
-                )
-                .bind(&admin_user_uuid)
-                .bind(&org_uuid)
-                .execute(p)
-                .await?;
-            }
-            DatabasePool::Sqlite(p) => {
-                sqlx::query(
-                    "INSERT OR IGNORE INTO user_permissions (user_id, organization_uuid, permission_name)
-                     VALUES (?1, ?2, 'super_admin')",
-                )
-                .bind(&admin_user_uuid)
-                .bind(&org_uuid)
-                .execute(p)
-                .await?;
-            }
-        }
+        pool.upsert(
+            "user_permissions",
+            &[
+                ("user_id", admin_user_uuid.as_str().into()),
+                ("organization_uuid", org_uuid.as_str().into()),
+                ("permission_name", "super_admin".into()),
+            ],
+            &["user_id", "organization_uuid", "permission_name"],
+        )
+        .await?;
     }
 
     Ok(())
@@ -511,6 +504,9 @@ pub async fn ensure_default_admin_user(pool: &DatabasePool) -> Result<(), UserDa
 
 /// Check if a user belongs to a specific organization
 ///
+/// Confirmed memberships are cached for `MEMBERSHIP_CACHE_TTL`, see
+/// [`MembershipCache`](super::MembershipCache).
+///
 /// # Arguments
 /// * `pool` - Database connection pool
 /// * `user_uuid` - UUID of the user to check
@@ -526,7 +522,14 @@ pub async fn user_belongs_to_organization(
     user_uuid: &str,
     organization_uuid: &str,
 ) -> Result<bool, UserDatabaseError> {
-    match pool {
+    let cache = MembershipCache::global();
+    if cache.contains(user_uuid, organization_uuid) {
+        return Ok(true);
+    }
+    // Read before the lookup, so a membership removed meanwhile is not cached
+    let generation = cache.generation();
+
+    let count: i64 = match pool {
         DatabasePool::MySql(p) => {
             let row = sqlx::query(
                 "SELECT COUNT(*) as count FROM organization_members 
@@ -537,13 +540,144 @@ pub async fn user_belongs_to_organization(
             .fetch_one(p)
             .await?;
             
+            row.get("count")
+        }
+        DatabasePool::Postgres(p) => {
+            let row = sqlx::query(
+                "SELECT COUNT(*) as count FROM organization_members 
+                 WHERE user_id = $1 AND org_id = $2",
+            )
+            .bind(user_uuid)
+            .bind(organization_uuid)
+            .fetch_one(p)
+            .await?;
+            
+            row.get("count")
+        }
+        DatabasePool::Sqlite(p) => {
+            let row = sqlx::query(
+                "SELECT COUNT(*) as count FROM organization_members 
+                 WHERE user_id = ?1 AND org_id = ?2",
+            )
+            .bind(user_uuid)
+            .bind(organization_uuid)
+            .fetch_one(p)
+            .await?;
+            
+            row.get("count")
+        }
+    };
+
+    if count > 0 {
+        cache.insert_if_current(user_uuid, organization_uuid, generation);
+    }
+    Ok(count > 0)
+}
+
+/// Remove a user from an organization
+///
+/// Used when a member leaves or is removed. All permissions of the user in the
+/// organization are revoked in the same transaction (with one `revoke` entry in the
+/// permission audit log each), so no orphaned permissions remain. The cached membership
+/// is invalidated, so the user loses access immediately on this API process.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `actor_user_uuid` - UUID of the user removing the member, recorded in the audit log
+/// * `user_uuid` - UUID of the user to remove
+/// * `organization_uuid` - UUID of the organization
+///
+/// # Returns
+/// Returns `true` if the user was a member, `false` otherwise
+///
+/// # Errors
+/// Returns `UserDatabaseError` if the database operation fails
+pub async fn remove_user_from_organization(
+    pool: &DatabasePool,
+    actor_user_uuid: &str,
+    user_uuid: &str,
+    organization_uuid: &str,
+) -> Result<bool, UserDatabaseError> {
+    let result = match pool {
+        DatabasePool::MySql(p) => {
+            let mut tx = p.begin().await?;
+            revoke_all_user_permissions_mysql(&mut tx, actor_user_uuid, user_uuid, organization_uuid).await?;
+            let removed = sqlx::query("DELETE FROM organization_members WHERE user_id = ? AND org_id = ?")
+                .bind(user_uuid)
+                .bind(organization_uuid)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            tx.commit().await?;
+            removed
+        }
+        DatabasePool::Postgres(p) => {
+            let mut tx = p.begin().await?;
+            revoke_all_user_permissions_postgres(&mut tx, actor_user_uuid, user_uuid, organization_uuid).await?;
+            let removed = sqlx::query("DELETE FROM organization_members WHERE user_id = $1 AND org_id = $2")
+                .bind(user_uuid)
+                .bind(organization_uuid)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            tx.commit().await?;
+            removed
+        }
+        DatabasePool::Sqlite(p) => {
+            let mut tx = p.begin().await?;
+            revoke_all_user_permissions_sqlite(&mut tx, actor_user_uuid, user_uuid, organization_uuid).await?;
+            let removed = sqlx::query("DELETE FROM organization_members WHERE user_id = ?1 AND org_id = ?2")
+                .bind(user_uuid)
+                .bind(organization_uuid)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            tx.commit().await?;
+            removed
+        }
+    };
+
+    MembershipCache::global().invalidate(user_uuid, organization_uuid);
+    Ok(result > 0)
+}
+
+/// Check if a user is an administrator of a specific organization
+///
+/// Organization owners and members with the `admin` role are administrators.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `user_uuid` - UUID of the user to check
+/// * `organization_uuid` - UUID of the organization to check
+///
+/// # Returns
+/// Returns `true` if the user is an owner or admin of the organization, `false` otherwise
+///
+/// # Errors
+/// Returns `UserDatabaseError` if the database query fails
+pub async fn user_is_organization_admin(
+    pool: &DatabasePool,
+    user_uuid: &str,
+    organization_uuid: &str,
+) -> Result<bool, UserDatabaseError> {
+    match pool {
+        DatabasePool::MySql(p) => {
+            let row = sqlx::query(
+                "SELECT COUNT(*) as count FROM organization_members 
+                 WHERE user_id = ? AND org_id = ? AND role IN ('owner', 'admin')",
+            )
+            .bind(user_uuid)
+            .bind(organization_uuid)
+            .fetch_one(p)
+            .await?;
+            
             let count: i64 = row.get("count");
             Ok(count > 0)
         }
         DatabasePool::Postgres(p) => {
             let row = sqlx::query(
                 "SELECT COUNT(*) as count FROM organization_members 
-                 WHERE user_id = $1 AND org_id = $2",
+                 WHERE user_id = $1 AND org_id = $2 AND role IN ('owner', 'admin')",
             )
             .bind(user_uuid)
             .bind(organization_uuid)
@@ -556,7 +690,7 @@ pub async fn user_belongs_to_organization(
         DatabasePool::Sqlite(p) => {
             let row = sqlx::query(
                 "SELECT COUNT(*) as count FROM organization_members 
-                 WHERE user_id = ?1 AND org_id = ?2",
+                 WHERE user_id = ?1 AND org_id = ?2 AND role IN ('owner', 'admin')",
             )
             .bind(user_uuid)
             .bind(organization_uuid)
@@ -568,4 +702,3 @@ pub async fn user_belongs_to_organization(
         }
     }
 }
-
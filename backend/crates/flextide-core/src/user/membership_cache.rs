@@ -0,0 +1,310 @@
+//! In-memory cache of organization memberships
+//!
+//! `user_belongs_to_organization` runs on nearly every request. Positive results are
+//! cached for a short time, so that repeated checks do not hit the database.
+//!
+//! Only memberships are cached, never their absence, so adding a member takes effect
+//! immediately. Removing a member must go through `remove_user_from_organization` (or
+//! call `invalidate_membership`) to take effect before the entry expires. Other API
+//! processes keep their cached entry until it expires after `MEMBERSHIP_CACHE_TTL`.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long a confirmed membership is served from the cache
+pub const MEMBERSHIP_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Number of entries above which expired entries are dropped on insert
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Cache shared by all membership checks of this process
+static GLOBAL_CACHE: LazyLock<MembershipCache> = LazyLock::new(|| MembershipCache::new(MEMBERSHIP_CACHE_TTL));
+
+#[derive(Debug, Default)]
+struct CacheState {
+    /// When each membership was confirmed, keyed by `(user_uuid, organization_uuid)`
+    entries: HashMap<(String, String), Instant>,
+    /// Incremented on every invalidation, so memberships loaded during one are not cached
+    generation: u64,
+}
+
+/// Cache of confirmed memberships keyed by `(user_uuid, organization_uuid)`
+#[derive(Debug)]
+pub struct MembershipCache {
+    ttl: Duration,
+    state: Mutex<CacheState>,
+}
+
+impl MembershipCache {
+    /// Create a cache serving memberships for `ttl` after they were confirmed
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Cache shared by the whole process
+    pub fn global() -> &'static MembershipCache {
+        &GLOBAL_CACHE
+    }
+
+    /// Whether a non-expired membership of the user in the organization is cached
+    pub fn contains(&self, user_uuid: &str, organization_uuid: &str) -> bool {
+        let key = (user_uuid.to_string(), organization_uuid.to_string());
+        let mut state = self.lock();
+        match state.entries.get(&key) {
+            Some(confirmed_at) if confirmed_at.elapsed() < self.ttl => true,
+            Some(_) => {
+                state.entries.remove(&key);
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Current invalidation generation, to be passed to [`MembershipCache::insert_if_current`]
+    pub fn generation(&self) -> u64 {
+        self.lock().generation
+    }
+
+    /// Remember that the user belongs to the organization, unless an invalidation
+    /// happened since `generation` was read
+    ///
+    /// A membership read from the database while the user was removed may already be revoked.
+    pub fn insert_if_current(&self, user_uuid: &str, organization_uuid: &str, generation: u64) {
+        let mut state = self.lock();
+        if state.generation != generation {
+            return;
+        }
+        if state.entries.len() >= PRUNE_THRESHOLD {
+            let ttl = self.ttl;
+            state.entries.retain(|_, confirmed_at| confirmed_at.elapsed() < ttl);
+        }
+        state
+            .entries
+            .insert((user_uuid.to_string(), organization_uuid.to_string()), Instant::now());
+    }
+
+    /// Forget the membership of the user in the organization
+    pub fn invalidate(&self, user_uuid: &str, organization_uuid: &str) {
+        let mut state = self.lock();
+        state.generation += 1;
+        state
+            .entries
+            .remove(&(user_uuid.to_string(), organization_uuid.to_string()));
+    }
+
+    /// Forget all memberships of an organization, e.g. when it is deleted
+    pub fn invalidate_organization(&self, organization_uuid: &str) {
+        let mut state = self.lock();
+        state.generation += 1;
+        state.entries.retain(|(_, org), _| org != organization_uuid);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Forget the cached membership of a user in an organization
+///
+/// Must be called by code that removes rows from `organization_members` without using
+/// `remove_user_from_organization`.
+pub fn invalidate_membership(user_uuid: &str, organization_uuid: &str) {
+    MembershipCache::global().invalidate(user_uuid, organization_uuid);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{create_test_pool, DatabasePool};
+    use crate::permissions::{list_permission_audit_log, list_user_permissions};
+    use crate::user::{remove_user_from_organization, user_belongs_to_organization};
+
+    async fn setup_test_db() -> DatabasePool {
+        let pool = create_test_pool().await.expect("Failed to create test pool");
+        match &pool {
+            DatabasePool::Sqlite(p) => {
+                sqlx::query(
+                    "CREATE TABLE IF NOT EXISTS organization_members (
+                        org_id CHAR(36) NOT NULL,
+                        user_id CHAR(36) NOT NULL,
+                        role VARCHAR(50) NOT NULL DEFAULT 'member',
+                        PRIMARY KEY (org_id, user_id)
+                    )",
+                )
+                .execute(p)
+                .await
+                .expect("Failed to create organization_members table");
+
+                sqlx::query(
+                    "CREATE TABLE IF NOT EXISTS user_permissions (
+                        user_id CHAR(36) NOT NULL,
+                        organization_uuid CHAR(36) NOT NULL,
+                        permission_name VARCHAR(255) NOT NULL,
+                        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                        PRIMARY KEY (user_id, organization_uuid, permission_name)
+                    )",
+                )
+                .execute(p)
+                .await
+                .expect("Failed to create user_permissions table");
+
+                sqlx::query(
+                    "CREATE TABLE IF NOT EXISTS permission_audit_log (
+                        uuid CHAR(36) NOT NULL PRIMARY KEY,
+                        organization_uuid CHAR(36) NOT NULL,
+                        actor_user_id CHAR(36) NOT NULL,
+                        target_user_id CHAR(36) NOT NULL,
+                        permission_name VARCHAR(255) NOT NULL,
+                        action VARCHAR(20) NOT NULL,
+                        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+                    )",
+                )
+                .execute(p)
+                .await
+                .expect("Failed to create permission_audit_log table");
+            }
+            _ => panic!("Test pool must be SQLite"),
+        }
+        pool
+    }
+
+    async fn add_member(pool: &DatabasePool, user_uuid: &str, org_uuid: &str) {
+        match pool {
+            DatabasePool::Sqlite(p) => {
+                sqlx::query("INSERT INTO organization_members (org_id, user_id, role) VALUES (?1, ?2, 'member')")
+                    .bind(org_uuid)
+                    .bind(user_uuid)
+                    .execute(p)
+                    .await
+                    .expect("Failed to add member");
+            }
+            _ => panic!("Test pool must be SQLite"),
+        }
+    }
+
+    /// Delete a membership directly, bypassing the cache invalidation
+    async fn delete_member_row(pool: &DatabasePool, user_uuid: &str, org_uuid: &str) {
+        match pool {
+            DatabasePool::Sqlite(p) => {
+                sqlx::query("DELETE FROM organization_members WHERE org_id = ?1 AND user_id = ?2")
+                    .bind(org_uuid)
+                    .bind(user_uuid)
+                    .execute(p)
+                    .await
+                    .expect("Failed to delete member");
+            }
+            _ => panic!("Test pool must be SQLite"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cached_membership_is_served_without_database() {
+        let pool = setup_test_db().await;
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = uuid::Uuid::new_v4().to_string();
+        add_member(&pool, &user_uuid, &org_uuid).await;
+
+        assert!(user_belongs_to_organization(&pool, &user_uuid, &org_uuid).await.unwrap());
+        assert!(MembershipCache::global().contains(&user_uuid, &org_uuid));
+
+        // The row is gone, but the cached membership is still served
+        delete_member_row(&pool, &user_uuid, &org_uuid).await;
+        assert!(user_belongs_to_organization(&pool, &user_uuid, &org_uuid).await.unwrap());
+
+        invalidate_membership(&user_uuid, &org_uuid);
+        assert!(!user_belongs_to_organization(&pool, &user_uuid, &org_uuid).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_removing_member_invalidates_cache() {
+        let pool = setup_test_db().await;
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = uuid::Uuid::new_v4().to_string();
+        add_member(&pool, &user_uuid, &org_uuid).await;
+
+        assert!(user_belongs_to_organization(&pool, &user_uuid, &org_uuid).await.unwrap());
+
+        assert!(remove_user_from_organization(&pool, &user_uuid, &user_uuid, &org_uuid).await.unwrap());
+        assert!(!MembershipCache::global().contains(&user_uuid, &org_uuid));
+        assert!(!user_belongs_to_organization(&pool, &user_uuid, &org_uuid).await.unwrap());
+
+        // Removing again reports that there was no membership
+        assert!(!remove_user_from_organization(&pool, &user_uuid, &user_uuid, &org_uuid).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_removing_member_revokes_permissions() {
+        let pool = setup_test_db().await;
+        let admin_uuid = uuid::Uuid::new_v4().to_string();
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = uuid::Uuid::new_v4().to_string();
+        let other_org_uuid = uuid::Uuid::new_v4().to_string();
+        add_member(&pool, &user_uuid, &org_uuid).await;
+
+        match &pool {
+            DatabasePool::Sqlite(p) => {
+                for (organization_uuid, permission_name) in
+                    [(&org_uuid, "can_read"), (&org_uuid, "can_write"), (&other_org_uuid, "can_read")]
+                {
+                    sqlx::query(
+                        "INSERT INTO user_permissions (user_id, organization_uuid, permission_name) VALUES (?1, ?2, ?3)",
+                    )
+                    .bind(&user_uuid)
+                    .bind(organization_uuid)
+                    .bind(permission_name)
+                    .execute(p)
+                    .await
+                    .expect("Failed to grant permission");
+                }
+            }
+            _ => panic!("Test pool must be SQLite"),
+        }
+
+        assert!(remove_user_from_organization(&pool, &admin_uuid, &user_uuid, &org_uuid).await.unwrap());
+
+        assert!(list_user_permissions(&pool, &user_uuid, &org_uuid).await.unwrap().is_empty());
+        // Permissions in other organizations are kept
+        assert_eq!(list_user_permissions(&pool, &user_uuid, &other_org_uuid).await.unwrap().len(), 1);
+
+        let revoked = list_permission_audit_log(&pool, &org_uuid).await.unwrap();
+        assert_eq!(revoked.len(), 2);
+        assert!(revoked.iter().all(|entry| entry.actor_user_id == admin_uuid && entry.target_user_id == user_uuid));
+    }
+
+    #[test]
+    fn test_entries_expire_after_ttl() {
+        let cache = MembershipCache::new(Duration::ZERO);
+        cache.insert_if_current("user", "org", cache.generation());
+        assert!(!cache.contains("user", "org"));
+
+        let cache = MembershipCache::new(Duration::from_secs(60));
+        cache.insert_if_current("user", "org-a", cache.generation());
+        cache.insert_if_current("user", "org-b", cache.generation());
+        cache.invalidate_organization("org-a");
+        assert!(!cache.contains("user", "org-a"));
+        assert!(cache.contains("user", "org-b"));
+    }
+
+    #[test]
+    fn test_membership_loaded_before_invalidation_is_not_cached() {
+        let cache = MembershipCache::new(Duration::from_secs(60));
+
+        // A lookup reads the generation, then the member is removed before it finishes
+        let generation = cache.generation();
+        cache.invalidate("user", "org");
+        cache.insert_if_current("user", "org", generation);
+        assert!(!cache.contains("user", "org"));
+
+        let generation = cache.generation();
+        cache.invalidate_organization("other-org");
+        cache.insert_if_current("user", "org", generation);
+        assert!(!cache.contains("user", "org"));
+
+        cache.insert_if_current("user", "org", cache.generation());
+        assert!(cache.contains("user", "org"));
+    }
+}
@@ -2,16 +2,27 @@
 //! 
 //! Provides functionality for user management, password hashing, and validation.
 
+mod auth_events;
 mod database;
+mod membership_cache;
 mod password;
 mod validation;
 
+pub use auth_events::{
+    list_auth_events, record_auth_event, AuthEvent, AuthEventDatabaseError, AuthEventFilter, AuthEventOutcome,
+    AuthEventType, CreateAuthEventRequest, PaginatedAuthEvents,
+};
 pub use database::{
-    ensure_default_admin_user, get_user_by_email, has_any_users, user_belongs_to_organization,
-    user_exists_by_uuid, user_has_permission, UserDatabaseError,
+    ensure_default_admin_user, get_user_by_email, has_any_users, remove_user_from_organization,
+    user_belongs_to_organization, user_exists_by_uuid, user_has_permission, user_is_organization_admin,
+    UserDatabaseError,
 };
+pub use membership_cache::{invalidate_membership, MembershipCache, MEMBERSHIP_CACHE_TTL};
 pub use password::{hash_password, verify_password, PasswordError};
-pub use validation::{validate_password, validate_email, PasswordValidationError, EmailValidationError};
+pub use validation::{
+    normalize_email, validate_email, validate_password, EmailValidationError, PasswordPolicy,
+    PasswordValidationError,
+};
 
 use thiserror::Error;
 
@@ -39,6 +50,8 @@ pub struct User {
     pub lastname: Option<String>,
     pub mail_verified: bool,
     pub activated: bool,
+    /// Server administrators can manage the whole installation, not just their organizations
+    pub is_server_admin: bool,
 }
 
 /// User creation request
@@ -62,12 +75,22 @@ pub struct UpdateUserRequest {
 impl User {
     /// Create a new user from a creation request
     pub fn from_request(request: CreateUserRequest) -> Result<(Self, String), UserCreationError> {
-        // Validate email
-        validation::validate_email(&request.email)
+        Self::from_request_with_policy(request, &PasswordPolicy::default())
+    }
+    
+    /// Create a new user from a creation request, validating the password against the given policy
+    pub fn from_request_with_policy(
+        request: CreateUserRequest,
+        policy: &PasswordPolicy,
+    ) -> Result<(Self, String), UserCreationError> {
+        // Normalize and validate email
+        let email = validation::normalize_email(&request.email);
+        validation::validate_email(&email)
             .map_err(UserCreationError::EmailValidation)?;
         
         // Validate password
-        validation::validate_password(&request.password)
+        policy
+            .validate(&request.password)
             .map_err(UserCreationError::PasswordValidation)?;
         
         // Hash password
@@ -81,13 +104,14 @@ impl User {
         Ok((
             Self {
                 uuid,
-                email: request.email,
+                email,
                 password_hash,
                 salt: None, // Argon2 includes salt in hash string
                 prename: request.prename,
                 lastname: request.lastname,
                 mail_verified: false,
                 activated: true,
+                is_server_admin: false,
             },
             password_hash_clone,
         ))
@@ -5,14 +5,112 @@ use thiserror::Error;
 /// Error type for validation operations
 #[derive(Debug, Error, PartialEq)]
 pub enum PasswordValidationError {
-    #[error("Password must be at least 10 characters long")]
-    TooShort,
+    #[error("Password must be at least {min_length} characters long")]
+    TooShort { min_length: usize },
     
     #[error("Password must be no more than 128 characters")]
     TooLong,
     
     #[error("Password is too common or easily guessable")]
     TooCommon,
+    
+    #[error("Password must contain at least one uppercase letter")]
+    MissingUppercase,
+    
+    #[error("Password must contain at least one digit")]
+    MissingDigit,
+    
+    #[error("Password must contain at least one symbol")]
+    MissingSymbol,
+    
+    #[error("Password violates multiple rules: {}", .0.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; "))]
+    Multiple(Vec<PasswordValidationError>),
+}
+
+impl PasswordValidationError {
+    /// Get every rule violation contained in this error
+    pub fn violations(&self) -> Vec<&PasswordValidationError> {
+        match self {
+            PasswordValidationError::Multiple(errors) => errors.iter().collect(),
+            other => vec![other],
+        }
+    }
+}
+
+/// Maximum password length, independent of the policy (prevents DoS on hashing)
+const MAX_PASSWORD_LENGTH: usize = 128;
+
+/// Common weak passwords that are always rejected
+const COMMON_PASSWORDS: [&str; 9] = [
+    "password", "12345678", "qwerty", "admin", "letmein",
+    "welcome", "monkey", "1234567890", "password123",
+];
+
+/// Configurable password policy
+///
+/// The maximum length and the check for common passwords always apply.
+/// The default policy only requires a minimum length of 10 characters.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub require_upper: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 10,
+            require_upper: false,
+            require_digit: false,
+            require_symbol: false,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Validate a password against this policy
+    ///
+    /// Returns the violated rule, or `PasswordValidationError::Multiple` if more than
+    /// one rule is violated. A password exceeding the maximum length is rejected
+    /// right away with `TooLong`.
+    pub fn validate(&self, password: &str) -> Result<(), PasswordValidationError> {
+        // Maximum length: 128 characters (prevent DoS)
+        if password.len() > MAX_PASSWORD_LENGTH {
+            return Err(PasswordValidationError::TooLong);
+        }
+        
+        let mut errors = Vec::new();
+        
+        if password.len() < self.min_length {
+            errors.push(PasswordValidationError::TooShort { min_length: self.min_length });
+        }
+        
+        if self.require_upper && !password.chars().any(|c| c.is_uppercase()) {
+            errors.push(PasswordValidationError::MissingUppercase);
+        }
+        
+        if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            errors.push(PasswordValidationError::MissingDigit);
+        }
+        
+        if self.require_symbol && !password.chars().any(|c| !c.is_alphanumeric() && !c.is_whitespace()) {
+            errors.push(PasswordValidationError::MissingSymbol);
+        }
+        
+        // Check for common weak passwords
+        let password_lower = password.to_lowercase();
+        if COMMON_PASSWORDS.iter().any(|&p| password_lower.contains(p)) {
+            errors.push(PasswordValidationError::TooCommon);
+        }
+        
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(PasswordValidationError::Multiple(errors)),
+        }
+    }
 }
 
 /// Error type for email validation
@@ -25,7 +123,7 @@ pub enum EmailValidationError {
     TooLong,
 }
 
-/// Validate password strength
+/// Validate password strength against the default [`PasswordPolicy`]
 /// 
 /// Enforces:
 /// - Minimum length: 10 characters
@@ -36,31 +134,26 @@ pub enum EmailValidationError {
 /// ```
 /// use flextide_core::user::validate_password;
 /// 
-/// assert!(validate_password("secure_password_123").is_ok());
+/// assert!(validate_password("correct_horse_battery").is_ok());
 /// assert!(validate_password("short").is_err());
 /// ```
 pub fn validate_password(password: &str) -> Result<(), PasswordValidationError> {
-    // Minimum length: 10 characters
-    if password.len() < 10 {
-        return Err(PasswordValidationError::TooShort);
-    }
-    
-    // Maximum length: 128 characters (prevent DoS)
-    if password.len() > 128 {
-        return Err(PasswordValidationError::TooLong);
-    }
-    
-    // Check for common weak passwords
-    let common_passwords = [
-        "password", "12345678", "qwerty", "admin", "letmein",
-        "welcome", "monkey", "1234567890", "password123",
-    ];
-    let password_lower = password.to_lowercase();
-    if common_passwords.iter().any(|&p| password_lower.contains(p)) {
-        return Err(PasswordValidationError::TooCommon);
-    }
-    
-    Ok(())
+    PasswordPolicy::default().validate(password)
+}
+
+/// Normalize an email address
+/// 
+/// Trims surrounding whitespace and lowercases the address. Emails are stored and
+/// looked up in this form, so addresses differing only in case belong to the same user.
+/// 
+/// # Example
+/// ```
+/// use flextide_core::user::normalize_email;
+/// 
+/// assert_eq!(normalize_email("  John.Doe@Example.COM "), "john.doe@example.com");
+/// ```
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
 }
 
 /// Validate email format
@@ -121,13 +214,13 @@ mod tests {
     #[test]
     fn test_password_validation() {
         // Valid passwords
-        assert!(validate_password("secure_password_123").is_ok());
-        assert!(validate_password("ThisIsAVeryLongPassword123!@#").is_ok());
+        assert!(validate_password("correct_horse_battery").is_ok());
+        assert!(validate_password("ThisIsAVeryLongPhrase123!@#").is_ok());
         
         // Too short
         assert_eq!(
             validate_password("short"),
-            Err(PasswordValidationError::TooShort)
+            Err(PasswordValidationError::TooShort { min_length: 10 })
         );
         
         // Too long
@@ -144,6 +237,83 @@ mod tests {
         );
     }
     
+    #[test]
+    fn test_password_policy_min_length() {
+        let policy = PasswordPolicy { min_length: 16, ..Default::default() };
+        
+        assert!(policy.validate("a_long_enough_secret").is_ok());
+        assert_eq!(
+            policy.validate("only_12_char"),
+            Err(PasswordValidationError::TooShort { min_length: 16 })
+        );
+    }
+    
+    #[test]
+    fn test_password_policy_require_upper() {
+        let policy = PasswordPolicy { require_upper: true, ..Default::default() };
+        
+        assert!(policy.validate("Correct_horse_battery").is_ok());
+        assert_eq!(
+            policy.validate("correct_horse_battery"),
+            Err(PasswordValidationError::MissingUppercase)
+        );
+    }
+    
+    #[test]
+    fn test_password_policy_require_digit() {
+        let policy = PasswordPolicy { require_digit: true, ..Default::default() };
+        
+        assert!(policy.validate("correct_horse_battery_7").is_ok());
+        assert_eq!(
+            policy.validate("correct_horse_battery"),
+            Err(PasswordValidationError::MissingDigit)
+        );
+    }
+    
+    #[test]
+    fn test_password_policy_require_symbol() {
+        let policy = PasswordPolicy { require_symbol: true, ..Default::default() };
+        
+        assert!(policy.validate("correct-horse-battery").is_ok());
+        assert_eq!(
+            policy.validate("correcthorsebattery"),
+            Err(PasswordValidationError::MissingSymbol)
+        );
+    }
+    
+    #[test]
+    fn test_password_policy_reports_multiple_violations() {
+        let policy = PasswordPolicy {
+            min_length: 12,
+            require_upper: true,
+            require_digit: true,
+            require_symbol: true,
+        };
+        
+        let error = policy.validate("horse").unwrap_err();
+        assert_eq!(
+            error,
+            PasswordValidationError::Multiple(vec![
+                PasswordValidationError::TooShort { min_length: 12 },
+                PasswordValidationError::MissingUppercase,
+                PasswordValidationError::MissingDigit,
+                PasswordValidationError::MissingSymbol,
+            ])
+        );
+        assert_eq!(error.violations().len(), 4);
+        
+        assert!(policy.validate("Correct-Horse-7").is_ok());
+    }
+    
+    #[test]
+    fn test_email_normalization() {
+        assert_eq!(normalize_email("user@example.com"), "user@example.com");
+        assert_eq!(normalize_email("  user@example.com\n"), "user@example.com");
+        assert_eq!(normalize_email("User@EXAMPLE.Com"), "user@example.com");
+        assert_eq!(normalize_email("invalid"), "invalid");
+        assert!(validate_email(&normalize_email(" user@example.com ")).is_ok());
+    }
+    
     #[test]
     fn test_email_validation() {
         // Valid emails
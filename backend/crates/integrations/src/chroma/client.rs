@@ -4,6 +4,7 @@
 
 use crate::chroma::error::ChromaError;
 use crate::chroma::types::*;
+use crate::http::SendLogged;
 use reqwest::Client;
 use tracing::{debug, error, info};
 
@@ -13,6 +14,7 @@ const CHROMA_API_VERSION: &str = "v2";
 /// Client for interacting with the Chroma vector database REST API
 pub struct ChromaClient {
     client: Client,
+    log_requests: bool,
     base_url: String,
     api_key: Option<String>,
 }
@@ -22,6 +24,7 @@ impl ChromaClient {
     pub fn new() -> Self {
         Self {
             client: Client::new(),
+            log_requests: false,
             base_url: DEFAULT_CHROMA_BASE_URL.to_string(),
             api_key: None,
         }
@@ -31,6 +34,7 @@ impl ChromaClient {
     pub fn with_base_url(base_url: String) -> Self {
         Self {
             client: Client::new(),
+            log_requests: false,
             base_url,
             api_key: None,
         }
@@ -40,11 +44,20 @@ impl ChromaClient {
     pub fn with_api_key(base_url: String, api_key: String) -> Self {
         Self {
             client: Client::new(),
+            log_requests: false,
             base_url,
             api_key: Some(api_key),
         }
     }
 
+    /// Log every request at `debug` level, with secrets redacted (default: off)
+    ///
+    /// Uses the request logging of [`HttpTransport`](crate::http::HttpTransport).
+    pub fn with_request_logging(mut self, enabled: bool) -> Self {
+        self.log_requests = enabled;
+        self
+    }
+
     /// Build the API URL for a given endpoint (API v2 with tenant/database)
     fn api_url(&self, tenant: &str, database: &str, endpoint: &str) -> String {
         format!(
@@ -168,7 +181,7 @@ impl ChromaClient {
             .post(&url)
             .headers(self.build_headers())
             .json(&request)
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -195,7 +208,7 @@ impl ChromaClient {
             .client
             .get(&url)
             .headers(self.build_headers())
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -220,7 +233,7 @@ impl ChromaClient {
             .client
             .get(&url)
             .headers(self.build_headers())
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -256,7 +269,7 @@ impl ChromaClient {
             .put(&url)
             .headers(self.build_headers())
             .json(&request)
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -283,7 +296,7 @@ impl ChromaClient {
             .client
             .delete(&url)
             .headers(self.build_headers())
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -319,7 +332,7 @@ impl ChromaClient {
             .post(&url)
             .headers(self.build_headers())
             .json(&request)
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -334,6 +347,92 @@ impl ChromaClient {
         Ok(())
     }
 
+    /// Add or update documents in a collection (API v2 - requires tenant and database)
+    ///
+    /// Documents whose id already exists are replaced, all others are added.
+    pub async fn upsert_documents(
+        &self,
+        tenant: &str,
+        database: &str,
+        collection_name: &str,
+        request: UpsertDocumentsRequest,
+    ) -> Result<(), ChromaError> {
+        let url = self.api_url(tenant, database, &format!("collections/{}/upsert", collection_name));
+
+        debug!(
+            "Upserting {} documents into collection: {}",
+            request.ids.len(),
+            collection_name
+        );
+
+        let response = self
+            .client
+            .post(&url)
+            .headers(self.build_headers())
+            .json(&request)
+            .send_logged(self.log_requests)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(self.handle_error(status, error_text));
+        }
+
+        info!("Documents upserted successfully into collection: {}", collection_name);
+
+        Ok(())
+    }
+
+    /// Upsert documents in batches of at most `chunk_size` documents
+    ///
+    /// Keeps single requests below the batch size limit of the Chroma server. Batches are
+    /// sent in order; if one fails, the earlier batches stay stored and the error is returned.
+    ///
+    /// Returns `ChromaError::InvalidRequest` if `chunk_size` is zero or the documents,
+    /// metadatas or embeddings do not have one entry per id.
+    pub async fn upsert_documents_chunked(
+        &self,
+        tenant: &str,
+        database: &str,
+        collection_name: &str,
+        request: UpsertDocumentsRequest,
+        chunk_size: usize,
+    ) -> Result<(), ChromaError> {
+        if chunk_size == 0 {
+            return Err(ChromaError::InvalidRequest("Chunk size must be at least 1".to_string()));
+        }
+        let count = request.ids.len();
+        for (field, len) in [
+            ("documents", request.documents.as_ref().map(Vec::len)),
+            ("metadatas", request.metadatas.as_ref().map(Vec::len)),
+            ("embeddings", request.embeddings.as_ref().map(Vec::len)),
+        ] {
+            if let Some(len) = len
+                && len != count
+            {
+                return Err(ChromaError::InvalidRequest(format!(
+                    "Expected {} {}, got {}",
+                    count, field, len
+                )));
+            }
+        }
+
+        for start in (0..count).step_by(chunk_size) {
+            let end = (start + chunk_size).min(count);
+            let chunk = UpsertDocumentsRequest {
+                ids: request.ids[start..end].to_vec(),
+                documents: request.documents.as_ref().map(|d| d[start..end].to_vec()),
+                metadatas: request.metadatas.as_ref().map(|m| m[start..end].to_vec()),
+                embeddings: request.embeddings.as_ref().map(|e| e[start..end].to_vec()),
+            };
+            self.upsert_documents(tenant, database, collection_name, chunk).await?;
+        }
+
+        Ok(())
+    }
+
     /// Update documents in a collection (API v2 - requires tenant and database)
     pub async fn update_documents(
         &self,
@@ -355,7 +454,7 @@ impl ChromaClient {
             .post(&url)
             .headers(self.build_headers())
             .json(&request)
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -387,7 +486,7 @@ impl ChromaClient {
             .post(&url)
             .headers(self.build_headers())
             .json(&request)
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -402,6 +501,43 @@ impl ChromaClient {
         Ok(())
     }
 
+    /// Delete documents by id (API v2 - requires tenant and database)
+    ///
+    /// Ids that do not exist in the collection are ignored. No request is sent if `ids` is empty.
+    pub async fn delete_documents_by_ids(
+        &self,
+        tenant: &str,
+        database: &str,
+        collection_name: &str,
+        ids: &[String],
+    ) -> Result<(), ChromaError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let request = DeleteDocumentsRequest {
+            ids: Some(ids.to_vec()),
+            where_clause: None,
+            where_document: None,
+        };
+        self.delete_documents(tenant, database, collection_name, request).await
+    }
+
+    /// Delete all documents whose metadata matches `filter` (API v2 - requires tenant and database)
+    pub async fn delete_by_where(
+        &self,
+        tenant: &str,
+        database: &str,
+        collection_name: &str,
+        filter: MetadataFilter,
+    ) -> Result<(), ChromaError> {
+        let request = DeleteDocumentsRequest {
+            ids: None,
+            where_clause: Some(filter),
+            where_document: None,
+        };
+        self.delete_documents(tenant, database, collection_name, request).await
+    }
+
     /// Query a collection for similar documents (API v2 - requires tenant and database)
     pub async fn query(
         &self,
@@ -419,7 +555,7 @@ impl ChromaClient {
             .post(&url)
             .headers(self.build_headers())
             .json(&request)
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -456,7 +592,7 @@ impl ChromaClient {
             .post(&url)
             .headers(self.build_headers())
             .json(&request)
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -484,7 +620,7 @@ impl ChromaClient {
             .post(&url)
             .headers(self.build_headers())
             .json(&serde_json::json!({}))
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -521,7 +657,7 @@ impl ChromaClient {
             .client
             .get(&url)
             .headers(self.build_headers())
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -548,7 +684,7 @@ impl ChromaClient {
             .client
             .get(&url)
             .headers(self.build_headers())
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -575,7 +711,7 @@ impl ChromaClient {
             .client
             .get(&url)
             .headers(self.build_headers())
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -605,7 +741,7 @@ impl ChromaClient {
             .client
             .get(&url)
             .headers(self.build_headers())
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -643,7 +779,7 @@ impl ChromaClient {
         let response = client
             .get(&url)
             .headers(headers)
-            .send()
+            .send_logged(creds.log_requests)
             .await?;
 
         let status = response.status();
@@ -680,7 +816,7 @@ impl ChromaClient {
         let response = client
             .get(&url)
             .headers(headers)
-            .send()
+            .send_logged(creds.log_requests)
             .await?;
 
         let status = response.status();
@@ -718,7 +854,7 @@ impl ChromaClient {
             .post(&url)
             .headers(headers)
             .json(&request)
-            .send()
+            .send_logged(creds.log_requests)
             .await?;
 
         let status = response.status();
@@ -773,7 +909,7 @@ impl ChromaClient {
             .put(&url)
             .headers(headers)
             .json(&request)
-            .send()
+            .send_logged(creds.log_requests)
             .await?;
 
         let status = response.status();
@@ -833,7 +969,7 @@ impl ChromaClient {
         let response = client
             .delete(&url)
             .headers(headers)
-            .send()
+            .send_logged(creds.log_requests)
             .await?;
 
         let status = response.status();
@@ -870,7 +1006,7 @@ impl ChromaClient {
         let response = client
             .get(&url)
             .headers(headers)
-            .send()
+            .send_logged(creds.log_requests)
             .await?;
 
         let status = response.status();
@@ -922,7 +1058,7 @@ impl ChromaClient {
         let response = client
             .get(&tenant_url)
             .headers(headers.clone())
-            .send()
+            .send_logged(creds.log_requests)
             .await?;
         
         let status = response.status();
@@ -944,7 +1080,7 @@ impl ChromaClient {
             let db_response = client
                 .get(&db_url)
                 .headers(headers)
-                .send()
+                .send_logged(creds.log_requests)
                 .await?;
             
             let db_status = db_response.status();
@@ -965,7 +1101,7 @@ impl ChromaClient {
             let db_response = client
                 .get(&db_list_url)
                 .headers(headers)
-                .send()
+                .send_logged(creds.log_requests)
                 .await?;
             
             let db_status = db_response.status();
@@ -1036,3 +1172,99 @@ impl Default for ChromaClient {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{serve, MockResponse};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_upsert_documents_chunked() {
+        let (base_url, server) = serve(vec![
+            MockResponse::json(200, json!({})),
+            MockResponse::json(200, json!({})),
+        ])
+        .await;
+        let client = ChromaClient::with_base_url(base_url);
+
+        let request = UpsertDocumentsRequest {
+            ids: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            documents: Some(vec!["A".to_string(), "B".to_string(), "C".to_string()]),
+            metadatas: None,
+            embeddings: Some(vec![vec![0.1], vec![0.2], vec![0.3]]),
+        };
+        client
+            .upsert_documents_chunked("tenant", "db", "docs", request, 2)
+            .await
+            .expect("Upsert failed");
+
+        let requests = server.await.unwrap();
+        assert_eq!(
+            requests[0].request_line,
+            "POST /api/v2/tenants/tenant/databases/db/collections/docs/upsert HTTP/1.1"
+        );
+        let first: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        let second: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+        assert_eq!(first["ids"], json!(["a", "b"]));
+        assert_eq!(second, json!({"ids": ["c"], "documents": ["C"], "embeddings": [[0.3]]}));
+    }
+
+    #[tokio::test]
+    async fn test_upsert_documents_chunked_rejects_mismatched_lengths() {
+        let client = ChromaClient::with_base_url("http://127.0.0.1:1".to_string());
+        let request = UpsertDocumentsRequest {
+            ids: vec!["a".to_string(), "b".to_string()],
+            documents: None,
+            metadatas: None,
+            embeddings: Some(vec![vec![0.1]]),
+        };
+
+        assert!(matches!(
+            client.upsert_documents_chunked("tenant", "db", "docs", request, 10).await,
+            Err(ChromaError::InvalidRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_delete_documents_by_ids() {
+        let (base_url, server) = serve(vec![MockResponse::json(200, json!({}))]).await;
+        let client = ChromaClient::with_base_url(base_url);
+
+        client
+            .delete_documents_by_ids("tenant", "db", "docs", &["a".to_string(), "b".to_string()])
+            .await
+            .expect("Delete failed");
+        // Nothing to delete, no request
+        client
+            .delete_documents_by_ids("tenant", "db", "docs", &[])
+            .await
+            .expect("Delete failed");
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].request_line,
+            "POST /api/v2/tenants/tenant/databases/db/collections/docs/delete HTTP/1.1"
+        );
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body, json!({"ids": ["a", "b"]}));
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_where() {
+        let (base_url, server) = serve(vec![MockResponse::json(200, json!({}))]).await;
+        let client = ChromaClient::with_base_url(base_url);
+
+        let filter = MetadataFilter::Equals(
+            [("page_uuid".to_string(), json!("page-1"))].into_iter().collect(),
+        );
+        client
+            .delete_by_where("tenant", "db", "docs", filter)
+            .await
+            .expect("Delete failed");
+
+        let requests = server.await.unwrap();
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body, json!({"where": {"page_uuid": "page-1"}}));
+    }
+}
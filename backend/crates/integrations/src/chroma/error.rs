@@ -33,5 +33,8 @@ pub enum ChromaError {
 
     #[error("Missing required field: {0}")]
     MissingField(String),
+
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
 }
 
@@ -62,6 +62,18 @@ pub struct AddDocumentsRequest {
     pub embeddings: Option<Vec<Vec<f32>>>,
 }
 
+/// Request to add documents to a collection, or update them if their id exists
+#[derive(Debug, Clone, Serialize)]
+pub struct UpsertDocumentsRequest {
+    pub ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documents: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadatas: Option<Vec<DocumentMetadata>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeddings: Option<Vec<Vec<f32>>>,
+}
+
 /// Request to update documents in a collection
 #[derive(Debug, Clone, Serialize)]
 pub struct UpdateDocumentsRequest {
@@ -220,6 +232,12 @@ pub struct ChromaCredentials {
     /// API version (default: "v2")
     #[serde(default = "default_api_version")]
     pub api_version: String,
+
+    /// Log the requests of the `*_with_credentials` functions of `ChromaClient` (default: off)
+    ///
+    /// Not stored with the credentials, see `ChromaClient::with_request_logging`.
+    #[serde(skip)]
+    pub log_requests: bool,
 }
 
 fn default_true() -> bool {
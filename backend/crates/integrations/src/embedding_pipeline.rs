@@ -0,0 +1,269 @@
+//! Embed-then-store pipeline
+//!
+//! Creates embeddings for texts with OpenAI and stores them together with the texts
+//! in a Chroma collection.
+
+use crate::chroma::{ChromaClient, ChromaError, DocumentMetadata, UpsertDocumentsRequest};
+use crate::error::{IntegrationError, IntegrationErrorKind};
+use crate::openai::{OpenAIClient, OpenAIError};
+use thiserror::Error;
+use tracing::info;
+
+/// Number of documents sent to Chroma per upsert request
+pub const DEFAULT_UPSERT_CHUNK_SIZE: usize = 100;
+
+/// Chroma collection the documents are stored in
+#[derive(Debug, Clone)]
+pub struct ChromaCollectionRef {
+    pub tenant: String,
+    pub database: String,
+    pub collection: String,
+}
+
+/// Errors of [`embed_and_store`], keeping apart in which step it failed
+#[derive(Debug, Error)]
+pub enum EmbeddingPipelineError {
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    #[error("Failed to create embeddings: {0}")]
+    Embedding(#[source] OpenAIError),
+
+    #[error("Failed to store embeddings: {0}")]
+    Storage(#[source] ChromaError),
+}
+
+impl IntegrationError for EmbeddingPipelineError {
+    fn kind(&self) -> IntegrationErrorKind {
+        match self {
+            EmbeddingPipelineError::InvalidInput(_) => IntegrationErrorKind::InvalidRequest,
+            EmbeddingPipelineError::Embedding(e) => e.kind(),
+            EmbeddingPipelineError::Storage(e) => e.kind(),
+        }
+    }
+
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            EmbeddingPipelineError::InvalidInput(_) => self.kind().status_code(),
+            EmbeddingPipelineError::Embedding(e) => e.status_code(),
+            EmbeddingPipelineError::Storage(e) => e.status_code(),
+        }
+    }
+}
+
+/// Embed texts with OpenAI and upsert them into a Chroma collection
+///
+/// Every text is stored as a document with a generated UUID as id, its embedding and,
+/// if given, the metadata at the same position.
+///
+/// # Arguments
+/// * `openai` - Client used to create the embeddings
+/// * `chroma` - Client used to store the documents
+/// * `target` - Collection the documents are stored in
+/// * `model` - OpenAI embedding model (e.g., "text-embedding-3-small")
+/// * `texts` - Texts to embed and store
+/// * `metadatas` - Optional metadata per text
+///
+/// # Returns
+/// The ids of the stored documents, in the order of `texts`
+///
+/// # Errors
+/// - `InvalidInput` if `texts` is empty or `metadatas` has a different length
+/// - `Embedding` if OpenAI fails or does not return one embedding per text; nothing is stored then
+/// - `Storage` if Chroma fails; earlier batches may have been stored
+pub async fn embed_and_store(
+    openai: &OpenAIClient,
+    chroma: &ChromaClient,
+    target: &ChromaCollectionRef,
+    model: &str,
+    texts: Vec<String>,
+    metadatas: Option<Vec<DocumentMetadata>>,
+) -> Result<Vec<String>, EmbeddingPipelineError> {
+    if texts.is_empty() {
+        return Err(EmbeddingPipelineError::InvalidInput(
+            "At least one text is required".to_string(),
+        ));
+    }
+    if let Some(metadatas) = &metadatas
+        && metadatas.len() != texts.len()
+    {
+        return Err(EmbeddingPipelineError::InvalidInput(format!(
+            "Expected {} metadatas, got {}",
+            texts.len(),
+            metadatas.len()
+        )));
+    }
+
+    let data = openai
+        .create_embeddings(model, texts.clone())
+        .await
+        .map_err(EmbeddingPipelineError::Embedding)?
+        .data;
+
+    // Every text needs exactly one embedding, otherwise texts would be stored with another text's vector
+    if data.len() != texts.len() || data.iter().enumerate().any(|(i, embedding)| embedding.index != i) {
+        return Err(EmbeddingPipelineError::Embedding(OpenAIError::InvalidResponse(format!(
+            "Expected one embedding per text for {} texts, got indices {:?}",
+            texts.len(),
+            data.iter().map(|embedding| embedding.index).collect::<Vec<_>>()
+        ))));
+    }
+    let embeddings = data.into_iter().map(|embedding| embedding.embedding).collect();
+
+    let ids: Vec<String> = texts.iter().map(|_| uuid::Uuid::new_v4().to_string()).collect();
+    let request = UpsertDocumentsRequest {
+        ids: ids.clone(),
+        documents: Some(texts),
+        metadatas,
+        embeddings: Some(embeddings),
+    };
+    chroma
+        .upsert_documents_chunked(
+            &target.tenant,
+            &target.database,
+            &target.collection,
+            request,
+            DEFAULT_UPSERT_CHUNK_SIZE,
+        )
+        .await
+        .map_err(EmbeddingPipelineError::Storage)?;
+
+    info!("Stored {} embeddings in collection: {}", ids.len(), target.collection);
+
+    Ok(ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{serve, MockResponse};
+    use serde_json::json;
+
+    fn target() -> ChromaCollectionRef {
+        ChromaCollectionRef {
+            tenant: "tenant".to_string(),
+            database: "db".to_string(),
+            collection: "docs".to_string(),
+        }
+    }
+
+    fn embeddings_response() -> serde_json::Value {
+        json!({
+            "object": "list",
+            "model": "text-embedding-3-small",
+            "data": [
+                {"object": "embedding", "index": 0, "embedding": [0.1, 0.2]},
+                {"object": "embedding", "index": 1, "embedding": [0.3, 0.4]}
+            ],
+            "usage": {"prompt_tokens": 4, "total_tokens": 4}
+        })
+    }
+
+    #[tokio::test]
+    async fn test_embeddings_are_forwarded_to_chroma() {
+        let (openai_url, openai_server) = serve(vec![MockResponse::json(200, embeddings_response())]).await;
+        let (chroma_url, chroma_server) = serve(vec![MockResponse::json(200, json!({}))]).await;
+        let openai = OpenAIClient::with_base_url("sk-valid".to_string(), openai_url);
+        let chroma = ChromaClient::with_base_url(chroma_url);
+
+        let metadata: DocumentMetadata = [("source".to_string(), json!("faq"))].into();
+        let ids = embed_and_store(
+            &openai,
+            &chroma,
+            &target(),
+            "text-embedding-3-small",
+            vec!["first".to_string(), "second".to_string()],
+            Some(vec![metadata.clone(), metadata]),
+        )
+        .await
+        .expect("Pipeline failed");
+        assert_eq!(ids.len(), 2);
+
+        assert_eq!(openai_server.await.unwrap().len(), 1);
+        let requests = chroma_server.await.unwrap();
+        assert_eq!(
+            requests[0].request_line,
+            "POST /api/v2/tenants/tenant/databases/db/collections/docs/upsert HTTP/1.1"
+        );
+        let stored: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(stored["ids"], json!(ids));
+        assert_eq!(stored["documents"], json!(["first", "second"]));
+        assert_eq!(stored["embeddings"], json!([[0.1, 0.2], [0.3, 0.4]]));
+        assert_eq!(stored["metadatas"], json!([{"source": "faq"}, {"source": "faq"}]));
+    }
+
+    #[tokio::test]
+    async fn test_errors_name_the_failed_step() {
+        let (openai_url, _openai_server) = serve(vec![MockResponse::json(401, json!({"error": {}}))]).await;
+        let openai = OpenAIClient::with_base_url("sk-invalid".to_string(), openai_url);
+        let chroma = ChromaClient::with_base_url("http://127.0.0.1:1".to_string());
+
+        let error = embed_and_store(&openai, &chroma, &target(), "model", vec!["text".to_string()], None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, EmbeddingPipelineError::Embedding(OpenAIError::InvalidApiKey)));
+        assert_eq!(error.kind(), IntegrationErrorKind::Authentication);
+
+        let (openai_url, _openai_server) = serve(vec![MockResponse::json(200, embeddings_response())]).await;
+        let (chroma_url, _chroma_server) = serve(vec![MockResponse::json(404, json!({"error": "NotFound"}))]).await;
+        let openai = OpenAIClient::with_base_url("sk-valid".to_string(), openai_url);
+        let chroma = ChromaClient::with_base_url(chroma_url);
+
+        let error = embed_and_store(
+            &openai,
+            &chroma,
+            &target(),
+            "model",
+            vec!["first".to_string(), "second".to_string()],
+            None,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(error, EmbeddingPipelineError::Storage(ChromaError::CollectionNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_embeddings_are_matched_to_texts_by_index() {
+        let shuffled = json!({
+            "object": "list",
+            "model": "text-embedding-3-small",
+            "data": [
+                {"object": "embedding", "index": 1, "embedding": [0.3, 0.4]},
+                {"object": "embedding", "index": 0, "embedding": [0.1, 0.2]}
+            ],
+            "usage": {"prompt_tokens": 4, "total_tokens": 4}
+        });
+        let (openai_url, _openai_server) = serve(vec![MockResponse::json(200, shuffled)]).await;
+        let (chroma_url, chroma_server) = serve(vec![MockResponse::json(200, json!({}))]).await;
+        let openai = OpenAIClient::with_base_url("sk-valid".to_string(), openai_url);
+        let chroma = ChromaClient::with_base_url(chroma_url);
+
+        let texts = vec!["first".to_string(), "second".to_string()];
+        embed_and_store(&openai, &chroma, &target(), "model", texts.clone(), None)
+            .await
+            .expect("Pipeline failed");
+        let requests = chroma_server.await.unwrap();
+        let stored: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(stored["embeddings"], json!([[0.1, 0.2], [0.3, 0.4]]));
+
+        let duplicated = json!({
+            "object": "list",
+            "model": "text-embedding-3-small",
+            "data": [
+                {"object": "embedding", "index": 0, "embedding": [0.1, 0.2]},
+                {"object": "embedding", "index": 0, "embedding": [0.3, 0.4]}
+            ],
+            "usage": {"prompt_tokens": 4, "total_tokens": 4}
+        });
+        let (openai_url, _openai_server) = serve(vec![MockResponse::json(200, duplicated)]).await;
+        let openai = OpenAIClient::with_base_url("sk-valid".to_string(), openai_url);
+        // Nothing may be stored, so Chroma is never contacted
+        let chroma = ChromaClient::with_base_url("http://127.0.0.1:1".to_string());
+
+        let error = embed_and_store(&openai, &chroma, &target(), "model", texts, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, EmbeddingPipelineError::Embedding(OpenAIError::InvalidResponse(_))));
+        assert_eq!(error.kind(), IntegrationErrorKind::InvalidResponse);
+    }
+}
@@ -0,0 +1,268 @@
+//! Shared Integration Error Classification
+//!
+//! Lets callers such as the workflow engine handle the errors of all integration
+//! clients uniformly, e.g. to decide whether a failed call should be retried.
+
+use crate::chroma::ChromaError;
+use crate::github::GitHubError;
+use crate::gitlab::GitLabError;
+use crate::http::HttpError;
+use crate::jira::JiraError;
+use crate::openai::OpenAIError;
+
+/// Category of an integration error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrationErrorKind {
+    /// The service could not be reached or did not answer in time
+    Connection,
+    /// Credentials are missing, invalid or lack permissions
+    Authentication,
+    /// The service rejected the request because of a rate limit
+    RateLimited,
+    /// The requested resource does not exist
+    NotFound,
+    /// The resource already exists
+    Conflict,
+    /// The request was invalid
+    InvalidRequest,
+    /// The response could not be parsed
+    InvalidResponse,
+    /// The service answered with another error
+    Api,
+}
+
+impl IntegrationErrorKind {
+    /// Typical HTTP status code of errors of this kind
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            IntegrationErrorKind::Authentication => Some(401),
+            IntegrationErrorKind::RateLimited => Some(429),
+            IntegrationErrorKind::NotFound => Some(404),
+            IntegrationErrorKind::Conflict => Some(409),
+            IntegrationErrorKind::InvalidRequest => Some(400),
+            IntegrationErrorKind::Connection
+            | IntegrationErrorKind::InvalidResponse
+            | IntegrationErrorKind::Api => None,
+        }
+    }
+
+    fn from_status(status: u16) -> Self {
+        match status {
+            401 | 403 => IntegrationErrorKind::Authentication,
+            404 => IntegrationErrorKind::NotFound,
+            409 => IntegrationErrorKind::Conflict,
+            429 => IntegrationErrorKind::RateLimited,
+            400..=499 => IntegrationErrorKind::InvalidRequest,
+            _ => IntegrationErrorKind::Api,
+        }
+    }
+}
+
+/// Uniform classification of the errors of all integration clients
+pub trait IntegrationError: std::error::Error {
+    /// Category of the error
+    fn kind(&self) -> IntegrationErrorKind;
+
+    /// HTTP status code of the failed response, if known
+    fn status_code(&self) -> Option<u16> {
+        self.kind().status_code()
+    }
+
+    /// Whether retrying the same request later may succeed
+    ///
+    /// True for rate limits, connection problems and server errors (5xx).
+    fn is_retryable(&self) -> bool {
+        matches!(self.kind(), IntegrationErrorKind::RateLimited | IntegrationErrorKind::Connection)
+            || self.status_code().is_some_and(|status| status >= 500)
+    }
+}
+
+/// Classify a failed HTTP request
+fn reqwest_kind(error: &reqwest::Error) -> IntegrationErrorKind {
+    if let Some(status) = error.status() {
+        IntegrationErrorKind::from_status(status.as_u16())
+    } else if error.is_decode() {
+        IntegrationErrorKind::InvalidResponse
+    } else if error.is_builder() {
+        IntegrationErrorKind::InvalidRequest
+    } else {
+        IntegrationErrorKind::Connection
+    }
+}
+
+fn reqwest_status(error: &reqwest::Error) -> Option<u16> {
+    error.status().map(|status| status.as_u16())
+}
+
+impl IntegrationError for ChromaError {
+    fn kind(&self) -> IntegrationErrorKind {
+        match self {
+            ChromaError::HttpError(e) => reqwest_kind(e),
+            ChromaError::ApiError(_) => IntegrationErrorKind::Api,
+            ChromaError::InvalidApiKey => IntegrationErrorKind::Authentication,
+            ChromaError::CollectionNotFound(_) => IntegrationErrorKind::NotFound,
+            ChromaError::CollectionExists(_) => IntegrationErrorKind::Conflict,
+            ChromaError::RateLimitExceeded => IntegrationErrorKind::RateLimited,
+            ChromaError::SerializationError(_) | ChromaError::InvalidResponse(_) => {
+                IntegrationErrorKind::InvalidResponse
+            }
+            ChromaError::InvalidEmbeddingDimensions { .. }
+            | ChromaError::MissingField(_)
+            | ChromaError::InvalidRequest(_) => IntegrationErrorKind::InvalidRequest,
+        }
+    }
+
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            ChromaError::HttpError(e) => reqwest_status(e),
+            _ => self.kind().status_code(),
+        }
+    }
+}
+
+impl IntegrationError for GitHubError {
+    fn kind(&self) -> IntegrationErrorKind {
+        match self {
+            GitHubError::HttpError(e) => reqwest_kind(e),
+            GitHubError::ApiError(_) | GitHubError::Unknown(_) => IntegrationErrorKind::Api,
+            GitHubError::AuthenticationError(_) => IntegrationErrorKind::Authentication,
+            GitHubError::RateLimitError(_) => IntegrationErrorKind::RateLimited,
+            GitHubError::NotFound(_) => IntegrationErrorKind::NotFound,
+            GitHubError::InvalidRequest(_) => IntegrationErrorKind::InvalidRequest,
+            GitHubError::JsonError(_) => IntegrationErrorKind::InvalidResponse,
+        }
+    }
+
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            GitHubError::HttpError(e) => reqwest_status(e),
+            _ => self.kind().status_code(),
+        }
+    }
+}
+
+impl IntegrationError for GitLabError {
+    fn kind(&self) -> IntegrationErrorKind {
+        match self {
+            GitLabError::HttpError(e) => reqwest_kind(e),
+            GitLabError::ApiError(_) | GitLabError::Unknown(_) => IntegrationErrorKind::Api,
+            GitLabError::AuthenticationError(_) | GitLabError::InvalidWebhookToken => {
+                IntegrationErrorKind::Authentication
+            }
+            GitLabError::RateLimitError(_) => IntegrationErrorKind::RateLimited,
+            GitLabError::NotFound(_) => IntegrationErrorKind::NotFound,
+            GitLabError::InvalidRequest(_) => IntegrationErrorKind::InvalidRequest,
+            GitLabError::JsonError(_) => IntegrationErrorKind::InvalidResponse,
+        }
+    }
+
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            GitLabError::HttpError(e) => reqwest_status(e),
+            _ => self.kind().status_code(),
+        }
+    }
+}
+
+impl IntegrationError for JiraError {
+    fn kind(&self) -> IntegrationErrorKind {
+        match self {
+            JiraError::HttpError(e) => reqwest_kind(e),
+            JiraError::JsonError(_) => IntegrationErrorKind::InvalidResponse,
+            JiraError::ApiError(_) => IntegrationErrorKind::Api,
+            JiraError::AuthenticationError(_) => IntegrationErrorKind::Authentication,
+            JiraError::InvalidRequest(_) => IntegrationErrorKind::InvalidRequest,
+        }
+    }
+
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            JiraError::HttpError(e) => reqwest_status(e),
+            _ => self.kind().status_code(),
+        }
+    }
+}
+
+impl IntegrationError for OpenAIError {
+    fn kind(&self) -> IntegrationErrorKind {
+        match self {
+            OpenAIError::HttpError(e) => reqwest_kind(e),
+            OpenAIError::ApiError(_) => IntegrationErrorKind::Api,
+            OpenAIError::InvalidApiKey => IntegrationErrorKind::Authentication,
+            OpenAIError::RateLimitExceeded => IntegrationErrorKind::RateLimited,
+            OpenAIError::SerializationError(_)
+            | OpenAIError::InvalidResponse(_)
+            | OpenAIError::InvalidJsonContent(_) => IntegrationErrorKind::InvalidResponse,
+        }
+    }
+
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            OpenAIError::HttpError(e) => reqwest_status(e),
+            _ => self.kind().status_code(),
+        }
+    }
+}
+
+impl IntegrationError for HttpError {
+    fn kind(&self) -> IntegrationErrorKind {
+        match self {
+            HttpError::HttpError(e) => reqwest_kind(e),
+            HttpError::InvalidRequest(_) => IntegrationErrorKind::InvalidRequest,
+        }
+    }
+
+    fn status_code(&self) -> Option<u16> {
+        match self {
+            HttpError::HttpError(e) => reqwest_status(e),
+            _ => self.kind().status_code(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rate_limited(error: &dyn IntegrationError) {
+        assert_eq!(error.kind(), IntegrationErrorKind::RateLimited, "{}", error);
+        assert_eq!(error.status_code(), Some(429));
+        assert!(error.is_retryable());
+    }
+
+    fn assert_auth_error(error: &dyn IntegrationError) {
+        assert_eq!(error.kind(), IntegrationErrorKind::Authentication, "{}", error);
+        assert_eq!(error.status_code(), Some(401));
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn test_rate_limit_and_auth_classification() {
+        assert_rate_limited(&ChromaError::RateLimitExceeded);
+        assert_auth_error(&ChromaError::InvalidApiKey);
+
+        assert_rate_limited(&GitHubError::RateLimitError("retry after 60".to_string()));
+        assert_auth_error(&GitHubError::AuthenticationError("Bad credentials".to_string()));
+
+        assert_rate_limited(&GitLabError::RateLimitError("retry later".to_string()));
+        assert_auth_error(&GitLabError::AuthenticationError("401 Unauthorized".to_string()));
+
+        assert_rate_limited(&OpenAIError::RateLimitExceeded);
+        assert_auth_error(&OpenAIError::InvalidApiKey);
+
+        // The Jira client has no dedicated rate limit error
+        assert_auth_error(&JiraError::AuthenticationError("Authentication failed".to_string()));
+        assert!(!JiraError::ApiError("HTTP 500".to_string()).is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_connection_errors_are_retryable() {
+        // Nothing listens on the discard port
+        let error = reqwest::get("http://127.0.0.1:9").await.unwrap_err();
+        let error = GitHubError::from(error);
+
+        assert_eq!(error.kind(), IntegrationErrorKind::Connection);
+        assert_eq!(error.status_code(), None);
+        assert!(error.is_retryable());
+    }
+}
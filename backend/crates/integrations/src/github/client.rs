@@ -4,6 +4,7 @@
 
 use crate::github::error::GitHubError;
 use crate::github::types::*;
+use crate::http::SendLogged;
 use reqwest::Client;
 use reqwest::StatusCode;
 use tracing::{debug, error, info, warn};
@@ -14,6 +15,7 @@ const GITHUB_API_VERSION: &str = "2022-11-28";
 /// Client for interacting with the GitHub API
 pub struct GitHubClient {
     client: Client,
+    log_requests: bool,
     token: Option<String>,
     base_url: String,
 }
@@ -23,6 +25,7 @@ impl GitHubClient {
     pub fn new() -> Self {
         Self {
             client: Client::new(),
+            log_requests: false,
             token: None,
             base_url: GITHUB_API_BASE.to_string(),
         }
@@ -32,6 +35,7 @@ impl GitHubClient {
     pub fn with_token(token: String) -> Self {
         Self {
             client: Client::new(),
+            log_requests: false,
             token: Some(token),
             base_url: GITHUB_API_BASE.to_string(),
         }
@@ -41,11 +45,20 @@ impl GitHubClient {
     pub fn with_base_url(token: Option<String>, base_url: String) -> Self {
         Self {
             client: Client::new(),
+            log_requests: false,
             token,
             base_url,
         }
     }
 
+    /// Log every request at `debug` level, with secrets redacted (default: off)
+    ///
+    /// Uses the request logging of [`HttpTransport`](crate::http::HttpTransport).
+    pub fn with_request_logging(mut self, enabled: bool) -> Self {
+        self.log_requests = enabled;
+        self
+    }
+
     /// Build request headers with authentication if token is available
     fn build_headers(&self) -> reqwest::header::HeaderMap {
         let mut headers = reqwest::header::HeaderMap::new();
@@ -114,6 +127,14 @@ impl GitHubClient {
                 let text = response.text().await.unwrap_or_default();
                 Err(GitHubError::NotFound(format!("Not found: {}", text)))
             }
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => {
+                // 422 is returned for validation failures, e.g. an unknown milestone
+                let text = response.text().await.unwrap_or_default();
+                Err(GitHubError::InvalidRequest(format!(
+                    "HTTP {}: {}",
+                    status, text
+                )))
+            }
             StatusCode::TOO_MANY_REQUESTS => {
                 let retry_after = headers
                     .get("retry-after")
@@ -137,6 +158,26 @@ impl GitHubClient {
         }
     }
 
+    /// Validate the connection and token without side effects
+    ///
+    /// Requests the authenticated user (`GET /user`). Returns
+    /// `GitHubError::AuthenticationError` if the token is missing or rejected,
+    /// `GitHubError::HttpError` if the API is not reachable.
+    pub async fn validate_connection(&self) -> Result<(), GitHubError> {
+        let url = format!("{}/user", self.base_url);
+        debug!("Validating GitHub connection: {}", url);
+
+        let response = self
+            .client
+            .get(&url)
+            .headers(self.build_headers())
+            .send_logged(self.log_requests)
+            .await?;
+
+        Self::handle_response::<serde_json::Value>(response).await?;
+        Ok(())
+    }
+
     /// Get all organizations (paginated)
     /// 
     /// Returns a list of all organizations. This endpoint supports pagination.
@@ -181,7 +222,7 @@ impl GitHubClient {
                 .client
                 .get(&url)
                 .headers(self.build_headers())
-                .send()
+                .send_logged(self.log_requests)
                 .await?;
 
             // Extract headers before consuming response
@@ -217,7 +258,7 @@ impl GitHubClient {
             .client
             .get(&url)
             .headers(self.build_headers())
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let organization: OrganizationFull = Self::handle_response(response).await?;
@@ -289,7 +330,7 @@ impl GitHubClient {
                 .client
                 .get(&url)
                 .headers(self.build_headers())
-                .send()
+                .send_logged(self.log_requests)
                 .await?;
 
             // Extract headers before consuming response
@@ -363,7 +404,7 @@ impl GitHubClient {
                 .client
                 .get(&url)
                 .headers(self.build_headers())
-                .send()
+                .send_logged(self.log_requests)
                 .await?;
 
             // Extract headers before consuming response
@@ -404,7 +445,7 @@ impl GitHubClient {
             .client
             .get(&url)
             .headers(self.build_headers())
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let repository: RepositoryFull = Self::handle_response(response).await?;
@@ -440,7 +481,7 @@ impl GitHubClient {
             .post(&url)
             .headers(self.build_headers())
             .json(&request)
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let repository: RepositoryFull = Self::handle_response(response).await?;
@@ -478,7 +519,7 @@ impl GitHubClient {
             .post(&url)
             .headers(self.build_headers())
             .json(&request)
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let repository: RepositoryFull = Self::handle_response(response).await?;
@@ -486,6 +527,152 @@ impl GitHubClient {
         Ok(repository)
     }
 
+    /// Add labels to an issue or pull request
+    ///
+    /// Labels that do not exist in the repository yet are created.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The repository owner (user or organization name)
+    /// * `repo` - The repository name
+    /// * `issue_number` - The number of the issue or pull request
+    /// * `labels` - Names of the labels to add
+    ///
+    /// # Returns
+    ///
+    /// All labels of the issue after adding
+    ///
+    /// # Note
+    ///
+    /// Requires authentication with write access to issues of the repository.
+    pub async fn add_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        labels: &[String],
+    ) -> Result<Vec<Label>, GitHubError> {
+        if labels.is_empty() {
+            return Err(GitHubError::InvalidRequest(
+                "At least one label is required".to_string(),
+            ));
+        }
+
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}/labels",
+            self.base_url, owner, repo, issue_number
+        );
+        debug!("Adding labels to issue {}/{}#{}: {:?}", owner, repo, issue_number, labels);
+
+        let response = self
+            .client
+            .post(&url)
+            .headers(self.build_headers())
+            .json(&serde_json::json!({ "labels": labels }))
+            .send_logged(self.log_requests)
+            .await?;
+
+        let labels: Vec<Label> = Self::handle_response(response).await?;
+        info!("Issue {}/{}#{} has {} labels", owner, repo, issue_number, labels.len());
+        Ok(labels)
+    }
+
+    /// Remove a label from an issue or pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The repository owner (user or organization name)
+    /// * `repo` - The repository name
+    /// * `issue_number` - The number of the issue or pull request
+    /// * `label` - Name of the label to remove
+    ///
+    /// # Returns
+    ///
+    /// The labels remaining on the issue
+    ///
+    /// # Note
+    ///
+    /// Returns `GitHubError::NotFound` if the issue does not have the label.
+    pub async fn remove_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        label: &str,
+    ) -> Result<Vec<Label>, GitHubError> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}/labels/{}",
+            self.base_url,
+            owner,
+            repo,
+            issue_number,
+            encode_path_segment(label)
+        );
+        debug!("Removing label from issue {}/{}#{}: {}", owner, repo, issue_number, label);
+
+        let response = self
+            .client
+            .delete(&url)
+            .headers(self.build_headers())
+            .send_logged(self.log_requests)
+            .await?;
+
+        let labels: Vec<Label> = Self::handle_response(response).await?;
+        info!("Removed label {} from issue {}/{}#{}", label, owner, repo, issue_number);
+        Ok(labels)
+    }
+
+    /// Set or clear the milestone of an issue or pull request
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The repository owner (user or organization name)
+    /// * `repo` - The repository name
+    /// * `issue_number` - The number of the issue or pull request
+    /// * `milestone_number` - Number of the milestone, `None` removes the milestone
+    ///
+    /// # Returns
+    ///
+    /// The updated `Issue`
+    ///
+    /// # Note
+    ///
+    /// Returns `GitHubError::InvalidRequest` if the milestone does not exist.
+    pub async fn set_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        milestone_number: Option<u64>,
+    ) -> Result<Issue, GitHubError> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}",
+            self.base_url, owner, repo, issue_number
+        );
+        debug!(
+            "Setting milestone of issue {}/{}#{}: {:?}",
+            owner, repo, issue_number, milestone_number
+        );
+
+        let response = self
+            .client
+            .patch(&url)
+            .headers(self.build_headers())
+            .json(&serde_json::json!({ "milestone": milestone_number }))
+            .send_logged(self.log_requests)
+            .await?;
+
+        let issue: Issue = Self::handle_response(response).await?;
+        info!(
+            "Milestone of issue {}/{}#{} is now {:?}",
+            owner,
+            repo,
+            issue_number,
+            issue.milestone.as_ref().map(|m| &m.title)
+        );
+        Ok(issue)
+    }
+
     /// Extract next page URL from Link header (for pagination)
     fn get_next_page_url(&self, headers: &reqwest::header::HeaderMap) -> Option<String> {
         headers
@@ -508,9 +695,171 @@ impl GitHubClient {
     }
 }
 
+/// Percent-encode a value for use as a single URL path segment
+fn encode_path_segment(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
 impl Default for GitHubClient {
     fn default() -> Self {
         Self::new()
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{serve, MockResponse};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_validate_connection() {
+        let (base_url, server) = serve(vec![MockResponse::json(200, json!({"login": "octocat"}))]).await;
+        let client = GitHubClient::with_base_url(Some("ghp_valid".to_string()), base_url);
+
+        client.validate_connection().await.expect("Connection should be valid");
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "GET /user HTTP/1.1");
+        assert_eq!(requests[0].header("authorization"), Some("Bearer ghp_valid"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_connection_unauthorized() {
+        let (base_url, _server) = serve(vec![MockResponse::json(401, json!({"message": "Bad credentials"}))]).await;
+        let client = GitHubClient::with_base_url(Some("ghp_invalid".to_string()), base_url);
+
+        assert!(matches!(
+            client.validate_connection().await,
+            Err(GitHubError::AuthenticationError(_))
+        ));
+    }
+
+    fn label(id: u64, name: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "node_id": format!("LA_{}", id),
+            "url": format!("https://api.github.com/repos/octocat/hello/labels/{}", name),
+            "name": name,
+            "color": "d73a4a",
+            "default": false
+        })
+    }
+
+    fn user() -> serde_json::Value {
+        json!({
+            "login": "octocat",
+            "id": 1,
+            "node_id": "MDQ6VXNlcjE=",
+            "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+            "url": "https://api.github.com/users/octocat",
+            "html_url": "https://github.com/octocat",
+            "type": "User",
+            "site_admin": false
+        })
+    }
+
+    #[tokio::test]
+    async fn test_add_labels() {
+        let (base_url, server) = serve(vec![MockResponse::json(
+            200,
+            json!([label(1, "bug"), label(2, "needs triage")]),
+        )])
+        .await;
+        let client = GitHubClient::with_base_url(Some("ghp_valid".to_string()), base_url);
+
+        let labels = client
+            .add_labels("octocat", "hello", 7, &["needs triage".to_string()])
+            .await
+            .expect("Failed to add labels");
+        assert_eq!(labels.iter().map(|l| l.name.as_str()).collect::<Vec<_>>(), vec!["bug", "needs triage"]);
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "POST /repos/octocat/hello/issues/7/labels HTTP/1.1");
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body, json!({"labels": ["needs triage"]}));
+    }
+
+    #[tokio::test]
+    async fn test_remove_label_encodes_name() {
+        let (base_url, server) = serve(vec![MockResponse::json(200, json!([label(1, "bug")]))]).await;
+        let client = GitHubClient::with_base_url(Some("ghp_valid".to_string()), base_url);
+
+        let labels = client
+            .remove_label("octocat", "hello", 7, "needs triage")
+            .await
+            .expect("Failed to remove label");
+        assert_eq!(labels.len(), 1);
+
+        let requests = server.await.unwrap();
+        assert_eq!(
+            requests[0].request_line,
+            "DELETE /repos/octocat/hello/issues/7/labels/needs%20triage HTTP/1.1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_milestone() {
+        let issue = json!({
+            "id": 1,
+            "node_id": "I_1",
+            "url": "https://api.github.com/repos/octocat/hello/issues/7",
+            "repository_url": "https://api.github.com/repos/octocat/hello",
+            "labels_url": "https://api.github.com/repos/octocat/hello/issues/7/labels{/name}",
+            "comments_url": "https://api.github.com/repos/octocat/hello/issues/7/comments",
+            "events_url": "https://api.github.com/repos/octocat/hello/issues/7/events",
+            "html_url": "https://github.com/octocat/hello/issues/7",
+            "number": 7,
+            "state": "open",
+            "title": "Crash on start",
+            "user": user(),
+            "milestone": {
+                "url": "https://api.github.com/repos/octocat/hello/milestones/3",
+                "html_url": "https://github.com/octocat/hello/milestone/3",
+                "labels_url": "https://api.github.com/repos/octocat/hello/milestones/3/labels",
+                "id": 1002,
+                "node_id": "MI_3",
+                "number": 3,
+                "state": "open",
+                "title": "v1.0",
+                "created_at": "2025-01-01T00:00:00Z"
+            },
+            "created_at": "2025-01-02T00:00:00Z"
+        });
+        let (base_url, server) = serve(vec![MockResponse::json(200, issue)]).await;
+        let client = GitHubClient::with_base_url(Some("ghp_valid".to_string()), base_url);
+
+        let issue = client
+            .set_milestone("octocat", "hello", 7, Some(3))
+            .await
+            .expect("Failed to set milestone");
+        assert_eq!(issue.milestone.map(|m| m.title), Some("v1.0".to_string()));
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "PATCH /repos/octocat/hello/issues/7 HTTP/1.1");
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body, json!({"milestone": 3}));
+    }
+
+    #[tokio::test]
+    async fn test_set_unknown_milestone_is_invalid_request() {
+        let (base_url, _server) = serve(vec![MockResponse::json(
+            422,
+            json!({"message": "Validation Failed", "errors": [{"field": "milestone", "code": "invalid"}]}),
+        )])
+        .await;
+        let client = GitHubClient::with_base_url(Some("ghp_valid".to_string()), base_url);
+
+        assert!(matches!(
+            client.set_milestone("octocat", "hello", 7, Some(99)).await,
+            Err(GitHubError::InvalidRequest(_))
+        ));
+    }
+}
@@ -4,15 +4,17 @@
 
 use crate::gitlab::error::GitLabError;
 use crate::gitlab::types::*;
+use crate::http::SendLogged;
 use reqwest::Client;
 use reqwest::StatusCode;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 const GITLAB_API_BASE: &str = "https://gitlab.com/api/v4";
 
 /// Client for interacting with the GitLab API
 pub struct GitLabClient {
     client: Client,
+    log_requests: bool,
     token: Option<String>,
     base_url: String,
 }
@@ -22,6 +24,7 @@ impl GitLabClient {
     pub fn new() -> Self {
         Self {
             client: Client::new(),
+            log_requests: false,
             token: None,
             base_url: GITLAB_API_BASE.to_string(),
         }
@@ -31,6 +34,7 @@ impl GitLabClient {
     pub fn with_token(token: String) -> Self {
         Self {
             client: Client::new(),
+            log_requests: false,
             token: Some(token),
             base_url: GITLAB_API_BASE.to_string(),
         }
@@ -40,11 +44,20 @@ impl GitLabClient {
     pub fn with_base_url(token: Option<String>, base_url: String) -> Self {
         Self {
             client: Client::new(),
+            log_requests: false,
             token,
             base_url,
         }
     }
 
+    /// Log every request at `debug` level, with secrets redacted (default: off)
+    ///
+    /// Uses the request logging of [`HttpTransport`](crate::http::HttpTransport).
+    pub fn with_request_logging(mut self, enabled: bool) -> Self {
+        self.log_requests = enabled;
+        self
+    }
+
     /// Build request headers with authentication if token is available
     fn build_headers(&self) -> reqwest::header::HeaderMap {
         let mut headers = reqwest::header::HeaderMap::new();
@@ -114,7 +127,6 @@ impl GitLabClient {
     }
 
     /// Extract pagination information from response headers
-    #[allow(dead_code)]
     fn extract_pagination_info(&self, response: &reqwest::Response) -> PaginationInfo {
         PaginationInfo {
             total: response
@@ -159,12 +171,21 @@ impl GitLabClient {
             .client
             .get(&url)
             .headers(self.build_headers())
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         self.handle_response(response).await
     }
 
+    /// Validate the connection and token without side effects
+    ///
+    /// Requests the authenticated user (`GET /user`). Returns
+    /// `GitLabError::AuthenticationError` if the token is missing or rejected,
+    /// `GitLabError::HttpError` if the API is not reachable.
+    pub async fn validate_connection(&self) -> Result<(), GitLabError> {
+        self.get_current_user().await.map(|_| ())
+    }
+
     /// List all projects
     pub async fn list_projects(
         &self,
@@ -184,10 +205,74 @@ impl GitLabClient {
             }
         }
 
-        let response = request.send().await?;
+        let response = request.send_logged(self.log_requests).await?;
         self.handle_response(response).await
     }
 
+    /// List all projects, following the pagination until the last page
+    ///
+    /// # Arguments
+    /// * `per_page` - Number of projects per request (1-100, default: 100)
+    /// * `max_pages` - Maximum number of pages to fetch, further pages are skipped
+    pub async fn list_all_projects(
+        &self,
+        per_page: Option<u32>,
+        max_pages: u32,
+    ) -> Result<Vec<Project>, GitLabError> {
+        self.list_all("projects", per_page, max_pages).await
+    }
+
+    /// Fetch all pages of a list endpoint
+    ///
+    /// Uses the `X-Total-Pages` header to detect the last page. GitLab omits it for
+    /// very large collections, then `X-Next-Page` is used instead.
+    async fn list_all<T: for<'de> Deserialize<'de>>(
+        &self,
+        endpoint: &str,
+        per_page: Option<u32>,
+        max_pages: u32,
+    ) -> Result<Vec<T>, GitLabError> {
+        let url = self.build_url(endpoint);
+        let per_page = per_page.unwrap_or(100).clamp(1, 100);
+        let mut items = Vec::new();
+        let mut page = 1;
+
+        loop {
+            debug!("Fetching {} page {} from GitLab API", endpoint, page);
+
+            let response = self
+                .client
+                .get(&url)
+                .headers(self.build_headers())
+                .query(&[("page", page.to_string()), ("per_page", per_page.to_string())])
+                .send_logged(self.log_requests)
+                .await?;
+
+            let pagination = self.extract_pagination_info(&response);
+            let page_items: Vec<T> = self.handle_response(response).await?;
+            if page_items.is_empty() {
+                break;
+            }
+            items.extend(page_items);
+
+            let has_next_page = match pagination.total_pages {
+                Some(total_pages) => page < total_pages,
+                None => pagination.next_page.is_some(),
+            };
+            if !has_next_page {
+                break;
+            }
+            if page >= max_pages {
+                warn!("Stopped listing {} after {} pages (page cap reached)", endpoint, max_pages);
+                break;
+            }
+            page += 1;
+        }
+
+        info!("Fetched {} items from {}", items.len(), endpoint);
+        Ok(items)
+    }
+
     /// Get a specific project by ID or path
     pub async fn get_project(&self, project_id: &str) -> Result<Project, GitLabError> {
         let url = self.build_url(&format!("projects/{}", project_id));
@@ -197,7 +282,7 @@ impl GitLabClient {
             .client
             .get(&url)
             .headers(self.build_headers())
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         self.handle_response(response).await
@@ -212,3 +297,80 @@ impl Default for GitLabClient {
 
 use serde::Deserialize;
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{serve, MockResponse};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_validate_connection() {
+        let user = json!({"id": 1, "username": "root", "name": "Administrator", "email": null});
+        let (base_url, server) = serve(vec![MockResponse::json(200, user)]).await;
+        let client = GitLabClient::with_base_url(Some("glpat-valid".to_string()), format!("{}/api/v4", base_url));
+
+        client.validate_connection().await.expect("Connection should be valid");
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "GET /api/v4/user HTTP/1.1");
+        assert_eq!(requests[0].header("private-token"), Some("glpat-valid"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_connection_unauthorized() {
+        let (base_url, _server) = serve(vec![MockResponse::json(401, json!({"message": "401 Unauthorized"}))]).await;
+        let client = GitLabClient::with_base_url(Some("glpat-invalid".to_string()), base_url);
+
+        assert!(matches!(
+            client.validate_connection().await,
+            Err(GitLabError::AuthenticationError(_))
+        ));
+    }
+
+    fn project(id: u64) -> serde_json::Value {
+        json!({
+            "id": id,
+            "name": format!("project-{}", id),
+            "path": format!("project-{}", id),
+            "path_with_namespace": format!("group/project-{}", id),
+            "description": null,
+            "visibility": "private",
+            "web_url": format!("https://gitlab.example.com/group/project-{}", id),
+            "ssh_url_to_repo": null,
+            "http_url_to_repo": null,
+            "default_branch": "main"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_all_projects_follows_pages() {
+        let (base_url, server) = serve(vec![
+            MockResponse::json(200, json!([project(1), project(2)])).with_header("X-Total-Pages", "3"),
+            MockResponse::json(200, json!([project(3), project(4)])).with_header("X-Total-Pages", "3"),
+            MockResponse::json(200, json!([project(5)])).with_header("X-Total-Pages", "3"),
+        ])
+        .await;
+        let client = GitLabClient::with_base_url(Some("glpat-valid".to_string()), base_url);
+
+        let projects = client.list_all_projects(Some(2), 10).await.expect("Failed to list projects");
+        assert_eq!(projects.iter().map(|p| p.id).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "GET /projects?page=1&per_page=2 HTTP/1.1");
+        assert_eq!(requests[2].request_line, "GET /projects?page=3&per_page=2 HTTP/1.1");
+    }
+
+    #[tokio::test]
+    async fn test_list_all_projects_stops_at_page_cap() {
+        let (base_url, server) = serve(vec![
+            MockResponse::json(200, json!([project(1)])).with_header("X-Next-Page", "2"),
+            MockResponse::json(200, json!([project(2)])).with_header("X-Next-Page", "3"),
+        ])
+        .await;
+        let client = GitLabClient::with_base_url(None, base_url);
+
+        let projects = client.list_all_projects(Some(1), 2).await.expect("Failed to list projects");
+        assert_eq!(projects.len(), 2);
+        assert_eq!(server.await.unwrap().len(), 2);
+    }
+}
@@ -26,6 +26,9 @@ pub enum GitLabError {
     #[error("JSON serialization/deserialization error: {0}")]
     JsonError(#[from] serde_json::Error),
 
+    #[error("Invalid webhook token")]
+    InvalidWebhookToken,
+
     #[error("Unknown error: {0}")]
     Unknown(String),
 }
@@ -6,8 +6,10 @@
 mod client;
 mod error;
 mod types;
+mod webhook;
 
 pub use client::GitLabClient;
 pub use error::GitLabError;
 pub use types::*;
+pub use webhook::{verify_webhook_token, GITLAB_TOKEN_HEADER};
 
@@ -0,0 +1,63 @@
+//! GitLab Webhook Verification
+//!
+//! GitLab sends the secret token configured for a webhook in the `X-Gitlab-Token`
+//! header of every webhook request.
+
+use crate::gitlab::error::GitLabError;
+
+/// Header GitLab sends the webhook secret token in
+pub const GITLAB_TOKEN_HEADER: &str = "X-Gitlab-Token";
+
+/// Verify the `X-Gitlab-Token` header of a webhook request against the configured secret
+///
+/// The comparison takes the same time for every token of the same length, so the
+/// secret cannot be guessed byte by byte from response times.
+///
+/// # Arguments
+/// * `expected` - Secret token configured for the webhook
+/// * `header` - Value of the `X-Gitlab-Token` header, `None` if it is missing
+///
+/// # Errors
+/// Returns `GitLabError::InvalidWebhookToken` if the header is missing or does not match
+pub fn verify_webhook_token(expected: &str, header: Option<&str>) -> Result<(), GitLabError> {
+    let Some(token) = header else {
+        return Err(GitLabError::InvalidWebhookToken);
+    };
+
+    if expected.is_empty() || !constant_time_eq(expected.as_bytes(), token.as_bytes()) {
+        return Err(GitLabError::InvalidWebhookToken);
+    }
+
+    Ok(())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_token() {
+        assert!(verify_webhook_token("s3cret-token", Some("s3cret-token")).is_ok());
+    }
+
+    #[test]
+    fn test_non_matching_token() {
+        for header in [Some("s3cret-tokem"), Some("s3cret"), Some(""), None] {
+            assert!(
+                matches!(verify_webhook_token("s3cret-token", header), Err(GitLabError::InvalidWebhookToken)),
+                "{:?}",
+                header
+            );
+        }
+
+        // An empty secret never verifies
+        assert!(verify_webhook_token("", Some("")).is_err());
+    }
+}
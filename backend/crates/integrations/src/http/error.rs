@@ -0,0 +1,23 @@
+//! HTTP Error Types
+
+use thiserror::Error;
+
+/// Errors that can occur when sending HTTP requests
+#[derive(Debug, Error)]
+pub enum HttpError {
+    #[error("HTTP request failed: {0}")]
+    HttpError(#[from] reqwest::Error),
+
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+}
+
+impl HttpError {
+    /// Strip the request URL, which may contain secrets, from the error
+    pub fn without_url(self) -> Self {
+        match self {
+            Self::HttpError(e) => Self::HttpError(e.without_url()),
+            other => other,
+        }
+    }
+}
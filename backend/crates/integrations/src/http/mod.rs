@@ -0,0 +1,13 @@
+//! Generic HTTP Integration
+//!
+//! Provides a shared HTTP transport for outbound requests and a generic
+//! HTTP request node for workflows.
+
+mod error;
+mod node;
+mod transport;
+
+pub use error::HttpError;
+pub use node::{HttpRequestNode, ERROR_CODE_HTTP_REQUEST_FAILED};
+pub use transport::{redact_headers, redact_url, ClientConfig, HttpRequest, HttpResponse, HttpTransport};
+pub(crate) use transport::SendLogged;
@@ -0,0 +1,309 @@
+//! HTTP Request Node
+//!
+//! Generic workflow node that sends an HTTP request and returns the response.
+
+use crate::http::transport::{redact_url, HttpRequest, HttpTransport};
+use flextide_sdk::{
+    ConfigOption, ExecutionResponseBuilder, InputPin, NodeDefinition, NodeExecutionRequest, NodeExecutionResponse,
+    OutputPin, PinType, ERROR_CODE_MISSING_INPUT, ERROR_CODE_TYPE_ERROR,
+};
+use serde_json::Value;
+use tracing::warn;
+
+/// Error code for a request that could not be sent or got no response
+pub const ERROR_CODE_HTTP_REQUEST_FAILED: &str = "HTTP_REQUEST_FAILED";
+
+const METHODS: &[&str] = &["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD"];
+
+/// Generic HTTP request node
+///
+/// Inputs: `url`, `headers` (JSON object of header names to string values) and `body`
+/// (a string is sent as-is, other JSON values are sent as JSON). The method is configured
+/// with the `method` option. Outputs the `status`, `headers` and `body` of the response,
+/// the body is parsed as JSON if possible.
+#[derive(Debug, Clone, Default)]
+pub struct HttpRequestNode {
+    transport: HttpTransport,
+}
+
+impl HttpRequestNode {
+    /// Create a node that sends its requests through the given transport
+    pub fn new(transport: HttpTransport) -> Self {
+        Self { transport }
+    }
+
+    /// Node definition for the node catalog
+    pub fn definition() -> NodeDefinition {
+        NodeDefinition {
+            name: "http-request".to_string(),
+            title: "HTTP Request".to_string(),
+            description: "Send an HTTP request and return the response".to_string(),
+            group: "http".to_string(),
+            inputs: vec![
+                input("url", "URL", "URL to send the request to", PinType::String),
+                input("headers", "Headers", "Request headers as a JSON object", PinType::Json),
+                input("body", "Body", "Request body, non-string values are sent as JSON", PinType::Any),
+            ],
+            outputs: vec![
+                output("status", "Status", "HTTP status code of the response", PinType::Number),
+                output("headers", "Headers", "Response headers as a JSON object", PinType::Json),
+                output("body", "Body", "Response body, parsed as JSON if possible", PinType::Any),
+            ],
+            config: vec![ConfigOption {
+                name: "method".to_string(),
+                title: "Method".to_string(),
+                description: "HTTP method".to_string(),
+                option_type: PinType::String,
+                custom_type: None,
+                required: true,
+                default: Some(Value::String("GET".to_string())),
+                min: None,
+                max: None,
+                allowed_values: Some(METHODS.iter().map(|m| m.to_string()).collect()),
+            }],
+        }
+    }
+
+    /// Execute the node
+    pub async fn execute(&self, mut request: NodeExecutionRequest) -> NodeExecutionResponse {
+        if let Err(e) = Self::definition().validate_request(&mut request) {
+            return ExecutionResponseBuilder::new().with_error_code(e, ERROR_CODE_TYPE_ERROR).build();
+        }
+
+        let Some(url) = request.input.get("url").and_then(Value::as_str) else {
+            return ExecutionResponseBuilder::new()
+                .with_error_code("Input 'url' is required", ERROR_CODE_MISSING_INPUT)
+                .build();
+        };
+        let method = request.config["method"].as_str().unwrap_or("GET").to_string();
+
+        let mut headers = Vec::new();
+        if let Some(header_values) = request.input.get("headers").filter(|h| !h.is_null()) {
+            let Some(header_values) = header_values.as_object() else {
+                return ExecutionResponseBuilder::new()
+                    .with_error_code("Input 'headers' must be a JSON object", ERROR_CODE_TYPE_ERROR)
+                    .build();
+            };
+            for (name, value) in header_values {
+                let value = match value {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                headers.push((name.clone(), value));
+            }
+        }
+
+        let body = match request.input.get("body") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(s)) => Some(s.clone()),
+            Some(json) => {
+                if !headers.iter().any(|(name, _)| name.eq_ignore_ascii_case("content-type")) {
+                    headers.push(("Content-Type".to_string(), "application/json".to_string()));
+                }
+                Some(json.to_string())
+            }
+        };
+
+        let http_request = HttpRequest { method, url: url.to_string(), headers, body };
+        match self.transport.send(&http_request).await {
+            Ok(response) => {
+                let body = serde_json::from_str(&response.body).unwrap_or(Value::String(response.body));
+                ExecutionResponseBuilder::new()
+                    .with_output("status", Value::from(response.status))
+                    .with_output("headers", serde_json::json!(response.headers))
+                    .with_output("body", body)
+                    .build()
+            }
+            Err(e) => {
+                let e = e.without_url();
+                warn!("HTTP request node failed for {}: {}", redact_url(&http_request.url), e);
+                ExecutionResponseBuilder::new()
+                    .with_error_code(e.to_string(), ERROR_CODE_HTTP_REQUEST_FAILED)
+                    .build()
+            }
+        }
+    }
+}
+
+fn input(name: &str, title: &str, description: &str, pin_type: PinType) -> InputPin {
+    InputPin {
+        name: name.to_string(),
+        title: title.to_string(),
+        description: description.to_string(),
+        pin_type,
+        custom_type: None,
+        min: None,
+        max: None,
+        allowed_values: None,
+    }
+}
+
+fn output(name: &str, title: &str, description: &str, pin_type: PinType) -> OutputPin {
+    OutputPin {
+        name: name.to_string(),
+        title: title.to_string(),
+        description: description.to_string(),
+        pin_type,
+        custom_type: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::transport::redact_headers;
+    use flextide_sdk::ExecutionRequestBuilder;
+    use serde_json::json;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Serve requests by echoing their method, path, content type and body as JSON
+    async fn serve_echo() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.expect("Failed to accept connection");
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 4096];
+
+                let (head, body) = loop {
+                    let read = stream.read(&mut buffer).await.expect("Failed to read request");
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    let Some(header_end) = text.find("\r\n\r\n") else {
+                        continue;
+                    };
+                    let head = text[..header_end].to_string();
+                    let content_length = head
+                        .lines()
+                        .find_map(|line| {
+                            let (name, value) = line.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length").then(|| value.trim().parse().ok())?
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= header_end + 4 + content_length || read == 0 {
+                        break (head, text[header_end + 4..].to_string());
+                    }
+                };
+
+                let mut request_line = head.lines().next().unwrap_or_default().split(' ');
+                let header = |name: &str| {
+                    head.lines()
+                        .find_map(|line| {
+                            let (key, value) = line.split_once(':')?;
+                            key.eq_ignore_ascii_case(name).then(|| value.trim().to_string())
+                        })
+                };
+                let echo = json!({
+                    "method": request_line.next(),
+                    "path": request_line.next(),
+                    "content_type": header("content-type"),
+                    "authorization": header("authorization"),
+                    "body": body,
+                })
+                .to_string();
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nX-Echo: yes\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    echo.len(),
+                    echo
+                );
+                stream.write_all(response.as_bytes()).await.expect("Failed to write response");
+            }
+        });
+
+        base_url
+    }
+
+    #[test]
+    fn test_definition_is_valid() {
+        HttpRequestNode::definition().validate().unwrap();
+    }
+
+    #[test]
+    fn test_authorization_header_is_redacted() {
+        let headers = vec![
+            ("Authorization".to_string(), "Bearer secret".to_string()),
+            ("Accept".to_string(), "application/json".to_string()),
+        ];
+        let redacted = redact_headers(&headers);
+        assert_eq!(redacted[0].1, "[REDACTED]");
+        assert_eq!(redacted[1].1, "application/json");
+    }
+
+    #[tokio::test]
+    async fn test_get_request() {
+        let base_url = serve_echo().await;
+        let node = HttpRequestNode::default();
+
+        let request = ExecutionRequestBuilder::new()
+            .with_input("url", json!(format!("{}/items?id=1", base_url)))
+            .with_input("headers", json!({"Authorization": "Bearer secret"}))
+            .build();
+        let response = node.execute(request).await;
+
+        assert!(response.error.is_none(), "{:?}", response.error);
+        assert_eq!(response.output["status"], 200);
+        assert_eq!(response.output["headers"]["x-echo"], "yes");
+        assert_eq!(response.output["body"]["method"], "GET");
+        assert_eq!(response.output["body"]["path"], "/items?id=1");
+        assert_eq!(response.output["body"]["authorization"], "Bearer secret");
+        assert_eq!(response.output["body"]["body"], "");
+    }
+
+    #[tokio::test]
+    async fn test_post_request_with_json_body() {
+        let base_url = serve_echo().await;
+        let node = HttpRequestNode::default();
+
+        let request = ExecutionRequestBuilder::new()
+            .with_input("url", json!(format!("{}/items", base_url)))
+            .with_input("body", json!({"name": "Apollo", "tags": ["a", "b"]}))
+            .with_config("method", json!("POST"))
+            .build();
+        let response = node.execute(request).await;
+
+        assert!(response.error.is_none(), "{:?}", response.error);
+        let echo = &response.output["body"];
+        assert_eq!(echo["method"], "POST");
+        assert_eq!(echo["content_type"], "application/json");
+        let sent: Value = serde_json::from_str(echo["body"].as_str().unwrap()).unwrap();
+        assert_eq!(sent, json!({"name": "Apollo", "tags": ["a", "b"]}));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_request() {
+        let node = HttpRequestNode::default();
+
+        let missing_url = node.execute(ExecutionRequestBuilder::new().build()).await;
+        assert_eq!(missing_url.error.unwrap().code.as_deref(), Some(ERROR_CODE_MISSING_INPUT));
+
+        let bad_method = node
+            .execute(
+                ExecutionRequestBuilder::new()
+                    .with_input("url", json!("http://127.0.0.1:9"))
+                    .with_config("method", json!("FETCH"))
+                    .build(),
+            )
+            .await;
+        assert_eq!(bad_method.error.unwrap().code.as_deref(), Some(ERROR_CODE_TYPE_ERROR));
+    }
+
+    #[tokio::test]
+    async fn test_failed_request_does_not_expose_url() {
+        let node = HttpRequestNode::default();
+
+        let response = node
+            .execute(
+                ExecutionRequestBuilder::new()
+                    .with_input("url", json!("http://127.0.0.1:9/items?api_key=sk-secret"))
+                    .build(),
+            )
+            .await;
+
+        let error = response.error.expect("Request should fail");
+        assert_eq!(error.code.as_deref(), Some(ERROR_CODE_HTTP_REQUEST_FAILED));
+        assert!(!error.message.contains("sk-secret"), "{}", error.message);
+    }
+}
@@ -0,0 +1,412 @@
+//! HTTP Transport
+//!
+//! Shared transport for outbound HTTP requests.
+
+use crate::http::error::HttpError;
+use reqwest::Client;
+use reqwest::Method;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// Headers whose values are never written to logs
+const SENSITIVE_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "x-api-key",
+    "api-key",
+    "private-token",
+    "x-chroma-token",
+];
+
+/// Query parameters whose values are never written to logs
+const SENSITIVE_QUERY_PARAMS: &[&str] = &[
+    "api_key",
+    "apikey",
+    "key",
+    "token",
+    "access_token",
+    "private_token",
+    "client_secret",
+    "secret",
+    "password",
+    "signature",
+];
+
+/// Outbound HTTP request
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// HTTP method (e.g. "GET", "POST")
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+/// Response of an outbound HTTP request
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    /// Response headers with lowercase names, repeated headers are joined with ", "
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+/// Replace the values of sensitive headers (e.g. `Authorization`) for logging
+pub fn redact_headers(headers: &[(String, String)]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            if SENSITIVE_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+                (name.clone(), "[REDACTED]".to_string())
+            } else {
+                (name.clone(), value.clone())
+            }
+        })
+        .collect()
+}
+
+/// Replace the values of sensitive query parameters (e.g. `api_key`) of a URL for logging
+pub fn redact_url(url: &str) -> String {
+    let (url, fragment) = match url.split_once('#') {
+        Some((url, fragment)) => (url, Some(fragment)),
+        None => (url, None),
+    };
+    let Some((base, query)) = url.split_once('?') else {
+        return url.to_string() + &fragment.map(|f| format!("#{}", f)).unwrap_or_default();
+    };
+
+    let query = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((name, _)) if SENSITIVE_QUERY_PARAMS.contains(&name.to_ascii_lowercase().as_str()) => {
+                format!("{}=[REDACTED]", name)
+            }
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+
+    match fragment {
+        Some(fragment) => format!("{}?{}#{}", base, query, fragment),
+        None => format!("{}?{}", base, query),
+    }
+}
+
+/// Configuration of an HTTP transport
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    /// Request timeout (default: 30 seconds)
+    pub timeout: Duration,
+    /// Log method, URL, status and latency of every request at `debug` level (default: off)
+    ///
+    /// Secrets in headers and query parameters are redacted.
+    pub log_requests: bool,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            log_requests: false,
+        }
+    }
+}
+
+/// Transport for outbound HTTP requests, shared by nodes and integration clients
+///
+/// Cloning the transport is cheap and reuses the underlying connection pool.
+#[derive(Debug, Clone)]
+pub struct HttpTransport {
+    client: Client,
+    log_requests: bool,
+}
+
+impl HttpTransport {
+    /// Create a transport with a 30 second request timeout
+    ///
+    /// # Panics
+    /// Panics if the HTTP client cannot be initialized, like `reqwest::Client::new`
+    pub fn new() -> Self {
+        Self::with_config(ClientConfig::default()).expect("Failed to initialize HTTP client")
+    }
+
+    /// Create a transport with a custom request timeout
+    ///
+    /// # Errors
+    /// Returns `HttpError::HttpError` if the HTTP client cannot be initialized
+    pub fn with_timeout(timeout: Duration) -> Result<Self, HttpError> {
+        Self::with_config(ClientConfig {
+            timeout,
+            ..ClientConfig::default()
+        })
+    }
+
+    /// Create a transport from a configuration
+    ///
+    /// # Errors
+    /// Returns `HttpError::HttpError` if the HTTP client cannot be initialized
+    pub fn with_config(config: ClientConfig) -> Result<Self, HttpError> {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .user_agent("Flextide-Integration/1.0")
+            .build()?;
+
+        Ok(Self {
+            client,
+            log_requests: config.log_requests,
+        })
+    }
+
+    /// Send a request and return the response, whatever its status code is
+    pub async fn send(&self, request: &HttpRequest) -> Result<HttpResponse, HttpError> {
+        let method = Method::from_bytes(request.method.to_ascii_uppercase().as_bytes())
+            .map_err(|_| HttpError::InvalidRequest(format!("Invalid HTTP method: {}", request.method)))?;
+
+        let started = Instant::now();
+        let result = self.send_request(method.clone(), request).await;
+
+        if self.log_requests {
+            let outcome = result.as_ref().map(|response| response.status).map_err(|e| match e {
+                HttpError::HttpError(inner) => redact_error_url(e.to_string(), inner),
+                other => other.to_string(),
+            });
+            log_request(&method, &request.url, &request.headers, started, outcome);
+        }
+
+        result
+    }
+
+    async fn send_request(&self, method: Method, request: &HttpRequest) -> Result<HttpResponse, HttpError> {
+        let mut builder = self.client.request(method, &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = &request.body {
+            builder = builder.body(body.clone());
+        }
+
+        let response = builder.send().await?;
+        let status = response.status().as_u16();
+
+        let mut headers: BTreeMap<String, String> = BTreeMap::new();
+        for (name, value) in response.headers() {
+            let value = String::from_utf8_lossy(value.as_bytes()).to_string();
+            headers
+                .entry(name.as_str().to_string())
+                .and_modify(|existing| {
+                    existing.push_str(", ");
+                    existing.push_str(&value);
+                })
+                .or_insert(value);
+        }
+
+        let body = response.text().await?;
+
+        Ok(HttpResponse { status, headers, body })
+    }
+}
+
+impl Default for HttpTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sending of `reqwest` requests with the request logging of [`HttpTransport`]
+///
+/// The integration clients (GitHub, GitLab, Jira, OpenAI, Chroma) build requests with
+/// their own `reqwest::Client` and send them through this trait, so they log like the
+/// transport when request logging is enabled.
+pub(crate) trait SendLogged {
+    /// Send the request, logging it at `debug` level if `log_requests` is set
+    async fn send_logged(self, log_requests: bool) -> Result<reqwest::Response, reqwest::Error>;
+}
+
+impl SendLogged for reqwest::RequestBuilder {
+    async fn send_logged(self, log_requests: bool) -> Result<reqwest::Response, reqwest::Error> {
+        if !log_requests {
+            return self.send().await;
+        }
+
+        let (client, request) = self.build_split();
+        let request = request?;
+        let method = request.method().clone();
+        let url = request.url().to_string();
+        let headers: Vec<(String, String)> = request
+            .headers()
+            .iter()
+            .map(|(name, value)| (name.to_string(), String::from_utf8_lossy(value.as_bytes()).to_string()))
+            .collect();
+
+        let started = Instant::now();
+        let result = client.execute(request).await;
+
+        let outcome = result
+            .as_ref()
+            .map(|response| response.status().as_u16())
+            .map_err(|e| redact_error_url(e.to_string(), e));
+        log_request(&method, &url, &headers, started, outcome);
+
+        result
+    }
+}
+
+/// Replace the raw URL of a failed request in its error `message` by the redacted one
+fn redact_error_url(message: String, error: &reqwest::Error) -> String {
+    match error.url() {
+        Some(url) => message.replace(url.as_str(), &redact_url(url.as_str())),
+        None => message,
+    }
+}
+
+/// Log method, redacted URL and headers, status and latency of a request
+fn log_request(
+    method: &Method,
+    url: &str,
+    headers: &[(String, String)],
+    started: Instant,
+    outcome: Result<u16, String>,
+) {
+    let latency_ms = started.elapsed().as_millis();
+    let url = redact_url(url);
+    let headers = redact_headers(headers);
+    match outcome {
+        Ok(status) => debug!("HTTP {} {} -> {} in {}ms headers={:?}", method, url, status, latency_ms, headers),
+        Err(e) => debug!("HTTP {} {} failed after {}ms headers={:?}: {}", method, url, latency_ms, headers, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{serve, MockResponse};
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    /// Log writer collecting the formatted tracing output
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+    impl CapturedLogs {
+        fn contents(&self) -> String {
+            String::from_utf8_lossy(&self.0.lock().unwrap()).to_string()
+        }
+    }
+
+    impl Write for CapturedLogs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn request(base_url: &str) -> HttpRequest {
+        HttpRequest {
+            method: "GET".to_string(),
+            url: format!("{}/search?q=rust&api_key=sk-secret", base_url),
+            headers: vec![("Authorization".to_string(), "Bearer sk-secret".to_string())],
+            body: None,
+        }
+    }
+
+    /// Run `future` and return what it logged
+    async fn captured_logs(future: impl std::future::Future<Output = ()>) -> String {
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        future.await;
+
+        logs.contents()
+    }
+
+    async fn send_with_captured_logs(config: ClientConfig) -> String {
+        captured_logs(async {
+            let (base_url, server) = serve(vec![MockResponse::json(200, serde_json::json!({}))]).await;
+            let response = HttpTransport::with_config(config).unwrap().send(&request(&base_url)).await.unwrap();
+            assert_eq!(response.status, 200);
+            server.await.unwrap();
+        })
+        .await
+    }
+
+    #[test]
+    fn test_redact_url() {
+        assert_eq!(
+            redact_url("https://example.com/a?q=1&API_KEY=abc&token=xyz#top"),
+            "https://example.com/a?q=1&API_KEY=[REDACTED]&token=[REDACTED]#top"
+        );
+        assert_eq!(redact_url("https://example.com/a"), "https://example.com/a");
+    }
+
+    #[tokio::test]
+    async fn test_request_logging_redacts_secrets() {
+        let logs = send_with_captured_logs(ClientConfig {
+            log_requests: true,
+            ..ClientConfig::default()
+        })
+        .await;
+
+        assert!(logs.contains("HTTP GET"), "{}", logs);
+        assert!(logs.contains("/search?q=rust&api_key=[REDACTED] -> 200 in"), "{}", logs);
+        assert!(logs.contains("\"[REDACTED]\""), "{}", logs);
+        assert!(!logs.contains("sk-secret"), "{}", logs);
+    }
+
+    #[tokio::test]
+    async fn test_failed_request_logging_redacts_secrets() {
+        // Nothing listens on the port once the listener is dropped
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let logs = captured_logs(async {
+            let transport = HttpTransport::with_config(ClientConfig {
+                log_requests: true,
+                ..ClientConfig::default()
+            })
+            .unwrap();
+            assert!(transport.send(&request(&base_url)).await.is_err());
+        })
+        .await;
+
+        assert!(logs.contains("/search?q=rust&api_key=[REDACTED] failed after"), "{}", logs);
+        assert!(!logs.contains("sk-secret"), "{}", logs);
+    }
+
+    #[tokio::test]
+    async fn test_request_logging_is_opt_in() {
+        let logs = send_with_captured_logs(ClientConfig::default()).await;
+        assert!(!logs.contains("HTTP GET"), "{}", logs);
+    }
+
+    #[tokio::test]
+    async fn test_integration_client_request_logging() {
+        let logs = captured_logs(async {
+            let response = serde_json::json!({"object": "list", "data": []});
+            let (base_url, server) = serve(vec![MockResponse::json(200, response)]).await;
+            crate::openai::OpenAIClient::with_base_url("sk-secret".to_string(), base_url)
+                .with_request_logging(true)
+                .list_models()
+                .await
+                .unwrap();
+            server.await.unwrap();
+        })
+        .await;
+
+        assert!(logs.contains("HTTP GET"), "{}", logs);
+        assert!(logs.contains("/models -> 200 in"), "{}", logs);
+        assert!(logs.contains("\"[REDACTED]\""), "{}", logs);
+        assert!(!logs.contains("sk-secret"), "{}", logs);
+    }
+}
@@ -2,13 +2,16 @@
 
 use crate::jira::error::JiraError;
 use crate::jira::types::*;
+use crate::http::SendLogged;
 use reqwest::Client;
-use tracing::{debug, error};
+use serde_json::{json, Value as JsonValue};
+use tracing::{debug, error, warn};
 
 /// Client for interacting with the Jira API
 pub struct JiraClient {
     base_url: String,
     client: Client,
+    log_requests: bool,
     email: String,
     auth_token: String,
 }
@@ -30,11 +33,58 @@ impl JiraClient {
         Self {
             base_url,
             client: Client::new(),
+            log_requests: false,
             email,
             auth_token,
         }
     }
 
+    /// Log every request at `debug` level, with secrets redacted (default: off)
+    ///
+    /// Uses the request logging of [`HttpTransport`](crate::http::HttpTransport).
+    pub fn with_request_logging(mut self, enabled: bool) -> Self {
+        self.log_requests = enabled;
+        self
+    }
+
+    /// Validate the connection and credentials without side effects
+    ///
+    /// Requests the authenticated user (`GET /rest/api/3/myself`). Returns
+    /// `JiraError::AuthenticationError` if the credentials are rejected,
+    /// `JiraError::HttpError` if the Jira instance is not reachable.
+    pub async fn validate_connection(&self) -> Result<(), JiraError> {
+        let url = format!("{}/rest/api/3/myself", self.base_url);
+        debug!("Validating Jira connection: {}", url);
+
+        let response = self
+            .client
+            .get(&url)
+            .basic_auth(&self.email, Some(&self.auth_token))
+            .header("Accept", "application/json")
+            .send_logged(self.log_requests)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            error!("Jira API error: status={}, body={}", status, error_text);
+
+            return match status.as_u16() {
+                401 | 403 => Err(JiraError::AuthenticationError(format!(
+                    "Authentication failed: {}",
+                    error_text
+                ))),
+                _ => Err(JiraError::ApiError(format!(
+                    "HTTP {}: {}",
+                    status, error_text
+                ))),
+            };
+        }
+
+        Ok(())
+    }
+
     /// Get all visible projects for the user in a paginated way
     /// 
     /// # Arguments
@@ -64,7 +114,7 @@ impl JiraClient {
             ])
             .basic_auth(&self.email, Some(&self.auth_token))
             .header("Accept", "application/json")
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -104,6 +154,36 @@ impl JiraClient {
         Ok(search_response)
     }
 
+    /// Get all visible projects, following the pagination until the last page
+    ///
+    /// # Arguments
+    /// * `max_results` - Number of projects per request (default: 100)
+    /// * `max_pages` - Maximum number of pages to fetch, further pages are skipped
+    pub async fn list_all_projects(
+        &self,
+        max_results: Option<usize>,
+        max_pages: usize,
+    ) -> Result<Vec<Project>, JiraError> {
+        let mut projects = Vec::new();
+        let mut start_at = 0;
+
+        for page in 1..=max_pages {
+            let response = self.get_projects(Some(start_at), max_results).await?;
+            let returned = response.values.len();
+            projects.extend(response.values);
+            start_at = response.start_at + returned;
+
+            if response.is_last || returned == 0 || start_at >= response.total {
+                return Ok(projects);
+            }
+            if page == max_pages {
+                warn!("Stopped listing Jira projects after {} pages (page cap reached)", max_pages);
+            }
+        }
+
+        Ok(projects)
+    }
+
     /// Get detailed information about a single project
     /// 
     /// # Arguments
@@ -125,7 +205,7 @@ impl JiraClient {
             .query(&[("expand", "*")])
             .basic_auth(&self.email, Some(&self.auth_token))
             .header("Accept", "application/json")
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -163,5 +243,247 @@ impl JiraClient {
 
         Ok(project)
     }
+
+    /// Create an issue
+    ///
+    /// # Arguments
+    /// * `request` - Project, issue type, summary and description of the issue
+    /// * `custom_fields` - Additional entries of the `fields` object, keyed by field ID
+    ///   (e.g., `{"customfield_10016": 5}` for story points). Must be a JSON object or `null`
+    ///
+    /// # Returns
+    /// A `CreatedIssue` with the ID and key of the new issue
+    ///
+    /// # Errors
+    /// Returns `JiraError::InvalidRequest` if `custom_fields` is not an object or sets one
+    /// of the standard fields (`project`, `issuetype`, `summary`, `description`), or if
+    /// Jira rejects the issue.
+    pub async fn create_issue(
+        &self,
+        request: &CreateIssueRequest,
+        custom_fields: &JsonValue,
+    ) -> Result<CreatedIssue, JiraError> {
+        let mut fields = serde_json::Map::new();
+        fields.insert("project".to_string(), json!({ "key": request.project_key }));
+        fields.insert("issuetype".to_string(), json!({ "name": request.issue_type }));
+        fields.insert("summary".to_string(), json!(request.summary));
+        if let Some(description) = &request.description {
+            fields.insert(
+                "description".to_string(),
+                json!({
+                    "type": "doc",
+                    "version": 1,
+                    "content": [{
+                        "type": "paragraph",
+                        "content": [{ "type": "text", "text": description }]
+                    }]
+                }),
+            );
+        }
+
+        match custom_fields {
+            JsonValue::Null => {}
+            JsonValue::Object(custom_fields) => {
+                for (field, value) in custom_fields {
+                    if fields.contains_key(field) {
+                        return Err(JiraError::InvalidRequest(format!(
+                            "Custom fields must not set the standard field '{}'",
+                            field
+                        )));
+                    }
+                    fields.insert(field.clone(), value.clone());
+                }
+            }
+            _ => {
+                return Err(JiraError::InvalidRequest(
+                    "Custom fields must be a JSON object".to_string(),
+                ));
+            }
+        }
+
+        let url = format!("{}/rest/api/3/issue", self.base_url);
+
+        debug!(
+            "Creating Jira issue: project={}, type={}, custom_fields={}",
+            request.project_key,
+            request.issue_type,
+            custom_fields.as_object().map_or(0, |custom_fields| custom_fields.len())
+        );
+
+        let response = self
+            .client
+            .post(&url)
+            .basic_auth(&self.email, Some(&self.auth_token))
+            .header("Accept", "application/json")
+            .json(&json!({ "fields": fields }))
+            .send_logged(self.log_requests)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            error!("Jira API error: status={}, body={}", status, error_text);
+
+            return match status.as_u16() {
+                400 => Err(JiraError::InvalidRequest(format!(
+                    "Bad request: {}",
+                    error_text
+                ))),
+                401 | 403 => Err(JiraError::AuthenticationError(format!(
+                    "Authentication failed: {}",
+                    error_text
+                ))),
+                _ => Err(JiraError::ApiError(format!(
+                    "HTTP {}: {}",
+                    status, error_text
+                ))),
+            };
+        }
+
+        let issue: CreatedIssue = response.json().await?;
+
+        debug!("Jira issue created: key={}", issue.key);
+
+        Ok(issue)
+    }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{serve, MockResponse};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_validate_connection() {
+        let (base_url, server) = serve(vec![MockResponse::json(200, json!({"accountId": "abc"}))]).await;
+        let client = JiraClient::new(base_url, "user@example.com".to_string(), "token".to_string());
+
+        client.validate_connection().await.expect("Connection should be valid");
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "GET /rest/api/3/myself HTTP/1.1");
+        assert!(requests[0].header("authorization").unwrap().starts_with("Basic "));
+    }
+
+    #[tokio::test]
+    async fn test_validate_connection_unauthorized() {
+        let (base_url, _server) = serve(vec![MockResponse::json(401, json!({"errorMessages": []}))]).await;
+        let client = JiraClient::new(base_url, "user@example.com".to_string(), "wrong".to_string());
+
+        assert!(matches!(
+            client.validate_connection().await,
+            Err(JiraError::AuthenticationError(_))
+        ));
+    }
+
+    fn project_page(start_at: usize, ids: &[usize], total: usize) -> serde_json::Value {
+        let values: Vec<serde_json::Value> = ids
+            .iter()
+            .map(|id| {
+                json!({
+                    "avatar_urls": {"16x16": "a", "24x24": "b", "32x32": "c", "48x48": "d"},
+                    "id": id.to_string(),
+                    "insight": null,
+                    "key": format!("P{}", id),
+                    "name": format!("Project {}", id),
+                    "projectCategory": null,
+                    "self": format!("https://jira.example.com/rest/api/3/project/{}", id),
+                    "simplified": false,
+                    "style": "classic"
+                })
+            })
+            .collect();
+        json!({
+            "isLast": start_at + ids.len() >= total,
+            "maxResults": 2,
+            "nextPage": null,
+            "self": "https://jira.example.com/rest/api/3/project/search",
+            "startAt": start_at,
+            "total": total,
+            "values": values
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_all_projects_follows_pages() {
+        let (base_url, server) = serve(vec![
+            MockResponse::json(200, project_page(0, &[1, 2], 5)),
+            MockResponse::json(200, project_page(2, &[3, 4], 5)),
+            MockResponse::json(200, project_page(4, &[5], 5)),
+        ])
+        .await;
+        let client = JiraClient::new(base_url, "user@example.com".to_string(), "token".to_string());
+
+        let projects = client.list_all_projects(Some(2), 10).await.expect("Failed to list projects");
+        assert_eq!(projects.iter().map(|p| p.key.as_str()).collect::<Vec<_>>(), vec!["P1", "P2", "P3", "P4", "P5"]);
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[1].request_line, "GET /rest/api/3/project/search?startAt=2&maxResults=2 HTTP/1.1");
+        assert_eq!(requests[2].request_line, "GET /rest/api/3/project/search?startAt=4&maxResults=2 HTTP/1.1");
+    }
+
+    #[tokio::test]
+    async fn test_list_all_projects_stops_at_page_cap() {
+        let (base_url, server) = serve(vec![
+            MockResponse::json(200, project_page(0, &[1, 2], 6)),
+            MockResponse::json(200, project_page(2, &[3, 4], 6)),
+        ])
+        .await;
+        let client = JiraClient::new(base_url, "user@example.com".to_string(), "token".to_string());
+
+        let projects = client.list_all_projects(Some(2), 2).await.expect("Failed to list projects");
+        assert_eq!(projects.len(), 4);
+        assert_eq!(server.await.unwrap().len(), 2);
+    }
+
+    fn issue_request() -> CreateIssueRequest {
+        CreateIssueRequest {
+            project_key: "EX".to_string(),
+            issue_type: "Story".to_string(),
+            summary: "Import customers".to_string(),
+            description: Some("From the CSV export".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_issue_with_custom_fields() {
+        let (base_url, server) = serve(vec![MockResponse::json(
+            201,
+            json!({"id": "10042", "key": "EX-42", "self": "https://jira.example.com/rest/api/3/issue/10042"}),
+        )])
+        .await;
+        let client = JiraClient::new(base_url, "user@example.com".to_string(), "token".to_string());
+
+        let issue = client
+            .create_issue(&issue_request(), &json!({"customfield_10016": 5, "customfield_10014": "EX-1"}))
+            .await
+            .expect("Failed to create issue");
+        assert_eq!(issue.key, "EX-42");
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "POST /rest/api/3/issue HTTP/1.1");
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body["fields"]["customfield_10016"], 5);
+        assert_eq!(body["fields"]["customfield_10014"], "EX-1");
+        assert_eq!(body["fields"]["project"], json!({"key": "EX"}));
+        assert_eq!(body["fields"]["issuetype"], json!({"name": "Story"}));
+        assert_eq!(body["fields"]["summary"], "Import customers");
+    }
+
+    #[tokio::test]
+    async fn test_create_issue_rejects_invalid_custom_fields() {
+        let client = JiraClient::new("http://127.0.0.1:1".to_string(), "user@example.com".to_string(), "token".to_string());
+
+        assert!(matches!(
+            client.create_issue(&issue_request(), &json!(["customfield_10016"])).await,
+            Err(JiraError::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            client.create_issue(&issue_request(), &json!({"summary": "Overridden"})).await,
+            Err(JiraError::InvalidRequest(_))
+        ));
+    }
+}
@@ -275,3 +275,28 @@ pub struct Version {
     #[serde(rename = "self")]
     pub self_url: Option<String>,
 }
+
+/// Standard fields of an issue to create
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateIssueRequest {
+    /// Key of the project the issue is created in (e.g., "EX")
+    pub project_key: String,
+    /// Name of the issue type (e.g., "Task", "Bug", "Story")
+    pub issue_type: String,
+    /// Issue summary (title)
+    pub summary: String,
+    /// Plain text description, sent as a single Atlassian Document Format paragraph
+    pub description: Option<String>,
+}
+
+/// Reference to a created issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedIssue {
+    /// Issue ID
+    pub id: String,
+    /// Issue key (e.g., "EX-123")
+    pub key: String,
+    /// URL to this issue
+    #[serde(rename = "self")]
+    pub self_url: String,
+}
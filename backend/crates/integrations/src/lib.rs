@@ -5,14 +5,23 @@
 //! that can be used by nodes in the workflow automation platform.
 
 pub mod chroma;
+pub mod embedding_pipeline;
+pub mod error;
 pub mod github;
 pub mod gitlab;
+pub mod http;
 pub mod jira;
 pub mod openai;
 
+#[cfg(test)]
+mod test_support;
+
 pub use chroma::ChromaClient;
+pub use embedding_pipeline::{embed_and_store, ChromaCollectionRef, EmbeddingPipelineError};
+pub use error::{IntegrationError, IntegrationErrorKind};
 pub use github::GitHubClient;
 pub use gitlab::GitLabClient;
+pub use http::{ClientConfig, HttpRequestNode, HttpTransport};
 pub use jira::JiraClient;
 pub use openai::OpenAIClient;
 
@@ -4,6 +4,7 @@
 
 use crate::openai::error::OpenAIError;
 use crate::openai::types::*;
+use crate::http::SendLogged;
 use reqwest::Client;
 use tracing::{debug, error, info};
 
@@ -12,6 +13,7 @@ const OPENAI_API_BASE: &str = "https://api.openai.com/v1";
 /// Client for interacting with the OpenAI API
 pub struct OpenAIClient {
     client: Client,
+    log_requests: bool,
     api_key: String,
     base_url: String,
 }
@@ -21,6 +23,7 @@ impl OpenAIClient {
     pub fn new(api_key: String) -> Self {
         Self {
             client: Client::new(),
+            log_requests: false,
             api_key,
             base_url: OPENAI_API_BASE.to_string(),
         }
@@ -30,12 +33,78 @@ impl OpenAIClient {
     pub fn with_base_url(api_key: String, base_url: String) -> Self {
         Self {
             client: Client::new(),
+            log_requests: false,
             api_key,
             base_url,
         }
     }
 
+    /// Log every request at `debug` level, with secrets redacted (default: off)
+    ///
+    /// Uses the request logging of [`HttpTransport`](crate::http::HttpTransport).
+    pub fn with_request_logging(mut self, enabled: bool) -> Self {
+        self.log_requests = enabled;
+        self
+    }
+
+    /// Validate the connection and API key without side effects
+    ///
+    /// Lists the available models, see `list_models`. Returns `OpenAIError::InvalidApiKey`
+    /// if the key is rejected, `OpenAIError::HttpError` if the API is not reachable.
+    pub async fn validate_connection(&self) -> Result<(), OpenAIError> {
+        debug!("Validating OpenAI connection: {}", self.base_url);
+        self.list_models().await?;
+        Ok(())
+    }
+
+    /// List the models available to the API key (`GET /models`), sorted by ID
+    ///
+    /// Returns `OpenAIError::InvalidApiKey` if the key is rejected and
+    /// `OpenAIError::InvalidResponse` if the model list cannot be parsed.
+    pub async fn list_models(&self) -> Result<Vec<Model>, OpenAIError> {
+        let url = format!("{}/models", self.base_url);
+        debug!("Listing OpenAI models: {}", url);
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .send_logged(self.log_requests)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            error!("OpenAI API error: status={}, body={}", status, error_text);
+
+            return match status.as_u16() {
+                401 => Err(OpenAIError::InvalidApiKey),
+                429 => Err(OpenAIError::RateLimitExceeded),
+                _ => Err(OpenAIError::ApiError(format!(
+                    "HTTP {}: {}",
+                    status, error_text
+                ))),
+            };
+        }
+
+        let body = response.text().await?;
+        let mut models = serde_json::from_str::<ModelList>(&body)
+            .map_err(|e| {
+                error!("OpenAI returned an invalid model list: {}", e);
+                OpenAIError::InvalidResponse(format!("Invalid model list: {}", e))
+            })?
+            .data;
+        models.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Ok(models)
+    }
+
     /// Send a chat completion request to the OpenAI API
+    ///
+    /// Build the messages of the request with `ChatMessages`, see `ChatCompletionRequest::new`.
+    /// If the request uses a JSON response format, the content of every choice is parsed into
+    /// `ChatChoice::parsed_content`; invalid JSON is reported as `OpenAIError::InvalidJsonContent`.
     pub async fn chat_completion(
         &self,
         request: ChatCompletionRequest,
@@ -50,7 +119,7 @@ impl OpenAIClient {
             .header("Authorization", format!("Bearer {}", self.api_key))
             .header("Content-Type", "application/json")
             .json(&request)
-            .send()
+            .send_logged(self.log_requests)
             .await?;
 
         let status = response.status();
@@ -69,8 +138,18 @@ impl OpenAIClient {
             };
         }
 
-        let completion: ChatCompletionResponse = response.json().await?;
-        
+        let mut completion: ChatCompletionResponse = response.json().await?;
+
+        if request.response_format.as_ref().is_some_and(ResponseFormat::is_json) {
+            for choice in &mut completion.choices {
+                let parsed = serde_json::from_str(&choice.message.content).map_err(|e| {
+                    error!("OpenAI returned invalid JSON content: {}", e);
+                    OpenAIError::InvalidJsonContent(e.to_string())
+                })?;
+                choice.parsed_content = Some(parsed);
+            }
+        }
+
         info!(
             "Chat completion successful: model={}, tokens={}",
             completion.model, completion.usage.total_tokens
@@ -78,5 +157,301 @@ impl OpenAIClient {
 
         Ok(completion)
     }
+
+    /// Create embeddings for a list of texts
+    ///
+    /// The embeddings in the response are sorted by their index, so `data[i]` belongs to
+    /// `input[i]`. Returns `OpenAIError::InvalidResponse` if the number of embeddings does
+    /// not match the number of texts.
+    pub async fn create_embeddings(
+        &self,
+        model: &str,
+        input: Vec<String>,
+    ) -> Result<EmbeddingResponse, OpenAIError> {
+        let url = format!("{}/embeddings", self.base_url);
+        let input_count = input.len();
+
+        debug!("Sending embeddings request to OpenAI: model={}, inputs={}", model, input_count);
+
+        let request = EmbeddingRequest {
+            model: model.to_string(),
+            input,
+        };
+        let response = self
+            .client
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .header("Content-Type", "application/json")
+            .json(&request)
+            .send_logged(self.log_requests)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            error!("OpenAI API error: status={}, body={}", status, error_text);
+
+            return match status.as_u16() {
+                401 => Err(OpenAIError::InvalidApiKey),
+                429 => Err(OpenAIError::RateLimitExceeded),
+                _ => Err(OpenAIError::ApiError(format!(
+                    "HTTP {}: {}",
+                    status, error_text
+                ))),
+            };
+        }
+
+        let mut embeddings: EmbeddingResponse = response.json().await?;
+        embeddings.data.sort_by_key(|embedding| embedding.index);
+
+        if embeddings.data.len() != input_count {
+            return Err(OpenAIError::InvalidResponse(format!(
+                "Expected {} embeddings, got {}",
+                input_count,
+                embeddings.data.len()
+            )));
+        }
+
+        info!(
+            "Embeddings created: model={}, count={}, tokens={}",
+            embeddings.model,
+            embeddings.data.len(),
+            embeddings.usage.total_tokens
+        );
+
+        Ok(embeddings)
+    }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{serve, MockResponse};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_validate_connection() {
+        let (base_url, server) = serve(vec![MockResponse::json(200, json!({"object": "list", "data": []}))]).await;
+        let client = OpenAIClient::with_base_url("sk-valid".to_string(), base_url);
+
+        client.validate_connection().await.expect("Connection should be valid");
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "GET /models HTTP/1.1");
+        assert_eq!(requests[0].header("authorization"), Some("Bearer sk-valid"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_connection_unauthorized() {
+        let (base_url, _server) = serve(vec![MockResponse::json(401, json!({"error": {}}))]).await;
+        let client = OpenAIClient::with_base_url("sk-invalid".to_string(), base_url);
+
+        assert!(matches!(client.validate_connection().await, Err(OpenAIError::InvalidApiKey)));
+    }
+
+    #[tokio::test]
+    async fn test_list_models() {
+        let (base_url, server) = serve(vec![MockResponse::json(
+            200,
+            json!({
+                "object": "list",
+                "data": [
+                    {"id": "gpt-4o-mini", "object": "model", "created": 1721172741, "owned_by": "system"},
+                    {"id": "gpt-4o", "object": "model", "created": 1715367049, "owned_by": "system"},
+                    {"id": "ft:gpt-4o-mini:acme", "object": "model", "created": 1730000000, "owned_by": "acme"}
+                ]
+            }),
+        )])
+        .await;
+        let client = OpenAIClient::with_base_url("sk-valid".to_string(), base_url);
+
+        let models = client.list_models().await.expect("Failed to list models");
+        assert_eq!(
+            models,
+            vec![
+                Model {
+                    id: "ft:gpt-4o-mini:acme".to_string(),
+                    created: 1730000000,
+                    owned_by: "acme".to_string(),
+                },
+                Model {
+                    id: "gpt-4o".to_string(),
+                    created: 1715367049,
+                    owned_by: "system".to_string(),
+                },
+                Model {
+                    id: "gpt-4o-mini".to_string(),
+                    created: 1721172741,
+                    owned_by: "system".to_string(),
+                },
+            ]
+        );
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "GET /models HTTP/1.1");
+        assert_eq!(requests[0].header("authorization"), Some("Bearer sk-valid"));
+    }
+
+    #[tokio::test]
+    async fn test_list_models_errors() {
+        let (base_url, _server) = serve(vec![
+            MockResponse::json(200, json!({"object": "list"})),
+            MockResponse::json(429, json!({"error": {}})),
+        ])
+        .await;
+        let client = OpenAIClient::with_base_url("sk-valid".to_string(), base_url);
+
+        assert!(matches!(client.list_models().await, Err(OpenAIError::InvalidResponse(_))));
+        assert!(matches!(client.list_models().await, Err(OpenAIError::RateLimitExceeded)));
+    }
+
+    #[test]
+    fn test_chat_messages_builder() {
+        let messages = ChatMessages::new()
+            .system("You are terse.")
+            .user("Hi")
+            .assistant("Hello.")
+            .user("Bye");
+
+        assert_eq!(
+            serde_json::to_value(&messages).unwrap(),
+            json!([
+                {"role": "system", "content": "You are terse."},
+                {"role": "user", "content": "Hi"},
+                {"role": "assistant", "content": "Hello."},
+                {"role": "user", "content": "Bye"}
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chat_completion_with_chat_messages() {
+        let (base_url, server) = serve(vec![MockResponse::json(
+            200,
+            json!({
+                "id": "mock",
+                "object": "chat.completion",
+                "created": 0,
+                "model": "mock-model",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": "pong"},
+                    "finish_reason": "stop"
+                }],
+                "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}
+            }),
+        )])
+        .await;
+        let client = OpenAIClient::with_base_url("sk-valid".to_string(), base_url);
+
+        let messages = ChatMessages::new().system("Answer with one word.").user("ping");
+        let response = client
+            .chat_completion(ChatCompletionRequest::new("mock-model", messages))
+            .await
+            .expect("Chat completion failed");
+        assert_eq!(response.choices[0].message.content, "pong");
+
+        let requests = server.await.unwrap();
+        let sent: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(
+            sent,
+            json!({
+                "model": "mock-model",
+                "messages": [
+                    {"role": "system", "content": "Answer with one word."},
+                    {"role": "user", "content": "ping"}
+                ]
+            })
+        );
+    }
+
+    fn completion_with_content(content: &str) -> serde_json::Value {
+        json!({
+            "id": "mock",
+            "object": "chat.completion",
+            "created": 0,
+            "model": "mock-model",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": content},
+                "finish_reason": "stop"
+            }],
+            "usage": {"prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2}
+        })
+    }
+
+    #[tokio::test]
+    async fn test_chat_completion_json_mode() {
+        let (base_url, server) = serve(vec![
+            MockResponse::json(200, completion_with_content(r#"{"sentiment": "positive"}"#)),
+            MockResponse::json(200, completion_with_content("Sure! Here is the JSON: {")),
+        ])
+        .await;
+        let client = OpenAIClient::with_base_url("sk-valid".to_string(), base_url);
+        let request = ChatCompletionRequest::new("mock-model", ChatMessages::new().user("Classify: great"))
+            .with_response_format(ResponseFormat::JsonObject);
+
+        let response = client.chat_completion(request.clone()).await.expect("Chat completion failed");
+        assert_eq!(response.choices[0].parsed_content, Some(json!({"sentiment": "positive"})));
+
+        assert!(matches!(
+            client.chat_completion(request).await,
+            Err(OpenAIError::InvalidJsonContent(_))
+        ));
+
+        let requests = server.await.unwrap();
+        let sent: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(sent["response_format"], json!({"type": "json_object"}));
+    }
+
+    #[test]
+    fn test_json_schema_response_format() {
+        let format = ResponseFormat::JsonSchema {
+            json_schema: JsonSchemaFormat {
+                name: "sentiment".to_string(),
+                schema: json!({"type": "object"}),
+                strict: Some(true),
+            },
+        };
+
+        assert_eq!(
+            serde_json::to_value(&format).unwrap(),
+            json!({
+                "type": "json_schema",
+                "json_schema": {"name": "sentiment", "schema": {"type": "object"}, "strict": true}
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_embeddings_sorted_by_index() {
+        let (base_url, server) = serve(vec![MockResponse::json(
+            200,
+            json!({
+                "object": "list",
+                "model": "text-embedding-3-small",
+                "data": [
+                    {"object": "embedding", "index": 1, "embedding": [0.3, 0.4]},
+                    {"object": "embedding", "index": 0, "embedding": [0.1, 0.2]}
+                ],
+                "usage": {"prompt_tokens": 4, "total_tokens": 4}
+            }),
+        )])
+        .await;
+        let client = OpenAIClient::with_base_url("sk-valid".to_string(), base_url);
+
+        let response = client
+            .create_embeddings("text-embedding-3-small", vec!["first".to_string(), "second".to_string()])
+            .await
+            .expect("Embeddings request failed");
+        assert_eq!(response.data[0].embedding, vec![0.1, 0.2]);
+        assert_eq!(response.data[1].embedding, vec![0.3, 0.4]);
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0].request_line, "POST /embeddings HTTP/1.1");
+        let sent: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(sent, json!({"model": "text-embedding-3-small", "input": ["first", "second"]}));
+    }
+}
@@ -21,5 +21,8 @@ pub enum OpenAIError {
 
     #[error("Invalid response format: {0}")]
     InvalidResponse(String),
+
+    #[error("Model returned invalid JSON content: {0}")]
+    InvalidJsonContent(String),
 }
 
@@ -1,6 +1,7 @@
 //! Type definitions for OpenAI API requests and responses
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ChatCompletionRequest {
@@ -12,6 +13,59 @@ pub struct ChatCompletionRequest {
     pub max_tokens: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<bool>,
+    /// Output format of the model, JSON modes make `chat_completion` parse the content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ResponseFormat>,
+}
+
+impl ChatCompletionRequest {
+    /// Create a request for a model with default parameters
+    ///
+    /// `messages` can be a `ChatMessages` builder or a list of `ChatMessage`s.
+    pub fn new(model: impl Into<String>, messages: impl Into<Vec<ChatMessage>>) -> Self {
+        Self {
+            model: model.into(),
+            messages: messages.into(),
+            temperature: None,
+            max_tokens: None,
+            stream: None,
+            response_format: None,
+        }
+    }
+
+    /// Set the output format of the model
+    pub fn with_response_format(mut self, response_format: ResponseFormat) -> Self {
+        self.response_format = Some(response_format);
+        self
+    }
+}
+
+/// Output format of a chat completion
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseFormat {
+    /// Plain text (the API default)
+    Text,
+    /// Any valid JSON object
+    JsonObject,
+    /// JSON matching a schema
+    JsonSchema { json_schema: JsonSchemaFormat },
+}
+
+impl ResponseFormat {
+    /// Whether the model is instructed to answer with JSON
+    pub fn is_json(&self) -> bool {
+        !matches!(self, ResponseFormat::Text)
+    }
+}
+
+/// JSON schema the model output has to match
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonSchemaFormat {
+    pub name: String,
+    pub schema: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +82,65 @@ pub enum MessageRole {
     Assistant,
 }
 
+/// Builder for the message history of a chat completion
+///
+/// ```
+/// use integrations::openai::{ChatCompletionRequest, ChatMessages};
+///
+/// let messages = ChatMessages::new()
+///     .system("You are a helpful assistant.")
+///     .user("What is Rust?");
+/// let request = ChatCompletionRequest::new("gpt-4o-mini", messages);
+/// assert_eq!(request.messages.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(transparent)]
+pub struct ChatMessages {
+    messages: Vec<ChatMessage>,
+}
+
+impl ChatMessages {
+    /// Create an empty message history
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a system message
+    pub fn system(self, content: impl Into<String>) -> Self {
+        self.message(MessageRole::System, content)
+    }
+
+    /// Append a user message
+    pub fn user(self, content: impl Into<String>) -> Self {
+        self.message(MessageRole::User, content)
+    }
+
+    /// Append an assistant message
+    pub fn assistant(self, content: impl Into<String>) -> Self {
+        self.message(MessageRole::Assistant, content)
+    }
+
+    /// Append a message with any role
+    pub fn message(mut self, role: MessageRole, content: impl Into<String>) -> Self {
+        self.messages.push(ChatMessage {
+            role,
+            content: content.into(),
+        });
+        self
+    }
+
+    /// Messages in the order they were added
+    pub fn messages(&self) -> &[ChatMessage] {
+        &self.messages
+    }
+}
+
+impl From<ChatMessages> for Vec<ChatMessage> {
+    fn from(messages: ChatMessages) -> Self {
+        messages.messages
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ChatCompletionResponse {
     pub id: String,
@@ -43,6 +156,9 @@ pub struct ChatChoice {
     pub index: u32,
     pub message: ChatMessage,
     pub finish_reason: Option<String>,
+    /// Content parsed as JSON, set if the request used a JSON response format
+    #[serde(skip)]
+    pub parsed_content: Option<Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -52,3 +168,46 @@ pub struct TokenUsage {
     pub total_tokens: u32,
 }
 
+
+/// Request to create embeddings for a list of texts
+#[derive(Debug, Clone, Serialize)]
+pub struct EmbeddingRequest {
+    pub model: String,
+    pub input: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EmbeddingResponse {
+    pub model: String,
+    /// One embedding per input text, in the order of the input
+    pub data: Vec<Embedding>,
+    pub usage: EmbeddingUsage,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Embedding {
+    /// Position of the embedded text in the input
+    pub index: usize,
+    pub embedding: Vec<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EmbeddingUsage {
+    pub prompt_tokens: u32,
+    pub total_tokens: u32,
+}
+
+/// Model available to the API key, see `OpenAIClient::list_models`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Model {
+    pub id: String,
+    /// Unix timestamp of the creation of the model
+    pub created: i64,
+    pub owned_by: String,
+}
+
+/// Response of `GET /models`
+#[derive(Debug, Deserialize)]
+pub struct ModelList {
+    pub data: Vec<Model>,
+}
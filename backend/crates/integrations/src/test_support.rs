@@ -0,0 +1,106 @@
+//! Helpers for tests against mock HTTP servers
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+/// A request received by a mock server
+pub struct ReceivedRequest {
+    /// Request line, e.g. "GET /user HTTP/1.1"
+    pub request_line: String,
+    /// Header lines
+    pub headers: Vec<String>,
+    /// Request body
+    pub body: String,
+}
+
+impl ReceivedRequest {
+    /// Value of a header (case-insensitive name)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+}
+
+/// A response served by a mock server
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    /// JSON response with a status code
+    pub fn json(status: u16, body: serde_json::Value) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.to_string(),
+        }
+    }
+
+    /// Add a response header
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// Serve the responses in order, one connection per response
+///
+/// Returns the base URL of the server and a handle resolving to the received requests.
+pub async fn serve(responses: Vec<MockResponse>) -> (String, JoinHandle<Vec<ReceivedRequest>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        let mut received = Vec::new();
+        for response in responses {
+            let (mut stream, _) = listener.accept().await.expect("Failed to accept connection");
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 4096];
+
+            let (head, body) = loop {
+                let read = stream.read(&mut buffer).await.expect("Failed to read request");
+                request.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let content_length = head
+                        .lines()
+                        .find_map(|line| {
+                            let (name, value) = line.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length").then(|| value.trim().parse::<usize>().ok())?
+                        })
+                        .unwrap_or(0);
+                    if body.len() >= content_length || read == 0 {
+                        break (head.to_string(), body.to_string());
+                    }
+                }
+            };
+
+            let mut lines = head.lines().map(str::to_string);
+            received.push(ReceivedRequest {
+                request_line: lines.next().unwrap_or_default(),
+                headers: lines.collect(),
+                body,
+            });
+
+            let mut raw = format!(
+                "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+                response.status,
+                response.body.len()
+            );
+            for (name, value) in &response.headers {
+                raw.push_str(&format!("{}: {}\r\n", name, value));
+            }
+            raw.push_str("\r\n");
+            raw.push_str(&response.body);
+            stream.write_all(raw.as_bytes()).await.expect("Failed to write response");
+        }
+        received
+    });
+
+    (base_url, handle)
+}
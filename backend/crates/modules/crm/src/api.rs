@@ -4,22 +4,24 @@
 
 #[allow(unused_imports)]
 use axum::{
-    extract::{Extension, Path, Query},
-    http::StatusCode,
+    body::Body,
+    extract::{Extension, Multipart, Path, Query},
+    http::{header, StatusCode},
     response::{IntoResponse, Json},
     routing::{delete, get, post, put}, // put is used in route definitions (lines 1182, 1187)
     Router,
 };
+use futures_util::TryStreamExt;
 use serde::Deserialize;
 use serde_json::{json, Value as JsonValue};
 
 use crate::customer::{
-    CreateCrmCustomerAddressRequest, CreateCrmCustomerConversationRequest,
-    CreateCrmCustomerNoteRequest, CreateCrmCustomerRequest, CrmCustomer, UpdateCrmCustomerRequest,
-    UpdateCrmCustomerNoteRequest,
+    import_customers_from_csv, stream_customers_csv, CreateCrmCustomerAddressRequest,
+    CreateCrmCustomerConversationRequest, CreateCrmCustomerNoteRequest, CreateCrmCustomerRequest, CrmCustomer,
+    CrmCustomerDatabaseError, UpdateCrmCustomerRequest, UpdateCrmCustomerNoteRequest,
 };
 use flextide_core::database::DatabasePool;
-use flextide_core::events::{Event, EventDispatcher, EventPayload};
+use flextide_core::events::{field_changes, Event, EventDispatcher, EventPayload};
 use flextide_core::jwt::Claims;
 use flextide_core::user::{user_belongs_to_organization, user_has_permission};
 
@@ -149,7 +151,193 @@ pub async fn create_customer(
     })))
 }
 
-/// Delete a customer by UUID
+/// Import customers from a CSV file
+///
+/// POST /api/modules/crm/customers/import
+///
+/// Expects a multipart upload with the CSV in the field `file`. Invalid rows and rows
+/// with an already known email are reported without aborting the import.
+pub async fn import_customers(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Extension(dispatcher): Extension<EventDispatcher>,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Check if user belongs to organization
+    let belongs = user_belongs_to_organization(&pool, &claims.user_uuid, &org_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !belongs {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not belong to this organization" })),
+        ));
+    }
+
+    // Check permission
+    let has_permission = user_has_permission(&pool, &claims.user_uuid, &org_uuid, "module_crm_can_create_customers")
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking permission: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !has_permission {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not have permission to create customers" })),
+        ));
+    }
+
+    // Read the uploaded file
+    let invalid_upload = |e: axum::extract::multipart::MultipartError| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": format!("Invalid upload: {}", e) })),
+        )
+    };
+    let mut csv = None;
+    while let Some(field) = multipart.next_field().await.map_err(invalid_upload)? {
+        if field.name() == Some("file") {
+            csv = Some(field.bytes().await.map_err(invalid_upload)?);
+            break;
+        }
+    }
+    let csv = csv.ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": "Missing CSV file in field 'file'" })),
+        )
+    })?;
+
+    let report = import_customers_from_csv(&pool, &org_uuid, &csv)
+        .await
+        .map_err(|e| match e {
+            CrmCustomerDatabaseError::InvalidImportFile(_) => {
+                (StatusCode::BAD_REQUEST, Json(json!({ "error": e.to_string() })))
+            }
+            _ => {
+                tracing::error!("Error importing customers: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to import customers" })),
+                )
+            }
+        })?;
+
+    // Emit a customer created event for every imported customer
+    for created in &report.created {
+        let event = Event::new(
+            "module_crm_customer_created",
+            EventPayload::new(json!({
+                "entity_type": "customer",
+                "entity_id": created.uuid,
+                "data": {
+                    "first_name": created.first_name,
+                    "last_name": created.last_name,
+                    "email": created.email,
+                    "company_name": created.company_name
+                }
+            }))
+        )
+        .with_organization(&org_uuid)
+        .with_user(&claims.user_uuid);
+
+        dispatcher.emit(event).await;
+    }
+
+    Ok(Json(json!(report)))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportCustomersQuery {
+    /// Include archived customers in the export
+    #[serde(default)]
+    pub include_archived: bool,
+}
+
+/// Export the customers of the organization as CSV
+///
+/// GET /api/modules/crm/customers/export
+///
+/// Customers are streamed from the database and sent row by row, nothing is buffered. A
+/// database error after the first row aborts the response.
+pub async fn export_customers(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Query(params): Query<ExportCustomersQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Check if user belongs to organization
+    let belongs = user_belongs_to_organization(&pool, &claims.user_uuid, &org_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !belongs {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not belong to this organization" })),
+        ));
+    }
+
+    // Check permission
+    let has_permission = user_has_permission(&pool, &claims.user_uuid, &org_uuid, "module_crm_can_see_all_customers")
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking permission: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !has_permission {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not have permission to view all customers" })),
+        ));
+    }
+
+    let csv = stream_customers_csv(pool, org_uuid, params.include_archived).inspect_err(|e| {
+        tracing::error!("Error exporting customers: {}", e);
+    });
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (header::CONTENT_DISPOSITION, "attachment; filename=\"customers.csv\""),
+        ],
+        Body::from_stream(csv),
+    ))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteCustomerQuery {
+    /// Delete the customer with all notes, addresses and conversations instead of archiving it
+    #[serde(default)]
+    pub permanent: bool,
+}
+
+/// Archive a customer by UUID, or delete it permanently with `?permanent=true`
+///
+/// Archived customers can be brought back with `POST /api/modules/crm/customers/{uuid}/restore`.
 ///
 /// DELETE /api/modules/crm/customers/{uuid}
 pub async fn delete_customer(
@@ -158,6 +346,7 @@ pub async fn delete_customer(
     Extension(claims): Extension<Claims>,
     Extension(dispatcher): Extension<EventDispatcher>,
     Path(customer_uuid): Path<String>,
+    Query(query): Query<DeleteCustomerQuery>,
 ) -> Result<Json<JsonValue>, (StatusCode, Json<JsonValue>)> {
     // Check if user belongs to organization
     let belongs = user_belongs_to_organization(&pool, &claims.user_uuid, &org_uuid)
@@ -222,6 +411,37 @@ pub async fn delete_customer(
         "company_name": customer.company_name
     });
 
+    if !query.permanent {
+        customer
+            .archive(&pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("Error archiving customer: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to archive customer" })),
+                )
+            })?;
+
+        let event = Event::new(
+            "module_crm_customer_archived",
+            EventPayload::new(json!({
+                "entity_type": "customer",
+                "entity_id": customer_uuid,
+                "data": customer_data
+            }))
+        )
+        .with_organization(&org_uuid)
+        .with_user(&claims.user_uuid);
+
+        // Emit event (non-blocking - errors are logged internally)
+        dispatcher.emit(event).await;
+
+        return Ok(Json(json!({
+            "message": "Customer archived successfully"
+        })));
+    }
+
     // Delete customer
     customer
         .delete(&pool)
@@ -254,6 +474,112 @@ pub async fn delete_customer(
     })))
 }
 
+/// Restore an archived customer by UUID
+///
+/// POST /api/modules/crm/customers/{uuid}/restore
+pub async fn restore_customer(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Extension(dispatcher): Extension<EventDispatcher>,
+    Path(customer_uuid): Path<String>,
+) -> Result<Json<JsonValue>, (StatusCode, Json<JsonValue>)> {
+    // Check if user belongs to organization
+    let belongs = user_belongs_to_organization(&pool, &claims.user_uuid, &org_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !belongs {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not belong to this organization" })),
+        ));
+    }
+
+    // Restoring undoes a deletion, so it needs the same permission
+    let has_permission = user_has_permission(&pool, &claims.user_uuid, &org_uuid, "module_crm_can_delete_customers")
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking permission: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !has_permission {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not have permission to restore customers" })),
+        ));
+    }
+
+    let customer = CrmCustomer::load_from_database(&pool, &customer_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Error loading customer: {}", e);
+            (
+                StatusCode::NOT_FOUND,
+                Json(json!({ "error": "Customer not found" })),
+            )
+        })?;
+
+    // Verify customer belongs to the organization
+    if customer.organization_uuid != org_uuid {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "Customer does not belong to this organization" })),
+        ));
+    }
+
+    if !customer.is_archived() {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(json!({ "error": "Customer is not archived" })),
+        ));
+    }
+
+    customer
+        .restore(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Error restoring customer: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to restore customer" })),
+            )
+        })?;
+
+    let event = Event::new(
+        "module_crm_customer_restored",
+        EventPayload::new(json!({
+            "entity_type": "customer",
+            "entity_id": customer_uuid,
+            "data": {
+                "first_name": customer.first_name,
+                "last_name": customer.last_name,
+                "email": customer.email,
+                "company_name": customer.company_name
+            }
+        }))
+    )
+    .with_organization(&org_uuid)
+    .with_user(&claims.user_uuid);
+
+    // Emit event (non-blocking - errors are logged internally)
+    dispatcher.emit(event).await;
+
+    Ok(Json(json!({
+        "message": "Customer restored successfully"
+    })))
+}
+
 /// Add a note to a customer
 ///
 /// POST /api/modules/crm/customers/{uuid}/notes
@@ -1058,6 +1384,91 @@ pub async fn get_customer_conversations(
     Ok(Json(json!(conversations)))
 }
 
+/// Get a customer conversation with all of its replies
+///
+/// GET /api/modules/crm/customers/{uuid}/conversations/{conversation_uuid}/thread
+pub async fn get_customer_conversation_thread(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Path((customer_uuid, conversation_uuid)): Path<(String, String)>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Check if user belongs to organization
+    let belongs = user_belongs_to_organization(&pool, &claims.user_uuid, &org_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !belongs {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not belong to this organization" })),
+        ));
+    }
+
+    // Check permission
+    let has_permission = user_has_permission(&pool, &claims.user_uuid, &org_uuid, "module_crm_can_see_customer")
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking permission: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !has_permission {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not have permission to view customer details" })),
+        ));
+    }
+
+    // Load customer to verify it belongs to the organization
+    let customer = CrmCustomer::load_from_database(&pool, &customer_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Error loading customer: {}", e);
+            (
+                StatusCode::NOT_FOUND,
+                Json(json!({ "error": "Customer not found" })),
+            )
+        })?;
+
+    // Verify customer belongs to the organization
+    if customer.organization_uuid != org_uuid {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "Customer does not belong to this organization" })),
+        ));
+    }
+
+    // Load the thread
+    let thread = customer
+        .list_conversation_thread(&pool, &conversation_uuid)
+        .await
+        .map_err(|e| match e {
+            CrmCustomerDatabaseError::ConversationNotFound(_) => (
+                StatusCode::NOT_FOUND,
+                Json(json!({ "error": "Conversation not found" })),
+            ),
+            e => {
+                tracing::error!("Error loading conversation thread: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to load conversation thread" })),
+                )
+            }
+        })?;
+
+    Ok(Json(json!(thread)))
+}
+
 /// Add a conversation to a customer
 ///
 /// POST /api/modules/crm/customers/{uuid}/conversations
@@ -1127,12 +1538,18 @@ pub async fn add_customer_conversation(
     let conversation_uuid = customer
         .add_conversation(&pool, request)
         .await
-        .map_err(|e| {
-            tracing::error!("Error adding conversation: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(json!({ "error": "Failed to add conversation" })),
-            )
+        .map_err(|e| match e {
+            CrmCustomerDatabaseError::ParentConversationNotFound(_) => (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": "Parent conversation not found for this customer" })),
+            ),
+            e => {
+                tracing::error!("Error adding conversation: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to add conversation" })),
+                )
+            }
         })?;
 
     Ok(Json(json!({
@@ -1224,12 +1641,18 @@ pub async fn update_customer(
         .await
         .ok();
 
-    // Emit customer updated event
+    // Emit customer updated event, with the changed fields compared to the customer
+    // loaded before the update
+    let changes = updated_customer
+        .as_ref()
+        .map(|c| field_changes(&customer_editable_fields(&customer), &customer_editable_fields(c)))
+        .unwrap_or(json!({}));
     let event = Event::new(
         "module_crm_customer_updated",
         EventPayload::new(json!({
             "entity_type": "customer",
             "entity_id": customer_uuid,
+            "changes": changes,
             "data": updated_customer.as_ref().map(|c| json!({
                 "first_name": c.first_name,
                 "last_name": c.last_name,
@@ -1249,6 +1672,24 @@ pub async fn update_customer(
     })))
 }
 
+/// Fields of a customer that can be changed with `UpdateCrmCustomerRequest`
+fn customer_editable_fields(customer: &CrmCustomer) -> JsonValue {
+    json!({
+        "first_name": customer.first_name,
+        "last_name": customer.last_name,
+        "email": customer.email,
+        "phone_number": customer.phone_number,
+        "user_id": customer.user_id,
+        "salutation": customer.salutation,
+        "job_title": customer.job_title,
+        "department": customer.department,
+        "company_name": customer.company_name,
+        "fax_number": customer.fax_number,
+        "website_url": customer.website_url,
+        "gender": customer.gender
+    })
+}
+
 /// Create the API router for CRM endpoints
 pub fn create_api_router<S>() -> Router<S>
 where
@@ -1257,7 +1698,10 @@ where
     Router::new()
         .route("/modules/crm/customers", post(create_customer))
         .route("/modules/crm/customers/search", get(search_customers))
+        .route("/modules/crm/customers/import", post(import_customers))
+        .route("/modules/crm/customers/export", get(export_customers))
         .route("/modules/crm/customers/{uuid}", get(get_customer).put(update_customer).delete(delete_customer))
+        .route("/modules/crm/customers/{uuid}/restore", post(restore_customer))
         .route("/modules/crm/customers/{uuid}/kpis", get(get_customer_kpis))
         .route("/modules/crm/customers/{uuid}/notes", get(get_customer_notes).post(add_customer_note))
         .route(
@@ -1265,6 +1709,10 @@ where
             delete(delete_customer_note).put(update_customer_note),
         )
         .route("/modules/crm/customers/{uuid}/conversations", get(get_customer_conversations).post(add_customer_conversation))
+        .route(
+            "/modules/crm/customers/{uuid}/conversations/{conversation_uuid}/thread",
+            get(get_customer_conversation_thread),
+        )
         .route("/modules/crm/customers/{uuid}/addresses", post(add_customer_address))
         .route(
             "/modules/crm/customers/{uuid}/addresses/{address_uuid}",
@@ -0,0 +1,417 @@
+//! CSV export of CRM customers
+//!
+//! Customers are read with a streaming query and written to the CSV one row at a time,
+//! so exporting a large organization does not hold all customers in memory.
+
+use std::io::Write;
+
+use chrono::{DateTime, Utc};
+use flextide_core::database::DatabasePool;
+use futures_util::stream::{self, BoxStream, Stream};
+use futures_util::{StreamExt, TryStreamExt};
+use sqlx::Row;
+
+use crate::customer::database::CrmCustomerDatabaseError;
+use crate::customer::CrmCustomer;
+
+/// Columns of the CSV export, in order
+///
+/// Columns shared with [`import_customers_from_csv`](crate::customer::import_customers_from_csv)
+/// use the same names.
+pub const CUSTOMER_EXPORT_COLUMNS: &[&str] = &[
+    "uuid",
+    "first_name",
+    "last_name",
+    "email",
+    "phone_number",
+    "salutation",
+    "job_title",
+    "department",
+    "company_name",
+    "fax_number",
+    "website_url",
+    "gender",
+    "created_at",
+    "updated_at",
+    "archived_at",
+];
+
+/// Number of encoded rows [`stream_customers_csv`] reads ahead of its consumer
+const CSV_STREAM_BUFFER: usize = 64;
+
+/// Stream all customers of an organization, sorted by last name, then first name
+///
+/// Rows are fetched from the database while the stream is consumed instead of being
+/// loaded up front.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization
+/// * `include_archived` - Whether archived customers are included
+///
+/// # Returns
+/// A stream yielding one `CrmCustomer` per row
+///
+/// # Errors
+/// The stream yields `CrmCustomerDatabaseError` if the database query fails
+pub fn stream_customers<'a>(
+    pool: &'a DatabasePool,
+    organization_uuid: &'a str,
+    include_archived: bool,
+) -> BoxStream<'a, Result<CrmCustomer, CrmCustomerDatabaseError>> {
+    match pool {
+        DatabasePool::MySql(p) => {
+            let sql = if include_archived {
+                "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number,
+                 user_id, salutation, job_title, department, company_name, fax_number,
+                 website_url, gender, created_at, updated_at, archived_at
+                 FROM module_crm_customers
+                 WHERE organization_uuid = ?
+                 ORDER BY last_name ASC, first_name ASC, uuid ASC"
+            } else {
+                "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number,
+                 user_id, salutation, job_title, department, company_name, fax_number,
+                 website_url, gender, created_at, updated_at, archived_at
+                 FROM module_crm_customers
+                 WHERE organization_uuid = ? AND archived_at IS NULL
+                 ORDER BY last_name ASC, first_name ASC, uuid ASC"
+            };
+
+            sqlx::query(sql)
+                .bind(organization_uuid)
+                .fetch(p)
+                .map_ok(|row| CrmCustomer {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    first_name: row.get("first_name"),
+                    last_name: row.get("last_name"),
+                    email: row.get::<Option<String>, _>("email"),
+                    phone_number: row.get::<Option<String>, _>("phone_number"),
+                    user_id: row.get::<Option<String>, _>("user_id"),
+                    salutation: row.get::<Option<String>, _>("salutation"),
+                    job_title: row.get::<Option<String>, _>("job_title"),
+                    department: row.get::<Option<String>, _>("department"),
+                    company_name: row.get::<Option<String>, _>("company_name"),
+                    fax_number: row.get::<Option<String>, _>("fax_number"),
+                    website_url: row.get::<Option<String>, _>("website_url"),
+                    gender: row.get::<Option<String>, _>("gender"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
+                })
+                .map_err(CrmCustomerDatabaseError::from)
+                .boxed()
+        }
+        DatabasePool::Postgres(p) => {
+            let sql = if include_archived {
+                "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number,
+                 user_id, salutation, job_title, department, company_name, fax_number,
+                 website_url, gender, created_at, updated_at, archived_at
+                 FROM module_crm_customers
+                 WHERE organization_uuid = $1
+                 ORDER BY last_name ASC, first_name ASC, uuid ASC"
+            } else {
+                "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number,
+                 user_id, salutation, job_title, department, company_name, fax_number,
+                 website_url, gender, created_at, updated_at, archived_at
+                 FROM module_crm_customers
+                 WHERE organization_uuid = $1 AND archived_at IS NULL
+                 ORDER BY last_name ASC, first_name ASC, uuid ASC"
+            };
+
+            sqlx::query(sql)
+                .bind(organization_uuid)
+                .fetch(p)
+                .map_ok(|row| CrmCustomer {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    first_name: row.get("first_name"),
+                    last_name: row.get("last_name"),
+                    email: row.get::<Option<String>, _>("email"),
+                    phone_number: row.get::<Option<String>, _>("phone_number"),
+                    user_id: row.get::<Option<String>, _>("user_id"),
+                    salutation: row.get::<Option<String>, _>("salutation"),
+                    job_title: row.get::<Option<String>, _>("job_title"),
+                    department: row.get::<Option<String>, _>("department"),
+                    company_name: row.get::<Option<String>, _>("company_name"),
+                    fax_number: row.get::<Option<String>, _>("fax_number"),
+                    website_url: row.get::<Option<String>, _>("website_url"),
+                    gender: row.get::<Option<String>, _>("gender"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
+                })
+                .map_err(CrmCustomerDatabaseError::from)
+                .boxed()
+        }
+        DatabasePool::Sqlite(p) => {
+            let sql = if include_archived {
+                "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number,
+                 user_id, salutation, job_title, department, company_name, fax_number,
+                 website_url, gender, created_at, updated_at, archived_at
+                 FROM module_crm_customers
+                 WHERE organization_uuid = ?1
+                 ORDER BY last_name ASC, first_name ASC, uuid ASC"
+            } else {
+                "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number,
+                 user_id, salutation, job_title, department, company_name, fax_number,
+                 website_url, gender, created_at, updated_at, archived_at
+                 FROM module_crm_customers
+                 WHERE organization_uuid = ?1 AND archived_at IS NULL
+                 ORDER BY last_name ASC, first_name ASC, uuid ASC"
+            };
+
+            sqlx::query(sql)
+                .bind(organization_uuid)
+                .fetch(p)
+                .map_ok(|row| CrmCustomer {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    first_name: row.get("first_name"),
+                    last_name: row.get("last_name"),
+                    email: row.get::<Option<String>, _>("email"),
+                    phone_number: row.get::<Option<String>, _>("phone_number"),
+                    user_id: row.get::<Option<String>, _>("user_id"),
+                    salutation: row.get::<Option<String>, _>("salutation"),
+                    job_title: row.get::<Option<String>, _>("job_title"),
+                    department: row.get::<Option<String>, _>("department"),
+                    company_name: row.get::<Option<String>, _>("company_name"),
+                    fax_number: row.get::<Option<String>, _>("fax_number"),
+                    website_url: row.get::<Option<String>, _>("website_url"),
+                    gender: row.get::<Option<String>, _>("gender"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
+                })
+                .map_err(CrmCustomerDatabaseError::from)
+                .boxed()
+        }
+    }
+}
+
+/// Write all customers of an organization as CSV
+///
+/// The first line holds [`CUSTOMER_EXPORT_COLUMNS`]. Customers are written as they are
+/// read from [`stream_customers`].
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization
+/// * `include_archived` - Whether archived customers are included
+/// * `writer` - Destination of the CSV
+///
+/// # Returns
+/// The number of exported customers
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError` if:
+/// - A database operation fails
+/// - Writing to `writer` fails (`ExportWrite`)
+pub async fn export_customers_to_csv<W: Write>(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    include_archived: bool,
+    writer: W,
+) -> Result<usize, CrmCustomerDatabaseError> {
+    let write_error = |e: csv::Error| CrmCustomerDatabaseError::ExportWrite(e.to_string());
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer.write_record(CUSTOMER_EXPORT_COLUMNS).map_err(write_error)?;
+
+    let mut customers = stream_customers(pool, organization_uuid, include_archived);
+    let mut count = 0;
+    while let Some(customer) = customers.try_next().await? {
+        csv_writer.write_record(customer_record(&customer)).map_err(write_error)?;
+        count += 1;
+    }
+
+    csv_writer
+        .flush()
+        .map_err(|e| CrmCustomerDatabaseError::ExportWrite(e.to_string()))?;
+    Ok(count)
+}
+
+/// Stream all customers of an organization as CSV, one encoded row per item
+///
+/// The first item holds [`CUSTOMER_EXPORT_COLUMNS`]. Unlike [`export_customers_to_csv`],
+/// the stream owns its arguments, so it can be used as an HTTP response body. Customers
+/// are read by a background task that stops once the stream is dropped.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization
+/// * `include_archived` - Whether archived customers are included
+///
+/// # Returns
+/// A stream yielding the CSV in chunks of one line each
+///
+/// # Errors
+/// The stream yields `CrmCustomerDatabaseError` and ends if a database query fails or a
+/// row cannot be encoded (`ExportWrite`)
+pub fn stream_customers_csv(
+    pool: DatabasePool,
+    organization_uuid: String,
+    include_archived: bool,
+) -> impl Stream<Item = Result<Vec<u8>, CrmCustomerDatabaseError>> + Send + 'static {
+    let (sender, receiver) = tokio::sync::mpsc::channel(CSV_STREAM_BUFFER);
+
+    tokio::spawn(async move {
+        if sender.send(encode_record(CUSTOMER_EXPORT_COLUMNS)).await.is_err() {
+            return;
+        }
+
+        let mut customers = stream_customers(&pool, &organization_uuid, include_archived);
+        while let Some(customer) = customers.next().await {
+            let line = customer.and_then(|customer| encode_record(customer_record(&customer)));
+            let failed = line.is_err();
+            // Sending fails once the stream was dropped, e.g. because the client disconnected
+            if sender.send(line).await.is_err() || failed {
+                return;
+            }
+        }
+    });
+
+    stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|line| (line, receiver))
+    })
+}
+
+/// Encode a single CSV line
+fn encode_record<I, T>(record: I) -> Result<Vec<u8>, CrmCustomerDatabaseError>
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]>,
+{
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    csv_writer
+        .write_record(record)
+        .map_err(|e| CrmCustomerDatabaseError::ExportWrite(e.to_string()))?;
+    csv_writer
+        .into_inner()
+        .map_err(|e| CrmCustomerDatabaseError::ExportWrite(e.to_string()))
+}
+
+/// Fields of a customer in the order of [`CUSTOMER_EXPORT_COLUMNS`]
+fn customer_record(customer: &CrmCustomer) -> [String; 15] {
+    let optional = |value: &Option<String>| value.clone().unwrap_or_default();
+    [
+        customer.uuid.clone(),
+        customer.first_name.clone(),
+        customer.last_name.clone(),
+        optional(&customer.email),
+        optional(&customer.phone_number),
+        optional(&customer.salutation),
+        optional(&customer.job_title),
+        optional(&customer.department),
+        optional(&customer.company_name),
+        optional(&customer.fax_number),
+        optional(&customer.website_url),
+        optional(&customer.gender),
+        customer.created_at.to_rfc3339(),
+        customer.updated_at.to_rfc3339(),
+        customer.archived_at.map(|at| at.to_rfc3339()).unwrap_or_default(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_organization, create_user, setup_tables, sqlite};
+
+    const SEEDED_CUSTOMERS: i64 = 5000;
+
+    /// Insert `count` customers in one statement
+    async fn seed_customers(pool: &DatabasePool, org_uuid: &str, count: i64) {
+        sqlx::query(
+            "WITH RECURSIVE seq(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM seq WHERE n < ?2)
+             INSERT INTO module_crm_customers (uuid, organization_uuid, first_name, last_name, email)
+             SELECT printf('00000000-0000-0000-0000-%012d', n), ?1, 'Customer', printf('Last %05d', n),
+                    printf('customer%d@example.com', n)
+             FROM seq",
+        )
+        .bind(org_uuid)
+        .bind(count)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to seed customers");
+    }
+
+    #[sqlx::test]
+    async fn test_streams_large_table_row_by_row(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        seed_customers(&pool, &org_uuid, SEEDED_CUSTOMERS).await;
+
+        // The first customer is available without reading the rest of the table
+        let mut customers = stream_customers(&pool, &org_uuid, false);
+        let first = customers.try_next().await.unwrap().expect("No customer streamed");
+        assert_eq!(first.last_name, "Last 00001");
+        drop(customers);
+
+        // Only the previous customer is kept while the rest is consumed
+        let (count, last) = stream_customers(&pool, &org_uuid, false)
+            .try_fold((0, None::<CrmCustomer>), |(count, previous), customer| async move {
+                if let Some(previous) = previous {
+                    assert!(previous.last_name < customer.last_name);
+                }
+                Ok((count + 1, Some(customer)))
+            })
+            .await
+            .unwrap();
+        assert_eq!(count, SEEDED_CUSTOMERS);
+        assert_eq!(last.unwrap().last_name, format!("Last {:05}", SEEDED_CUSTOMERS));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_streamed_csv_matches_written_csv(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        seed_customers(&pool, &org_uuid, 3).await;
+
+        let lines: Vec<Vec<u8>> = stream_customers_csv(pool.clone(), org_uuid.clone(), false)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(lines.len(), 4);
+
+        let mut written = Vec::new();
+        export_customers_to_csv(&pool, &org_uuid, false, &mut written).await.unwrap();
+        assert_eq!(lines.concat(), written);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_exports_customers_as_csv(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        seed_customers(&pool, &org_uuid, 3).await;
+        sqlx::query("UPDATE module_crm_customers SET archived_at = CURRENT_TIMESTAMP WHERE last_name = 'Last 00002'")
+            .execute(sqlite(&pool))
+            .await?;
+
+        let mut csv = Vec::new();
+        let count = export_customers_to_csv(&pool, &org_uuid, false, &mut csv).await.unwrap();
+        assert_eq!(count, 2);
+
+        let mut reader = csv::Reader::from_reader(csv.as_slice());
+        assert_eq!(reader.headers().unwrap(), CUSTOMER_EXPORT_COLUMNS);
+        let last_names: Vec<String> = reader
+            .records()
+            .map(|record| record.unwrap()[2].to_string())
+            .collect();
+        assert_eq!(last_names, ["Last 00001", "Last 00003"]);
+
+        Ok(())
+    }
+}
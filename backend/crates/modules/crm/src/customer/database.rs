@@ -2,14 +2,19 @@
 
 use crate::customer::{
     CreateCrmCustomerAddressRequest, CreateCrmCustomerConversationRequest,
-    CreateCrmCustomerNoteRequest, CreateCrmCustomerRequest, CrmCustomer, CrmCustomerConversation,
-    CrmCustomerNote, UpdateCrmCustomerRequest, UpdateCrmCustomerNoteRequest,
+    CreateCrmCustomerNoteRequest, CreateCrmCustomerRequest, CrmCustomer, CrmCustomerAddress,
+    CrmCustomerConversation, CrmCustomerNote, CrmCustomerSort, UpdateCrmCustomerRequest, UpdateCrmCustomerNoteRequest,
 };
 use chrono::{DateTime, Utc};
-use flextide_core::database::{DatabaseError, DatabasePool};
+use flextide_core::database::{begin_with_statement_timeout, with_statement_timeout, DatabaseError, DatabasePool};
+use flextide_core::pagination::{clamp_pagination, Pagination, MAX_PAGE_SIZE};
 use sqlx::Row;
+use std::time::Duration;
 use thiserror::Error;
 
+/// Statement timeout of the customer count queries
+pub const CUSTOMER_COUNT_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Error type for CRM customer database operations
 #[derive(Debug, Error)]
 pub enum CrmCustomerDatabaseError {
@@ -27,6 +32,30 @@ pub enum CrmCustomerDatabaseError {
 
     #[error("Address type cannot be empty")]
     EmptyAddressType,
+
+    #[error("Customer does not belong to this organization")]
+    CustomerNotInOrganization,
+
+    #[error("Invalid import file: {0}")]
+    InvalidImportFile(String),
+
+    #[error("Failed to write export: {0}")]
+    ExportWrite(String),
+
+    #[error("Invalid sort column: {0}")]
+    InvalidSortColumn(String),
+
+    #[error("Invalid sort direction: {0}")]
+    InvalidSortDirection(String),
+
+    #[error("Conversation not found: {0}")]
+    ConversationNotFound(String),
+
+    #[error("Parent conversation not found for this customer: {0}")]
+    ParentConversationNotFound(String),
+
+    #[error("A conversation cannot be a reply to itself or to one of its replies")]
+    ConversationCycle,
 }
 
 /// Load a customer from the database by UUID
@@ -46,7 +75,7 @@ pub async fn load_customer_by_uuid(
             let row = sqlx::query(
                 "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number, 
                  user_id, salutation, job_title, department, company_name, fax_number, 
-                 website_url, gender, created_at, updated_at 
+                 website_url, gender, created_at, updated_at, archived_at 
                  FROM module_crm_customers WHERE uuid = ?",
             )
             .bind(customer_uuid)
@@ -71,6 +100,7 @@ pub async fn load_customer_by_uuid(
                     gender: row.get::<Option<String>, _>("gender"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                     updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 }),
                 None => Err(CrmCustomerDatabaseError::Sql(sqlx::Error::RowNotFound)),
             }
@@ -79,7 +109,7 @@ pub async fn load_customer_by_uuid(
             let row = sqlx::query(
                 "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number, 
                  user_id, salutation, job_title, department, company_name, fax_number, 
-                 website_url, gender, created_at, updated_at 
+                 website_url, gender, created_at, updated_at, archived_at 
                  FROM module_crm_customers WHERE uuid = $1",
             )
             .bind(customer_uuid)
@@ -104,6 +134,7 @@ pub async fn load_customer_by_uuid(
                     gender: row.get::<Option<String>, _>("gender"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                     updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 }),
                 None => Err(CrmCustomerDatabaseError::Sql(sqlx::Error::RowNotFound)),
             }
@@ -112,7 +143,7 @@ pub async fn load_customer_by_uuid(
             let row = sqlx::query(
                 "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number, 
                  user_id, salutation, job_title, department, company_name, fax_number, 
-                 website_url, gender, created_at, updated_at 
+                 website_url, gender, created_at, updated_at, archived_at 
                  FROM module_crm_customers WHERE uuid = ?1",
             )
             .bind(customer_uuid)
@@ -137,6 +168,7 @@ pub async fn load_customer_by_uuid(
                     gender: row.get::<Option<String>, _>("gender"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                     updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 }),
                 None => Err(CrmCustomerDatabaseError::Sql(sqlx::Error::RowNotFound)),
             }
@@ -302,6 +334,110 @@ pub async fn delete_customer(
     Ok(())
 }
 
+/// Archive a customer, archiving an already archived customer is a no-op
+///
+/// Archived customers keep all their notes, addresses and conversations, but are hidden
+/// from customer lists and search until they are restored.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `customer_uuid` - UUID of the customer to archive
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError` if the database operation fails
+pub async fn archive_customer(
+    pool: &DatabasePool,
+    customer_uuid: &str,
+) -> Result<(), CrmCustomerDatabaseError> {
+    let now = Utc::now();
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query(
+                "UPDATE module_crm_customers SET archived_at = ?, updated_at = ?
+                 WHERE uuid = ? AND archived_at IS NULL",
+            )
+            .bind(now)
+            .bind(now)
+            .bind(customer_uuid)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query(
+                "UPDATE module_crm_customers SET archived_at = $1, updated_at = $1
+                 WHERE uuid = $2 AND archived_at IS NULL",
+            )
+            .bind(now)
+            .bind(customer_uuid)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query(
+                "UPDATE module_crm_customers SET archived_at = ?1, updated_at = ?1
+                 WHERE uuid = ?2 AND archived_at IS NULL",
+            )
+            .bind(now)
+            .bind(customer_uuid)
+            .execute(p)
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Restore an archived customer, restoring a customer that is not archived is a no-op
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `customer_uuid` - UUID of the customer to restore
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError` if the database operation fails
+pub async fn restore_customer(
+    pool: &DatabasePool,
+    customer_uuid: &str,
+) -> Result<(), CrmCustomerDatabaseError> {
+    let now = Utc::now();
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query(
+                "UPDATE module_crm_customers SET archived_at = NULL, updated_at = ?
+                 WHERE uuid = ? AND archived_at IS NOT NULL",
+            )
+            .bind(now)
+            .bind(customer_uuid)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query(
+                "UPDATE module_crm_customers SET archived_at = NULL, updated_at = $1
+                 WHERE uuid = $2 AND archived_at IS NOT NULL",
+            )
+            .bind(now)
+            .bind(customer_uuid)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query(
+                "UPDATE module_crm_customers SET archived_at = NULL, updated_at = ?1
+                 WHERE uuid = ?2 AND archived_at IS NOT NULL",
+            )
+            .bind(now)
+            .bind(customer_uuid)
+            .execute(p)
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Create a new customer note in the database
 ///
 /// # Arguments
@@ -795,6 +931,112 @@ pub async fn create_customer_address(
     Ok(address_uuid)
 }
 
+/// Load all addresses for a customer
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `customer_uuid` - UUID of the customer
+///
+/// # Returns
+/// Returns a vector of `CrmCustomerAddress`, primary addresses first, then by creation date (oldest first)
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError` if the database query fails
+pub async fn load_customer_addresses(
+    pool: &DatabasePool,
+    customer_uuid: &str,
+) -> Result<Vec<CrmCustomerAddress>, CrmCustomerDatabaseError> {
+    match pool {
+        DatabasePool::MySql(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, customer_uuid, address_type, street, city, state_province,
+                 postal_code, country, is_primary, created_at, updated_at
+                 FROM module_crm_customer_addresses
+                 WHERE customer_uuid = ?
+                 ORDER BY is_primary DESC, created_at ASC",
+            )
+            .bind(customer_uuid)
+            .fetch_all(p)
+            .await?;
+
+            Ok(rows
+                .into_iter()
+                .map(|row| CrmCustomerAddress {
+                    uuid: row.get("uuid"),
+                    customer_uuid: row.get("customer_uuid"),
+                    address_type: row.get("address_type"),
+                    street: row.get::<Option<String>, _>("street"),
+                    city: row.get::<Option<String>, _>("city"),
+                    state_province: row.get::<Option<String>, _>("state_province"),
+                    postal_code: row.get::<Option<String>, _>("postal_code"),
+                    country: row.get::<Option<String>, _>("country"),
+                    is_primary: row.get::<i64, _>("is_primary") != 0,
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                })
+                .collect())
+        }
+        DatabasePool::Postgres(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, customer_uuid, address_type, street, city, state_province,
+                 postal_code, country, is_primary, created_at, updated_at
+                 FROM module_crm_customer_addresses
+                 WHERE customer_uuid = $1
+                 ORDER BY is_primary DESC, created_at ASC",
+            )
+            .bind(customer_uuid)
+            .fetch_all(p)
+            .await?;
+
+            Ok(rows
+                .into_iter()
+                .map(|row| CrmCustomerAddress {
+                    uuid: row.get("uuid"),
+                    customer_uuid: row.get("customer_uuid"),
+                    address_type: row.get("address_type"),
+                    street: row.get::<Option<String>, _>("street"),
+                    city: row.get::<Option<String>, _>("city"),
+                    state_province: row.get::<Option<String>, _>("state_province"),
+                    postal_code: row.get::<Option<String>, _>("postal_code"),
+                    country: row.get::<Option<String>, _>("country"),
+                    is_primary: row.get::<i32, _>("is_primary") != 0,
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                })
+                .collect())
+        }
+        DatabasePool::Sqlite(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, customer_uuid, address_type, street, city, state_province,
+                 postal_code, country, is_primary, created_at, updated_at
+                 FROM module_crm_customer_addresses
+                 WHERE customer_uuid = ?1
+                 ORDER BY is_primary DESC, created_at ASC",
+            )
+            .bind(customer_uuid)
+            .fetch_all(p)
+            .await?;
+
+            Ok(rows
+                .into_iter()
+                .map(|row| CrmCustomerAddress {
+                    uuid: row.get("uuid"),
+                    customer_uuid: row.get("customer_uuid"),
+                    address_type: row.get("address_type"),
+                    street: row.get::<Option<String>, _>("street"),
+                    city: row.get::<Option<String>, _>("city"),
+                    state_province: row.get::<Option<String>, _>("state_province"),
+                    postal_code: row.get::<Option<String>, _>("postal_code"),
+                    country: row.get::<Option<String>, _>("country"),
+                    is_primary: row.get::<i64, _>("is_primary") != 0,
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                })
+                .collect())
+        }
+    }
+}
+
 /// Delete a customer address from the database
 ///
 /// # Arguments
@@ -863,7 +1105,7 @@ pub async fn delete_customer_address(
     Ok(())
 }
 
-/// Search customers by query string
+/// Search customers by query string, archived customers are skipped
 ///
 /// # Arguments
 /// * `pool` - Database connection pool
@@ -887,9 +1129,10 @@ pub async fn search_customers(
             let rows = sqlx::query(
                 "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number, 
                  user_id, salutation, job_title, department, company_name, fax_number, 
-                 website_url, gender, created_at, updated_at 
+                 website_url, gender, created_at, updated_at, archived_at 
                  FROM module_crm_customers 
                  WHERE organization_uuid = ? 
+                 AND archived_at IS NULL
                  AND (
                      first_name LIKE ? 
                      OR last_name LIKE ? 
@@ -929,6 +1172,7 @@ pub async fn search_customers(
                     gender: row.get::<Option<String>, _>("gender"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                     updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 })
                 .collect())
         }
@@ -936,9 +1180,10 @@ pub async fn search_customers(
             let rows = sqlx::query(
                 "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number, 
                  user_id, salutation, job_title, department, company_name, fax_number, 
-                 website_url, gender, created_at, updated_at 
+                 website_url, gender, created_at, updated_at, archived_at 
                  FROM module_crm_customers 
                  WHERE organization_uuid = $1 
+                 AND archived_at IS NULL
                  AND (
                      first_name ILIKE $2 
                      OR last_name ILIKE $2 
@@ -973,6 +1218,7 @@ pub async fn search_customers(
                     gender: row.get::<Option<String>, _>("gender"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                     updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 })
                 .collect())
         }
@@ -980,9 +1226,10 @@ pub async fn search_customers(
             let rows = sqlx::query(
                 "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number, 
                  user_id, salutation, job_title, department, company_name, fax_number, 
-                 website_url, gender, created_at, updated_at 
+                 website_url, gender, created_at, updated_at, archived_at 
                  FROM module_crm_customers 
                  WHERE organization_uuid = ?1 
+                 AND archived_at IS NULL
                  AND (
                      first_name LIKE ?2 
                      OR last_name LIKE ?2 
@@ -1017,19 +1264,146 @@ pub async fn search_customers(
                     gender: row.get::<Option<String>, _>("gender"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                     updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 })
                 .collect())
         }
     }
 }
 
+/// Count the customers of an organization, archived customers are not counted
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization to count customers for
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError` if the database query fails
+pub async fn count_customers(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+) -> Result<u32, CrmCustomerDatabaseError> {
+    count_customers_filtered(pool, organization_uuid, false).await
+}
+
+/// Count the customers of an organization, optionally including archived customers
+///
+/// The count feeds the KPI dashboard, so it runs with [`CUSTOMER_COUNT_TIMEOUT`].
+async fn count_customers_filtered(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    include_archived: bool,
+) -> Result<u32, CrmCustomerDatabaseError> {
+    let archived_filter = if include_archived { "" } else { " AND archived_at IS NULL" };
+
+    let count: i64 = match pool {
+        DatabasePool::MySql(p) => {
+            let sql = format!(
+                "SELECT COUNT(*) as count FROM module_crm_customers WHERE organization_uuid = ?{}",
+                archived_filter
+            );
+            let row = with_statement_timeout(
+                CUSTOMER_COUNT_TIMEOUT,
+                sqlx::query(&sql).bind(organization_uuid).fetch_one(p),
+            )
+            .await?;
+            row.get("count")
+        }
+        DatabasePool::Postgres(p) => {
+            let sql = format!(
+                "SELECT COUNT(*) as count FROM module_crm_customers WHERE organization_uuid = $1{}",
+                archived_filter
+            );
+            let row = with_statement_timeout(CUSTOMER_COUNT_TIMEOUT, async {
+                let mut tx = begin_with_statement_timeout(p, CUSTOMER_COUNT_TIMEOUT).await?;
+                let row = sqlx::query(&sql).bind(organization_uuid).fetch_one(&mut *tx).await?;
+                tx.commit().await?;
+                Ok(row)
+            })
+            .await?;
+            row.get("count")
+        }
+        DatabasePool::Sqlite(p) => {
+            let sql = format!(
+                "SELECT COUNT(*) as count FROM module_crm_customers WHERE organization_uuid = ?1{}",
+                archived_filter
+            );
+            let row = with_statement_timeout(
+                CUSTOMER_COUNT_TIMEOUT,
+                sqlx::query(&sql).bind(organization_uuid).fetch_one(p),
+            )
+            .await?;
+            row.get("count")
+        }
+    };
+
+    Ok(count as u32)
+}
+
+/// Check whether an organization already has a customer with the given email address
+///
+/// The comparison is case-insensitive.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization to search in
+/// * `email` - Email address to look for
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError` if the database query fails
+pub async fn customer_email_exists(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    email: &str,
+) -> Result<bool, CrmCustomerDatabaseError> {
+    let count: i64 = match pool {
+        DatabasePool::MySql(p) => {
+            let row = sqlx::query(
+                "SELECT COUNT(*) as count FROM module_crm_customers
+                 WHERE organization_uuid = ? AND LOWER(email) = LOWER(?)",
+            )
+            .bind(organization_uuid)
+            .bind(email)
+            .fetch_one(p)
+            .await?;
+            row.get("count")
+        }
+        DatabasePool::Postgres(p) => {
+            let row = sqlx::query(
+                "SELECT COUNT(*) as count FROM module_crm_customers
+                 WHERE organization_uuid = $1 AND LOWER(email) = LOWER($2)",
+            )
+            .bind(organization_uuid)
+            .bind(email)
+            .fetch_one(p)
+            .await?;
+            row.get("count")
+        }
+        DatabasePool::Sqlite(p) => {
+            let row = sqlx::query(
+                "SELECT COUNT(*) as count FROM module_crm_customers
+                 WHERE organization_uuid = ?1 AND LOWER(email) = LOWER(?2)",
+            )
+            .bind(organization_uuid)
+            .bind(email)
+            .fetch_one(p)
+            .await?;
+            row.get("count")
+        }
+    };
+
+    Ok(count > 0)
+}
+
 /// List customers for an organization with pagination
 ///
 /// # Arguments
 /// * `pool` - Database connection pool
 /// * `organization_uuid` - UUID of the organization to list customers for
 /// * `page` - Page number (1-based)
-/// * `page_size` - Number of customers per page (max 50)
+/// * `page_size` - Number of customers per page, at most [`MAX_PAGE_SIZE`]
+/// * `sort` - Sort order of the customers
+/// * `include_archived` - Whether archived customers are listed as well
 ///
 /// # Returns
 /// Returns a tuple of (customers, total_count)
@@ -1041,51 +1415,33 @@ pub async fn list_customers_paginated(
     organization_uuid: &str,
     page: u32,
     page_size: u32,
+    sort: CrmCustomerSort,
+    include_archived: bool,
 ) -> Result<(Vec<CrmCustomer>, u32), CrmCustomerDatabaseError> {
-    // Ensure page_size doesn't exceed 50
-    let page_size = page_size.min(50);
-    let offset = (page.saturating_sub(1)) * page_size;
+    // Callers clamp to the configured maximum, this bounds the query for any other caller
+    let Pagination { limit: page_size, offset, .. } = clamp_pagination(page, page_size, MAX_PAGE_SIZE);
     
     // Get total count
-    let total_count = match pool {
-        DatabasePool::MySql(p) => {
-            let row = sqlx::query("SELECT COUNT(*) as count FROM module_crm_customers WHERE organization_uuid = ?")
-                .bind(organization_uuid)
-                .fetch_one(p)
-                .await?;
-            let count: i64 = row.get("count");
-            count as u32
-        }
-        DatabasePool::Postgres(p) => {
-            let row = sqlx::query("SELECT COUNT(*) as count FROM module_crm_customers WHERE organization_uuid = $1")
-                .bind(organization_uuid)
-                .fetch_one(p)
-                .await?;
-            let count: i64 = row.get("count");
-            count as u32
-        }
-        DatabasePool::Sqlite(p) => {
-            let row = sqlx::query("SELECT COUNT(*) as count FROM module_crm_customers WHERE organization_uuid = ?1")
-                .bind(organization_uuid)
-                .fetch_one(p)
-                .await?;
-            let count: i64 = row.get("count");
-            count as u32
-        }
-    };
+    let total_count = count_customers_filtered(pool, organization_uuid, include_archived).await?;
     
+    // The clauses are fixed strings, so they are safe to format into the query
+    let order_by = sort.order_by_clause();
+    let archived_filter = if include_archived { "" } else { "AND archived_at IS NULL" };
+
     // Get paginated customers
     let customers = match pool {
         DatabasePool::MySql(p) => {
-            let rows = sqlx::query(
+            let rows = sqlx::query(&format!(
                 "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number, 
                  user_id, salutation, job_title, department, company_name, fax_number, 
-                 website_url, gender, created_at, updated_at 
+                 website_url, gender, created_at, updated_at, archived_at 
                  FROM module_crm_customers 
-                 WHERE organization_uuid = ? 
-                 ORDER BY last_name ASC, first_name ASC 
-                 LIMIT ? OFFSET ?"
-            )
+                 WHERE organization_uuid = ? {}
+                 ORDER BY {} 
+                 LIMIT ? OFFSET ?",
+                archived_filter,
+                order_by
+            ))
             .bind(organization_uuid)
             .bind(page_size as i64)
             .bind(offset as i64)
@@ -1110,19 +1466,22 @@ pub async fn list_customers_paginated(
                     gender: row.get::<Option<String>, _>("gender"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                     updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 })
                 .collect()
         }
         DatabasePool::Postgres(p) => {
-            let rows = sqlx::query(
+            let rows = sqlx::query(&format!(
                 "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number, 
                  user_id, salutation, job_title, department, company_name, fax_number, 
-                 website_url, gender, created_at, updated_at 
+                 website_url, gender, created_at, updated_at, archived_at 
                  FROM module_crm_customers 
-                 WHERE organization_uuid = $1 
-                 ORDER BY last_name ASC, first_name ASC 
-                 LIMIT $2 OFFSET $3"
-            )
+                 WHERE organization_uuid = $1 {}
+                 ORDER BY {} 
+                 LIMIT $2 OFFSET $3",
+                archived_filter,
+                order_by
+            ))
             .bind(organization_uuid)
             .bind(page_size as i64)
             .bind(offset as i64)
@@ -1147,19 +1506,22 @@ pub async fn list_customers_paginated(
                     gender: row.get::<Option<String>, _>("gender"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                     updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 })
                 .collect()
         }
         DatabasePool::Sqlite(p) => {
-            let rows = sqlx::query(
+            let rows = sqlx::query(&format!(
                 "SELECT uuid, organization_uuid, first_name, last_name, email, phone_number, 
                  user_id, salutation, job_title, department, company_name, fax_number, 
-                 website_url, gender, created_at, updated_at 
+                 website_url, gender, created_at, updated_at, archived_at 
                  FROM module_crm_customers 
-                 WHERE organization_uuid = ?1 
-                 ORDER BY last_name ASC, first_name ASC 
-                 LIMIT ?2 OFFSET ?3"
-            )
+                 WHERE organization_uuid = ?1 {}
+                 ORDER BY {} 
+                 LIMIT ?2 OFFSET ?3",
+                archived_filter,
+                order_by
+            ))
             .bind(organization_uuid)
             .bind(page_size as i64)
             .bind(offset as i64)
@@ -1184,6 +1546,7 @@ pub async fn list_customers_paginated(
                     gender: row.get::<Option<String>, _>("gender"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                     updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 })
                 .collect()
         }
@@ -1210,7 +1573,7 @@ pub async fn load_customer_conversations(
     match pool {
         DatabasePool::MySql(p) => {
             let rows = sqlx::query(
-                "SELECT conversation_uuid, customer_uuid, message, source, channel_uuid, created_at 
+                "SELECT conversation_uuid, customer_uuid, message, source, channel_uuid, parent_conversation_uuid, created_at 
                  FROM module_crm_customer_conversations 
                  WHERE customer_uuid = ? 
                  ORDER BY created_at DESC",
@@ -1227,13 +1590,14 @@ pub async fn load_customer_conversations(
                     message: row.get("message"),
                     source: row.get("source"),
                     channel_uuid: row.get("channel_uuid"),
+                    parent_conversation_uuid: row.get("parent_conversation_uuid"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                 })
                 .collect())
         }
         DatabasePool::Postgres(p) => {
             let rows = sqlx::query(
-                "SELECT conversation_uuid, customer_uuid, message, source, channel_uuid, created_at 
+                "SELECT conversation_uuid, customer_uuid, message, source, channel_uuid, parent_conversation_uuid, created_at 
                  FROM module_crm_customer_conversations 
                  WHERE customer_uuid = $1 
                  ORDER BY created_at DESC",
@@ -1250,13 +1614,14 @@ pub async fn load_customer_conversations(
                     message: row.get("message"),
                     source: row.get("source"),
                     channel_uuid: row.get("channel_uuid"),
+                    parent_conversation_uuid: row.get("parent_conversation_uuid"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                 })
                 .collect())
         }
         DatabasePool::Sqlite(p) => {
             let rows = sqlx::query(
-                "SELECT conversation_uuid, customer_uuid, message, source, channel_uuid, created_at 
+                "SELECT conversation_uuid, customer_uuid, message, source, channel_uuid, parent_conversation_uuid, created_at 
                  FROM module_crm_customer_conversations 
                  WHERE customer_uuid = ?1 
                  ORDER BY created_at DESC",
@@ -1273,6 +1638,7 @@ pub async fn load_customer_conversations(
                     message: row.get("message"),
                     source: row.get("source"),
                     channel_uuid: row.get("channel_uuid"),
+                    parent_conversation_uuid: row.get("parent_conversation_uuid"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
                 })
                 .collect())
@@ -1291,12 +1657,25 @@ pub async fn load_customer_conversations(
 /// Returns the UUID of the newly created conversation
 ///
 /// # Errors
-/// Returns `CrmCustomerDatabaseError` if the database operation fails
+/// Returns `CrmCustomerDatabaseError::ParentConversationNotFound` if the parent conversation
+/// does not belong to the customer, or another `CrmCustomerDatabaseError` if the database
+/// operation fails
 pub async fn create_customer_conversation(
     pool: &DatabasePool,
     customer_uuid: &str,
     request: CreateCrmCustomerConversationRequest,
 ) -> Result<String, CrmCustomerDatabaseError> {
+    // Replies can only be attached to conversations of the same customer
+    if let Some(parent_uuid) = &request.parent_conversation_uuid {
+        match load_conversation_by_uuid(pool, parent_uuid).await {
+            Ok(parent) if parent.customer_uuid == customer_uuid => {}
+            Ok(_) | Err(CrmCustomerDatabaseError::ConversationNotFound(_)) => {
+                return Err(CrmCustomerDatabaseError::ParentConversationNotFound(parent_uuid.clone()));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
     let conversation_uuid = uuid::Uuid::new_v4().to_string();
     let now = Utc::now();
 
@@ -1304,14 +1683,15 @@ pub async fn create_customer_conversation(
         DatabasePool::MySql(p) => {
             sqlx::query(
                 "INSERT INTO module_crm_customer_conversations 
-                 (conversation_uuid, customer_uuid, message, source, channel_uuid, created_at) 
-                 VALUES (?, ?, ?, ?, ?, ?)",
+                 (conversation_uuid, customer_uuid, message, source, channel_uuid, parent_conversation_uuid, created_at) 
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
             )
             .bind(&conversation_uuid)
             .bind(customer_uuid)
             .bind(&request.message)
             .bind(&request.source)
             .bind(&request.channel_uuid)
+            .bind(&request.parent_conversation_uuid)
             .bind(now)
             .execute(p)
             .await?;
@@ -1319,14 +1699,15 @@ pub async fn create_customer_conversation(
         DatabasePool::Postgres(p) => {
             sqlx::query(
                 "INSERT INTO module_crm_customer_conversations 
-                 (conversation_uuid, customer_uuid, message, source, channel_uuid, created_at) 
-                 VALUES ($1, $2, $3, $4, $5, $6)",
+                 (conversation_uuid, customer_uuid, message, source, channel_uuid, parent_conversation_uuid, created_at) 
+                 VALUES ($1, $2, $3, $4, $5, $6, $7)",
             )
             .bind(&conversation_uuid)
             .bind(customer_uuid)
             .bind(&request.message)
             .bind(&request.source)
             .bind(&request.channel_uuid)
+            .bind(&request.parent_conversation_uuid)
             .bind(now)
             .execute(p)
             .await?;
@@ -1334,14 +1715,15 @@ pub async fn create_customer_conversation(
         DatabasePool::Sqlite(p) => {
             sqlx::query(
                 "INSERT INTO module_crm_customer_conversations 
-                 (conversation_uuid, customer_uuid, message, source, channel_uuid, created_at) 
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                 (conversation_uuid, customer_uuid, message, source, channel_uuid, parent_conversation_uuid, created_at) 
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
             )
             .bind(&conversation_uuid)
             .bind(customer_uuid)
             .bind(&request.message)
             .bind(&request.source)
             .bind(&request.channel_uuid)
+            .bind(&request.parent_conversation_uuid)
             .bind(now)
             .execute(p)
             .await?;
@@ -1351,6 +1733,202 @@ pub async fn create_customer_conversation(
     Ok(conversation_uuid)
 }
 
+/// Load a single customer conversation by UUID
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `conversation_uuid` - UUID of the conversation to load
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError::ConversationNotFound` if the conversation does not exist,
+/// or another `CrmCustomerDatabaseError` if the database query fails
+pub async fn load_conversation_by_uuid(
+    pool: &DatabasePool,
+    conversation_uuid: &str,
+) -> Result<CrmCustomerConversation, CrmCustomerDatabaseError> {
+    let row = match pool {
+        DatabasePool::MySql(p) => sqlx::query(
+            "SELECT conversation_uuid, customer_uuid, message, source, channel_uuid, parent_conversation_uuid, created_at 
+             FROM module_crm_customer_conversations 
+             WHERE conversation_uuid = ?",
+        )
+        .bind(conversation_uuid)
+        .fetch_optional(p)
+        .await?
+        .map(|row| CrmCustomerConversation {
+            uuid: row.get("conversation_uuid"),
+            customer_uuid: row.get("customer_uuid"),
+            message: row.get("message"),
+            source: row.get("source"),
+            channel_uuid: row.get("channel_uuid"),
+            parent_conversation_uuid: row.get("parent_conversation_uuid"),
+            created_at: row.get::<DateTime<Utc>, _>("created_at"),
+        }),
+        DatabasePool::Postgres(p) => sqlx::query(
+            "SELECT conversation_uuid, customer_uuid, message, source, channel_uuid, parent_conversation_uuid, created_at 
+             FROM module_crm_customer_conversations 
+             WHERE conversation_uuid = $1",
+        )
+        .bind(conversation_uuid)
+        .fetch_optional(p)
+        .await?
+        .map(|row| CrmCustomerConversation {
+            uuid: row.get("conversation_uuid"),
+            customer_uuid: row.get("customer_uuid"),
+            message: row.get("message"),
+            source: row.get("source"),
+            channel_uuid: row.get("channel_uuid"),
+            parent_conversation_uuid: row.get("parent_conversation_uuid"),
+            created_at: row.get::<DateTime<Utc>, _>("created_at"),
+        }),
+        DatabasePool::Sqlite(p) => sqlx::query(
+            "SELECT conversation_uuid, customer_uuid, message, source, channel_uuid, parent_conversation_uuid, created_at 
+             FROM module_crm_customer_conversations 
+             WHERE conversation_uuid = ?1",
+        )
+        .bind(conversation_uuid)
+        .fetch_optional(p)
+        .await?
+        .map(|row| CrmCustomerConversation {
+            uuid: row.get("conversation_uuid"),
+            customer_uuid: row.get("customer_uuid"),
+            message: row.get("message"),
+            source: row.get("source"),
+            channel_uuid: row.get("channel_uuid"),
+            parent_conversation_uuid: row.get("parent_conversation_uuid"),
+            created_at: row.get::<DateTime<Utc>, _>("created_at"),
+        }),
+    };
+
+    row.ok_or_else(|| CrmCustomerDatabaseError::ConversationNotFound(conversation_uuid.to_string()))
+}
+
+/// Set or clear the parent of a customer conversation
+///
+/// The parent must belong to the same customer and must not be the conversation itself
+/// or one of its (indirect) replies, otherwise the thread would contain a cycle.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `conversation_uuid` - UUID of the conversation to move
+/// * `parent_conversation_uuid` - UUID of the new parent, `None` to make the conversation a thread root
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError::ConversationNotFound` if the conversation does not exist,
+/// `CrmCustomerDatabaseError::ParentConversationNotFound` if the parent does not belong to the
+/// same customer, `CrmCustomerDatabaseError::ConversationCycle` if the parent is a reply of the
+/// conversation, or another `CrmCustomerDatabaseError` if the database operation fails
+pub async fn set_conversation_parent(
+    pool: &DatabasePool,
+    conversation_uuid: &str,
+    parent_conversation_uuid: Option<&str>,
+) -> Result<(), CrmCustomerDatabaseError> {
+    let conversation = load_conversation_by_uuid(pool, conversation_uuid).await?;
+
+    if let Some(parent_uuid) = parent_conversation_uuid {
+        let conversations = load_customer_conversations(pool, &conversation.customer_uuid).await?;
+        let parents: std::collections::HashMap<&str, Option<&str>> = conversations
+            .iter()
+            .map(|c| (c.uuid.as_str(), c.parent_conversation_uuid.as_deref()))
+            .collect();
+
+        if !parents.contains_key(parent_uuid) {
+            return Err(CrmCustomerDatabaseError::ParentConversationNotFound(parent_uuid.to_string()));
+        }
+
+        // Walk up from the new parent, reaching the conversation itself means a cycle
+        let mut visited = std::collections::HashSet::new();
+        let mut current = Some(parent_uuid);
+        while let Some(uuid) = current {
+            if uuid == conversation_uuid {
+                return Err(CrmCustomerDatabaseError::ConversationCycle);
+            }
+            if !visited.insert(uuid) {
+                break;
+            }
+            current = parents.get(uuid).copied().flatten();
+        }
+    }
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query(
+                "UPDATE module_crm_customer_conversations SET parent_conversation_uuid = ? WHERE conversation_uuid = ?",
+            )
+            .bind(parent_conversation_uuid)
+            .bind(conversation_uuid)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query(
+                "UPDATE module_crm_customer_conversations SET parent_conversation_uuid = $1 WHERE conversation_uuid = $2",
+            )
+            .bind(parent_conversation_uuid)
+            .bind(conversation_uuid)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query(
+                "UPDATE module_crm_customer_conversations SET parent_conversation_uuid = ?1 WHERE conversation_uuid = ?2",
+            )
+            .bind(parent_conversation_uuid)
+            .bind(conversation_uuid)
+            .execute(p)
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Load a conversation and all of its (indirect) replies
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `root_uuid` - UUID of the conversation the thread starts at
+///
+/// # Returns
+/// Returns the thread in reading order: every conversation is followed by its replies,
+/// replies to the same conversation are sorted by creation date (oldest first)
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError::ConversationNotFound` if the root conversation does not
+/// exist, or another `CrmCustomerDatabaseError` if the database query fails
+pub async fn list_conversation_thread(
+    pool: &DatabasePool,
+    root_uuid: &str,
+) -> Result<Vec<CrmCustomerConversation>, CrmCustomerDatabaseError> {
+    let root = load_conversation_by_uuid(pool, root_uuid).await?;
+    let conversations = load_customer_conversations(pool, &root.customer_uuid).await?;
+
+    let mut replies: std::collections::HashMap<String, Vec<CrmCustomerConversation>> =
+        std::collections::HashMap::new();
+    for conversation in conversations {
+        if let Some(parent_uuid) = conversation.parent_conversation_uuid.clone() {
+            replies.entry(parent_uuid).or_default().push(conversation);
+        }
+    }
+    for children in replies.values_mut() {
+        // Reversed, so popping from the stack below yields the oldest reply first
+        children.sort_by(|a, b| b.created_at.cmp(&a.created_at).then_with(|| b.uuid.cmp(&a.uuid)));
+    }
+
+    let mut thread = Vec::new();
+    let mut stack = vec![root];
+    while let Some(conversation) = stack.pop() {
+        // Each conversation has exactly one parent, so removing its replies also
+        // guarantees termination should the data ever contain a cycle
+        if let Some(children) = replies.remove(&conversation.uuid) {
+            stack.extend(children);
+        }
+        thread.push(conversation);
+    }
+
+    Ok(thread)
+}
+
 /// Update a customer in the database
 ///
 /// # Arguments
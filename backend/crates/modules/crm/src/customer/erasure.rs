@@ -0,0 +1,299 @@
+//! CRM Customer Erasure module
+//!
+//! Permanently deletes a customer with all child records, e.g. to answer a GDPR
+//! erasure request, and keeps a proof of the erasure without personal data.
+
+use chrono::{DateTime, Utc};
+use flextide_core::database::DatabasePool;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::database::{self, CrmCustomerDatabaseError};
+
+/// Entry of the customer erasure log
+///
+/// Contains no personal data of the erased customer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrmCustomerErasureEntry {
+    pub uuid: String,
+    pub organization_uuid: String,
+    pub customer_uuid: String,
+    pub notes_deleted: i64,
+    pub addresses_deleted: i64,
+    pub conversations_deleted: i64,
+    pub erased_at: DateTime<Utc>,
+}
+
+/// Erase a customer with all notes, addresses, and conversations
+///
+/// Child records are deleted explicitly instead of relying on foreign key cascades.
+/// All deletions and the erasure log entry are written in one transaction.
+///
+/// This does not perform permission checks; callers must verify that the user may
+/// erase customers of the organization.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization the customer must belong to
+/// * `customer_uuid` - UUID of the customer
+///
+/// # Returns
+/// Returns the erasure log entry
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError` if:
+/// - The customer does not exist (`Sql(RowNotFound)`)
+/// - The customer belongs to another organization (`CustomerNotInOrganization`)
+/// - A database operation fails
+///
+/// # Note
+/// Only records of the CRM module are erased. Mentions of the person in free text of
+/// other modules (e.g. docs pages) cannot be detected reliably and are not scrubbed.
+pub async fn erase_customer(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    customer_uuid: &str,
+) -> Result<CrmCustomerErasureEntry, CrmCustomerDatabaseError> {
+    let customer = database::load_customer_by_uuid(pool, customer_uuid).await?;
+    if customer.organization_uuid != organization_uuid {
+        return Err(CrmCustomerDatabaseError::CustomerNotInOrganization);
+    }
+
+    let entry_uuid = Uuid::new_v4().to_string();
+    let erased_at = Utc::now();
+
+    let (notes_deleted, addresses_deleted, conversations_deleted) = match pool {
+        DatabasePool::MySql(p) => {
+            let mut tx = p.begin().await?;
+
+            let notes = sqlx::query("DELETE FROM module_crm_customer_notes WHERE customer_uuid = ?")
+                .bind(customer_uuid)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            let addresses = sqlx::query("DELETE FROM module_crm_customer_addresses WHERE customer_uuid = ?")
+                .bind(customer_uuid)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            let conversations =
+                sqlx::query("DELETE FROM module_crm_customer_conversations WHERE customer_uuid = ?")
+                    .bind(customer_uuid)
+                    .execute(&mut *tx)
+                    .await?
+                    .rows_affected();
+
+            sqlx::query("DELETE FROM module_crm_customers WHERE uuid = ? AND organization_uuid = ?")
+                .bind(customer_uuid)
+                .bind(organization_uuid)
+                .execute(&mut *tx)
+                .await?;
+
+            sqlx::query(
+                "INSERT INTO module_crm_customer_erasure_log
+                 (uuid, organization_uuid, customer_uuid, notes_deleted, addresses_deleted, conversations_deleted, erased_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&entry_uuid)
+            .bind(organization_uuid)
+            .bind(customer_uuid)
+            .bind(notes as i64)
+            .bind(addresses as i64)
+            .bind(conversations as i64)
+            .bind(erased_at)
+            .execute(&mut *tx)
+            .await?;
+
+            tx.commit().await?;
+            (notes, addresses, conversations)
+        }
+        DatabasePool::Postgres(p) => {
+            let mut tx = p.begin().await?;
+
+            let notes = sqlx::query("DELETE FROM module_crm_customer_notes WHERE customer_uuid = $1")
+                .bind(customer_uuid)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            let addresses = sqlx::query("DELETE FROM module_crm_customer_addresses WHERE customer_uuid = $1")
+                .bind(customer_uuid)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            let conversations =
+                sqlx::query("DELETE FROM module_crm_customer_conversations WHERE customer_uuid = $1")
+                    .bind(customer_uuid)
+                    .execute(&mut *tx)
+                    .await?
+                    .rows_affected();
+
+            sqlx::query("DELETE FROM module_crm_customers WHERE uuid = $1 AND organization_uuid = $2")
+                .bind(customer_uuid)
+                .bind(organization_uuid)
+                .execute(&mut *tx)
+                .await?;
+
+            sqlx::query(
+                "INSERT INTO module_crm_customer_erasure_log
+                 (uuid, organization_uuid, customer_uuid, notes_deleted, addresses_deleted, conversations_deleted, erased_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            )
+            .bind(&entry_uuid)
+            .bind(organization_uuid)
+            .bind(customer_uuid)
+            .bind(notes as i32)
+            .bind(addresses as i32)
+            .bind(conversations as i32)
+            .bind(erased_at)
+            .execute(&mut *tx)
+            .await?;
+
+            tx.commit().await?;
+            (notes, addresses, conversations)
+        }
+        DatabasePool::Sqlite(p) => {
+            let mut tx = p.begin().await?;
+
+            let notes = sqlx::query("DELETE FROM module_crm_customer_notes WHERE customer_uuid = ?1")
+                .bind(customer_uuid)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            let addresses = sqlx::query("DELETE FROM module_crm_customer_addresses WHERE customer_uuid = ?1")
+                .bind(customer_uuid)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            let conversations =
+                sqlx::query("DELETE FROM module_crm_customer_conversations WHERE customer_uuid = ?1")
+                    .bind(customer_uuid)
+                    .execute(&mut *tx)
+                    .await?
+                    .rows_affected();
+
+            sqlx::query("DELETE FROM module_crm_customers WHERE uuid = ?1 AND organization_uuid = ?2")
+                .bind(customer_uuid)
+                .bind(organization_uuid)
+                .execute(&mut *tx)
+                .await?;
+
+            sqlx::query(
+                "INSERT INTO module_crm_customer_erasure_log
+                 (uuid, organization_uuid, customer_uuid, notes_deleted, addresses_deleted, conversations_deleted, erased_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )
+            .bind(&entry_uuid)
+            .bind(organization_uuid)
+            .bind(customer_uuid)
+            .bind(notes as i64)
+            .bind(addresses as i64)
+            .bind(conversations as i64)
+            .bind(erased_at)
+            .execute(&mut *tx)
+            .await?;
+
+            tx.commit().await?;
+            (notes, addresses, conversations)
+        }
+    };
+
+    tracing::info!(
+        "Erased CRM customer {} of organization {} ({} notes, {} addresses, {} conversations)",
+        customer_uuid,
+        organization_uuid,
+        notes_deleted,
+        addresses_deleted,
+        conversations_deleted
+    );
+
+    Ok(CrmCustomerErasureEntry {
+        uuid: entry_uuid,
+        organization_uuid: organization_uuid.to_string(),
+        customer_uuid: customer_uuid.to_string(),
+        notes_deleted: notes_deleted as i64,
+        addresses_deleted: addresses_deleted as i64,
+        conversations_deleted: conversations_deleted as i64,
+        erased_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        create_address, create_channel, create_conversation, create_customer, create_note, create_organization,
+        create_user, setup_tables, sqlite,
+    };
+
+    async fn count(pool: &DatabasePool, table: &str, customer_uuid: &str) -> i64 {
+        let column = if table == "module_crm_customers" { "uuid" } else { "customer_uuid" };
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {} WHERE {} = ?1", table, column))
+            .bind(customer_uuid)
+            .fetch_one(sqlite(pool))
+            .await
+            .expect("Failed to count rows")
+    }
+
+    #[sqlx::test]
+    async fn test_erase_customer(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let author_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &author_uuid).await;
+        let channel_uuid = create_channel(&pool, &org_uuid).await;
+        let customer_uuid = create_customer(&pool, &org_uuid).await;
+        create_note(&pool, &customer_uuid, &author_uuid).await;
+        create_note(&pool, &customer_uuid, &author_uuid).await;
+        create_address(&pool, &customer_uuid).await;
+        create_conversation(&pool, &customer_uuid, &channel_uuid).await;
+        let other_customer_uuid = create_customer(&pool, &org_uuid).await;
+        create_note(&pool, &other_customer_uuid, &author_uuid).await;
+
+        let entry = erase_customer(&pool, &org_uuid, &customer_uuid).await.unwrap();
+        assert_eq!((entry.notes_deleted, entry.addresses_deleted, entry.conversations_deleted), (2, 1, 1));
+
+        for table in [
+            "module_crm_customers",
+            "module_crm_customer_notes",
+            "module_crm_customer_addresses",
+            "module_crm_customer_conversations",
+        ] {
+            assert_eq!(count(&pool, table, &customer_uuid).await, 0, "{} still has records", table);
+        }
+        // Other customers are untouched
+        assert_eq!(count(&pool, "module_crm_customer_notes", &other_customer_uuid).await, 1);
+
+        let (logged_org, notes_deleted): (String, i64) = sqlx::query_as(
+            "SELECT organization_uuid, notes_deleted FROM module_crm_customer_erasure_log
+             WHERE customer_uuid = ?1",
+        )
+        .bind(&customer_uuid)
+        .fetch_one(sqlite(&pool))
+        .await?;
+        assert_eq!((logged_org.as_str(), notes_deleted), (org_uuid.as_str(), 2));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_erase_customer_of_other_organization(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let author_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &author_uuid).await;
+        let other_org_uuid = create_organization(&pool, &author_uuid).await;
+        let customer_uuid = create_customer(&pool, &other_org_uuid).await;
+        create_note(&pool, &customer_uuid, &author_uuid).await;
+
+        assert!(matches!(
+            erase_customer(&pool, &org_uuid, &customer_uuid).await,
+            Err(CrmCustomerDatabaseError::CustomerNotInOrganization)
+        ));
+        assert_eq!(count(&pool, "module_crm_customers", &customer_uuid).await, 1);
+        assert_eq!(count(&pool, "module_crm_customer_notes", &customer_uuid).await, 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//! CRM Customer Data Export module
+//!
+//! Bundles all data stored about a customer into a single document, e.g. to answer
+//! a GDPR data access request.
+
+use chrono::{DateTime, Utc};
+use flextide_core::database::DatabasePool;
+use serde::{Deserialize, Serialize};
+
+use super::database::CrmCustomerDatabaseError;
+use super::{CrmCustomer, CrmCustomerAddress, CrmCustomerConversation, CrmCustomerNote, CrmCustomerWithChildren};
+
+/// All data stored about a customer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrmCustomerDataExport {
+    pub customer: CrmCustomer,
+    pub notes: Vec<CrmCustomerNote>,
+    pub addresses: Vec<CrmCustomerAddress>,
+    pub conversations: Vec<CrmCustomerConversation>,
+    pub exported_at: DateTime<Utc>,
+}
+
+/// Export all data stored about a customer
+///
+/// This does not perform permission checks; callers must verify that the user may
+/// export customer data of the organization.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization the customer must belong to
+/// * `customer_uuid` - UUID of the customer
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError` if:
+/// - The customer does not exist (`Sql(RowNotFound)`)
+/// - The customer belongs to another organization (`CustomerNotInOrganization`)
+/// - A database operation fails
+pub async fn export_customer_data(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    customer_uuid: &str,
+) -> Result<CrmCustomerDataExport, CrmCustomerDatabaseError> {
+    let CrmCustomerWithChildren {
+        customer,
+        notes,
+        addresses,
+        conversations,
+    } = CrmCustomer::load_full(pool, customer_uuid).await?;
+    if customer.organization_uuid != organization_uuid {
+        return Err(CrmCustomerDatabaseError::CustomerNotInOrganization);
+    }
+
+    Ok(CrmCustomerDataExport {
+        customer,
+        notes,
+        addresses,
+        conversations,
+        exported_at: Utc::now(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        create_channel, create_customer_with_children, create_organization, create_user, setup_tables,
+    };
+
+    #[sqlx::test]
+    async fn test_export_customer_data(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let author_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &author_uuid).await;
+        let channel_uuid = create_channel(&pool, &org_uuid).await;
+        let customer = create_customer_with_children(&pool, &org_uuid, &author_uuid, &channel_uuid).await;
+
+        let other_org_uuid = create_organization(&pool, &author_uuid).await;
+        let other_channel_uuid = create_channel(&pool, &other_org_uuid).await;
+        let other_customer_uuid =
+            create_customer_with_children(&pool, &other_org_uuid, &author_uuid, &other_channel_uuid)
+                .await
+                .customer_uuid;
+
+        let export = export_customer_data(&pool, &org_uuid, &customer.customer_uuid).await.unwrap();
+        assert_eq!(export.customer.uuid, customer.customer_uuid);
+        assert_eq!(
+            export.notes.iter().map(|n| n.uuid.as_str()).collect::<Vec<_>>(),
+            [customer.note_uuid.as_str()]
+        );
+        assert_eq!(
+            export.addresses.iter().map(|a| a.uuid.as_str()).collect::<Vec<_>>(),
+            [customer.address_uuid.as_str()]
+        );
+        assert_eq!(
+            export.conversations.iter().map(|c| c.uuid.as_str()).collect::<Vec<_>>(),
+            [customer.conversation_uuid.as_str()]
+        );
+
+        let document = serde_json::to_value(&export).unwrap();
+        assert!(!document.to_string().contains(&other_customer_uuid));
+
+        // Customers of other organizations cannot be exported
+        assert!(matches!(
+            export_customer_data(&pool, &org_uuid, &other_customer_uuid).await,
+            Err(CrmCustomerDatabaseError::CustomerNotInOrganization)
+        ));
+
+        Ok(())
+    }
+}
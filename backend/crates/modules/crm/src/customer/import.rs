@@ -0,0 +1,274 @@
+//! CRM Customer Import module
+//!
+//! Creates customers from a CSV file. Invalid rows are reported and skipped instead of
+//! aborting the whole import.
+
+use std::collections::HashSet;
+
+use flextide_core::database::DatabasePool;
+use serde::{Deserialize, Serialize};
+
+use super::database::{self, CrmCustomerDatabaseError};
+use super::CreateCrmCustomerRequest;
+
+/// Columns that every customer import file must contain
+const REQUIRED_IMPORT_COLUMNS: &[&str] = &["first_name", "last_name"];
+
+/// Result of a customer import
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CrmCustomerImportReport {
+    /// Customers created from valid rows
+    pub created: Vec<CrmCustomerImportCreated>,
+    /// Rows skipped because a customer with the same email already exists
+    pub skipped: Vec<CrmCustomerImportRowIssue>,
+    /// Rows that could not be imported
+    pub errors: Vec<CrmCustomerImportRowIssue>,
+}
+
+/// Customer created by an import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrmCustomerImportCreated {
+    /// Line of the row in the CSV file (the header is line 1)
+    pub line: u64,
+    pub uuid: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: Option<String>,
+    pub company_name: Option<String>,
+}
+
+/// Row of an import that was skipped or failed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrmCustomerImportRowIssue {
+    /// Line of the row in the CSV file (the header is line 1)
+    pub line: u64,
+    pub message: String,
+}
+
+/// Row of the import file, unknown columns are ignored
+#[derive(Debug, Deserialize)]
+struct ImportRow {
+    first_name: Option<String>,
+    last_name: Option<String>,
+    email: Option<String>,
+    phone_number: Option<String>,
+    salutation: Option<String>,
+    job_title: Option<String>,
+    department: Option<String>,
+    company_name: Option<String>,
+    fax_number: Option<String>,
+    website_url: Option<String>,
+    gender: Option<String>,
+}
+
+/// Import customers from a CSV file into an organization
+///
+/// The first line must be a header naming the columns. `first_name` and `last_name` are
+/// required, the other columns of [`CreateCrmCustomerRequest`] except `user_id` are optional.
+/// Rows with an email address that already exists in the organization, or appeared
+/// earlier in the file, are skipped (case-insensitive).
+///
+/// This does not perform permission checks; callers must verify that the user may
+/// create customers in the organization.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization the customers are created in
+/// * `csv` - Content of the CSV file
+///
+/// # Errors
+/// Returns `CrmCustomerDatabaseError` if:
+/// - The header is unreadable or lacks a required column (`InvalidImportFile`)
+/// - A database operation fails
+pub async fn import_customers_from_csv(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    csv: &[u8],
+) -> Result<CrmCustomerImportReport, CrmCustomerDatabaseError> {
+    let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(csv);
+
+    let headers = reader
+        .headers()
+        .map_err(|e| CrmCustomerDatabaseError::InvalidImportFile(e.to_string()))?
+        .clone();
+    for column in REQUIRED_IMPORT_COLUMNS {
+        if !headers.iter().any(|header| header == *column) {
+            return Err(CrmCustomerDatabaseError::InvalidImportFile(format!(
+                "Missing required column '{}'",
+                column
+            )));
+        }
+    }
+
+    let mut report = CrmCustomerImportReport::default();
+    let mut seen_emails = HashSet::new();
+
+    for result in reader.records() {
+        let record = match result {
+            Ok(record) => record,
+            Err(e) => {
+                report.errors.push(CrmCustomerImportRowIssue {
+                    line: e.position().map(|p| p.line()).unwrap_or_default(),
+                    message: format!("Invalid row: {}", e),
+                });
+                continue;
+            }
+        };
+        let line = record.position().map(|p| p.line()).unwrap_or_default();
+
+        let row = match record.deserialize::<ImportRow>(Some(&headers)) {
+            Ok(row) => row,
+            Err(e) => {
+                report.errors.push(CrmCustomerImportRowIssue {
+                    line,
+                    message: format!("Invalid row: {}", e),
+                });
+                continue;
+            }
+        };
+
+        let request = match validate_import_row(row) {
+            Ok(request) => request,
+            Err(message) => {
+                report.errors.push(CrmCustomerImportRowIssue { line, message });
+                continue;
+            }
+        };
+
+        if let Some(email) = &request.email {
+            let is_duplicate = !seen_emails.insert(email.to_lowercase())
+                || database::customer_email_exists(pool, organization_uuid, email).await?;
+            if is_duplicate {
+                report.skipped.push(CrmCustomerImportRowIssue {
+                    line,
+                    message: format!("A customer with email '{}' already exists", email),
+                });
+                continue;
+            }
+        }
+
+        let first_name = request.first_name.clone();
+        let last_name = request.last_name.clone();
+        let email = request.email.clone();
+        let company_name = request.company_name.clone();
+        let uuid = database::create_customer(pool, organization_uuid, request).await?;
+
+        report.created.push(CrmCustomerImportCreated {
+            line,
+            uuid,
+            first_name,
+            last_name,
+            email,
+            company_name,
+        });
+    }
+
+    Ok(report)
+}
+
+/// Turn a row into a create request, or describe why it is invalid
+fn validate_import_row(row: ImportRow) -> Result<CreateCrmCustomerRequest, String> {
+    let first_name = non_empty(row.first_name).ok_or("First name cannot be empty")?;
+    let last_name = non_empty(row.last_name).ok_or("Last name cannot be empty")?;
+
+    Ok(CreateCrmCustomerRequest {
+        first_name,
+        last_name,
+        email: non_empty(row.email),
+        phone_number: non_empty(row.phone_number),
+        user_id: None,
+        salutation: non_empty(row.salutation),
+        job_title: non_empty(row.job_title),
+        department: non_empty(row.department),
+        company_name: non_empty(row.company_name),
+        fax_number: non_empty(row.fax_number),
+        website_url: non_empty(row.website_url),
+        gender: non_empty(row.gender),
+    })
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::customer::CrmCustomer;
+    use crate::test_support::{create_customer, create_organization, create_user, setup_tables};
+
+    #[sqlx::test]
+    async fn test_import_reports_invalid_rows(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+
+        let csv = "first_name,last_name,email,company_name\n\
+                   Max,Mustermann,max@example.com,Example Corp\n\
+                   ,Nobody,nobody@example.com,\n";
+        let report = import_customers_from_csv(&pool, &org_uuid, csv.as_bytes()).await.unwrap();
+
+        assert_eq!(report.created.len(), 1);
+        assert_eq!(report.created[0].line, 2);
+        assert!(report.skipped.is_empty());
+        assert_eq!(
+            report.errors,
+            [CrmCustomerImportRowIssue {
+                line: 3,
+                message: "First name cannot be empty".to_string(),
+            }]
+        );
+
+        let customer = CrmCustomer::load_from_database(&pool, &report.created[0].uuid).await.unwrap();
+        assert_eq!(customer.organization_uuid, org_uuid);
+        assert_eq!(customer.first_name, "Max");
+        assert_eq!(customer.email.as_deref(), Some("max@example.com"));
+        assert_eq!(customer.company_name.as_deref(), Some("Example Corp"));
+        assert_eq!(CrmCustomer::count_customers(&pool, &org_uuid).await.unwrap(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_import_deduplicates_by_email(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        // Has the email jane.doe@example.com
+        create_customer(&pool, &org_uuid).await;
+
+        let csv = "first_name,last_name,email\n\
+                   Jane,Doe,JANE.DOE@example.com\n\
+                   John,Smith,john@example.com\n\
+                   Johnny,Smith,john@example.com\n\
+                   Anna,Anonymous,\n";
+        let report = import_customers_from_csv(&pool, &org_uuid, csv.as_bytes()).await.unwrap();
+
+        assert_eq!(report.created.iter().map(|c| c.line).collect::<Vec<_>>(), [3, 5]);
+        assert_eq!(report.skipped.iter().map(|s| s.line).collect::<Vec<_>>(), [2, 4]);
+        assert!(report.errors.is_empty());
+        assert_eq!(CrmCustomer::count_customers(&pool, &org_uuid).await.unwrap(), 3);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_import_requires_name_columns(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+
+        assert!(matches!(
+            import_customers_from_csv(&pool, &org_uuid, b"first_name,email\nMax,max@example.com\n").await,
+            Err(CrmCustomerDatabaseError::InvalidImportFile(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -2,9 +2,19 @@
 //!
 //! Provides functionality for managing CRM customers, including database operations.
 
+mod csv_export;
 mod database;
+mod erasure;
+mod export;
+mod import;
 
+pub use csv_export::{export_customers_to_csv, stream_customers, stream_customers_csv, CUSTOMER_EXPORT_COLUMNS};
 pub use database::CrmCustomerDatabaseError;
+pub use erasure::{erase_customer, CrmCustomerErasureEntry};
+pub use export::{export_customer_data, CrmCustomerDataExport};
+pub use import::{
+    import_customers_from_csv, CrmCustomerImportCreated, CrmCustomerImportReport, CrmCustomerImportRowIssue,
+};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -30,9 +40,119 @@ pub struct CrmCustomer {
     pub gender: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set while the customer is archived (soft-deleted), see [`CrmCustomer::archive`]
+    #[serde(default)]
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+/// Column customer lists can be sorted by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrmCustomerSortColumn {
+    /// Last name, then first name
+    #[default]
+    Name,
+    CreatedAt,
+    /// Company name, customers without a company last
+    Company,
+}
+
+impl CrmCustomerSortColumn {
+    /// Parse the value of the `sort_by` parameter
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "name" => Some(CrmCustomerSortColumn::Name),
+            "created_at" => Some(CrmCustomerSortColumn::CreatedAt),
+            "company" => Some(CrmCustomerSortColumn::Company),
+            _ => None,
+        }
+    }
+}
+
+/// Direction of a customer list sort
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrmSortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl CrmSortDirection {
+    /// Parse the value of the `sort_dir` parameter
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "asc" => Some(CrmSortDirection::Asc),
+            "desc" => Some(CrmSortDirection::Desc),
+            _ => None,
+        }
+    }
+}
+
+/// Sort order of a customer list, by name ascending by default
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrmCustomerSort {
+    pub column: CrmCustomerSortColumn,
+    pub direction: CrmSortDirection,
+}
+
+impl CrmCustomerSort {
+    /// Build a sort order from the `sort_by` and `sort_dir` request parameters
+    ///
+    /// Missing parameters fall back to sorting by name ascending.
+    ///
+    /// # Errors
+    /// Returns `CrmCustomerDatabaseError::InvalidSortColumn` or `InvalidSortDirection`
+    /// if a parameter is not one of the allowed values
+    pub fn from_params(sort_by: Option<&str>, sort_dir: Option<&str>) -> Result<Self, CrmCustomerDatabaseError> {
+        let column = match sort_by {
+            Some(s) => CrmCustomerSortColumn::parse(s)
+                .ok_or_else(|| CrmCustomerDatabaseError::InvalidSortColumn(s.to_string()))?,
+            None => CrmCustomerSortColumn::default(),
+        };
+        let direction = match sort_dir {
+            Some(s) => CrmSortDirection::parse(s)
+                .ok_or_else(|| CrmCustomerDatabaseError::InvalidSortDirection(s.to_string()))?,
+            None => CrmSortDirection::default(),
+        };
+        Ok(Self { column, direction })
+    }
+
+    /// `ORDER BY` clause of the sort order, valid for all database backends
+    ///
+    /// Only built from fixed strings, never from user input. The UUID is the last sort key
+    /// so pages are stable for equal values.
+    pub(crate) fn order_by_clause(&self) -> &'static str {
+        match (self.column, self.direction) {
+            (CrmCustomerSortColumn::Name, CrmSortDirection::Asc) => "last_name ASC, first_name ASC, uuid ASC",
+            (CrmCustomerSortColumn::Name, CrmSortDirection::Desc) => "last_name DESC, first_name DESC, uuid DESC",
+            (CrmCustomerSortColumn::CreatedAt, CrmSortDirection::Asc) => "created_at ASC, uuid ASC",
+            (CrmCustomerSortColumn::CreatedAt, CrmSortDirection::Desc) => "created_at DESC, uuid DESC",
+            (CrmCustomerSortColumn::Company, CrmSortDirection::Asc) => {
+                "company_name IS NULL, company_name ASC, last_name ASC, first_name ASC, uuid ASC"
+            }
+            (CrmCustomerSortColumn::Company, CrmSortDirection::Desc) => {
+                "company_name IS NULL, company_name DESC, last_name ASC, first_name ASC, uuid ASC"
+            }
+        }
+    }
 }
 
 /// Request structure for creating a new customer
+/// A customer with all of its notes, addresses and conversations
+///
+/// Returned by [`CrmCustomer::load_full`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrmCustomerWithChildren {
+    pub customer: CrmCustomer,
+    /// Sorted by creation date (oldest first)
+    pub notes: Vec<CrmCustomerNote>,
+    /// Primary addresses first
+    pub addresses: Vec<CrmCustomerAddress>,
+    /// Sorted by creation date (newest first)
+    pub conversations: Vec<CrmCustomerConversation>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateCrmCustomerRequest {
     pub first_name: String,
@@ -106,6 +226,9 @@ pub struct CrmCustomerConversation {
     pub message: String,
     pub source: String, // FROM_TEAM, FROM_CUSTOMER, INTERNAL_NOTE
     pub channel_uuid: String,
+    /// Conversation this message replies to, `None` if it starts a thread
+    #[serde(default)]
+    pub parent_conversation_uuid: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -115,6 +238,9 @@ pub struct CreateCrmCustomerConversationRequest {
     pub message: String,
     pub source: String,
     pub channel_uuid: String,
+    /// Conversation of the same customer this message replies to
+    #[serde(default)]
+    pub parent_conversation_uuid: Option<String>,
 }
 
 /// Request structure for creating a new customer address
@@ -162,6 +288,37 @@ impl CrmCustomer {
         database::load_customer_by_uuid(pool, customer_uuid).await
     }
 
+    /// Load a customer together with all of its notes, addresses and conversations
+    ///
+    /// The child collections are loaded concurrently once the customer was found.
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    /// * `customer_uuid` - UUID of the customer to load
+    ///
+    /// # Errors
+    /// Returns `CrmCustomerDatabaseError` if the customer is not found (`Sql(RowNotFound)`)
+    /// or a database query fails
+    pub async fn load_full(
+        pool: &flextide_core::database::DatabasePool,
+        customer_uuid: &str,
+    ) -> Result<CrmCustomerWithChildren, CrmCustomerDatabaseError> {
+        let customer = database::load_customer_by_uuid(pool, customer_uuid).await?;
+
+        let (notes, addresses, conversations) = futures_util::try_join!(
+            database::load_customer_notes(pool, customer_uuid),
+            database::load_customer_addresses(pool, customer_uuid),
+            database::load_customer_conversations(pool, customer_uuid),
+        )?;
+
+        Ok(CrmCustomerWithChildren {
+            customer,
+            notes,
+            addresses,
+            conversations,
+        })
+    }
+
     /// Create a new customer in the database for the specified organization
     ///
     /// # Arguments
@@ -316,6 +473,23 @@ impl CrmCustomer {
         database::create_customer_address(pool, &self.uuid, request).await
     }
 
+    /// List all addresses for this customer
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    ///
+    /// # Returns
+    /// Returns a vector of `CrmCustomerAddress`, primary addresses first
+    ///
+    /// # Errors
+    /// Returns `CrmCustomerDatabaseError` if the database query fails
+    pub async fn list_addresses(
+        &self,
+        pool: &flextide_core::database::DatabasePool,
+    ) -> Result<Vec<CrmCustomerAddress>, CrmCustomerDatabaseError> {
+        database::load_customer_addresses(pool, &self.uuid).await
+    }
+
     /// Delete an address from this customer
     ///
     /// # Arguments
@@ -353,6 +527,7 @@ impl CrmCustomer {
     ///
     /// # Note
     /// This will cascade delete all related records (notes, addresses, conversations) due to foreign key constraints
+    /// and cannot be undone. Use [`CrmCustomer::archive`] for a deletion that can be restored.
     pub async fn delete(
         self,
         pool: &flextide_core::database::DatabasePool,
@@ -360,7 +535,43 @@ impl CrmCustomer {
         database::delete_customer(pool, &self.uuid).await
     }
 
-    /// Search customers by query string
+    /// Archive this customer, hiding it from customer lists and search
+    ///
+    /// Notes, addresses and conversations are kept, so [`CrmCustomer::restore`] brings the
+    /// customer back unchanged. Archiving an archived customer is a no-op.
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    ///
+    /// # Errors
+    /// Returns `CrmCustomerDatabaseError` if the database operation fails
+    pub async fn archive(
+        &self,
+        pool: &flextide_core::database::DatabasePool,
+    ) -> Result<(), CrmCustomerDatabaseError> {
+        database::archive_customer(pool, &self.uuid).await
+    }
+
+    /// Restore this customer if it is archived
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    ///
+    /// # Errors
+    /// Returns `CrmCustomerDatabaseError` if the database operation fails
+    pub async fn restore(
+        &self,
+        pool: &flextide_core::database::DatabasePool,
+    ) -> Result<(), CrmCustomerDatabaseError> {
+        database::restore_customer(pool, &self.uuid).await
+    }
+
+    /// Whether this customer is archived
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    /// Search customers by query string, archived customers are skipped
     ///
     /// # Arguments
     /// * `pool` - Database connection pool
@@ -386,7 +597,9 @@ impl CrmCustomer {
     /// * `pool` - Database connection pool
     /// * `organization_uuid` - UUID of the organization to list customers for
     /// * `page` - Page number (1-based)
-    /// * `page_size` - Number of customers per page (max 50)
+    /// * `page_size` - Number of customers per page, callers clamp it to the configured maximum
+    /// * `sort` - Sort order of the customers
+    /// * `include_archived` - Whether archived customers are listed as well
     ///
     /// # Returns
     /// Returns a tuple of (customers, total_count)
@@ -398,8 +611,25 @@ impl CrmCustomer {
         organization_uuid: &str,
         page: u32,
         page_size: u32,
+        sort: CrmCustomerSort,
+        include_archived: bool,
     ) -> Result<(Vec<CrmCustomer>, u32), CrmCustomerDatabaseError> {
-        database::list_customers_paginated(pool, organization_uuid, page, page_size).await
+        database::list_customers_paginated(pool, organization_uuid, page, page_size, sort, include_archived).await
+    }
+
+    /// Count the customers of an organization, archived customers are not counted
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    /// * `organization_uuid` - UUID of the organization to count customers for
+    ///
+    /// # Errors
+    /// Returns `CrmCustomerDatabaseError` if the database query fails
+    pub async fn count_customers(
+        pool: &flextide_core::database::DatabasePool,
+        organization_uuid: &str,
+    ) -> Result<u32, CrmCustomerDatabaseError> {
+        database::count_customers(pool, organization_uuid).await
     }
 
     /// List all conversations for this customer
@@ -438,6 +668,54 @@ impl CrmCustomer {
         database::create_customer_conversation(pool, &self.uuid, request).await
     }
 
+    /// Load a conversation of this customer and all of its replies
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    /// * `root_uuid` - UUID of the conversation the thread starts at
+    ///
+    /// # Returns
+    /// Returns the thread in reading order, every conversation followed by its replies (oldest first)
+    ///
+    /// # Errors
+    /// Returns `CrmCustomerDatabaseError::ConversationNotFound` if the conversation does not
+    /// belong to this customer, or another `CrmCustomerDatabaseError` if the database query fails
+    pub async fn list_conversation_thread(
+        &self,
+        pool: &flextide_core::database::DatabasePool,
+        root_uuid: &str,
+    ) -> Result<Vec<CrmCustomerConversation>, CrmCustomerDatabaseError> {
+        let thread = database::list_conversation_thread(pool, root_uuid).await?;
+        if thread.first().is_some_and(|root| root.customer_uuid != self.uuid) {
+            return Err(CrmCustomerDatabaseError::ConversationNotFound(root_uuid.to_string()));
+        }
+        Ok(thread)
+    }
+
+    /// Move a conversation of this customer into another thread
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    /// * `conversation_uuid` - UUID of the conversation to move
+    /// * `parent_conversation_uuid` - UUID of the new parent, `None` to start a new thread
+    ///
+    /// # Errors
+    /// Returns `CrmCustomerDatabaseError::ConversationCycle` if the parent is a reply of the
+    /// conversation, `CrmCustomerDatabaseError::ConversationNotFound` if the conversation does not
+    /// belong to this customer, or another `CrmCustomerDatabaseError` if the operation fails
+    pub async fn set_conversation_parent(
+        &self,
+        pool: &flextide_core::database::DatabasePool,
+        conversation_uuid: &str,
+        parent_conversation_uuid: Option<&str>,
+    ) -> Result<(), CrmCustomerDatabaseError> {
+        let conversation = database::load_conversation_by_uuid(pool, conversation_uuid).await?;
+        if conversation.customer_uuid != self.uuid {
+            return Err(CrmCustomerDatabaseError::ConversationNotFound(conversation_uuid.to_string()));
+        }
+        database::set_conversation_parent(pool, conversation_uuid, parent_conversation_uuid).await
+    }
+
     /// Update this customer in the database
     ///
     /// # Arguments
@@ -458,3 +736,251 @@ impl CrmCustomer {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        create_channel, create_conversation, create_customer, create_customer_with_children, create_note,
+        create_organization, create_user, setup_tables,
+    };
+    use flextide_core::database::DatabasePool;
+
+    fn sorted(uuids: impl Iterator<Item = String>) -> Vec<String> {
+        let mut uuids: Vec<String> = uuids.collect();
+        uuids.sort();
+        uuids
+    }
+
+    #[sqlx::test]
+    async fn test_load_full(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let author_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &author_uuid).await;
+        let channel_uuid = create_channel(&pool, &org_uuid).await;
+        let customer = create_customer_with_children(&pool, &org_uuid, &author_uuid, &channel_uuid).await;
+        let note_uuids = vec![
+            customer.note_uuid.clone(),
+            create_note(&pool, &customer.customer_uuid, &author_uuid).await,
+        ];
+
+        // Children of other customers are not included
+        create_customer_with_children(&pool, &org_uuid, &author_uuid, &channel_uuid).await;
+
+        let full = CrmCustomer::load_full(&pool, &customer.customer_uuid).await.unwrap();
+        assert_eq!(full.customer.uuid, customer.customer_uuid);
+        assert_eq!(sorted(full.notes.into_iter().map(|n| n.uuid)), sorted(note_uuids.into_iter()));
+        assert_eq!(
+            full.addresses.iter().map(|a| a.uuid.as_str()).collect::<Vec<_>>(),
+            [customer.address_uuid.as_str()]
+        );
+        assert_eq!(
+            full.conversations.iter().map(|c| c.uuid.as_str()).collect::<Vec<_>>(),
+            [customer.conversation_uuid.as_str()]
+        );
+
+        Ok(())
+    }
+
+    async fn insert_customer(
+        pool: &DatabasePool,
+        org_uuid: &str,
+        first_name: &str,
+        last_name: &str,
+        company_name: Option<&str>,
+        created_at: &str,
+    ) -> String {
+        let customer_uuid = uuid::Uuid::new_v4().to_string();
+        sqlx::query(
+            "INSERT INTO module_crm_customers (uuid, organization_uuid, first_name, last_name, company_name, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )
+        .bind(&customer_uuid)
+        .bind(org_uuid)
+        .bind(first_name)
+        .bind(last_name)
+        .bind(company_name)
+        .bind(created_at)
+        .execute(crate::test_support::sqlite(pool))
+        .await
+        .expect("Failed to insert customer");
+        customer_uuid
+    }
+
+    #[sqlx::test]
+    async fn test_list_customers_sorted(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        let miller = insert_customer(&pool, &org_uuid, "Anna", "Miller", Some("Zeta GmbH"), "2025-01-02 10:00:00").await;
+        let adams = insert_customer(&pool, &org_uuid, "Bob", "Adams", None, "2025-03-01 10:00:00").await;
+        let clark = insert_customer(&pool, &org_uuid, "Carl", "Clark", Some("Acme Inc"), "2025-02-01 10:00:00").await;
+
+        let uuids = |customers: Vec<CrmCustomer>| customers.into_iter().map(|c| c.uuid).collect::<Vec<_>>();
+
+        let sort = CrmCustomerSort::from_params(Some("created_at"), Some("desc")).unwrap();
+        let (customers, total) = CrmCustomer::list_customers_paginated(&pool, &org_uuid, 1, 10, sort, false).await.unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(uuids(customers), vec![adams.clone(), clark.clone(), miller.clone()]);
+
+        let sort = CrmCustomerSort::from_params(Some("name"), Some("asc")).unwrap();
+        let (customers, _) = CrmCustomer::list_customers_paginated(&pool, &org_uuid, 1, 10, sort, false).await.unwrap();
+        assert_eq!(uuids(customers), vec![adams.clone(), clark.clone(), miller.clone()]);
+
+        // Customers without a company come last
+        let sort = CrmCustomerSort::from_params(Some("company"), None).unwrap();
+        let (customers, _) = CrmCustomer::list_customers_paginated(&pool, &org_uuid, 1, 10, sort, false).await.unwrap();
+        assert_eq!(uuids(customers), vec![clark.clone(), miller.clone(), adams.clone()]);
+
+        let sort = CrmCustomerSort::from_params(Some("created_at"), Some("desc")).unwrap();
+        let (customers, _) = CrmCustomer::list_customers_paginated(&pool, &org_uuid, 2, 2, sort, false).await.unwrap();
+        assert_eq!(uuids(customers), vec![miller]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_archive_and_restore_customer(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        let archived_uuid = create_customer(&pool, &org_uuid).await;
+        let active_uuid = create_customer(&pool, &org_uuid).await;
+        create_note(&pool, &archived_uuid, &owner_uuid).await;
+
+        let customer = CrmCustomer::load_from_database(&pool, &archived_uuid).await.unwrap();
+        customer.archive(&pool).await.unwrap();
+        // Archiving twice is a no-op
+        customer.archive(&pool).await.unwrap();
+
+        let uuids = |customers: Vec<CrmCustomer>| sorted(customers.into_iter().map(|c| c.uuid));
+        let list = |include_archived| {
+            CrmCustomer::list_customers_paginated(&pool, &org_uuid, 1, 10, CrmCustomerSort::default(), include_archived)
+        };
+
+        let (customers, total) = list(false).await.unwrap();
+        assert_eq!((uuids(customers), total), (vec![active_uuid.clone()], 1));
+        assert_eq!(CrmCustomer::count_customers(&pool, &org_uuid).await.unwrap(), 1);
+        assert_eq!(
+            uuids(CrmCustomer::search_customers(&pool, &org_uuid, "Jane").await.unwrap()),
+            vec![active_uuid.clone()]
+        );
+
+        let (customers, total) = list(true).await.unwrap();
+        assert_eq!((uuids(customers), total), (sorted([archived_uuid.clone(), active_uuid.clone()].into_iter()), 2));
+
+        // Archived customers can still be loaded, with their notes
+        let archived = CrmCustomer::load_full(&pool, &archived_uuid).await.unwrap();
+        assert!(archived.customer.is_archived());
+        assert_eq!(archived.notes.len(), 1);
+
+        archived.customer.restore(&pool).await.unwrap();
+
+        let restored = CrmCustomer::load_from_database(&pool, &archived_uuid).await.unwrap();
+        assert!(!restored.is_archived());
+        let (customers, total) = list(false).await.unwrap();
+        assert_eq!((uuids(customers), total), (sorted([archived_uuid.clone(), active_uuid.clone()].into_iter()), 2));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_conversation_thread(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = create_user(&pool).await;
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        let channel_uuid = create_channel(&pool, &org_uuid).await;
+        let customer_uuid = create_customer(&pool, &org_uuid).await;
+        let other_customer_uuid = create_customer(&pool, &org_uuid).await;
+        let other_conversation_uuid = create_conversation(&pool, &other_customer_uuid, &channel_uuid).await;
+
+        let customer = CrmCustomer::load_from_database(&pool, &customer_uuid).await.unwrap();
+        let add = |message: &str, parent: Option<&String>| {
+            customer.add_conversation(
+                &pool,
+                CreateCrmCustomerConversationRequest {
+                    message: message.to_string(),
+                    source: "FROM_CUSTOMER".to_string(),
+                    channel_uuid: channel_uuid.clone(),
+                    parent_conversation_uuid: parent.cloned(),
+                },
+            )
+        };
+
+        // Created out of reading order: the second reply arrives before the answer to the first
+        let root = add("Question", None).await.unwrap();
+        let first_reply = add("First reply", Some(&root)).await.unwrap();
+        let second_reply = add("Second reply", Some(&root)).await.unwrap();
+        let nested_reply = add("Reply to first reply", Some(&first_reply)).await.unwrap();
+        let unrelated = add("Another question", None).await.unwrap();
+
+        let thread = customer.list_conversation_thread(&pool, &root).await.unwrap();
+        let uuids: Vec<String> = thread.iter().map(|c| c.uuid.clone()).collect();
+        assert_eq!(uuids, vec![root.clone(), first_reply.clone(), nested_reply.clone(), second_reply.clone()]);
+        assert_eq!(thread[2].parent_conversation_uuid.as_deref(), Some(first_reply.as_str()));
+
+        // Replies can only point to conversations of the same customer
+        assert!(matches!(
+            add("Wrong customer", Some(&other_conversation_uuid)).await,
+            Err(CrmCustomerDatabaseError::ParentConversationNotFound(_))
+        ));
+
+        // A conversation cannot become a reply to itself or to one of its replies
+        for parent in [&root, &nested_reply] {
+            assert!(matches!(
+                customer.set_conversation_parent(&pool, &root, Some(parent)).await,
+                Err(CrmCustomerDatabaseError::ConversationCycle)
+            ));
+        }
+
+        // Moving a subtree carries its replies along
+        customer.set_conversation_parent(&pool, &first_reply, Some(&unrelated)).await.unwrap();
+        let uuids: Vec<String> = customer
+            .list_conversation_thread(&pool, &unrelated)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.uuid)
+            .collect();
+        assert_eq!(uuids, vec![unrelated.clone(), first_reply.clone(), nested_reply.clone()]);
+
+        customer.set_conversation_parent(&pool, &first_reply, None).await.unwrap();
+        assert_eq!(customer.list_conversation_thread(&pool, &unrelated).await.unwrap().len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_sort_params_are_rejected() {
+        assert_eq!(CrmCustomerSort::from_params(None, None).unwrap(), CrmCustomerSort::default());
+        assert!(matches!(
+            CrmCustomerSort::from_params(Some("email; DROP TABLE module_crm_customers"), None),
+            Err(CrmCustomerDatabaseError::InvalidSortColumn(_))
+        ));
+        assert!(matches!(
+            CrmCustomerSort::from_params(Some("name"), Some("sideways")),
+            Err(CrmCustomerDatabaseError::InvalidSortDirection(_))
+        ));
+    }
+
+    #[sqlx::test]
+    async fn test_load_full_missing_customer(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        assert!(matches!(
+            CrmCustomer::load_full(&pool, "00000000-0000-0000-0000-000000000000").await,
+            Err(CrmCustomerDatabaseError::Sql(sqlx::Error::RowNotFound))
+        ));
+
+        Ok(())
+    }
+}
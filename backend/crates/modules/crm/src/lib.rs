@@ -1,5 +1,7 @@
 mod api;
 mod customer;
+#[cfg(test)]
+mod test_support;
 
 use axum::{
     extract::{Extension, Query},
@@ -9,18 +11,23 @@ use axum::{
     Router,
 };
 use chrono::{Datelike, Utc};
-use flextide_core::database::DatabasePool;
+use flextide_core::database::{DatabaseError, DatabasePool};
 use flextide_core::jwt::Claims;
+use flextide_core::pagination::{PaginatedResource, Pagination, PaginationConfig};
 use flextide_core::user::{user_belongs_to_organization, user_has_permission};
 use serde::Serialize;
 use serde_json::json;
-use sqlx::Row;
+use std::sync::Arc;
+
+use customer::CrmCustomerDatabaseError;
 
 pub use customer::{
-    CrmCustomer, CrmCustomerAddress, CrmCustomerConversation, CrmCustomerNote,
-    CreateCrmCustomerAddressRequest, CreateCrmCustomerConversationRequest,
-    CreateCrmCustomerNoteRequest, CreateCrmCustomerRequest, UpdateCrmCustomerRequest,
-    UpdateCrmCustomerNoteRequest,
+    erase_customer, export_customer_data, export_customers_to_csv, import_customers_from_csv, stream_customers,
+    stream_customers_csv, CrmCustomer, CrmCustomerAddress, CrmCustomerConversation, CrmCustomerDataExport, CrmCustomerErasureEntry, CrmCustomerImportCreated,
+    CrmCustomerImportReport, CrmCustomerImportRowIssue, CrmCustomerNote, CrmCustomerSort, CrmCustomerSortColumn,
+    CrmCustomerWithChildren, CrmSortDirection, CreateCrmCustomerAddressRequest,
+    CreateCrmCustomerConversationRequest, CreateCrmCustomerNoteRequest, CreateCrmCustomerRequest,
+    UpdateCrmCustomerRequest, UpdateCrmCustomerNoteRequest, CUSTOMER_EXPORT_COLUMNS,
 };
 
 pub fn create_router<S>() -> Router<S>
@@ -106,53 +113,24 @@ async fn get_kpis(
     Extension(org_uuid): Extension<String>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     // Count total customers for the organization
-    let total_customers = match &pool {
-        DatabasePool::MySql(p) => {
-            let row = sqlx::query("SELECT COUNT(*) as count FROM module_crm_customers WHERE organization_uuid = ?")
-                .bind(&org_uuid)
-                .fetch_one(p)
-                .await
-                .map_err(|e| {
-                    tracing::error!("Failed to count customers for organization {}: {}", org_uuid, e);
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(json!({ "error": "Failed to fetch customer count" })),
-                    )
-                })?;
-            let count: i64 = row.get("count");
-            count as u32
-        }
-        DatabasePool::Postgres(p) => {
-            let row = sqlx::query("SELECT COUNT(*) as count FROM module_crm_customers WHERE organization_uuid = $1")
-                .bind(&org_uuid)
-                .fetch_one(p)
-                .await
-                .map_err(|e| {
-                    tracing::error!("Failed to count customers for organization {}: {}", org_uuid, e);
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(json!({ "error": "Failed to fetch customer count" })),
-                    )
-                })?;
-            let count: i64 = row.get("count");
-            count as u32
-        }
-        DatabasePool::Sqlite(p) => {
-            let row = sqlx::query("SELECT COUNT(*) as count FROM module_crm_customers WHERE organization_uuid = ?1")
-                .bind(&org_uuid)
-                .fetch_one(p)
-                .await
-                .map_err(|e| {
-                    tracing::error!("Failed to count customers for organization {}: {}", org_uuid, e);
-                    (
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(json!({ "error": "Failed to fetch customer count" })),
-                    )
-                })?;
-            let count: i64 = row.get("count");
-            count as u32
-        }
-    };
+    let total_customers = CrmCustomer::count_customers(&pool, &org_uuid)
+        .await
+        .map_err(|e| match e {
+            CrmCustomerDatabaseError::Database(DatabaseError::QueryTimeout(timeout)) => {
+                tracing::warn!("Counting customers for organization {} timed out after {:?}", org_uuid, timeout);
+                (
+                    StatusCode::GATEWAY_TIMEOUT,
+                    Json(json!({ "error": "Fetching customer count timed out" })),
+                )
+            }
+            e => {
+                tracing::error!("Failed to count customers for organization {}: {}", org_uuid, e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch customer count" })),
+                )
+            }
+        })?;
     
     // TODO: Fetch other KPIs from database based on org_uuid
     // For now, return mocked data for other fields
@@ -173,12 +151,20 @@ async fn get_kpis(
 struct CustomersQuery {
     page: Option<u32>,
     page_size: Option<u32>,
+    /// `name`, `created_at` or `company`
+    sort_by: Option<String>,
+    /// `asc` or `desc`
+    sort_dir: Option<String>,
+    /// List archived customers as well
+    #[serde(default)]
+    include_archived: bool,
 }
 
 async fn get_customers(
     Extension(pool): Extension<DatabasePool>,
     Extension(org_uuid): Extension<String>,
     Extension(claims): Extension<Claims>,
+    Extension(pagination): Extension<Arc<PaginationConfig>>,
     Query(params): Query<CustomersQuery>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
     // Check if user belongs to organization
@@ -217,19 +203,27 @@ async fn get_customers(
         ));
     }
 
-    let page = params.page.unwrap_or(1);
-    let page_size = params.page_size.unwrap_or(50).min(50);
+    let sort = CrmCustomerSort::from_params(params.sort_by.as_deref(), params.sort_dir.as_deref()).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": e.to_string() })),
+        )
+    })?;
+
+    let Pagination { page, limit: page_size, .. } =
+        pagination.clamp(PaginatedResource::CrmCustomers, params.page.unwrap_or(1), params.page_size);
     
     // Fetch customers with pagination
-    let (crm_customers, total_count) = CrmCustomer::list_customers_paginated(&pool, &org_uuid, page, page_size)
-        .await
-        .map_err(|e| {
-            tracing::error!("Failed to list customers: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(json!({ "error": "Failed to fetch customers" })),
-            )
-        })?;
+    let (crm_customers, total_count) =
+        CrmCustomer::list_customers_paginated(&pool, &org_uuid, page, page_size, sort, params.include_archived)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to list customers: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to fetch customers" })),
+                )
+            })?;
     
     // Convert to response format
     let customers: Vec<Customer> = crm_customers
@@ -239,7 +233,7 @@ async fn get_customers(
             name: format!("{} {}", c.first_name, c.last_name),
             email: c.email.unwrap_or_default(),
             company: c.company_name,
-            status: "Active".to_string(), // TODO: Add status field to database
+            status: if c.archived_at.is_some() { "Archived" } else { "Active" }.to_string(),
             created_at: c.created_at.to_rfc3339(),
             last_contact: None, // TODO: Add last_contact field to database
         })
@@ -0,0 +1,236 @@
+//! Shared helpers for CRM module tests
+//!
+//! Creates the SQLite schema the CRM module relies on and inserts test fixtures.
+
+use flextide_core::database::DatabasePool;
+
+/// Tables required by the CRM module (SQLite syntax)
+const SCHEMA: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS users (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        email VARCHAR(255) NOT NULL UNIQUE,
+        password_hash VARCHAR(255) NOT NULL,
+        prename VARCHAR(255) NOT NULL,
+        lastname VARCHAR(255),
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+    )",
+    "CREATE TABLE IF NOT EXISTS organizations (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        name VARCHAR(255) NOT NULL,
+        owner_user_id CHAR(36) NOT NULL,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+    )",
+    "CREATE TABLE IF NOT EXISTS module_crm_customers (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        organization_uuid CHAR(36) NOT NULL,
+        first_name VARCHAR(255) NOT NULL,
+        last_name VARCHAR(255) NOT NULL,
+        email VARCHAR(255),
+        phone_number VARCHAR(50),
+        user_id CHAR(36),
+        salutation VARCHAR(10),
+        job_title VARCHAR(255),
+        department VARCHAR(255),
+        company_name VARCHAR(255),
+        fax_number VARCHAR(50),
+        website_url VARCHAR(500),
+        gender VARCHAR(20),
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        archived_at TIMESTAMP NULL
+    )",
+    "CREATE TABLE IF NOT EXISTS module_crm_customer_notes (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        customer_uuid CHAR(36) NOT NULL,
+        note_text TEXT NOT NULL,
+        author_id CHAR(36) NOT NULL,
+        visible_to_customer INTEGER NOT NULL DEFAULT 0,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+    )",
+    "CREATE TABLE IF NOT EXISTS module_crm_customer_addresses (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        customer_uuid CHAR(36) NOT NULL,
+        address_type VARCHAR(50) NOT NULL,
+        street VARCHAR(255),
+        city VARCHAR(255),
+        state_province VARCHAR(255),
+        postal_code VARCHAR(50),
+        country VARCHAR(100),
+        is_primary INTEGER NOT NULL DEFAULT 0,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+    )",
+    "CREATE TABLE IF NOT EXISTS module_crm_conversation_channels (
+        channel_uuid CHAR(36) NOT NULL PRIMARY KEY,
+        organization_uuid CHAR(36) NOT NULL,
+        name VARCHAR(255) NOT NULL,
+        description VARCHAR(600),
+        icon_name VARCHAR(255),
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+    )",
+    "CREATE TABLE IF NOT EXISTS module_crm_customer_conversations (
+        conversation_uuid CHAR(36) NOT NULL PRIMARY KEY,
+        customer_uuid CHAR(36) NOT NULL,
+        message TEXT NOT NULL,
+        source VARCHAR(20) NOT NULL CHECK (source IN ('FROM_TEAM', 'FROM_CUSTOMER', 'INTERNAL_NOTE')),
+        channel_uuid CHAR(36) NOT NULL,
+        parent_conversation_uuid CHAR(36) NULL,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+    )",
+    "CREATE TABLE IF NOT EXISTS module_crm_customer_erasure_log (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        organization_uuid CHAR(36) NOT NULL,
+        customer_uuid CHAR(36) NOT NULL,
+        notes_deleted INTEGER NOT NULL DEFAULT 0,
+        addresses_deleted INTEGER NOT NULL DEFAULT 0,
+        conversations_deleted INTEGER NOT NULL DEFAULT 0,
+        erased_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+    )",
+];
+
+pub(crate) fn sqlite(pool: &DatabasePool) -> &sqlx::SqlitePool {
+    match pool {
+        DatabasePool::Sqlite(p) => p,
+        _ => panic!("Test only supports SQLite"),
+    }
+}
+
+/// Create all CRM module tables
+pub(crate) async fn setup_tables(pool: &DatabasePool) {
+    for statement in SCHEMA {
+        sqlx::query(statement)
+            .execute(sqlite(pool))
+            .await
+            .expect("Failed to create test table");
+    }
+}
+
+/// Create a user and return its UUID
+pub(crate) async fn create_user(pool: &DatabasePool) -> String {
+    let user_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query(
+        "INSERT INTO users (uuid, email, password_hash, prename, lastname)
+         VALUES (?1, ?2, 'hash', 'Test', 'User')",
+    )
+    .bind(&user_uuid)
+    .bind(format!("{}@example.com", user_uuid))
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert user");
+    user_uuid
+}
+
+/// Create an organization and return its UUID
+pub(crate) async fn create_organization(pool: &DatabasePool, owner_uuid: &str) -> String {
+    let org_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO organizations (uuid, name, owner_user_id) VALUES (?1, 'Test Organization', ?2)")
+        .bind(&org_uuid)
+        .bind(owner_uuid)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert organization");
+    org_uuid
+}
+
+/// Create a conversation channel and return its UUID
+pub(crate) async fn create_channel(pool: &DatabasePool, org_uuid: &str) -> String {
+    let channel_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query(
+        "INSERT INTO module_crm_conversation_channels (channel_uuid, organization_uuid, name)
+         VALUES (?1, ?2, 'E-Mail')",
+    )
+    .bind(&channel_uuid)
+    .bind(org_uuid)
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert conversation channel");
+    channel_uuid
+}
+
+/// Create a customer and return its UUID
+pub(crate) async fn create_customer(pool: &DatabasePool, org_uuid: &str) -> String {
+    let customer_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query(
+        "INSERT INTO module_crm_customers (uuid, organization_uuid, first_name, last_name, email)
+         VALUES (?1, ?2, 'Jane', 'Doe', 'jane.doe@example.com')",
+    )
+    .bind(&customer_uuid)
+    .bind(org_uuid)
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert customer");
+    customer_uuid
+}
+
+/// Create a note on a customer and return its UUID
+pub(crate) async fn create_note(pool: &DatabasePool, customer_uuid: &str, author_uuid: &str) -> String {
+    let note_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query(
+        "INSERT INTO module_crm_customer_notes (uuid, customer_uuid, note_text, author_id)
+         VALUES (?1, ?2, 'Called about the renewal', ?3)",
+    )
+    .bind(&note_uuid)
+    .bind(customer_uuid)
+    .bind(author_uuid)
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert note");
+    note_uuid
+}
+
+/// Create an address of a customer and return its UUID
+pub(crate) async fn create_address(pool: &DatabasePool, customer_uuid: &str) -> String {
+    let address_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query(
+        "INSERT INTO module_crm_customer_addresses (uuid, customer_uuid, address_type, city, is_primary)
+         VALUES (?1, ?2, 'billing', 'Berlin', 1)",
+    )
+    .bind(&address_uuid)
+    .bind(customer_uuid)
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert address");
+    address_uuid
+}
+
+/// Create a conversation message of a customer and return its UUID
+pub(crate) async fn create_conversation(pool: &DatabasePool, customer_uuid: &str, channel_uuid: &str) -> String {
+    let conversation_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query(
+        "INSERT INTO module_crm_customer_conversations (conversation_uuid, customer_uuid, message, source, channel_uuid)
+         VALUES (?1, ?2, 'Hello, I have a question', 'FROM_CUSTOMER', ?3)",
+    )
+    .bind(&conversation_uuid)
+    .bind(customer_uuid)
+    .bind(channel_uuid)
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert conversation");
+    conversation_uuid
+}
+
+/// UUIDs of a customer created by [`create_customer_with_children`]
+pub(crate) struct CustomerFixture {
+    pub customer_uuid: String,
+    pub note_uuid: String,
+    pub address_uuid: String,
+    pub conversation_uuid: String,
+}
+
+/// Create a customer with one note, one address and one conversation message
+pub(crate) async fn create_customer_with_children(
+    pool: &DatabasePool,
+    org_uuid: &str,
+    author_uuid: &str,
+    channel_uuid: &str,
+) -> CustomerFixture {
+    let customer_uuid = create_customer(pool, org_uuid).await;
+    CustomerFixture {
+        note_uuid: create_note(pool, &customer_uuid, author_uuid).await,
+        address_uuid: create_address(pool, &customer_uuid).await,
+        conversation_uuid: create_conversation(pool, &customer_uuid, channel_uuid).await,
+        customer_uuid,
+    }
+}
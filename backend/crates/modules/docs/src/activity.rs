@@ -0,0 +1,446 @@
+//! Docs Activity module
+//!
+//! Records page changes within documentation areas and provides the activity feed of an area.
+
+use chrono::{DateTime, Utc};
+use flextide_core::database::{DatabaseError, DatabasePool};
+use flextide_core::user::{user_belongs_to_organization, user_has_permission};
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+use thiserror::Error;
+
+use crate::area::{load_area_by_uuid, load_area_member_permissions, DocsAreaDatabaseError};
+
+/// Maximum number of activity entries returned at once
+pub const MAX_AREA_ACTIVITY_LIMIT: i64 = 100;
+
+/// Error type for Docs activity database operations
+#[derive(Debug, Error)]
+pub enum DocsActivityDatabaseError {
+    #[error("Database error: {0}")]
+    Database(#[from] DatabaseError),
+
+    #[error("SQL execution error: {0}")]
+    Sql(#[from] sqlx::Error),
+
+    #[error("User does not belong to this organization")]
+    UserNotInOrganization,
+
+    #[error("Area not found")]
+    AreaNotFound,
+
+    #[error("Area does not belong to this organization")]
+    AreaNotInOrganization,
+
+    #[error("Invalid activity action: {0}")]
+    InvalidAction(String),
+}
+
+impl From<DocsAreaDatabaseError> for DocsActivityDatabaseError {
+    fn from(error: DocsAreaDatabaseError) -> Self {
+        match error {
+            DocsAreaDatabaseError::Database(e) => DocsActivityDatabaseError::Database(e),
+            DocsAreaDatabaseError::Sql(e) => DocsActivityDatabaseError::Sql(e),
+            DocsAreaDatabaseError::UserNotInOrganization => DocsActivityDatabaseError::UserNotInOrganization,
+            DocsAreaDatabaseError::AreaNotFound => DocsActivityDatabaseError::AreaNotFound,
+            DocsAreaDatabaseError::AreaNotInOrganization => DocsActivityDatabaseError::AreaNotInOrganization,
+            DocsAreaDatabaseError::PermissionDenied | DocsAreaDatabaseError::EmptyShortName => {
+                DocsActivityDatabaseError::Database(DatabaseError::PoolCreationFailed(sqlx::Error::RowNotFound))
+            }
+        }
+    }
+}
+
+/// Kind of change recorded in the activity of an area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocsActivityAction {
+    PageCreated,
+    PageUpdated,
+    PageDeleted,
+}
+
+impl DocsActivityAction {
+    /// Get the string stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocsActivityAction::PageCreated => "page_created",
+            DocsActivityAction::PageUpdated => "page_updated",
+            DocsActivityAction::PageDeleted => "page_deleted",
+        }
+    }
+
+    /// Parse the string stored in the database
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "page_created" => Some(DocsActivityAction::PageCreated),
+            "page_updated" => Some(DocsActivityAction::PageUpdated),
+            "page_deleted" => Some(DocsActivityAction::PageDeleted),
+            _ => None,
+        }
+    }
+}
+
+/// Activity entry of an area - who changed which page and how
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocsAreaActivity {
+    pub uuid: String,
+    pub organization_uuid: String,
+    pub area_uuid: String,
+    /// User who performed the change
+    pub user_uuid: String,
+    pub action: DocsActivityAction,
+    pub page_uuid: String,
+    /// Title of the page at the time of the change
+    pub page_title: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Record a page change in the activity of its area (MySQL)
+///
+/// Called within the transaction of the change, so the entry is only kept if the change is.
+pub(crate) async fn record_area_activity_mysql(
+    conn: &mut sqlx::MySqlConnection,
+    organization_uuid: &str,
+    area_uuid: &str,
+    user_uuid: &str,
+    action: DocsActivityAction,
+    page_uuid: &str,
+    page_title: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO module_docs_area_activity
+         (uuid, organization_uuid, area_uuid, user_uuid, action, page_uuid, page_title, created_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(organization_uuid)
+    .bind(area_uuid)
+    .bind(user_uuid)
+    .bind(action.as_str())
+    .bind(page_uuid)
+    .bind(page_title)
+    .bind(Utc::now())
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Record a page change in the activity of its area (PostgreSQL), see [`record_area_activity_mysql`]
+pub(crate) async fn record_area_activity_postgres(
+    conn: &mut sqlx::PgConnection,
+    organization_uuid: &str,
+    area_uuid: &str,
+    user_uuid: &str,
+    action: DocsActivityAction,
+    page_uuid: &str,
+    page_title: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO module_docs_area_activity
+         (uuid, organization_uuid, area_uuid, user_uuid, action, page_uuid, page_title, created_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(organization_uuid)
+    .bind(area_uuid)
+    .bind(user_uuid)
+    .bind(action.as_str())
+    .bind(page_uuid)
+    .bind(page_title)
+    .bind(Utc::now())
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Record a page change in the activity of its area (SQLite), see [`record_area_activity_mysql`]
+pub(crate) async fn record_area_activity_sqlite(
+    conn: &mut sqlx::SqliteConnection,
+    organization_uuid: &str,
+    area_uuid: &str,
+    user_uuid: &str,
+    action: DocsActivityAction,
+    page_uuid: &str,
+    page_title: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO module_docs_area_activity
+         (uuid, organization_uuid, area_uuid, user_uuid, action, page_uuid, page_title, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(organization_uuid)
+    .bind(area_uuid)
+    .bind(user_uuid)
+    .bind(action.as_str())
+    .bind(page_uuid)
+    .bind(page_title)
+    .bind(Utc::now())
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// List the most recent activity of an area, newest first
+///
+/// Users who cannot view the area's pages (neither an area member with view rights
+/// nor `module_docs_super_admin`) get an empty list.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization (for verification)
+/// * `user_uuid` - UUID of the user requesting the activity
+/// * `area_uuid` - UUID of the area
+/// * `limit` - Maximum number of entries, clamped to 1..=`MAX_AREA_ACTIVITY_LIMIT`
+///
+/// # Errors
+/// Returns `DocsActivityDatabaseError` if:
+/// - User does not belong to the organization
+/// - Area does not exist or does not belong to the organization
+/// - Database operation fails
+pub async fn list_area_activity(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    user_uuid: &str,
+    area_uuid: &str,
+    limit: i64,
+) -> Result<Vec<DocsAreaActivity>, DocsActivityDatabaseError> {
+    let belongs = user_belongs_to_organization(pool, user_uuid, organization_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            DocsActivityDatabaseError::Database(e.into())
+        })?;
+
+    if !belongs {
+        return Err(DocsActivityDatabaseError::UserNotInOrganization);
+    }
+
+    let area = load_area_by_uuid(pool, area_uuid).await?;
+
+    if area.organization_uuid != organization_uuid {
+        return Err(DocsActivityDatabaseError::AreaNotInOrganization);
+    }
+
+    let can_view = match load_area_member_permissions(pool, area_uuid, user_uuid).await? {
+        Some(perms) => perms.admin || perms.role == "owner" || perms.can_view,
+        None => false,
+    };
+
+    let can_view = can_view
+        || user_has_permission(pool, user_uuid, organization_uuid, "module_docs_super_admin")
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error checking permission: {}", e);
+                DocsActivityDatabaseError::Database(e.into())
+            })?;
+
+    if !can_view {
+        return Ok(Vec::new());
+    }
+
+    let limit = limit.clamp(1, MAX_AREA_ACTIVITY_LIMIT);
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, area_uuid, user_uuid, action, page_uuid, page_title, created_at
+                 FROM module_docs_area_activity
+                 WHERE area_uuid = ? AND organization_uuid = ?
+                 ORDER BY created_at DESC
+                 LIMIT ?",
+            )
+            .bind(area_uuid)
+            .bind(organization_uuid)
+            .bind(limit)
+            .fetch_all(p)
+            .await?;
+
+            rows.iter()
+                .map(|row| {
+                    Ok(DocsAreaActivity {
+                        uuid: row.get("uuid"),
+                        organization_uuid: row.get("organization_uuid"),
+                        area_uuid: row.get("area_uuid"),
+                        user_uuid: row.get("user_uuid"),
+                        action: parse_activity_action(&row.get::<String, _>("action"))?,
+                        page_uuid: row.get("page_uuid"),
+                        page_title: row.get("page_title"),
+                        created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    })
+                })
+                .collect()
+        }
+        DatabasePool::Postgres(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, area_uuid, user_uuid, action, page_uuid, page_title, created_at
+                 FROM module_docs_area_activity
+                 WHERE area_uuid = $1 AND organization_uuid = $2
+                 ORDER BY created_at DESC
+                 LIMIT $3",
+            )
+            .bind(area_uuid)
+            .bind(organization_uuid)
+            .bind(limit)
+            .fetch_all(p)
+            .await?;
+
+            rows.iter()
+                .map(|row| {
+                    Ok(DocsAreaActivity {
+                        uuid: row.get("uuid"),
+                        organization_uuid: row.get("organization_uuid"),
+                        area_uuid: row.get("area_uuid"),
+                        user_uuid: row.get("user_uuid"),
+                        action: parse_activity_action(&row.get::<String, _>("action"))?,
+                        page_uuid: row.get("page_uuid"),
+                        page_title: row.get("page_title"),
+                        created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    })
+                })
+                .collect()
+        }
+        DatabasePool::Sqlite(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, area_uuid, user_uuid, action, page_uuid, page_title, created_at
+                 FROM module_docs_area_activity
+                 WHERE area_uuid = ?1 AND organization_uuid = ?2
+                 ORDER BY created_at DESC
+                 LIMIT ?3",
+            )
+            .bind(area_uuid)
+            .bind(organization_uuid)
+            .bind(limit)
+            .fetch_all(p)
+            .await?;
+
+            rows.iter()
+                .map(|row| {
+                    Ok(DocsAreaActivity {
+                        uuid: row.get("uuid"),
+                        organization_uuid: row.get("organization_uuid"),
+                        area_uuid: row.get("area_uuid"),
+                        user_uuid: row.get("user_uuid"),
+                        action: parse_activity_action(&row.get::<String, _>("action"))?,
+                        page_uuid: row.get("page_uuid"),
+                        page_title: row.get("page_title"),
+                        created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    })
+                })
+                .collect()
+        }
+    }
+}
+
+/// Parse an activity action read from the database
+fn parse_activity_action(action: &str) -> Result<DocsActivityAction, DocsActivityDatabaseError> {
+    DocsActivityAction::parse(action)
+        .ok_or_else(|| DocsActivityDatabaseError::InvalidAction(action.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::page::{create_page, delete_page, save_page_content, CreateDocsPageRequest};
+    use crate::test_support::{add_organization_member, create_area, create_organization, setup_tables};
+    use flextide_core::events::EventDispatcher;
+
+    fn page_request(area_uuid: &str, title: &str) -> CreateDocsPageRequest {
+        CreateDocsPageRequest {
+            area_uuid: area_uuid.to_string(),
+            title: title.to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_page_changes_produce_ordered_activity(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let other_area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        let first_page = create_page(&pool, &org_uuid, &user_uuid, page_request(&area_uuid, "First"), &dispatcher)
+            .await
+            .expect("Failed to create page");
+        save_page_content(&pool, &org_uuid, &first_page, &user_uuid, "edited", &dispatcher)
+            .await
+            .expect("Failed to save page content");
+        let second_page = create_page(&pool, &org_uuid, &user_uuid, page_request(&area_uuid, "Second"), &dispatcher)
+            .await
+            .expect("Failed to create page");
+        delete_page(&pool, &second_page, &org_uuid, &user_uuid, &dispatcher, None)
+            .await
+            .expect("Failed to delete page");
+        // Changes in other areas do not show up
+        create_page(&pool, &org_uuid, &user_uuid, page_request(&other_area_uuid, "Elsewhere"), &dispatcher)
+            .await
+            .expect("Failed to create page");
+
+        let activity = list_area_activity(&pool, &org_uuid, &user_uuid, &area_uuid, 10).await.unwrap();
+        let entries: Vec<_> = activity
+            .iter()
+            .map(|entry| (entry.action, entry.page_uuid.as_str(), entry.page_title.as_str()))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                (DocsActivityAction::PageDeleted, second_page.as_str(), "Second"),
+                (DocsActivityAction::PageCreated, second_page.as_str(), "Second"),
+                (DocsActivityAction::PageUpdated, first_page.as_str(), "First"),
+                (DocsActivityAction::PageCreated, first_page.as_str(), "First"),
+            ]
+        );
+        assert!(activity.iter().all(|entry| entry.user_uuid == user_uuid));
+
+        // The limit keeps the most recent entries
+        let activity = list_area_activity(&pool, &org_uuid, &user_uuid, &area_uuid, 2).await.unwrap();
+        assert_eq!(activity.len(), 2);
+        assert_eq!(activity[0].action, DocsActivityAction::PageDeleted);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_non_member_sees_no_activity(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = uuid::Uuid::new_v4().to_string();
+        let other_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        add_organization_member(&pool, &org_uuid, &other_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &owner_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        create_page(&pool, &org_uuid, &owner_uuid, page_request(&area_uuid, "Private"), &dispatcher)
+            .await
+            .expect("Failed to create page");
+
+        assert_eq!(list_area_activity(&pool, &org_uuid, &owner_uuid, &area_uuid, 10).await.unwrap().len(), 1);
+        assert!(list_area_activity(&pool, &org_uuid, &other_uuid, &area_uuid, 10).await.unwrap().is_empty());
+
+        // Users outside the organization are rejected
+        let outsider_uuid = uuid::Uuid::new_v4().to_string();
+        assert!(matches!(
+            list_area_activity(&pool, &org_uuid, &outsider_uuid, &area_uuid, 10).await,
+            Err(DocsActivityDatabaseError::UserNotInOrganization)
+        ));
+
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@
 
 use axum::{
     extract::{Extension, Path, Query},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Json},
     routing::{delete, get, post, put},
     Router,
@@ -15,17 +15,27 @@ use flextide_core::events::{Event, EventDispatcher, EventPayload};
 use flextide_core::jwt::Claims;
 use serde_json::{json, Value as JsonValue};
 
+use crate::activity::{list_area_activity, DocsActivityDatabaseError};
 use crate::area::{
-    create_area, delete_area, load_area_by_uuid, load_area_member_permissions, list_accessible_areas, update_area,
-    CreateDocsAreaRequest, DocsAreaDatabaseError, UpdateDocsAreaRequest,
+    archive_area, create_area, delete_area, load_area_by_uuid, load_area_member_permissions, list_accessible_areas,
+    unarchive_area, update_area, AreaMemberPermissions, CreateDocsAreaRequest, DocsAreaDatabaseError,
+    UpdateDocsAreaRequest,
 };
+use crate::export::{export_page_pdf, DocsExportError};
 use crate::folder::{
     create_folder, delete_folder, list_folders, move_folder, reorder_folder, update_folder, update_folder_name,
     update_folder_properties,
     CreateDocsFolderRequest, DocsFolderDatabaseError, MoveDocsFolderRequest, UpdateDocsFolderRequest,
 };
-use crate::page::{create_page, list_pages, list_page_versions, load_page_with_version, move_page, save_page_content, update_page_properties, CreateDocsPageRequest, MoveDocsPageRequest, DocsPageDatabaseError};
+use crate::member::{
+    add_area_member, remove_area_member, update_area_member_permissions, AddAreaMemberRequest,
+    DocsAreaMemberDatabaseError,
+};
+use crate::page::{clone_page, create_page, list_pages, list_page_versions, load_page_with_version, move_page, reorder_pages, save_page_content, update_page_properties, CreateDocsPageRequest, MoveDocsPageRequest, ReorderDocsPagesRequest, DocsPageDatabaseError};
+use crate::stats::docs_stats;
 use crate::tree::{get_area_tree, DocsTreeError};
+use crate::tree_cache::AreaTreeCache;
+use crate::views::{list_recent_pages, record_page_view};
 use flextide_core::user::{user_belongs_to_organization, user_has_permission};
 
 /// Create the API router for Docs endpoints
@@ -42,6 +52,13 @@ where
                 .put(update_area_endpoint)
                 .delete(delete_area_endpoint),
         )
+        .route("/modules/docs/areas/{uuid}/archive", post(archive_area_endpoint))
+        .route("/modules/docs/areas/{uuid}/unarchive", post(unarchive_area_endpoint))
+        .route("/modules/docs/areas/{uuid}/members", post(add_area_member_endpoint))
+        .route(
+            "/modules/docs/areas/{uuid}/members/{user_uuid}",
+            put(update_area_member_endpoint).delete(remove_area_member_endpoint),
+        )
         .route("/modules/docs/areas/{area_uuid}/folders", get(list_folders_endpoint))
         .route("/modules/docs/areas/{area_uuid}/folders", post(create_folder_endpoint))
         .route("/modules/docs/areas/{area_uuid}/pages", get(list_pages_endpoint))
@@ -58,12 +75,21 @@ where
             "/modules/docs/folders/{uuid}/move",
             put(move_folder_endpoint),
         )
+        .route(
+            "/modules/docs/folders/{uuid}/pages/order",
+            put(reorder_pages_endpoint),
+        )
         .route("/modules/docs/activity", get(list_activity_endpoint))
+        .route("/modules/docs/stats", get(docs_stats_endpoint))
         .route("/modules/docs/areas/{area_uuid}/tree", get(get_area_tree_endpoint))
+        .route("/modules/docs/areas/{area_uuid}/activity", get(list_area_activity_endpoint))
+        .route("/modules/docs/pages/recent", get(list_recent_pages_endpoint))
         .route("/modules/docs/pages/{uuid}", get(get_page_endpoint))
         .route("/modules/docs/pages/{uuid}/content", put(update_page_content_endpoint))
         .route("/modules/docs/pages/{uuid}/properties", put(update_page_properties_endpoint))
         .route("/modules/docs/pages/{uuid}/versions", get(list_page_versions_endpoint))
+        .route("/modules/docs/pages/{uuid}/clone", post(clone_page_endpoint))
+        .route("/modules/docs/pages/{uuid}/export/pdf", get(export_page_pdf_endpoint))
         .route(
             "/modules/docs/pages/{uuid}/move",
             put(move_page_endpoint),
@@ -81,13 +107,21 @@ async fn list_documents(
     })))
 }
 
+/// Query parameters for endpoints that hide archived areas by default
+#[derive(Debug, Deserialize)]
+pub(crate) struct IncludeArchivedQuery {
+    #[serde(default)]
+    include_archived: bool,
+}
+
 /// List all accessible areas for the current user
 ///
-/// GET /api/modules/docs/areas
+/// GET /api/modules/docs/areas?include_archived={bool}
 pub async fn list_areas_endpoint(
     Extension(pool): Extension<DatabasePool>,
     Extension(org_uuid): Extension<String>,
     Extension(claims): Extension<Claims>,
+    Query(query): Query<IncludeArchivedQuery>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
     // Check if user belongs to organization
     let belongs = user_belongs_to_organization(&pool, &claims.user_uuid, &org_uuid)
@@ -108,7 +142,7 @@ pub async fn list_areas_endpoint(
     }
 
     // List accessible areas
-    let areas = list_accessible_areas(&pool, &org_uuid, &claims.user_uuid)
+    let areas = list_accessible_areas(&pool, &org_uuid, &claims.user_uuid, query.include_archived)
         .await
         .map_err(|e| {
             tracing::error!("Database error listing areas: {}", e);
@@ -174,6 +208,95 @@ pub async fn list_activity_endpoint(
     })))
 }
 
+/// Query parameters for listing the activity of an area
+#[derive(Debug, Deserialize)]
+pub(crate) struct ListAreaActivityQuery {
+    #[serde(default = "default_area_activity_limit")]
+    limit: i64,
+}
+
+fn default_area_activity_limit() -> i64 {
+    20
+}
+
+/// List recent page changes of an area, newest first
+///
+/// GET /api/modules/docs/areas/{area_uuid}/activity?limit={n}
+pub async fn list_area_activity_endpoint(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Path(area_uuid): Path<String>,
+    Query(query): Query<ListAreaActivityQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Visibility checks are done inside list_area_activity
+    let activities = list_area_activity(&pool, &org_uuid, &claims.user_uuid, &area_uuid, query.limit)
+        .await
+        .map_err(|e| {
+            tracing::error!("Error listing area activity: {}", e);
+            match e {
+                DocsActivityDatabaseError::UserNotInOrganization => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "User does not belong to this organization" })),
+                ),
+                DocsActivityDatabaseError::AreaNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Area not found" })),
+                ),
+                DocsActivityDatabaseError::AreaNotInOrganization => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "Area does not belong to this organization" })),
+                ),
+                _ => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to list area activity" })),
+                ),
+            }
+        })?;
+
+    Ok(Json(json!({
+        "activities": activities
+    })))
+}
+
+/// Get documentation statistics of the organization
+///
+/// GET /api/modules/docs/stats
+///
+/// Requires the `module_docs_can_view_stats` permission.
+pub async fn docs_stats_endpoint(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    let can_view_stats = user_has_permission(&pool, &claims.user_uuid, &org_uuid, "module_docs_can_view_stats")
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking permission: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Database error" })),
+            )
+        })?;
+
+    if !can_view_stats {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not have permission to view documentation statistics" })),
+        ));
+    }
+
+    let stats = docs_stats(&pool, &org_uuid).await.map_err(|e| {
+        tracing::error!("Error computing docs statistics: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": "Failed to compute documentation statistics" })),
+        )
+    })?;
+
+    Ok(Json(stats))
+}
+
 /// Query parameters for listing pages
 #[derive(Debug, Deserialize)]
 pub(crate) struct ListPagesQuery {
@@ -514,6 +637,227 @@ pub async fn delete_area_endpoint(
     })))
 }
 
+/// Archive an area
+///
+/// POST /api/modules/docs/areas/{uuid}/archive
+pub async fn archive_area_endpoint(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Extension(dispatcher): Extension<EventDispatcher>,
+    Path(area_uuid): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Permission checks are done inside archive_area
+    archive_area(&pool, &area_uuid, &org_uuid, &claims.user_uuid, Some(&dispatcher))
+        .await
+        .map_err(|e| {
+            tracing::error!("Error archiving area: {}", e);
+            area_archive_error_response(e, "Failed to archive area")
+        })?;
+
+    Ok(Json(json!({
+        "message": "Area archived successfully"
+    })))
+}
+
+/// Unarchive an area
+///
+/// POST /api/modules/docs/areas/{uuid}/unarchive
+pub async fn unarchive_area_endpoint(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Extension(dispatcher): Extension<EventDispatcher>,
+    Path(area_uuid): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Permission checks are done inside unarchive_area
+    unarchive_area(&pool, &area_uuid, &org_uuid, &claims.user_uuid, Some(&dispatcher))
+        .await
+        .map_err(|e| {
+            tracing::error!("Error unarchiving area: {}", e);
+            area_archive_error_response(e, "Failed to unarchive area")
+        })?;
+
+    Ok(Json(json!({
+        "message": "Area unarchived successfully"
+    })))
+}
+
+/// Map errors of archiving/unarchiving an area to an HTTP response
+fn area_archive_error_response(
+    error: DocsAreaDatabaseError,
+    fallback_message: &str,
+) -> (StatusCode, Json<JsonValue>) {
+    match error {
+        DocsAreaDatabaseError::UserNotInOrganization => (
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not belong to this organization" })),
+        ),
+        DocsAreaDatabaseError::PermissionDenied => (
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not have permission to archive this area" })),
+        ),
+        DocsAreaDatabaseError::AreaNotFound => (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "error": "Area not found" })),
+        ),
+        DocsAreaDatabaseError::AreaNotInOrganization => (
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "Area does not belong to this organization" })),
+        ),
+        _ => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": fallback_message })),
+        ),
+    }
+}
+
+/// Add a member to an area
+///
+/// POST /api/modules/docs/areas/{uuid}/members
+pub async fn add_area_member_endpoint(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Extension(dispatcher): Extension<EventDispatcher>,
+    Path(area_uuid): Path<String>,
+    Json(request): Json<AddAreaMemberRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Permission checks are done inside add_area_member
+    add_area_member(
+        &pool,
+        &area_uuid,
+        &org_uuid,
+        &claims.user_uuid,
+        &request.user_uuid,
+        &request.permissions,
+        Some(&dispatcher),
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Error adding area member: {}", e);
+        area_member_error_response(e, "Failed to add area member")
+    })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(json!({
+            "message": "Area member added successfully"
+        })),
+    ))
+}
+
+/// Update the role and permissions of an area member
+///
+/// PUT /api/modules/docs/areas/{uuid}/members/{user_uuid}
+pub async fn update_area_member_endpoint(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Extension(dispatcher): Extension<EventDispatcher>,
+    Path((area_uuid, member_uuid)): Path<(String, String)>,
+    Json(request): Json<AreaMemberPermissions>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Permission checks are done inside update_area_member_permissions
+    update_area_member_permissions(
+        &pool,
+        &area_uuid,
+        &org_uuid,
+        &claims.user_uuid,
+        &member_uuid,
+        &request,
+        Some(&dispatcher),
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Error updating area member: {}", e);
+        area_member_error_response(e, "Failed to update area member")
+    })?;
+
+    Ok(Json(json!({
+        "message": "Area member updated successfully"
+    })))
+}
+
+/// Remove a member from an area
+///
+/// DELETE /api/modules/docs/areas/{uuid}/members/{user_uuid}
+pub async fn remove_area_member_endpoint(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Extension(dispatcher): Extension<EventDispatcher>,
+    Path((area_uuid, member_uuid)): Path<(String, String)>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Permission checks are done inside remove_area_member
+    remove_area_member(
+        &pool,
+        &area_uuid,
+        &org_uuid,
+        &claims.user_uuid,
+        &member_uuid,
+        Some(&dispatcher),
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Error removing area member: {}", e);
+        area_member_error_response(e, "Failed to remove area member")
+    })?;
+
+    Ok(Json(json!({
+        "message": "Area member removed successfully"
+    })))
+}
+
+/// Map errors of managing area members to an HTTP response
+fn area_member_error_response(
+    error: DocsAreaMemberDatabaseError,
+    fallback_message: &str,
+) -> (StatusCode, Json<JsonValue>) {
+    match error {
+        DocsAreaMemberDatabaseError::UserNotInOrganization => (
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not belong to this organization" })),
+        ),
+        DocsAreaMemberDatabaseError::PermissionDenied => (
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "User does not have permission to manage members of this area" })),
+        ),
+        DocsAreaMemberDatabaseError::AreaNotFound => (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "error": "Area not found" })),
+        ),
+        DocsAreaMemberDatabaseError::AreaNotInOrganization => (
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "Area does not belong to this organization" })),
+        ),
+        DocsAreaMemberDatabaseError::MemberNotInOrganization => (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": "Member does not belong to this organization" })),
+        ),
+        DocsAreaMemberDatabaseError::MemberAlreadyExists => (
+            StatusCode::CONFLICT,
+            Json(json!({ "error": "User is already a member of this area" })),
+        ),
+        DocsAreaMemberDatabaseError::MemberNotFound => (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "error": "User is not a member of this area" })),
+        ),
+        DocsAreaMemberDatabaseError::InvalidRole(role) => (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": format!("Invalid area member role: {}", role) })),
+        ),
+        DocsAreaMemberDatabaseError::CannotModifyOwner => (
+            StatusCode::FORBIDDEN,
+            Json(json!({ "error": "The area owner cannot be modified or removed" })),
+        ),
+        _ => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": fallback_message })),
+        ),
+    }
+}
+
 /// Query parameters for listing folders
 #[derive(Debug, Deserialize)]
 pub(crate) struct ListFoldersQuery {
@@ -651,6 +995,10 @@ pub async fn create_folder_endpoint(
                     StatusCode::FORBIDDEN,
                     Json(json!({ "error": "Parent folder does not belong to this organization" })),
                 ),
+                DocsFolderDatabaseError::DuplicateFolderName => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "A folder with this name already exists in the same parent" })),
+                ),
                 _ => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "error": "Failed to create folder" })),
@@ -770,6 +1118,10 @@ pub async fn update_folder_name_endpoint(
                     StatusCode::BAD_REQUEST,
                     Json(json!({ "error": "Name cannot be empty" })),
                 ),
+                DocsFolderDatabaseError::DuplicateFolderName => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "A folder with this name already exists in the same parent" })),
+                ),
                 _ => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "error": "Failed to update folder name" })),
@@ -908,6 +1260,10 @@ pub async fn update_folder_endpoint(
                     StatusCode::BAD_REQUEST,
                     Json(json!({ "error": "Name cannot be empty" })),
                 ),
+                DocsFolderDatabaseError::DuplicateFolderName => (
+                    StatusCode::CONFLICT,
+                    Json(json!({ "error": "A folder with this name already exists in the same parent" })),
+                ),
                 _ => (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(json!({ "error": "Failed to update folder" })),
@@ -1058,6 +1414,59 @@ pub async fn reorder_folder_endpoint(
     })))
 }
 
+/// Reorder the pages of a folder
+///
+/// PUT /api/modules/docs/folders/{uuid}/pages/order
+pub async fn reorder_pages_endpoint(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Extension(dispatcher): Extension<EventDispatcher>,
+    Path(folder_uuid): Path<String>,
+    Json(request): Json<ReorderDocsPagesRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Reorder pages (permission checks are done inside reorder_pages)
+    let order = reorder_pages(
+        &pool,
+        &org_uuid,
+        &claims.user_uuid,
+        &folder_uuid,
+        &request.page_uuids,
+        Some(&dispatcher),
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Error reordering pages: {}", e);
+        match e {
+            DocsPageDatabaseError::UserNotInOrganization => (
+                StatusCode::FORBIDDEN,
+                Json(json!({ "error": "User does not belong to this organization" })),
+            ),
+            DocsPageDatabaseError::PermissionDenied => (
+                StatusCode::FORBIDDEN,
+                Json(json!({ "error": "User does not have permission to reorder these pages" })),
+            ),
+            DocsPageDatabaseError::FolderNotFound => (
+                StatusCode::NOT_FOUND,
+                Json(json!({ "error": "Folder not found" })),
+            ),
+            DocsPageDatabaseError::PageNotInFolder(_) | DocsPageDatabaseError::DuplicatePageInOrder(_) => (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": e.to_string() })),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to reorder pages" })),
+            ),
+        }
+    })?;
+
+    Ok(Json(json!({
+        "message": "Pages reordered successfully",
+        "page_uuids": order
+    })))
+}
+
 /// Move a folder to a different parent and/or position
 ///
 /// PUT /api/modules/docs/folders/{uuid}/move
@@ -1103,6 +1512,10 @@ pub async fn move_folder_endpoint(
                 StatusCode::FORBIDDEN,
                 Json(json!({ "error": "Area does not belong to this organization" })),
             ),
+            DocsFolderDatabaseError::DuplicateFolderName => (
+                StatusCode::CONFLICT,
+                Json(json!({ "error": "A folder with this name already exists in the same parent" })),
+            ),
             _ => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(json!({ "error": "Failed to move folder" })),
@@ -1117,12 +1530,14 @@ pub async fn move_folder_endpoint(
 
 /// Get the folder tree structure for an area (with nested folders and pages)
 ///
-/// GET /api/modules/docs/areas/{area_uuid}/tree
+/// GET /api/modules/docs/areas/{area_uuid}/tree?include_archived={bool}
 pub async fn get_area_tree_endpoint(
     Extension(pool): Extension<DatabasePool>,
+    Extension(dispatcher): Extension<EventDispatcher>,
     Extension(org_uuid): Extension<String>,
     Extension(claims): Extension<Claims>,
     Path(area_uuid): Path<String>,
+    Query(query): Query<IncludeArchivedQuery>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
     // Check if user belongs to organization
     let belongs = user_belongs_to_organization(&pool, &claims.user_uuid, &org_uuid)
@@ -1217,12 +1632,32 @@ pub async fn get_area_tree_endpoint(
         ));
     }
 
-    // Get the tree structure
-    let tree = get_area_tree(&pool, &org_uuid, &area_uuid)
-        .await
+    // Get the tree structure, cached trees are only safe if mutations invalidate them
+    let tree = if AreaTreeCache::is_subscribed(&dispatcher) {
+        AreaTreeCache::global()
+            .get_or_build(&pool, &org_uuid, &area_uuid, query.include_archived)
+            .await
+    } else {
+        get_area_tree(&pool, &org_uuid, &area_uuid, query.include_archived)
+            .await
+            .and_then(|tree| serde_json::to_string(&tree).map_err(DocsTreeError::from))
+    };
+    let tree = tree
         .map_err(|e| {
             tracing::error!("Error building area tree: {}", e);
             match e {
+                DocsTreeError::AreaArchived => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Area is archived" })),
+                ),
+                DocsTreeError::AreaError(DocsAreaDatabaseError::AreaNotFound) => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Area not found" })),
+                ),
+                DocsTreeError::AreaError(_) => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to load area" })),
+                ),
                 DocsTreeError::FolderError(folder_err) => match folder_err {
                     DocsFolderDatabaseError::Database(_) | DocsFolderDatabaseError::Sql(_) => (
                         StatusCode::INTERNAL_SERVER_ERROR,
@@ -1243,12 +1678,17 @@ pub async fn get_area_tree_endpoint(
                         Json(json!({ "error": "Failed to load pages" })),
                     ),
                 },
+                DocsTreeError::Serialization(_) => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to serialize tree" })),
+                ),
             }
         })?;
 
-    Ok(Json(json!({
-        "tree": tree
-    })))
+    Ok((
+        [(header::CONTENT_TYPE, "application/json")],
+        format!("{{\"tree\":{}}}", tree),
+    ))
 }
 
 /// Create a new page
@@ -1316,6 +1756,18 @@ pub async fn create_page_endpoint(
                 StatusCode::BAD_REQUEST,
                 Json(json!({ "error": "Title cannot be empty" })),
             ),
+            DocsPageDatabaseError::EmptyAreaShortName => (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": e.to_string() })),
+            ),
+            DocsPageDatabaseError::EmptyPageTemplate => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(json!({ "error": e.to_string() })),
+            ),
+            DocsPageDatabaseError::InvalidPageContent(_) => (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": e.to_string() })),
+            ),
             _ => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(json!({ "error": "Failed to create page" })),
@@ -1385,11 +1837,177 @@ pub async fn get_page_endpoint(
         ));
     }
 
+    // A failed view record must not prevent loading the page
+    if let Err(e) = record_page_view(&pool, &org_uuid, &claims.user_uuid, &page_uuid).await {
+        tracing::warn!("Failed to record view of page {}: {}", page_uuid, e);
+    }
+
     Ok(Json(json!({
         "page": page
     })))
 }
 
+/// Query parameters for listing recently viewed pages
+#[derive(Debug, Deserialize)]
+pub(crate) struct ListRecentPagesQuery {
+    #[serde(default = "default_recent_pages_limit")]
+    limit: i64,
+}
+
+fn default_recent_pages_limit() -> i64 {
+    10
+}
+
+/// List the pages the current user viewed most recently, newest first
+///
+/// GET /api/modules/docs/pages/recent?limit={n}
+pub async fn list_recent_pages_endpoint(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<ListRecentPagesQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Visibility checks are done inside list_recent_pages
+    let pages = list_recent_pages(&pool, &org_uuid, &claims.user_uuid, query.limit)
+        .await
+        .map_err(|e| {
+            tracing::error!("Error listing recent pages: {}", e);
+            match e {
+                DocsPageDatabaseError::UserNotInOrganization => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "User does not belong to this organization" })),
+                ),
+                _ => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to list recent pages" })),
+                ),
+            }
+        })?;
+
+    Ok(Json(json!({
+        "pages": pages
+    })))
+}
+
+/// Request structure for cloning a page
+#[derive(Debug, Deserialize)]
+pub struct ClonePageRequest {
+    pub title: String,
+}
+
+/// Create a copy of a page with a new title
+///
+/// POST /api/modules/docs/pages/{uuid}/clone
+pub async fn clone_page_endpoint(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Extension(dispatcher): Extension<EventDispatcher>,
+    Path(page_uuid): Path<String>,
+    Json(request): Json<ClonePageRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    if request.title.len() > 255 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": "Title cannot exceed 255 characters" })),
+        ));
+    }
+
+    let new_page_uuid = clone_page(
+        &pool,
+        &org_uuid,
+        &claims.user_uuid,
+        &page_uuid,
+        &request.title,
+        &dispatcher,
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Error cloning page: {}", e);
+        match e {
+            DocsPageDatabaseError::UserNotInOrganization => (
+                StatusCode::FORBIDDEN,
+                Json(json!({ "error": "User does not belong to this organization" })),
+            ),
+            DocsPageDatabaseError::PermissionDenied => (
+                StatusCode::FORBIDDEN,
+                Json(json!({ "error": "User does not have permission to create pages" })),
+            ),
+            DocsPageDatabaseError::PageNotFound => (
+                StatusCode::NOT_FOUND,
+                Json(json!({ "error": "Page not found" })),
+            ),
+            DocsPageDatabaseError::PageNotInOrganization => (
+                StatusCode::FORBIDDEN,
+                Json(json!({ "error": "Page does not belong to this organization" })),
+            ),
+            DocsPageDatabaseError::EmptyTitle => (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": "Title cannot be empty" })),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "error": "Failed to clone page" })),
+            ),
+        }
+    })?;
+
+    Ok(Json(json!({
+        "uuid": new_page_uuid,
+        "message": "Page cloned successfully"
+    })))
+}
+
+/// Export a page as a PDF document
+///
+/// GET /api/modules/docs/pages/{uuid}/export/pdf
+pub async fn export_page_pdf_endpoint(
+    Extension(pool): Extension<DatabasePool>,
+    Extension(org_uuid): Extension<String>,
+    Extension(claims): Extension<Claims>,
+    Path(page_uuid): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, Json<JsonValue>)> {
+    // Permission checks are done inside export_page_pdf
+    let document = export_page_pdf(&pool, &org_uuid, &claims.user_uuid, &page_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Error exporting page as PDF: {}", e);
+            match e {
+                DocsExportError::UserNotInOrganization => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "User does not belong to this organization" })),
+                ),
+                DocsExportError::PermissionDenied => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "You do not have permission to view this page" })),
+                ),
+                DocsExportError::PageNotFound => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "error": "Page not found" })),
+                ),
+                DocsExportError::PageNotInOrganization => (
+                    StatusCode::FORBIDDEN,
+                    Json(json!({ "error": "Page does not belong to this organization" })),
+                ),
+                _ => (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(json!({ "error": "Failed to export page" })),
+                ),
+            }
+        })?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/pdf".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}.pdf\"", page_uuid),
+            ),
+        ],
+        document,
+    ))
+}
+
 /// Update page content (creates new version if content changed)
 ///
 /// PUT /api/modules/docs/pages/{uuid}/content
@@ -1448,6 +2066,14 @@ pub async fn update_page_content_endpoint(
                 StatusCode::FORBIDDEN,
                 Json(json!({ "error": "Page does not belong to this organization" })),
             ),
+            DocsPageDatabaseError::ContentTooLarge { .. } => (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                Json(json!({ "error": e.to_string() })),
+            ),
+            DocsPageDatabaseError::InvalidPageContent(_) => (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": e.to_string() })),
+            ),
             _ => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(json!({ "error": "Failed to save page content" })),
@@ -51,6 +51,8 @@ pub struct DocsArea {
     pub deletable: bool,
     pub creator_uuid: String,
     pub created_at: DateTime<Utc>,
+    /// Set while the area is archived; archived areas are hidden by default
+    pub archived_at: Option<DateTime<Utc>>,
 }
 
 /// Request structure for creating a new area
@@ -80,7 +82,8 @@ pub struct UpdateDocsAreaRequest {
 }
 
 /// Area member permissions structure
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AreaMemberPermissions {
     pub role: String,
     pub can_view: bool,
@@ -227,7 +230,7 @@ pub async fn load_area_by_uuid(
         DatabasePool::MySql(p) => {
             let row = sqlx::query(
                 "SELECT uuid, organization_uuid, short_name, description, icon_name,
-                 color_hex, topics, public, visible, deletable, creator_uuid, created_at
+                 color_hex, topics, public, visible, deletable, creator_uuid, created_at, archived_at
                  FROM module_docs_areas WHERE uuid = ?",
             )
             .bind(area_uuid)
@@ -248,6 +251,7 @@ pub async fn load_area_by_uuid(
                     deletable: row.get::<i64, _>("deletable") != 0,
                     creator_uuid: row.get("creator_uuid"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 }),
                 None => Err(DocsAreaDatabaseError::AreaNotFound),
             }
@@ -255,7 +259,7 @@ pub async fn load_area_by_uuid(
         DatabasePool::Postgres(p) => {
             let row = sqlx::query(
                 "SELECT uuid, organization_uuid, short_name, description, icon_name,
-                 color_hex, topics, public, visible, deletable, creator_uuid, created_at
+                 color_hex, topics, public, visible, deletable, creator_uuid, created_at, archived_at
                  FROM module_docs_areas WHERE uuid = $1",
             )
             .bind(area_uuid)
@@ -276,6 +280,7 @@ pub async fn load_area_by_uuid(
                     deletable: row.get::<i32, _>("deletable") != 0,
                     creator_uuid: row.get("creator_uuid"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 }),
                 None => Err(DocsAreaDatabaseError::AreaNotFound),
             }
@@ -283,7 +288,7 @@ pub async fn load_area_by_uuid(
         DatabasePool::Sqlite(p) => {
             let row = sqlx::query(
                 "SELECT uuid, organization_uuid, short_name, description, icon_name,
-                 color_hex, topics, public, visible, deletable, creator_uuid, created_at
+                 color_hex, topics, public, visible, deletable, creator_uuid, created_at, archived_at
                  FROM module_docs_areas WHERE uuid = ?1",
             )
             .bind(area_uuid)
@@ -304,6 +309,7 @@ pub async fn load_area_by_uuid(
                     deletable: row.get::<i64, _>("deletable") != 0,
                     creator_uuid: row.get("creator_uuid"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                 }),
                 None => Err(DocsAreaDatabaseError::AreaNotFound),
             }
@@ -1080,6 +1086,188 @@ pub async fn delete_area(
     Ok(())
 }
 
+/// Archive an area
+///
+/// Archived areas keep their `visible`/`deletable` flags and content, but are hidden
+/// from area listings and trees unless archived areas are explicitly requested.
+/// Archiving an already archived area is a no-op.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `area_uuid` - UUID of the area to archive
+/// * `organization_uuid` - UUID of the organization (for verification)
+/// * `user_uuid` - UUID of the user archiving the area
+/// * `dispatcher` - Optional event dispatcher
+///
+/// # Errors
+/// Returns `DocsAreaDatabaseError` if:
+/// - User does not belong to the organization
+/// - Area does not belong to the organization
+/// - User does not have permission to archive the area
+/// - Database operation fails
+pub async fn archive_area(
+    pool: &DatabasePool,
+    area_uuid: &str,
+    organization_uuid: &str,
+    user_uuid: &str,
+    dispatcher: Option<&EventDispatcher>,
+) -> Result<(), DocsAreaDatabaseError> {
+    set_area_archived(pool, area_uuid, organization_uuid, user_uuid, true, dispatcher).await
+}
+
+/// Unarchive an area
+///
+/// Unarchiving an area that is not archived is a no-op.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `area_uuid` - UUID of the area to unarchive
+/// * `organization_uuid` - UUID of the organization (for verification)
+/// * `user_uuid` - UUID of the user unarchiving the area
+/// * `dispatcher` - Optional event dispatcher
+///
+/// # Errors
+/// Returns `DocsAreaDatabaseError` if:
+/// - User does not belong to the organization
+/// - Area does not belong to the organization
+/// - User does not have permission to archive the area
+/// - Database operation fails
+pub async fn unarchive_area(
+    pool: &DatabasePool,
+    area_uuid: &str,
+    organization_uuid: &str,
+    user_uuid: &str,
+    dispatcher: Option<&EventDispatcher>,
+) -> Result<(), DocsAreaDatabaseError> {
+    set_area_archived(pool, area_uuid, organization_uuid, user_uuid, false, dispatcher).await
+}
+
+/// Set or clear `archived_at` of an area and emit the matching event on a transition
+async fn set_area_archived(
+    pool: &DatabasePool,
+    area_uuid: &str,
+    organization_uuid: &str,
+    user_uuid: &str,
+    archived: bool,
+    dispatcher: Option<&EventDispatcher>,
+) -> Result<(), DocsAreaDatabaseError> {
+    // Check if user belongs to organization
+    let belongs = user_belongs_to_organization(pool, user_uuid, organization_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            DocsAreaDatabaseError::Database(e.into())
+        })?;
+
+    if !belongs {
+        return Err(DocsAreaDatabaseError::UserNotInOrganization);
+    }
+
+    // Load area to verify it belongs to the organization
+    let area = load_area_by_uuid(pool, area_uuid).await?;
+
+    if area.organization_uuid != organization_uuid {
+        return Err(DocsAreaDatabaseError::AreaNotInOrganization);
+    }
+
+    // Check permission: can_archive_areas or (can_archive_own_areas and user is creator)
+    let has_archive_all = user_has_permission(
+        pool,
+        user_uuid,
+        organization_uuid,
+        "module_docs_can_archive_areas",
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error checking permission: {}", e);
+        DocsAreaDatabaseError::Database(e.into())
+    })?;
+
+    let has_archive_own = user_has_permission(
+        pool,
+        user_uuid,
+        organization_uuid,
+        "module_docs_can_archive_own_areas",
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error checking permission: {}", e);
+        DocsAreaDatabaseError::Database(e.into())
+    })?;
+
+    let is_creator = area.creator_uuid == user_uuid;
+    let has_archive_permission = has_archive_all || (has_archive_own && is_creator);
+
+    if !has_archive_permission {
+        // Also check area member permissions
+        let member_perms = load_area_member_permissions(pool, area_uuid, user_uuid).await?;
+        match member_perms {
+            Some(perms) if perms.admin || perms.role == "owner" => {}
+            _ => return Err(DocsAreaDatabaseError::PermissionDenied),
+        }
+    }
+
+    if area.archived_at.is_some() == archived {
+        // Nothing to do, the area is already in the requested state
+        return Ok(());
+    }
+
+    let archived_at = if archived { Some(Utc::now()) } else { None };
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query("UPDATE module_docs_areas SET archived_at = ? WHERE uuid = ?")
+                .bind(archived_at)
+                .bind(area_uuid)
+                .execute(p)
+                .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query("UPDATE module_docs_areas SET archived_at = $1 WHERE uuid = $2")
+                .bind(archived_at)
+                .bind(area_uuid)
+                .execute(p)
+                .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query("UPDATE module_docs_areas SET archived_at = ?1 WHERE uuid = ?2")
+                .bind(archived_at)
+                .bind(area_uuid)
+                .execute(p)
+                .await?;
+        }
+    }
+
+    // Emit area archived/unarchived event
+    if let Some(disp) = dispatcher {
+        let event_name = if archived {
+            "module_docs_area_archived"
+        } else {
+            "module_docs_area_unarchived"
+        };
+        let event = Event::new(
+            event_name,
+            EventPayload::new(json!({
+                "entity_type": "area",
+                "entity_id": area_uuid,
+                "organization_uuid": organization_uuid,
+                "data": json!({
+                    "short_name": area.short_name,
+                    "visible": area.visible,
+                    "deletable": area.deletable,
+                    "archived_at": archived_at
+                })
+            }))
+        )
+        .with_organization(organization_uuid)
+        .with_user(user_uuid);
+
+        disp.emit(event).await;
+    }
+
+    Ok(())
+}
+
 /// Area with membership information for listing
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocsAreaWithMembership {
@@ -1095,6 +1283,7 @@ pub struct DocsAreaWithMembership {
 /// * `pool` - Database connection pool
 /// * `organization_uuid` - UUID of the organization
 /// * `user_uuid` - UUID of the user
+/// * `include_archived` - Whether archived areas are included
 ///
 /// # Returns
 /// Returns a list of areas the user can access. If the user has super_admin permission,
@@ -1110,7 +1299,10 @@ pub async fn list_accessible_areas(
     pool: &DatabasePool,
     organization_uuid: &str,
     user_uuid: &str,
+    include_archived: bool,
 ) -> Result<Vec<DocsAreaWithMembership>, DocsAreaDatabaseError> {
+    let include_archived = if include_archived { 1 } else { 0 };
+
     // Check if user has super_admin permission
     let has_super_admin = user_has_permission(
         pool,
@@ -1130,12 +1322,14 @@ pub async fn list_accessible_areas(
             DatabasePool::MySql(p) => {
                 let rows = sqlx::query(
                     "SELECT uuid, organization_uuid, short_name, description, icon_name,
-                     color_hex, topics, public, visible, deletable, creator_uuid, created_at
+                     color_hex, topics, public, visible, deletable, creator_uuid, created_at, archived_at
                      FROM module_docs_areas
                      WHERE organization_uuid = ? AND visible = 1
+                     AND (archived_at IS NULL OR ? = 1)
                      ORDER BY created_at DESC",
                 )
                 .bind(organization_uuid)
+                .bind(include_archived)
                 .fetch_all(p)
                 .await?;
 
@@ -1156,6 +1350,7 @@ pub async fn list_accessible_areas(
                                 deletable: row.get::<i64, _>("deletable") != 0,
                                 creator_uuid: row.get("creator_uuid"),
                                 created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                                archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                             },
                             area_uuid,
                         )
@@ -1165,12 +1360,14 @@ pub async fn list_accessible_areas(
             DatabasePool::Postgres(p) => {
                 let rows = sqlx::query(
                     "SELECT uuid, organization_uuid, short_name, description, icon_name,
-                     color_hex, topics, public, visible, deletable, creator_uuid, created_at
+                     color_hex, topics, public, visible, deletable, creator_uuid, created_at, archived_at
                      FROM module_docs_areas
                      WHERE organization_uuid = $1 AND visible = 1
+                     AND (archived_at IS NULL OR $2 = 1)
                      ORDER BY created_at DESC",
                 )
                 .bind(organization_uuid)
+                .bind(include_archived)
                 .fetch_all(p)
                 .await?;
 
@@ -1191,6 +1388,7 @@ pub async fn list_accessible_areas(
                                 deletable: row.get::<i32, _>("deletable") != 0,
                                 creator_uuid: row.get("creator_uuid"),
                                 created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                                archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                             },
                             area_uuid,
                         )
@@ -1200,12 +1398,14 @@ pub async fn list_accessible_areas(
             DatabasePool::Sqlite(p) => {
                 let rows = sqlx::query(
                     "SELECT uuid, organization_uuid, short_name, description, icon_name,
-                     color_hex, topics, public, visible, deletable, creator_uuid, created_at
+                     color_hex, topics, public, visible, deletable, creator_uuid, created_at, archived_at
                      FROM module_docs_areas
                      WHERE organization_uuid = ?1 AND visible = 1
+                     AND (archived_at IS NULL OR ?2 = 1)
                      ORDER BY created_at DESC",
                 )
                 .bind(organization_uuid)
+                .bind(include_archived)
                 .fetch_all(p)
                 .await?;
 
@@ -1226,6 +1426,7 @@ pub async fn list_accessible_areas(
                                 deletable: row.get::<i64, _>("deletable") != 0,
                                 creator_uuid: row.get("creator_uuid"),
                                 created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                                archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                             },
                             area_uuid,
                         )
@@ -1239,15 +1440,17 @@ pub async fn list_accessible_areas(
             DatabasePool::MySql(p) => {
                 let rows = sqlx::query(
                     "SELECT DISTINCT a.uuid, a.organization_uuid, a.short_name, a.description, a.icon_name,
-                     a.color_hex, a.topics, a.public, a.visible, a.deletable, a.creator_uuid, a.created_at
+                     a.color_hex, a.topics, a.public, a.visible, a.deletable, a.creator_uuid, a.created_at, a.archived_at
                      FROM module_docs_areas a
                      LEFT JOIN module_docs_area_members m ON a.uuid = m.area_uuid AND m.user_uuid = ?
                      WHERE a.organization_uuid = ? AND a.visible = 1
+                     AND (a.archived_at IS NULL OR ? = 1)
                      AND (a.public = 1 OR m.user_uuid IS NOT NULL)
                      ORDER BY a.created_at DESC",
                 )
                 .bind(user_uuid)
                 .bind(organization_uuid)
+                .bind(include_archived)
                 .fetch_all(p)
                 .await?;
 
@@ -1268,6 +1471,7 @@ pub async fn list_accessible_areas(
                                 deletable: row.get::<i64, _>("deletable") != 0,
                                 creator_uuid: row.get("creator_uuid"),
                                 created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                                archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                             },
                             area_uuid,
                         )
@@ -1277,15 +1481,17 @@ pub async fn list_accessible_areas(
             DatabasePool::Postgres(p) => {
                 let rows = sqlx::query(
                     "SELECT DISTINCT a.uuid, a.organization_uuid, a.short_name, a.description, a.icon_name,
-                     a.color_hex, a.topics, a.public, a.visible, a.deletable, a.creator_uuid, a.created_at
+                     a.color_hex, a.topics, a.public, a.visible, a.deletable, a.creator_uuid, a.created_at, a.archived_at
                      FROM module_docs_areas a
                      LEFT JOIN module_docs_area_members m ON a.uuid = m.area_uuid AND m.user_uuid = $1
                      WHERE a.organization_uuid = $2 AND a.visible = 1
+                     AND (a.archived_at IS NULL OR $3 = 1)
                      AND (a.public = 1 OR m.user_uuid IS NOT NULL)
                      ORDER BY a.created_at DESC",
                 )
                 .bind(user_uuid)
                 .bind(organization_uuid)
+                .bind(include_archived)
                 .fetch_all(p)
                 .await?;
 
@@ -1306,6 +1512,7 @@ pub async fn list_accessible_areas(
                                 deletable: row.get::<i32, _>("deletable") != 0,
                                 creator_uuid: row.get("creator_uuid"),
                                 created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                                archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                             },
                             area_uuid,
                         )
@@ -1315,15 +1522,17 @@ pub async fn list_accessible_areas(
             DatabasePool::Sqlite(p) => {
                 let rows = sqlx::query(
                     "SELECT DISTINCT a.uuid, a.organization_uuid, a.short_name, a.description, a.icon_name,
-                     a.color_hex, a.topics, a.public, a.visible, a.deletable, a.creator_uuid, a.created_at
+                     a.color_hex, a.topics, a.public, a.visible, a.deletable, a.creator_uuid, a.created_at, a.archived_at
                      FROM module_docs_areas a
                      LEFT JOIN module_docs_area_members m ON a.uuid = m.area_uuid AND m.user_uuid = ?1
                      WHERE a.organization_uuid = ?2 AND a.visible = 1
+                     AND (a.archived_at IS NULL OR ?3 = 1)
                      AND (a.public = 1 OR m.user_uuid IS NOT NULL)
                      ORDER BY a.created_at DESC",
                 )
                 .bind(user_uuid)
                 .bind(organization_uuid)
+                .bind(include_archived)
                 .fetch_all(p)
                 .await?;
 
@@ -1344,6 +1553,7 @@ pub async fn list_accessible_areas(
                                 deletable: row.get::<i64, _>("deletable") != 0,
                                 creator_uuid: row.get("creator_uuid"),
                                 created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                                archived_at: row.get::<Option<DateTime<Utc>>, _>("archived_at"),
                             },
                             area_uuid,
                         )
@@ -1401,3 +1611,114 @@ pub async fn list_accessible_areas(
     Ok(result)
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        add_organization_member, create_area as create_test_area, create_organization, grant_permission,
+        setup_tables, RecordingSubscriber,
+    };
+    use crate::tree::{get_area_tree, DocsTreeError};
+
+    #[sqlx::test]
+    async fn test_archived_area_hidden_by_default(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_test_area(&pool, &org_uuid, &user_uuid).await;
+
+        archive_area(&pool, &area_uuid, &org_uuid, &user_uuid, None)
+            .await
+            .expect("Failed to archive area");
+
+        let area = load_area_by_uuid(&pool, &area_uuid).await.unwrap();
+        assert!(area.archived_at.is_some());
+        // Archiving keeps the visibility flags
+        assert!(area.visible);
+        assert!(area.deletable);
+
+        // Hidden from the default listing and tree
+        let areas = list_accessible_areas(&pool, &org_uuid, &user_uuid, false).await.unwrap();
+        assert!(areas.is_empty());
+        assert!(matches!(
+            get_area_tree(&pool, &org_uuid, &area_uuid, false).await,
+            Err(DocsTreeError::AreaArchived)
+        ));
+
+        // Visible when archived areas are requested
+        let areas = list_accessible_areas(&pool, &org_uuid, &user_uuid, true).await.unwrap();
+        assert_eq!(areas.len(), 1);
+        assert_eq!(areas[0].area.uuid, area_uuid);
+        assert!(get_area_tree(&pool, &org_uuid, &area_uuid, true).await.is_ok());
+
+        // Unarchiving restores the default view
+        unarchive_area(&pool, &area_uuid, &org_uuid, &user_uuid, None)
+            .await
+            .expect("Failed to unarchive area");
+        let areas = list_accessible_areas(&pool, &org_uuid, &user_uuid, false).await.unwrap();
+        assert_eq!(areas.len(), 1);
+        assert!(areas[0].area.archived_at.is_none());
+        assert!(get_area_tree(&pool, &org_uuid, &area_uuid, false).await.is_ok());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_archive_transitions_emit_events(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_test_area(&pool, &org_uuid, &user_uuid).await;
+
+        let dispatcher = EventDispatcher::new();
+        let (archived_subscriber, archived) = RecordingSubscriber::new("module_docs_area_archived");
+        let (unarchived_subscriber, unarchived) = RecordingSubscriber::new("module_docs_area_unarchived");
+        dispatcher.subscribe(Box::new(archived_subscriber));
+        dispatcher.subscribe(Box::new(unarchived_subscriber));
+
+        archive_area(&pool, &area_uuid, &org_uuid, &user_uuid, Some(&dispatcher)).await.unwrap();
+        // Archiving twice does not emit a second event
+        archive_area(&pool, &area_uuid, &org_uuid, &user_uuid, Some(&dispatcher)).await.unwrap();
+        unarchive_area(&pool, &area_uuid, &org_uuid, &user_uuid, Some(&dispatcher)).await.unwrap();
+
+        let archived = archived.lock().unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].payload.data["entity_id"], area_uuid.as_str());
+        assert_eq!(archived[0].user_uuid.as_deref(), Some(user_uuid.as_str()));
+        assert_eq!(unarchived.lock().unwrap().len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_archive_area_requires_permission(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = uuid::Uuid::new_v4().to_string();
+        let other_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        add_organization_member(&pool, &org_uuid, &other_uuid).await;
+        let area_uuid = create_test_area(&pool, &org_uuid, &owner_uuid).await;
+
+        assert!(matches!(
+            archive_area(&pool, &area_uuid, &org_uuid, &other_uuid, None).await,
+            Err(DocsAreaDatabaseError::PermissionDenied)
+        ));
+        assert!(load_area_by_uuid(&pool, &area_uuid).await.unwrap().archived_at.is_none());
+
+        // The organization-wide archive permission is sufficient without area membership
+        grant_permission(&pool, &org_uuid, &other_uuid, "module_docs_can_archive_areas").await;
+        archive_area(&pool, &area_uuid, &org_uuid, &other_uuid, None)
+            .await
+            .expect("Failed to archive area");
+        assert!(load_area_by_uuid(&pool, &area_uuid).await.unwrap().archived_at.is_some());
+
+        Ok(())
+    }
+}
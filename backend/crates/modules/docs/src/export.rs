@@ -0,0 +1,269 @@
+//! Docs Export module
+//!
+//! Exports documentation pages to formats for use outside of Flextide, currently PDF.
+
+mod images;
+mod pdf;
+
+use flextide_core::database::{DatabaseError, DatabasePool};
+use flextide_core::user::{user_belongs_to_organization, user_has_permission};
+use pulldown_cmark::{html, Options, Parser};
+use thiserror::Error;
+use tracing::info;
+
+use crate::area::{load_area_member_permissions, DocsAreaDatabaseError};
+use crate::page::{load_page_with_version, DocsPageDatabaseError};
+
+use self::images::{fetch_images, ImageFetchPolicy};
+
+/// Error type for Docs export operations
+#[derive(Debug, Error)]
+pub enum DocsExportError {
+    #[error("Database error: {0}")]
+    Database(#[from] DatabaseError),
+
+    #[error("SQL execution error: {0}")]
+    Sql(#[from] sqlx::Error),
+
+    #[error("User does not belong to this organization")]
+    UserNotInOrganization,
+
+    #[error("User does not have permission to perform this action")]
+    PermissionDenied,
+
+    #[error("Page not found")]
+    PageNotFound,
+
+    #[error("Page does not belong to this organization")]
+    PageNotInOrganization,
+
+    #[error("Area not found")]
+    AreaNotFound,
+}
+
+impl From<DocsAreaDatabaseError> for DocsExportError {
+    fn from(error: DocsAreaDatabaseError) -> Self {
+        match error {
+            DocsAreaDatabaseError::Database(e) => DocsExportError::Database(e),
+            DocsAreaDatabaseError::Sql(e) => DocsExportError::Sql(e),
+            DocsAreaDatabaseError::UserNotInOrganization => DocsExportError::UserNotInOrganization,
+            DocsAreaDatabaseError::PermissionDenied => DocsExportError::PermissionDenied,
+            DocsAreaDatabaseError::AreaNotFound => DocsExportError::AreaNotFound,
+            DocsAreaDatabaseError::AreaNotInOrganization | DocsAreaDatabaseError::EmptyShortName => {
+                DocsExportError::Database(DatabaseError::PoolCreationFailed(sqlx::Error::RowNotFound))
+            }
+        }
+    }
+}
+
+impl From<DocsPageDatabaseError> for DocsExportError {
+    fn from(error: DocsPageDatabaseError) -> Self {
+        match error {
+            DocsPageDatabaseError::Database(e) => DocsExportError::Database(e),
+            DocsPageDatabaseError::Sql(e) => DocsExportError::Sql(e),
+            DocsPageDatabaseError::UserNotInOrganization => DocsExportError::UserNotInOrganization,
+            DocsPageDatabaseError::PermissionDenied => DocsExportError::PermissionDenied,
+            DocsPageDatabaseError::PageNotFound => DocsExportError::PageNotFound,
+            DocsPageDatabaseError::PageNotInOrganization => DocsExportError::PageNotInOrganization,
+            DocsPageDatabaseError::AreaNotFound => DocsExportError::AreaNotFound,
+            _ => DocsExportError::Database(DatabaseError::PoolCreationFailed(sqlx::Error::RowNotFound)),
+        }
+    }
+}
+
+/// Export a page as a PDF document
+///
+/// The page's markdown is rendered to HTML, which is then laid out on A4 pages.
+/// Images referenced by an http(s) URL of a public host are embedded; images that cannot
+/// be fetched or decoded are left out instead of failing the export.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization (for verification)
+/// * `user_uuid` - UUID of the user exporting the page
+/// * `page_uuid` - UUID of the page to export
+///
+/// # Returns
+/// Returns the bytes of the PDF document
+///
+/// # Errors
+/// Returns `DocsExportError` if:
+/// - User does not belong to the organization
+/// - Page not found or doesn't belong to the organization
+/// - User cannot view the pages of the page's area
+/// - Database operation fails
+pub async fn export_page_pdf(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    user_uuid: &str,
+    page_uuid: &str,
+) -> Result<Vec<u8>, DocsExportError> {
+    export_page_pdf_with_policy(pool, organization_uuid, user_uuid, page_uuid, ImageFetchPolicy::default()).await
+}
+
+/// Export a page as a PDF document, fetching images according to `image_policy`
+async fn export_page_pdf_with_policy(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    user_uuid: &str,
+    page_uuid: &str,
+    image_policy: ImageFetchPolicy,
+) -> Result<Vec<u8>, DocsExportError> {
+    let belongs = user_belongs_to_organization(pool, user_uuid, organization_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            DocsExportError::Database(e.into())
+        })?;
+
+    if !belongs {
+        return Err(DocsExportError::UserNotInOrganization);
+    }
+
+    let page = load_page_with_version(pool, page_uuid).await?;
+
+    if page.organization_uuid != organization_uuid {
+        return Err(DocsExportError::PageNotInOrganization);
+    }
+
+    let can_view = match load_area_member_permissions(pool, &page.area_uuid, user_uuid).await? {
+        Some(perms) => perms.admin || perms.role == "owner" || perms.can_view,
+        None => false,
+    };
+
+    let can_view = can_view
+        || user_has_permission(pool, user_uuid, organization_uuid, "module_docs_super_admin")
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error checking permission: {}", e);
+                DocsExportError::Database(e.into())
+            })?;
+
+    if !can_view {
+        return Err(DocsExportError::PermissionDenied);
+    }
+
+    let content = page.version.map(|version| version.content).unwrap_or_default();
+    let blocks = pdf::html_to_blocks(&markdown_to_html(&content));
+    let images = fetch_images(pdf::image_sources(&blocks), image_policy).await;
+    let document = pdf::render_pdf(&page.title, &blocks, &images);
+
+    info!(
+        "Exported page {} as PDF ({} bytes, {} images)",
+        page_uuid,
+        document.len(),
+        images.len()
+    );
+
+    Ok(document)
+}
+
+/// Render markdown to HTML
+pub(crate) fn markdown_to_html(markdown: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut output = String::new();
+    html::push_html(&mut output, Parser::new_ext(markdown, options));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::page::save_page_content;
+    use crate::test_support::{add_organization_member, create_area, create_organization, create_page, setup_tables};
+    use flextide_core::events::EventDispatcher;
+
+    /// Serve a small PNG image to a single request, returns its URL
+    async fn serve_png() -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let mut png = std::io::Cursor::new(Vec::new());
+        image::RgbImage::from_pixel(8, 4, image::Rgb([200, 30, 30]))
+            .write_to(&mut png, image::ImageFormat::Png)
+            .expect("Failed to encode PNG");
+        let png = png.into_inner();
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/image.png", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.expect("Failed to accept connection");
+            let mut buffer = [0u8; 4096];
+            let _ = stream.read(&mut buffer).await;
+            let header = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                png.len()
+            );
+            stream.write_all(header.as_bytes()).await.expect("Failed to write header");
+            stream.write_all(&png).await.expect("Failed to write image");
+        });
+
+        url
+    }
+
+    #[sqlx::test]
+    async fn test_export_page_pdf(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = uuid::Uuid::new_v4().to_string();
+        let outsider_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        add_organization_member(&pool, &org_uuid, &outsider_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &owner_uuid).await;
+        let page_uuid = create_page(&pool, &org_uuid, &area_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        let content = format!(
+            "# Handout\n\nSome **bold** and *italic* text with `code`.\n\n\
+             - First\n- Second\n  1. Nested\n\n\
+             ```\nfn main() {{}}\n```\n\n\
+             | Name | Value |\n|------|-------|\n| a | 1 |\n\n\
+             ![Broken](http://127.0.0.1:9/missing.png)\n\n![Logo]({})\n\n---\n\nThe end.",
+            serve_png().await
+        );
+        save_page_content(&pool, &org_uuid, &page_uuid, &owner_uuid, &content, &dispatcher)
+            .await
+            .expect("Failed to save content");
+
+        // The test server listens on a loopback address
+        let image_policy = ImageFetchPolicy { allow_private_hosts: true };
+        let document = export_page_pdf_with_policy(&pool, &org_uuid, &owner_uuid, &page_uuid, image_policy)
+            .await
+            .expect("Failed to export page");
+        assert!(document.starts_with(b"%PDF-"));
+
+        // The reachable image is embedded, the broken one is skipped
+        let image_count = document.windows(b"/Subtype /Image".len()).filter(|w| w == b"/Subtype /Image").count();
+        assert_eq!(image_count, 1);
+
+        let text = pdf_extract::extract_text_from_mem(&document).expect("Exported PDF should be readable");
+        for expected in ["Test Page", "Handout", "bold", "Second", "Nested", "fn main() {}", "The end."] {
+            assert!(text.contains(expected), "{:?} missing in {:?}", expected, text);
+        }
+
+        // Users without view rights in the area cannot export
+        assert!(matches!(
+            export_page_pdf(&pool, &org_uuid, &outsider_uuid, &page_uuid).await,
+            Err(DocsExportError::PermissionDenied)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_html_blocks_from_markdown() {
+        let blocks = pdf::html_to_blocks(&markdown_to_html(
+            "Intro with an ![image](https://example.com/a.png) inline\n\n1. One\n2. Two &amp; more",
+        ));
+
+        assert_eq!(pdf::image_sources(&blocks).collect::<Vec<_>>(), vec!["https://example.com/a.png"]);
+        assert_eq!(
+            pdf::block_texts(&blocks),
+            vec!["Intro with an", "inline", "1. One", "2. Two & more"]
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Fetching of the images referenced by an exported page
+//!
+//! Image URLs come from page content, so they are untrusted. Images are only fetched
+//! from hosts that resolve to public addresses, the checked address is pinned for the
+//! request and redirects are not followed. Size and time spent are bounded.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use futures_util::stream::{self, StreamExt};
+use reqwest::Url;
+use tracing::warn;
+
+use super::pdf::PdfImage;
+
+/// Timeout for fetching a single image
+const IMAGE_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time spent fetching all images of a page, images fetched until then are embedded
+const IMAGES_TOTAL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Number of images fetched at the same time
+const IMAGE_FETCH_CONCURRENCY: usize = 4;
+
+/// Maximum size of a single image
+const MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+
+/// Hosts images may be fetched from
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ImageFetchPolicy {
+    /// Also fetch from loopback, private and link-local addresses (tests only)
+    pub allow_private_hosts: bool,
+}
+
+/// Fetch and decode the images referenced by a page, skipping those that fail
+pub(crate) async fn fetch_images<'a>(
+    sources: impl IntoIterator<Item = &'a str>,
+    policy: ImageFetchPolicy,
+) -> HashMap<String, PdfImage> {
+    let mut unique_sources: Vec<String> = Vec::new();
+    for source in sources {
+        if !unique_sources.iter().any(|known| known == source) {
+            unique_sources.push(source.to_string());
+        }
+    }
+
+    let mut fetches = stream::iter(unique_sources)
+        .map(|source| async move {
+            let image = fetch_image(&source, policy).await;
+            (source, image)
+        })
+        .buffer_unordered(IMAGE_FETCH_CONCURRENCY);
+
+    let deadline = tokio::time::Instant::now() + IMAGES_TOTAL_TIMEOUT;
+    let mut images = HashMap::new();
+    loop {
+        match tokio::time::timeout_at(deadline, fetches.next()).await {
+            Ok(Some((source, Ok(image)))) => {
+                images.insert(source, image);
+            }
+            Ok(Some((source, Err(e)))) => warn!("Skipping image {} in PDF export: {}", source, e),
+            Ok(None) => break,
+            Err(_) => {
+                warn!(
+                    "Fetching images for PDF export took longer than {:?}, skipping the remaining images",
+                    IMAGES_TOTAL_TIMEOUT
+                );
+                break;
+            }
+        }
+    }
+
+    images
+}
+
+/// Fetch and decode a single image
+async fn fetch_image(source: &str, policy: ImageFetchPolicy) -> Result<PdfImage, String> {
+    let url = Url::parse(source).map_err(|e| e.to_string())?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err("only http(s) URLs are supported".to_string());
+    }
+    let host = url.host_str().ok_or("URL has no host")?.to_string();
+    let port = url.port_or_known_default().ok_or("URL has no port")?;
+
+    let address = resolve_address(&host, port).await?;
+    if !policy.allow_private_hosts && !is_public_address(address.ip()) {
+        return Err(format!("{} resolves to the non-public address {}", host, address.ip()));
+    }
+
+    // Pin the checked address, so a second lookup cannot point the request elsewhere
+    let client = reqwest::Client::builder()
+        .timeout(IMAGE_FETCH_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none())
+        .resolve(&host, address)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let mut response = client.get(url).send().await.map_err(|e| e.without_url().to_string())?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("HTTP {}", status));
+    }
+
+    let too_large = || format!("image is larger than {} bytes", MAX_IMAGE_BYTES);
+    if response.content_length().is_some_and(|length| length > MAX_IMAGE_BYTES as u64) {
+        return Err(too_large());
+    }
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| e.without_url().to_string())? {
+        if bytes.len() + chunk.len() > MAX_IMAGE_BYTES {
+            return Err(too_large());
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+
+    let image = image::load_from_memory(&bytes).map_err(|e| e.to_string())?.to_rgb8();
+
+    Ok(PdfImage {
+        width: image.width(),
+        height: image.height(),
+        rgb: image.into_raw(),
+    })
+}
+
+/// Resolve a host to the address to connect to
+///
+/// If the host has any non-public address, that address is returned so the host is
+/// rejected: the connection could otherwise end up on it.
+async fn resolve_address(host: &str, port: u16) -> Result<SocketAddr, String> {
+    let literal = host.trim_start_matches('[').trim_end_matches(']');
+    if let Ok(ip) = literal.parse::<IpAddr>() {
+        return Ok(SocketAddr::new(ip, port));
+    }
+
+    let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| format!("failed to resolve {}: {}", host, e))?
+        .collect();
+
+    if let Some(address) = addresses.iter().find(|address| !is_public_address(address.ip())) {
+        return Ok(*address);
+    }
+    addresses
+        .into_iter()
+        .next()
+        .ok_or_else(|| format!("{} did not resolve to any address", host))
+}
+
+/// Whether an address is reachable on the public internet
+///
+/// Loopback, private, link-local (including cloud metadata endpoints), shared, reserved
+/// and multicast addresses are not.
+fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ipv4(ip),
+            None => {
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local())
+            }
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    let is_shared = first == 100 && (64..128).contains(&second);
+    let is_reserved = first == 0 || first >= 240;
+
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        || is_shared
+        || is_reserved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    const ALLOW_PRIVATE: ImageFetchPolicy = ImageFetchPolicy { allow_private_hosts: true };
+
+    /// Answer a single request with `head` followed by `body`, returns the URL
+    async fn serve_once(head: String, body: Vec<u8>) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/image.png", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.expect("Failed to accept connection");
+            let mut buffer = [0u8; 4096];
+            let _ = stream.read(&mut buffer).await;
+            let _ = stream.write_all(head.as_bytes()).await;
+            let _ = stream.write_all(&body).await;
+        });
+        url
+    }
+
+    fn png() -> Vec<u8> {
+        let mut png = std::io::Cursor::new(Vec::new());
+        image::RgbImage::from_pixel(2, 2, image::Rgb([0, 0, 0]))
+            .write_to(&mut png, image::ImageFormat::Png)
+            .expect("Failed to encode PNG");
+        png.into_inner()
+    }
+
+    #[test]
+    fn test_non_public_addresses() {
+        for address in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public_address(address.parse().unwrap()), "{} should not be public", address);
+        }
+        for address in ["93.184.216.34", "2606:2800:220:1:248:1893:25c8:1946"] {
+            assert!(is_public_address(address.parse().unwrap()), "{} should be public", address);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_private_hosts_are_not_fetched() {
+        let image = png();
+        let head = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", image.len());
+        let url = serve_once(head, image).await;
+
+        let images = fetch_images([url.as_str()], ImageFetchPolicy::default()).await;
+        assert!(images.is_empty());
+
+        let error = fetch_image("http://localhost:9/image.png", ImageFetchPolicy::default())
+            .await
+            .err()
+            .expect("Loopback host should be rejected");
+        assert!(error.contains("non-public"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_images_are_rejected_before_download() {
+        let head = format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            MAX_IMAGE_BYTES + 1
+        );
+        let url = serve_once(head, Vec::new()).await;
+
+        let error = fetch_image(&url, ALLOW_PRIVATE).await.err().expect("Image should be too large");
+        assert!(error.contains("larger than"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_redirects_are_not_followed() {
+        let head = "HTTP/1.1 302 Found\r\nLocation: http://169.254.169.254/latest/meta-data\r\n\
+                    Content-Length: 0\r\nConnection: close\r\n\r\n"
+            .to_string();
+        let url = serve_once(head, Vec::new()).await;
+
+        let error = fetch_image(&url, ALLOW_PRIVATE).await.err().expect("Redirect should fail");
+        assert_eq!(error, "HTTP 302 Found");
+    }
+}
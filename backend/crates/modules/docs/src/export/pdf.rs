@@ -0,0 +1,774 @@
+//! PDF rendering of page HTML
+//!
+//! Lays out the HTML rendered from a page's markdown on A4 pages. Only the standard
+//! PDF fonts are used, so no font files need to be embedded; characters outside of
+//! WinAnsiEncoding are replaced with `?`.
+
+use std::collections::HashMap;
+
+use miniz_oxide::deflate::compress_to_vec_zlib;
+use pdf_writer::{Content, Filter, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 56.0;
+const BODY_SIZE: f32 = 11.0;
+const CODE_SIZE: f32 = 9.0;
+const LINE_SPACING: f32 = 1.4;
+const INDENT: f32 = 18.0;
+/// Images are assumed to be 96 DPI, PDF units are 1/72 inch
+const POINTS_PER_PIXEL: f32 = 0.75;
+const COMPRESSION_LEVEL: u8 = 6;
+/// Marks a hard line break in span text, other newlines are soft breaks
+const LINE_BREAK: char = '\u{2028}';
+
+const FONT_REGULAR: Name = Name(b"F1");
+const FONT_BOLD: Name = Name(b"F2");
+const FONT_ITALIC: Name = Name(b"F3");
+const FONT_BOLD_ITALIC: Name = Name(b"F4");
+const FONT_CODE: Name = Name(b"F5");
+
+/// Fonts of the document as (resource name, base font)
+const FONTS: [(Name, Name); 5] = [
+    (FONT_REGULAR, Name(b"Helvetica")),
+    (FONT_BOLD, Name(b"Helvetica-Bold")),
+    (FONT_ITALIC, Name(b"Helvetica-Oblique")),
+    (FONT_BOLD_ITALIC, Name(b"Helvetica-BoldOblique")),
+    (FONT_CODE, Name(b"Courier")),
+];
+
+/// Helvetica glyph widths (1/1000 em) for the printable ASCII characters
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, // ' ' to '/'
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, // '0' to '?'
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778, // '@' to 'O'
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556, // 'P' to '_'
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556, // '`' to 'o'
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584, // 'p' to '~'
+];
+
+/// Decoded RGB image ready to be embedded
+pub(crate) struct PdfImage {
+    pub width: u32,
+    pub height: u32,
+    /// 8 bit RGB samples, row by row
+    pub rgb: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct SpanStyle {
+    bold: bool,
+    italic: bool,
+    code: bool,
+}
+
+impl SpanStyle {
+    fn font(self) -> Name<'static> {
+        match (self.code, self.bold, self.italic) {
+            (true, _, _) => FONT_CODE,
+            (false, true, true) => FONT_BOLD_ITALIC,
+            (false, true, false) => FONT_BOLD,
+            (false, false, true) => FONT_ITALIC,
+            (false, false, false) => FONT_REGULAR,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Span {
+    text: String,
+    style: SpanStyle,
+}
+
+/// Block level element of a page
+#[derive(Debug)]
+pub(crate) enum Block {
+    Text {
+        spans: Vec<Span>,
+        size: f32,
+        /// List nesting depth
+        depth: usize,
+        bullet: Option<String>,
+        quote: bool,
+    },
+    Code {
+        text: String,
+        depth: usize,
+    },
+    Rule,
+    Image {
+        source: String,
+    },
+}
+
+/// Image sources referenced by the blocks, in document order
+pub(crate) fn image_sources(blocks: &[Block]) -> impl Iterator<Item = &str> {
+    blocks.iter().filter_map(|block| match block {
+        Block::Image { source } => Some(source.as_str()),
+        _ => None,
+    })
+}
+
+/// Plain text of the text blocks, prefixed with their bullet
+#[cfg(test)]
+pub(crate) fn block_texts(blocks: &[Block]) -> Vec<String> {
+    blocks
+        .iter()
+        .filter_map(|block| match block {
+            Block::Text { spans, bullet, .. } => {
+                let text: String = spans.iter().map(|span| span.text.as_str()).collect();
+                let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                Some(match bullet {
+                    Some(bullet) => format!("{} {}", bullet, text),
+                    None => text,
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Builds blocks from the HTML tags and text of a page
+#[derive(Default)]
+struct BlockBuilder {
+    blocks: Vec<Block>,
+    spans: Vec<Span>,
+    bold: usize,
+    italic: usize,
+    code: usize,
+    heading_size: Option<f32>,
+    /// Open lists, with the next number for ordered lists
+    lists: Vec<Option<u64>>,
+    quote: usize,
+    pending_bullet: Option<String>,
+    preformatted: Option<String>,
+}
+
+impl BlockBuilder {
+    fn style(&self) -> SpanStyle {
+        SpanStyle {
+            bold: self.bold > 0 || self.heading_size.is_some(),
+            italic: self.italic > 0,
+            code: self.code > 0,
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if let Some(preformatted) = &mut self.preformatted {
+            preformatted.push_str(text);
+            return;
+        }
+
+        let style = self.style();
+        match self.spans.last_mut() {
+            Some(span) if span.style == style => span.text.push_str(text),
+            _ => self.spans.push(Span {
+                text: text.to_string(),
+                style,
+            }),
+        }
+    }
+
+    /// Finish the current text block, keeping a pending bullet if there is no text yet
+    fn flush(&mut self) {
+        let spans = std::mem::take(&mut self.spans);
+        if spans.iter().all(|span| span.text.trim().is_empty()) {
+            return;
+        }
+
+        self.blocks.push(Block::Text {
+            spans,
+            size: self.heading_size.unwrap_or(BODY_SIZE),
+            depth: self.lists.len(),
+            bullet: self.pending_bullet.take(),
+            quote: self.quote > 0,
+        });
+    }
+
+    fn open_tag(&mut self, name: &str, attributes: &str) {
+        if self.preformatted.is_some() {
+            return;
+        }
+
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.flush();
+                self.heading_size = Some(match name {
+                    "h1" => 20.0,
+                    "h2" => 16.0,
+                    "h3" => 14.0,
+                    _ => 12.0,
+                });
+            }
+            "p" | "tr" => self.flush(),
+            "ul" => {
+                self.flush();
+                self.lists.push(None);
+            }
+            "ol" => {
+                self.flush();
+                let start = attribute(attributes, "start")
+                    .and_then(|start| start.parse().ok())
+                    .unwrap_or(1);
+                self.lists.push(Some(start));
+            }
+            "li" => {
+                self.flush();
+                self.pending_bullet = Some(match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}.", *number - 1)
+                    }
+                    _ => "\u{2022}".to_string(),
+                });
+            }
+            "blockquote" => {
+                self.flush();
+                self.quote += 1;
+            }
+            "pre" => {
+                self.flush();
+                self.preformatted = Some(String::new());
+            }
+            "th" | "td" => {
+                if name == "th" {
+                    self.bold += 1;
+                }
+                if !self.spans.is_empty() {
+                    self.push_text(" | ");
+                }
+            }
+            "strong" | "b" => self.bold += 1,
+            "em" | "i" => self.italic += 1,
+            "code" => self.code += 1,
+            "br" => self.push_text(&LINE_BREAK.to_string()),
+            "hr" => {
+                self.flush();
+                self.blocks.push(Block::Rule);
+            }
+            "img" => {
+                if let Some(source) = attribute(attributes, "src") {
+                    self.flush();
+                    self.blocks.push(Block::Image { source });
+                }
+            }
+            "input" => {
+                // Task list checkbox
+                let checked = attributes.contains("checked");
+                self.push_text(if checked { "[x] " } else { "[ ] " });
+            }
+            _ => {}
+        }
+    }
+
+    fn close_tag(&mut self, name: &str) {
+        if let Some(preformatted) = &self.preformatted {
+            if name == "pre" {
+                self.blocks.push(Block::Code {
+                    text: preformatted.trim_end_matches('\n').to_string(),
+                    depth: self.lists.len(),
+                });
+                self.preformatted = None;
+            }
+            return;
+        }
+
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.flush();
+                self.heading_size = None;
+            }
+            "p" | "li" | "tr" | "table" => self.flush(),
+            "ul" | "ol" => {
+                self.flush();
+                self.lists.pop();
+            }
+            "blockquote" => {
+                self.flush();
+                self.quote = self.quote.saturating_sub(1);
+            }
+            "th" => self.bold = self.bold.saturating_sub(1),
+            "strong" | "b" => self.bold = self.bold.saturating_sub(1),
+            "em" | "i" => self.italic = self.italic.saturating_sub(1),
+            "code" => self.code = self.code.saturating_sub(1),
+            _ => {}
+        }
+    }
+}
+
+/// Split HTML into blocks
+///
+/// Understands the subset of HTML produced from markdown. Unknown tags are ignored
+/// while their text is kept.
+pub(crate) fn html_to_blocks(html: &str) -> Vec<Block> {
+    let mut builder = BlockBuilder::default();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        if start > 0 {
+            builder.push_text(&decode_entities(&rest[..start]));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map(|end| &comment[end + 3..]).unwrap_or("");
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            builder.push_text(&decode_entities(rest));
+            rest = "";
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let tag = tag.trim_end_matches('/');
+        let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+        let name = tag[..name_end].to_ascii_lowercase();
+
+        if closing {
+            builder.close_tag(&name);
+        } else {
+            builder.open_tag(&name, &tag[name_end..]);
+        }
+    }
+
+    if !rest.is_empty() {
+        builder.push_text(&decode_entities(rest));
+    }
+    builder.flush();
+
+    builder.blocks
+}
+
+/// Get the decoded value of a double quoted attribute
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let pattern = format!("{}=\"", name);
+    let mut search = attributes;
+
+    while let Some(position) = search.find(&pattern) {
+        let preceded_by_space = search[..position].ends_with(|c: char| c.is_whitespace()) || position == 0;
+        let value = &search[position + pattern.len()..];
+        if preceded_by_space {
+            return value.find('"').map(|end| decode_entities(&value[..end]));
+        }
+        search = value;
+    }
+
+    None
+}
+
+/// Decode the HTML character references produced by markdown rendering
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let character = rest.find(';').filter(|end| *end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let character = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|decimal| decimal.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            character.map(|character| (character, end))
+        });
+
+        match character {
+            Some((character, end)) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Encode text in WinAnsiEncoding, replacing unsupported characters with `?`
+fn encode_win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            ' '..='~' | '\u{a0}'..='\u{ff}' => c as u8,
+            '\u{20ac}' => 0x80,
+            '\u{2026}' => 0x85,
+            '\u{2018}' => 0x91,
+            '\u{2019}' => 0x92,
+            '\u{201c}' => 0x93,
+            '\u{201d}' => 0x94,
+            '\u{2022}' => 0x95,
+            '\u{2013}' => 0x96,
+            '\u{2014}' => 0x97,
+            '\t' => b' ',
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Width of text in points
+fn text_width(text: &str, style: SpanStyle, size: f32) -> f32 {
+    let units: f32 = encode_win_ansi(text)
+        .iter()
+        .map(|&byte| {
+            if style.code {
+                600.0
+            } else if (32..=126).contains(&byte) {
+                f32::from(HELVETICA_WIDTHS[usize::from(byte - 32)])
+            } else {
+                556.0
+            }
+        })
+        .sum();
+
+    // Bold glyphs are slightly wider than the regular Helvetica widths
+    let units = if style.bold && !style.code { units * 1.06 } else { units };
+    units * size / 1000.0
+}
+
+/// Word made of differently styled pieces, or a forced line break
+enum Word {
+    Pieces(Vec<(String, SpanStyle)>),
+    Break,
+}
+
+/// Split spans into words, keeping pieces of a word that change style together
+fn split_words(spans: &[Span]) -> Vec<Word> {
+    let mut words = Vec::new();
+    let mut current: Vec<(String, SpanStyle)> = Vec::new();
+
+    for span in spans {
+        for c in span.text.chars() {
+            if c.is_whitespace() {
+                if !current.is_empty() {
+                    words.push(Word::Pieces(std::mem::take(&mut current)));
+                }
+                if c == LINE_BREAK {
+                    words.push(Word::Break);
+                }
+                continue;
+            }
+            match current.last_mut() {
+                Some((text, style)) if *style == span.style => text.push(c),
+                _ => current.push((c.to_string(), span.style)),
+            }
+        }
+    }
+    if !current.is_empty() {
+        words.push(Word::Pieces(current));
+    }
+
+    words
+}
+
+/// Lines of styled pieces fitting into the given width
+fn wrap_words(words: Vec<Word>, size: f32, width: f32) -> Vec<Vec<(String, SpanStyle)>> {
+    let space = text_width(" ", SpanStyle::default(), size);
+    let mut lines = Vec::new();
+    let mut line: Vec<(String, SpanStyle)> = Vec::new();
+    let mut line_width = 0.0;
+
+    for word in words {
+        let pieces = match word {
+            Word::Break => {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0.0;
+                continue;
+            }
+            Word::Pieces(pieces) => pieces,
+        };
+
+        let word_width: f32 = pieces.iter().map(|(text, style)| text_width(text, *style, size)).sum();
+        if !line.is_empty() && line_width + space + word_width > width {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0.0;
+        }
+
+        if !line.is_empty() {
+            line.push((" ".to_string(), SpanStyle::default()));
+            line_width += space;
+        }
+
+        // Words longer than a line (e.g. URLs) are broken between characters
+        for (text, style) in pieces {
+            for c in text.chars() {
+                let char_width = text_width(c.encode_utf8(&mut [0; 4]), style, size);
+                if line_width + char_width > width && line_width > 0.0 {
+                    lines.push(std::mem::take(&mut line));
+                    line_width = 0.0;
+                }
+                match line.last_mut() {
+                    Some((last, last_style)) if *last_style == style => last.push(c),
+                    _ => line.push((c.to_string(), style)),
+                }
+                line_width += char_width;
+            }
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Writes blocks onto pages, starting a new page when the current one is full
+struct PageWriter<'a> {
+    pages: Vec<Content>,
+    content: Content,
+    y: f32,
+    images: &'a HashMap<String, PdfImage>,
+    image_names: &'a HashMap<&'a str, String>,
+}
+
+impl PageWriter<'_> {
+    /// Make sure the given height fits on the current page
+    fn reserve(&mut self, height: f32) {
+        if self.y - height < MARGIN && self.y < PAGE_HEIGHT - MARGIN {
+            let full = std::mem::replace(&mut self.content, Content::new());
+            self.pages.push(full);
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+    }
+
+    fn show(&mut self, text: &str, style: SpanStyle, size: f32, x: f32) {
+        self.content
+            .begin_text()
+            .set_font(style.font(), size)
+            .next_line(x, self.y)
+            .show(Str(&encode_win_ansi(text)))
+            .end_text();
+    }
+
+    fn write_text(&mut self, spans: &[Span], size: f32, depth: usize, bullet: Option<&str>, quote: bool) {
+        let x = MARGIN + depth as f32 * INDENT + if quote { INDENT } else { 0.0 };
+        let line_height = size * LINE_SPACING;
+
+        if size > BODY_SIZE {
+            self.y -= size * 0.5;
+        }
+        if quote {
+            self.content.set_fill_gray(0.35);
+        }
+
+        let lines = wrap_words(split_words(spans), size, PAGE_WIDTH - MARGIN - x);
+        for (index, line) in lines.iter().enumerate() {
+            self.reserve(line_height);
+            self.y -= line_height;
+
+            if index == 0 {
+                if let Some(bullet) = bullet {
+                    self.show(bullet, SpanStyle::default(), size, x - INDENT + 4.0);
+                }
+            }
+
+            let mut piece_x = x;
+            for (text, style) in line {
+                if !text.trim().is_empty() {
+                    self.show(text, *style, size, piece_x);
+                }
+                piece_x += text_width(text, *style, size);
+            }
+        }
+
+        if quote {
+            self.content.set_fill_gray(0.0);
+        }
+        self.y -= size * 0.5;
+    }
+
+    fn write_code(&mut self, text: &str, depth: usize) {
+        let x = MARGIN + depth as f32 * INDENT + INDENT / 2.0;
+        let line_height = CODE_SIZE * LINE_SPACING;
+        let max_chars = (((PAGE_WIDTH - MARGIN - x) / (CODE_SIZE * 0.6)) as usize).max(1);
+        let style = SpanStyle {
+            code: true,
+            ..SpanStyle::default()
+        };
+
+        for line in text.lines() {
+            let chars: Vec<char> = line.replace('\t', "    ").chars().collect();
+            let chunks: Vec<String> = if chars.is_empty() {
+                vec![String::new()]
+            } else {
+                chars.chunks(max_chars).map(|chunk| chunk.iter().collect()).collect()
+            };
+            for chunk in chunks {
+                self.reserve(line_height);
+                self.y -= line_height;
+                if !chunk.is_empty() {
+                    self.show(&chunk, style, CODE_SIZE, x);
+                }
+            }
+        }
+
+        self.y -= BODY_SIZE * 0.5;
+    }
+
+    fn write_rule(&mut self) {
+        self.reserve(BODY_SIZE);
+        self.y -= BODY_SIZE / 2.0;
+        self.content
+            .set_line_width(0.5)
+            .move_to(MARGIN, self.y)
+            .line_to(PAGE_WIDTH - MARGIN, self.y)
+            .stroke();
+        self.y -= BODY_SIZE / 2.0;
+    }
+
+    fn write_image(&mut self, source: &str) {
+        let (Some(image), Some(name)) = (self.images.get(source), self.image_names.get(source)) else {
+            return;
+        };
+
+        let max_width = PAGE_WIDTH - 2.0 * MARGIN;
+        let max_height = PAGE_HEIGHT - 2.0 * MARGIN;
+        let width = image.width as f32 * POINTS_PER_PIXEL;
+        let height = image.height as f32 * POINTS_PER_PIXEL;
+        let scale = (max_width / width).min(max_height / height).min(1.0);
+        let (width, height) = (width * scale, height * scale);
+
+        self.reserve(height);
+        self.y -= height;
+        self.content
+            .save_state()
+            .transform([width, 0.0, 0.0, height, MARGIN, self.y])
+            .x_object(Name(name.as_bytes()))
+            .restore_state();
+        self.y -= BODY_SIZE * 0.5;
+    }
+}
+
+/// Render a page title and its blocks to a PDF document
+pub(crate) fn render_pdf(title: &str, blocks: &[Block], images: &HashMap<String, PdfImage>) -> Vec<u8> {
+    let mut next_id = Ref::new(1);
+    let mut alloc = || next_id.bump();
+
+    let catalog_id = alloc();
+    let page_tree_id = alloc();
+    let info_id = alloc();
+    let font_ids: Vec<Ref> = FONTS.iter().map(|_| alloc()).collect();
+
+    // Only images that are actually referenced are embedded, each once
+    let mut image_names: HashMap<&str, String> = HashMap::new();
+    let mut image_ids: Vec<(Ref, &PdfImage, String)> = Vec::new();
+    for source in image_sources(blocks) {
+        if let (Some(image), false) = (images.get(source), image_names.contains_key(source)) {
+            let name = format!("Im{}", image_ids.len() + 1);
+            image_names.insert(source, name.clone());
+            image_ids.push((alloc(), image, name));
+        }
+    }
+
+    let mut writer = PageWriter {
+        pages: Vec::new(),
+        content: Content::new(),
+        y: PAGE_HEIGHT - MARGIN,
+        images,
+        image_names: &image_names,
+    };
+
+    let title_span = Span {
+        text: title.to_string(),
+        style: SpanStyle {
+            bold: true,
+            ..SpanStyle::default()
+        },
+    };
+    writer.write_text(std::slice::from_ref(&title_span), 22.0, 0, None, false);
+
+    for block in blocks {
+        match block {
+            Block::Text {
+                spans,
+                size,
+                depth,
+                bullet,
+                quote,
+            } => writer.write_text(spans, *size, *depth, bullet.as_deref(), *quote),
+            Block::Code { text, depth } => writer.write_code(text, *depth),
+            Block::Rule => writer.write_rule(),
+            Block::Image { source } => writer.write_image(source),
+        }
+    }
+
+    let mut pages = writer.pages;
+    pages.push(writer.content);
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.document_info(info_id)
+        .title(TextStr(title))
+        .producer(TextStr("Flextide"));
+
+    for ((_, base_font), id) in FONTS.iter().zip(&font_ids) {
+        pdf.type1_font(*id)
+            .base_font(*base_font)
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+    }
+
+    for (id, image, _) in &image_ids {
+        let samples = compress_to_vec_zlib(&image.rgb, COMPRESSION_LEVEL);
+        let mut xobject = pdf.image_xobject(*id, &samples);
+        xobject.filter(Filter::FlateDecode);
+        xobject.width(image.width as i32);
+        xobject.height(image.height as i32);
+        xobject.color_space().device_rgb();
+        xobject.bits_per_component(8);
+        xobject.finish();
+    }
+
+    let mut page_ids = Vec::new();
+    for content in pages {
+        let page_id = alloc();
+        let content_id = alloc();
+        page_ids.push(page_id);
+
+        let mut page = pdf.page(page_id);
+        page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT));
+        page.parent(page_tree_id);
+        page.contents(content_id);
+        let mut resources = page.resources();
+        let mut fonts = resources.fonts();
+        for ((resource_name, _), id) in FONTS.iter().zip(&font_ids) {
+            fonts.pair(*resource_name, *id);
+        }
+        fonts.finish();
+        let mut x_objects = resources.x_objects();
+        for (id, _, name) in &image_ids {
+            x_objects.pair(Name(name.as_bytes()), *id);
+        }
+        x_objects.finish();
+        resources.finish();
+        page.finish();
+
+        let compressed = compress_to_vec_zlib(&content.finish(), COMPRESSION_LEVEL);
+        pdf.stream(content_id, &compressed).filter(Filter::FlateDecode);
+    }
+
+    let page_count = page_ids.len() as i32;
+    pdf.pages(page_tree_id).kids(page_ids).count(page_count);
+
+    pdf.finish()
+}
@@ -45,6 +45,9 @@ pub enum DocsFolderDatabaseError {
     #[error("Name cannot be empty")]
     EmptyName,
 
+    #[error("A folder with this name already exists in the same parent")]
+    DuplicateFolderName,
+
     #[error("Folder cannot be deleted: contains sub-folders or pages")]
     FolderNotEmpty,
 
@@ -506,6 +509,40 @@ pub async fn load_folder_by_uuid(
     }
 }
 
+/// Ensure no other folder with the same parent in the area has the given name
+///
+/// Names are compared after trimming and case-sensitively, the same way paths are resolved
+/// in the area tree.
+///
+/// # Arguments
+/// * `exclude_folder_uuid` - Folder that is being renamed or moved, which is not a sibling of itself
+///
+/// # Errors
+/// Returns `DocsFolderDatabaseError::DuplicateFolderName` if a sibling already has the name
+async fn ensure_unique_folder_name(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    area_uuid: &str,
+    parent_folder_uuid: Option<&str>,
+    name: &str,
+    exclude_folder_uuid: Option<&str>,
+) -> Result<(), DocsFolderDatabaseError> {
+    let duplicate = get_all_folders(pool, organization_uuid, area_uuid)
+        .await?
+        .into_iter()
+        .any(|folder| {
+            folder.parent_folder_uuid.as_deref() == parent_folder_uuid
+                && Some(folder.uuid.as_str()) != exclude_folder_uuid
+                && folder.name.trim() == name.trim()
+        });
+
+    if duplicate {
+        return Err(DocsFolderDatabaseError::DuplicateFolderName);
+    }
+
+    Ok(())
+}
+
 /// Create a new folder in the database
 ///
 /// # Arguments
@@ -523,6 +560,7 @@ pub async fn load_folder_by_uuid(
 /// - User does not have permission to create folders
 /// - Area does not belong to the organization
 /// - Name is empty
+/// - A sibling folder already has the name
 /// - Database operation fails
 pub async fn create_folder(
     pool: &DatabasePool,
@@ -652,6 +690,16 @@ pub async fn create_folder(
         (false, false, false)
     };
 
+    ensure_unique_folder_name(
+        pool,
+        organization_uuid,
+        &request.area_uuid,
+        request.parent_folder_uuid.as_deref(),
+        &request.name,
+        None,
+    )
+    .await?;
+
     // Create folder
     let folder_uuid = uuid::Uuid::new_v4().to_string();
     let sort_order = request.sort_order.unwrap_or(0);
@@ -996,6 +1044,7 @@ pub async fn delete_folder(
 /// - User does not have permission to edit folders
 /// - Folder does not belong to the organization
 /// - Name is empty
+/// - A sibling folder already has the name
 /// - Folder not found
 /// - Database operation fails
 pub async fn update_folder_name(
@@ -1091,6 +1140,16 @@ pub async fn update_folder_name(
         return Err(DocsFolderDatabaseError::PermissionDenied);
     }
 
+    ensure_unique_folder_name(
+        pool,
+        organization_uuid,
+        &folder.area_uuid,
+        folder.parent_folder_uuid.as_deref(),
+        &name,
+        Some(folder_uuid),
+    )
+    .await?;
+
     // Update folder name
     match pool {
         DatabasePool::MySql(p) => {
@@ -1370,6 +1429,7 @@ pub async fn update_folder_properties(
 /// - User does not belong to the organization
 /// - User does not have permission to edit folders
 /// - Folder does not belong to the organization
+/// - A sibling folder already has the new name
 /// - Database operation fails
 pub async fn update_folder(
     pool: &DatabasePool,
@@ -1471,6 +1531,15 @@ pub async fn update_folder(
                 ),
             ));
         }
+        ensure_unique_folder_name(
+            pool,
+            organization_uuid,
+            &folder.area_uuid,
+            folder.parent_folder_uuid.as_deref(),
+            name,
+            Some(folder_uuid),
+        )
+        .await?;
     }
 
     // Update folder fields
@@ -2077,6 +2146,7 @@ pub async fn reorder_folder(
 /// - Folder does not belong to the organization
 /// - Parent folder does not belong to the organization or area
 /// - Folder would be moved into itself or a descendant
+/// - A folder with the same name already exists in the new parent
 /// - Folder not found
 /// - Database operation fails
 pub async fn move_folder(
@@ -2225,6 +2295,16 @@ pub async fn move_folder(
         return Err(DocsFolderDatabaseError::PermissionDenied);
     }
 
+    ensure_unique_folder_name(
+        pool,
+        organization_uuid,
+        &folder.area_uuid,
+        parent_folder_uuid.as_deref(),
+        &folder.name,
+        Some(folder_uuid),
+    )
+    .await?;
+
     // Update parent_folder_uuid and sort_order
     match pool {
         DatabasePool::MySql(p) => {
@@ -2299,3 +2379,83 @@ pub async fn move_folder(
     Ok(())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_area, create_organization, setup_tables};
+
+    async fn create_named_folder(
+        pool: &DatabasePool,
+        org_uuid: &str,
+        user_uuid: &str,
+        area_uuid: &str,
+        name: &str,
+        parent_folder_uuid: Option<&str>,
+    ) -> Result<String, DocsFolderDatabaseError> {
+        let request = CreateDocsFolderRequest {
+            area_uuid: area_uuid.to_string(),
+            name: name.to_string(),
+            icon_name: None,
+            folder_color: None,
+            parent_folder_uuid: parent_folder_uuid.map(str::to_string),
+            sort_order: None,
+        };
+        create_folder(pool, org_uuid, user_uuid, request, None).await
+    }
+
+    #[sqlx::test]
+    async fn test_sibling_folder_names_must_be_unique(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+
+        let guides = create_named_folder(&pool, &org_uuid, &user_uuid, &area_uuid, "Guides", None)
+            .await
+            .expect("Failed to create folder");
+        let setup = create_named_folder(&pool, &org_uuid, &user_uuid, &area_uuid, "Setup", Some(&guides))
+            .await
+            .expect("Failed to create folder");
+
+        // Same name among siblings is rejected, also with surrounding whitespace
+        assert!(matches!(
+            create_named_folder(&pool, &org_uuid, &user_uuid, &area_uuid, "Guides", None).await,
+            Err(DocsFolderDatabaseError::DuplicateFolderName)
+        ));
+        assert!(matches!(
+            create_named_folder(&pool, &org_uuid, &user_uuid, &area_uuid, " Setup ", Some(&guides)).await,
+            Err(DocsFolderDatabaseError::DuplicateFolderName)
+        ));
+
+        // Same name under a different parent is allowed
+        let root_setup = create_named_folder(&pool, &org_uuid, &user_uuid, &area_uuid, "Setup", None)
+            .await
+            .expect("Same name under a different parent should be allowed");
+
+        // Renaming to a sibling's name is rejected, keeping the own name is allowed
+        let other = create_named_folder(&pool, &org_uuid, &user_uuid, &area_uuid, "Other", Some(&guides))
+            .await
+            .expect("Failed to create folder");
+        assert!(matches!(
+            update_folder_name(&pool, &other, &org_uuid, &user_uuid, "Setup".to_string(), None).await,
+            Err(DocsFolderDatabaseError::DuplicateFolderName)
+        ));
+        update_folder_name(&pool, &setup, &org_uuid, &user_uuid, "Setup".to_string(), None)
+            .await
+            .expect("Keeping the own name should be allowed");
+        update_folder_name(&pool, &other, &org_uuid, &user_uuid, "Guides".to_string(), None)
+            .await
+            .expect("Name of a folder in another parent should be allowed");
+
+        // Moving next to a folder with the same name is rejected
+        assert!(matches!(
+            move_folder(&pool, &root_setup, &org_uuid, &user_uuid, Some(guides.clone()), 0, None).await,
+            Err(DocsFolderDatabaseError::DuplicateFolderName)
+        ));
+        assert_eq!(load_folder_by_uuid(&pool, &root_setup).await.unwrap().parent_folder_uuid, None);
+
+        Ok(())
+    }
+}
@@ -1,31 +1,65 @@
+mod activity;
 mod api;
 mod area;
+mod export;
 mod folder;
+mod member;
 mod page;
+mod page_type;
+mod qa;
+mod search;
+mod stats;
 mod summary;
+#[cfg(test)]
+mod test_support;
 mod tree;
+mod tree_cache;
+mod vector_store;
+mod views;
 
+pub use activity::{
+    DocsActivityAction, DocsActivityDatabaseError, DocsAreaActivity, MAX_AREA_ACTIVITY_LIMIT, list_area_activity,
+};
 pub use area::{
     AreaMemberPermissions, CreateDocsAreaRequest, DocsArea, DocsAreaDatabaseError,
-    UpdateDocsAreaRequest, create_area, delete_area, load_area_by_uuid, update_area,
+    UpdateDocsAreaRequest, archive_area, create_area, delete_area, load_area_by_uuid, unarchive_area,
+    update_area,
 };
+pub use export::{DocsExportError, export_page_pdf};
 pub use folder::{
     CreateDocsFolderRequest, DocsFolder, DocsFolderDatabaseError, MoveDocsFolderRequest, UpdateDocsFolderRequest,
     create_folder, delete_folder, get_all_folders, list_folders, load_folder_by_uuid, move_folder, reorder_folder, update_folder, update_folder_name,
 };
+pub use member::{
+    AddAreaMemberRequest, DocsAreaMemberDatabaseError, add_area_member, remove_area_member,
+    update_area_member_permissions,
+};
 pub use page::{
-    CreateDocsPageRequest, MoveDocsPageRequest, DocsPage, DocsPageDatabaseError, DocsPageVersion,
-    DocsPageWithVersion, create_page, delete_page, generate_page_summary, get_all_pages, get_page_user_permissions,
-    list_pages, list_page_versions, load_page_with_version, move_page, save_page_content, save_page_summary,
-    update_page_properties,
+    AREA_SUMMARY_CONCURRENCY, DEFAULT_MAX_PAGE_CONTENT_BYTES, DEFAULT_PAGE_TEMPLATE, AreaSummaryReport, build_summary_generator, CreateDocsPageRequest, MoveDocsPageRequest, ReorderDocsPagesRequest, DocsPage,
+    DocsPageDatabaseError, DocsPageVersion, DocsPageWithVersion, PageSummaryOutcome, clone_page, create_page, delete_page,
+    generate_area_summaries, generate_page_summary, get_all_pages, get_page_user_permissions,
+    list_pages, list_page_versions, load_page_with_version, max_page_content_bytes, move_page, reorder_pages, save_page_content,
+    save_page_summary, suggest_page_title, update_page_properties, MAX_SUGGESTED_TITLE_LENGTH,
+};
+pub use page_type::{InitialPageContent, PageTypeHandler, PageTypeRegistry, MARKDOWN_PAGE_TYPE};
+pub use qa::{
+    CHUNK_METADATA_INCLUDES_PRIVATE_DATA, CHUNK_METADATA_ORGANIZATION_UUID, CHUNK_METADATA_PAGE_TITLE,
+    CHUNK_METADATA_PAGE_UUID, DEFAULT_QA_TOP_K, DocsPageChunkReference, DocsQaError, DocsQaOptions,
+    find_relevant_page_chunks,
 };
+pub use search::{find_pages_by_metadata, list_stale_pages};
+pub use stats::{docs_stats, DocsStats};
 pub use summary::{
-    ClaudePageSummaryGenerator, GeminiPageSummaryGenerator, OpenAIPageSummaryGenerator,
-    PageSummaryError, PageSummaryGenerator,
+    build_summary_prompt, build_title_prompt, ClaudePageSummaryGenerator, FallbackPageSummaryGenerator, GeminiPageSummaryGenerator, OpenAIPageSummaryGenerator,
+    PageSummaryError, PageSummaryGenerator, SummaryOptions,
 };
+pub use vector_store::{docs_collection_name, DocsVectorStore};
+pub use views::{list_recent_pages, record_page_view, MAX_RECENT_PAGES_LIMIT};
 pub use tree::{
     build_area_tree, DocsAreaTree, DocsTreeError, FolderNode, PageNode, TreeNode, get_area_tree,
+    build_area_tree_with_max_depth, resolve_tree_path, TruncatedNode, DEFAULT_MAX_TREE_DEPTH,
 };
+pub use tree_cache::{AreaTreeCache, AREA_TREE_CACHE_SUBSCRIBER_ID, TREE_INVALIDATING_EVENTS};
 
 use axum::{
     http::StatusCode,
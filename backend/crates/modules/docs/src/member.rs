@@ -0,0 +1,625 @@
+//! Docs Area Member module
+//!
+//! Provides functionality for managing the members of documentation areas and their granular permissions.
+
+use flextide_core::database::{DatabaseError, DatabasePool};
+use flextide_core::events::{Event, EventDispatcher, EventPayload};
+use flextide_core::user::user_belongs_to_organization;
+use serde::Deserialize;
+use serde_json::json;
+use sqlx::Row;
+use thiserror::Error;
+
+use crate::area::{load_area_by_uuid, load_area_member_permissions, AreaMemberPermissions, DocsAreaDatabaseError};
+
+/// Roles that can be assigned to area members; `owner` is reserved for the area creator
+const ASSIGNABLE_ROLES: &[&str] = &["admin", "member", "guest"];
+
+/// Error type for Docs area member database operations
+#[derive(Debug, Error)]
+pub enum DocsAreaMemberDatabaseError {
+    #[error("Database error: {0}")]
+    Database(#[from] DatabaseError),
+
+    #[error("SQL execution error: {0}")]
+    Sql(#[from] sqlx::Error),
+
+    #[error("User does not belong to this organization")]
+    UserNotInOrganization,
+
+    #[error("User does not have permission to perform this action")]
+    PermissionDenied,
+
+    #[error("Area not found")]
+    AreaNotFound,
+
+    #[error("Area does not belong to this organization")]
+    AreaNotInOrganization,
+
+    #[error("Member does not belong to this organization")]
+    MemberNotInOrganization,
+
+    #[error("User is already a member of this area")]
+    MemberAlreadyExists,
+
+    #[error("User is not a member of this area")]
+    MemberNotFound,
+
+    #[error("Invalid area member role: {0}")]
+    InvalidRole(String),
+
+    #[error("The area owner cannot be modified or removed")]
+    CannotModifyOwner,
+}
+
+impl From<DocsAreaDatabaseError> for DocsAreaMemberDatabaseError {
+    fn from(error: DocsAreaDatabaseError) -> Self {
+        match error {
+            DocsAreaDatabaseError::Database(e) => DocsAreaMemberDatabaseError::Database(e),
+            DocsAreaDatabaseError::Sql(e) => DocsAreaMemberDatabaseError::Sql(e),
+            DocsAreaDatabaseError::UserNotInOrganization => DocsAreaMemberDatabaseError::UserNotInOrganization,
+            DocsAreaDatabaseError::PermissionDenied => DocsAreaMemberDatabaseError::PermissionDenied,
+            DocsAreaDatabaseError::AreaNotFound => DocsAreaMemberDatabaseError::AreaNotFound,
+            DocsAreaDatabaseError::AreaNotInOrganization => DocsAreaMemberDatabaseError::AreaNotInOrganization,
+            DocsAreaDatabaseError::EmptyShortName => DocsAreaMemberDatabaseError::Database(
+                DatabaseError::PoolCreationFailed(sqlx::Error::RowNotFound),
+            ),
+        }
+    }
+}
+
+/// Request structure for adding a member to an area
+#[derive(Debug, Deserialize)]
+pub struct AddAreaMemberRequest {
+    pub user_uuid: String,
+    #[serde(flatten)]
+    pub permissions: AreaMemberPermissions,
+}
+
+/// Permission flags in the column order used by the member queries
+fn permission_flags(permissions: &AreaMemberPermissions) -> [i32; 15] {
+    [
+        permissions.can_view,
+        permissions.can_add_pages,
+        permissions.can_edit_pages,
+        permissions.can_edit_own_pages,
+        permissions.can_archive_pages,
+        permissions.can_archive_own_pages,
+        permissions.can_delete_pages,
+        permissions.can_delete_own_pages,
+        permissions.can_export_pages,
+        permissions.can_add_folders,
+        permissions.can_edit_folders,
+        permissions.can_delete_folders,
+        permissions.can_edit_page_properties,
+        permissions.can_edit_folder_properties,
+        permissions.admin,
+    ]
+    .map(i32::from)
+}
+
+/// Verify that the acting user may manage the members of an area
+///
+/// The user must belong to the organization, the area must belong to the organization
+/// and the user must be an admin or the owner of the area.
+async fn verify_area_admin(
+    pool: &DatabasePool,
+    area_uuid: &str,
+    organization_uuid: &str,
+    user_uuid: &str,
+) -> Result<(), DocsAreaMemberDatabaseError> {
+    let belongs = user_belongs_to_organization(pool, user_uuid, organization_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            DocsAreaMemberDatabaseError::Database(e.into())
+        })?;
+
+    if !belongs {
+        return Err(DocsAreaMemberDatabaseError::UserNotInOrganization);
+    }
+
+    let area = load_area_by_uuid(pool, area_uuid).await?;
+
+    if area.organization_uuid != organization_uuid {
+        return Err(DocsAreaMemberDatabaseError::AreaNotInOrganization);
+    }
+
+    match load_area_member_permissions(pool, area_uuid, user_uuid).await? {
+        Some(perms) if perms.admin || perms.role == "owner" => Ok(()),
+        _ => Err(DocsAreaMemberDatabaseError::PermissionDenied),
+    }
+}
+
+/// Validate that a role can be assigned to an area member
+fn validate_role(role: &str) -> Result<(), DocsAreaMemberDatabaseError> {
+    if ASSIGNABLE_ROLES.contains(&role) {
+        Ok(())
+    } else {
+        Err(DocsAreaMemberDatabaseError::InvalidRole(role.to_string()))
+    }
+}
+
+/// Emit an area member event if a dispatcher is available
+async fn emit_member_event(
+    dispatcher: Option<&EventDispatcher>,
+    event_name: &str,
+    area_uuid: &str,
+    organization_uuid: &str,
+    user_uuid: &str,
+    member_uuid: &str,
+    role: Option<&str>,
+) {
+    if let Some(disp) = dispatcher {
+        let event = Event::new(
+            event_name,
+            EventPayload::new(json!({
+                "entity_type": "area_member",
+                "entity_id": member_uuid,
+                "organization_uuid": organization_uuid,
+                "data": json!({
+                    "area_uuid": area_uuid,
+                    "user_uuid": member_uuid,
+                    "role": role
+                })
+            }))
+        )
+        .with_organization(organization_uuid)
+        .with_user(user_uuid);
+
+        disp.emit(event).await;
+    }
+}
+
+/// Add a user as member of an area
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `area_uuid` - UUID of the area
+/// * `organization_uuid` - UUID of the organization (for verification)
+/// * `user_uuid` - UUID of the user adding the member
+/// * `member_uuid` - UUID of the user to add
+/// * `permissions` - Role and permission flags of the new member
+/// * `dispatcher` - Optional event dispatcher
+///
+/// # Errors
+/// Returns `DocsAreaMemberDatabaseError` if:
+/// - User or member does not belong to the organization
+/// - Area does not belong to the organization
+/// - User is not an admin or the owner of the area
+/// - Role is not assignable
+/// - Member already belongs to the area
+/// - Database operation fails
+pub async fn add_area_member(
+    pool: &DatabasePool,
+    area_uuid: &str,
+    organization_uuid: &str,
+    user_uuid: &str,
+    member_uuid: &str,
+    permissions: &AreaMemberPermissions,
+    dispatcher: Option<&EventDispatcher>,
+) -> Result<(), DocsAreaMemberDatabaseError> {
+    verify_area_admin(pool, area_uuid, organization_uuid, user_uuid).await?;
+    validate_role(&permissions.role)?;
+
+    let member_belongs = user_belongs_to_organization(pool, member_uuid, organization_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            DocsAreaMemberDatabaseError::Database(e.into())
+        })?;
+
+    if !member_belongs {
+        return Err(DocsAreaMemberDatabaseError::MemberNotInOrganization);
+    }
+
+    if load_area_member_permissions(pool, area_uuid, member_uuid).await?.is_some() {
+        return Err(DocsAreaMemberDatabaseError::MemberAlreadyExists);
+    }
+
+    let flags = permission_flags(permissions);
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            let mut query = sqlx::query(
+                "INSERT INTO module_docs_area_members
+                 (area_uuid, user_uuid, role, can_view, can_add_pages, can_edit_pages,
+                  can_edit_own_pages, can_archive_pages, can_archive_own_pages,
+                  can_delete_pages, can_delete_own_pages, can_export_pages,
+                  can_add_folders, can_edit_folders, can_delete_folders,
+                  can_edit_page_properties, can_edit_folder_properties, admin, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)",
+            )
+            .bind(area_uuid)
+            .bind(member_uuid)
+            .bind(&permissions.role);
+            for flag in flags {
+                query = query.bind(flag);
+            }
+            query.execute(p).await?;
+        }
+        DatabasePool::Postgres(p) => {
+            let mut query = sqlx::query(
+                "INSERT INTO module_docs_area_members
+                 (area_uuid, user_uuid, role, can_view, can_add_pages, can_edit_pages,
+                  can_edit_own_pages, can_archive_pages, can_archive_own_pages,
+                  can_delete_pages, can_delete_own_pages, can_export_pages,
+                  can_add_folders, can_edit_folders, can_delete_folders,
+                  can_edit_page_properties, can_edit_folder_properties, admin, created_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, CURRENT_TIMESTAMP)",
+            )
+            .bind(area_uuid)
+            .bind(member_uuid)
+            .bind(&permissions.role);
+            for flag in flags {
+                query = query.bind(flag);
+            }
+            query.execute(p).await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            let mut query = sqlx::query(
+                "INSERT INTO module_docs_area_members
+                 (area_uuid, user_uuid, role, can_view, can_add_pages, can_edit_pages,
+                  can_edit_own_pages, can_archive_pages, can_archive_own_pages,
+                  can_delete_pages, can_delete_own_pages, can_export_pages,
+                  can_add_folders, can_edit_folders, can_delete_folders,
+                  can_edit_page_properties, can_edit_folder_properties, admin, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, CURRENT_TIMESTAMP)",
+            )
+            .bind(area_uuid)
+            .bind(member_uuid)
+            .bind(&permissions.role);
+            for flag in flags {
+                query = query.bind(flag);
+            }
+            query.execute(p).await?;
+        }
+    }
+
+    emit_member_event(
+        dispatcher,
+        "module_docs_area_member_added",
+        area_uuid,
+        organization_uuid,
+        user_uuid,
+        member_uuid,
+        Some(&permissions.role),
+    )
+    .await;
+
+    Ok(())
+}
+
+/// Update the role and permission flags of an area member
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `area_uuid` - UUID of the area
+/// * `organization_uuid` - UUID of the organization (for verification)
+/// * `user_uuid` - UUID of the user updating the member
+/// * `member_uuid` - UUID of the member to update
+/// * `permissions` - New role and permission flags of the member
+/// * `dispatcher` - Optional event dispatcher
+///
+/// # Errors
+/// Returns `DocsAreaMemberDatabaseError` if:
+/// - User does not belong to the organization
+/// - Area does not belong to the organization
+/// - User is not an admin or the owner of the area
+/// - Role is not assignable
+/// - Member does not belong to the area or is the area owner
+/// - Database operation fails
+pub async fn update_area_member_permissions(
+    pool: &DatabasePool,
+    area_uuid: &str,
+    organization_uuid: &str,
+    user_uuid: &str,
+    member_uuid: &str,
+    permissions: &AreaMemberPermissions,
+    dispatcher: Option<&EventDispatcher>,
+) -> Result<(), DocsAreaMemberDatabaseError> {
+    verify_area_admin(pool, area_uuid, organization_uuid, user_uuid).await?;
+    validate_role(&permissions.role)?;
+
+    match load_area_member_permissions(pool, area_uuid, member_uuid).await? {
+        None => return Err(DocsAreaMemberDatabaseError::MemberNotFound),
+        Some(existing) if existing.role == "owner" => {
+            return Err(DocsAreaMemberDatabaseError::CannotModifyOwner)
+        }
+        Some(_) => {}
+    }
+
+    let flags = permission_flags(permissions);
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            let mut query = sqlx::query(
+                "UPDATE module_docs_area_members
+                 SET role = ?, can_view = ?, can_add_pages = ?, can_edit_pages = ?,
+                     can_edit_own_pages = ?, can_archive_pages = ?, can_archive_own_pages = ?,
+                     can_delete_pages = ?, can_delete_own_pages = ?, can_export_pages = ?,
+                     can_add_folders = ?, can_edit_folders = ?, can_delete_folders = ?,
+                     can_edit_page_properties = ?, can_edit_folder_properties = ?, admin = ?
+                 WHERE area_uuid = ? AND user_uuid = ?",
+            )
+            .bind(&permissions.role);
+            for flag in flags {
+                query = query.bind(flag);
+            }
+            query.bind(area_uuid).bind(member_uuid).execute(p).await?;
+        }
+        DatabasePool::Postgres(p) => {
+            let mut query = sqlx::query(
+                "UPDATE module_docs_area_members
+                 SET role = $1, can_view = $2, can_add_pages = $3, can_edit_pages = $4,
+                     can_edit_own_pages = $5, can_archive_pages = $6, can_archive_own_pages = $7,
+                     can_delete_pages = $8, can_delete_own_pages = $9, can_export_pages = $10,
+                     can_add_folders = $11, can_edit_folders = $12, can_delete_folders = $13,
+                     can_edit_page_properties = $14, can_edit_folder_properties = $15, admin = $16
+                 WHERE area_uuid = $17 AND user_uuid = $18",
+            )
+            .bind(&permissions.role);
+            for flag in flags {
+                query = query.bind(flag);
+            }
+            query.bind(area_uuid).bind(member_uuid).execute(p).await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            let mut query = sqlx::query(
+                "UPDATE module_docs_area_members
+                 SET role = ?1, can_view = ?2, can_add_pages = ?3, can_edit_pages = ?4,
+                     can_edit_own_pages = ?5, can_archive_pages = ?6, can_archive_own_pages = ?7,
+                     can_delete_pages = ?8, can_delete_own_pages = ?9, can_export_pages = ?10,
+                     can_add_folders = ?11, can_edit_folders = ?12, can_delete_folders = ?13,
+                     can_edit_page_properties = ?14, can_edit_folder_properties = ?15, admin = ?16
+                 WHERE area_uuid = ?17 AND user_uuid = ?18",
+            )
+            .bind(&permissions.role);
+            for flag in flags {
+                query = query.bind(flag);
+            }
+            query.bind(area_uuid).bind(member_uuid).execute(p).await?;
+        }
+    }
+
+    emit_member_event(
+        dispatcher,
+        "module_docs_area_member_updated",
+        area_uuid,
+        organization_uuid,
+        user_uuid,
+        member_uuid,
+        Some(&permissions.role),
+    )
+    .await;
+
+    Ok(())
+}
+
+/// Remove a member from an area
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `area_uuid` - UUID of the area
+/// * `organization_uuid` - UUID of the organization (for verification)
+/// * `user_uuid` - UUID of the user removing the member
+/// * `member_uuid` - UUID of the member to remove
+/// * `dispatcher` - Optional event dispatcher
+///
+/// # Errors
+/// Returns `DocsAreaMemberDatabaseError` if:
+/// - User does not belong to the organization
+/// - Area does not belong to the organization
+/// - User is not an admin or the owner of the area
+/// - Member does not belong to the area or is the area owner
+/// - Database operation fails
+pub async fn remove_area_member(
+    pool: &DatabasePool,
+    area_uuid: &str,
+    organization_uuid: &str,
+    user_uuid: &str,
+    member_uuid: &str,
+    dispatcher: Option<&EventDispatcher>,
+) -> Result<(), DocsAreaMemberDatabaseError> {
+    verify_area_admin(pool, area_uuid, organization_uuid, user_uuid).await?;
+
+    let role = match pool {
+        DatabasePool::MySql(p) => sqlx::query(
+            "SELECT role FROM module_docs_area_members WHERE area_uuid = ? AND user_uuid = ?",
+        )
+        .bind(area_uuid)
+        .bind(member_uuid)
+        .fetch_optional(p)
+        .await?
+        .map(|row| row.get::<String, _>("role")),
+        DatabasePool::Postgres(p) => sqlx::query(
+            "SELECT role FROM module_docs_area_members WHERE area_uuid = $1 AND user_uuid = $2",
+        )
+        .bind(area_uuid)
+        .bind(member_uuid)
+        .fetch_optional(p)
+        .await?
+        .map(|row| row.get::<String, _>("role")),
+        DatabasePool::Sqlite(p) => sqlx::query(
+            "SELECT role FROM module_docs_area_members WHERE area_uuid = ?1 AND user_uuid = ?2",
+        )
+        .bind(area_uuid)
+        .bind(member_uuid)
+        .fetch_optional(p)
+        .await?
+        .map(|row| row.get::<String, _>("role")),
+    };
+
+    match role.as_deref() {
+        None => return Err(DocsAreaMemberDatabaseError::MemberNotFound),
+        Some("owner") => return Err(DocsAreaMemberDatabaseError::CannotModifyOwner),
+        Some(_) => {}
+    }
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query("DELETE FROM module_docs_area_members WHERE area_uuid = ? AND user_uuid = ?")
+                .bind(area_uuid)
+                .bind(member_uuid)
+                .execute(p)
+                .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query("DELETE FROM module_docs_area_members WHERE area_uuid = $1 AND user_uuid = $2")
+                .bind(area_uuid)
+                .bind(member_uuid)
+                .execute(p)
+                .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query("DELETE FROM module_docs_area_members WHERE area_uuid = ?1 AND user_uuid = ?2")
+                .bind(area_uuid)
+                .bind(member_uuid)
+                .execute(p)
+                .await?;
+        }
+    }
+
+    emit_member_event(
+        dispatcher,
+        "module_docs_area_member_removed",
+        area_uuid,
+        organization_uuid,
+        user_uuid,
+        member_uuid,
+        None,
+    )
+    .await;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::page::{save_page_content, DocsPageDatabaseError};
+    use crate::test_support::{
+        add_organization_member, create_area, create_organization, create_page, setup_tables,
+    };
+
+    fn member_permissions(can_edit_pages: bool) -> AreaMemberPermissions {
+        AreaMemberPermissions {
+            role: "member".to_string(),
+            can_view: true,
+            can_edit_pages,
+            ..Default::default()
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_can_edit_pages_is_applied_to_save_page_content(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = uuid::Uuid::new_v4().to_string();
+        let member_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        add_organization_member(&pool, &org_uuid, &member_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &owner_uuid).await;
+        let page_uuid = create_page(&pool, &org_uuid, &area_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        add_area_member(&pool, &area_uuid, &org_uuid, &owner_uuid, &member_uuid, &member_permissions(false), None)
+            .await
+            .expect("Failed to add area member");
+        assert!(matches!(
+            save_page_content(&pool, &org_uuid, &page_uuid, &member_uuid, "first", &dispatcher).await,
+            Err(DocsPageDatabaseError::PermissionDenied)
+        ));
+
+        update_area_member_permissions(
+            &pool, &area_uuid, &org_uuid, &owner_uuid, &member_uuid, &member_permissions(true), None,
+        )
+        .await
+        .expect("Failed to update area member");
+        save_page_content(&pool, &org_uuid, &page_uuid, &member_uuid, "first", &dispatcher)
+            .await
+            .expect("Member with can_edit_pages should be able to save");
+
+        update_area_member_permissions(
+            &pool, &area_uuid, &org_uuid, &owner_uuid, &member_uuid, &member_permissions(false), None,
+        )
+        .await
+        .expect("Failed to update area member");
+        assert!(matches!(
+            save_page_content(&pool, &org_uuid, &page_uuid, &member_uuid, "second", &dispatcher).await,
+            Err(DocsPageDatabaseError::PermissionDenied)
+        ));
+
+        remove_area_member(&pool, &area_uuid, &org_uuid, &owner_uuid, &member_uuid, None)
+            .await
+            .expect("Failed to remove area member");
+        assert!(load_area_member_permissions(&pool, &area_uuid, &member_uuid).await.unwrap().is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_member_management_requires_area_admin(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let owner_uuid = uuid::Uuid::new_v4().to_string();
+        let member_uuid = uuid::Uuid::new_v4().to_string();
+        let other_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &owner_uuid).await;
+        add_organization_member(&pool, &org_uuid, &member_uuid).await;
+        add_organization_member(&pool, &org_uuid, &other_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &owner_uuid).await;
+
+        add_area_member(&pool, &area_uuid, &org_uuid, &owner_uuid, &member_uuid, &member_permissions(true), None)
+            .await
+            .expect("Failed to add area member");
+
+        // A regular member cannot manage other members
+        assert!(matches!(
+            add_area_member(&pool, &area_uuid, &org_uuid, &member_uuid, &other_uuid, &member_permissions(true), None)
+                .await,
+            Err(DocsAreaMemberDatabaseError::PermissionDenied)
+        ));
+
+        // Promoting the member to admin allows managing members
+        let admin_permissions = AreaMemberPermissions {
+            role: "admin".to_string(),
+            admin: true,
+            ..member_permissions(true)
+        };
+        update_area_member_permissions(
+            &pool, &area_uuid, &org_uuid, &owner_uuid, &member_uuid, &admin_permissions, None,
+        )
+        .await
+        .expect("Failed to promote area member");
+        add_area_member(&pool, &area_uuid, &org_uuid, &member_uuid, &other_uuid, &member_permissions(true), None)
+            .await
+            .expect("Area admin should be able to add members");
+
+        // Duplicates, unknown roles and the owner are rejected
+        assert!(matches!(
+            add_area_member(&pool, &area_uuid, &org_uuid, &owner_uuid, &other_uuid, &member_permissions(true), None)
+                .await,
+            Err(DocsAreaMemberDatabaseError::MemberAlreadyExists)
+        ));
+        let owner_permissions = AreaMemberPermissions {
+            role: "owner".to_string(),
+            ..member_permissions(true)
+        };
+        assert!(matches!(
+            update_area_member_permissions(
+                &pool, &area_uuid, &org_uuid, &owner_uuid, &other_uuid, &owner_permissions, None,
+            )
+            .await,
+            Err(DocsAreaMemberDatabaseError::InvalidRole(_))
+        ));
+        assert!(matches!(
+            remove_area_member(&pool, &area_uuid, &org_uuid, &member_uuid, &owner_uuid, None).await,
+            Err(DocsAreaMemberDatabaseError::CannotModifyOwner)
+        ));
+
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@
 
 use chrono::{DateTime, Utc};
 use flextide_core::database::{DatabaseError, DatabasePool};
-use flextide_core::events::{Event, EventDispatcher, EventPayload};
+use flextide_core::events::{field_changes, Event, EventDispatcher, EventPayload};
 use flextide_core::settings::{get_organizational_setting_value, SettingsDatabaseError};
 use flextide_core::user::{user_belongs_to_organization, user_has_permission};
 use serde::{Deserialize, Serialize};
@@ -13,6 +13,11 @@ use sqlx::Row;
 use thiserror::Error;
 use tracing::{error, info, warn};
 
+use crate::activity::{
+    record_area_activity_mysql, record_area_activity_postgres, record_area_activity_sqlite, DocsActivityAction,
+};
+use crate::page_type::{InitialPageContent, PageTypeRegistry, MARKDOWN_PAGE_TYPE};
+use crate::vector_store::DocsVectorStore;
 use crate::area::{
     load_area_by_uuid, load_area_member_permissions, AreaMemberPermissions, DocsAreaDatabaseError,
 };
@@ -62,8 +67,55 @@ pub enum DocsPageDatabaseError {
     #[error("Unsupported AI provider: {0}")]
     UnsupportedAIProvider(String),
 
+    #[error("Summary max words must be a positive integer, got: {0}")]
+    InvalidSummaryMaxWords(String),
+
+    #[error("Summary calls per minute must be a positive integer, got: {0}")]
+    InvalidSummaryRateLimit(String),
+
+    #[error("Summary generation rate limit exceeded, retry in {retry_after_seconds} seconds")]
+    RateLimited { retry_after_seconds: u64 },
+
     #[error("Summary generation error: {0}")]
     SummaryGeneration(#[from] crate::summary::PageSummaryError),
+
+    #[error("Page content is too large ({length} bytes, maximum {max_length} bytes)")]
+    ContentTooLarge { length: usize, max_length: usize },
+
+    #[error("Page template setting must not be empty")]
+    EmptyPageTemplate,
+
+    #[error("Invalid page content: {0}")]
+    InvalidPageContent(String),
+
+    #[error("Folder not found")]
+    FolderNotFound,
+
+    #[error("Page {0} is not in the folder")]
+    PageNotInFolder(String),
+
+    #[error("Page {0} is listed more than once")]
+    DuplicatePageInOrder(String),
+
+    #[error("Number of days is out of range: {0}")]
+    InvalidDays(u32),
+
+    #[error("Area short name cannot be empty")]
+    EmptyAreaShortName,
+}
+
+impl From<DocsAreaDatabaseError> for DocsPageDatabaseError {
+    fn from(error: DocsAreaDatabaseError) -> Self {
+        match error {
+            DocsAreaDatabaseError::Database(e) => DocsPageDatabaseError::Database(e),
+            DocsAreaDatabaseError::Sql(e) => DocsPageDatabaseError::Sql(e),
+            DocsAreaDatabaseError::UserNotInOrganization => DocsPageDatabaseError::UserNotInOrganization,
+            DocsAreaDatabaseError::PermissionDenied => DocsPageDatabaseError::PermissionDenied,
+            DocsAreaDatabaseError::AreaNotFound => DocsPageDatabaseError::AreaNotFound,
+            DocsAreaDatabaseError::AreaNotInOrganization => DocsPageDatabaseError::AreaNotInOrganization,
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
+        }
+    }
 }
 
 /// Docs Page data structure
@@ -107,6 +159,12 @@ pub struct MoveDocsPageRequest {
     pub sort_order: i32,
 }
 
+/// Request structure for reordering the pages of a folder
+#[derive(Debug, Deserialize)]
+pub struct ReorderDocsPagesRequest {
+    pub page_uuids: Vec<String>,
+}
+
 /// Docs Page Version data structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocsPageVersion {
@@ -174,7 +232,7 @@ async fn load_and_verify_page_ownership(
 }
 
 /// Load a page by UUID
-async fn load_page_by_uuid(
+pub(crate) async fn load_page_by_uuid(
     pool: &DatabasePool,
     page_uuid: &str,
 ) -> Result<DocsPage, DocsPageDatabaseError> {
@@ -280,6 +338,9 @@ async fn load_page_by_uuid(
 
 /// Create a new page in the database
 ///
+/// The initial version of the page is defined by the handler of its page type in
+/// `PageTypeRegistry::global()`; pages of unregistered types are created without a version.
+///
 /// # Arguments
 /// * `pool` - Database connection pool
 /// * `organization_uuid` - UUID of the organization the page belongs to
@@ -295,6 +356,8 @@ async fn load_page_by_uuid(
 /// - User does not have permission to create pages
 /// - Area does not belong to the organization
 /// - Title is empty
+/// - The organization's page template setting is empty (`EmptyPageTemplate`)
+/// - The initial content is rejected by the handler of the page type (`InvalidPageContent`)
 /// - Database operation fails
 pub async fn create_page(
     pool: &DatabasePool,
@@ -302,6 +365,20 @@ pub async fn create_page(
     user_uuid: &str,
     request: CreateDocsPageRequest,
     dispatcher: &EventDispatcher,
+) -> Result<String, DocsPageDatabaseError> {
+    create_page_with_content(pool, organization_uuid, user_uuid, request, None, dispatcher).await
+}
+
+/// Create a new page, optionally with the content of its initial version
+///
+/// Without `initial_content`, markdown pages start with the template content.
+async fn create_page_with_content(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    user_uuid: &str,
+    request: CreateDocsPageRequest,
+    initial_content: Option<&str>,
+    dispatcher: &EventDispatcher,
 ) -> Result<String, DocsPageDatabaseError> {
     // Validate title
     if request.title.trim().is_empty() {
@@ -334,13 +411,7 @@ pub async fn create_page(
             DocsAreaDatabaseError::AreaNotInOrganization => {
                 DocsPageDatabaseError::AreaNotInOrganization
             }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
         })?;
 
     if area.organization_uuid != organization_uuid {
@@ -361,13 +432,7 @@ pub async fn create_page(
             DocsAreaDatabaseError::AreaNotInOrganization => {
                 DocsPageDatabaseError::AreaNotInOrganization
             }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
         })?;
 
     // Check if user has permission to add pages
@@ -475,13 +540,36 @@ pub async fn create_page(
     let page_uuid = uuid::Uuid::new_v4().to_string();
     let page_type = request
         .page_type
-        .unwrap_or_else(|| "markdown_page".to_string());
+        .unwrap_or_else(|| MARKDOWN_PAGE_TYPE.to_string());
+    let handler = PageTypeRegistry::global().get(&page_type);
+
+    let initial_version = match initial_content {
+        Some(content) => InitialPageContent::Content(content.to_string()),
+        None => handler
+            .as_ref()
+            .map(|handler| handler.initial_content(&request.title))
+            .unwrap_or(InitialPageContent::None),
+    };
+    if let (InitialPageContent::Content(content), Some(handler)) = (&initial_version, &handler) {
+        handler
+            .validate_content(content)
+            .map_err(DocsPageDatabaseError::InvalidPageContent)?;
+    }
+
+    // Load the template before inserting, so an invalid template does not leave a page without content
+    let template = match initial_version {
+        InitialPageContent::PageTemplate => Some(load_page_template(pool, organization_uuid).await?),
+        _ => None,
+    };
 
     match pool {
         DatabasePool::MySql(p) => {
+            let mut tx = p.begin().await?;
+            let sort_order =
+                next_page_sort_order_mysql(&mut tx, &request.area_uuid, request.folder_uuid.as_deref()).await?;
             sqlx::query(
-                "INSERT INTO module_docs_pages (uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid, page_type, auto_sync_to_vector_db, vcs_export_allowed, includes_private_data)
-                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                "INSERT INTO module_docs_pages (uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid, page_type, auto_sync_to_vector_db, vcs_export_allowed, includes_private_data, sort_order)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             )
             .bind(&page_uuid)
             .bind(organization_uuid)
@@ -494,13 +582,28 @@ pub async fn create_page(
             .bind(if auto_sync_to_vector_db { 1 } else { 0 })
             .bind(if vcs_export_allowed { 1 } else { 0 })
             .bind(if includes_private_data { 1 } else { 0 })
-            .execute(p)
+            .bind(sort_order)
+            .execute(&mut *tx)
             .await?;
+            record_area_activity_mysql(
+                &mut tx,
+                organization_uuid,
+                &request.area_uuid,
+                user_uuid,
+                DocsActivityAction::PageCreated,
+                &page_uuid,
+                &request.title,
+            )
+            .await?;
+            tx.commit().await?;
         }
         DatabasePool::Postgres(p) => {
+            let mut tx = p.begin().await?;
+            let sort_order =
+                next_page_sort_order_postgres(&mut tx, &request.area_uuid, request.folder_uuid.as_deref()).await?;
             sqlx::query(
-                "INSERT INTO module_docs_pages (uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid, page_type, auto_sync_to_vector_db, vcs_export_allowed, includes_private_data)
-                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+                "INSERT INTO module_docs_pages (uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid, page_type, auto_sync_to_vector_db, vcs_export_allowed, includes_private_data, sort_order)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
             )
             .bind(&page_uuid)
             .bind(organization_uuid)
@@ -513,13 +616,28 @@ pub async fn create_page(
             .bind(if auto_sync_to_vector_db { 1 } else { 0 })
             .bind(if vcs_export_allowed { 1 } else { 0 })
             .bind(if includes_private_data { 1 } else { 0 })
-            .execute(p)
+            .bind(sort_order)
+            .execute(&mut *tx)
+            .await?;
+            record_area_activity_postgres(
+                &mut tx,
+                organization_uuid,
+                &request.area_uuid,
+                user_uuid,
+                DocsActivityAction::PageCreated,
+                &page_uuid,
+                &request.title,
+            )
             .await?;
+            tx.commit().await?;
         }
         DatabasePool::Sqlite(p) => {
+            let mut tx = p.begin().await?;
+            let sort_order =
+                next_page_sort_order_sqlite(&mut tx, &request.area_uuid, request.folder_uuid.as_deref()).await?;
             sqlx::query(
-                "INSERT INTO module_docs_pages (uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid, page_type, auto_sync_to_vector_db, vcs_export_allowed, includes_private_data)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                "INSERT INTO module_docs_pages (uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid, page_type, auto_sync_to_vector_db, vcs_export_allowed, includes_private_data, sort_order)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
             )
             .bind(&page_uuid)
             .bind(organization_uuid)
@@ -532,14 +650,32 @@ pub async fn create_page(
             .bind(if auto_sync_to_vector_db { 1 } else { 0 })
             .bind(if vcs_export_allowed { 1 } else { 0 })
             .bind(if includes_private_data { 1 } else { 0 })
-            .execute(p)
+            .bind(sort_order)
+            .execute(&mut *tx)
+            .await?;
+            record_area_activity_sqlite(
+                &mut tx,
+                organization_uuid,
+                &request.area_uuid,
+                user_uuid,
+                DocsActivityAction::PageCreated,
+                &page_uuid,
+                &request.title,
+            )
             .await?;
+            tx.commit().await?;
         }
     }
 
-    // Create initial version with template content (only for markdown_page type)
-    if page_type == "markdown_page" {
-        create_initial_page_version(pool, &page_uuid, &request.title).await?;
+    // Create the initial version defined by the page type
+    match (initial_version, template) {
+        (InitialPageContent::Content(content), _) => {
+            insert_initial_page_version(pool, &page_uuid, &content).await?;
+        }
+        (InitialPageContent::PageTemplate, Some(template)) => {
+            create_initial_page_version(pool, &page_uuid, &request.title, &template).await?;
+        }
+        _ => {}
     }
 
     // Emit page created event
@@ -568,12 +704,42 @@ pub async fn create_page(
     Ok(page_uuid)
 }
 
+/// Initial content of new pages if the organization did not configure a template
+pub const DEFAULT_PAGE_TEMPLATE: &str = "# {{title}}\n\n\n\n\n";
+
+/// Placeholder in page templates replaced by the page title
+const PAGE_TEMPLATE_TITLE_PLACEHOLDER: &str = "{{title}}";
+
+/// Load the template for the initial content of new pages of an organization
+///
+/// The template is configured through the `module_docs_page_template` setting. If the
+/// setting is not defined or has no value, `DEFAULT_PAGE_TEMPLATE` is used.
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - The setting is set to an empty or whitespace-only template (`EmptyPageTemplate`)
+/// - Loading the setting fails
+async fn load_page_template(pool: &DatabasePool, organization_uuid: &str) -> Result<String, DocsPageDatabaseError> {
+    let template = match get_organizational_setting_value(pool, organization_uuid, "module_docs_page_template").await {
+        Ok(template) => template,
+        Err(SettingsDatabaseError::SettingNotFound(_)) => None,
+        Err(e) => return Err(e.into()),
+    };
+
+    match template {
+        Some(template) if template.trim().is_empty() => Err(DocsPageDatabaseError::EmptyPageTemplate),
+        Some(template) => Ok(template),
+        None => Ok(DEFAULT_PAGE_TEMPLATE.to_string()),
+    }
+}
+
 /// Create an initial version for a page with template content
 ///
 /// # Arguments
 /// * `pool` - Database connection pool
 /// * `page_uuid` - UUID of the page
-/// * `page_title` - Title of the page (used in template)
+/// * `page_title` - Title of the page (replaces `{{title}}` in the template)
+/// * `template` - Template of the initial content
 ///
 /// # Returns
 /// Returns the UUID of the created version
@@ -584,9 +750,32 @@ async fn create_initial_page_version(
     pool: &DatabasePool,
     page_uuid: &str,
     page_title: &str,
+    template: &str,
+) -> Result<String, DocsPageDatabaseError> {
+    let template_content = template.replace(PAGE_TEMPLATE_TITLE_PLACEHOLDER, page_title);
+    let version_uuid = insert_initial_page_version(pool, page_uuid, &template_content).await?;
+
+    info!(
+        "Created initial version {} for page {} with template content",
+        version_uuid, page_uuid
+    );
+
+    Ok(version_uuid)
+}
+
+/// Insert the first version of a page and make it the page's current version
+///
+/// # Returns
+/// Returns the UUID of the created version
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if database operation fails
+async fn insert_initial_page_version(
+    pool: &DatabasePool,
+    page_uuid: &str,
+    content: &str,
 ) -> Result<String, DocsPageDatabaseError> {
     let version_uuid = uuid::Uuid::new_v4().to_string();
-    let template_content = format!("# {}\n\n\n\n\n", page_title);
     let now = Utc::now();
 
     match pool {
@@ -599,7 +788,7 @@ async fn create_initial_page_version(
             .bind(&version_uuid)
             .bind(page_uuid)
             .bind(1) // First version
-            .bind(&template_content)
+            .bind(content)
             .bind(now)
             .bind(now)
             .execute(p)
@@ -624,7 +813,7 @@ async fn create_initial_page_version(
             .bind(&version_uuid)
             .bind(page_uuid)
             .bind(1) // First version
-            .bind(&template_content)
+            .bind(content)
             .bind(now)
             .bind(now)
             .execute(p)
@@ -649,7 +838,7 @@ async fn create_initial_page_version(
             .bind(&version_uuid)
             .bind(page_uuid)
             .bind(1) // First version
-            .bind(&template_content)
+            .bind(content)
             .bind(now)
             .bind(now)
             .execute(p)
@@ -667,14 +856,97 @@ async fn create_initial_page_version(
         }
     }
 
-    info!(
-        "Created initial version {} for page {} with template content",
-        version_uuid, page_uuid
-    );
-
     Ok(version_uuid)
 }
 
+/// Create a copy of a page in the same area and folder
+///
+/// The copy starts a fresh version history: its initial version holds the content of the
+/// source page's current version. Flags and metadata are copied, the copy is not nested
+/// under the source's parent page.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization
+/// * `user_uuid` - UUID of the user cloning the page
+/// * `source_page_uuid` - UUID of the page to copy
+/// * `new_title` - Title of the new page
+/// * `dispatcher` - Event dispatcher
+///
+/// # Returns
+/// Returns the UUID of the newly created page
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - User does not belong to the organization
+/// - User does not have permission to create pages in the source page's area
+/// - Source page not found or doesn't belong to the organization
+/// - Title is empty
+/// - Database operation fails
+pub async fn clone_page(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    user_uuid: &str,
+    source_page_uuid: &str,
+    new_title: &str,
+    dispatcher: &EventDispatcher,
+) -> Result<String, DocsPageDatabaseError> {
+    let source = load_page_with_version(pool, source_page_uuid).await?;
+
+    if source.organization_uuid != organization_uuid {
+        warn!(
+            "Page {} does not belong to organization {}",
+            source_page_uuid, organization_uuid
+        );
+        return Err(DocsPageDatabaseError::PageNotInOrganization);
+    }
+
+    let request = CreateDocsPageRequest {
+        area_uuid: source.area_uuid.clone(),
+        title: new_title.to_string(),
+        short_summary: source.short_summary.clone(),
+        folder_uuid: source.folder_uuid.clone(),
+        parent_page_uuid: None,
+        page_type: Some(source.page_type.clone()),
+        auto_sync_to_vector_db: Some(source.auto_sync_to_vector_db != 0),
+        vcs_export_allowed: Some(source.vcs_export_allowed != 0),
+        includes_private_data: Some(source.includes_private_data != 0),
+    };
+
+    let content = source.version.as_ref().map(|version| version.content.as_str());
+    let page_uuid =
+        create_page_with_content(pool, organization_uuid, user_uuid, request, content, dispatcher)
+            .await?;
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query("UPDATE module_docs_pages SET metadata = ? WHERE uuid = ?")
+                .bind(&source.metadata)
+                .bind(&page_uuid)
+                .execute(p)
+                .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query("UPDATE module_docs_pages SET metadata = $1 WHERE uuid = $2")
+                .bind(&source.metadata)
+                .bind(&page_uuid)
+                .execute(p)
+                .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query("UPDATE module_docs_pages SET metadata = ?1 WHERE uuid = ?2")
+                .bind(&source.metadata)
+                .bind(&page_uuid)
+                .execute(p)
+                .await?;
+        }
+    }
+
+    info!("Cloned page {} to {}", source_page_uuid, page_uuid);
+
+    Ok(page_uuid)
+}
+
 /// Delete a page from the database
 ///
 /// # Arguments
@@ -682,6 +954,8 @@ async fn create_initial_page_version(
 /// * `page_uuid` - UUID of the page to delete
 /// * `organization_uuid` - UUID of the organization
 /// * `user_uuid` - UUID of the user deleting the page
+/// * `vector_store` - Vector database of the page vectors; if given and the page has
+///   `auto_sync_to_vector_db` set, its chunks are removed as well
 ///
 /// # Returns
 /// Returns `()` on success
@@ -693,12 +967,15 @@ async fn create_initial_page_version(
 /// - Page does not belong to the organization
 /// - Page not found
 /// - Database operation fails
+///
+/// Failing to remove the chunks is logged but does not fail the deletion.
 pub async fn delete_page(
     pool: &DatabasePool,
     page_uuid: &str,
     organization_uuid: &str,
     user_uuid: &str,
     dispatcher: &EventDispatcher,
+    vector_store: Option<&DocsVectorStore>,
 ) -> Result<(), DocsPageDatabaseError> {
     // Check if user belongs to organization
     let belongs = user_belongs_to_organization(pool, user_uuid, organization_uuid)
@@ -730,13 +1007,7 @@ pub async fn delete_page(
             DocsAreaDatabaseError::AreaNotInOrganization => {
                 DocsPageDatabaseError::AreaNotInOrganization
             }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
         })?;
 
     if area.organization_uuid != organization_uuid {
@@ -757,13 +1028,7 @@ pub async fn delete_page(
             DocsAreaDatabaseError::AreaNotInOrganization => {
                 DocsPageDatabaseError::AreaNotInOrganization
             }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
         })?;
 
     // Check if user has permission to delete pages
@@ -810,34 +1075,79 @@ pub async fn delete_page(
     // Delete page (cascade will delete page_versions)
     match pool {
         DatabasePool::MySql(p) => {
+            let mut tx = p.begin().await?;
             let result = sqlx::query("DELETE FROM module_docs_pages WHERE uuid = ?")
                 .bind(page_uuid)
-                .execute(p)
+                .execute(&mut *tx)
                 .await?;
 
             if result.rows_affected() == 0 {
                 return Err(DocsPageDatabaseError::PageNotFound);
             }
+
+            record_area_activity_mysql(
+                &mut tx,
+                organization_uuid,
+                &page.area_uuid,
+                user_uuid,
+                DocsActivityAction::PageDeleted,
+                page_uuid,
+                &page.title,
+            )
+            .await?;
+            tx.commit().await?;
         }
         DatabasePool::Postgres(p) => {
+            let mut tx = p.begin().await?;
             let result = sqlx::query("DELETE FROM module_docs_pages WHERE uuid = $1")
                 .bind(page_uuid)
-                .execute(p)
+                .execute(&mut *tx)
                 .await?;
 
             if result.rows_affected() == 0 {
                 return Err(DocsPageDatabaseError::PageNotFound);
             }
+
+            record_area_activity_postgres(
+                &mut tx,
+                organization_uuid,
+                &page.area_uuid,
+                user_uuid,
+                DocsActivityAction::PageDeleted,
+                page_uuid,
+                &page.title,
+            )
+            .await?;
+            tx.commit().await?;
         }
         DatabasePool::Sqlite(p) => {
+            let mut tx = p.begin().await?;
             let result = sqlx::query("DELETE FROM module_docs_pages WHERE uuid = ?1")
                 .bind(page_uuid)
-                .execute(p)
+                .execute(&mut *tx)
                 .await?;
 
             if result.rows_affected() == 0 {
                 return Err(DocsPageDatabaseError::PageNotFound);
             }
+
+            record_area_activity_sqlite(
+                &mut tx,
+                organization_uuid,
+                &page.area_uuid,
+                user_uuid,
+                DocsActivityAction::PageDeleted,
+                page_uuid,
+                &page.title,
+            )
+            .await?;
+            tx.commit().await?;
+        }
+    }
+
+    if let Some(vector_store) = vector_store.filter(|_| page.auto_sync_to_vector_db != 0) {
+        if let Err(e) = vector_store.delete_page(organization_uuid, page_uuid).await {
+            tracing::warn!("Failed to delete vector chunks of page {}: {}", page_uuid, e);
         }
     }
 
@@ -905,13 +1215,7 @@ pub async fn get_page_user_permissions(
             DocsAreaDatabaseError::AreaNotInOrganization => {
                 DocsPageDatabaseError::AreaNotInOrganization
             }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
         })?;
 
     Ok(permissions)
@@ -927,7 +1231,8 @@ pub async fn get_page_user_permissions(
 /// * `user_uuid` - UUID of the user requesting the pages
 ///
 /// # Returns
-/// Returns a vector of pages that the user has permission to view
+/// Returns a vector of pages that the user has permission to view, ordered by sort order
+/// (see [`reorder_pages`]) and then newest first
 ///
 /// # Errors
 /// Returns `DocsPageDatabaseError` if:
@@ -968,13 +1273,7 @@ pub async fn list_pages(
             DocsAreaDatabaseError::AreaNotInOrganization => {
                 DocsPageDatabaseError::AreaNotInOrganization
             }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
         })?;
 
     if area.organization_uuid != organization_uuid {
@@ -995,13 +1294,7 @@ pub async fn list_pages(
             DocsAreaDatabaseError::AreaNotInOrganization => {
                 DocsPageDatabaseError::AreaNotInOrganization
             }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
         })?;
 
     // Check if user has permission to view pages
@@ -1052,7 +1345,7 @@ pub async fn list_pages(
                      vcs_export_allowed, includes_private_data, metadata
                      FROM module_docs_pages
                      WHERE organization_uuid = ? AND area_uuid = ? AND folder_uuid = ?
-                     ORDER BY created_at DESC",
+                     ORDER BY sort_order ASC, created_at DESC",
                 )
                 .bind(organization_uuid)
                 .bind(area_uuid)
@@ -1066,7 +1359,7 @@ pub async fn list_pages(
                      vcs_export_allowed, includes_private_data, metadata
                      FROM module_docs_pages
                      WHERE organization_uuid = ? AND area_uuid = ? AND folder_uuid IS NULL
-                     ORDER BY created_at DESC",
+                     ORDER BY sort_order ASC, created_at DESC",
                 )
                 .bind(organization_uuid)
                 .bind(area_uuid)
@@ -1103,7 +1396,7 @@ pub async fn list_pages(
                      vcs_export_allowed, includes_private_data, metadata
                      FROM module_docs_pages
                      WHERE organization_uuid = $1 AND area_uuid = $2 AND folder_uuid = $3
-                     ORDER BY created_at DESC",
+                     ORDER BY sort_order ASC, created_at DESC",
                 )
                 .bind(organization_uuid)
                 .bind(area_uuid)
@@ -1117,7 +1410,7 @@ pub async fn list_pages(
                      vcs_export_allowed, includes_private_data, metadata
                      FROM module_docs_pages
                      WHERE organization_uuid = $1 AND area_uuid = $2 AND folder_uuid IS NULL
-                     ORDER BY created_at DESC",
+                     ORDER BY sort_order ASC, created_at DESC",
                 )
                 .bind(organization_uuid)
                 .bind(area_uuid)
@@ -1154,7 +1447,7 @@ pub async fn list_pages(
                      vcs_export_allowed, includes_private_data, metadata
                      FROM module_docs_pages
                      WHERE organization_uuid = ?1 AND area_uuid = ?2 AND folder_uuid = ?3
-                     ORDER BY created_at DESC",
+                     ORDER BY sort_order ASC, created_at DESC",
                 )
                 .bind(organization_uuid)
                 .bind(area_uuid)
@@ -1168,7 +1461,7 @@ pub async fn list_pages(
                      vcs_export_allowed, includes_private_data, metadata
                      FROM module_docs_pages
                      WHERE organization_uuid = ?1 AND area_uuid = ?2 AND folder_uuid IS NULL
-                     ORDER BY created_at DESC",
+                     ORDER BY sort_order ASC, created_at DESC",
                 )
                 .bind(organization_uuid)
                 .bind(area_uuid)
@@ -1208,7 +1501,7 @@ pub async fn list_pages(
 /// * `area_uuid` - UUID of the area
 ///
 /// # Returns
-/// Returns a vector of all pages sorted by created_at DESC
+/// Returns a vector of all pages sorted by sort_order, then created_at DESC
 ///
 /// # Errors
 /// Returns `DocsPageDatabaseError` if database operation fails
@@ -1225,7 +1518,7 @@ pub async fn get_all_pages(
                  vcs_export_allowed, includes_private_data, metadata
                  FROM module_docs_pages
                  WHERE organization_uuid = ? AND area_uuid = ?
-                 ORDER BY created_at DESC",
+                 ORDER BY sort_order ASC, created_at DESC",
             )
             .bind(organization_uuid)
             .bind(area_uuid)
@@ -1260,7 +1553,7 @@ pub async fn get_all_pages(
                  vcs_export_allowed, includes_private_data, metadata
                  FROM module_docs_pages
                  WHERE organization_uuid = $1 AND area_uuid = $2
-                 ORDER BY created_at DESC",
+                 ORDER BY sort_order ASC, created_at DESC",
             )
             .bind(organization_uuid)
             .bind(area_uuid)
@@ -1295,7 +1588,7 @@ pub async fn get_all_pages(
                  vcs_export_allowed, includes_private_data, metadata
                  FROM module_docs_pages
                  WHERE organization_uuid = ?1 AND area_uuid = ?2
-                 ORDER BY created_at DESC",
+                 ORDER BY sort_order ASC, created_at DESC",
             )
             .bind(organization_uuid)
             .bind(area_uuid)
@@ -1501,9 +1794,12 @@ pub async fn load_page_with_version(
 /// * `page_uuid` - UUID of the page to generate summary for
 /// * `dispatcher` - Optional event dispatcher to emit events
 /// * `user_uuid` - Optional user UUID who triggered the generation
+/// * `force` - Call the AI provider even if the saved summary matches the current content
 ///
 /// # Returns
-/// Returns the generated summary as a String
+/// Returns the generated summary as a String. Unless `force` is set, the saved summary is
+/// returned without setting up or calling the AI provider if it was saved for the current
+/// content and summary options.
 ///
 /// # Errors
 /// Returns `DocsPageDatabaseError` if:
@@ -1511,14 +1807,125 @@ pub async fn load_page_with_version(
 /// - Page not found
 /// - Page version not found
 /// - AI provider setting not configured
-/// - Unsupported AI provider
-/// - Summary generation fails
+/// - None of the configured AI providers is supported and set up
+/// - The organization reached its summary rate limit (`RateLimited`)
+/// - Summary generation fails with every configured provider
 pub async fn generate_page_summary(
     pool: &DatabasePool,
     organization_uuid: &str,
     page_uuid: &str,
     dispatcher: &EventDispatcher,
     user_uuid: Option<&str>,
+    force: bool,
+) -> Result<String, DocsPageDatabaseError> {
+    if !force {
+        let page = load_and_verify_page_ownership(pool, page_uuid, organization_uuid).await?;
+        let version = load_current_page_version(pool, &page).await?;
+        if let Some(summary) = reusable_summary(pool, organization_uuid, &page, &version).await? {
+            return Ok(summary);
+        }
+    }
+
+    let (ai_provider, generator) = create_summary_generator(pool, organization_uuid).await?;
+
+    // The saved summary was checked above, so the generator is always called
+    generate_page_summary_with_generator(
+        pool,
+        organization_uuid,
+        page_uuid,
+        generator.as_ref(),
+        &ai_provider,
+        dispatcher,
+        user_uuid,
+        true,
+    )
+    .await
+}
+
+/// Maximum length in characters of a suggested title
+pub const MAX_SUGGESTED_TITLE_LENGTH: usize = 120;
+
+/// Suggest a concise title for a page based on its current content
+///
+/// Asks the AI provider configured for summaries (`module_docs_page_summary_ai_provider`)
+/// for a title. The suggestion is returned without changing the page. Every call counts
+/// against the organization's summary rate limit.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization
+/// * `page_uuid` - UUID of the page to suggest a title for
+///
+/// # Returns
+/// Returns the suggested title, without surrounding quotes and at most
+/// `MAX_SUGGESTED_TITLE_LENGTH` characters long
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - Page doesn't belong to the organization
+/// - Page not found
+/// - Page version not found
+/// - None of the configured AI providers is supported and set up
+/// - The organization reached its summary rate limit (`RateLimited`)
+/// - The provider fails or returns an empty title
+pub async fn suggest_page_title(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    page_uuid: &str,
+) -> Result<String, DocsPageDatabaseError> {
+    let (_, generator) = create_summary_generator(pool, organization_uuid).await?;
+
+    suggest_page_title_with_generator(pool, organization_uuid, page_uuid, generator.as_ref()).await
+}
+
+/// Suggest a title for a page using the given generator
+async fn suggest_page_title_with_generator(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    page_uuid: &str,
+    generator: &dyn crate::summary::PageSummaryGenerator,
+) -> Result<String, DocsPageDatabaseError> {
+    let page = load_and_verify_page_ownership(pool, page_uuid, organization_uuid).await?;
+    let version = load_current_page_version(pool, &page).await?;
+
+    check_summary_rate_limit(pool, organization_uuid).await?;
+
+    let suggestion = generator.suggest_title(&page, &version).await?;
+    let title = clean_suggested_title(&suggestion);
+    if title.is_empty() {
+        return Err(crate::summary::PageSummaryError::ProviderError(
+            "AI provider returned an empty title".to_string(),
+        )
+        .into());
+    }
+
+    info!("Suggested title for page {}: {}", page_uuid, title);
+
+    Ok(title)
+}
+
+/// Reduce a model answer to a plain title: first non-empty line, without surrounding quotes
+fn clean_suggested_title(suggestion: &str) -> String {
+    let line = suggestion.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or("");
+    let line = line.strip_prefix("Title:").map(str::trim).unwrap_or(line);
+    line.trim_matches(|c| matches!(c, '"' | '\'' | '`' | '*'))
+        .trim()
+        .chars()
+        .take(MAX_SUGGESTED_TITLE_LENGTH)
+        .collect()
+}
+
+/// Generate a summary for a page using the given generator, reusing the saved summary if possible
+#[allow(clippy::too_many_arguments)]
+async fn generate_page_summary_with_generator(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    page_uuid: &str,
+    generator: &dyn crate::summary::PageSummaryGenerator,
+    ai_provider: &str,
+    dispatcher: &EventDispatcher,
+    user_uuid: Option<&str>,
+    force: bool,
 ) -> Result<String, DocsPageDatabaseError> {
     info!(
         "Starting summary generation for page {} in organization {}",
@@ -1530,16 +1937,52 @@ pub async fn generate_page_summary(
 
     info!("Page {} belongs to organization {}", page_uuid, organization_uuid);
 
-    // Get the current version
+    let version = load_current_page_version(pool, &page).await?;
+
+    info!(
+        "Page version {} loaded successfully (content length: {} characters)",
+        version.uuid,
+        version.content.len()
+    );
+
+    if !force {
+        if let Some(summary) = reusable_summary(pool, organization_uuid, &page, &version).await? {
+            return Ok(summary);
+        }
+    }
+
+    check_summary_rate_limit(pool, organization_uuid).await?;
+
+    summarize_page(
+        &page,
+        &version,
+        generator,
+        ai_provider,
+        organization_uuid,
+        dispatcher,
+        user_uuid,
+    )
+    .await
+}
+
+/// Load the current version of a page
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError::PageVersionNotFound` if the page has no current version
+/// or the version does not exist
+async fn load_current_page_version(
+    pool: &DatabasePool,
+    page: &DocsPage,
+) -> Result<DocsPageVersion, DocsPageDatabaseError> {
     let version_uuid = page.current_version_uuid.clone().ok_or_else(|| {
-        error!("Page {} has no current version", page_uuid);
+        error!("Page {} has no current version", page.uuid);
         DocsPageDatabaseError::PageVersionNotFound
     })?;
 
-    info!("Loading page version {} for page {}", version_uuid, page_uuid);
+    info!("Loading page version {} for page {}", version_uuid, page.uuid);
 
     // Load the version
-    let version = match pool {
+    match pool {
         DatabasePool::MySql(p) => {
             let row = sqlx::query(
                 "SELECT uuid, page_uuid, version_number, content, last_updated, created_at
@@ -1550,17 +1993,17 @@ pub async fn generate_page_summary(
             .await?;
 
             match row {
-                Some(row) => DocsPageVersion {
+                Some(row) => Ok(DocsPageVersion {
                     uuid: row.get("uuid"),
                     page_uuid: row.get("page_uuid"),
                     version_number: row.get("version_number"),
                     content: row.get("content"),
                     last_updated: row.get("last_updated"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
-                },
+                }),
                 None => {
-                    error!("Version {} not found for page {}", version_uuid, page_uuid);
-                    return Err(DocsPageDatabaseError::PageVersionNotFound);
+                    error!("Version {} not found for page {}", version_uuid, page.uuid);
+                    Err(DocsPageDatabaseError::PageVersionNotFound)
                 }
             }
         }
@@ -1574,17 +2017,17 @@ pub async fn generate_page_summary(
             .await?;
 
             match row {
-                Some(row) => DocsPageVersion {
+                Some(row) => Ok(DocsPageVersion {
                     uuid: row.get("uuid"),
                     page_uuid: row.get("page_uuid"),
                     version_number: row.get("version_number"),
                     content: row.get("content"),
                     last_updated: row.get("last_updated"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
-                },
+                }),
                 None => {
-                    error!("Version {} not found for page {}", version_uuid, page_uuid);
-                    return Err(DocsPageDatabaseError::PageVersionNotFound);
+                    error!("Version {} not found for page {}", version_uuid, page.uuid);
+                    Err(DocsPageDatabaseError::PageVersionNotFound)
                 }
             }
         }
@@ -1598,28 +2041,41 @@ pub async fn generate_page_summary(
             .await?;
 
             match row {
-                Some(row) => DocsPageVersion {
+                Some(row) => Ok(DocsPageVersion {
                     uuid: row.get("uuid"),
                     page_uuid: row.get("page_uuid"),
                     version_number: row.get("version_number"),
                     content: row.get("content"),
                     last_updated: row.get("last_updated"),
                     created_at: row.get::<DateTime<Utc>, _>("created_at"),
-                },
+                }),
                 None => {
-                    error!("Version {} not found for page {}", version_uuid, page_uuid);
-                    return Err(DocsPageDatabaseError::PageVersionNotFound);
+                    error!("Version {} not found for page {}", version_uuid, page.uuid);
+                    Err(DocsPageDatabaseError::PageVersionNotFound)
                 }
             }
         }
-    };
-
-    info!(
-        "Page version {} loaded successfully (content length: {} characters)",
-        version_uuid,
-        version.content.len()
-    );
+    }
+}
 
+/// Create the summary generator configured for an organization
+///
+/// `module_docs_page_summary_ai_provider` holds one provider (e.g. `openai`) or a
+/// comma-separated list in order of preference (e.g. `openai,claude`). Providers that are
+/// not set up are skipped; if more than one remains, they are tried in order until one
+/// generates a summary.
+///
+/// # Returns
+/// Returns the configured AI provider setting together with its generator
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - AI provider setting not configured or empty
+/// - None of the providers is set up (the error of the last provider is returned)
+async fn create_summary_generator(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+) -> Result<(String, Box<dyn crate::summary::PageSummaryGenerator>), DocsPageDatabaseError> {
     // Get the AI provider setting
     let ai_provider = get_organizational_setting_value(
         pool,
@@ -1641,8 +2097,48 @@ pub async fn generate_page_summary(
         ai_provider
     );
 
-    // Create the appropriate generator based on the provider
-    let generator: Box<dyn crate::summary::PageSummaryGenerator> = match ai_provider.as_str() {
+    let mut generators = Vec::new();
+    let mut last_error = DocsPageDatabaseError::AIProviderSettingNotFound;
+    for provider in ai_provider.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        match build_summary_generator(provider, pool, organization_uuid).await {
+            Ok(generator) => generators.push((provider.to_string(), generator)),
+            Err(e) => {
+                warn!("Skipping AI provider '{}' for summary generation: {}", provider, e);
+                last_error = e;
+            }
+        }
+    }
+
+    let generator: Box<dyn crate::summary::PageSummaryGenerator> = match generators.len() {
+        0 => return Err(last_error),
+        1 => generators.remove(0).1,
+        _ => Box::new(crate::summary::FallbackPageSummaryGenerator::new(generators)),
+    };
+
+    Ok((ai_provider, generator))
+}
+
+/// Build the summary generator of a single AI provider
+///
+/// Resolves the API key, model and summary options of the provider from the
+/// organization's settings.
+///
+/// # Arguments
+/// * `ai_provider` - Name of the provider (`openai`, `claude` or `gemini`)
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization whose settings are used
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - The API key of the provider is not configured (`AIProviderSettingNotFound`)
+/// - The provider is unknown or not implemented yet (`UnsupportedAIProvider`)
+/// - Loading the settings fails
+pub async fn build_summary_generator(
+    ai_provider: &str,
+    pool: &DatabasePool,
+    organization_uuid: &str,
+) -> Result<Box<dyn crate::summary::PageSummaryGenerator>, DocsPageDatabaseError> {
+    match ai_provider {
         "openai" => {
             // Get OpenAI API key from settings
             let api_key = get_organizational_setting_value(
@@ -1666,33 +2162,205 @@ pub async fn generate_page_summary(
             .unwrap_or_else(|| "gpt-4o-mini".to_string());
 
             info!("Creating OpenAI generator with model: {}", model);
-            Box::new(crate::summary::OpenAIPageSummaryGenerator::new(api_key, model))
+            Ok(Box::new(
+                crate::summary::OpenAIPageSummaryGenerator::new(api_key, model)
+                    .with_summary_options(load_summary_options(pool, organization_uuid).await?),
+            ))
         }
         "claude" => {
             error!("Claude provider not yet implemented");
-            return Err(DocsPageDatabaseError::UnsupportedAIProvider(ai_provider));
+            Err(DocsPageDatabaseError::UnsupportedAIProvider(ai_provider.to_string()))
         }
         "gemini" => {
             error!("Gemini provider not yet implemented");
-            return Err(DocsPageDatabaseError::UnsupportedAIProvider(ai_provider));
+            Err(DocsPageDatabaseError::UnsupportedAIProvider(ai_provider.to_string()))
         }
         _ => {
             error!("Unsupported AI provider: {}", ai_provider);
-            return Err(DocsPageDatabaseError::UnsupportedAIProvider(ai_provider));
+            Err(DocsPageDatabaseError::UnsupportedAIProvider(ai_provider.to_string()))
+        }
+    }
+}
+
+/// Load the summary length and style preferences of an organization
+///
+/// Settings that are not defined are treated as not set.
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - `module_docs_summary_max_words` is set but not a positive integer
+/// - Loading the settings fails
+async fn load_summary_options(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+) -> Result<crate::summary::SummaryOptions, DocsPageDatabaseError> {
+    let setting = |name| async move {
+        match get_organizational_setting_value(pool, organization_uuid, name).await {
+            Err(SettingsDatabaseError::SettingNotFound(_)) => Ok(None),
+            result => result,
+        }
+    };
+
+    let max_words = setting("module_docs_summary_max_words")
+        .await?
+        .filter(|value| !value.trim().is_empty())
+        .map(|value| parse_summary_max_words(&value))
+        .transpose()?;
+
+    let style = setting("module_docs_summary_style")
+        .await?
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+
+    Ok(crate::summary::SummaryOptions { max_words, style })
+}
+
+/// Parse the `module_docs_summary_max_words` setting, which must be a positive integer
+fn parse_summary_max_words(value: &str) -> Result<u32, DocsPageDatabaseError> {
+    match value.trim().parse::<u32>() {
+        Ok(max_words) if max_words > 0 => Ok(max_words),
+        _ => Err(DocsPageDatabaseError::InvalidSummaryMaxWords(value.to_string())),
+    }
+}
+
+/// Load the organization's summary rate limit in calls per minute
+///
+/// The limit is configured through the `module_docs_summary_calls_per_minute` setting and
+/// defaults to `DEFAULT_SUMMARY_CALLS_PER_MINUTE`.
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - `module_docs_summary_calls_per_minute` is set but not a positive integer
+/// - Loading the setting fails
+async fn load_summary_calls_per_minute(pool: &DatabasePool, organization_uuid: &str) -> Result<u32, DocsPageDatabaseError> {
+    Ok(get_organizational_setting_value(pool, organization_uuid, "module_docs_summary_calls_per_minute")
+        .await?
+        .filter(|value| !value.trim().is_empty())
+        .map(|value| match value.trim().parse::<u32>() {
+            Ok(limit) if limit > 0 => Ok(limit),
+            _ => Err(DocsPageDatabaseError::InvalidSummaryRateLimit(value)),
+        })
+        .transpose()?
+        .unwrap_or(crate::summary::DEFAULT_SUMMARY_CALLS_PER_MINUTE))
+}
+
+/// Count a call to the AI provider against the organization's summary rate limit
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - The organization reached its limit (`RateLimited`)
+/// - The limit cannot be loaded, see `load_summary_calls_per_minute`
+async fn check_summary_rate_limit(pool: &DatabasePool, organization_uuid: &str) -> Result<(), DocsPageDatabaseError> {
+    let calls_per_minute = load_summary_calls_per_minute(pool, organization_uuid).await?;
+
+    crate::summary::SummaryRateLimiter::global()
+        .try_acquire(organization_uuid, calls_per_minute)
+        .map_err(|retry_after| {
+            warn!(
+                "Summary rate limit of {} calls per minute reached for organization {}",
+                calls_per_minute, organization_uuid
+            );
+            DocsPageDatabaseError::RateLimited {
+                retry_after_seconds: retry_after.as_secs_f64().ceil() as u64,
+            }
+        })
+}
+
+/// Hash of page content and summary options, stored with a saved summary to detect
+/// whether a new summary would be generated from the same input
+fn summary_content_hash(content: &str, options: &crate::summary::SummaryOptions) -> String {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    // Separated by NUL, which neither the content nor the options contain in practice
+    hasher.update(b"\0");
+    hasher.update(options.max_words.map(|words| words.to_string()).unwrap_or_default().as_bytes());
+    hasher.update(b"\0");
+    hasher.update(options.style.as_deref().unwrap_or_default().as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Saved summary of a page if it was saved for the current content and summary options
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if loading the summary options or the saved hash fails
+async fn reusable_summary(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    page: &DocsPage,
+    version: &DocsPageVersion,
+) -> Result<Option<String>, DocsPageDatabaseError> {
+    let Some(summary) = page.short_summary.as_deref().filter(|summary| !summary.is_empty()) else {
+        return Ok(None);
+    };
+
+    let options = load_summary_options(pool, organization_uuid).await?;
+    let saved_hash = load_summary_content_hash(pool, &page.uuid).await?;
+    if saved_hash.as_deref() != Some(summary_content_hash(&version.content, &options).as_str()) {
+        return Ok(None);
+    }
+
+    info!(
+        "Content and summary options of page {} are unchanged since its summary was saved, reusing it",
+        page.uuid
+    );
+    Ok(Some(summary.to_string()))
+}
+
+/// Load the content hash stored with the saved summary of a page
+async fn load_summary_content_hash(
+    pool: &DatabasePool,
+    page_uuid: &str,
+) -> Result<Option<String>, DocsPageDatabaseError> {
+    let hash = match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query("SELECT summary_content_hash FROM module_docs_pages WHERE uuid = ?")
+                .bind(page_uuid)
+                .fetch_optional(p)
+                .await?
+                .and_then(|row| row.get("summary_content_hash"))
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query("SELECT summary_content_hash FROM module_docs_pages WHERE uuid = $1")
+                .bind(page_uuid)
+                .fetch_optional(p)
+                .await?
+                .and_then(|row| row.get("summary_content_hash"))
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query("SELECT summary_content_hash FROM module_docs_pages WHERE uuid = ?1")
+                .bind(page_uuid)
+                .fetch_optional(p)
+                .await?
+                .and_then(|row| row.get("summary_content_hash"))
         }
     };
 
+    Ok(hash)
+}
+
+/// Generate a summary for a loaded page version and emit `module_docs_page_summary_generated`
+async fn summarize_page(
+    page: &DocsPage,
+    version: &DocsPageVersion,
+    generator: &dyn crate::summary::PageSummaryGenerator,
+    ai_provider: &str,
+    organization_uuid: &str,
+    dispatcher: &EventDispatcher,
+    user_uuid: Option<&str>,
+) -> Result<String, DocsPageDatabaseError> {
     // Generate the summary
     info!(
         "Calling AI provider '{}' to generate summary for page {}",
-        ai_provider, page_uuid
+        ai_provider, page.uuid
     );
 
-    let summary = generator.generate_summary(&page, &version).await?;
+    let summary = generator.generate_summary(page, version).await?;
 
     info!(
         "Successfully generated summary for page {} (length: {} characters)",
-        page_uuid,
+        page.uuid,
         summary.len()
     );
 
@@ -1701,10 +2369,10 @@ pub async fn generate_page_summary(
         "module_docs_page_summary_generated",
         EventPayload::new(json!({
             "entity_type": "page",
-            "entity_id": page_uuid,
+            "entity_id": page.uuid,
             "organization_uuid": organization_uuid,
             "data": {
-                "page_uuid": page_uuid,
+                "page_uuid": page.uuid,
                 "summary_length": summary.len(),
                 "ai_provider": ai_provider
             }
@@ -1731,6 +2399,9 @@ pub async fn generate_page_summary(
 /// * `dispatcher` - Optional event dispatcher to emit events
 /// * `user_uuid` - Optional user UUID who saved the summary
 ///
+/// The hash of the current content and summary options is stored with the summary, so
+/// that `generate_page_summary` can reuse the summary until either changes.
+///
 /// # Returns
 /// Returns `()` on success
 ///
@@ -1738,6 +2409,7 @@ pub async fn generate_page_summary(
 /// Returns `DocsPageDatabaseError` if:
 /// - Page doesn't belong to the organization
 /// - Page not found
+/// - `module_docs_summary_max_words` is set but not a positive integer
 /// - Database operation fails
 pub async fn save_page_summary(
     pool: &DatabasePool,
@@ -1753,17 +2425,28 @@ pub async fn save_page_summary(
     );
 
     // Load the page and verify it belongs to the organization
-    load_and_verify_page_ownership(pool, page_uuid, organization_uuid).await?;
+    let page = load_and_verify_page_ownership(pool, page_uuid, organization_uuid).await?;
 
     info!("Page {} belongs to organization {}", page_uuid, organization_uuid);
 
+    // Pages without content (e.g. databases) have no content to tie the summary to
+    let summary_content_hash = match page.current_version_uuid {
+        Some(_) => {
+            let version = load_current_page_version(pool, &page).await?;
+            let options = load_summary_options(pool, organization_uuid).await?;
+            Some(summary_content_hash(&version.content, &options))
+        }
+        None => None,
+    };
+
     // Update the short_summary field
     match pool {
         DatabasePool::MySql(p) => {
             sqlx::query(
-                "UPDATE module_docs_pages SET short_summary = ? WHERE uuid = ? AND organization_uuid = ?",
+                "UPDATE module_docs_pages SET short_summary = ?, summary_content_hash = ? WHERE uuid = ? AND organization_uuid = ?",
             )
             .bind(summary)
+            .bind(&summary_content_hash)
             .bind(page_uuid)
             .bind(organization_uuid)
             .execute(p)
@@ -1771,9 +2454,10 @@ pub async fn save_page_summary(
         }
         DatabasePool::Postgres(p) => {
             sqlx::query(
-                "UPDATE module_docs_pages SET short_summary = $1 WHERE uuid = $2 AND organization_uuid = $3",
+                "UPDATE module_docs_pages SET short_summary = $1, summary_content_hash = $2 WHERE uuid = $3 AND organization_uuid = $4",
             )
             .bind(summary)
+            .bind(&summary_content_hash)
             .bind(page_uuid)
             .bind(organization_uuid)
             .execute(p)
@@ -1781,9 +2465,10 @@ pub async fn save_page_summary(
         }
         DatabasePool::Sqlite(p) => {
             sqlx::query(
-                "UPDATE module_docs_pages SET short_summary = ?1 WHERE uuid = ?2 AND organization_uuid = ?3",
+                "UPDATE module_docs_pages SET short_summary = ?1, summary_content_hash = ?2 WHERE uuid = ?3 AND organization_uuid = ?4",
             )
             .bind(summary)
+            .bind(&summary_content_hash)
             .bind(page_uuid)
             .bind(organization_uuid)
             .execute(p)
@@ -1821,48 +2506,93 @@ pub async fn save_page_summary(
     Ok(())
 }
 
-/// Save page content by creating a new version (if content changed)
+/// Maximum number of page summaries generated at the same time by `generate_area_summaries`
+pub const AREA_SUMMARY_CONCURRENCY: usize = 4;
+
+/// Outcome of generating the summary of a single page
+#[derive(Debug, Clone, Serialize)]
+pub struct PageSummaryOutcome {
+    pub page_uuid: String,
+    pub title: String,
+    /// Generated and saved summary, `None` if generation failed
+    pub summary: Option<String>,
+    /// Error message if generation or saving failed
+    pub error: Option<String>,
+}
+
+/// Report of generating summaries for the pages of an area
+#[derive(Debug, Clone, Serialize)]
+pub struct AreaSummaryReport {
+    pub area_uuid: String,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// One outcome per page that lacked a summary, in page creation order
+    pub pages: Vec<PageSummaryOutcome>,
+}
+
+/// Generate and save summaries for all pages of an area that do not have one yet
+///
+/// Pages are processed with at most `AREA_SUMMARY_CONCURRENCY` generations in flight.
+/// A failing page is recorded in the report and does not abort the remaining pages.
+/// Calls count against the organization's summary rate limit; once it is reached,
+/// generation waits for the window to clear instead of failing, so large areas take
+/// about one minute per `module_docs_summary_calls_per_minute` pages.
 ///
 /// # Arguments
 /// * `pool` - Database connection pool
 /// * `organization_uuid` - UUID of the organization
-/// * `page_uuid` - UUID of the page to save content for
-/// * `user_uuid` - UUID of the user saving the content
-/// * `content` - The content text to save
-/// * `dispatcher` - Optional event dispatcher to emit events
+/// * `area_uuid` - UUID of the area
+/// * `dispatcher` - Event dispatcher to emit events
+/// * `user_uuid` - Optional user UUID who triggered the generation
 ///
 /// # Returns
-/// Returns the UUID of the new version (or existing version if content unchanged)
+/// Returns an `AreaSummaryReport` with the outcome of every page
 ///
 /// # Errors
 /// Returns `DocsPageDatabaseError` if:
-/// - Page doesn't belong to the organization
-/// - Page not found
-/// - User doesn't have permission to edit pages
-/// - Database operation fails
-pub async fn save_page_content(
+/// - Area not found or doesn't belong to the organization
+/// - AI provider setting not configured
+/// - Unsupported AI provider
+/// - The summary rate limit setting is invalid
+/// - Listing the pages fails
+pub async fn generate_area_summaries(
     pool: &DatabasePool,
     organization_uuid: &str,
-    page_uuid: &str,
-    user_uuid: &str,
-    content: &str,
+    area_uuid: &str,
     dispatcher: &EventDispatcher,
-) -> Result<String, DocsPageDatabaseError> {
-    use flextide_core::user::user_has_permission;
-    use crate::area::{load_area_by_uuid, load_area_member_permissions};
-
-    info!(
-        "Saving content for page {} in organization {}",
-        page_uuid, organization_uuid
-    );
+    user_uuid: Option<&str>,
+) -> Result<AreaSummaryReport, DocsPageDatabaseError> {
+    let (ai_provider, generator) = create_summary_generator(pool, organization_uuid).await?;
 
-    // Load the page and verify it belongs to the organization
-    let page = load_and_verify_page_ownership(pool, page_uuid, organization_uuid).await?;
+    generate_area_summaries_with_generator(
+        pool,
+        organization_uuid,
+        area_uuid,
+        generator.as_ref(),
+        &ai_provider,
+        crate::summary::SummaryRateLimiter::global(),
+        dispatcher,
+        user_uuid,
+    )
+    .await
+}
 
-    info!("Page {} belongs to organization {}", page_uuid, organization_uuid);
+/// Generate and save summaries for the pages of an area lacking one, using the given
+/// generator and rate limiter
+#[allow(clippy::too_many_arguments)]
+async fn generate_area_summaries_with_generator(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    area_uuid: &str,
+    generator: &dyn crate::summary::PageSummaryGenerator,
+    ai_provider: &str,
+    rate_limiter: &crate::summary::SummaryRateLimiter,
+    dispatcher: &EventDispatcher,
+    user_uuid: Option<&str>,
+) -> Result<AreaSummaryReport, DocsPageDatabaseError> {
+    use futures_util::stream::{self, StreamExt};
 
-    // Verify area exists (for permission checking)
-    let _area = load_area_by_uuid(pool, &page.area_uuid)
+    let area = load_area_by_uuid(pool, area_uuid)
         .await
         .map_err(|e| match e {
             DocsAreaDatabaseError::AreaNotFound => DocsPageDatabaseError::AreaNotFound,
@@ -1875,71 +2605,383 @@ pub async fn save_page_content(
             DocsAreaDatabaseError::AreaNotInOrganization => {
                 DocsPageDatabaseError::AreaNotInOrganization
             }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
         })?;
 
-    // Check area member permissions
-    let member_perms = load_area_member_permissions(pool, &page.area_uuid, user_uuid)
-        .await
-        .map_err(|e| match e {
-            DocsAreaDatabaseError::Database(e) => DocsPageDatabaseError::Database(e),
-            DocsAreaDatabaseError::Sql(e) => DocsPageDatabaseError::Sql(e),
-            DocsAreaDatabaseError::UserNotInOrganization => {
-                DocsPageDatabaseError::UserNotInOrganization
-            }
-            DocsAreaDatabaseError::PermissionDenied => DocsPageDatabaseError::PermissionDenied,
-            DocsAreaDatabaseError::AreaNotFound => DocsPageDatabaseError::AreaNotFound,
-            DocsAreaDatabaseError::AreaNotInOrganization => {
-                DocsPageDatabaseError::AreaNotInOrganization
-            }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
-        })?;
+    if area.organization_uuid != organization_uuid {
+        return Err(DocsPageDatabaseError::AreaNotInOrganization);
+    }
 
-    // Check if user has permission to edit pages
-    let can_edit = if let Some(perms) = &member_perms {
-        perms.admin || perms.role == "owner" || perms.can_edit_pages
-        // TODO: Add check for can_edit_own_pages when creator_uuid is added to pages
-    } else {
-        // If not a member, user cannot edit pages
-        false
-    };
+    let calls_per_minute = load_summary_calls_per_minute(pool, organization_uuid).await?;
 
-    // Also check for organization-wide super_admin permission
-    let has_super_admin = user_has_permission(
-        pool,
-        user_uuid,
-        organization_uuid,
-        "module_docs_super_admin",
-    )
-    .await
-    .map_err(|e| {
-        tracing::error!("Database error checking permission: {}", e);
-        match e {
-            flextide_core::user::UserDatabaseError::Database(db_err) => {
-                DocsPageDatabaseError::Database(db_err)
-            }
-            flextide_core::user::UserDatabaseError::Sql(sql_err) => {
-                DocsPageDatabaseError::Sql(sql_err)
-            }
-            _ => DocsPageDatabaseError::Database(
-                flextide_core::database::DatabaseError::PoolCreationFailed(
-                    sqlx::Error::RowNotFound,
-                ),
-            ),
+    // Pages without a summary, oldest first
+    let page_uuids: Vec<String> = match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query(
+                "SELECT uuid FROM module_docs_pages
+                 WHERE area_uuid = ? AND organization_uuid = ?
+                 AND (short_summary IS NULL OR short_summary = '')
+                 ORDER BY created_at ASC",
+            )
+            .bind(area_uuid)
+            .bind(organization_uuid)
+            .fetch_all(p)
+            .await?
+            .iter()
+            .map(|row| row.get("uuid"))
+            .collect()
         }
-    })?;
+        DatabasePool::Postgres(p) => {
+            sqlx::query(
+                "SELECT uuid FROM module_docs_pages
+                 WHERE area_uuid = $1 AND organization_uuid = $2
+                 AND (short_summary IS NULL OR short_summary = '')
+                 ORDER BY created_at ASC",
+            )
+            .bind(area_uuid)
+            .bind(organization_uuid)
+            .fetch_all(p)
+            .await?
+            .iter()
+            .map(|row| row.get("uuid"))
+            .collect()
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query(
+                "SELECT uuid FROM module_docs_pages
+                 WHERE area_uuid = ?1 AND organization_uuid = ?2
+                 AND (short_summary IS NULL OR short_summary = '')
+                 ORDER BY created_at ASC",
+            )
+            .bind(area_uuid)
+            .bind(organization_uuid)
+            .fetch_all(p)
+            .await?
+            .iter()
+            .map(|row| row.get("uuid"))
+            .collect()
+        }
+    };
+
+    info!(
+        "Generating summaries for {} pages in area {}",
+        page_uuids.len(),
+        area_uuid
+    );
+
+    let outcomes: Vec<PageSummaryOutcome> = stream::iter(page_uuids)
+        .map(|page_uuid| async move {
+            let result = async {
+                let page = load_and_verify_page_ownership(pool, &page_uuid, organization_uuid).await?;
+                let version = load_current_page_version(pool, &page).await?;
+                rate_limiter.acquire(organization_uuid, calls_per_minute).await;
+                let summary = summarize_page(
+                    &page,
+                    &version,
+                    generator,
+                    ai_provider,
+                    organization_uuid,
+                    dispatcher,
+                    user_uuid,
+                )
+                .await?;
+                save_page_summary(pool, organization_uuid, &page.uuid, &summary, dispatcher, user_uuid)
+                    .await?;
+                Ok::<_, DocsPageDatabaseError>((page.title, summary))
+            }
+            .await;
+
+            match result {
+                Ok((title, summary)) => PageSummaryOutcome {
+                    page_uuid,
+                    title,
+                    summary: Some(summary),
+                    error: None,
+                },
+                Err(e) => {
+                    warn!("Failed to generate summary for page {}: {}", page_uuid, e);
+                    // Title is informational only, an unloadable page is reported without it
+                    let title = load_page_by_uuid(pool, &page_uuid)
+                        .await
+                        .map(|page| page.title)
+                        .unwrap_or_default();
+                    PageSummaryOutcome {
+                        page_uuid,
+                        title,
+                        summary: None,
+                        error: Some(e.to_string()),
+                    }
+                }
+            }
+        })
+        .buffered(AREA_SUMMARY_CONCURRENCY)
+        .collect()
+        .await;
+
+    let succeeded = outcomes.iter().filter(|outcome| outcome.error.is_none()).count();
+    let failed = outcomes.len() - succeeded;
+
+    info!(
+        "Generated summaries for area {}: {} succeeded, {} failed",
+        area_uuid, succeeded, failed
+    );
+
+    Ok(AreaSummaryReport {
+        area_uuid: area_uuid.to_string(),
+        succeeded,
+        failed,
+        pages: outcomes,
+    })
+}
+
+/// Maximum page content size in bytes if `DOCS_MAX_PAGE_CONTENT_BYTES` is not set (1 MiB)
+pub const DEFAULT_MAX_PAGE_CONTENT_BYTES: usize = 1024 * 1024;
+
+/// Maximum size in bytes of the content of a page version
+///
+/// Configured through the `DOCS_MAX_PAGE_CONTENT_BYTES` environment variable, missing or
+/// invalid values fall back to `DEFAULT_MAX_PAGE_CONTENT_BYTES`.
+pub fn max_page_content_bytes() -> usize {
+    match std::env::var("DOCS_MAX_PAGE_CONTENT_BYTES") {
+        Ok(value) => match value.trim().parse::<usize>() {
+            Ok(max_length) if max_length > 0 => max_length,
+            _ => {
+                warn!("Ignoring invalid value for DOCS_MAX_PAGE_CONTENT_BYTES: {}", value);
+                DEFAULT_MAX_PAGE_CONTENT_BYTES
+            }
+        },
+        Err(_) => DEFAULT_MAX_PAGE_CONTENT_BYTES,
+    }
+}
+
+/// Number of attempts to insert a page version before a version number conflict is returned
+const VERSION_INSERT_ATTEMPTS: usize = 5;
+
+/// Insert a new version of a page with the next free version number
+///
+/// The version number is computed in the `INSERT` itself, so concurrent saves cannot read
+/// the same maximum. If two saves still compute the same number, the unique
+/// `(page_uuid, version_number)` constraint rejects one of them and it is retried.
+///
+/// # Returns
+/// Returns the UUID and number of the created version
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if the database operation fails, or the version number
+/// still conflicts after `VERSION_INSERT_ATTEMPTS` attempts
+async fn insert_next_page_version(
+    pool: &DatabasePool,
+    page_uuid: &str,
+    content: &str,
+    now: DateTime<Utc>,
+) -> Result<(String, i32), DocsPageDatabaseError> {
+    let mut attempt = 1;
+    loop {
+        let version_uuid = uuid::Uuid::new_v4().to_string();
+        let result = match pool {
+            DatabasePool::MySql(p) => {
+                sqlx::query(
+                    "INSERT INTO module_docs_page_versions (uuid, page_uuid, version_number, content, last_updated, created_at)
+                     SELECT ?, ?, COALESCE(MAX(version_number), 0) + 1, ?, ?, ?
+                     FROM module_docs_page_versions WHERE page_uuid = ?",
+                )
+                .bind(&version_uuid)
+                .bind(page_uuid)
+                .bind(content)
+                .bind(now)
+                .bind(now)
+                .bind(page_uuid)
+                .execute(p)
+                .await
+                .map(|_| ())
+            }
+            DatabasePool::Postgres(p) => {
+                sqlx::query(
+                    "INSERT INTO module_docs_page_versions (uuid, page_uuid, version_number, content, last_updated, created_at)
+                     SELECT $1, $2, COALESCE(MAX(version_number), 0) + 1, $3, $4, $5
+                     FROM module_docs_page_versions WHERE page_uuid = $2",
+                )
+                .bind(&version_uuid)
+                .bind(page_uuid)
+                .bind(content)
+                .bind(now)
+                .bind(now)
+                .execute(p)
+                .await
+                .map(|_| ())
+            }
+            DatabasePool::Sqlite(p) => {
+                sqlx::query(
+                    "INSERT INTO module_docs_page_versions (uuid, page_uuid, version_number, content, last_updated, created_at)
+                     SELECT ?1, ?2, COALESCE(MAX(version_number), 0) + 1, ?3, ?4, ?5
+                     FROM module_docs_page_versions WHERE page_uuid = ?2",
+                )
+                .bind(&version_uuid)
+                .bind(page_uuid)
+                .bind(content)
+                .bind(now)
+                .bind(now)
+                .execute(p)
+                .await
+                .map(|_| ())
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                let version_number = load_version_number(pool, &version_uuid).await?;
+                return Ok((version_uuid, version_number));
+            }
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() && attempt < VERSION_INSERT_ATTEMPTS => {
+                warn!(
+                    "Version number of page {} was taken by a concurrent save, retrying (attempt {})",
+                    page_uuid, attempt
+                );
+                attempt += 1;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Load the version number of a page version
+async fn load_version_number(pool: &DatabasePool, version_uuid: &str) -> Result<i32, DocsPageDatabaseError> {
+    let version_number = match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query("SELECT version_number FROM module_docs_page_versions WHERE uuid = ?")
+                .bind(version_uuid)
+                .fetch_one(p)
+                .await?
+                .get::<i32, _>("version_number")
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query("SELECT version_number FROM module_docs_page_versions WHERE uuid = $1")
+                .bind(version_uuid)
+                .fetch_one(p)
+                .await?
+                .get::<i32, _>("version_number")
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query("SELECT version_number FROM module_docs_page_versions WHERE uuid = ?1")
+                .bind(version_uuid)
+                .fetch_one(p)
+                .await?
+                .get::<i32, _>("version_number")
+        }
+    };
+
+    Ok(version_number)
+}
+
+/// Save page content by creating a new version (if content changed)
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization
+/// * `page_uuid` - UUID of the page to save content for
+/// * `user_uuid` - UUID of the user saving the content
+/// * `content` - The content text to save
+/// * `dispatcher` - Optional event dispatcher to emit events
+///
+/// # Returns
+/// Returns the UUID of the new version (or existing version if content unchanged)
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - Page doesn't belong to the organization
+/// - Page not found
+/// - User doesn't have permission to edit pages
+/// - Content is rejected by the handler of the page type (`InvalidPageContent`)
+/// - Content is larger than `max_page_content_bytes()` (`ContentTooLarge`)
+/// - Database operation fails
+pub async fn save_page_content(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    page_uuid: &str,
+    user_uuid: &str,
+    content: &str,
+    dispatcher: &EventDispatcher,
+) -> Result<String, DocsPageDatabaseError> {
+    use flextide_core::user::user_has_permission;
+    use crate::area::{load_area_by_uuid, load_area_member_permissions};
+
+    info!(
+        "Saving content for page {} in organization {}",
+        page_uuid, organization_uuid
+    );
+
+    // Load the page and verify it belongs to the organization
+    let page = load_and_verify_page_ownership(pool, page_uuid, organization_uuid).await?;
+
+    info!("Page {} belongs to organization {}", page_uuid, organization_uuid);
+
+    // Verify area exists (for permission checking)
+    let _area = load_area_by_uuid(pool, &page.area_uuid)
+        .await
+        .map_err(|e| match e {
+            DocsAreaDatabaseError::AreaNotFound => DocsPageDatabaseError::AreaNotFound,
+            DocsAreaDatabaseError::Database(e) => DocsPageDatabaseError::Database(e),
+            DocsAreaDatabaseError::Sql(e) => DocsPageDatabaseError::Sql(e),
+            DocsAreaDatabaseError::UserNotInOrganization => {
+                DocsPageDatabaseError::UserNotInOrganization
+            }
+            DocsAreaDatabaseError::PermissionDenied => DocsPageDatabaseError::PermissionDenied,
+            DocsAreaDatabaseError::AreaNotInOrganization => {
+                DocsPageDatabaseError::AreaNotInOrganization
+            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
+        })?;
+
+    // Check area member permissions
+    let member_perms = load_area_member_permissions(pool, &page.area_uuid, user_uuid)
+        .await
+        .map_err(|e| match e {
+            DocsAreaDatabaseError::Database(e) => DocsPageDatabaseError::Database(e),
+            DocsAreaDatabaseError::Sql(e) => DocsPageDatabaseError::Sql(e),
+            DocsAreaDatabaseError::UserNotInOrganization => {
+                DocsPageDatabaseError::UserNotInOrganization
+            }
+            DocsAreaDatabaseError::PermissionDenied => DocsPageDatabaseError::PermissionDenied,
+            DocsAreaDatabaseError::AreaNotFound => DocsPageDatabaseError::AreaNotFound,
+            DocsAreaDatabaseError::AreaNotInOrganization => {
+                DocsPageDatabaseError::AreaNotInOrganization
+            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
+        })?;
+
+    // Check if user has permission to edit pages
+    let can_edit = if let Some(perms) = &member_perms {
+        perms.admin || perms.role == "owner" || perms.can_edit_pages
+        // TODO: Add check for can_edit_own_pages when creator_uuid is added to pages
+    } else {
+        // If not a member, user cannot edit pages
+        false
+    };
+
+    // Also check for organization-wide super_admin permission
+    let has_super_admin = user_has_permission(
+        pool,
+        user_uuid,
+        organization_uuid,
+        "module_docs_super_admin",
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error checking permission: {}", e);
+        match e {
+            flextide_core::user::UserDatabaseError::Database(db_err) => {
+                DocsPageDatabaseError::Database(db_err)
+            }
+            flextide_core::user::UserDatabaseError::Sql(sql_err) => {
+                DocsPageDatabaseError::Sql(sql_err)
+            }
+            _ => DocsPageDatabaseError::Database(
+                flextide_core::database::DatabaseError::PoolCreationFailed(
+                    sqlx::Error::RowNotFound,
+                ),
+            ),
+        }
+    })?;
 
     if !can_edit && !has_super_admin {
         warn!(
@@ -1949,6 +2991,26 @@ pub async fn save_page_content(
         return Err(DocsPageDatabaseError::PermissionDenied);
     }
 
+    if let Some(handler) = PageTypeRegistry::global().get(&page.page_type) {
+        handler
+            .validate_content(content)
+            .map_err(DocsPageDatabaseError::InvalidPageContent)?;
+    }
+
+    let max_length = max_page_content_bytes();
+    if content.len() > max_length {
+        warn!(
+            "Rejecting content of page {}: {} bytes exceed the maximum of {} bytes",
+            page_uuid,
+            content.len(),
+            max_length
+        );
+        return Err(DocsPageDatabaseError::ContentTooLarge {
+            length: content.len(),
+            max_length,
+        });
+    }
+
     // Get current version content if it exists
     let current_content = if let Some(ref version_uuid) = page.current_version_uuid {
         match pool {
@@ -1995,126 +3057,93 @@ pub async fn save_page_content(
         return Ok(page.current_version_uuid.unwrap());
     }
 
-    // Get the next version number
-    let next_version_number = match pool {
-        DatabasePool::MySql(p) => {
-            let row = sqlx::query(
-                "SELECT COALESCE(MAX(version_number), 0) + 1 as next_version
-                 FROM module_docs_page_versions WHERE page_uuid = ?",
-            )
-            .bind(page_uuid)
-            .fetch_one(p)
-            .await?;
-
-            row.get::<i32, _>("next_version")
-        }
-        DatabasePool::Postgres(p) => {
-            let row = sqlx::query(
-                "SELECT COALESCE(MAX(version_number), 0) + 1 as next_version
-                 FROM module_docs_page_versions WHERE page_uuid = $1",
-            )
-            .bind(page_uuid)
-            .fetch_one(p)
-            .await?;
-
-            row.get::<i32, _>("next_version")
-        }
-        DatabasePool::Sqlite(p) => {
-            let row = sqlx::query(
-                "SELECT COALESCE(MAX(version_number), 0) + 1 as next_version
-                 FROM module_docs_page_versions WHERE page_uuid = ?1",
-            )
-            .bind(page_uuid)
-            .fetch_one(p)
-            .await?;
-
-            row.get::<i32, _>("next_version")
-        }
-    };
-
     // Create new version
-    let version_uuid = uuid::Uuid::new_v4().to_string();
     let now = Utc::now();
+    let (version_uuid, next_version_number) = insert_next_page_version(pool, page_uuid, content, now).await?;
 
+    // Update page's current_version_uuid and last_updated, unless a concurrent save already
+    // made a newer version current, and record the change in the same transaction
     match pool {
         DatabasePool::MySql(p) => {
+            let mut tx = p.begin().await?;
             sqlx::query(
-                "INSERT INTO module_docs_page_versions (uuid, page_uuid, version_number, content, last_updated, created_at)
-                 VALUES (?, ?, ?, ?, ?, ?)",
+                "UPDATE module_docs_pages SET current_version_uuid = ?, last_updated = ?
+                 WHERE uuid = ? AND organization_uuid = ?
+                   AND (current_version_uuid IS NULL
+                        OR (SELECT v.version_number FROM module_docs_page_versions v WHERE v.uuid = module_docs_pages.current_version_uuid) < ?)",
             )
             .bind(&version_uuid)
+            .bind(now)
             .bind(page_uuid)
+            .bind(organization_uuid)
             .bind(next_version_number)
-            .bind(content)
-            .bind(now)
-            .bind(now)
-            .execute(p)
+            .execute(&mut *tx)
+            .await?;
+            record_area_activity_mysql(
+                &mut tx,
+                organization_uuid,
+                &page.area_uuid,
+                user_uuid,
+                DocsActivityAction::PageUpdated,
+                page_uuid,
+                &page.title,
+            )
             .await?;
+            tx.commit().await?;
         }
         DatabasePool::Postgres(p) => {
+            let mut tx = p.begin().await?;
             sqlx::query(
-                "INSERT INTO module_docs_page_versions (uuid, page_uuid, version_number, content, last_updated, created_at)
-                 VALUES ($1, $2, $3, $4, $5, $6)",
+                "UPDATE module_docs_pages SET current_version_uuid = $1, last_updated = $2
+                 WHERE uuid = $3 AND organization_uuid = $4
+                   AND (current_version_uuid IS NULL
+                        OR (SELECT v.version_number FROM module_docs_page_versions v WHERE v.uuid = module_docs_pages.current_version_uuid) < $5)",
             )
             .bind(&version_uuid)
-            .bind(page_uuid)
-            .bind(next_version_number)
-            .bind(content)
-            .bind(now)
             .bind(now)
-            .execute(p)
-            .await?;
-        }
-        DatabasePool::Sqlite(p) => {
-            sqlx::query(
-                "INSERT INTO module_docs_page_versions (uuid, page_uuid, version_number, content, last_updated, created_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-            )
-            .bind(&version_uuid)
             .bind(page_uuid)
+            .bind(organization_uuid)
             .bind(next_version_number)
-            .bind(content)
-            .bind(now)
-            .bind(now)
-            .execute(p)
+            .execute(&mut *tx)
             .await?;
-        }
-    }
-
-    // Update page's current_version_uuid and last_updated
-    match pool {
-        DatabasePool::MySql(p) => {
-            sqlx::query(
-                "UPDATE module_docs_pages SET current_version_uuid = ?, last_updated = ? WHERE uuid = ? AND organization_uuid = ?",
+            record_area_activity_postgres(
+                &mut tx,
+                organization_uuid,
+                &page.area_uuid,
+                user_uuid,
+                DocsActivityAction::PageUpdated,
+                page_uuid,
+                &page.title,
             )
-            .bind(&version_uuid)
-            .bind(now)
-            .bind(page_uuid)
-            .bind(organization_uuid)
-            .execute(p)
             .await?;
+            tx.commit().await?;
         }
-        DatabasePool::Postgres(p) => {
+        DatabasePool::Sqlite(p) => {
+            let mut tx = p.begin().await?;
             sqlx::query(
-                "UPDATE module_docs_pages SET current_version_uuid = $1, last_updated = $2 WHERE uuid = $3 AND organization_uuid = $4",
+                "UPDATE module_docs_pages SET current_version_uuid = ?1, last_updated = ?2
+                 WHERE uuid = ?3 AND organization_uuid = ?4
+                   AND (current_version_uuid IS NULL
+                        OR (SELECT v.version_number FROM module_docs_page_versions v WHERE v.uuid = module_docs_pages.current_version_uuid) < ?5)",
             )
             .bind(&version_uuid)
             .bind(now)
             .bind(page_uuid)
             .bind(organization_uuid)
-            .execute(p)
+            .bind(next_version_number)
+            .execute(&mut *tx)
             .await?;
-        }
-        DatabasePool::Sqlite(p) => {
-            sqlx::query(
-                "UPDATE module_docs_pages SET current_version_uuid = ?1, last_updated = ?2 WHERE uuid = ?3 AND organization_uuid = ?4",
+            record_area_activity_sqlite(
+                &mut tx,
+                organization_uuid,
+                &page.area_uuid,
+                user_uuid,
+                DocsActivityAction::PageUpdated,
+                page_uuid,
+                &page.title,
             )
-            .bind(&version_uuid)
-            .bind(now)
-            .bind(page_uuid)
-            .bind(organization_uuid)
-            .execute(p)
             .await?;
+            tx.commit().await?;
         }
     }
 
@@ -2252,6 +3281,18 @@ pub async fn list_page_versions(
     }
 }
 
+/// Editable properties of a page as sent in `module_docs_page_properties_updated` events
+fn page_properties_json(page: &DocsPage) -> JsonValue {
+    json!({
+        "title": page.title,
+        "short_summary": page.short_summary,
+        "auto_sync_to_vector_db": page.auto_sync_to_vector_db,
+        "vcs_export_allowed": page.vcs_export_allowed,
+        "includes_private_data": page.includes_private_data,
+        "metadata": page.metadata
+    })
+}
+
 /// Update page properties (title, short_summary, auto_sync_to_vector_db, vcs_export_allowed, includes_private_data, metadata)
 ///
 /// # Arguments
@@ -2330,13 +3371,7 @@ pub async fn update_page_properties(
             DocsAreaDatabaseError::AreaNotInOrganization => {
                 DocsPageDatabaseError::AreaNotInOrganization
             }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
         })?;
 
     // Check if user has permission to edit page properties
@@ -2440,22 +3475,24 @@ pub async fn update_page_properties(
         }
     }
 
-    // Emit page properties updated event
-    let page = load_page_by_uuid(pool, page_uuid).await.ok();
+    // Emit page properties updated event, with the changed properties compared to
+    // the page loaded before the update
+    let properties_before = page_properties_json(&page);
+    let properties = load_page_by_uuid(pool, page_uuid)
+        .await
+        .ok()
+        .map(|p| page_properties_json(&p));
     let event = Event::new(
         "module_docs_page_properties_updated",
         EventPayload::new(json!({
             "entity_type": "page",
             "entity_id": page_uuid,
             "organization_uuid": organization_uuid,
-            "data": page.as_ref().map(|p| json!({
-                "title": p.title,
-                "short_summary": p.short_summary,
-                "auto_sync_to_vector_db": p.auto_sync_to_vector_db,
-                "vcs_export_allowed": p.vcs_export_allowed,
-                "includes_private_data": p.includes_private_data,
-                "metadata": p.metadata
-            })).unwrap_or(json!({}))
+            "changes": properties
+                .as_ref()
+                .map(|properties| field_changes(&properties_before, properties))
+                .unwrap_or(json!({})),
+            "data": properties.unwrap_or(json!({}))
         }))
     )
     .with_organization(organization_uuid)
@@ -2479,7 +3516,7 @@ pub async fn update_page_properties(
 /// * `organization_uuid` - UUID of the organization
 /// * `user_uuid` - UUID of the user performing the move
 /// * `folder_uuid` - UUID of the target folder (None for root)
-/// * `sort_order` - Sort order (currently not used, but kept for API consistency)
+/// * `sort_order` - Ignored, the page is placed after the pages of the target folder
 /// * `dispatcher` - Optional event dispatcher
 ///
 /// # Returns
@@ -2550,13 +3587,7 @@ pub async fn move_page(
             DocsAreaDatabaseError::AreaNotInOrganization => {
                 DocsPageDatabaseError::AreaNotInOrganization
             }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
         })?;
 
     // Check area member permissions
@@ -2573,13 +3604,7 @@ pub async fn move_page(
             DocsAreaDatabaseError::AreaNotInOrganization => {
                 DocsPageDatabaseError::AreaNotInOrganization
             }
-            DocsAreaDatabaseError::EmptyShortName => {
-                DocsPageDatabaseError::Database(
-                    flextide_core::database::DatabaseError::PoolCreationFailed(
-                        sqlx::Error::RowNotFound,
-                    ),
-                )
-            }
+            DocsAreaDatabaseError::EmptyShortName => DocsPageDatabaseError::EmptyAreaShortName,
         })?;
 
     // Check if user has permission to edit pages
@@ -2618,49 +3643,61 @@ pub async fn move_page(
         return Err(DocsPageDatabaseError::PermissionDenied);
     }
 
-    // Update folder_uuid
+    // Update folder_uuid and append the page to the target folder
     match pool {
         DatabasePool::MySql(p) => {
+            let mut tx = p.begin().await?;
+            let sort_order = next_page_sort_order_mysql(&mut tx, &page.area_uuid, folder_uuid.as_deref()).await?;
             let result = sqlx::query(
-                "UPDATE module_docs_pages SET folder_uuid = ? WHERE uuid = ? AND organization_uuid = ?"
+                "UPDATE module_docs_pages SET folder_uuid = ?, sort_order = ? WHERE uuid = ? AND organization_uuid = ?"
             )
                 .bind(&folder_uuid)
+                .bind(sort_order)
                 .bind(page_uuid)
                 .bind(organization_uuid)
-                .execute(p)
+                .execute(&mut *tx)
                 .await?;
 
             if result.rows_affected() == 0 {
                 return Err(DocsPageDatabaseError::PageNotFound);
             }
+            tx.commit().await?;
         }
         DatabasePool::Postgres(p) => {
+            let mut tx = p.begin().await?;
+            let sort_order = next_page_sort_order_postgres(&mut tx, &page.area_uuid, folder_uuid.as_deref()).await?;
             let result = sqlx::query(
-                "UPDATE module_docs_pages SET folder_uuid = $1 WHERE uuid = $2 AND organization_uuid = $3"
+                "UPDATE module_docs_pages SET folder_uuid = $1, sort_order = $2 WHERE uuid = $3 AND organization_uuid = $4"
             )
                 .bind(&folder_uuid)
+                .bind(sort_order)
                 .bind(page_uuid)
                 .bind(organization_uuid)
-                .execute(p)
+                .execute(&mut *tx)
                 .await?;
 
             if result.rows_affected() == 0 {
                 return Err(DocsPageDatabaseError::PageNotFound);
             }
+            tx.commit().await?;
         }
         DatabasePool::Sqlite(p) => {
+            let mut tx = p.begin().await?;
+            let sort_order = next_page_sort_order_sqlite(&mut tx, &page.area_uuid, folder_uuid.as_deref()).await?;
             let result = sqlx::query(
-                "UPDATE module_docs_pages SET folder_uuid = ?1 WHERE uuid = ?2 AND organization_uuid = ?3"
+                "UPDATE module_docs_pages SET folder_uuid = ?1, sort_order = ?2 WHERE uuid = ?3 AND organization_uuid = ?4"
             )
                 .bind(&folder_uuid)
+                .bind(sort_order)
                 .bind(page_uuid)
                 .bind(organization_uuid)
-                .execute(p)
+                .execute(&mut *tx)
                 .await?;
 
             if result.rows_affected() == 0 {
                 return Err(DocsPageDatabaseError::PageNotFound);
             }
+            tx.commit().await?;
         }
     }
 
@@ -2694,6 +3731,264 @@ pub async fn move_page(
     Ok(())
 }
 
+/// Reorder the pages of a folder
+///
+/// The listed pages get contiguous sort orders starting at 0 in the given order. Pages
+/// of the folder that are not listed keep their relative order and are placed after
+/// the listed ones. All sort orders are written in one transaction.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization
+/// * `user_uuid` - UUID of the user reordering the pages
+/// * `folder_uuid` - UUID of the folder containing the pages
+/// * `ordered_page_uuids` - Pages in their new order
+/// * `dispatcher` - Optional event dispatcher
+///
+/// # Returns
+/// Returns the UUIDs of all pages of the folder in their new order
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - User does not belong to the organization
+/// - Folder not found or does not belong to the organization
+/// - User does not have permission to edit pages in the area
+/// - A listed page is not in the folder (`PageNotInFolder`) or listed twice (`DuplicatePageInOrder`)
+/// - Database operation fails
+pub async fn reorder_pages(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    user_uuid: &str,
+    folder_uuid: &str,
+    ordered_page_uuids: &[String],
+    dispatcher: Option<&EventDispatcher>,
+) -> Result<Vec<String>, DocsPageDatabaseError> {
+    // Check if user belongs to organization
+    let belongs = user_belongs_to_organization(pool, user_uuid, organization_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            DocsPageDatabaseError::Database(e.into())
+        })?;
+
+    if !belongs {
+        return Err(DocsPageDatabaseError::UserNotInOrganization);
+    }
+
+    // Load folder to verify it belongs to the organization
+    let folder = crate::folder::load_folder_by_uuid(pool, folder_uuid)
+        .await
+        .map_err(|e| match e {
+            crate::folder::DocsFolderDatabaseError::Database(db_err) => DocsPageDatabaseError::Database(db_err),
+            crate::folder::DocsFolderDatabaseError::Sql(sql_err) => DocsPageDatabaseError::Sql(sql_err),
+            _ => DocsPageDatabaseError::FolderNotFound,
+        })?;
+
+    if folder.organization_uuid != organization_uuid {
+        return Err(DocsPageDatabaseError::FolderNotFound);
+    }
+
+    // Check if user has permission to edit pages
+    let member_perms = load_area_member_permissions(pool, &folder.area_uuid, user_uuid).await?;
+    let can_edit = member_perms
+        .as_ref()
+        .is_some_and(|perms| perms.admin || perms.role == "owner" || perms.can_edit_pages);
+
+    let has_super_admin = user_has_permission(pool, user_uuid, organization_uuid, "module_docs_super_admin")
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking super_admin permission: {}", e);
+            match e {
+                flextide_core::user::UserDatabaseError::Database(db_err) => DocsPageDatabaseError::Database(db_err),
+                flextide_core::user::UserDatabaseError::Sql(sql_err) => DocsPageDatabaseError::Sql(sql_err),
+                _ => DocsPageDatabaseError::Database(DatabaseError::PoolCreationFailed(sqlx::Error::RowNotFound)),
+            }
+        })?;
+
+    if !can_edit && !has_super_admin {
+        return Err(DocsPageDatabaseError::PermissionDenied);
+    }
+
+    let order = match pool {
+        DatabasePool::MySql(p) => {
+            let mut tx = p.begin().await?;
+
+            let current: Vec<String> = sqlx::query(
+                "SELECT uuid FROM module_docs_pages
+                 WHERE organization_uuid = ? AND folder_uuid = ?
+                 ORDER BY sort_order ASC, created_at DESC",
+            )
+            .bind(organization_uuid)
+            .bind(folder_uuid)
+            .fetch_all(&mut *tx)
+            .await?
+            .iter()
+            .map(|row| row.get("uuid"))
+            .collect();
+            let order = page_sort_order(&current, ordered_page_uuids)?;
+
+            for (sort_order, page_uuid) in order.iter().enumerate() {
+                sqlx::query("UPDATE module_docs_pages SET sort_order = ? WHERE uuid = ?")
+                    .bind(i32::try_from(sort_order).unwrap_or(i32::MAX))
+                    .bind(page_uuid)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+
+            tx.commit().await?;
+            order
+        }
+        DatabasePool::Postgres(p) => {
+            let mut tx = p.begin().await?;
+
+            let current: Vec<String> = sqlx::query(
+                "SELECT uuid FROM module_docs_pages
+                 WHERE organization_uuid = $1 AND folder_uuid = $2
+                 ORDER BY sort_order ASC, created_at DESC",
+            )
+            .bind(organization_uuid)
+            .bind(folder_uuid)
+            .fetch_all(&mut *tx)
+            .await?
+            .iter()
+            .map(|row| row.get("uuid"))
+            .collect();
+            let order = page_sort_order(&current, ordered_page_uuids)?;
+
+            for (sort_order, page_uuid) in order.iter().enumerate() {
+                sqlx::query("UPDATE module_docs_pages SET sort_order = $1 WHERE uuid = $2")
+                    .bind(i32::try_from(sort_order).unwrap_or(i32::MAX))
+                    .bind(page_uuid)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+
+            tx.commit().await?;
+            order
+        }
+        DatabasePool::Sqlite(p) => {
+            let mut tx = p.begin().await?;
+
+            let current: Vec<String> = sqlx::query(
+                "SELECT uuid FROM module_docs_pages
+                 WHERE organization_uuid = ?1 AND folder_uuid = ?2
+                 ORDER BY sort_order ASC, created_at DESC",
+            )
+            .bind(organization_uuid)
+            .bind(folder_uuid)
+            .fetch_all(&mut *tx)
+            .await?
+            .iter()
+            .map(|row| row.get("uuid"))
+            .collect();
+            let order = page_sort_order(&current, ordered_page_uuids)?;
+
+            for (sort_order, page_uuid) in order.iter().enumerate() {
+                sqlx::query("UPDATE module_docs_pages SET sort_order = ?1 WHERE uuid = ?2")
+                    .bind(i32::try_from(sort_order).unwrap_or(i32::MAX))
+                    .bind(page_uuid)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+
+            tx.commit().await?;
+            order
+        }
+    };
+
+    // Emit pages reordered event
+    if let Some(disp) = dispatcher {
+        let event = Event::new(
+            "module_docs_pages_reordered",
+            EventPayload::new(json!({
+                "entity_type": "folder",
+                "entity_id": folder_uuid,
+                "organization_uuid": organization_uuid,
+                "data": {
+                    "area_uuid": folder.area_uuid,
+                    "page_uuids": order
+                }
+            }))
+        )
+        .with_organization(organization_uuid)
+        .with_user(user_uuid);
+
+        disp.emit(event).await;
+    }
+
+    info!(
+        "Successfully reordered {} pages of folder {} in organization {}",
+        order.len(), folder_uuid, organization_uuid
+    );
+
+    Ok(order)
+}
+
+/// Sort order placing a page after all pages of its folder (or of the area root)
+async fn next_page_sort_order_mysql(
+    conn: &mut sqlx::MySqlConnection,
+    area_uuid: &str,
+    folder_uuid: Option<&str>,
+) -> Result<i32, sqlx::Error> {
+    let max: Option<i32> = sqlx::query_scalar(
+        "SELECT MAX(sort_order) FROM module_docs_pages WHERE area_uuid = ? AND folder_uuid <=> ?",
+    )
+    .bind(area_uuid)
+    .bind(folder_uuid)
+    .fetch_one(&mut *conn)
+    .await?;
+    Ok(max.map_or(0, |max| max.saturating_add(1)))
+}
+
+async fn next_page_sort_order_postgres(
+    conn: &mut sqlx::PgConnection,
+    area_uuid: &str,
+    folder_uuid: Option<&str>,
+) -> Result<i32, sqlx::Error> {
+    let max: Option<i32> = sqlx::query_scalar(
+        "SELECT MAX(sort_order) FROM module_docs_pages WHERE area_uuid = $1 AND folder_uuid IS NOT DISTINCT FROM $2",
+    )
+    .bind(area_uuid)
+    .bind(folder_uuid)
+    .fetch_one(&mut *conn)
+    .await?;
+    Ok(max.map_or(0, |max| max.saturating_add(1)))
+}
+
+async fn next_page_sort_order_sqlite(
+    conn: &mut sqlx::SqliteConnection,
+    area_uuid: &str,
+    folder_uuid: Option<&str>,
+) -> Result<i32, sqlx::Error> {
+    let max: Option<i32> = sqlx::query_scalar(
+        "SELECT MAX(sort_order) FROM module_docs_pages WHERE area_uuid = ?1 AND folder_uuid IS ?2",
+    )
+    .bind(area_uuid)
+    .bind(folder_uuid)
+    .fetch_one(&mut *conn)
+    .await?;
+    Ok(max.map_or(0, |max| max.saturating_add(1)))
+}
+
+/// Combine the requested order with the current order of a folder's pages
+///
+/// Requested pages come first, the remaining pages follow in their current order.
+fn page_sort_order(current: &[String], requested: &[String]) -> Result<Vec<String>, DocsPageDatabaseError> {
+    let mut order: Vec<String> = Vec::with_capacity(current.len());
+    for page_uuid in requested {
+        if !current.contains(page_uuid) {
+            return Err(DocsPageDatabaseError::PageNotInFolder(page_uuid.clone()));
+        }
+        if order.contains(page_uuid) {
+            return Err(DocsPageDatabaseError::DuplicatePageInOrder(page_uuid.clone()));
+        }
+        order.push(page_uuid.clone());
+    }
+
+    order.extend(current.iter().filter(|page_uuid| !requested.contains(page_uuid)).cloned());
+    Ok(order)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2844,7 +4139,7 @@ mod tests {
         }
 
         // Test: Create initial version
-        let version_uuid = create_initial_page_version(&pool, &page_uuid, page_title)
+        let version_uuid = create_initial_page_version(&pool, &page_uuid, page_title, DEFAULT_PAGE_TEMPLATE)
             .await
             .expect("Failed to create initial page version");
 
@@ -3148,5 +4443,1273 @@ mod tests {
 
         Ok(())
     }
-}
 
+    /// Summary generator failing for pages whose title contains "fail"
+    struct MockSummaryGenerator;
+
+    #[async_trait::async_trait]
+    impl crate::summary::PageSummaryGenerator for MockSummaryGenerator {
+        async fn generate_summary(
+            &self,
+            page: &DocsPage,
+            _version: &DocsPageVersion,
+        ) -> Result<String, crate::summary::PageSummaryError> {
+            if page.title.to_lowercase().contains("fail") {
+                Err(crate::summary::PageSummaryError::ProviderError("mock failure".to_string()))
+            } else {
+                Ok(format!("Summary of {}", page.title))
+            }
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_generate_area_summaries_reports_each_page(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        let mut page_uuids = Vec::new();
+        for (title, page_type) in [
+            ("Alpha", None),
+            ("Will fail", None),
+            ("Beta", None),
+            ("Without content", Some("database")),
+            ("Gamma", None),
+            ("Already summarized", None),
+        ] {
+            let request = CreateDocsPageRequest {
+                area_uuid: area_uuid.clone(),
+                title: title.to_string(),
+                short_summary: None,
+                folder_uuid: None,
+                parent_page_uuid: None,
+                page_type: page_type.map(str::to_string),
+                auto_sync_to_vector_db: None,
+                vcs_export_allowed: None,
+                includes_private_data: None,
+            };
+            page_uuids.push(
+                create_page(&pool, &org_uuid, &user_uuid, request, &dispatcher)
+                    .await
+                    .expect("Failed to create page"),
+            );
+        }
+        save_page_summary(&pool, &org_uuid, &page_uuids[5], "Existing", &dispatcher, None)
+            .await
+            .expect("Failed to save summary");
+
+        let report = generate_area_summaries_with_generator(
+            &pool,
+            &org_uuid,
+            &area_uuid,
+            &MockSummaryGenerator,
+            "mock",
+            &crate::summary::SummaryRateLimiter::new(std::time::Duration::from_secs(60)),
+            &dispatcher,
+            Some(&user_uuid),
+        )
+        .await
+        .expect("Area summary generation should not fail as a whole");
+
+        assert_eq!(report.area_uuid, area_uuid);
+        assert_eq!(report.succeeded, 3);
+        assert_eq!(report.failed, 2);
+
+        // Pages with a summary are skipped, the others keep their creation order
+        let outcomes: Vec<_> = report
+            .pages
+            .iter()
+            .map(|outcome| (outcome.page_uuid.as_str(), outcome.summary.as_deref(), outcome.error.is_some()))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (page_uuids[0].as_str(), Some("Summary of Alpha"), false),
+                (page_uuids[1].as_str(), None, true),
+                (page_uuids[2].as_str(), Some("Summary of Beta"), false),
+                (page_uuids[3].as_str(), None, true),
+                (page_uuids[4].as_str(), Some("Summary of Gamma"), false),
+            ]
+        );
+        assert_eq!(report.pages[1].title, "Will fail");
+
+        // Successful summaries are saved, failed and skipped pages are unchanged
+        assert_eq!(
+            load_page_by_uuid(&pool, &page_uuids[2]).await.unwrap().short_summary.as_deref(),
+            Some("Summary of Beta")
+        );
+        assert_eq!(load_page_by_uuid(&pool, &page_uuids[1]).await.unwrap().short_summary, None);
+        assert_eq!(
+            load_page_by_uuid(&pool, &page_uuids[5]).await.unwrap().short_summary.as_deref(),
+            Some("Existing")
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_generate_area_summaries_waits_for_rate_limit(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        let page_count = crate::summary::DEFAULT_SUMMARY_CALLS_PER_MINUTE as usize + 2;
+        for index in 0..page_count {
+            let request = CreateDocsPageRequest {
+                area_uuid: area_uuid.clone(),
+                title: format!("Page {}", index),
+                short_summary: None,
+                folder_uuid: None,
+                parent_page_uuid: None,
+                page_type: None,
+                auto_sync_to_vector_db: None,
+                vcs_export_allowed: None,
+                includes_private_data: None,
+            };
+            create_page(&pool, &org_uuid, &user_uuid, request, &dispatcher)
+                .await
+                .expect("Failed to create page");
+        }
+
+        // Short window, so the pages above the limit wait briefly instead of a minute
+        let window = std::time::Duration::from_millis(300);
+        let started = std::time::Instant::now();
+        let report = generate_area_summaries_with_generator(
+            &pool,
+            &org_uuid,
+            &area_uuid,
+            &MockSummaryGenerator,
+            "mock",
+            &crate::summary::SummaryRateLimiter::new(window),
+            &dispatcher,
+            Some(&user_uuid),
+        )
+        .await
+        .expect("Area summary generation should not fail as a whole");
+
+        assert_eq!(report.succeeded, page_count);
+        assert_eq!(report.failed, 0);
+        assert!(started.elapsed() >= window);
+
+        Ok(())
+    }
+
+    /// Summary generator counting how often the provider is called
+    #[derive(Default)]
+    struct CountingSummaryGenerator {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl CountingSummaryGenerator {
+        fn calls(&self) -> usize {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl crate::summary::PageSummaryGenerator for CountingSummaryGenerator {
+        async fn generate_summary(
+            &self,
+            _page: &DocsPage,
+            version: &DocsPageVersion,
+        ) -> Result<String, crate::summary::PageSummaryError> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            Ok(format!("Summary {} of {}", call, version.content))
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_generate_page_summary_reuses_summary_for_unchanged_content(
+        pool: sqlx::SqlitePool,
+    ) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        let request = CreateDocsPageRequest {
+            area_uuid,
+            title: "Cached".to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request, &dispatcher)
+            .await
+            .expect("Failed to create page");
+        save_page_content(&pool, &org_uuid, &page_uuid, &user_uuid, "first", &dispatcher)
+            .await
+            .expect("Failed to save content");
+
+        let generator = CountingSummaryGenerator::default();
+        let generate = |force| {
+            generate_page_summary_with_generator(
+                &pool,
+                &org_uuid,
+                &page_uuid,
+                &generator,
+                "mock",
+                &dispatcher,
+                Some(&user_uuid),
+                force,
+            )
+        };
+
+        // Nothing saved yet, so the provider is called
+        let summary = generate(false).await.expect("Failed to generate summary");
+        assert_eq!(summary, "Summary 1 of first");
+        save_page_summary(&pool, &org_uuid, &page_uuid, &summary, &dispatcher, None)
+            .await
+            .expect("Failed to save summary");
+
+        // Unchanged content reuses the saved summary
+        assert_eq!(generate(false).await.unwrap(), "Summary 1 of first");
+        assert_eq!(generator.calls(), 1);
+
+        // Force bypasses the saved summary
+        assert_eq!(generate(true).await.unwrap(), "Summary 2 of first");
+        assert_eq!(generator.calls(), 2);
+
+        // Changed content invalidates the saved summary
+        save_page_content(&pool, &org_uuid, &page_uuid, &user_uuid, "second", &dispatcher)
+            .await
+            .expect("Failed to save content");
+        assert_eq!(generate(false).await.unwrap(), "Summary 3 of second");
+        assert_eq!(generator.calls(), 3);
+        save_page_summary(&pool, &org_uuid, &page_uuid, "Summary 3 of second", &dispatcher, None)
+            .await
+            .expect("Failed to save summary");
+
+        // The saved summary is returned before the provider is set up, none is configured here
+        let summary = generate_page_summary(&pool, &org_uuid, &page_uuid, &dispatcher, None, false)
+            .await
+            .expect("Saved summary should be reused");
+        assert_eq!(summary, "Summary 3 of second");
+
+        // Changed summary options invalidate the saved summary
+        crate::test_support::set_setting(&pool, &org_uuid, "module_docs_summary_style", "formal").await;
+        assert_eq!(generate(false).await.unwrap(), "Summary 4 of second");
+        assert_eq!(generator.calls(), 4);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_save_page_content_rejects_too_large_content(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        let request = CreateDocsPageRequest {
+            area_uuid,
+            title: "Large".to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request, &dispatcher)
+            .await
+            .expect("Failed to create page");
+        let version_before = load_page_by_uuid(&pool, &page_uuid).await.unwrap().current_version_uuid;
+
+        // One byte over the limit is rejected without creating a version
+        let too_large = "a".repeat(DEFAULT_MAX_PAGE_CONTENT_BYTES + 1);
+        assert!(matches!(
+            save_page_content(&pool, &org_uuid, &page_uuid, &user_uuid, &too_large, &dispatcher).await,
+            Err(DocsPageDatabaseError::ContentTooLarge { length, max_length })
+                if length == DEFAULT_MAX_PAGE_CONTENT_BYTES + 1 && max_length == DEFAULT_MAX_PAGE_CONTENT_BYTES
+        ));
+        assert_eq!(load_page_by_uuid(&pool, &page_uuid).await.unwrap().current_version_uuid, version_before);
+
+        // Content just under and exactly at the limit is saved
+        let just_under = "a".repeat(DEFAULT_MAX_PAGE_CONTENT_BYTES - 1);
+        save_page_content(&pool, &org_uuid, &page_uuid, &user_uuid, &just_under, &dispatcher)
+            .await
+            .expect("Content below the limit should be saved");
+        let at_limit = "a".repeat(DEFAULT_MAX_PAGE_CONTENT_BYTES);
+        let version_uuid = save_page_content(&pool, &org_uuid, &page_uuid, &user_uuid, &at_limit, &dispatcher)
+            .await
+            .expect("Content at the limit should be saved");
+        assert_eq!(
+            load_page_by_uuid(&pool, &page_uuid).await.unwrap().current_version_uuid,
+            Some(version_uuid)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_update_page_properties_event_contains_changes(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_organization, setup_tables, RecordingSubscriber};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+        let (recorder, received) = RecordingSubscriber::new("module_docs_page_properties_updated");
+        dispatcher.subscribe(Box::new(recorder));
+
+        let request = CreateDocsPageRequest {
+            area_uuid,
+            title: "Old title".to_string(),
+            short_summary: Some("Unchanged summary".to_string()),
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request, &dispatcher)
+            .await
+            .expect("Failed to create page");
+        let page = load_page_by_uuid(&pool, &page_uuid).await.unwrap();
+
+        update_page_properties(
+            &pool,
+            &page_uuid,
+            &org_uuid,
+            &user_uuid,
+            "New title",
+            Some("Unchanged summary"),
+            page.auto_sync_to_vector_db != 0,
+            page.vcs_export_allowed != 0,
+            page.includes_private_data == 0,
+            json!({ "owner": "docs-team" }),
+            &dispatcher,
+        )
+        .await
+        .expect("Failed to update page properties");
+
+        let events = received.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].payload.data["changes"],
+            json!({
+                "title": { "old": "Old title", "new": "New title" },
+                "includes_private_data": {
+                    "old": page.includes_private_data,
+                    "new": i32::from(page.includes_private_data == 0)
+                },
+                "metadata": { "old": null, "new": { "owner": "docs-team" } }
+            })
+        );
+        assert_eq!(events[0].payload.data["data"]["title"], "New title");
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_generate_page_summary_is_rate_limited(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_organization, set_setting, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+        set_setting(&pool, &org_uuid, "module_docs_summary_calls_per_minute", "2").await;
+
+        let request = CreateDocsPageRequest {
+            area_uuid,
+            title: "Throttled".to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request, &dispatcher)
+            .await
+            .expect("Failed to create page");
+        save_page_content(&pool, &org_uuid, &page_uuid, &user_uuid, "content", &dispatcher)
+            .await
+            .expect("Failed to save content");
+
+        let generator = CountingSummaryGenerator::default();
+        for _ in 0..2 {
+            generate_page_summary_with_generator(
+                &pool, &org_uuid, &page_uuid, &generator, "mock", &dispatcher, None, true,
+            )
+            .await
+            .expect("Calls within the limit should succeed");
+        }
+
+        // The third call within the same minute is rejected before the provider is called
+        let result = generate_page_summary_with_generator(
+            &pool, &org_uuid, &page_uuid, &generator, "mock", &dispatcher, None, true,
+        )
+        .await;
+        assert!(
+            matches!(result, Err(DocsPageDatabaseError::RateLimited { retry_after_seconds }) if retry_after_seconds > 0),
+            "result: {:?}",
+            result
+        );
+        assert_eq!(generator.calls(), 2);
+
+        Ok(())
+    }
+
+    /// Serve a single OpenAI chat completion request and return the received request body
+    async fn serve_mock_openai(listener: tokio::net::TcpListener) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (mut stream, _) = listener.accept().await.expect("Failed to accept connection");
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 4096];
+        let body = loop {
+            let read = stream.read(&mut buffer).await.expect("Failed to read request");
+            request.extend_from_slice(&buffer[..read]);
+            let text = String::from_utf8_lossy(&request).to_string();
+            if let Some((headers, body)) = text.split_once("\r\n\r\n") {
+                let content_length = headers
+                    .lines()
+                    .find_map(|line| {
+                        let (name, value) = line.split_once(':')?;
+                        name.eq_ignore_ascii_case("content-length").then(|| value.trim().parse::<usize>().ok())?
+                    })
+                    .unwrap_or(0);
+                if body.len() >= content_length || read == 0 {
+                    break body.to_string();
+                }
+            }
+        };
+
+        let response = json!({
+            "id": "mock",
+            "object": "chat.completion",
+            "created": 0,
+            "model": "mock-model",
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": "Mock summary" },
+                "finish_reason": "stop"
+            }],
+            "usage": { "prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2 }
+        })
+        .to_string();
+        stream
+            .write_all(
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                )
+                .as_bytes(),
+            )
+            .await
+            .expect("Failed to write response");
+
+        body
+    }
+
+    #[sqlx::test]
+    async fn test_summary_falls_back_to_next_provider(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::summary::FallbackPageSummaryGenerator;
+        use crate::test_support::{create_area, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        let request = CreateDocsPageRequest {
+            area_uuid,
+            title: "Fail on primary".to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request, &dispatcher)
+            .await
+            .expect("Failed to create page");
+        save_page_content(&pool, &org_uuid, &page_uuid, &user_uuid, "content", &dispatcher)
+            .await
+            .expect("Failed to save content");
+
+        // The primary fails for this page's title, the secondary succeeds
+        let generator = FallbackPageSummaryGenerator::new(vec![
+            ("openai".to_string(), Box::new(MockSummaryGenerator)),
+            ("claude".to_string(), Box::new(CountingSummaryGenerator::default())),
+        ]);
+        let summary = generate_page_summary_with_generator(
+            &pool,
+            &org_uuid,
+            &page_uuid,
+            &generator,
+            "openai,claude",
+            &dispatcher,
+            None,
+            true,
+        )
+        .await
+        .expect("Secondary provider should generate the summary");
+        assert_eq!(summary, "Summary 1 of content");
+
+        // The last error is returned if all providers fail
+        let generator = FallbackPageSummaryGenerator::new(vec![
+            ("openai".to_string(), Box::new(MockSummaryGenerator)),
+            ("claude".to_string(), Box::new(MockSummaryGenerator)),
+        ]);
+        let result = generate_page_summary_with_generator(
+            &pool, &org_uuid, &page_uuid, &generator, "openai,claude", &dispatcher, None, true,
+        )
+        .await;
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_summary_provider_chain_skips_providers_not_set_up(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_organization, set_setting, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+
+        set_setting(&pool, &org_uuid, "module_docs_page_summary_ai_provider", "claude, gemini").await;
+        assert!(matches!(
+            create_summary_generator(&pool, &org_uuid).await,
+            Err(DocsPageDatabaseError::UnsupportedAIProvider(provider)) if provider == "gemini"
+        ));
+
+        set_setting(&pool, &org_uuid, "module_docs_page_summary_ai_provider", "claude,openai").await;
+        set_setting(&pool, &org_uuid, "module_docs_openai_api_key", "test-key").await;
+        set_setting(&pool, &org_uuid, "module_docs_openai_model", "gpt-4o-mini").await;
+        set_setting(&pool, &org_uuid, "module_docs_summary_max_words", "").await;
+        set_setting(&pool, &org_uuid, "module_docs_summary_style", "").await;
+        let (ai_provider, _) = create_summary_generator(&pool, &org_uuid)
+            .await
+            .expect("OpenAI should be used after skipping Claude");
+        assert_eq!(ai_provider, "claude,openai");
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_build_summary_generator_for_each_provider(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_organization, set_setting, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        set_setting(&pool, &org_uuid, "module_docs_openai_model", "gpt-4o-mini").await;
+        set_setting(&pool, &org_uuid, "module_docs_summary_max_words", "").await;
+        set_setting(&pool, &org_uuid, "module_docs_summary_style", "").await;
+
+        // OpenAI requires an API key: define the setting without a value
+        set_setting(&pool, &org_uuid, "module_docs_openai_api_key", "").await;
+        if let DatabasePool::Sqlite(p) = &pool {
+            sqlx::query("DELETE FROM organizational_settings_values WHERE setting_name = 'module_docs_openai_api_key'")
+                .execute(p)
+                .await?;
+        }
+        assert!(matches!(
+            build_summary_generator("openai", &pool, &org_uuid).await,
+            Err(DocsPageDatabaseError::AIProviderSettingNotFound)
+        ));
+
+        set_setting(&pool, &org_uuid, "module_docs_openai_api_key", "test-key").await;
+        assert!(build_summary_generator("openai", &pool, &org_uuid).await.is_ok());
+
+        for provider in ["claude", "gemini", "mistral"] {
+            assert!(
+                matches!(
+                    build_summary_generator(provider, &pool, &org_uuid).await,
+                    Err(DocsPageDatabaseError::UnsupportedAIProvider(name)) if name == provider
+                ),
+                "provider: {}",
+                provider
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Summary generator answering title suggestions like a chatty model would
+    struct TitleSuggestingGenerator;
+
+    #[async_trait::async_trait]
+    impl crate::summary::PageSummaryGenerator for TitleSuggestingGenerator {
+        async fn generate_summary(
+            &self,
+            _page: &DocsPage,
+            _version: &DocsPageVersion,
+        ) -> Result<String, crate::summary::PageSummaryError> {
+            Ok("Unused".to_string())
+        }
+
+        async fn suggest_title(
+            &self,
+            _page: &DocsPage,
+            version: &DocsPageVersion,
+        ) -> Result<String, crate::summary::PageSummaryError> {
+            assert_eq!(version.content, "How to roll out a release to production");
+            Ok("\n\"Deploying to Production\"\nThis title describes the page.".to_string())
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_suggest_page_title_returns_suggestion_without_saving(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        let request = CreateDocsPageRequest {
+            area_uuid,
+            title: "Untitled".to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request, &dispatcher)
+            .await
+            .expect("Failed to create page");
+        save_page_content(
+            &pool,
+            &org_uuid,
+            &page_uuid,
+            &user_uuid,
+            "How to roll out a release to production",
+            &dispatcher,
+        )
+        .await
+        .expect("Failed to save content");
+
+        let title = suggest_page_title_with_generator(&pool, &org_uuid, &page_uuid, &TitleSuggestingGenerator)
+            .await
+            .expect("Failed to suggest title");
+        assert_eq!(title, "Deploying to Production");
+
+        // The suggestion is not persisted
+        assert_eq!(load_page_by_uuid(&pool, &page_uuid).await.unwrap().title, "Untitled");
+
+        // Providers without title support report an error
+        let result = suggest_page_title_with_generator(&pool, &org_uuid, &page_uuid, &MockSummaryGenerator).await;
+        assert!(matches!(result, Err(DocsPageDatabaseError::SummaryGeneration(_))), "{:?}", result);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_create_page_uses_organization_page_template(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_organization, set_setting, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+        let request = |title: &str| CreateDocsPageRequest {
+            area_uuid: area_uuid.clone(),
+            title: title.to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+        let initial_content = |page_uuid: String| {
+            let pool = &pool;
+            async move {
+                load_page_with_version(pool, &page_uuid)
+                    .await
+                    .expect("Failed to load page")
+                    .version
+                    .expect("Page should have an initial version")
+                    .content
+            }
+        };
+
+        // Default template while the setting is not defined
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request("Default"), &dispatcher)
+            .await
+            .expect("Failed to create page");
+        assert_eq!(initial_content(page_uuid).await, "# Default\n\n\n\n\n");
+
+        set_setting(
+            &pool,
+            &org_uuid,
+            "module_docs_page_template",
+            "---\ntitle: {{title}}\n---\n\n## Overview\n\n{{title}} is ...",
+        )
+        .await;
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request("Runbook"), &dispatcher)
+            .await
+            .expect("Failed to create page");
+        assert_eq!(
+            initial_content(page_uuid).await,
+            "---\ntitle: Runbook\n---\n\n## Overview\n\nRunbook is ..."
+        );
+
+        // Default template while the setting has no value
+        if let DatabasePool::Sqlite(p) = &pool {
+            sqlx::query("DELETE FROM organizational_settings_values WHERE setting_name = 'module_docs_page_template'")
+                .execute(p)
+                .await?;
+        }
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request("Unset"), &dispatcher)
+            .await
+            .expect("Failed to create page");
+        assert_eq!(initial_content(page_uuid).await, "# Unset\n\n\n\n\n");
+
+        set_setting(&pool, &org_uuid, "module_docs_page_template", "  \n ").await;
+        assert!(matches!(
+            create_page(&pool, &org_uuid, &user_uuid, request("Empty"), &dispatcher).await,
+            Err(DocsPageDatabaseError::EmptyPageTemplate)
+        ));
+        // No page without content is left behind
+        if let DatabasePool::Sqlite(p) = &pool {
+            let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM module_docs_pages WHERE title = 'Empty'")
+                .fetch_one(p)
+                .await?;
+            assert_eq!(count, 0);
+        }
+
+        Ok(())
+    }
+
+    /// Page type whose pages start with an empty JSON diagram and must contain JSON
+    struct DiagramPageType;
+
+    impl crate::page_type::PageTypeHandler for DiagramPageType {
+        fn page_type(&self) -> &str {
+            "test_diagram"
+        }
+
+        fn initial_content(&self, title: &str) -> InitialPageContent {
+            InitialPageContent::Content(json!({ "name": title, "nodes": [] }).to_string())
+        }
+
+        fn validate_content(&self, content: &str) -> Result<(), String> {
+            serde_json::from_str::<JsonValue>(content)
+                .map(|_| ())
+                .map_err(|e| format!("Diagram must be JSON: {}", e))
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_registered_page_type_defines_initialization(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+        let request = |page_type: &str| CreateDocsPageRequest {
+            area_uuid: area_uuid.clone(),
+            title: "Architecture".to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: Some(page_type.to_string()),
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+
+        PageTypeRegistry::global().register(std::sync::Arc::new(DiagramPageType));
+        assert!(PageTypeRegistry::global().page_types().contains(&"test_diagram".to_string()));
+
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request("test_diagram"), &dispatcher)
+            .await
+            .expect("Failed to create diagram page");
+        let version = load_page_with_version(&pool, &page_uuid)
+            .await
+            .expect("Failed to load page")
+            .version
+            .expect("Diagram page should have an initial version");
+        assert_eq!(
+            serde_json::from_str::<JsonValue>(&version.content).unwrap(),
+            json!({ "name": "Architecture", "nodes": [] })
+        );
+
+        // Content is validated by the page type
+        assert!(matches!(
+            save_page_content(&pool, &org_uuid, &page_uuid, &user_uuid, "not json", &dispatcher).await,
+            Err(DocsPageDatabaseError::InvalidPageContent(_))
+        ));
+        save_page_content(&pool, &org_uuid, &page_uuid, &user_uuid, r#"{"nodes": [1]}"#, &dispatcher)
+            .await
+            .expect("Valid diagram should be saved");
+
+        // Unregistered page types get no initial version and accept any content
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request("test_unregistered"), &dispatcher)
+            .await
+            .expect("Failed to create page");
+        assert!(load_page_with_version(&pool, &page_uuid).await.unwrap().version.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_concurrent_saves_get_distinct_version_numbers(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        let request = CreateDocsPageRequest {
+            area_uuid,
+            title: "Busy".to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request, &dispatcher)
+            .await
+            .expect("Failed to create page");
+
+        let contents: Vec<String> = (0..8).map(|i| format!("Edit {}", i)).collect();
+        let results = futures_util::future::join_all(
+            contents
+                .iter()
+                .map(|content| save_page_content(&pool, &org_uuid, &page_uuid, &user_uuid, content, &dispatcher)),
+        )
+        .await;
+        for result in &results {
+            assert!(result.is_ok(), "Save failed: {:?}", result);
+        }
+
+        // The initial version plus one distinct, sequential version per save
+        let versions = list_page_versions(&pool, &page_uuid, None, None)
+            .await
+            .expect("Failed to list versions");
+        let mut numbers: Vec<i32> = versions.iter().map(|v| v.version_number).collect();
+        numbers.sort();
+        assert_eq!(numbers, (1..=9).collect::<Vec<i32>>());
+
+        // The newest version is the current one
+        let page = load_page_with_version(&pool, &page_uuid).await.expect("Failed to load page");
+        assert_eq!(page.version.expect("Page should have a version").version_number, 9);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_summary_settings_are_sent_in_prompt(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::summary::{OpenAIPageSummaryGenerator, PageSummaryGenerator};
+        use crate::test_support::{create_area, create_organization, set_setting, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+        set_setting(&pool, &org_uuid, "module_docs_summary_max_words", "42").await;
+        set_setting(&pool, &org_uuid, "module_docs_summary_style", "technical").await;
+
+        let request = CreateDocsPageRequest {
+            area_uuid,
+            title: "Deployment".to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+        let page_uuid = create_page(&pool, &org_uuid, &user_uuid, request, &dispatcher)
+            .await
+            .expect("Failed to create page");
+        let page = load_page_by_uuid(&pool, &page_uuid).await.unwrap();
+        let version = load_current_page_version(&pool, &page).await.unwrap();
+
+        let options = load_summary_options(&pool, &org_uuid).await.unwrap();
+        assert_eq!(options.max_words, Some(42));
+        assert_eq!(options.style.as_deref(), Some("technical"));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let generator = OpenAIPageSummaryGenerator::with_base_url("test-key".to_string(), base_url, "mock-model".to_string())
+            .with_summary_options(options);
+
+        let (summary, body) = futures_util::join!(
+            generator.generate_summary(&page, &version),
+            serve_mock_openai(listener)
+        );
+        assert_eq!(summary.expect("Failed to generate summary"), "Mock summary");
+
+        let body: JsonValue = serde_json::from_str(&body).expect("Request body should be JSON");
+        let prompt = body["messages"][1]["content"].as_str().expect("Missing user prompt");
+        assert!(prompt.contains("at most 42 words"), "prompt: {}", prompt);
+        assert!(prompt.contains("technical style"), "prompt: {}", prompt);
+        assert!(prompt.contains("Page Title: Deployment"), "prompt: {}", prompt);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_summary_max_words_must_be_positive_integer(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_organization, set_setting, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        set_setting(&pool, &org_uuid, "module_docs_summary_style", "").await;
+
+        for invalid in ["0", "-5", "many", "1.5"] {
+            set_setting(&pool, &org_uuid, "module_docs_summary_max_words", invalid).await;
+            assert!(
+                matches!(
+                    load_summary_options(&pool, &org_uuid).await,
+                    Err(DocsPageDatabaseError::InvalidSummaryMaxWords(ref value)) if value == invalid
+                ),
+                "{} should be rejected",
+                invalid
+            );
+        }
+
+        // An empty value means no limit
+        set_setting(&pool, &org_uuid, "module_docs_summary_max_words", "").await;
+        assert_eq!(
+            load_summary_options(&pool, &org_uuid).await.unwrap(),
+            crate::summary::SummaryOptions::default()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_clone_page_copies_current_content(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{add_organization_member, create_area, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let outsider_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        add_organization_member(&pool, &org_uuid, &outsider_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let dispatcher = EventDispatcher::new();
+
+        let new_request = |title: &str, parent_page_uuid: Option<String>| CreateDocsPageRequest {
+            area_uuid: area_uuid.clone(),
+            title: title.to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: Some(true),
+            includes_private_data: None,
+        };
+        let parent_uuid = create_page(&pool, &org_uuid, &user_uuid, new_request("Parent", None), &dispatcher)
+            .await
+            .expect("Failed to create parent page");
+        let source_uuid = create_page(
+            &pool,
+            &org_uuid,
+            &user_uuid,
+            new_request("Source", Some(parent_uuid.clone())),
+            &dispatcher,
+        )
+        .await
+        .expect("Failed to create source page");
+
+        for content in ["# Source\n\nDraft", "# Source\n\nFinal"] {
+            save_page_content(&pool, &org_uuid, &source_uuid, &user_uuid, content, &dispatcher)
+                .await
+                .expect("Failed to save content");
+        }
+        if let DatabasePool::Sqlite(p) = &pool {
+            sqlx::query("UPDATE module_docs_pages SET metadata = ?1 WHERE uuid = ?2")
+                .bind(json!({ "owner": "docs-team" }))
+                .bind(&source_uuid)
+                .execute(p)
+                .await?;
+        }
+
+        let clone_uuid = clone_page(&pool, &org_uuid, &user_uuid, &source_uuid, "Copy", &dispatcher)
+            .await
+            .expect("Failed to clone page");
+
+        let clone = load_page_with_version(&pool, &clone_uuid).await.expect("Clone should exist");
+        assert_eq!(clone.title, "Copy");
+        assert_eq!(clone.area_uuid, area_uuid);
+        assert_eq!(clone.parent_page_uuid, None);
+        assert_eq!(clone.vcs_export_allowed, 1);
+        assert_eq!(clone.metadata, Some(json!({ "owner": "docs-team" })));
+        assert_eq!(
+            clone.version.map(|version| version.content),
+            Some("# Source\n\nFinal".to_string())
+        );
+
+        // The copy starts its own history with a single version
+        let versions = list_page_versions(&pool, &clone_uuid, None, None)
+            .await
+            .expect("Failed to list versions");
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].version_number, 1);
+
+        // Cloning needs the permission to add pages in the area
+        assert!(matches!(
+            clone_page(&pool, &org_uuid, &outsider_uuid, &source_uuid, "Copy", &dispatcher).await,
+            Err(DocsPageDatabaseError::PermissionDenied)
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_delete_page_removes_vector_chunks(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::qa::CHUNK_METADATA_PAGE_UUID;
+        use crate::test_support::{create_area, create_organization, create_page, serve_json_once, setup_tables};
+        use integrations::chroma::ChromaClient;
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let page_uuid = create_page(&pool, &org_uuid, &area_uuid).await;
+        if let DatabasePool::Sqlite(p) = &pool {
+            sqlx::query("UPDATE module_docs_pages SET auto_sync_to_vector_db = 1 WHERE uuid = ?1")
+                .bind(&page_uuid)
+                .execute(p)
+                .await?;
+        }
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let vector_store = DocsVectorStore::new(
+            ChromaClient::with_base_url(format!("http://{}", listener.local_addr().unwrap())),
+            "tenant".to_string(),
+            "db".to_string(),
+        );
+        let dispatcher = EventDispatcher::new();
+
+        let (result, body) = futures_util::join!(
+            delete_page(&pool, &page_uuid, &org_uuid, &user_uuid, &dispatcher, Some(&vector_store)),
+            serve_json_once(listener, json!({}))
+        );
+        result.expect("Failed to delete page");
+
+        let body: JsonValue = serde_json::from_str(&body).expect("Request body should be JSON");
+        // All chunks of the page are deleted by their page UUID metadata
+        assert_eq!(body, json!({ "where": { CHUNK_METADATA_PAGE_UUID: { "$eq": page_uuid } } }));
+        assert!(matches!(
+            load_page_by_uuid(&pool, &page_uuid).await,
+            Err(DocsPageDatabaseError::PageNotFound)
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_reorder_pages_changes_listing_order(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_folder, create_folder_page, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let folder_uuid = create_folder(&pool, &org_uuid, &area_uuid).await;
+
+        let first = create_folder_page(&pool, &org_uuid, &area_uuid, &folder_uuid, "First").await;
+        let second = create_folder_page(&pool, &org_uuid, &area_uuid, &folder_uuid, "Second").await;
+        let third = create_folder_page(&pool, &org_uuid, &area_uuid, &folder_uuid, "Third").await;
+
+        // Unlisted pages are placed after the listed ones
+        let order = reorder_pages(
+            &pool,
+            &org_uuid,
+            &user_uuid,
+            &folder_uuid,
+            &[second.clone(), first.clone()],
+            None,
+        )
+        .await
+        .expect("Failed to reorder pages");
+        assert_eq!(order, vec![second.clone(), first.clone(), third.clone()]);
+
+        let pages = list_pages(&pool, &org_uuid, &area_uuid, Some(&folder_uuid), &user_uuid)
+            .await
+            .expect("Failed to list pages");
+        let listed: Vec<String> = pages.into_iter().map(|page| page.uuid).collect();
+        assert_eq!(listed, vec![second, first, third]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_reorder_pages_rejects_page_outside_folder(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_folder, create_folder_page, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let folder_uuid = create_folder(&pool, &org_uuid, &area_uuid).await;
+        let other_folder_uuid = create_folder(&pool, &org_uuid, &area_uuid).await;
+
+        let inside = create_folder_page(&pool, &org_uuid, &area_uuid, &folder_uuid, "Inside").await;
+        let outside = create_folder_page(&pool, &org_uuid, &area_uuid, &other_folder_uuid, "Outside").await;
+
+        let result = reorder_pages(
+            &pool,
+            &org_uuid,
+            &user_uuid,
+            &folder_uuid,
+            &[outside.clone(), inside.clone()],
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(DocsPageDatabaseError::PageNotInFolder(uuid)) if uuid == outside));
+
+        let result = reorder_pages(
+            &pool,
+            &org_uuid,
+            &user_uuid,
+            &folder_uuid,
+            &[inside.clone(), inside.clone()],
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(DocsPageDatabaseError::DuplicatePageInOrder(uuid)) if uuid == inside));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_created_and_moved_pages_are_appended_to_folder(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        use crate::test_support::{create_area, create_folder, create_organization, setup_tables};
+
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let folder_uuid = create_folder(&pool, &org_uuid, &area_uuid).await;
+        let other_folder_uuid = create_folder(&pool, &org_uuid, &area_uuid).await;
+
+        let dispatcher = EventDispatcher::new();
+        let new_request = |title: &str, folder_uuid: &str| CreateDocsPageRequest {
+            area_uuid: area_uuid.clone(),
+            title: title.to_string(),
+            short_summary: None,
+            folder_uuid: Some(folder_uuid.to_string()),
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+        let mut page_uuids = Vec::new();
+        for title in ["First", "Second", "Third"] {
+            let page_uuid = create_page(&pool, &org_uuid, &user_uuid, new_request(title, &folder_uuid), &dispatcher)
+                .await
+                .expect("Failed to create page");
+            page_uuids.push(page_uuid);
+        }
+        let moved = create_page(&pool, &org_uuid, &user_uuid, new_request("Moved", &other_folder_uuid), &dispatcher)
+            .await
+            .expect("Failed to create page");
+
+        let list_folder = || async {
+            list_pages(&pool, &org_uuid, &area_uuid, Some(&folder_uuid), &user_uuid)
+                .await
+                .expect("Failed to list pages")
+                .into_iter()
+                .map(|page| page.uuid)
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(list_folder().await, page_uuids);
+
+        move_page(&pool, &moved, &org_uuid, &user_uuid, Some(folder_uuid.clone()), 0, None)
+            .await
+            .expect("Failed to move page");
+        page_uuids.push(moved.clone());
+
+        assert_eq!(list_folder().await, page_uuids);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+//! Page type handlers
+//!
+//! The `page_type` of a page selects a handler defining how new pages of the type are
+//! initialized and which content they accept. New types (e.g. `diagram`, `api_spec`) are
+//! added by registering a [`PageTypeHandler`] with [`PageTypeRegistry::global`].
+//!
+//! Pages of types without a registered handler get no initial version and accept any content.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+
+/// Page type of Markdown pages, used if a page is created without a type
+pub const MARKDOWN_PAGE_TYPE: &str = "markdown_page";
+
+/// Registry shared by all page operations of this process
+static GLOBAL_REGISTRY: LazyLock<PageTypeRegistry> = LazyLock::new(PageTypeRegistry::with_builtin_types);
+
+/// Initial version of a new page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InitialPageContent {
+    /// The page is created without a version
+    None,
+    /// The organization's page template (`module_docs_page_template`) with the title filled in
+    PageTemplate,
+    /// The given content
+    Content(String),
+}
+
+/// Behavior of the pages of one page type
+pub trait PageTypeHandler: Send + Sync {
+    /// Name of the page type, stored in `page_type`
+    fn page_type(&self) -> &str;
+
+    /// Initial version of a new page with the given title
+    ///
+    /// Not used when a page is created with content, e.g. when it is cloned.
+    fn initial_content(&self, _title: &str) -> InitialPageContent {
+        InitialPageContent::None
+    }
+
+    /// Check content before it is saved as a new version
+    ///
+    /// # Errors
+    /// Returns a message describing why the content is invalid
+    fn validate_content(&self, _content: &str) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Markdown pages, starting with the organization's page template
+struct MarkdownPageType;
+
+impl PageTypeHandler for MarkdownPageType {
+    fn page_type(&self) -> &str {
+        MARKDOWN_PAGE_TYPE
+    }
+
+    fn initial_content(&self, _title: &str) -> InitialPageContent {
+        InitialPageContent::PageTemplate
+    }
+}
+
+/// Page type handlers keyed by page type
+pub struct PageTypeRegistry {
+    handlers: RwLock<HashMap<String, Arc<dyn PageTypeHandler>>>,
+}
+
+impl PageTypeRegistry {
+    /// Create a registry without any page types
+    pub fn new() -> Self {
+        Self {
+            handlers: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Create a registry with the built-in page types (`markdown_page`)
+    pub fn with_builtin_types() -> Self {
+        let registry = Self::new();
+        registry.register(Arc::new(MarkdownPageType));
+        registry
+    }
+
+    /// Registry shared by the whole process, used by `create_page` and `save_page_content`
+    pub fn global() -> &'static PageTypeRegistry {
+        &GLOBAL_REGISTRY
+    }
+
+    /// Register a handler, replacing the handler previously registered for its page type
+    pub fn register(&self, handler: Arc<dyn PageTypeHandler>) {
+        self.handlers
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(handler.page_type().to_string(), handler);
+    }
+
+    /// Handler of a page type, `None` if the type is not registered
+    pub fn get(&self, page_type: &str) -> Option<Arc<dyn PageTypeHandler>> {
+        self.handlers
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(page_type)
+            .cloned()
+    }
+
+    /// Names of all registered page types, sorted
+    pub fn page_types(&self) -> Vec<String> {
+        let mut page_types: Vec<String> = self
+            .handlers
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .keys()
+            .cloned()
+            .collect();
+        page_types.sort();
+        page_types
+    }
+}
+
+impl Default for PageTypeRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
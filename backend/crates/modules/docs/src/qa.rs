@@ -0,0 +1,417 @@
+//! Docs Q&A retrieval module
+//!
+//! Finds the page chunks most relevant to a question in the vector database, as
+//! context for retrieval-augmented generation (RAG).
+//!
+//! Chunks are expected in the Chroma collection [`docs_collection_name`] of the
+//! organization, with the page UUID, page title, organization UUID and the
+//! `includes_private_data` flag of the page in their metadata (see the
+//! `CHUNK_METADATA_*` constants).
+
+use std::collections::HashMap;
+
+use flextide_core::database::DatabasePool;
+use integrations::chroma::{ChromaClient, ChromaError, MetadataFilter, QueryRequest};
+use integrations::openai::{OpenAIClient, OpenAIError};
+use serde::Serialize;
+use serde_json::{json, Value as JsonValue};
+use thiserror::Error;
+
+use crate::page::{load_page_by_uuid, DocsPageDatabaseError};
+use crate::search::viewable_area_uuids;
+use crate::vector_store::docs_collection_name;
+
+/// Metadata key holding the UUID of the page a chunk belongs to
+pub const CHUNK_METADATA_PAGE_UUID: &str = "page_uuid";
+/// Metadata key holding the title of the page a chunk belongs to
+pub const CHUNK_METADATA_PAGE_TITLE: &str = "page_title";
+/// Metadata key holding the UUID of the organization of the page
+pub const CHUNK_METADATA_ORGANIZATION_UUID: &str = "organization_uuid";
+/// Metadata key holding the `includes_private_data` flag of the page (boolean)
+pub const CHUNK_METADATA_INCLUDES_PRIVATE_DATA: &str = "includes_private_data";
+
+/// Number of chunks returned if not configured otherwise
+pub const DEFAULT_QA_TOP_K: usize = 5;
+
+/// Chunks queried per requested chunk, so that `top_k` chunks remain after dropping
+/// the chunks of pages the user may not see
+const QA_OVERFETCH_FACTOR: usize = 4;
+
+/// Where and how to search for relevant chunks
+#[derive(Debug, Clone)]
+pub struct DocsQaOptions {
+    /// Chroma tenant of the collection
+    pub tenant: String,
+    /// Chroma database of the collection
+    pub database: String,
+    /// OpenAI model used to embed the question; must match the model of the stored chunks
+    pub embedding_model: String,
+    /// Maximum number of chunks to return
+    pub top_k: usize,
+    /// Whether chunks of pages flagged with `includes_private_data` may be returned
+    pub include_private_pages: bool,
+}
+
+/// Page chunk relevant to a question
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DocsPageChunkReference {
+    /// ID of the chunk in the vector database
+    pub chunk_id: String,
+    pub page_uuid: String,
+    /// Current title of the page
+    pub page_title: String,
+    /// Text of the chunk
+    pub content: String,
+    /// Distance to the question, smaller is more relevant
+    pub distance: Option<f32>,
+}
+
+/// Errors of [`find_relevant_page_chunks`]
+#[derive(Debug, Error)]
+pub enum DocsQaError {
+    #[error("Question cannot be empty")]
+    EmptyQuestion,
+
+    #[error("Failed to embed question: {0}")]
+    Embedding(#[source] OpenAIError),
+
+    #[error("Failed to search the vector database: {0}")]
+    Search(#[source] ChromaError),
+
+    #[error(transparent)]
+    Database(#[from] DocsPageDatabaseError),
+}
+
+/// Find the page chunks most relevant to a question
+///
+/// Embeds the question with OpenAI and queries the organization's Chroma collection for
+/// the `top_k` nearest chunks, most relevant first. Unless `include_private_pages` is set,
+/// chunks of private pages are excluded in the query. Every returned page is checked
+/// against the database as well, so chunks of deleted pages, pages of other
+/// organizations, pages that became private since they were synced, and pages in areas
+/// the user cannot view (including archived areas) are dropped. More chunks than
+/// `top_k` are queried to make up for dropped ones.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `openai` - Client used to embed the question
+/// * `chroma` - Client of the vector database
+/// * `organization_uuid` - UUID of the organization whose pages are searched
+/// * `user_uuid` - UUID of the user asking, only pages they can view are returned
+/// * `question` - The question to find context for
+/// * `options` - Collection location, embedding model and result limits
+///
+/// # Errors
+/// Returns `DocsQaError` if:
+/// - The question is empty
+/// - Embedding the question fails or returns no embedding (`Embedding`)
+/// - The vector database query fails (`Search`)
+/// - The user does not belong to the organization
+/// - Loading the pages fails
+pub async fn find_relevant_page_chunks(
+    pool: &DatabasePool,
+    openai: &OpenAIClient,
+    chroma: &ChromaClient,
+    organization_uuid: &str,
+    user_uuid: &str,
+    question: &str,
+    options: &DocsQaOptions,
+) -> Result<Vec<DocsPageChunkReference>, DocsQaError> {
+    let question = question.trim();
+    if question.is_empty() {
+        return Err(DocsQaError::EmptyQuestion);
+    }
+    if options.top_k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let area_uuids = viewable_area_uuids(pool, organization_uuid, user_uuid).await?;
+    if area_uuids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let embedding = openai
+        .create_embeddings(&options.embedding_model, vec![question.to_string()])
+        .await
+        .map_err(DocsQaError::Embedding)?
+        .data
+        .into_iter()
+        .next()
+        .map(|embedding| embedding.embedding)
+        .filter(|embedding| !embedding.is_empty())
+        .ok_or_else(|| DocsQaError::Embedding(OpenAIError::InvalidResponse("No embedding returned".to_string())))?;
+
+    let organization_filter = json!({ CHUNK_METADATA_ORGANIZATION_UUID: { "$eq": organization_uuid } });
+    let where_clause = if options.include_private_pages {
+        organization_filter
+    } else {
+        json!({ "$and": [
+            organization_filter,
+            { CHUNK_METADATA_INCLUDES_PRIVATE_DATA: { "$eq": false } }
+        ] })
+    };
+    let request = QueryRequest {
+        query_texts: None,
+        query_embeddings: Some(vec![embedding]),
+        n_results: Some(options.top_k.saturating_mul(QA_OVERFETCH_FACTOR)),
+        where_clause: Some(MetadataFilter::Complex(where_clause)),
+        where_document: None,
+        include: Some(vec![
+            "documents".to_string(),
+            "metadatas".to_string(),
+            "distances".to_string(),
+        ]),
+    };
+    let result = chroma
+        .query(
+            &options.tenant,
+            &options.database,
+            &docs_collection_name(organization_uuid),
+            request,
+        )
+        .await
+        .map_err(DocsQaError::Search)?;
+
+    // Only one query embedding was sent, so there is one result set
+    let ids = result.ids.into_iter().next().unwrap_or_default();
+    let mut documents = result.documents.into_iter().next().unwrap_or_default().into_iter();
+    let mut metadatas = result.metadatas.into_iter().next().unwrap_or_default().into_iter();
+    let mut distances = result.distances.into_iter().next().unwrap_or_default().into_iter();
+
+    // Current title of each page, `None` if the page may not be returned
+    let mut page_titles: HashMap<String, Option<String>> = HashMap::new();
+    let mut references = Vec::new();
+
+    for chunk_id in ids {
+        if references.len() == options.top_k {
+            break;
+        }
+
+        let content = documents.next().flatten();
+        let metadata = metadatas.next().flatten().unwrap_or_default();
+        let distance = distances.next();
+
+        let (Some(content), Some(page_uuid)) = (
+            content,
+            metadata.get(CHUNK_METADATA_PAGE_UUID).and_then(JsonValue::as_str),
+        ) else {
+            continue;
+        };
+
+        if !page_titles.contains_key(page_uuid) {
+            let title = match load_page_by_uuid(pool, page_uuid).await {
+                Ok(page)
+                    if page.organization_uuid == organization_uuid
+                        && area_uuids.contains(&page.area_uuid)
+                        && (options.include_private_pages || page.includes_private_data == 0) =>
+                {
+                    Some(page.title)
+                }
+                Ok(_) | Err(DocsPageDatabaseError::PageNotFound) => None,
+                Err(e) => return Err(e.into()),
+            };
+            page_titles.insert(page_uuid.to_string(), title);
+        }
+        let Some(page_title) = page_titles[page_uuid].clone() else {
+            continue;
+        };
+
+        references.push(DocsPageChunkReference {
+            chunk_id,
+            page_uuid: page_uuid.to_string(),
+            page_title,
+            content,
+            distance,
+        });
+    }
+
+    Ok(references)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_area, create_organization, create_page, serve_json_once, setup_tables};
+
+    async fn set_page(pool: &DatabasePool, page_uuid: &str, title: &str, includes_private_data: bool) {
+        let DatabasePool::Sqlite(p) = pool else {
+            panic!("Test pool must be SQLite");
+        };
+        sqlx::query("UPDATE module_docs_pages SET title = ?1, includes_private_data = ?2 WHERE uuid = ?3")
+            .bind(title)
+            .bind(i32::from(includes_private_data))
+            .bind(page_uuid)
+            .execute(p)
+            .await
+            .expect("Failed to update page");
+    }
+
+    fn chunk_metadata(page_uuid: &str, org_uuid: &str, includes_private_data: bool) -> JsonValue {
+        json!({
+            CHUNK_METADATA_PAGE_UUID: page_uuid,
+            CHUNK_METADATA_PAGE_TITLE: "Outdated title",
+            CHUNK_METADATA_ORGANIZATION_UUID: org_uuid,
+            CHUNK_METADATA_INCLUDES_PRIVATE_DATA: includes_private_data,
+        })
+    }
+
+    #[sqlx::test]
+    async fn test_find_relevant_page_chunks_returns_top_k_references(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let deploy_page = create_page(&pool, &org_uuid, &area_uuid).await;
+        let rollback_page = create_page(&pool, &org_uuid, &area_uuid).await;
+        let private_page = create_page(&pool, &org_uuid, &area_uuid).await;
+        set_page(&pool, &deploy_page, "Deployment", false).await;
+        set_page(&pool, &rollback_page, "Rollback", false).await;
+        // Became private after its chunks were synced
+        set_page(&pool, &private_page, "Credentials", true).await;
+        // In an area the user is not a member of
+        let hidden_area = create_area(&pool, &org_uuid, &uuid::Uuid::new_v4().to_string()).await;
+        let hidden_page = create_page(&pool, &org_uuid, &hidden_area).await;
+
+        let openai_listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let chroma_listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let openai = OpenAIClient::with_base_url(
+            "test-key".to_string(),
+            format!("http://{}", openai_listener.local_addr().unwrap()),
+        );
+        let chroma = ChromaClient::with_base_url(format!("http://{}", chroma_listener.local_addr().unwrap()));
+
+        let embedding_response = json!({
+            "object": "list",
+            "model": "text-embedding-3-small",
+            "data": [{ "object": "embedding", "index": 0, "embedding": [0.5, 0.25, 0.125] }],
+            "usage": { "prompt_tokens": 5, "total_tokens": 5 }
+        });
+        let query_response = json!({
+            "ids": [["deploy-0", "private-0", "hidden-0", "rollback-1", "deleted-0", "deploy-1"]],
+            "documents": [[
+                "Run the deploy script.", "The password is ...", "Hidden", "Revert the release tag.", "Gone",
+                "Check the logs."
+            ]],
+            "metadatas": [[
+                chunk_metadata(&deploy_page, &org_uuid, false),
+                chunk_metadata(&private_page, &org_uuid, false),
+                chunk_metadata(&hidden_page, &org_uuid, false),
+                chunk_metadata(&rollback_page, &org_uuid, false),
+                chunk_metadata(&uuid::Uuid::new_v4().to_string(), &org_uuid, false),
+                chunk_metadata(&deploy_page, &org_uuid, false),
+            ]],
+            "distances": [[0.1, 0.2, 0.3, 0.4, 0.5, 0.6]]
+        });
+
+        let options = DocsQaOptions {
+            tenant: "default_tenant".to_string(),
+            database: "default_database".to_string(),
+            embedding_model: "text-embedding-3-small".to_string(),
+            // The dropped chunks rank between the two returned ones, the last chunk is cut off
+            top_k: 2,
+            include_private_pages: false,
+        };
+        let (result, embedding_body, query_body) = futures_util::join!(
+            find_relevant_page_chunks(&pool, &openai, &chroma, &org_uuid, &user_uuid, "How do I deploy?", &options),
+            serve_json_once(openai_listener, embedding_response),
+            serve_json_once(chroma_listener, query_response)
+        );
+        let references = result.expect("Failed to find relevant chunks");
+
+        assert_eq!(
+            references,
+            vec![
+                DocsPageChunkReference {
+                    chunk_id: "deploy-0".to_string(),
+                    page_uuid: deploy_page,
+                    page_title: "Deployment".to_string(),
+                    content: "Run the deploy script.".to_string(),
+                    distance: Some(0.1),
+                },
+                DocsPageChunkReference {
+                    chunk_id: "rollback-1".to_string(),
+                    page_uuid: rollback_page,
+                    page_title: "Rollback".to_string(),
+                    content: "Revert the release tag.".to_string(),
+                    distance: Some(0.4),
+                },
+            ]
+        );
+
+        let embedding_body: JsonValue = serde_json::from_str(&embedding_body).expect("Request body should be JSON");
+        assert_eq!(embedding_body["input"], json!(["How do I deploy?"]));
+
+        let query_body: JsonValue = serde_json::from_str(&query_body).expect("Request body should be JSON");
+        assert_eq!(query_body["n_results"], json!(2 * QA_OVERFETCH_FACTOR));
+        assert_eq!(query_body["query_embeddings"], json!([[0.5, 0.25, 0.125]]));
+        assert_eq!(
+            query_body["where"],
+            json!({ "$and": [
+                { "organization_uuid": { "$eq": org_uuid } },
+                { "includes_private_data": { "$eq": false } }
+            ] })
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_find_relevant_page_chunks_rejects_empty_question(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        let openai = OpenAIClient::with_base_url("test-key".to_string(), "http://127.0.0.1:9".to_string());
+        let chroma = ChromaClient::with_base_url("http://127.0.0.1:9".to_string());
+        let options = DocsQaOptions {
+            tenant: "default_tenant".to_string(),
+            database: "default_database".to_string(),
+            embedding_model: "text-embedding-3-small".to_string(),
+            top_k: DEFAULT_QA_TOP_K,
+            include_private_pages: true,
+        };
+
+        let result = find_relevant_page_chunks(&pool, &openai, &chroma, "org", "user", "   ", &options).await;
+        assert!(matches!(result, Err(DocsQaError::EmptyQuestion)));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_find_relevant_page_chunks_rejects_missing_embedding(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        create_area(&pool, &org_uuid, &user_uuid).await;
+
+        let openai_listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let openai = OpenAIClient::with_base_url(
+            "test-key".to_string(),
+            format!("http://{}", openai_listener.local_addr().unwrap()),
+        );
+        // Chroma must not be queried without an embedding
+        let chroma = ChromaClient::with_base_url("http://127.0.0.1:9".to_string());
+        let options = DocsQaOptions {
+            tenant: "default_tenant".to_string(),
+            database: "default_database".to_string(),
+            embedding_model: "text-embedding-3-small".to_string(),
+            top_k: DEFAULT_QA_TOP_K,
+            include_private_pages: false,
+        };
+
+        let embedding_response = json!({
+            "object": "list",
+            "model": "text-embedding-3-small",
+            "data": [],
+            "usage": { "prompt_tokens": 5, "total_tokens": 5 }
+        });
+        let (result, _) = futures_util::join!(
+            find_relevant_page_chunks(&pool, &openai, &chroma, &org_uuid, &user_uuid, "How do I deploy?", &options),
+            serve_json_once(openai_listener, embedding_response)
+        );
+        assert!(matches!(result, Err(DocsQaError::Embedding(OpenAIError::InvalidResponse(_)))));
+
+        Ok(())
+    }
+}
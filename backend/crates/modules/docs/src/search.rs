@@ -0,0 +1,478 @@
+//! Docs Search module
+//!
+//! Finds pages across all areas of an organization that a user can view.
+
+use chrono::{DateTime, Duration, Utc};
+use flextide_core::database::DatabasePool;
+use flextide_core::user::{user_belongs_to_organization, user_has_permission};
+use sqlx::Row;
+
+use crate::area::{list_accessible_areas, load_area_member_permissions};
+use crate::page::{DocsPage, DocsPageDatabaseError};
+
+/// UUIDs of the areas of an organization whose pages the user can view
+///
+/// Uses the same rules as `list_pages`: area members need the view permission (or be
+/// area admin/owner), non-members can view public areas if they may create areas.
+pub(crate) async fn viewable_area_uuids(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    user_uuid: &str,
+) -> Result<Vec<String>, DocsPageDatabaseError> {
+    let belongs = user_belongs_to_organization(pool, user_uuid, organization_uuid)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking organization membership: {}", e);
+            DocsPageDatabaseError::Database(e.into())
+        })?;
+
+    if !belongs {
+        return Err(DocsPageDatabaseError::UserNotInOrganization);
+    }
+
+    let areas = list_accessible_areas(pool, organization_uuid, user_uuid, false).await?;
+    let mut can_view_public_areas = None;
+    let mut area_uuids = Vec::new();
+
+    for area in areas {
+        let can_view = match load_area_member_permissions(pool, &area.area.uuid, user_uuid).await? {
+            Some(perms) => perms.admin || perms.role == "owner" || perms.can_view,
+            None if area.area.public => match can_view_public_areas {
+                Some(can_view) => can_view,
+                None => {
+                    let can_view =
+                        user_has_permission(pool, user_uuid, organization_uuid, "module_docs_can_create_areas")
+                            .await
+                            .map_err(|e| {
+                                tracing::error!("Database error checking permission: {}", e);
+                                DocsPageDatabaseError::Database(e.into())
+                            })?;
+                    can_view_public_areas = Some(can_view);
+                    can_view
+                }
+            },
+            None => false,
+        };
+
+        if can_view {
+            area_uuids.push(area.area.uuid);
+        }
+    }
+
+    Ok(area_uuids)
+}
+
+/// JSON path selecting a top-level key (MySQL and SQLite)
+fn metadata_key_path(key: &str) -> String {
+    format!("$.\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Find the pages of an organization whose metadata contains a value under a key
+///
+/// Only top-level keys are matched. The value is compared with the text of the metadata
+/// value, so string values match their content and numbers their decimal representation.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization
+/// * `key` - Top-level metadata key
+/// * `value` - Value the metadata key must have
+/// * `user_uuid` - UUID of the user searching
+///
+/// # Returns
+/// Returns the matching pages in areas the user can view, sorted by title
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - User does not belong to the organization
+/// - Database operation fails
+pub async fn find_pages_by_metadata(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    key: &str,
+    value: &str,
+    user_uuid: &str,
+) -> Result<Vec<DocsPage>, DocsPageDatabaseError> {
+    let area_uuids = viewable_area_uuids(pool, organization_uuid, user_uuid).await?;
+    if area_uuids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pages: Vec<DocsPage> = match pool {
+        DatabasePool::MySql(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid,
+                 current_version_uuid, page_type, last_updated, created_at, auto_sync_to_vector_db,
+                 vcs_export_allowed, includes_private_data, metadata
+                 FROM module_docs_pages
+                 WHERE organization_uuid = ? AND JSON_UNQUOTE(JSON_EXTRACT(metadata, ?)) = ?
+                 ORDER BY title ASC",
+            )
+            .bind(organization_uuid)
+            .bind(metadata_key_path(key))
+            .bind(value)
+            .fetch_all(p)
+            .await?;
+
+            rows.into_iter()
+                .map(|row| DocsPage {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    area_uuid: row.get("area_uuid"),
+                    folder_uuid: row.get("folder_uuid"),
+                    title: row.get("title"),
+                    short_summary: row.get("short_summary"),
+                    parent_page_uuid: row.get("parent_page_uuid"),
+                    current_version_uuid: row.get("current_version_uuid"),
+                    page_type: row.get("page_type"),
+                    last_updated: row.get::<DateTime<Utc>, _>("last_updated"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    auto_sync_to_vector_db: row.get("auto_sync_to_vector_db"),
+                    vcs_export_allowed: row.get("vcs_export_allowed"),
+                    includes_private_data: row.get("includes_private_data"),
+                    metadata: row.get("metadata"),
+                })
+                .collect()
+        }
+        DatabasePool::Postgres(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid,
+                 current_version_uuid, page_type, last_updated, created_at, auto_sync_to_vector_db,
+                 vcs_export_allowed, includes_private_data, metadata
+                 FROM module_docs_pages
+                 WHERE organization_uuid = $1 AND metadata ->> $2 = $3
+                 ORDER BY title ASC",
+            )
+            .bind(organization_uuid)
+            .bind(key)
+            .bind(value)
+            .fetch_all(p)
+            .await?;
+
+            rows.into_iter()
+                .map(|row| DocsPage {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    area_uuid: row.get("area_uuid"),
+                    folder_uuid: row.get("folder_uuid"),
+                    title: row.get("title"),
+                    short_summary: row.get("short_summary"),
+                    parent_page_uuid: row.get("parent_page_uuid"),
+                    current_version_uuid: row.get("current_version_uuid"),
+                    page_type: row.get("page_type"),
+                    last_updated: row.get::<DateTime<Utc>, _>("last_updated"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    auto_sync_to_vector_db: row.get("auto_sync_to_vector_db"),
+                    vcs_export_allowed: row.get("vcs_export_allowed"),
+                    includes_private_data: row.get("includes_private_data"),
+                    metadata: row.get("metadata"),
+                })
+                .collect()
+        }
+        DatabasePool::Sqlite(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid,
+                 current_version_uuid, page_type, last_updated, created_at, auto_sync_to_vector_db,
+                 vcs_export_allowed, includes_private_data, metadata
+                 FROM module_docs_pages
+                 WHERE organization_uuid = ?1 AND json_valid(metadata)
+                 AND CAST(json_extract(metadata, ?2) AS TEXT) = ?3
+                 ORDER BY title ASC",
+            )
+            .bind(organization_uuid)
+            .bind(metadata_key_path(key))
+            .bind(value)
+            .fetch_all(p)
+            .await?;
+
+            rows.into_iter()
+                .map(|row| DocsPage {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    area_uuid: row.get("area_uuid"),
+                    folder_uuid: row.get("folder_uuid"),
+                    title: row.get("title"),
+                    short_summary: row.get("short_summary"),
+                    parent_page_uuid: row.get("parent_page_uuid"),
+                    current_version_uuid: row.get("current_version_uuid"),
+                    page_type: row.get("page_type"),
+                    last_updated: row.get::<DateTime<Utc>, _>("last_updated"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    auto_sync_to_vector_db: row.get("auto_sync_to_vector_db"),
+                    vcs_export_allowed: row.get("vcs_export_allowed"),
+                    includes_private_data: row.get("includes_private_data"),
+                    metadata: row.get("metadata"),
+                })
+                .collect()
+        }
+    };
+
+    Ok(pages
+        .into_iter()
+        .filter(|page| area_uuids.contains(&page.area_uuid))
+        .collect())
+}
+
+/// List the pages of an organization that have not been updated for a number of days
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization
+/// * `older_than_days` - Minimum age in days of the last update
+/// * `user_uuid` - UUID of the user requesting the pages
+///
+/// # Returns
+/// Returns the stale pages in areas the user can view, least recently updated first
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - User does not belong to the organization
+/// - `older_than_days` reaches before the earliest representable date (`InvalidDays`)
+/// - Database operation fails
+pub async fn list_stale_pages(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    older_than_days: u32,
+    user_uuid: &str,
+) -> Result<Vec<DocsPage>, DocsPageDatabaseError> {
+    let area_uuids = viewable_area_uuids(pool, organization_uuid, user_uuid).await?;
+    if area_uuids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let cutoff = Utc::now()
+        .checked_sub_signed(Duration::days(i64::from(older_than_days)))
+        .ok_or(DocsPageDatabaseError::InvalidDays(older_than_days))?;
+
+    let pages: Vec<DocsPage> = match pool {
+        DatabasePool::MySql(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid,
+                 current_version_uuid, page_type, last_updated, created_at, auto_sync_to_vector_db,
+                 vcs_export_allowed, includes_private_data, metadata
+                 FROM module_docs_pages
+                 WHERE organization_uuid = ? AND last_updated < ?
+                 ORDER BY last_updated ASC",
+            )
+            .bind(organization_uuid)
+            .bind(cutoff)
+            .fetch_all(p)
+            .await?;
+
+            rows.into_iter()
+                .map(|row| DocsPage {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    area_uuid: row.get("area_uuid"),
+                    folder_uuid: row.get("folder_uuid"),
+                    title: row.get("title"),
+                    short_summary: row.get("short_summary"),
+                    parent_page_uuid: row.get("parent_page_uuid"),
+                    current_version_uuid: row.get("current_version_uuid"),
+                    page_type: row.get("page_type"),
+                    last_updated: row.get::<DateTime<Utc>, _>("last_updated"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    auto_sync_to_vector_db: row.get("auto_sync_to_vector_db"),
+                    vcs_export_allowed: row.get("vcs_export_allowed"),
+                    includes_private_data: row.get("includes_private_data"),
+                    metadata: row.get("metadata"),
+                })
+                .collect()
+        }
+        DatabasePool::Postgres(p) => {
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid,
+                 current_version_uuid, page_type, last_updated, created_at, auto_sync_to_vector_db,
+                 vcs_export_allowed, includes_private_data, metadata
+                 FROM module_docs_pages
+                 WHERE organization_uuid = $1 AND last_updated < $2
+                 ORDER BY last_updated ASC",
+            )
+            .bind(organization_uuid)
+            .bind(cutoff)
+            .fetch_all(p)
+            .await?;
+
+            rows.into_iter()
+                .map(|row| DocsPage {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    area_uuid: row.get("area_uuid"),
+                    folder_uuid: row.get("folder_uuid"),
+                    title: row.get("title"),
+                    short_summary: row.get("short_summary"),
+                    parent_page_uuid: row.get("parent_page_uuid"),
+                    current_version_uuid: row.get("current_version_uuid"),
+                    page_type: row.get("page_type"),
+                    last_updated: row.get::<DateTime<Utc>, _>("last_updated"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    auto_sync_to_vector_db: row.get("auto_sync_to_vector_db"),
+                    vcs_export_allowed: row.get("vcs_export_allowed"),
+                    includes_private_data: row.get("includes_private_data"),
+                    metadata: row.get("metadata"),
+                })
+                .collect()
+        }
+        DatabasePool::Sqlite(p) => {
+            // Timestamps are stored both as "YYYY-MM-DD HH:MM:SS" (column default) and as
+            // RFC 3339 (bound by the application), datetime() normalizes them for comparison
+            let rows = sqlx::query(
+                "SELECT uuid, organization_uuid, area_uuid, folder_uuid, title, short_summary, parent_page_uuid,
+                 current_version_uuid, page_type, last_updated, created_at, auto_sync_to_vector_db,
+                 vcs_export_allowed, includes_private_data, metadata
+                 FROM module_docs_pages
+                 WHERE organization_uuid = ?1 AND datetime(last_updated) < datetime(?2)
+                 ORDER BY datetime(last_updated) ASC",
+            )
+            .bind(organization_uuid)
+            .bind(cutoff.format("%Y-%m-%d %H:%M:%S").to_string())
+            .fetch_all(p)
+            .await?;
+
+            rows.into_iter()
+                .map(|row| DocsPage {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    area_uuid: row.get("area_uuid"),
+                    folder_uuid: row.get("folder_uuid"),
+                    title: row.get("title"),
+                    short_summary: row.get("short_summary"),
+                    parent_page_uuid: row.get("parent_page_uuid"),
+                    current_version_uuid: row.get("current_version_uuid"),
+                    page_type: row.get("page_type"),
+                    last_updated: row.get::<DateTime<Utc>, _>("last_updated"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    auto_sync_to_vector_db: row.get("auto_sync_to_vector_db"),
+                    vcs_export_allowed: row.get("vcs_export_allowed"),
+                    includes_private_data: row.get("includes_private_data"),
+                    metadata: row.get("metadata"),
+                })
+                .collect()
+        }
+    };
+
+    Ok(pages
+        .into_iter()
+        .filter(|page| area_uuids.contains(&page.area_uuid))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{add_organization_member, create_area, create_organization, create_page, setup_tables};
+
+    async fn set_metadata(pool: &DatabasePool, page_uuid: &str, metadata: &str) {
+        let DatabasePool::Sqlite(p) = pool else {
+            unreachable!()
+        };
+        sqlx::query("UPDATE module_docs_pages SET metadata = ?1 WHERE uuid = ?2")
+            .bind(metadata)
+            .bind(page_uuid)
+            .execute(p)
+            .await
+            .expect("Failed to set page metadata");
+    }
+
+    #[sqlx::test]
+    async fn test_find_pages_by_metadata(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let other_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        add_organization_member(&pool, &org_uuid, &other_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let other_area_uuid = create_area(&pool, &org_uuid, &other_uuid).await;
+
+        let draft = create_page(&pool, &org_uuid, &area_uuid).await;
+        set_metadata(&pool, &draft, r#"{"status": "draft", "version": 2}"#).await;
+        let published = create_page(&pool, &org_uuid, &area_uuid).await;
+        set_metadata(&pool, &published, r#"{"status": "published"}"#).await;
+        let nested = create_page(&pool, &org_uuid, &area_uuid).await;
+        set_metadata(&pool, &nested, r#"{"review": {"status": "draft"}}"#).await;
+        create_page(&pool, &org_uuid, &area_uuid).await;
+        // Drafts in areas the user cannot view are not found
+        let hidden_draft = create_page(&pool, &org_uuid, &other_area_uuid).await;
+        set_metadata(&pool, &hidden_draft, r#"{"status": "draft"}"#).await;
+
+        let pages = find_pages_by_metadata(&pool, &org_uuid, "status", "draft", &user_uuid).await.unwrap();
+        assert_eq!(pages.iter().map(|p| p.uuid.as_str()).collect::<Vec<_>>(), vec![draft.as_str()]);
+        assert_eq!(pages[0].metadata.as_ref().unwrap()["version"], 2);
+
+        let pages = find_pages_by_metadata(&pool, &org_uuid, "version", "2", &user_uuid).await.unwrap();
+        assert_eq!(pages.len(), 1);
+        assert!(find_pages_by_metadata(&pool, &org_uuid, "status", "archived", &user_uuid).await.unwrap().is_empty());
+
+        let pages = find_pages_by_metadata(&pool, &org_uuid, "status", "draft", &other_uuid).await.unwrap();
+        assert_eq!(pages.iter().map(|p| p.uuid.as_str()).collect::<Vec<_>>(), vec![hidden_draft.as_str()]);
+
+        let outsider_uuid = uuid::Uuid::new_v4().to_string();
+        assert!(matches!(
+            find_pages_by_metadata(&pool, &org_uuid, "status", "draft", &outsider_uuid).await,
+            Err(DocsPageDatabaseError::UserNotInOrganization)
+        ));
+
+        Ok(())
+    }
+
+    async fn set_last_updated(pool: &DatabasePool, page_uuid: &str, last_updated: &str) {
+        let DatabasePool::Sqlite(p) = pool else {
+            unreachable!()
+        };
+        sqlx::query("UPDATE module_docs_pages SET last_updated = ?1 WHERE uuid = ?2")
+            .bind(last_updated)
+            .bind(page_uuid)
+            .execute(p)
+            .await
+            .expect("Failed to set last update of page");
+    }
+
+    #[sqlx::test]
+    async fn test_list_stale_pages(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let other_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        add_organization_member(&pool, &org_uuid, &other_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let other_area_uuid = create_area(&pool, &org_uuid, &other_uuid).await;
+
+        let days_ago = |days: i64| Utc::now() - Duration::days(days);
+
+        let old = create_page(&pool, &org_uuid, &area_uuid).await;
+        set_last_updated(&pool, &old, &days_ago(200).format("%Y-%m-%d %H:%M:%S").to_string()).await;
+        let oldest = create_page(&pool, &org_uuid, &area_uuid).await;
+        set_last_updated(&pool, &oldest, &days_ago(400).to_rfc3339()).await;
+        let recent = create_page(&pool, &org_uuid, &area_uuid).await;
+        set_last_updated(&pool, &recent, &days_ago(10).to_rfc3339()).await;
+        // Created just now with the column default
+        create_page(&pool, &org_uuid, &area_uuid).await;
+        // Stale pages in areas the user cannot view are not listed
+        let hidden = create_page(&pool, &org_uuid, &other_area_uuid).await;
+        set_last_updated(&pool, &hidden, &days_ago(300).to_rfc3339()).await;
+
+        let pages = list_stale_pages(&pool, &org_uuid, 90, &user_uuid).await.unwrap();
+        assert_eq!(
+            pages.iter().map(|p| p.uuid.as_str()).collect::<Vec<_>>(),
+            vec![oldest.as_str(), old.as_str()]
+        );
+
+        let pages = list_stale_pages(&pool, &org_uuid, 7, &user_uuid).await.unwrap();
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[2].uuid, recent);
+
+        let pages = list_stale_pages(&pool, &org_uuid, 90, &other_uuid).await.unwrap();
+        assert_eq!(pages.iter().map(|p| p.uuid.as_str()).collect::<Vec<_>>(), vec![hidden.as_str()]);
+
+        assert!(matches!(
+            list_stale_pages(&pool, &org_uuid, u32::MAX, &user_uuid).await,
+            Err(DocsPageDatabaseError::InvalidDays(u32::MAX))
+        ));
+
+        Ok(())
+    }
+}
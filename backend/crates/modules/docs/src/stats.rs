@@ -0,0 +1,168 @@
+//! Docs Statistics module
+//!
+//! Aggregates documentation health figures of an organization.
+
+use flextide_core::database::DatabasePool;
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+
+use crate::page::DocsPageDatabaseError;
+
+/// Documentation health figures of an organization
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocsStats {
+    /// Number of areas, including archived ones
+    pub total_areas: i64,
+    /// Number of pages in all areas
+    pub total_pages: i64,
+    /// Pages without a short summary (missing or blank)
+    pub pages_without_summary: i64,
+    /// Pages not synced to the vector database (`auto_sync_to_vector_db` is off)
+    pub pages_not_synced: i64,
+}
+
+/// Compute the documentation statistics of an organization
+///
+/// This does not perform permission checks; callers must verify that the user may
+/// see organization-wide statistics.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if the database operation fails
+pub async fn docs_stats(pool: &DatabasePool, organization_uuid: &str) -> Result<DocsStats, DocsPageDatabaseError> {
+    let counts: (i64, i64, i64, i64) = match pool {
+        DatabasePool::MySql(p) => {
+            let row = sqlx::query(
+                "SELECT
+                    (SELECT COUNT(*) FROM module_docs_areas WHERE organization_uuid = ?) AS total_areas,
+                    (SELECT COUNT(*) FROM module_docs_pages WHERE organization_uuid = ?) AS total_pages,
+                    (SELECT COUNT(*) FROM module_docs_pages WHERE organization_uuid = ?
+                     AND (short_summary IS NULL OR TRIM(short_summary) = '')) AS pages_without_summary,
+                    (SELECT COUNT(*) FROM module_docs_pages WHERE organization_uuid = ?
+                     AND auto_sync_to_vector_db = 0) AS pages_not_synced",
+            )
+            .bind(organization_uuid)
+            .bind(organization_uuid)
+            .bind(organization_uuid)
+            .bind(organization_uuid)
+            .fetch_one(p)
+            .await?;
+
+            (
+                row.get("total_areas"),
+                row.get("total_pages"),
+                row.get("pages_without_summary"),
+                row.get("pages_not_synced"),
+            )
+        }
+        DatabasePool::Postgres(p) => {
+            let row = sqlx::query(
+                "SELECT
+                    (SELECT COUNT(*) FROM module_docs_areas WHERE organization_uuid = $1) AS total_areas,
+                    (SELECT COUNT(*) FROM module_docs_pages WHERE organization_uuid = $1) AS total_pages,
+                    (SELECT COUNT(*) FROM module_docs_pages WHERE organization_uuid = $1
+                     AND (short_summary IS NULL OR TRIM(short_summary) = '')) AS pages_without_summary,
+                    (SELECT COUNT(*) FROM module_docs_pages WHERE organization_uuid = $1
+                     AND auto_sync_to_vector_db = 0) AS pages_not_synced",
+            )
+            .bind(organization_uuid)
+            .fetch_one(p)
+            .await?;
+
+            (
+                row.get("total_areas"),
+                row.get("total_pages"),
+                row.get("pages_without_summary"),
+                row.get("pages_not_synced"),
+            )
+        }
+        DatabasePool::Sqlite(p) => {
+            let row = sqlx::query(
+                "SELECT
+                    (SELECT COUNT(*) FROM module_docs_areas WHERE organization_uuid = ?1) AS total_areas,
+                    (SELECT COUNT(*) FROM module_docs_pages WHERE organization_uuid = ?1) AS total_pages,
+                    (SELECT COUNT(*) FROM module_docs_pages WHERE organization_uuid = ?1
+                     AND (short_summary IS NULL OR TRIM(short_summary) = '')) AS pages_without_summary,
+                    (SELECT COUNT(*) FROM module_docs_pages WHERE organization_uuid = ?1
+                     AND auto_sync_to_vector_db = 0) AS pages_not_synced",
+            )
+            .bind(organization_uuid)
+            .fetch_one(p)
+            .await?;
+
+            (
+                row.get("total_areas"),
+                row.get("total_pages"),
+                row.get("pages_without_summary"),
+                row.get("pages_not_synced"),
+            )
+        }
+    };
+
+    Ok(DocsStats {
+        total_areas: counts.0,
+        total_pages: counts.1,
+        pages_without_summary: counts.2,
+        pages_not_synced: counts.3,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_area, create_organization, create_page, setup_tables};
+
+    async fn update_page(pool: &DatabasePool, page_uuid: &str, short_summary: Option<&str>, auto_sync: i32) {
+        let DatabasePool::Sqlite(p) = pool else {
+            unreachable!()
+        };
+        sqlx::query("UPDATE module_docs_pages SET short_summary = ?1, auto_sync_to_vector_db = ?2 WHERE uuid = ?3")
+            .bind(short_summary)
+            .bind(auto_sync)
+            .bind(page_uuid)
+            .execute(p)
+            .await
+            .expect("Failed to update page");
+    }
+
+    #[sqlx::test]
+    async fn test_docs_stats_counts(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let other_area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        create_area(&pool, &org_uuid, &user_uuid).await;
+
+        let complete = create_page(&pool, &org_uuid, &area_uuid).await;
+        update_page(&pool, &complete, Some("Explains the setup"), 1).await;
+        let unsynced = create_page(&pool, &org_uuid, &area_uuid).await;
+        update_page(&pool, &unsynced, Some("Explains the release"), 0).await;
+        let blank_summary = create_page(&pool, &org_uuid, &other_area_uuid).await;
+        update_page(&pool, &blank_summary, Some("  "), 1).await;
+        // Neither summary nor sync
+        create_page(&pool, &org_uuid, &other_area_uuid).await;
+
+        // Other organizations are not counted
+        let other_org_uuid = create_organization(&pool, &user_uuid).await;
+        let foreign_area_uuid = create_area(&pool, &other_org_uuid, &user_uuid).await;
+        create_page(&pool, &other_org_uuid, &foreign_area_uuid).await;
+
+        assert_eq!(
+            docs_stats(&pool, &org_uuid).await.unwrap(),
+            DocsStats {
+                total_areas: 3,
+                total_pages: 4,
+                pages_without_summary: 2,
+                pages_not_synced: 2,
+            }
+        );
+
+        Ok(())
+    }
+}
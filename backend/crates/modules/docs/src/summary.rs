@@ -21,12 +21,16 @@
 //! ```
 
 mod claude;
+mod fallback;
 mod gemini;
 mod openai;
+mod rate_limit;
 
 pub use claude::ClaudePageSummaryGenerator;
+pub use fallback::FallbackPageSummaryGenerator;
 pub use gemini::GeminiPageSummaryGenerator;
 pub use openai::OpenAIPageSummaryGenerator;
+pub use rate_limit::{SummaryRateLimiter, DEFAULT_SUMMARY_CALLS_PER_MINUTE};
 
 use async_trait::async_trait;
 use crate::page::{DocsPage, DocsPageVersion};
@@ -57,6 +61,75 @@ pub enum PageSummaryError {
     NoContent,
 }
 
+/// Length and style preferences for generated summaries
+///
+/// Configured per organization through the `module_docs_summary_max_words` and
+/// `module_docs_summary_style` settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SummaryOptions {
+    /// Maximum number of words the summary should have
+    pub max_words: Option<u32>,
+    /// Desired style or tone of the summary (e.g. "formal", "technical")
+    pub style: Option<String>,
+}
+
+/// System prompt shared by all summary generators
+const SUMMARY_SYSTEM_PROMPT: &str = "You are a documentation assistant. Generate a concise, informative summary of the following documentation page. The summary should be clear, professional, and capture the key points. Keep it brief and focused.";
+
+/// Build the system and user prompt for summarizing a page
+///
+/// # Arguments
+/// * `title` - Title of the page
+/// * `content` - Content to summarize (already truncated to the provider's limits)
+/// * `max_summary_length` - Maximum summary length in characters
+/// * `options` - Length and style preferences of the organization
+///
+/// # Returns
+/// Returns a tuple of `(system_prompt, user_prompt)`
+pub fn build_summary_prompt(
+    title: &str,
+    content: &str,
+    max_summary_length: usize,
+    options: &SummaryOptions,
+) -> (String, String) {
+    let mut instructions = format!("Generate a short summary (maximum {} characters", max_summary_length);
+    if let Some(max_words) = options.max_words {
+        instructions.push_str(&format!(", at most {} words", max_words));
+    }
+    instructions.push(')');
+    if let Some(style) = &options.style {
+        instructions.push_str(&format!(" written in a {} style", style));
+    }
+    instructions.push(':');
+
+    let user_prompt = format!(
+        "Page Title: {}\n\nPage Content:\n{}\n\n{}",
+        title, content, instructions
+    );
+
+    (SUMMARY_SYSTEM_PROMPT.to_string(), user_prompt)
+}
+
+/// System prompt shared by all title suggestions
+const TITLE_SYSTEM_PROMPT: &str = "You are a documentation assistant. Suggest a concise, descriptive title for the following documentation page. Answer with the title only, without quotes or explanations.";
+
+/// Build the system and user prompt for suggesting a page title
+///
+/// # Arguments
+/// * `current_title` - Current (possibly placeholder) title of the page
+/// * `content` - Content of the page (already truncated to the provider's limits)
+///
+/// # Returns
+/// Returns a tuple of `(system_prompt, user_prompt)`
+pub fn build_title_prompt(current_title: &str, content: &str) -> (String, String) {
+    let user_prompt = format!(
+        "Current Title: {}\n\nPage Content:\n{}\n\nSuggest a title of at most 10 words:",
+        current_title, content
+    );
+
+    (TITLE_SYSTEM_PROMPT.to_string(), user_prompt)
+}
+
 /// Trait for generating page summaries using AI
 ///
 /// This trait allows different AI providers (OpenAI, Claude, Gemini, etc.)
@@ -83,5 +156,26 @@ pub trait PageSummaryGenerator: Send + Sync {
         page: &DocsPage,
         version: &DocsPageVersion,
     ) -> Result<String, PageSummaryError>;
+
+    /// Suggest a concise title for a documentation page based on its content
+    ///
+    /// Providers that do not support title suggestions keep the default implementation,
+    /// which returns `PageSummaryError::ProviderError`.
+    ///
+    /// # Arguments
+    /// * `page` - The documentation page to suggest a title for
+    /// * `version` - The latest version of the page containing its content
+    ///
+    /// # Errors
+    /// Returns `PageSummaryError` under the same conditions as `generate_summary`
+    async fn suggest_title(
+        &self,
+        _page: &DocsPage,
+        _version: &DocsPageVersion,
+    ) -> Result<String, PageSummaryError> {
+        Err(PageSummaryError::ProviderError(
+            "Title suggestions are not supported by this AI provider".to_string(),
+        ))
+    }
 }
 
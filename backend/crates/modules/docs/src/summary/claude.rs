@@ -6,7 +6,7 @@
 
 use async_trait::async_trait;
 use crate::page::{DocsPage, DocsPageVersion};
-use crate::summary::{PageSummaryError, PageSummaryGenerator};
+use crate::summary::{build_summary_prompt, PageSummaryError, PageSummaryGenerator, SummaryOptions};
 use tracing::{debug, error};
 
 /// Claude-based page summary generator
@@ -24,6 +24,7 @@ pub struct ClaudePageSummaryGenerator {
     api_key: String,
     model: String,
     max_summary_length: Option<usize>,
+    options: SummaryOptions,
 }
 
 impl ClaudePageSummaryGenerator {
@@ -40,6 +41,7 @@ impl ClaudePageSummaryGenerator {
             api_key,
             model,
             max_summary_length: Some(200),
+            options: SummaryOptions::default(),
         }
     }
 
@@ -51,6 +53,15 @@ impl ClaudePageSummaryGenerator {
         self.max_summary_length = length;
         self
     }
+
+    /// Set the length and style preferences used in the prompt
+    ///
+    /// # Arguments
+    /// * `options` - Summary options of the organization
+    pub fn with_summary_options(mut self, options: SummaryOptions) -> Self {
+        self.options = options;
+        self
+    }
 }
 
 #[async_trait]
@@ -65,9 +76,16 @@ impl PageSummaryGenerator for ClaudePageSummaryGenerator {
             return Err(PageSummaryError::NoContent);
         }
 
+        let (_system_prompt, user_prompt) = build_summary_prompt(
+            &page.title,
+            &version.content,
+            self.max_summary_length.unwrap_or(500),
+            &self.options,
+        );
+
         debug!(
-            "Generating summary for page {} using Claude model {}",
-            page.uuid, self.model
+            "Generating summary for page {} using Claude model {} (prompt length: {})",
+            page.uuid, self.model, user_prompt.len()
         );
 
         // TODO: Implement Claude API integration
@@ -0,0 +1,84 @@
+//! Summary generator trying several AI providers in order
+
+use async_trait::async_trait;
+use tracing::{info, warn};
+
+use super::{PageSummaryError, PageSummaryGenerator};
+use crate::page::{DocsPage, DocsPageVersion};
+
+/// Generator delegating to a chain of providers, using the first summary generated
+///
+/// Configured through a comma-separated `module_docs_page_summary_ai_provider` setting,
+/// e.g. `openai,claude`.
+pub struct FallbackPageSummaryGenerator {
+    providers: Vec<(String, Box<dyn PageSummaryGenerator>)>,
+}
+
+impl FallbackPageSummaryGenerator {
+    /// Create a generator trying `providers` in order
+    ///
+    /// # Arguments
+    /// * `providers` - Provider names with their generators, in order of preference
+    pub fn new(providers: Vec<(String, Box<dyn PageSummaryGenerator>)>) -> Self {
+        Self { providers }
+    }
+}
+
+#[async_trait]
+impl PageSummaryGenerator for FallbackPageSummaryGenerator {
+    /// Try every provider in order until one generates a summary
+    ///
+    /// # Errors
+    /// Returns the error of the last provider if all providers fail, or
+    /// `PageSummaryError::ProviderError` if the chain is empty
+    async fn generate_summary(
+        &self,
+        page: &DocsPage,
+        version: &DocsPageVersion,
+    ) -> Result<String, PageSummaryError> {
+        let mut last_error = PageSummaryError::ProviderError("No AI provider configured".to_string());
+
+        for (name, generator) in &self.providers {
+            match generator.generate_summary(page, version).await {
+                Ok(summary) => {
+                    info!("AI provider '{}' generated the summary for page {}", name, page.uuid);
+                    return Ok(summary);
+                }
+                Err(e) => {
+                    warn!("AI provider '{}' failed to summarize page {}: {}", name, page.uuid, e);
+                    last_error = e;
+                }
+            }
+        }
+
+        Err(last_error)
+    }
+
+    /// Try every provider in order until one suggests a title
+    ///
+    /// # Errors
+    /// Returns the error of the last provider if all providers fail, or
+    /// `PageSummaryError::ProviderError` if the chain is empty
+    async fn suggest_title(
+        &self,
+        page: &DocsPage,
+        version: &DocsPageVersion,
+    ) -> Result<String, PageSummaryError> {
+        let mut last_error = PageSummaryError::ProviderError("No AI provider configured".to_string());
+
+        for (name, generator) in &self.providers {
+            match generator.suggest_title(page, version).await {
+                Ok(title) => {
+                    info!("AI provider '{}' suggested a title for page {}", name, page.uuid);
+                    return Ok(title);
+                }
+                Err(e) => {
+                    warn!("AI provider '{}' failed to suggest a title for page {}: {}", name, page.uuid, e);
+                    last_error = e;
+                }
+            }
+        }
+
+        Err(last_error)
+    }
+}
@@ -4,7 +4,7 @@
 
 use async_trait::async_trait;
 use crate::page::{DocsPage, DocsPageVersion};
-use crate::summary::{PageSummaryError, PageSummaryGenerator};
+use crate::summary::{build_summary_prompt, build_title_prompt, PageSummaryError, PageSummaryGenerator, SummaryOptions};
 use integrations::openai::{ChatCompletionRequest, ChatMessage, MessageRole, OpenAIClient};
 use tracing::{debug, error, warn};
 
@@ -13,6 +13,7 @@ pub struct OpenAIPageSummaryGenerator {
     client: OpenAIClient,
     model: String,
     max_summary_length: Option<usize>,
+    options: SummaryOptions,
 }
 
 impl OpenAIPageSummaryGenerator {
@@ -29,6 +30,7 @@ impl OpenAIPageSummaryGenerator {
             client: OpenAIClient::new(api_key),
             model,
             max_summary_length: Some(200), // Default to 200 characters
+            options: SummaryOptions::default(),
         }
     }
 
@@ -46,6 +48,7 @@ impl OpenAIPageSummaryGenerator {
             client: OpenAIClient::with_base_url(api_key, base_url),
             model,
             max_summary_length: Some(200),
+            options: SummaryOptions::default(),
         }
     }
 
@@ -58,6 +61,15 @@ impl OpenAIPageSummaryGenerator {
         self
     }
 
+    /// Set the length and style preferences used in the prompt
+    ///
+    /// # Arguments
+    /// * `options` - Summary options of the organization
+    pub fn with_summary_options(mut self, options: SummaryOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// Truncate content if it's too long for the model's context window
     ///
     /// OpenAI models have token limits. This function provides a rough estimate
@@ -77,55 +89,31 @@ impl OpenAIPageSummaryGenerator {
             content.to_string()
         }
     }
-}
 
-#[async_trait]
-impl PageSummaryGenerator for OpenAIPageSummaryGenerator {
-    async fn generate_summary(
+    /// Send a system and user prompt to the chat completion API and return the trimmed answer
+    async fn complete(
         &self,
-        page: &DocsPage,
-        version: &DocsPageVersion,
+        system_prompt: String,
+        user_prompt: String,
+        max_tokens: u32,
     ) -> Result<String, PageSummaryError> {
-        // Check if content is empty
-        if version.content.trim().is_empty() {
-            return Err(PageSummaryError::NoContent);
-        }
-
-        // Truncate content if necessary (most OpenAI models have ~128k token context)
-        // Using a conservative 100k tokens to leave room for the prompt
-        let content = self.truncate_content(&version.content, 100_000);
-
-        // Build the prompt
-        let system_prompt = "You are a documentation assistant. Generate a concise, informative summary of the following documentation page. The summary should be clear, professional, and capture the key points. Keep it brief and focused.";
-        
-        let user_prompt = format!(
-            "Page Title: {}\n\nPage Content:\n{}\n\nGenerate a short summary (maximum {} characters):",
-            page.title,
-            content,
-            self.max_summary_length.unwrap_or(500)
-        );
-
-        debug!(
-            "Generating summary for page {} using OpenAI model {}",
-            page.uuid, self.model
-        );
-
         // Create the chat completion request
         let request = ChatCompletionRequest {
             model: self.model.clone(),
             messages: vec![
                 ChatMessage {
                     role: MessageRole::System,
-                    content: system_prompt.to_string(),
+                    content: system_prompt,
                 },
                 ChatMessage {
                     role: MessageRole::User,
                     content: user_prompt,
                 },
             ],
-            temperature: Some(0.3), // Lower temperature for more consistent summaries
-            max_tokens: Some(150),  // Limit tokens to keep summaries concise
+            temperature: Some(0.3), // Lower temperature for more consistent answers
+            max_tokens: Some(max_tokens),
             stream: Some(false),
+            response_format: None,
         };
 
         // Call OpenAI API
@@ -151,18 +139,55 @@ impl PageSummaryGenerator for OpenAIPageSummaryGenerator {
                     integrations::openai::OpenAIError::InvalidResponse(msg) => {
                         PageSummaryError::ProviderError(format!("Invalid response: {}", msg))
                     }
+                    integrations::openai::OpenAIError::InvalidJsonContent(msg) => {
+                        PageSummaryError::ProviderError(format!("Invalid JSON content: {}", msg))
+                    }
                 }
             })?;
 
-        // Extract the summary from the response
-        let summary = response
+        // Extract the answer from the response
+        response
             .choices
             .first()
             .and_then(|choice| Some(choice.message.content.trim().to_string()))
             .ok_or_else(|| {
                 error!("OpenAI response missing content");
                 PageSummaryError::ProviderError("No content in OpenAI response".to_string())
-            })?;
+            })
+    }
+}
+
+#[async_trait]
+impl PageSummaryGenerator for OpenAIPageSummaryGenerator {
+    async fn generate_summary(
+        &self,
+        page: &DocsPage,
+        version: &DocsPageVersion,
+    ) -> Result<String, PageSummaryError> {
+        // Check if content is empty
+        if version.content.trim().is_empty() {
+            return Err(PageSummaryError::NoContent);
+        }
+
+        // Truncate content if necessary (most OpenAI models have ~128k token context)
+        // Using a conservative 100k tokens to leave room for the prompt
+        let content = self.truncate_content(&version.content, 100_000);
+
+        // Build the prompt
+        let (system_prompt, user_prompt) = build_summary_prompt(
+            &page.title,
+            &content,
+            self.max_summary_length.unwrap_or(500),
+            &self.options,
+        );
+
+        debug!(
+            "Generating summary for page {} using OpenAI model {}",
+            page.uuid, self.model
+        );
+
+        // Limit tokens to keep summaries concise
+        let summary = self.complete(system_prompt, user_prompt, 150).await?;
 
         // Truncate to max length if specified
         let summary = if let Some(max_len) = self.max_summary_length {
@@ -189,5 +214,24 @@ impl PageSummaryGenerator for OpenAIPageSummaryGenerator {
 
         Ok(summary)
     }
-}
 
+    async fn suggest_title(
+        &self,
+        page: &DocsPage,
+        version: &DocsPageVersion,
+    ) -> Result<String, PageSummaryError> {
+        if version.content.trim().is_empty() {
+            return Err(PageSummaryError::NoContent);
+        }
+
+        let content = self.truncate_content(&version.content, 100_000);
+        let (system_prompt, user_prompt) = build_title_prompt(&page.title, &content);
+
+        debug!(
+            "Suggesting title for page {} using OpenAI model {}",
+            page.uuid, self.model
+        );
+
+        self.complete(system_prompt, user_prompt, 30).await
+    }
+}
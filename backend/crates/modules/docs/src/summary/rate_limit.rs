@@ -0,0 +1,100 @@
+//! Per-organization rate limit for summary generation
+//!
+//! Every generated summary is a paid call to an external AI provider, so the number
+//! of calls per organization is limited within a sliding window.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// Calls per minute allowed if the organization did not configure a limit
+pub const DEFAULT_SUMMARY_CALLS_PER_MINUTE: u32 = 10;
+
+/// Limiter shared by all summary generations of this process
+static GLOBAL_LIMITER: LazyLock<SummaryRateLimiter> =
+    LazyLock::new(|| SummaryRateLimiter::new(Duration::from_secs(60)));
+
+/// Sliding window rate limiter keyed by organization UUID
+#[derive(Debug)]
+pub struct SummaryRateLimiter {
+    window: Duration,
+    calls: Mutex<HashMap<String, VecDeque<Instant>>>,
+}
+
+impl SummaryRateLimiter {
+    /// Create a limiter counting calls within `window`
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            calls: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Limiter with a one minute window shared by the whole process
+    pub fn global() -> &'static SummaryRateLimiter {
+        &GLOBAL_LIMITER
+    }
+
+    /// Record a call for `organization_uuid` if fewer than `limit` calls were made within the window
+    ///
+    /// # Errors
+    /// Returns the time until the next call is allowed if the limit is reached
+    pub fn try_acquire(&self, organization_uuid: &str, limit: u32) -> Result<(), Duration> {
+        self.try_acquire_at(organization_uuid, limit, Instant::now())
+    }
+
+    /// Record a call for `organization_uuid`, waiting until the window has room for it
+    pub async fn acquire(&self, organization_uuid: &str, limit: u32) {
+        while let Err(retry_after) = self.try_acquire(organization_uuid, limit) {
+            tokio::time::sleep(retry_after).await;
+        }
+    }
+
+    fn try_acquire_at(&self, organization_uuid: &str, limit: u32, now: Instant) -> Result<(), Duration> {
+        let mut calls = self.calls.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let timestamps = calls.entry(organization_uuid.to_string()).or_default();
+
+        while timestamps
+            .front()
+            .is_some_and(|oldest| now.duration_since(*oldest) >= self.window)
+        {
+            timestamps.pop_front();
+        }
+
+        if timestamps.len() >= limit as usize {
+            let retry_after = timestamps
+                .front()
+                .map(|oldest| self.window.saturating_sub(now.duration_since(*oldest)))
+                .unwrap_or(self.window);
+            return Err(retry_after);
+        }
+
+        timestamps.push_back(now);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_calls_above_limit_within_window() {
+        let limiter = SummaryRateLimiter::new(Duration::from_secs(60));
+        let start = Instant::now();
+
+        assert!(limiter.try_acquire_at("org-a", 2, start).is_ok());
+        assert!(limiter.try_acquire_at("org-a", 2, start + Duration::from_secs(10)).is_ok());
+        assert_eq!(
+            limiter.try_acquire_at("org-a", 2, start + Duration::from_secs(20)),
+            Err(Duration::from_secs(40))
+        );
+
+        // Other organizations have their own budget
+        assert!(limiter.try_acquire_at("org-b", 2, start + Duration::from_secs(20)).is_ok());
+
+        // Calls leave the window after it elapsed
+        assert!(limiter.try_acquire_at("org-a", 2, start + Duration::from_secs(60)).is_ok());
+        assert!(limiter.try_acquire_at("org-a", 2, start + Duration::from_secs(61)).is_err());
+    }
+}
@@ -0,0 +1,383 @@
+//! Shared helpers for Docs module tests
+//!
+//! Creates the SQLite schema the Docs module relies on and inserts test fixtures.
+
+use async_trait::async_trait;
+use flextide_core::database::DatabasePool;
+use flextide_core::events::{Event, EventSubscriber};
+use std::sync::{Arc, Mutex};
+
+/// Tables required by the Docs module (SQLite syntax)
+const SCHEMA: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS organizations (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        name VARCHAR(255) NOT NULL,
+        owner_user_id CHAR(36) NOT NULL,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+    )",
+    "CREATE TABLE IF NOT EXISTS organization_members (
+        org_id CHAR(36) NOT NULL,
+        user_id CHAR(36) NOT NULL,
+        role VARCHAR(20) NOT NULL DEFAULT 'member',
+        joined_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (org_id, user_id)
+    )",
+    "CREATE TABLE IF NOT EXISTS user_permissions (
+        user_id CHAR(36) NOT NULL,
+        organization_uuid CHAR(36) NOT NULL,
+        permission_name VARCHAR(255) NOT NULL,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (user_id, organization_uuid, permission_name)
+    )",
+    "CREATE TABLE IF NOT EXISTS module_docs_areas (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        organization_uuid CHAR(36) NOT NULL,
+        short_name VARCHAR(255) NOT NULL,
+        description TEXT,
+        icon_name VARCHAR(50),
+        color_hex VARCHAR(20),
+        topics TEXT,
+        public INTEGER NOT NULL DEFAULT 0,
+        visible INTEGER NOT NULL DEFAULT 1,
+        deletable INTEGER NOT NULL DEFAULT 1,
+        creator_uuid CHAR(36) NOT NULL,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        archived_at TIMESTAMP NULL
+    )",
+    "CREATE TABLE IF NOT EXISTS module_docs_area_members (
+        area_uuid CHAR(36) NOT NULL,
+        user_uuid CHAR(36) NOT NULL,
+        role VARCHAR(20) NOT NULL DEFAULT 'guest',
+        can_view INTEGER NOT NULL DEFAULT 0,
+        can_add_pages INTEGER NOT NULL DEFAULT 0,
+        can_edit_pages INTEGER NOT NULL DEFAULT 0,
+        can_edit_own_pages INTEGER NOT NULL DEFAULT 0,
+        can_archive_pages INTEGER NOT NULL DEFAULT 0,
+        can_archive_own_pages INTEGER NOT NULL DEFAULT 0,
+        can_delete_pages INTEGER NOT NULL DEFAULT 0,
+        can_delete_own_pages INTEGER NOT NULL DEFAULT 0,
+        can_export_pages INTEGER NOT NULL DEFAULT 0,
+        can_add_folders INTEGER NOT NULL DEFAULT 0,
+        can_edit_folders INTEGER NOT NULL DEFAULT 0,
+        can_delete_folders INTEGER NOT NULL DEFAULT 0,
+        can_edit_page_properties INTEGER NOT NULL DEFAULT 0,
+        can_edit_folder_properties INTEGER NOT NULL DEFAULT 0,
+        admin INTEGER NOT NULL DEFAULT 0,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (area_uuid, user_uuid)
+    )",
+    "CREATE TABLE IF NOT EXISTS module_docs_folders (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        organization_uuid CHAR(36) NOT NULL,
+        area_uuid CHAR(36) NOT NULL,
+        name VARCHAR(255) NOT NULL,
+        icon_name VARCHAR(50) NULL,
+        folder_color VARCHAR(20) NULL,
+        parent_folder_uuid CHAR(36),
+        sort_order INTEGER NOT NULL DEFAULT 0,
+        visible INTEGER NOT NULL DEFAULT 1,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        activated INTEGER NOT NULL DEFAULT 1,
+        auto_sync_to_vector_db INTEGER NOT NULL DEFAULT 0,
+        vcs_export_allowed INTEGER NOT NULL DEFAULT 0,
+        includes_private_data INTEGER NOT NULL DEFAULT 0,
+        metadata TEXT
+    )",
+    "CREATE TABLE IF NOT EXISTS module_docs_pages (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        organization_uuid CHAR(36) NOT NULL,
+        area_uuid CHAR(36) NOT NULL,
+        folder_uuid CHAR(36),
+        title VARCHAR(255) NOT NULL,
+        short_summary TEXT,
+        summary_content_hash CHAR(64),
+        parent_page_uuid CHAR(36),
+        current_version_uuid CHAR(36),
+        -- No CHECK on page_type, see 20251129090000_drop_module_docs_pages_page_type_check.sql
+        page_type VARCHAR(50) NOT NULL DEFAULT 'markdown_page',
+        last_updated TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        auto_sync_to_vector_db INTEGER NOT NULL DEFAULT 0,
+        vcs_export_allowed INTEGER NOT NULL DEFAULT 0,
+        includes_private_data INTEGER NOT NULL DEFAULT 0,
+        metadata TEXT,
+        sort_order INTEGER NOT NULL DEFAULT 0
+    )",
+    "CREATE TABLE IF NOT EXISTS module_docs_page_versions (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        page_uuid CHAR(36) NOT NULL,
+        version_number INTEGER NOT NULL DEFAULT 1,
+        content TEXT NOT NULL,
+        last_updated TIMESTAMP,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        CONSTRAINT unique_page_version UNIQUE (page_uuid, version_number)
+    )",
+    "CREATE TABLE IF NOT EXISTS module_docs_area_activity (
+        uuid CHAR(36) NOT NULL PRIMARY KEY,
+        organization_uuid CHAR(36) NOT NULL,
+        area_uuid CHAR(36) NOT NULL,
+        user_uuid CHAR(36) NOT NULL,
+        action VARCHAR(50) NOT NULL,
+        page_uuid CHAR(36) NOT NULL,
+        page_title VARCHAR(255) NOT NULL,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+    )",
+    "CREATE TABLE IF NOT EXISTS module_docs_page_views (
+        user_uuid CHAR(36) NOT NULL,
+        page_uuid CHAR(36) NOT NULL,
+        organization_uuid CHAR(36) NOT NULL,
+        viewed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (user_uuid, page_uuid)
+    )",
+    "CREATE TABLE IF NOT EXISTS organizational_settings (
+        name VARCHAR(255) NOT NULL PRIMARY KEY,
+        organizational_settings_group_name VARCHAR(255) NOT NULL,
+        title VARCHAR(255) NOT NULL,
+        description VARCHAR(255),
+        type VARCHAR(50) NOT NULL,
+        metadata JSON,
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+    )",
+    "CREATE TABLE IF NOT EXISTS organizational_settings_values (
+        organization_uuid CHAR(36) NOT NULL,
+        setting_name VARCHAR(255) NOT NULL,
+        value VARCHAR(600),
+        created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (setting_name, organization_uuid)
+    )",
+    // Settings read on every summary generation, defined by migrations
+    "INSERT OR IGNORE INTO organizational_settings (name, organizational_settings_group_name, title, type)
+     VALUES ('module_docs_summary_calls_per_minute', 'module_docs', 'Summary Generations per Minute', 'textfield')",
+];
+
+fn sqlite(pool: &DatabasePool) -> &sqlx::SqlitePool {
+    match pool {
+        DatabasePool::Sqlite(p) => p,
+        _ => panic!("Test only supports SQLite"),
+    }
+}
+
+/// Create all Docs module tables
+pub(crate) async fn setup_tables(pool: &DatabasePool) {
+    for statement in SCHEMA {
+        sqlx::query(statement)
+            .execute(sqlite(pool))
+            .await
+            .expect("Failed to create test table");
+    }
+}
+
+/// Create an organization and return its UUID
+pub(crate) async fn create_organization(pool: &DatabasePool, owner_uuid: &str) -> String {
+    let org_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO organizations (uuid, name, owner_user_id) VALUES (?1, 'Test Organization', ?2)")
+        .bind(&org_uuid)
+        .bind(owner_uuid)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert organization");
+    add_organization_member(pool, &org_uuid, owner_uuid).await;
+    org_uuid
+}
+
+/// Add a user to an organization
+pub(crate) async fn add_organization_member(pool: &DatabasePool, org_uuid: &str, user_uuid: &str) {
+    sqlx::query("INSERT INTO organization_members (org_id, user_id) VALUES (?1, ?2)")
+        .bind(org_uuid)
+        .bind(user_uuid)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert organization member");
+}
+
+/// Grant an organization-wide permission to a user
+pub(crate) async fn grant_permission(pool: &DatabasePool, org_uuid: &str, user_uuid: &str, permission: &str) {
+    sqlx::query("INSERT INTO user_permissions (user_id, organization_uuid, permission_name) VALUES (?1, ?2, ?3)")
+        .bind(user_uuid)
+        .bind(org_uuid)
+        .bind(permission)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert user permission");
+}
+
+/// Define an organizational setting if needed and set its value for an organization
+pub(crate) async fn set_setting(pool: &DatabasePool, org_uuid: &str, name: &str, value: &str) {
+    sqlx::query(
+        "INSERT OR IGNORE INTO organizational_settings (name, organizational_settings_group_name, title, type)
+         VALUES (?1, 'module_docs', ?1, 'textfield')",
+    )
+    .bind(name)
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert setting");
+
+    sqlx::query(
+        "INSERT OR REPLACE INTO organizational_settings_values (organization_uuid, setting_name, value)
+         VALUES (?1, ?2, ?3)",
+    )
+    .bind(org_uuid)
+    .bind(name)
+    .bind(value)
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert setting value");
+}
+
+/// Create an area and add the creator as area owner, returns the area UUID
+pub(crate) async fn create_area(pool: &DatabasePool, org_uuid: &str, creator_uuid: &str) -> String {
+    let area_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query(
+        "INSERT INTO module_docs_areas (uuid, organization_uuid, short_name, creator_uuid)
+         VALUES (?1, ?2, 'Test Area', ?3)",
+    )
+    .bind(&area_uuid)
+    .bind(org_uuid)
+    .bind(creator_uuid)
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert area");
+
+    sqlx::query(
+        "INSERT INTO module_docs_area_members (area_uuid, user_uuid, role, can_view, admin)
+         VALUES (?1, ?2, 'owner', 1, 1)",
+    )
+    .bind(&area_uuid)
+    .bind(creator_uuid)
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert area owner");
+
+    area_uuid
+}
+
+/// Create a root folder in an area, returns the folder UUID
+pub(crate) async fn create_folder(pool: &DatabasePool, org_uuid: &str, area_uuid: &str) -> String {
+    let folder_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO module_docs_folders (uuid, organization_uuid, area_uuid, name) VALUES (?1, ?2, ?3, 'Test Folder')")
+        .bind(&folder_uuid)
+        .bind(org_uuid)
+        .bind(area_uuid)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert folder");
+    folder_uuid
+}
+
+/// Create a page without content in an area, returns the page UUID
+pub(crate) async fn create_page(pool: &DatabasePool, org_uuid: &str, area_uuid: &str) -> String {
+    let page_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO module_docs_pages (uuid, organization_uuid, area_uuid, title) VALUES (?1, ?2, ?3, 'Test Page')")
+        .bind(&page_uuid)
+        .bind(org_uuid)
+        .bind(area_uuid)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert page");
+    page_uuid
+}
+
+/// Create a page without content in a folder, returns the page UUID
+pub(crate) async fn create_folder_page(
+    pool: &DatabasePool,
+    org_uuid: &str,
+    area_uuid: &str,
+    folder_uuid: &str,
+    title: &str,
+) -> String {
+    let page_uuid = uuid::Uuid::new_v4().to_string();
+    sqlx::query(
+        "INSERT INTO module_docs_pages (uuid, organization_uuid, area_uuid, folder_uuid, title, sort_order)
+         VALUES (?1, ?2, ?3, ?4, ?5,
+                 (SELECT COALESCE(MAX(sort_order) + 1, 0) FROM module_docs_pages WHERE area_uuid = ?3 AND folder_uuid = ?4))",
+    )
+    .bind(&page_uuid)
+    .bind(org_uuid)
+    .bind(area_uuid)
+    .bind(folder_uuid)
+    .bind(title)
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert page");
+    page_uuid
+}
+
+/// Event subscriber recording the names of all received events
+pub(crate) struct RecordingSubscriber {
+    event_name: String,
+    received: Arc<Mutex<Vec<Event>>>,
+}
+
+impl RecordingSubscriber {
+    /// Create a subscriber for `event_name` and return the shared list of received events
+    pub(crate) fn new(event_name: &str) -> (Self, Arc<Mutex<Vec<Event>>>) {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        (
+            Self {
+                event_name: event_name.to_string(),
+                received: received.clone(),
+            },
+            received,
+        )
+    }
+}
+
+#[async_trait]
+impl EventSubscriber for RecordingSubscriber {
+    async fn handle_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.received.lock().unwrap().push(event.clone());
+        Ok(())
+    }
+
+    fn event_name(&self) -> &str {
+        &self.event_name
+    }
+
+    fn subscriber_id(&self) -> &str {
+        "docs-test-recorder"
+    }
+}
+
+/// Answer a single HTTP request on `listener` with `response` as JSON and return the received request body
+pub(crate) async fn serve_json_once(listener: tokio::net::TcpListener, response: serde_json::Value) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let (mut stream, _) = listener.accept().await.expect("Failed to accept connection");
+    let mut request = Vec::new();
+    let mut buffer = [0u8; 4096];
+    let body = loop {
+        let read = stream.read(&mut buffer).await.expect("Failed to read request");
+        request.extend_from_slice(&buffer[..read]);
+        let text = String::from_utf8_lossy(&request).to_string();
+        if let Some((headers, body)) = text.split_once("\r\n\r\n") {
+            let content_length = headers
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length").then(|| value.trim().parse::<usize>().ok())?
+                })
+                .unwrap_or(0);
+            if body.len() >= content_length || read == 0 {
+                break body.to_string();
+            }
+        }
+    };
+
+    let response = response.to_string();
+    stream
+        .write_all(
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            )
+            .as_bytes(),
+        )
+        .await
+        .expect("Failed to write response");
+
+    body
+}
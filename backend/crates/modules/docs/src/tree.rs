@@ -4,10 +4,18 @@
 
 use flextide_core::database::DatabasePool;
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 
+use crate::area::{load_area_by_uuid, DocsAreaDatabaseError};
 use crate::folder::{get_all_folders, DocsFolder, DocsFolderDatabaseError};
 use crate::page::{get_all_pages, DocsPage, DocsPageDatabaseError};
 
+/// Maximum nesting depth of area trees built by [`get_area_tree`]
+///
+/// Root folders and pages are at depth 1. Real structures stay far below this, deeper
+/// chains are a sign of corrupt parent references.
+pub const DEFAULT_MAX_TREE_DEPTH: usize = 64;
+
 /// Tree node that can represent either a folder or a page
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -16,6 +24,29 @@ pub enum TreeNode {
     Folder(FolderNode),
     #[serde(rename = "page")]
     Page(PageNode),
+    /// Replaces the children of a node at the maximum depth
+    #[serde(rename = "truncated")]
+    Truncated(TruncatedNode),
+}
+
+impl TreeNode {
+    /// Name of the node used in paths: the folder name or the page title
+    pub fn name(&self) -> &str {
+        match self {
+            TreeNode::Folder(node) => &node.folder.name,
+            TreeNode::Page(node) => &node.page.title,
+            TreeNode::Truncated(_) => "",
+        }
+    }
+
+    /// Child folders and pages of the node
+    pub fn children(&self) -> &[TreeNode] {
+        match self {
+            TreeNode::Folder(node) => &node.children,
+            TreeNode::Page(node) => &node.children,
+            TreeNode::Truncated(_) => &[],
+        }
+    }
 }
 
 /// Folder node in the tree structure
@@ -32,6 +63,13 @@ pub struct PageNode {
     pub children: Vec<TreeNode>,
 }
 
+/// Marker for children that were left out because the maximum depth was reached
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TruncatedNode {
+    /// Number of direct children that were left out
+    pub omitted: usize,
+}
+
 /// Complete tree structure for an area
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocsAreaTree {
@@ -41,6 +79,8 @@ pub struct DocsAreaTree {
 
 /// Build a tree structure from folders and pages
 ///
+/// Nesting is limited to [`DEFAULT_MAX_TREE_DEPTH`], see [`build_area_tree_with_max_depth`].
+///
 /// # Arguments
 /// * `folders` - All folders for the area
 /// * `pages` - All pages for the area
@@ -51,6 +91,29 @@ pub fn build_area_tree(
     folders: Vec<DocsFolder>,
     pages: Vec<DocsPage>,
 ) -> DocsAreaTree {
+    build_area_tree_with_max_depth(folders, pages, DEFAULT_MAX_TREE_DEPTH)
+}
+
+/// Build a tree structure from folders and pages with a custom maximum depth
+///
+/// Nodes at `max_depth` (root nodes are at depth 1) get a single `TreeNode::Truncated`
+/// child in place of their children, and a warning is logged. This keeps corrupt or
+/// maliciously deep parent chains from exhausting the stack.
+///
+/// # Arguments
+/// * `folders` - All folders for the area
+/// * `pages` - All pages for the area
+/// * `max_depth` - Deepest level that is built, at least 1
+///
+/// # Returns
+/// Returns a `DocsAreaTree` with hierarchical structure
+pub fn build_area_tree_with_max_depth(
+    folders: Vec<DocsFolder>,
+    pages: Vec<DocsPage>,
+    max_depth: usize,
+) -> DocsAreaTree {
+    let limits = DepthLimit { depth: 1, max_depth: max_depth.max(1) };
+
     // Separate root items from children
     let root_folders: Vec<&DocsFolder> = folders
         .iter()
@@ -65,13 +128,13 @@ pub fn build_area_tree(
     // Build folder tree nodes
     let folder_nodes: Vec<TreeNode> = root_folders
         .into_iter()
-        .map(|folder| build_folder_node(folder, &folders, &pages))
+        .map(|folder| build_folder_node(folder, &folders, &pages, limits))
         .collect();
 
     // Build page tree nodes (root pages not in folders)
     let page_nodes: Vec<TreeNode> = root_pages
         .into_iter()
-        .map(|page| build_page_node(page, &pages))
+        .map(|page| build_page_node(page, &pages, limits))
         .collect();
 
     DocsAreaTree {
@@ -80,11 +143,63 @@ pub fn build_area_tree(
     }
 }
 
+/// Resolve a path of folder names and page titles to a node of the tree
+///
+/// The path is relative to the area, e.g. `["Guides", "Setup", "Installation"]` for the
+/// page "Installation" in the subfolder "Setup" of the root folder "Guides".
+/// Segments are matched exactly. If a folder and a page share a name, the folder wins.
+///
+/// # Returns
+/// Returns the node of the last segment, or `None` if the path is empty or a segment
+/// does not match any child of the previous node
+pub fn resolve_tree_path<'a>(tree: &'a DocsAreaTree, path: &[&str]) -> Option<&'a TreeNode> {
+    let (first, rest) = path.split_first()?;
+
+    let mut node = tree
+        .folders
+        .iter()
+        .chain(tree.pages.iter())
+        .find(|node| node.name() == *first)?;
+
+    for segment in rest {
+        node = node.children().iter().find(|child| child.name() == *segment)?;
+    }
+
+    Some(node)
+}
+
+/// Depth of the node being built and the deepest level that may be built
+#[derive(Debug, Clone, Copy)]
+struct DepthLimit {
+    depth: usize,
+    max_depth: usize,
+}
+
+impl DepthLimit {
+    fn child(self) -> Self {
+        Self { depth: self.depth + 1, ..self }
+    }
+
+    fn reached(self) -> bool {
+        self.depth >= self.max_depth
+    }
+}
+
+/// Marker replacing the children of a node at the maximum depth
+fn truncated_children(kind: &str, uuid: &str, omitted: usize, limits: DepthLimit) -> Vec<TreeNode> {
+    warn!(
+        "Docs tree truncated at {} {}: maximum depth of {} reached, {} children omitted",
+        kind, uuid, limits.max_depth, omitted
+    );
+    vec![TreeNode::Truncated(TruncatedNode { omitted })]
+}
+
 /// Build a folder node with its children
 fn build_folder_node(
     folder: &DocsFolder,
     all_folders: &[DocsFolder],
     all_pages: &[DocsPage],
+    limits: DepthLimit,
 ) -> TreeNode {
     let folder_uuid = &folder.uuid;
     
@@ -100,20 +215,28 @@ fn build_folder_node(
         .filter(|p| p.folder_uuid.as_deref() == Some(folder_uuid) && p.parent_page_uuid.is_none())
         .collect();
 
+    if limits.reached() && !(child_folders.is_empty() && child_pages.is_empty()) {
+        return TreeNode::Folder(FolderNode {
+            folder: folder.clone(),
+            children: truncated_children("folder", folder_uuid, child_folders.len() + child_pages.len(), limits),
+        });
+    }
+
     // Build child nodes
     let mut children: Vec<TreeNode> = Vec::new();
 
     // Add child folders
     for child_folder in child_folders {
-        children.push(build_folder_node(child_folder, all_folders, all_pages));
+        children.push(build_folder_node(child_folder, all_folders, all_pages, limits.child()));
     }
 
     // Add child pages
     for child_page in child_pages {
-        children.push(build_page_node(child_page, all_pages));
+        children.push(build_page_node(child_page, all_pages, limits.child()));
     }
 
-    // Sort children: folders first (by sort_order), then pages (by created_at)
+    // Sort children: folders first (by sort_order), then pages. The sort is stable, so pages
+    // keep the order of get_all_pages (by sort_order, then newer first)
     children.sort_by(|a, b| {
         match (a, b) {
             (TreeNode::Folder(fa), TreeNode::Folder(fb)) => {
@@ -121,9 +244,7 @@ fn build_folder_node(
             }
             (TreeNode::Folder(_), TreeNode::Page(_)) => std::cmp::Ordering::Less,
             (TreeNode::Page(_), TreeNode::Folder(_)) => std::cmp::Ordering::Greater,
-            (TreeNode::Page(pa), TreeNode::Page(pb)) => {
-                pb.page.created_at.cmp(&pa.page.created_at) // Newer first
-            }
+            _ => std::cmp::Ordering::Equal, // Truncated markers are never mixed with other children
         }
     });
 
@@ -134,7 +255,7 @@ fn build_folder_node(
 }
 
 /// Build a page node with its children
-fn build_page_node(page: &DocsPage, all_pages: &[DocsPage]) -> TreeNode {
+fn build_page_node(page: &DocsPage, all_pages: &[DocsPage], limits: DepthLimit) -> TreeNode {
     let page_uuid = &page.uuid;
 
     // Find child pages
@@ -143,22 +264,19 @@ fn build_page_node(page: &DocsPage, all_pages: &[DocsPage]) -> TreeNode {
         .filter(|p| p.parent_page_uuid.as_deref() == Some(page_uuid))
         .collect();
 
-    // Build child nodes
-    let mut children: Vec<TreeNode> = child_pages
+    if limits.reached() && !child_pages.is_empty() {
+        return TreeNode::Page(PageNode {
+            page: page.clone(),
+            children: truncated_children("page", page_uuid, child_pages.len(), limits),
+        });
+    }
+
+    // Build child nodes, in the order of get_all_pages (by sort_order, then newer first)
+    let children: Vec<TreeNode> = child_pages
         .into_iter()
-        .map(|child_page| build_page_node(child_page, all_pages))
+        .map(|child_page| build_page_node(child_page, all_pages, limits.child()))
         .collect();
 
-    // Sort children by created_at (newer first)
-    children.sort_by(|a, b| {
-        match (a, b) {
-            (TreeNode::Page(pa), TreeNode::Page(pb)) => {
-                pb.page.created_at.cmp(&pa.page.created_at)
-            }
-            _ => std::cmp::Ordering::Equal, // Shouldn't happen for page children
-        }
-    });
-
     TreeNode::Page(PageNode {
         page: page.clone(),
         children,
@@ -168,10 +286,16 @@ fn build_page_node(page: &DocsPage, all_pages: &[DocsPage]) -> TreeNode {
 /// Error type for tree building operations
 #[derive(Debug, thiserror::Error)]
 pub enum DocsTreeError {
+    #[error("Area database error: {0}")]
+    AreaError(#[from] DocsAreaDatabaseError),
+    #[error("Area is archived")]
+    AreaArchived,
     #[error("Folder database error: {0}")]
     FolderError(#[from] DocsFolderDatabaseError),
     #[error("Page database error: {0}")]
     PageError(#[from] DocsPageDatabaseError),
+    #[error("Failed to serialize tree: {0}")]
+    Serialization(#[from] serde_json::Error),
 }
 
 /// Build a tree structure for an area by fetching folders and pages from the database
@@ -180,20 +304,179 @@ pub enum DocsTreeError {
 /// * `pool` - Database connection pool
 /// * `organization_uuid` - UUID of the organization
 /// * `area_uuid` - UUID of the area
+/// * `include_archived` - Whether the tree of an archived area may be returned
 ///
 /// # Returns
 /// Returns a `DocsAreaTree` with hierarchical structure
 ///
 /// # Errors
-/// Returns `DocsTreeError` if database operations fail
+/// Returns `DocsTreeError::AreaArchived` if the area is archived and `include_archived` is false,
+/// or `DocsTreeError` if database operations fail
 pub async fn get_area_tree(
     pool: &DatabasePool,
     organization_uuid: &str,
     area_uuid: &str,
+    include_archived: bool,
 ) -> Result<DocsAreaTree, DocsTreeError> {
+    let area = load_area_by_uuid(pool, area_uuid).await?;
+    if area.archived_at.is_some() && !include_archived {
+        return Err(DocsTreeError::AreaArchived);
+    }
+
     let folders = get_all_folders(pool, organization_uuid, area_uuid).await?;
     let pages = get_all_pages(pool, organization_uuid, area_uuid).await?;
 
     Ok(build_area_tree(folders, pages))
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn folder(uuid: &str, name: &str, parent_folder_uuid: Option<&str>) -> DocsFolder {
+        DocsFolder {
+            uuid: uuid.to_string(),
+            organization_uuid: "org".to_string(),
+            area_uuid: "area".to_string(),
+            name: name.to_string(),
+            icon_name: None,
+            folder_color: None,
+            parent_folder_uuid: parent_folder_uuid.map(str::to_string),
+            sort_order: 0,
+            visible: true,
+            created_at: Utc::now(),
+            activated: true,
+            auto_sync_to_vector_db: false,
+            vcs_export_allowed: false,
+            includes_private_data: false,
+            metadata: None,
+        }
+    }
+
+    fn page(uuid: &str, title: &str, folder_uuid: Option<&str>, parent_page_uuid: Option<&str>) -> DocsPage {
+        DocsPage {
+            uuid: uuid.to_string(),
+            organization_uuid: "org".to_string(),
+            area_uuid: "area".to_string(),
+            folder_uuid: folder_uuid.map(str::to_string),
+            title: title.to_string(),
+            short_summary: None,
+            parent_page_uuid: parent_page_uuid.map(str::to_string),
+            current_version_uuid: None,
+            page_type: "markdown_page".to_string(),
+            last_updated: Utc::now(),
+            created_at: Utc::now(),
+            auto_sync_to_vector_db: 0,
+            vcs_export_allowed: 0,
+            includes_private_data: 0,
+            metadata: None,
+        }
+    }
+
+    fn uuid_of(node: Option<&TreeNode>) -> Option<&str> {
+        node.map(|node| match node {
+            TreeNode::Folder(node) => node.folder.uuid.as_str(),
+            TreeNode::Page(node) => node.page.uuid.as_str(),
+            TreeNode::Truncated(_) => "truncated",
+        })
+    }
+
+    fn sample_tree() -> DocsAreaTree {
+        build_area_tree(
+            vec![
+                folder("guides", "Guides", None),
+                folder("setup", "Setup", Some("guides")),
+            ],
+            vec![
+                page("install", "Installation", Some("setup"), None),
+                page("linux", "Linux", Some("setup"), Some("install")),
+                page("overview", "Overview", Some("guides"), None),
+                page("readme", "Readme", None, None),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_resolve_tree_path_walks_nested_segments() {
+        let tree = sample_tree();
+
+        assert_eq!(uuid_of(resolve_tree_path(&tree, &["Guides"])), Some("guides"));
+        assert_eq!(uuid_of(resolve_tree_path(&tree, &["Readme"])), Some("readme"));
+        assert_eq!(uuid_of(resolve_tree_path(&tree, &["Guides", "Overview"])), Some("overview"));
+        assert_eq!(
+            uuid_of(resolve_tree_path(&tree, &["Guides", "Setup", "Installation"])),
+            Some("install")
+        );
+        assert_eq!(
+            uuid_of(resolve_tree_path(&tree, &["Guides", "Setup", "Installation", "Linux"])),
+            Some("linux")
+        );
+    }
+
+    /// Depth of the deepest node and the marker found below it, following first children
+    fn depth_of(node: &TreeNode) -> (usize, Option<usize>) {
+        let mut depth = 1;
+        let mut node = node;
+        while let Some(child) = node.children().first() {
+            if let TreeNode::Truncated(marker) = child {
+                return (depth, Some(marker.omitted));
+            }
+            node = child;
+            depth += 1;
+        }
+        (depth, None)
+    }
+
+    #[test]
+    fn test_build_area_tree_stops_at_max_depth() {
+        // A folder chain of 200 levels, the last folder containing a page with a child page
+        let folders: Vec<DocsFolder> = (0..200)
+            .map(|i| {
+                let parent = (i > 0).then(|| format!("folder-{}", i - 1));
+                folder(&format!("folder-{}", i), "Folder", parent.as_deref())
+            })
+            .collect();
+        let pages = vec![
+            page("deep", "Deep", Some("folder-199"), None),
+            page("deeper", "Deeper", Some("folder-199"), Some("deep")),
+        ];
+
+        let tree = build_area_tree_with_max_depth(folders.clone(), pages.clone(), 10);
+        assert_eq!(tree.folders.len(), 1);
+        assert_eq!(depth_of(&tree.folders[0]), (10, Some(1)));
+
+        // Chains below the limit are built completely
+        let tree = build_area_tree_with_max_depth(folders, pages, 300);
+        assert_eq!(depth_of(&tree.folders[0]), (202, None));
+    }
+
+    #[test]
+    fn test_build_area_tree_limits_deep_page_chains() {
+        let pages: Vec<DocsPage> = (0..5_000)
+            .map(|i| {
+                let parent = (i > 0).then(|| format!("page-{}", i - 1));
+                page(&format!("page-{}", i), "Page", None, parent.as_deref())
+            })
+            .collect();
+
+        let tree = build_area_tree(Vec::new(), pages);
+        assert_eq!(tree.pages.len(), 1);
+        assert_eq!(depth_of(&tree.pages[0]), (DEFAULT_MAX_TREE_DEPTH, Some(1)));
+        assert!(resolve_tree_path(&tree, &["Page", "Page"]).is_some());
+    }
+
+    #[test]
+    fn test_resolve_tree_path_returns_none_for_missing_segment() {
+        let tree = sample_tree();
+
+        assert!(resolve_tree_path(&tree, &[]).is_none());
+        assert!(resolve_tree_path(&tree, &["Missing"]).is_none());
+        assert!(resolve_tree_path(&tree, &["Guides", "Missing", "Installation"]).is_none());
+        assert!(resolve_tree_path(&tree, &["Guides", "Setup", "Installation", "Windows"]).is_none());
+        // Pages are only reachable through their folder
+        assert!(resolve_tree_path(&tree, &["Installation"]).is_none());
+        // Names are matched exactly
+        assert!(resolve_tree_path(&tree, &["guides"]).is_none());
+    }
+}
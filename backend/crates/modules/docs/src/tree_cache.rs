@@ -0,0 +1,334 @@
+//! In-memory cache of serialized area trees
+//!
+//! Building an area tree loads all folders and pages of the area, while the tree only
+//! changes when folders or pages are mutated. The cache keeps the serialized tree per
+//! `(organization, area)` and drops it when the dispatcher it is subscribed to emits a
+//! folder, page or area event.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use flextide_core::database::DatabasePool;
+use flextide_core::events::{Event, EventDispatcher};
+
+use crate::area::load_area_by_uuid;
+use crate::folder::get_all_folders;
+use crate::page::get_all_pages;
+use crate::tree::{build_area_tree, DocsTreeError};
+
+/// Subscriber ID the cache registers with the dispatcher
+pub const AREA_TREE_CACHE_SUBSCRIBER_ID: &str = "module_docs_area_tree_cache";
+
+/// Events after which a cached area tree may be stale
+pub const TREE_INVALIDATING_EVENTS: &[&str] = &[
+    "module_docs_page_created",
+    "module_docs_page_deleted",
+    "module_docs_page_moved",
+    "module_docs_page_properties_updated",
+    "module_docs_page_content_updated",
+    "module_docs_page_summary_generated",
+    "module_docs_page_summary_updated",
+    "module_docs_page_version_created",
+    "module_docs_pages_reordered",
+    "module_docs_folder_created",
+    "module_docs_folder_deleted",
+    "module_docs_folder_updated",
+    "module_docs_folder_properties_updated",
+    "module_docs_area_updated",
+    "module_docs_area_deleted",
+    "module_docs_area_archived",
+    "module_docs_area_unarchived",
+];
+
+/// Cache shared by all requests of this process
+static GLOBAL_CACHE: LazyLock<Arc<AreaTreeCache>> = LazyLock::new(|| Arc::new(AreaTreeCache::new()));
+
+#[derive(Debug, Default)]
+struct CacheState {
+    /// Serialized trees keyed by `(organization_uuid, area_uuid)`
+    trees: HashMap<(String, String), String>,
+    /// Incremented on every invalidation, see [`AreaTreeCache::get_or_build`]
+    generation: u64,
+}
+
+/// Serialized area trees keyed by organization and area
+#[derive(Debug, Default)]
+pub struct AreaTreeCache {
+    state: Mutex<CacheState>,
+}
+
+impl AreaTreeCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cache shared by the whole process
+    pub fn global() -> &'static Arc<AreaTreeCache> {
+        &GLOBAL_CACHE
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Invalidate the cache whenever `dispatcher` emits one of [`TREE_INVALIDATING_EVENTS`]
+    ///
+    /// Runtime subscribers are awaited by [`EventDispatcher::emit`], so the tree is
+    /// dropped before the request that mutated the area completes.
+    pub fn subscribe(self: &Arc<Self>, dispatcher: &EventDispatcher) {
+        for event_name in TREE_INVALIDATING_EVENTS {
+            let cache = Arc::clone(self);
+            dispatcher.subscribe_fn(AREA_TREE_CACHE_SUBSCRIBER_ID, *event_name, move |event: Event| {
+                let cache = Arc::clone(&cache);
+                async move {
+                    cache.handle_event(&event);
+                    Ok(())
+                }
+            });
+        }
+    }
+
+    /// Whether the cache is invalidated by all relevant events of `dispatcher`
+    pub fn is_subscribed(dispatcher: &EventDispatcher) -> bool {
+        TREE_INVALIDATING_EVENTS
+            .iter()
+            .all(|event_name| dispatcher.has_subscriber(event_name, AREA_TREE_CACHE_SUBSCRIBER_ID))
+    }
+
+    /// Drop the trees affected by an event
+    ///
+    /// Area events carry the area as entity, folder and page events carry it in their
+    /// data. If the area cannot be determined, all trees of the organization (or all
+    /// trees if the event has no organization) are dropped.
+    pub fn handle_event(&self, event: &Event) {
+        let payload = &event.payload.data;
+        let area_uuid = if payload.get("entity_type").and_then(|v| v.as_str()) == Some("area") {
+            payload.get("entity_id").and_then(|v| v.as_str())
+        } else {
+            payload
+                .get("data")
+                .and_then(|data| data.get("area_uuid"))
+                .and_then(|v| v.as_str())
+        };
+
+        match (event.organization_uuid.as_deref(), area_uuid) {
+            (Some(organization_uuid), Some(area_uuid)) => self.invalidate_area(organization_uuid, area_uuid),
+            (Some(organization_uuid), None) => self.invalidate_organization(organization_uuid),
+            (None, _) => self.clear(),
+        }
+    }
+
+    /// Drop the tree of one area
+    pub fn invalidate_area(&self, organization_uuid: &str, area_uuid: &str) {
+        let mut state = self.lock();
+        state.generation += 1;
+        state
+            .trees
+            .remove(&(organization_uuid.to_string(), area_uuid.to_string()));
+    }
+
+    /// Drop the trees of all areas of an organization
+    pub fn invalidate_organization(&self, organization_uuid: &str) {
+        let mut state = self.lock();
+        state.generation += 1;
+        state.trees.retain(|(organization, _), _| organization != organization_uuid);
+    }
+
+    /// Drop all trees
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.generation += 1;
+        state.trees.clear();
+    }
+
+    /// Get the serialized tree of an area, building it on a cache miss
+    ///
+    /// The archived check runs on every call since the tree itself does not depend on it.
+    /// A tree built while an invalidation happened is returned but not cached, it may
+    /// have been read before the mutation was committed.
+    ///
+    /// # Arguments
+    /// * `pool` - Database connection pool
+    /// * `organization_uuid` - UUID of the organization
+    /// * `area_uuid` - UUID of the area
+    /// * `include_archived` - Whether the tree of an archived area may be returned
+    ///
+    /// # Returns
+    /// The `DocsAreaTree` serialized as JSON
+    ///
+    /// # Errors
+    /// Returns `DocsTreeError` if the area is archived or loading the area, folders or pages fails
+    pub async fn get_or_build(
+        &self,
+        pool: &DatabasePool,
+        organization_uuid: &str,
+        area_uuid: &str,
+        include_archived: bool,
+    ) -> Result<String, DocsTreeError> {
+        let area = load_area_by_uuid(pool, area_uuid).await?;
+        if area.archived_at.is_some() && !include_archived {
+            return Err(DocsTreeError::AreaArchived);
+        }
+
+        let key = (organization_uuid.to_string(), area_uuid.to_string());
+        let generation = {
+            let state = self.lock();
+            if let Some(tree) = state.trees.get(&key) {
+                return Ok(tree.clone());
+            }
+            state.generation
+        };
+
+        let folders = get_all_folders(pool, organization_uuid, area_uuid).await?;
+        let pages = get_all_pages(pool, organization_uuid, area_uuid).await?;
+        let tree = serde_json::to_string(&build_area_tree(folders, pages))?;
+
+        let mut state = self.lock();
+        if state.generation == generation {
+            state.trees.insert(key, tree.clone());
+        }
+
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::page::{create_page, reorder_pages, CreateDocsPageRequest};
+    use crate::test_support;
+    use flextide_core::events::EventPayload;
+    use crate::tree::DocsAreaTree;
+    use serde_json::json;
+
+    fn page_titles(tree: &str) -> Vec<String> {
+        let tree: DocsAreaTree = serde_json::from_str(tree).expect("Tree is not valid JSON");
+        let mut titles: Vec<String> = tree.pages.iter().map(|node| node.name().to_string()).collect();
+        titles.sort();
+        titles
+    }
+
+    /// Titles of the pages in the first root folder, in tree order
+    fn folder_page_titles(tree: &str) -> Vec<String> {
+        let tree: DocsAreaTree = serde_json::from_str(tree).expect("Tree is not valid JSON");
+        tree.folders[0].children().iter().map(|node| node.name().to_string()).collect()
+    }
+
+    #[sqlx::test]
+    async fn test_serves_cached_tree_until_page_created(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        test_support::setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = test_support::create_organization(&pool, &user_uuid).await;
+        let area_uuid = test_support::create_area(&pool, &org_uuid, &user_uuid).await;
+        test_support::create_page(&pool, &org_uuid, &area_uuid).await;
+
+        let dispatcher = EventDispatcher::new();
+        let cache = Arc::new(AreaTreeCache::new());
+        cache.subscribe(&dispatcher);
+        assert!(AreaTreeCache::is_subscribed(&dispatcher));
+
+        let tree = cache
+            .get_or_build(&pool, &org_uuid, &area_uuid, false)
+            .await
+            .expect("Failed to build tree");
+        assert_eq!(page_titles(&tree), vec!["Test Page"]);
+
+        // Written without an event, so the cached tree is served
+        test_support::create_page(&pool, &org_uuid, &area_uuid).await;
+        let cached = cache
+            .get_or_build(&pool, &org_uuid, &area_uuid, false)
+            .await
+            .expect("Failed to load tree");
+        assert_eq!(cached, tree);
+
+        let request = CreateDocsPageRequest {
+            area_uuid: area_uuid.clone(),
+            title: "New Page".to_string(),
+            short_summary: None,
+            folder_uuid: None,
+            parent_page_uuid: None,
+            page_type: None,
+            auto_sync_to_vector_db: None,
+            vcs_export_allowed: None,
+            includes_private_data: None,
+        };
+        create_page(&pool, &org_uuid, &user_uuid, request, &dispatcher)
+            .await
+            .expect("Failed to create page");
+
+        let rebuilt = cache
+            .get_or_build(&pool, &org_uuid, &area_uuid, false)
+            .await
+            .expect("Failed to rebuild tree");
+        assert_eq!(page_titles(&rebuilt), vec!["New Page", "Test Page", "Test Page"]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_reorder_invalidates_cached_tree(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        test_support::setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = test_support::create_organization(&pool, &user_uuid).await;
+        let area_uuid = test_support::create_area(&pool, &org_uuid, &user_uuid).await;
+        let folder_uuid = test_support::create_folder(&pool, &org_uuid, &area_uuid).await;
+        let first = test_support::create_folder_page(&pool, &org_uuid, &area_uuid, &folder_uuid, "First").await;
+        let second = test_support::create_folder_page(&pool, &org_uuid, &area_uuid, &folder_uuid, "Second").await;
+
+        let dispatcher = EventDispatcher::new();
+        let cache = Arc::new(AreaTreeCache::new());
+        cache.subscribe(&dispatcher);
+
+        reorder_pages(&pool, &org_uuid, &user_uuid, &folder_uuid, &[first.clone(), second.clone()], Some(&dispatcher))
+            .await
+            .expect("Failed to reorder pages");
+        let tree = cache
+            .get_or_build(&pool, &org_uuid, &area_uuid, false)
+            .await
+            .expect("Failed to build tree");
+        assert_eq!(folder_page_titles(&tree), vec!["First", "Second"]);
+
+        reorder_pages(&pool, &org_uuid, &user_uuid, &folder_uuid, &[second, first], Some(&dispatcher))
+            .await
+            .expect("Failed to reorder pages");
+        let tree = cache
+            .get_or_build(&pool, &org_uuid, &area_uuid, false)
+            .await
+            .expect("Failed to rebuild tree");
+        assert_eq!(folder_page_titles(&tree), vec!["Second", "First"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_invalidates_only_its_area() {
+        let cache = AreaTreeCache::new();
+        {
+            let mut state = cache.lock();
+            state.trees.insert(("org".to_string(), "area-a".to_string()), "[]".to_string());
+            state.trees.insert(("org".to_string(), "area-b".to_string()), "[]".to_string());
+        }
+
+        let event = Event::new(
+            "module_docs_folder_created",
+            EventPayload::new(json!({
+                "entity_type": "folder",
+                "entity_id": "folder",
+                "organization_uuid": "org",
+                "data": { "area_uuid": "area-a" }
+            })),
+        )
+        .with_organization("org");
+        cache.handle_event(&event);
+
+        let state = cache.lock();
+        assert!(!state.trees.contains_key(&("org".to_string(), "area-a".to_string())));
+        assert!(state.trees.contains_key(&("org".to_string(), "area-b".to_string())));
+        assert_eq!(state.generation, 1);
+    }
+}
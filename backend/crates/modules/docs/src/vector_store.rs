@@ -0,0 +1,43 @@
+//! Vector database layout of the Docs module
+//!
+//! Every organization has its own Chroma collection ([`docs_collection_name`]). A page
+//! is stored as chunks with their own ids, which carry the page UUID in their metadata
+//! ([`CHUNK_METADATA_PAGE_UUID`]).
+
+use integrations::chroma::{ChromaClient, ChromaError, MetadataFilter};
+use serde_json::json;
+
+use crate::qa::CHUNK_METADATA_PAGE_UUID;
+
+/// Name of the Chroma collection holding the page vectors of an organization
+pub fn docs_collection_name(organization_uuid: &str) -> String {
+    format!("module_docs_{}", organization_uuid)
+}
+
+/// Chroma database the page vectors are stored in
+pub struct DocsVectorStore {
+    pub client: ChromaClient,
+    pub tenant: String,
+    pub database: String,
+}
+
+impl DocsVectorStore {
+    pub fn new(client: ChromaClient, tenant: String, database: String) -> Self {
+        Self { client, tenant, database }
+    }
+
+    /// Remove all chunks of a page
+    ///
+    /// # Errors
+    /// Returns `ChromaError` if the delete request fails
+    pub async fn delete_page(&self, organization_uuid: &str, page_uuid: &str) -> Result<(), ChromaError> {
+        self.client
+            .delete_by_where(
+                &self.tenant,
+                &self.database,
+                &docs_collection_name(organization_uuid),
+                MetadataFilter::Complex(json!({ CHUNK_METADATA_PAGE_UUID: { "$eq": page_uuid } })),
+            )
+            .await
+    }
+}
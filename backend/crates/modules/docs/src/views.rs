@@ -0,0 +1,359 @@
+//! Docs Page Views module
+//!
+//! Records which pages a user viewed and provides the user's recently viewed pages.
+
+use chrono::{DateTime, Utc};
+use flextide_core::database::DatabasePool;
+use sqlx::Row;
+
+use crate::page::{load_page_by_uuid, DocsPage, DocsPageDatabaseError};
+use crate::search::viewable_area_uuids;
+
+/// Maximum number of recently viewed pages returned at once
+pub const MAX_RECENT_PAGES_LIMIT: i64 = 50;
+
+/// Record that a user viewed a page
+///
+/// Each user keeps one entry per page; viewing a page again moves it to the top of the
+/// user's recently viewed pages.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization (for verification)
+/// * `user_uuid` - UUID of the user viewing the page
+/// * `page_uuid` - UUID of the viewed page
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - User does not belong to the organization
+/// - Page does not exist or does not belong to the organization
+/// - User cannot view the page's area
+/// - Database operation fails
+pub async fn record_page_view(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    user_uuid: &str,
+    page_uuid: &str,
+) -> Result<(), DocsPageDatabaseError> {
+    let area_uuids = viewable_area_uuids(pool, organization_uuid, user_uuid).await?;
+
+    let page = load_page_by_uuid(pool, page_uuid).await?;
+
+    if page.organization_uuid != organization_uuid {
+        return Err(DocsPageDatabaseError::PageNotInOrganization);
+    }
+
+    if !area_uuids.contains(&page.area_uuid) {
+        return Err(DocsPageDatabaseError::PermissionDenied);
+    }
+
+    let now = Utc::now();
+
+    match pool {
+        DatabasePool::MySql(p) => {
+            sqlx::query(
+                "INSERT INTO module_docs_page_views (user_uuid, page_uuid, organization_uuid, viewed_at)
+                 VALUES (?, ?, ?, ?)
+                 ON DUPLICATE KEY UPDATE viewed_at = VALUES(viewed_at)",
+            )
+            .bind(user_uuid)
+            .bind(page_uuid)
+            .bind(organization_uuid)
+            .bind(now)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Postgres(p) => {
+            sqlx::query(
+                "INSERT INTO module_docs_page_views (user_uuid, page_uuid, organization_uuid, viewed_at)
+                 VALUES ($1, $2, $3, $4)
+                 ON CONFLICT (user_uuid, page_uuid) DO UPDATE SET viewed_at = EXCLUDED.viewed_at",
+            )
+            .bind(user_uuid)
+            .bind(page_uuid)
+            .bind(organization_uuid)
+            .bind(now)
+            .execute(p)
+            .await?;
+        }
+        DatabasePool::Sqlite(p) => {
+            sqlx::query(
+                "INSERT INTO module_docs_page_views (user_uuid, page_uuid, organization_uuid, viewed_at)
+                 VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT (user_uuid, page_uuid) DO UPDATE SET viewed_at = excluded.viewed_at",
+            )
+            .bind(user_uuid)
+            .bind(page_uuid)
+            .bind(organization_uuid)
+            .bind(now)
+            .execute(p)
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// List the pages a user viewed most recently, newest first
+///
+/// Pages the user can no longer view (e.g. after losing access to their area) are left out.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `organization_uuid` - UUID of the organization
+/// * `user_uuid` - UUID of the user
+/// * `limit` - Maximum number of pages, clamped to 1..=`MAX_RECENT_PAGES_LIMIT`
+///
+/// # Returns
+/// Returns the recently viewed pages in areas the user can view
+///
+/// # Errors
+/// Returns `DocsPageDatabaseError` if:
+/// - User does not belong to the organization
+/// - Database operation fails
+pub async fn list_recent_pages(
+    pool: &DatabasePool,
+    organization_uuid: &str,
+    user_uuid: &str,
+    limit: i64,
+) -> Result<Vec<DocsPage>, DocsPageDatabaseError> {
+    let area_uuids = viewable_area_uuids(pool, organization_uuid, user_uuid).await?;
+    if area_uuids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let limit = limit.clamp(1, MAX_RECENT_PAGES_LIMIT);
+
+    let pages: Vec<DocsPage> = match pool {
+        DatabasePool::MySql(p) => {
+            let placeholders = area_uuids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+            let query = format!(
+                "SELECT p.uuid, p.organization_uuid, p.area_uuid, p.folder_uuid, p.title, p.short_summary,
+                 p.parent_page_uuid, p.current_version_uuid, p.page_type, p.last_updated, p.created_at,
+                 p.auto_sync_to_vector_db, p.vcs_export_allowed, p.includes_private_data, p.metadata
+                 FROM module_docs_page_views v
+                 INNER JOIN module_docs_pages p ON p.uuid = v.page_uuid
+                 WHERE v.user_uuid = ? AND v.organization_uuid = ? AND p.organization_uuid = ?
+                 AND p.area_uuid IN ({})
+                 ORDER BY v.viewed_at DESC
+                 LIMIT ?",
+                placeholders
+            );
+
+            let mut query_builder = sqlx::query(&query)
+                .bind(user_uuid)
+                .bind(organization_uuid)
+                .bind(organization_uuid);
+            for area_uuid in &area_uuids {
+                query_builder = query_builder.bind(area_uuid);
+            }
+            let rows = query_builder.bind(limit).fetch_all(p).await?;
+
+            rows.into_iter()
+                .map(|row| DocsPage {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    area_uuid: row.get("area_uuid"),
+                    folder_uuid: row.get("folder_uuid"),
+                    title: row.get("title"),
+                    short_summary: row.get("short_summary"),
+                    parent_page_uuid: row.get("parent_page_uuid"),
+                    current_version_uuid: row.get("current_version_uuid"),
+                    page_type: row.get("page_type"),
+                    last_updated: row.get::<DateTime<Utc>, _>("last_updated"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    auto_sync_to_vector_db: row.get("auto_sync_to_vector_db"),
+                    vcs_export_allowed: row.get("vcs_export_allowed"),
+                    includes_private_data: row.get("includes_private_data"),
+                    metadata: row.get("metadata"),
+                })
+                .collect()
+        }
+        DatabasePool::Postgres(p) => {
+            // PostgreSQL uses $1, $2, etc., the area UUIDs start at $3
+            let placeholders: Vec<String> = (3..area_uuids.len() + 3).map(|i| format!("${}", i)).collect();
+            let query = format!(
+                "SELECT p.uuid, p.organization_uuid, p.area_uuid, p.folder_uuid, p.title, p.short_summary,
+                 p.parent_page_uuid, p.current_version_uuid, p.page_type, p.last_updated, p.created_at,
+                 p.auto_sync_to_vector_db, p.vcs_export_allowed, p.includes_private_data, p.metadata
+                 FROM module_docs_page_views v
+                 INNER JOIN module_docs_pages p ON p.uuid = v.page_uuid
+                 WHERE v.user_uuid = $1 AND v.organization_uuid = $2 AND p.organization_uuid = $2
+                 AND p.area_uuid IN ({})
+                 ORDER BY v.viewed_at DESC
+                 LIMIT ${}",
+                placeholders.join(","),
+                area_uuids.len() + 3
+            );
+
+            let mut query_builder = sqlx::query(&query).bind(user_uuid).bind(organization_uuid);
+            for area_uuid in &area_uuids {
+                query_builder = query_builder.bind(area_uuid);
+            }
+            let rows = query_builder.bind(limit).fetch_all(p).await?;
+
+            rows.into_iter()
+                .map(|row| DocsPage {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    area_uuid: row.get("area_uuid"),
+                    folder_uuid: row.get("folder_uuid"),
+                    title: row.get("title"),
+                    short_summary: row.get("short_summary"),
+                    parent_page_uuid: row.get("parent_page_uuid"),
+                    current_version_uuid: row.get("current_version_uuid"),
+                    page_type: row.get("page_type"),
+                    last_updated: row.get::<DateTime<Utc>, _>("last_updated"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    auto_sync_to_vector_db: row.get("auto_sync_to_vector_db"),
+                    vcs_export_allowed: row.get("vcs_export_allowed"),
+                    includes_private_data: row.get("includes_private_data"),
+                    metadata: row.get("metadata"),
+                })
+                .collect()
+        }
+        DatabasePool::Sqlite(p) => {
+            // The area UUIDs start at ?3
+            let placeholders: Vec<String> = (3..area_uuids.len() + 3).map(|i| format!("?{}", i)).collect();
+            let query = format!(
+                "SELECT p.uuid, p.organization_uuid, p.area_uuid, p.folder_uuid, p.title, p.short_summary,
+                 p.parent_page_uuid, p.current_version_uuid, p.page_type, p.last_updated, p.created_at,
+                 p.auto_sync_to_vector_db, p.vcs_export_allowed, p.includes_private_data, p.metadata
+                 FROM module_docs_page_views v
+                 INNER JOIN module_docs_pages p ON p.uuid = v.page_uuid
+                 WHERE v.user_uuid = ?1 AND v.organization_uuid = ?2 AND p.organization_uuid = ?2
+                 AND p.area_uuid IN ({})
+                 ORDER BY v.viewed_at DESC
+                 LIMIT ?{}",
+                placeholders.join(","),
+                area_uuids.len() + 3
+            );
+
+            let mut query_builder = sqlx::query(&query).bind(user_uuid).bind(organization_uuid);
+            for area_uuid in &area_uuids {
+                query_builder = query_builder.bind(area_uuid);
+            }
+            let rows = query_builder.bind(limit).fetch_all(p).await?;
+
+            rows.into_iter()
+                .map(|row| DocsPage {
+                    uuid: row.get("uuid"),
+                    organization_uuid: row.get("organization_uuid"),
+                    area_uuid: row.get("area_uuid"),
+                    folder_uuid: row.get("folder_uuid"),
+                    title: row.get("title"),
+                    short_summary: row.get("short_summary"),
+                    parent_page_uuid: row.get("parent_page_uuid"),
+                    current_version_uuid: row.get("current_version_uuid"),
+                    page_type: row.get("page_type"),
+                    last_updated: row.get::<DateTime<Utc>, _>("last_updated"),
+                    created_at: row.get::<DateTime<Utc>, _>("created_at"),
+                    auto_sync_to_vector_db: row.get("auto_sync_to_vector_db"),
+                    vcs_export_allowed: row.get("vcs_export_allowed"),
+                    includes_private_data: row.get("includes_private_data"),
+                    metadata: row.get("metadata"),
+                })
+                .collect()
+        }
+    };
+
+    Ok(pages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{add_organization_member, create_area, create_organization, create_page, setup_tables};
+
+    #[sqlx::test]
+    async fn test_recent_pages_follow_views(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let first = create_page(&pool, &org_uuid, &area_uuid).await;
+        let second = create_page(&pool, &org_uuid, &area_uuid).await;
+        let third = create_page(&pool, &org_uuid, &area_uuid).await;
+
+        assert!(list_recent_pages(&pool, &org_uuid, &user_uuid, 10).await.unwrap().is_empty());
+
+        record_page_view(&pool, &org_uuid, &user_uuid, &first).await.unwrap();
+        record_page_view(&pool, &org_uuid, &user_uuid, &second).await.unwrap();
+        record_page_view(&pool, &org_uuid, &user_uuid, &third).await.unwrap();
+
+        let recent = list_recent_pages(&pool, &org_uuid, &user_uuid, 10).await.unwrap();
+        assert_eq!(
+            recent.iter().map(|p| p.uuid.as_str()).collect::<Vec<_>>(),
+            vec![third.as_str(), second.as_str(), first.as_str()]
+        );
+
+        // Viewing a page again moves it to the top without duplicating it
+        record_page_view(&pool, &org_uuid, &user_uuid, &first).await.unwrap();
+        let recent = list_recent_pages(&pool, &org_uuid, &user_uuid, 10).await.unwrap();
+        assert_eq!(
+            recent.iter().map(|p| p.uuid.as_str()).collect::<Vec<_>>(),
+            vec![first.as_str(), third.as_str(), second.as_str()]
+        );
+
+        let recent = list_recent_pages(&pool, &org_uuid, &user_uuid, 2).await.unwrap();
+        assert_eq!(
+            recent.iter().map(|p| p.uuid.as_str()).collect::<Vec<_>>(),
+            vec![first.as_str(), third.as_str()]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_page_views_respect_view_permissions(pool: sqlx::SqlitePool) -> sqlx::Result<()> {
+        let pool = DatabasePool::Sqlite(pool);
+        setup_tables(&pool).await;
+
+        let user_uuid = uuid::Uuid::new_v4().to_string();
+        let other_uuid = uuid::Uuid::new_v4().to_string();
+        let org_uuid = create_organization(&pool, &user_uuid).await;
+        add_organization_member(&pool, &org_uuid, &other_uuid).await;
+        let area_uuid = create_area(&pool, &org_uuid, &user_uuid).await;
+        let other_area_uuid = create_area(&pool, &org_uuid, &other_uuid).await;
+        let page = create_page(&pool, &org_uuid, &area_uuid).await;
+        let hidden_page = create_page(&pool, &org_uuid, &other_area_uuid).await;
+
+        assert!(matches!(
+            record_page_view(&pool, &org_uuid, &user_uuid, &hidden_page).await,
+            Err(DocsPageDatabaseError::PermissionDenied)
+        ));
+
+        let outsider_uuid = uuid::Uuid::new_v4().to_string();
+        assert!(matches!(
+            record_page_view(&pool, &org_uuid, &outsider_uuid, &page).await,
+            Err(DocsPageDatabaseError::UserNotInOrganization)
+        ));
+        assert!(matches!(
+            list_recent_pages(&pool, &org_uuid, &outsider_uuid, 10).await,
+            Err(DocsPageDatabaseError::UserNotInOrganization)
+        ));
+
+        // Views of pages the user can no longer view are left out
+        let visible_page = create_page(&pool, &org_uuid, &other_area_uuid).await;
+        record_page_view(&pool, &org_uuid, &other_uuid, &visible_page).await.unwrap();
+        record_page_view(&pool, &org_uuid, &other_uuid, &hidden_page).await.unwrap();
+        let DatabasePool::Sqlite(p) = &pool else {
+            unreachable!()
+        };
+        sqlx::query("UPDATE module_docs_pages SET area_uuid = ?1 WHERE uuid = ?2")
+            .bind(&area_uuid)
+            .bind(&hidden_page)
+            .execute(p)
+            .await?;
+        // The limit applies to the pages left after the area filter
+        let recent = list_recent_pages(&pool, &org_uuid, &other_uuid, 1).await.unwrap();
+        assert_eq!(
+            recent.iter().map(|p| p.uuid.as_str()).collect::<Vec<_>>(),
+            vec![visible_page.as_str()]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use flextide_sdk::{NodeDefinition, NodeGroup};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::registry::NodeRegistry;
+
+/// Full catalog of node groups and node definitions, e.g. for a frontend node palette
+#[derive(Debug, Clone, Default)]
+pub struct NodeCatalog {
+    /// Node groups in display order
+    groups: Vec<NodeGroup>,
+    /// Node definitions, each referencing one of the groups by name
+    nodes: Vec<NodeDefinition>,
+}
+
+/// Node group with its nodes, as serialized in the catalog JSON
+#[derive(Serialize)]
+struct CatalogGroup<'a> {
+    #[serde(flatten)]
+    group: &'a NodeGroup,
+    nodes: Vec<&'a NodeDefinition>,
+}
+
+impl NodeCatalog {
+    /// Create a catalog from node groups and node definitions
+    pub fn new(groups: Vec<NodeGroup>, nodes: Vec<NodeDefinition>) -> Self {
+        Self { groups, nodes }
+    }
+
+    /// Get all node groups
+    pub fn groups(&self) -> &[NodeGroup] {
+        &self.groups
+    }
+
+    /// Get all node definitions
+    pub fn nodes(&self) -> &[NodeDefinition] {
+        &self.nodes
+    }
+
+    /// Check that every node references a known group
+    pub fn validate(&self) -> Result<(), String> {
+        for node in &self.nodes {
+            if !self.groups.iter().any(|g| g.name == node.group) {
+                return Err(format!("Node '{}' references unknown group '{}'", node.name, node.group));
+            }
+        }
+        Ok(())
+    }
+
+    /// Serialize the catalog as JSON with the nodes nested under their groups
+    ///
+    /// The result has the form `{"groups": [{"name", "title", "description", "nodes": [...]}]}`,
+    /// groups and nodes keep their order in the catalog.
+    pub fn to_json(&self) -> Result<Value, String> {
+        self.validate()?;
+
+        let groups: Vec<CatalogGroup> = self
+            .groups
+            .iter()
+            .map(|group| CatalogGroup {
+                group,
+                nodes: self.nodes.iter().filter(|n| n.group == group.name).collect(),
+            })
+            .collect();
+
+        serde_json::to_value(&groups)
+            .map(|groups| serde_json::json!({ "groups": groups }))
+            .map_err(|e| format!("Failed to serialize node catalog: {}", e))
+    }
+}
+
+impl From<&NodeRegistry> for NodeCatalog {
+    fn from(registry: &NodeRegistry) -> Self {
+        let (groups, nodes) = registry
+            .list_groups_with_nodes()
+            .into_iter()
+            .fold((Vec::new(), Vec::new()), |(mut groups, mut nodes), (group, group_nodes)| {
+                groups.push(group);
+                nodes.extend(group_nodes);
+                (groups, nodes)
+            });
+        Self::new(groups, nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(name: &str) -> NodeGroup {
+        NodeGroup {
+            name: name.to_string(),
+            title: name.to_uppercase(),
+            description: format!("{} nodes", name),
+        }
+    }
+
+    fn node(name: &str, group: &str) -> NodeDefinition {
+        NodeDefinition {
+            name: name.to_string(),
+            title: name.to_uppercase(),
+            description: format!("{} node", name),
+            group: group.to_string(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            config: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_unknown_group_fails_validation() {
+        let catalog = NodeCatalog::new(vec![group("logic")], vec![node("or", "logic"), node("get", "http")]);
+
+        let error = catalog.validate().unwrap_err();
+        assert!(error.contains("'get'") && error.contains("'http'"), "{}", error);
+        assert!(catalog.to_json().is_err());
+    }
+
+    #[test]
+    fn test_catalog_serializes_grouped_nodes() {
+        let catalog = NodeCatalog::new(
+            vec![group("logic"), group("http"), group("database")],
+            vec![node("or", "logic"), node("get", "http"), node("and", "logic")],
+        );
+
+        let json = catalog.to_json().unwrap();
+        let groups = json["groups"].as_array().unwrap();
+        assert_eq!(groups.len(), 3);
+
+        assert_eq!(groups[0]["name"], "logic");
+        assert_eq!(groups[0]["title"], "LOGIC");
+        let logic_nodes: Vec<&str> = groups[0]["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|n| n["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(logic_nodes, vec!["or", "and"]);
+
+        assert_eq!(groups[1]["name"], "http");
+        assert_eq!(groups[1]["nodes"][0]["name"], "get");
+        assert_eq!(groups[2]["nodes"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_catalog_from_registry() {
+        let mut registry = NodeRegistry::new();
+        registry.register_group(group("logic"));
+        registry.register_node(node("or", "logic")).unwrap();
+
+        let catalog = NodeCatalog::from(&registry);
+        assert_eq!(catalog.groups().len(), 1);
+        assert_eq!(catalog.nodes().len(), 1);
+        assert_eq!(catalog.to_json().unwrap()["groups"][0]["nodes"][0]["name"], "or");
+    }
+}
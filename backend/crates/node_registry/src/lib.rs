@@ -1,4 +1,6 @@
+pub mod catalog;
 pub mod registry;
 
+pub use catalog::NodeCatalog;
 pub use flextide_sdk::{ConfigOption, InputPin, NodeDefinition, NodeGroup, OutputPin, PinType};
 pub use registry::NodeRegistry;
@@ -0,0 +1,326 @@
+//! Credential resolution for integration nodes
+//!
+//! Builds integration clients from the credentials an organization stored for a service.
+
+use flextide_core::credentials::{get_credentials_by_type, CredentialsError, CredentialsManager};
+use flextide_core::database::DatabasePool;
+use integrations::{GitHubClient, JiraClient, OpenAIClient};
+use serde_json::Value;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// External service an integration node can call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrationService {
+    OpenAI,
+    GitHub,
+    Jira,
+}
+
+impl IntegrationService {
+    /// Credential type the credentials of this service are stored with
+    pub fn credential_type(&self) -> &'static str {
+        match self {
+            IntegrationService::OpenAI => "openai_credential",
+            IntegrationService::GitHub => "github_credential",
+            IntegrationService::Jira => "jira_credential",
+        }
+    }
+}
+
+impl FromStr for IntegrationService {
+    type Err = ClientCredentialsError;
+
+    fn from_str(service: &str) -> Result<Self, Self::Err> {
+        match service.to_ascii_lowercase().as_str() {
+            "openai" => Ok(IntegrationService::OpenAI),
+            "github" => Ok(IntegrationService::GitHub),
+            "jira" => Ok(IntegrationService::Jira),
+            _ => Err(ClientCredentialsError::UnknownService(service.to_string())),
+        }
+    }
+}
+
+/// Integration client configured with the credentials of an organization
+pub enum IntegrationClient {
+    OpenAI(OpenAIClient),
+    GitHub(GitHubClient),
+    Jira(JiraClient),
+}
+
+/// Errors that can occur when resolving the credentials of an integration client
+#[derive(Debug, Error)]
+pub enum ClientCredentialsError {
+    #[error("Unknown integration service: {0}")]
+    UnknownService(String),
+
+    #[error("No {service} credential stored for organization {organization_uuid}")]
+    CredentialNotFound {
+        organization_uuid: String,
+        service: String,
+    },
+
+    #[error("Credential '{credential}' is missing the field '{field}'")]
+    MissingField { credential: String, field: String },
+
+    #[error("Credentials error: {0}")]
+    Credentials(#[from] CredentialsError),
+}
+
+/// Build the client of a service from the credential the organization stored for it
+///
+/// If the organization stored several credentials for the service, the newest one is used.
+/// Expected credential data per service:
+/// - `openai`: `{"api_key", "base_url"?}`
+/// - `github`: `{"token", "base_url"?}`
+/// - `jira`: `{"base_url", "email", "auth_token"}`
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+/// * `manager` - Credentials manager for decryption
+/// * `organization_uuid` - UUID of the organization
+/// * `service` - Service name (e.g. "openai", "github", "jira")
+///
+/// # Errors
+/// Returns `ClientCredentialsError` if:
+/// - The service is unknown
+/// - The organization has no credential for the service
+/// - The credential lacks a required field
+/// - Decryption or a database operation fails
+///
+/// # Note
+/// Like `get_credentials_by_type`, this does not perform permission checks, as it is
+/// called from nodes without user context.
+pub async fn resolve_client_credentials(
+    pool: &DatabasePool,
+    manager: &CredentialsManager,
+    organization_uuid: &str,
+    service: &str,
+) -> Result<IntegrationClient, ClientCredentialsError> {
+    let service_type = IntegrationService::from_str(service)?;
+
+    let credential = get_credentials_by_type(pool, manager, organization_uuid, service_type.credential_type())
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ClientCredentialsError::CredentialNotFound {
+            organization_uuid: organization_uuid.to_string(),
+            service: service.to_string(),
+        })?;
+
+    let field = |name: &str| -> Result<String, ClientCredentialsError> {
+        optional_field(&credential.data, name).ok_or_else(|| ClientCredentialsError::MissingField {
+            credential: credential.name.clone(),
+            field: name.to_string(),
+        })
+    };
+
+    let client = match service_type {
+        IntegrationService::OpenAI => {
+            let api_key = field("api_key")?;
+            IntegrationClient::OpenAI(match optional_field(&credential.data, "base_url") {
+                Some(base_url) => OpenAIClient::with_base_url(api_key, base_url),
+                None => OpenAIClient::new(api_key),
+            })
+        }
+        IntegrationService::GitHub => {
+            let token = field("token")?;
+            IntegrationClient::GitHub(match optional_field(&credential.data, "base_url") {
+                Some(base_url) => GitHubClient::with_base_url(Some(token), base_url),
+                None => GitHubClient::with_token(token),
+            })
+        }
+        IntegrationService::Jira => {
+            IntegrationClient::Jira(JiraClient::new(field("base_url")?, field("email")?, field("auth_token")?))
+        }
+    };
+
+    tracing::debug!(
+        "Resolved {} client for organization {} from credential {}",
+        service,
+        organization_uuid,
+        credential.uuid
+    );
+
+    Ok(client)
+}
+
+/// Non-empty string field of credential data
+fn optional_field(data: &Value, name: &str) -> Option<String> {
+    data.get(name)
+        .and_then(Value::as_str)
+        .filter(|value| !value.trim().is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flextide_core::database::create_test_pool;
+    use integrations::openai::{ChatCompletionRequest, ChatMessage, MessageRole};
+    use serde_json::json;
+
+    fn create_test_manager() -> CredentialsManager {
+        // SAFETY: test-only environment setup
+        unsafe { std::env::set_var("CREDENTIALS_MASTER_KEY", hex::encode([0u8; 32])) };
+        CredentialsManager::new().unwrap()
+    }
+
+    async fn setup_test_db() -> DatabasePool {
+        let pool = create_test_pool().await.expect("Failed to create test pool");
+        pool.execute(
+            "CREATE TABLE credentials (
+                uuid CHAR(36) NOT NULL PRIMARY KEY,
+                organization_uuid CHAR(36) NOT NULL,
+                name VARCHAR(255) NOT NULL,
+                credential_type VARCHAR(255) NOT NULL,
+                encrypted_data BLOB NOT NULL,
+                creator_user_uuid CHAR(36) NOT NULL,
+                created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMP NULL
+            )",
+        )
+        .await
+        .expect("Failed to create credentials table");
+        pool
+    }
+
+    async fn store_credential(
+        pool: &DatabasePool,
+        manager: &CredentialsManager,
+        organization_uuid: &str,
+        credential_type: &str,
+        data: &Value,
+    ) {
+        let DatabasePool::Sqlite(p) = pool else {
+            unreachable!()
+        };
+        sqlx::query(
+            "INSERT INTO credentials (uuid, organization_uuid, name, credential_type, encrypted_data, creator_user_uuid)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )
+        .bind(uuid::Uuid::new_v4().to_string())
+        .bind(organization_uuid)
+        .bind("Test credential")
+        .bind(credential_type)
+        .bind(manager.encrypt(data).unwrap())
+        .bind(uuid::Uuid::new_v4().to_string())
+        .execute(p)
+        .await
+        .expect("Failed to store credential");
+    }
+
+    /// Answer a single chat completion request, returns the received request head
+    async fn serve_chat_completion(listener: tokio::net::TcpListener) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let (mut stream, _) = listener.accept().await.expect("Failed to accept connection");
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 4096];
+        let head = loop {
+            let read = stream.read(&mut buffer).await.expect("Failed to read request");
+            request.extend_from_slice(&buffer[..read]);
+            let text = String::from_utf8_lossy(&request).to_string();
+            if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                let content_length = head
+                    .lines()
+                    .find_map(|line| {
+                        let (name, value) = line.split_once(':')?;
+                        name.eq_ignore_ascii_case("content-length").then(|| value.trim().parse::<usize>().ok())?
+                    })
+                    .unwrap_or(0);
+                if body.len() >= content_length || read == 0 {
+                    break head.to_string();
+                }
+            }
+        };
+
+        let response = json!({
+            "id": "mock",
+            "object": "chat.completion",
+            "created": 0,
+            "model": "mock-model",
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": "pong" },
+                "finish_reason": "stop"
+            }],
+            "usage": { "prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2 }
+        })
+        .to_string();
+        stream
+            .write_all(
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                )
+                .as_bytes(),
+            )
+            .await
+            .expect("Failed to write response");
+
+        head
+    }
+
+    #[tokio::test]
+    async fn test_stored_openai_key_yields_client() {
+        let pool = setup_test_db().await;
+        let manager = create_test_manager();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        store_credential(
+            &pool,
+            &manager,
+            "org-1",
+            "openai_credential",
+            &json!({"api_key": "sk-stored-key", "base_url": base_url}),
+        )
+        .await;
+
+        let client = resolve_client_credentials(&pool, &manager, "org-1", "openai")
+            .await
+            .expect("Failed to resolve client");
+        let IntegrationClient::OpenAI(client) = client else {
+            panic!("Expected an OpenAI client");
+        };
+
+        let request = ChatCompletionRequest {
+            model: "mock-model".to_string(),
+            messages: vec![ChatMessage { role: MessageRole::User, content: "ping".to_string() }],
+            temperature: None,
+            max_tokens: None,
+            stream: None,
+            response_format: None,
+        };
+        let (response, head) = tokio::join!(client.chat_completion(request), serve_chat_completion(listener));
+
+        assert_eq!(response.expect("Chat completion failed").choices[0].message.content, "pong");
+        assert!(
+            head.lines().any(|line| line.eq_ignore_ascii_case("authorization: Bearer sk-stored-key")),
+            "{}",
+            head
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_credential_errors() {
+        let pool = setup_test_db().await;
+        let manager = create_test_manager();
+        store_credential(&pool, &manager, "org-2", "openai_credential", &json!({"api_key": "sk-other-org"})).await;
+        store_credential(&pool, &manager, "org-1", "jira_credential", &json!({"base_url": "https://jira.example.com"})).await;
+
+        assert!(matches!(
+            resolve_client_credentials(&pool, &manager, "org-1", "openai").await,
+            Err(ClientCredentialsError::CredentialNotFound { .. })
+        ));
+        assert!(matches!(
+            resolve_client_credentials(&pool, &manager, "org-1", "jira").await,
+            Err(ClientCredentialsError::MissingField { ref field, .. }) if field == "email"
+        ));
+        assert!(matches!(
+            resolve_client_credentials(&pool, &manager, "org-1", "gitlab").await,
+            Err(ClientCredentialsError::UnknownService(_))
+        ));
+    }
+}
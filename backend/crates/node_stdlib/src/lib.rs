@@ -2,3 +2,6 @@
 //! 
 //! Standard library of nodes for the Flextide workflow automation platform.
 
+pub mod credentials;
+
+pub use credentials::{resolve_client_credentials, ClientCredentialsError, IntegrationClient, IntegrationService};
@@ -63,6 +63,41 @@ pub struct NodeError {
     pub details: Option<Value>,
 }
 
+/// Error code for a value that does not match the type of its pin or config option
+pub const ERROR_CODE_TYPE_ERROR: &str = "TYPE_ERROR";
+
+/// Error code for a required input or config option that is missing
+pub const ERROR_CODE_MISSING_INPUT: &str = "MISSING_INPUT";
+
+/// Error code for a request with an incompatible ABI version
+pub const ERROR_CODE_ABI_MISMATCH: &str = "ABI_MISMATCH";
+
+/// Error code for a node that was rejected by a rate limit
+pub const ERROR_CODE_RATE_LIMITED: &str = "RATE_LIMITED";
+
+impl NodeError {
+    /// HTTP status code an execution API should answer with for this error
+    ///
+    /// Unknown or missing codes map to 500 (Internal Server Error).
+    pub fn http_status(&self) -> u16 {
+        match self.code.as_deref() {
+            Some(ERROR_CODE_TYPE_ERROR) | Some(ERROR_CODE_MISSING_INPUT) => 422,
+            Some(ERROR_CODE_ABI_MISMATCH) => 400,
+            Some(ERROR_CODE_RATE_LIMITED) => 429,
+            _ => 500,
+        }
+    }
+}
+
+impl NodeExecutionResponse {
+    /// HTTP status code an execution API should answer with for this response
+    ///
+    /// Returns 200 if the node did not fail, otherwise the status of its error.
+    pub fn http_status(&self) -> u16 {
+        self.error.as_ref().map_or(200, NodeError::http_status)
+    }
+}
+
 /// Helper for building execution requests from pin values
 #[derive(Debug, Default)]
 pub struct ExecutionRequestBuilder {
@@ -267,5 +302,26 @@ mod tests {
         assert!(response.error.is_some());
         assert_eq!(response.error.as_ref().unwrap().message, "Something went wrong");
     }
+
+    #[test]
+    fn test_error_codes_map_to_http_status() {
+        for (code, status) in [
+            (ERROR_CODE_TYPE_ERROR, 422),
+            (ERROR_CODE_MISSING_INPUT, 422),
+            (ERROR_CODE_ABI_MISMATCH, 400),
+            (ERROR_CODE_RATE_LIMITED, 429),
+            ("SOMETHING_ELSE", 500),
+        ] {
+            let response = ExecutionResponseBuilder::new()
+                .with_error_code("Node failed", code)
+                .build();
+            assert_eq!(response.http_status(), status, "{}", code);
+        }
+
+        // Errors without a code fall back to 500, successful responses are 200
+        let response = ExecutionResponseBuilder::new().with_error("Node failed").build();
+        assert_eq!(response.http_status(), 500);
+        assert_eq!(ExecutionResponseBuilder::new().build().http_status(), 200);
+    }
 }
 
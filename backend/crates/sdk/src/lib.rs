@@ -4,7 +4,8 @@ pub mod plugin;
 
 pub use abi::{
     ExecutionContext, ExecutionRequestBuilder, ExecutionResponseBuilder, NodeError,
-    NodeExecutionRequest, NodeExecutionResponse, ABI_VERSION,
+    NodeExecutionRequest, NodeExecutionResponse, ABI_VERSION, ERROR_CODE_ABI_MISMATCH,
+    ERROR_CODE_MISSING_INPUT, ERROR_CODE_RATE_LIMITED, ERROR_CODE_TYPE_ERROR,
 };
 pub use node::{ConfigOption, InputPin, NodeDefinition, NodeGroup, OutputPin, PinType};
 pub use plugin::Plugin;
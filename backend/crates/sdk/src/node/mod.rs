@@ -1,4 +1,7 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::abi::NodeExecutionRequest;
 
 /// Pin type for node inputs, outputs, and configuration
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -13,6 +16,59 @@ pub enum PinType {
     Custom,
 }
 
+impl PinType {
+    /// Check whether a JSON value has the type of this pin
+    ///
+    /// Exec pins carry booleans; `Json`, `Any` and `Custom` accept every value.
+    pub fn matches(&self, value: &Value) -> bool {
+        match self {
+            PinType::Exec | PinType::Boolean => value.is_boolean(),
+            PinType::String => value.is_string(),
+            PinType::Number => value.is_number(),
+            PinType::Json | PinType::Any | PinType::Custom => true,
+        }
+    }
+
+    /// JSON Schema type of values of this pin, `None` if every value is accepted
+    pub fn json_schema_type(&self) -> Option<&'static str> {
+        match self {
+            PinType::Exec | PinType::Boolean => Some("boolean"),
+            PinType::String => Some("string"),
+            PinType::Number => Some("number"),
+            PinType::Json | PinType::Any | PinType::Custom => None,
+        }
+    }
+}
+
+/// Check a value against the min/max and allowed value constraints of a pin or config option
+///
+/// Returns a description of the violated constraint.
+fn check_constraints(
+    value: &Value,
+    min: Option<f64>,
+    max: Option<f64>,
+    allowed_values: Option<&[String]>,
+) -> Result<(), String> {
+    if let Some(number) = value.as_f64() {
+        if let Some(min) = min
+            && number < min
+        {
+            return Err(format!("must be at least {}", min));
+        }
+        if let Some(max) = max
+            && number > max
+        {
+            return Err(format!("must be at most {}", max));
+        }
+    }
+    if let (Some(text), Some(allowed_values)) = (value.as_str(), allowed_values)
+        && !allowed_values.iter().any(|allowed| allowed == text)
+    {
+        return Err(format!("must be one of {}", allowed_values.join(", ")));
+    }
+    Ok(())
+}
+
 /// Input pin definition for a node
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InputPin {
@@ -27,6 +83,15 @@ pub struct InputPin {
     /// Custom type value (only used when pin_type is Custom)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_type: Option<String>,
+    /// Minimum allowed value (only used when pin_type is Number)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    /// Maximum allowed value (only used when pin_type is Number)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    /// Allowed values (only used when pin_type is String)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_values: Option<Vec<String>>,
 }
 
 /// Output pin definition for a node
@@ -61,6 +126,18 @@ pub struct ConfigOption {
     pub custom_type: Option<String>,
     /// Whether this option is required
     pub required: bool,
+    /// Value used when the option is not set (must match option_type)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    /// Minimum allowed value (only used when option_type is Number)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    /// Maximum allowed value (only used when option_type is Number)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    /// Allowed values (only used when option_type is String)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_values: Option<Vec<String>>,
 }
 
 /// Node group definition (e.g., "Logic", "HTTP", "Database")
@@ -93,3 +170,320 @@ pub struct NodeDefinition {
     pub config: Vec<ConfigOption>,
 }
 
+
+impl NodeDefinition {
+    /// Check that the definition is consistent: every config default matches its option
+    /// type and constraints
+    pub fn validate(&self) -> Result<(), String> {
+        for option in &self.config {
+            if let Some(default) = &option.default {
+                if !option.option_type.matches(default) {
+                    return Err(format!(
+                        "Default of config option '{}' in node '{}' does not match type {:?}",
+                        option.name, self.name, option.option_type
+                    ));
+                }
+                check_constraints(default, option.min, option.max, option.allowed_values.as_deref())
+                    .map_err(|e| {
+                        format!("Default of config option '{}' in node '{}' {}", option.name, self.name, e)
+                    })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// JSON Schema (draft 2020-12) describing the config object of this node
+    ///
+    /// Every config option becomes a property carrying its type, title, description,
+    /// default and min/max/allowed value constraints. Options without a JSON type
+    /// (`Json`, `Any`, `Custom`) accept any value. Used by the frontend to generate
+    /// config forms.
+    pub fn config_json_schema(&self) -> Value {
+        let mut properties = serde_json::Map::new();
+        let mut required = Vec::new();
+
+        for option in &self.config {
+            let mut property = serde_json::Map::new();
+            if let Some(schema_type) = option.option_type.json_schema_type() {
+                property.insert("type".to_string(), json!(schema_type));
+            }
+            property.insert("title".to_string(), json!(option.title));
+            property.insert("description".to_string(), json!(option.description));
+            if let Some(default) = &option.default {
+                property.insert("default".to_string(), default.clone());
+            }
+            if let Some(min) = option.min {
+                property.insert("minimum".to_string(), json!(min));
+            }
+            if let Some(max) = option.max {
+                property.insert("maximum".to_string(), json!(max));
+            }
+            if let Some(allowed_values) = &option.allowed_values {
+                property.insert("enum".to_string(), json!(allowed_values));
+            }
+            properties.insert(option.name.clone(), Value::Object(property));
+
+            if option.required {
+                required.push(option.name.clone());
+            }
+        }
+
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": self.title,
+            "type": "object",
+            "properties": properties,
+            "required": required,
+        })
+    }
+
+    /// Validate an execution request against this definition before execution
+    ///
+    /// Absent config options are filled in with their default. Fails if a required
+    /// option is missing, or a config or input value does not match its type or
+    /// violates its min/max or allowed value constraints.
+    pub fn validate_request(&self, request: &mut NodeExecutionRequest) -> Result<(), String> {
+        self.validate()?;
+
+        if let Value::Object(input) = &request.input {
+            for pin in &self.inputs {
+                let Some(value) = input.get(&pin.name).filter(|value| !value.is_null()) else {
+                    continue;
+                };
+                if !pin.pin_type.matches(value) {
+                    return Err(format!("Input '{}' must be of type {:?}", pin.name, pin.pin_type));
+                }
+                check_constraints(value, pin.min, pin.max, pin.allowed_values.as_deref())
+                    .map_err(|e| format!("Input '{}' {}", pin.name, e))?;
+            }
+        }
+
+        if request.config.is_null() {
+            request.config = Value::Object(serde_json::Map::new());
+        }
+        let Value::Object(config) = &mut request.config else {
+            return Err("Request config must be a JSON object".to_string());
+        };
+
+        for option in &self.config {
+            match config.get(&option.name).filter(|value| !value.is_null()) {
+                Some(value) => {
+                    if !option.option_type.matches(value) {
+                        return Err(format!(
+                            "Config option '{}' must be of type {:?}",
+                            option.name, option.option_type
+                        ));
+                    }
+                    check_constraints(value, option.min, option.max, option.allowed_values.as_deref())
+                        .map_err(|e| format!("Config option '{}' {}", option.name, e))?;
+                }
+                None => match &option.default {
+                    Some(default) => {
+                        config.insert(option.name.clone(), default.clone());
+                    }
+                    None if option.required => {
+                        return Err(format!("Missing required config option '{}'", option.name));
+                    }
+                    None => {}
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::ExecutionRequestBuilder;
+
+    fn option(name: &str, option_type: PinType, required: bool, default: Option<Value>) -> ConfigOption {
+        ConfigOption {
+            name: name.to_string(),
+            title: name.to_string(),
+            description: String::new(),
+            option_type,
+            custom_type: None,
+            required,
+            default,
+            min: None,
+            max: None,
+            allowed_values: None,
+        }
+    }
+
+    fn node(config: Vec<ConfigOption>) -> NodeDefinition {
+        NodeDefinition {
+            name: "compare".to_string(),
+            title: "Compare".to_string(),
+            description: String::new(),
+            group: "logic".to_string(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            config,
+        }
+    }
+
+    #[test]
+    fn test_validate_request_fills_in_defaults() {
+        let definition = node(vec![
+            option("case_sensitive", PinType::Boolean, false, Some(json!(true))),
+            option("mode", PinType::String, true, None),
+            option("limit", PinType::Number, false, None),
+        ]);
+
+        let mut request = ExecutionRequestBuilder::new()
+            .with_config("mode", json!("exact"))
+            .build();
+        definition.validate_request(&mut request).unwrap();
+
+        assert_eq!(request.config, json!({ "mode": "exact", "case_sensitive": true }));
+
+        // Values that are set are kept
+        let mut request = ExecutionRequestBuilder::new()
+            .with_config("mode", json!("exact"))
+            .with_config("case_sensitive", json!(false))
+            .build();
+        definition.validate_request(&mut request).unwrap();
+        assert_eq!(request.config["case_sensitive"], json!(false));
+    }
+
+    #[test]
+    fn test_validate_request_rejects_invalid_config() {
+        let definition = node(vec![option("mode", PinType::String, true, None)]);
+
+        let mut request = ExecutionRequestBuilder::new().build();
+        assert!(definition.validate_request(&mut request).is_err());
+
+        let mut request = ExecutionRequestBuilder::new().with_config("mode", json!(1)).build();
+        assert!(definition.validate_request(&mut request).is_err());
+    }
+
+    #[test]
+    fn test_type_mismatched_default_is_rejected() {
+        let definition = node(vec![option("limit", PinType::Number, false, Some(json!("ten")))]);
+
+        assert!(definition.validate().is_err());
+        let mut request = ExecutionRequestBuilder::new().build();
+        assert!(definition.validate_request(&mut request).is_err());
+    }
+
+    #[test]
+    fn test_config_option_default_is_optional_in_json() {
+        let option: ConfigOption = serde_json::from_value(json!({
+            "name": "mode",
+            "title": "Mode",
+            "description": "",
+            "option_type": "string",
+            "required": false
+        }))
+        .unwrap();
+
+        assert_eq!(option.default, None);
+        assert!(serde_json::to_value(&option).unwrap().get("default").is_none());
+    }
+
+    #[test]
+    fn test_validate_request_enforces_number_range() {
+        let mut limit = option("limit", PinType::Number, true, None);
+        limit.min = Some(1.0);
+        limit.max = Some(100.0);
+        let mut definition = node(vec![limit]);
+        definition.inputs.push(InputPin {
+            name: "ratio".to_string(),
+            title: "Ratio".to_string(),
+            description: String::new(),
+            pin_type: PinType::Number,
+            custom_type: None,
+            min: Some(0.0),
+            max: Some(1.0),
+            allowed_values: None,
+        });
+
+        for (limit, ratio, valid) in [
+            (json!(1), json!(0.5), true),
+            (json!(100), json!(1), true),
+            (json!(0), json!(0.5), false),
+            (json!(100.5), json!(0.5), false),
+            (json!(50), json!(-0.1), false),
+            (json!(50), json!(1.5), false),
+        ] {
+            let mut request = ExecutionRequestBuilder::new()
+                .with_input("ratio", ratio.clone())
+                .with_config("limit", limit.clone())
+                .build();
+            assert_eq!(
+                definition.validate_request(&mut request).is_ok(),
+                valid,
+                "limit {} ratio {}",
+                limit,
+                ratio
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_request_enforces_allowed_values() {
+        let mut mode = option("mode", PinType::String, true, None);
+        mode.allowed_values = Some(vec!["exact".to_string(), "contains".to_string()]);
+        let definition = node(vec![mode]);
+
+        let mut request = ExecutionRequestBuilder::new().with_config("mode", json!("contains")).build();
+        assert!(definition.validate_request(&mut request).is_ok());
+
+        let mut request = ExecutionRequestBuilder::new().with_config("mode", json!("regex")).build();
+        let error = definition.validate_request(&mut request).unwrap_err();
+        assert!(error.contains("exact, contains"), "{}", error);
+    }
+
+    #[test]
+    fn test_default_violating_constraints_is_rejected() {
+        let mut mode = option("mode", PinType::String, false, Some(json!("regex")));
+        mode.allowed_values = Some(vec!["exact".to_string()]);
+
+        assert!(node(vec![mode]).validate().is_err());
+    }
+
+    #[test]
+    fn test_config_json_schema() {
+        let mut limit = option("limit", PinType::Number, true, None);
+        limit.min = Some(1.0);
+        limit.max = Some(100.0);
+        let mut mode = option("mode", PinType::String, false, Some(json!("exact")));
+        mode.allowed_values = Some(vec!["exact".to_string(), "contains".to_string()]);
+        let payload = option("payload", PinType::Json, false, None);
+
+        let schema = node(vec![limit, mode, payload]).config_json_schema();
+
+        assert_eq!(
+            schema,
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "title": "Compare",
+                "type": "object",
+                "properties": {
+                    "limit": {
+                        "type": "number",
+                        "title": "limit",
+                        "description": "",
+                        "minimum": 1.0,
+                        "maximum": 100.0
+                    },
+                    "mode": {
+                        "type": "string",
+                        "title": "mode",
+                        "description": "",
+                        "default": "exact",
+                        "enum": ["exact", "contains"]
+                    },
+                    "payload": {
+                        "title": "payload",
+                        "description": ""
+                    }
+                },
+                "required": ["limit"]
+            })
+        );
+    }
+}
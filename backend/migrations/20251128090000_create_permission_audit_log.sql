@@ -0,0 +1,33 @@
+-- Create permission_audit_log table
+-- Supports both MySQL and PostgreSQL
+--
+-- Records every permission grant and revocation for a user in an organization:
+-- who changed it (actor), whose access changed (target user), which permission
+-- and whether it was granted or revoked.
+-- Entries intentionally do not reference users or permissions, so the audit
+-- trail survives deleting either of them.
+
+-- ============================================================================
+-- PERMISSION_AUDIT_LOG TABLE
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS permission_audit_log (
+    uuid CHAR(36) NOT NULL PRIMARY KEY,
+    organization_uuid CHAR(36) NOT NULL,
+    actor_user_id CHAR(36) NOT NULL,
+    target_user_id CHAR(36) NOT NULL,
+    permission_name VARCHAR(255) NOT NULL,
+    action VARCHAR(20) NOT NULL CHECK (action IN ('grant', 'revoke')),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (organization_uuid) REFERENCES organizations(uuid) ON DELETE CASCADE
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+-- Composite index for listing the audit trail of an organization, newest first
+CREATE INDEX IF NOT EXISTS idx_permission_audit_log_org_created ON permission_audit_log(organization_uuid, created_at);
+
+-- Index on target_user_id for looking up the history of a single user
+CREATE INDEX IF NOT EXISTS idx_permission_audit_log_target_user ON permission_audit_log(target_user_id);
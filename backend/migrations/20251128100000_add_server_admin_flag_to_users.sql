@@ -0,0 +1,14 @@
+-- Add is_server_admin flag to users table
+-- Supports both MySQL and PostgreSQL
+--
+-- Server administrators were previously determined by a hardcoded email address.
+-- The flag replaces that check; the existing default admin user keeps its rights.
+
+-- Add server admin column
+ALTER TABLE users
+ADD COLUMN is_server_admin INTEGER NOT NULL DEFAULT 0;
+
+-- Migrate the default admin user to the new flag
+UPDATE users
+SET is_server_admin = 1
+WHERE email = 'admin@example.com';
@@ -0,0 +1,12 @@
+-- Add archived_at column to module_docs_areas table
+-- Supports both MySQL and PostgreSQL
+--
+-- Archiving is separate from deletion: archived areas keep their content and
+-- visible/deletable flags, but are hidden from area listings and trees by default.
+-- NULL means the area is not archived.
+
+ALTER TABLE module_docs_areas
+ADD COLUMN archived_at TIMESTAMP NULL;
+
+-- Index on archived_at for filtering out archived areas
+CREATE INDEX IF NOT EXISTS idx_module_docs_areas_archived_at ON module_docs_areas(archived_at);
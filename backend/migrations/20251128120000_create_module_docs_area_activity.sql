@@ -0,0 +1,35 @@
+-- Create module_docs_area_activity table
+-- Supports both MySQL and PostgreSQL
+--
+-- Records page changes (creates, edits, deletes) within a documentation area
+-- for the area's activity feed.
+-- Entries intentionally do not reference pages, so deleting a page keeps its
+-- history; they are removed together with their area.
+-- created_at uses microsecond precision so changes within the same second keep
+-- their order.
+
+-- ============================================================================
+-- MODULE_DOCS_AREA_ACTIVITY TABLE
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS module_docs_area_activity (
+    uuid CHAR(36) NOT NULL PRIMARY KEY,
+    organization_uuid CHAR(36) NOT NULL,
+    area_uuid CHAR(36) NOT NULL,
+    user_uuid CHAR(36) NOT NULL,
+    action VARCHAR(50) NOT NULL CHECK (action IN ('page_created', 'page_updated', 'page_deleted')),
+    page_uuid CHAR(36) NOT NULL,
+    page_title VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    FOREIGN KEY (area_uuid) REFERENCES module_docs_areas(uuid) ON DELETE CASCADE
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+-- Composite index for listing the activity of an area, newest first
+CREATE INDEX IF NOT EXISTS idx_module_docs_area_activity_area_created ON module_docs_area_activity(area_uuid, created_at);
+
+-- Index on page_uuid for looking up the history of a single page
+CREATE INDEX IF NOT EXISTS idx_module_docs_area_activity_page ON module_docs_area_activity(page_uuid);
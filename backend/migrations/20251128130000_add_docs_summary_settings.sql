@@ -0,0 +1,54 @@
+-- Add Docs module summary length and style settings
+-- Supports both MySQL and PostgreSQL
+--
+-- This migration adds:
+-- 1. Setting "module_docs_summary_max_words" - textfield for the maximum number of words of a page summary
+-- 2. Setting "module_docs_summary_style" - dropdown for the style/tone of page summaries
+
+-- ============================================================================
+-- INSERT SETTINGS
+-- ============================================================================
+
+-- Summary max words setting (textfield, must be a positive integer)
+INSERT INTO organizational_settings (
+    name,
+    organizational_settings_group_name,
+    title,
+    description,
+    type,
+    metadata,
+    created_at,
+    updated_at
+)
+SELECT 
+    'module_docs_summary_max_words',
+    'module_docs',
+    'Summary Maximum Words',
+    'Maximum number of words of generated page summaries (positive integer, leave empty for no limit)',
+    'textfield',
+    '{"placeholder": "50", "required": false}',
+    CURRENT_TIMESTAMP,
+    CURRENT_TIMESTAMP
+WHERE NOT EXISTS (SELECT 1 FROM organizational_settings WHERE name = 'module_docs_summary_max_words');
+
+-- Summary style setting (dropdown)
+INSERT INTO organizational_settings (
+    name,
+    organizational_settings_group_name,
+    title,
+    description,
+    type,
+    metadata,
+    created_at,
+    updated_at
+)
+SELECT 
+    'module_docs_summary_style',
+    'module_docs',
+    'Summary Style',
+    'Style or tone of generated page summaries',
+    'dropdown',
+    '{"options": [{"value": "neutral", "label": "Neutral"}, {"value": "formal", "label": "Formal"}, {"value": "friendly", "label": "Friendly"}, {"value": "technical", "label": "Technical"}]}',
+    CURRENT_TIMESTAMP,
+    CURRENT_TIMESTAMP
+WHERE NOT EXISTS (SELECT 1 FROM organizational_settings WHERE name = 'module_docs_summary_style');
@@ -0,0 +1,10 @@
+-- Add summary_content_hash column to module_docs_pages table
+-- Supports both MySQL and PostgreSQL
+--
+-- Stores the SHA-256 hash (hex encoded) of the version content the saved
+-- short_summary was created for. Summary generation reuses the saved summary
+-- while the current content still has this hash.
+-- NULL means the summary is not tied to any content.
+
+ALTER TABLE module_docs_pages
+ADD COLUMN summary_content_hash CHAR(64) NULL;
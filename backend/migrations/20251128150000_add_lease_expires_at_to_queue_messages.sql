@@ -0,0 +1,14 @@
+-- Add lease_expires_at column to queue_messages table
+-- Supports both MySQL and PostgreSQL
+--
+-- A worker that dequeues a message holds a lease on it until lease_expires_at.
+-- Messages still 'processing' after their lease expired (e.g. because the
+-- worker crashed) are returned to 'pending' so another worker can pick them up.
+-- NULL means the message is not leased.
+
+ALTER TABLE queue_messages
+ADD COLUMN lease_expires_at TIMESTAMP NULL;
+
+-- Index for finding expired leases
+CREATE INDEX IF NOT EXISTS idx_queue_messages_status_lease
+    ON queue_messages(status, lease_expires_at);
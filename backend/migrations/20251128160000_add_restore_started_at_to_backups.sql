@@ -0,0 +1,9 @@
+-- Add restore_started_at column to backups table
+-- Supports both MySQL and PostgreSQL
+--
+-- Set while a backup is being restored from. Retention pruning never deletes
+-- a backup with this column set.
+-- NULL means the backup is not being restored.
+
+ALTER TABLE backups
+ADD COLUMN restore_started_at TIMESTAMP NULL;
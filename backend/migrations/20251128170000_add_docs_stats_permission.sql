@@ -0,0 +1,10 @@
+-- Add permission to view organization-wide documentation statistics
+-- Supports both MySQL and PostgreSQL
+
+INSERT INTO permissions (name, title, description, visible, sort_order, permission_group_name)
+SELECT new_permissions.name, new_permissions.title, new_permissions.description, new_permissions.visible, new_permissions.sort_order, new_permissions.permission_group_name
+FROM (
+    VALUES
+        ('module_docs_can_view_stats', 'Can view statistics', 'The user is able to view documentation statistics of the organization', 1, 8, 'module_docs')
+) AS new_permissions(name, title, description, visible, sort_order, permission_group_name)
+WHERE NOT EXISTS (SELECT 1 FROM permissions WHERE permissions.name = new_permissions.name);
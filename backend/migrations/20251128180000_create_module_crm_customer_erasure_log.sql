@@ -0,0 +1,33 @@
+-- Create module_crm_customer_erasure_log table
+-- Supports both MySQL and PostgreSQL
+--
+-- Records every GDPR erasure of a CRM customer: which customer was erased in
+-- which organization, how many child records were deleted and when.
+-- Entries intentionally contain no personal data (no names, e-mail addresses
+-- or note texts) and do not reference the customer, so the proof of erasure
+-- survives the erasure itself.
+
+-- ============================================================================
+-- MODULE_CRM_CUSTOMER_ERASURE_LOG TABLE
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS module_crm_customer_erasure_log (
+    uuid CHAR(36) NOT NULL PRIMARY KEY,
+    organization_uuid CHAR(36) NOT NULL,
+    customer_uuid CHAR(36) NOT NULL,
+    notes_deleted INTEGER NOT NULL DEFAULT 0,
+    addresses_deleted INTEGER NOT NULL DEFAULT 0,
+    conversations_deleted INTEGER NOT NULL DEFAULT 0,
+    erased_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (organization_uuid) REFERENCES organizations(uuid) ON DELETE CASCADE
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+-- Composite index for listing the erasures of an organization, newest first
+CREATE INDEX IF NOT EXISTS idx_module_crm_customer_erasure_log_org_erased ON module_crm_customer_erasure_log(organization_uuid, erased_at);
+
+-- Index on customer_uuid for answering whether a customer was erased
+CREATE INDEX IF NOT EXISTS idx_module_crm_customer_erasure_log_customer ON module_crm_customer_erasure_log(customer_uuid);
@@ -0,0 +1,10 @@
+-- Add permission to list the workflows of an organization
+-- Supports both MySQL and PostgreSQL
+
+INSERT INTO permissions (name, title, description, visible, sort_order, permission_group_name)
+SELECT new_permissions.name, new_permissions.title, new_permissions.description, new_permissions.visible, new_permissions.sort_order, new_permissions.permission_group_name
+FROM (
+    VALUES
+        ('can_see_workflows', 'Can see workflows', 'The user is able to see the workflows of the organization and their last run status', 1, 5, 'workflows')
+) AS new_permissions(name, title, description, visible, sort_order, permission_group_name)
+WHERE NOT EXISTS (SELECT 1 FROM permissions WHERE permissions.name = new_permissions.name);
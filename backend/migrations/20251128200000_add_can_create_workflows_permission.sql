@@ -0,0 +1,10 @@
+-- Add permission to create workflows
+-- Supports both MySQL and PostgreSQL
+
+INSERT INTO permissions (name, title, description, visible, sort_order, permission_group_name)
+SELECT new_permissions.name, new_permissions.title, new_permissions.description, new_permissions.visible, new_permissions.sort_order, new_permissions.permission_group_name
+FROM (
+    VALUES
+        ('can_create_workflows', 'Can create workflows', 'The user is able to create new workflows in the organization', 1, 6, 'workflows')
+) AS new_permissions(name, title, description, visible, sort_order, permission_group_name)
+WHERE NOT EXISTS (SELECT 1 FROM permissions WHERE permissions.name = new_permissions.name);
@@ -0,0 +1,21 @@
+-- Add archived_at column to workflows table
+-- Supports both MySQL and PostgreSQL
+--
+-- Deleting a workflow via the API archives it: archived workflows are hidden from
+-- the workflow listing, but their runs are kept as execution history.
+-- NULL means the workflow is not archived.
+
+ALTER TABLE workflows
+ADD COLUMN archived_at TIMESTAMP NULL;
+
+-- Index on archived_at for filtering out archived workflows
+CREATE INDEX IF NOT EXISTS idx_workflows_archived_at ON workflows(archived_at);
+
+-- Permission to delete (archive) workflows
+INSERT INTO permissions (name, title, description, visible, sort_order, permission_group_name)
+SELECT new_permissions.name, new_permissions.title, new_permissions.description, new_permissions.visible, new_permissions.sort_order, new_permissions.permission_group_name
+FROM (
+    VALUES
+        ('can_delete_workflows', 'Can delete workflows', 'The user is able to delete workflows of the organization, their execution history is kept', 1, 7, 'workflows')
+) AS new_permissions(name, title, description, visible, sort_order, permission_group_name)
+WHERE NOT EXISTS (SELECT 1 FROM permissions WHERE permissions.name = new_permissions.name);
@@ -0,0 +1,32 @@
+-- Add Docs module summary rate limit setting
+-- Supports both MySQL and PostgreSQL
+--
+-- This migration adds:
+-- 1. Setting "module_docs_summary_calls_per_minute" - textfield for the maximum number of
+--    AI summary generations per minute and organization
+
+-- ============================================================================
+-- INSERT SETTINGS
+-- ============================================================================
+
+-- Summary calls per minute setting (textfield, must be a positive integer)
+INSERT INTO organizational_settings (
+    name,
+    organizational_settings_group_name,
+    title,
+    description,
+    type,
+    metadata,
+    created_at,
+    updated_at
+)
+SELECT 
+    'module_docs_summary_calls_per_minute',
+    'module_docs',
+    'Summary Generations per Minute',
+    'Maximum number of AI summary generations per minute (positive integer, leave empty for the default of 10)',
+    'textfield',
+    '{"placeholder": "10", "required": false}',
+    CURRENT_TIMESTAMP,
+    CURRENT_TIMESTAMP
+WHERE NOT EXISTS (SELECT 1 FROM organizational_settings WHERE name = 'module_docs_summary_calls_per_minute');
@@ -0,0 +1,18 @@
+-- Allow a fallback chain of AI providers for Docs page summaries
+-- Supports both MySQL and PostgreSQL
+--
+-- This migration changes:
+-- 1. Setting "module_docs_page_summary_ai_provider" from a dropdown to a textfield, so that
+--    a comma-separated list of providers can be entered (e.g. "openai,claude")
+
+-- ============================================================================
+-- UPDATE SETTINGS
+-- ============================================================================
+
+UPDATE organizational_settings
+SET
+    type = 'textfield',
+    description = 'AI provider for generating page summaries (openai, claude or gemini). Enter a comma-separated list (e.g. "openai,claude") to try the next provider if one fails',
+    metadata = '{"placeholder": "openai", "required": true}',
+    updated_at = CURRENT_TIMESTAMP
+WHERE name = 'module_docs_page_summary_ai_provider';
@@ -0,0 +1,31 @@
+-- Add Docs module page template setting
+-- Supports both MySQL and PostgreSQL
+--
+-- This migration adds:
+-- 1. Setting "module_docs_page_template" - textarea for the initial content of new pages
+
+-- ============================================================================
+-- INSERT SETTINGS
+-- ============================================================================
+
+-- Page template setting (textarea, {{title}} is replaced by the page title, must not be blank)
+INSERT INTO organizational_settings (
+    name,
+    organizational_settings_group_name,
+    title,
+    description,
+    type,
+    metadata,
+    created_at,
+    updated_at
+)
+SELECT 
+    'module_docs_page_template',
+    'module_docs',
+    'New Page Template',
+    'Initial Markdown content of new pages, {{title}} is replaced by the page title. Without a template, pages start with a heading of their title',
+    'textarea',
+    '{"placeholder": "# {{title}}", "required": false}',
+    CURRENT_TIMESTAMP,
+    CURRENT_TIMESTAMP
+WHERE NOT EXISTS (SELECT 1 FROM organizational_settings WHERE name = 'module_docs_page_template');
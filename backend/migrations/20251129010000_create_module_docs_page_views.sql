@@ -0,0 +1,32 @@
+-- Create module_docs_page_views table
+-- Supports both MySQL and PostgreSQL
+--
+-- Records when a user last viewed a documentation page, for the user's list of
+-- recently viewed pages.
+-- Each user has one row per page; viewing the page again updates viewed_at.
+-- Rows are removed together with their page.
+-- viewed_at uses microsecond precision so views within the same second keep
+-- their order.
+
+-- ============================================================================
+-- MODULE_DOCS_PAGE_VIEWS TABLE
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS module_docs_page_views (
+    user_uuid CHAR(36) NOT NULL,
+    page_uuid CHAR(36) NOT NULL,
+    organization_uuid CHAR(36) NOT NULL,
+    viewed_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    PRIMARY KEY (user_uuid, page_uuid),
+    FOREIGN KEY (page_uuid) REFERENCES module_docs_pages(uuid) ON DELETE CASCADE
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+-- Composite index for listing the recently viewed pages of a user, newest first
+CREATE INDEX IF NOT EXISTS idx_module_docs_page_views_user_viewed ON module_docs_page_views(user_uuid, organization_uuid, viewed_at);
+
+-- Index on page_uuid for removing the views of a deleted page
+CREATE INDEX IF NOT EXISTS idx_module_docs_page_views_page ON module_docs_page_views(page_uuid);
@@ -0,0 +1,38 @@
+-- Create auth_events table
+-- Supports both MySQL and PostgreSQL
+--
+-- Audit log of authentication events: every login attempt (successful or not)
+-- and every logout, with the user, the email address used, the client IP
+-- address and the outcome.
+-- Entries intentionally do not reference users, so failed logins for unknown
+-- email addresses can be recorded and the audit trail survives deleting a user.
+-- created_at uses microsecond precision so events within the same second keep
+-- their order.
+
+-- ============================================================================
+-- AUTH_EVENTS TABLE
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS auth_events (
+    uuid CHAR(36) NOT NULL PRIMARY KEY,
+    event_type VARCHAR(20) NOT NULL CHECK (event_type IN ('login', 'logout')),
+    outcome VARCHAR(20) NOT NULL CHECK (outcome IN ('success', 'failure')),
+    user_uuid CHAR(36),
+    email VARCHAR(255),
+    ip_address VARCHAR(45),
+    failure_reason VARCHAR(50),
+    created_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6)
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+-- Index on created_at for listing the newest events
+CREATE INDEX IF NOT EXISTS idx_auth_events_created ON auth_events(created_at);
+
+-- Composite index for looking up the events of a single user, newest first
+CREATE INDEX IF NOT EXISTS idx_auth_events_user_created ON auth_events(user_uuid, created_at);
+
+-- Composite index for looking up the login attempts for an email address, newest first
+CREATE INDEX IF NOT EXISTS idx_auth_events_email_created ON auth_events(email, created_at);
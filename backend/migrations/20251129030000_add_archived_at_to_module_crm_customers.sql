@@ -0,0 +1,14 @@
+-- Add archived_at column to module_crm_customers table
+-- Supports both MySQL and PostgreSQL
+--
+-- Deleting a customer via the API archives it: archived customers are hidden from
+-- the customer listing and search, but keep their notes, addresses and conversations
+-- so they can be restored. Permanently deleting a customer is a separate call.
+-- NULL means the customer is not archived.
+
+ALTER TABLE module_crm_customers
+ADD COLUMN archived_at TIMESTAMP NULL;
+
+-- Composite index for listing the active customers of an organization
+CREATE INDEX IF NOT EXISTS idx_module_crm_customers_org_archived
+    ON module_crm_customers(organization_uuid, archived_at);
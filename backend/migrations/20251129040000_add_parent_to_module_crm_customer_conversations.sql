@@ -0,0 +1,17 @@
+-- Add parent_conversation_uuid column to module_crm_customer_conversations table
+-- Supports both MySQL and PostgreSQL
+--
+-- Replies point to the conversation message they answer, so conversations form
+-- threads. NULL means the message starts a thread. Deleting a message turns its
+-- replies into thread roots instead of deleting them.
+
+ALTER TABLE module_crm_customer_conversations
+ADD COLUMN parent_conversation_uuid CHAR(36) NULL;
+
+ALTER TABLE module_crm_customer_conversations
+ADD CONSTRAINT fk_module_crm_customer_conversations_parent
+    FOREIGN KEY (parent_conversation_uuid) REFERENCES module_crm_customer_conversations(conversation_uuid) ON DELETE SET NULL;
+
+-- Index for loading the replies of a conversation
+CREATE INDEX IF NOT EXISTS idx_module_crm_customer_conversations_parent
+    ON module_crm_customer_conversations(parent_conversation_uuid);
@@ -0,0 +1,9 @@
+-- Add payload_template column to event_webhooks table
+-- Supports both MySQL and PostgreSQL
+--
+-- Optional JSON template for the request body of a webhook delivery. String values
+-- may contain placeholders like {event} or {payload.customer.email} which are
+-- replaced with values of the event before sending. NULL sends the raw event.
+
+ALTER TABLE event_webhooks
+ADD COLUMN payload_template JSON NULL;
@@ -0,0 +1,37 @@
+-- Create webhook_deliveries table
+-- Supports both MySQL and PostgreSQL
+--
+-- History of webhook delivery attempts: every attempt (including retries) with
+-- the HTTP status code, latency and outcome, so operators can see which
+-- deliveries failed and why.
+-- status_code is NULL if the endpoint could not be reached.
+-- created_at uses microsecond precision so attempts within the same second keep
+-- their order.
+
+-- ============================================================================
+-- WEBHOOK_DELIVERIES TABLE
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id CHAR(36) NOT NULL PRIMARY KEY,
+    webhook_id CHAR(36) NOT NULL,
+    organization_uuid CHAR(36) NOT NULL,
+    event_name VARCHAR(255) NOT NULL,
+    attempt INTEGER NOT NULL,
+    status_code INTEGER NULL,
+    success INTEGER NOT NULL DEFAULT 0, -- 1 = delivered, 0 = failed (works in both databases)
+    latency_ms BIGINT NOT NULL,
+    error TEXT NULL,
+    created_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+
+    -- Foreign keys
+    FOREIGN KEY (webhook_id) REFERENCES event_webhooks(id) ON DELETE CASCADE
+);
+
+-- ============================================================================
+-- INDEXES
+-- ============================================================================
+
+-- Composite index for listing the newest deliveries of a webhook
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_webhook_created
+    ON webhook_deliveries(webhook_id, created_at);
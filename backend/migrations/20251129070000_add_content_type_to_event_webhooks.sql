@@ -0,0 +1,9 @@
+-- Add content_type column to event_webhooks table
+-- Supports both MySQL and PostgreSQL
+--
+-- Encoding of the request body of webhook deliveries: 'application/json' sends
+-- the payload as JSON document, 'application/x-www-form-urlencoded' sends its
+-- top-level fields as form fields for receivers that expect form posts.
+
+ALTER TABLE event_webhooks
+ADD COLUMN content_type VARCHAR(50) NOT NULL DEFAULT 'application/json';
@@ -0,0 +1,11 @@
+-- Add sort_order column to module_docs_pages table
+-- Supports both MySQL and PostgreSQL
+--
+-- Explicit position of a page within its folder, assigned by reordering the
+-- pages of a folder. Pages that were never reordered share 0 and fall back to
+-- the newest first order.
+
+ALTER TABLE module_docs_pages
+ADD COLUMN sort_order INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS idx_module_docs_pages_folder_sort_order ON module_docs_pages(folder_uuid, sort_order);
@@ -0,0 +1,14 @@
+-- Drop the page_type CHECK constraint from module_docs_pages table
+-- Supports both MySQL and PostgreSQL
+--
+-- Page types are registered at runtime through the PageTypeRegistry of the Docs
+-- module. A fixed list of page types in the database would reject every type
+-- registered later (e.g. diagram) on insert.
+--
+-- PostgreSQL names the column constraint of 20251118170000_module_docs_pages.sql
+-- module_docs_pages_page_type_check. MySQL also keeps the unnamed column constraint
+-- of that migration as module_docs_pages_chk_1, which has to be dropped as well:
+-- MySQL: ALTER TABLE module_docs_pages DROP CHECK module_docs_pages_chk_1;
+
+ALTER TABLE module_docs_pages
+DROP CONSTRAINT module_docs_pages_page_type_check;
@@ -0,0 +1,10 @@
+-- Store user emails in lowercase
+-- Supports both MySQL and PostgreSQL
+--
+-- Emails are normalized (trimmed and lowercased) on write and compared directly on
+-- lookup, so existing rows are normalized the same way. The UNIQUE constraint on
+-- users.email then also rejects emails differing only in case. If two existing users
+-- have such emails, this migration fails and one of them has to be changed first.
+
+UPDATE users
+SET email = LOWER(TRIM(email));
@@ -3,7 +3,8 @@ use jsonwebtoken::{decode, DecodingKey, Validation};
 use serde_json::{json, Value};
 
 mod common;
-use api::Claims;
+use api::{create_app, Claims};
+use flextide_core::database::DatabasePool;
 
 #[tokio::test]
 async fn test_login_success() {
@@ -152,3 +153,255 @@ async fn test_health_check() {
     assert_eq!(body.get("status").unwrap().as_str().unwrap(), "ok");
 }
 
+
+/// Decode the claims of a token issued by the test app
+fn decode_claims(body: &Value) -> Claims {
+    let token = body.get("token").unwrap().as_str().unwrap();
+    decode::<Claims>(
+        token,
+        &DecodingKey::from_secret("test-secret-key".as_ref()),
+        &Validation::default(),
+    )
+    .unwrap()
+    .claims
+}
+
+#[tokio::test]
+async fn test_login_email_is_case_insensitive() {
+    let app = common::create_test_app().await;
+    let server = TestServer::new(app).unwrap();
+
+    let lower = server
+        .post("/api/login")
+        .json(&json!({
+            "email": "admin@example.com",
+            "password": "admin"
+        }))
+        .await;
+    lower.assert_status_ok();
+
+    let mixed = server
+        .post("/api/login")
+        .json(&json!({
+            "email": "  Admin@Example.COM ",
+            "password": "admin"
+        }))
+        .await;
+    mixed.assert_status_ok();
+
+    let lower_claims = decode_claims(&lower.json());
+    let mixed_claims = decode_claims(&mixed.json());
+    assert_eq!(mixed_claims.user_uuid, lower_claims.user_uuid);
+    assert_eq!(mixed_claims.sub, "admin@example.com");
+    assert!(mixed_claims.is_server_admin);
+}
+
+#[tokio::test]
+async fn test_register_email_is_case_insensitive() {
+    let app = common::create_test_app().await;
+    let server = TestServer::new(app).unwrap();
+
+    let lower = server
+        .post("/api/register")
+        .json(&json!({
+            "email": "newuser@example.com",
+            "password": "password123"
+        }))
+        .await;
+    lower.assert_status_ok();
+
+    let mixed = server
+        .post("/api/register")
+        .json(&json!({
+            "email": "NewUser@EXAMPLE.com",
+            "password": "password123"
+        }))
+        .await;
+    mixed.assert_status_ok();
+
+    let body: Value = mixed.json();
+    assert_eq!(body.get("email").unwrap(), "newuser@example.com");
+    assert_eq!(decode_claims(&body).user_uuid, decode_claims(&lower.json()).user_uuid);
+    assert!(!decode_claims(&body).is_server_admin);
+}
+
+#[tokio::test]
+async fn test_register_never_grants_server_admin() {
+    let app = common::create_test_app().await;
+    let server = TestServer::new(app).unwrap();
+
+    let response = server
+        .post("/api/register")
+        .json(&json!({
+            "email": "admin@example.com",
+            "password": "password123"
+        }))
+        .await;
+
+    response.assert_status_ok();
+    assert!(!decode_claims(&response.json()).is_server_admin);
+}
+
+/// Insert an activated user with the given server admin flag
+async fn insert_user(pool: &DatabasePool, email: &str, password: &str, is_server_admin: bool) {
+    let p = match pool {
+        DatabasePool::Sqlite(p) => p,
+        _ => unreachable!("Test pool should be SQLite"),
+    };
+
+    sqlx::query(
+        "INSERT INTO users (uuid, email, password_hash, prename, mail_verified, activated, is_server_admin)
+         VALUES (?1, ?2, ?3, 'Test', 1, 1, ?4)",
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(email)
+    .bind(flextide_core::user::hash_password(password).unwrap())
+    .bind(is_server_admin as i32)
+    .execute(p)
+    .await
+    .expect("Failed to insert user");
+}
+
+#[tokio::test]
+async fn test_login_flagged_server_admin() {
+    let (state, _, _, _) = common::create_test_state_with_org().await;
+    insert_user(&state.db_pool, "operator@example.org", "operator-secret", true).await;
+    let server = TestServer::new(create_app(state)).unwrap();
+
+    let response = server
+        .post("/api/login")
+        .json(&json!({
+            "email": "operator@example.org",
+            "password": "operator-secret"
+        }))
+        .await;
+
+    response.assert_status_ok();
+    assert!(decode_claims(&response.json()).is_server_admin);
+}
+
+#[tokio::test]
+async fn test_login_unflagged_user_is_not_server_admin() {
+    let (state, _, _, _) = common::create_test_state_with_org().await;
+    insert_user(&state.db_pool, "member@example.org", "member-secret", false).await;
+
+    // Revoke the flag of the default admin - the email alone must not grant admin rights
+    match &state.db_pool {
+        DatabasePool::Sqlite(p) => {
+            sqlx::query("UPDATE users SET is_server_admin = 0 WHERE email = 'admin@example.com'")
+                .execute(p)
+                .await
+                .expect("Failed to update admin user");
+        }
+        _ => unreachable!("Test pool should be SQLite"),
+    }
+    let server = TestServer::new(create_app(state)).unwrap();
+
+    for (email, password) in [("member@example.org", "member-secret"), ("admin@example.com", "admin")] {
+        let response = server
+            .post("/api/login")
+            .json(&json!({
+                "email": email,
+                "password": password
+            }))
+            .await;
+
+        response.assert_status_ok();
+        assert!(!decode_claims(&response.json()).is_server_admin, "{} must not be server admin", email);
+    }
+}
+
+/// List the authentication audit log through the admin endpoint
+async fn list_auth_events(server: &TestServer, org_uuid: &str, token: &str, query: &str) -> Value {
+    let response = server
+        .get(&format!("/api/admin/auth-events{}", query))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", org_uuid)
+        .await;
+
+    response.assert_status_ok();
+    response.json()
+}
+
+async fn login_token(server: &TestServer, email: &str, password: &str) -> String {
+    let response = server
+        .post("/api/login")
+        .json(&json!({
+            "email": email,
+            "password": password
+        }))
+        .await;
+
+    response.assert_status_ok();
+    response.json::<Value>()["token"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_failed_login_is_audited() {
+    let (state, org_uuid, _, _) = common::create_test_state_with_org().await;
+    let server = TestServer::new(create_app(state)).unwrap();
+
+    let response = server
+        .post("/api/login")
+        .add_header("X-Forwarded-For", "203.0.113.7, 10.0.0.1")
+        .json(&json!({
+            "email": "Admin@EXAMPLE.com",
+            "password": "wrongpassword"
+        }))
+        .await;
+    response.assert_status_unauthorized();
+
+    let token = login_token(&server, "admin@example.com", "admin").await;
+    let body = list_auth_events(&server, &org_uuid, &token, "?outcome=failure&email=admin@example.com").await;
+
+    assert_eq!(body["total"], 1);
+    let event = &body["events"][0];
+    assert_eq!(event["event_type"], "login");
+    assert_eq!(event["outcome"], "failure");
+    assert_eq!(event["email"], "admin@example.com");
+    assert_eq!(event["failure_reason"], "invalid_password");
+    // No trusted proxy is configured, so the forwarded header is ignored
+    assert_eq!(event["ip_address"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_successful_login_and_logout_are_audited() {
+    let (state, org_uuid, admin_uuid, _) = common::create_test_state_with_org().await;
+    let server = TestServer::new(create_app(state)).unwrap();
+
+    let token = login_token(&server, "admin@example.com", "admin").await;
+    server
+        .post("/api/logout")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .json(&json!({ "user_uuid": admin_uuid }))
+        .await
+        .assert_status_ok();
+
+    let body = list_auth_events(&server, &org_uuid, &token, &format!("?user_uuid={}", admin_uuid)).await;
+
+    assert_eq!(body["total"], 2);
+    let logout = &body["events"][0];
+    assert_eq!(logout["event_type"], "logout");
+    assert_eq!(logout["outcome"], "success");
+    let login = &body["events"][1];
+    assert_eq!(login["event_type"], "login");
+    assert_eq!(login["outcome"], "success");
+    assert_eq!(login["email"], "admin@example.com");
+    assert_eq!(login["failure_reason"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_auth_events_require_server_admin() {
+    let (state, org_uuid, _, _) = common::create_test_state_with_org().await;
+    insert_user(&state.db_pool, "member@example.org", "member-secret", false).await;
+    let server = TestServer::new(create_app(state)).unwrap();
+
+    let token = login_token(&server, "member@example.org", "member-secret").await;
+    let response = server
+        .get("/api/admin/auth-events")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+
+    response.assert_status_forbidden();
+}
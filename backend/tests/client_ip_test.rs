@@ -0,0 +1,68 @@
+use std::net::IpAddr;
+
+use api::{extract_client_ip, TrustedProxies};
+use axum::http::{HeaderMap, HeaderValue};
+
+fn ip(value: &str) -> IpAddr {
+    value.parse().unwrap()
+}
+
+fn headers(values: &[(&'static str, &'static str)]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for (name, value) in values {
+        headers.append(*name, HeaderValue::from_static(value));
+    }
+    headers
+}
+
+#[test]
+fn test_forwarded_for_from_trusted_proxy() {
+    let trusted = TrustedProxies::parse("10.0.0.0/8, 192.168.1.10").unwrap();
+
+    // The rightmost untrusted entry is the client, entries left of it may be forged
+    let forwarded = headers(&[("x-forwarded-for", "198.51.100.1, 203.0.113.7, 10.0.0.2")]);
+    assert_eq!(
+        extract_client_ip(&forwarded, Some(ip("10.1.2.3")), &trusted),
+        Some(ip("203.0.113.7"))
+    );
+
+    // Multiple header lines are read in order
+    let split = headers(&[("x-forwarded-for", "203.0.113.7"), ("x-forwarded-for", "10.0.0.2")]);
+    assert_eq!(extract_client_ip(&split, Some(ip("192.168.1.10")), &trusted), Some(ip("203.0.113.7")));
+
+    let real_ip = headers(&[("x-real-ip", "203.0.113.8")]);
+    assert_eq!(extract_client_ip(&real_ip, Some(ip("10.1.2.3")), &trusted), Some(ip("203.0.113.8")));
+
+    // Without forwarded headers the proxy itself is the client
+    assert_eq!(extract_client_ip(&HeaderMap::new(), Some(ip("10.1.2.3")), &trusted), Some(ip("10.1.2.3")));
+}
+
+#[test]
+fn test_forwarded_for_from_untrusted_source_is_ignored() {
+    let spoofed = headers(&[("x-forwarded-for", "203.0.113.7"), ("x-real-ip", "203.0.113.8")]);
+    let trusted = TrustedProxies::parse("10.0.0.0/8").unwrap();
+
+    assert_eq!(extract_client_ip(&spoofed, Some(ip("198.51.100.1")), &trusted), Some(ip("198.51.100.1")));
+    assert_eq!(
+        extract_client_ip(&spoofed, Some(ip("10.1.2.3")), &TrustedProxies::default()),
+        Some(ip("10.1.2.3"))
+    );
+    // Without a peer address the source cannot be verified
+    assert_eq!(extract_client_ip(&spoofed, None, &trusted), None);
+}
+
+#[test]
+fn test_trusted_proxies_parse() {
+    let trusted = TrustedProxies::parse(" 10.0.0.0/8 ,, fd00::/8, 192.168.1.10 ").unwrap();
+    assert!(trusted.contains(ip("10.255.0.1")));
+    assert!(!trusted.contains(ip("11.0.0.1")));
+    assert!(trusted.contains(ip("fd12::1")));
+    assert!(trusted.contains(ip("192.168.1.10")));
+    assert!(!trusted.contains(ip("192.168.1.11")));
+    // IPv4 peers accepted on an IPv6 socket
+    assert!(trusted.contains(ip("::ffff:10.0.0.1")));
+
+    assert!(TrustedProxies::parse("").unwrap().is_empty());
+    assert_eq!(TrustedProxies::parse("10.0.0.0/33"), Err("10.0.0.0/33".to_string()));
+    assert_eq!(TrustedProxies::parse("10.0.0.1, proxy.local"), Err("proxy.local".to_string()));
+}
@@ -1,4 +1,31 @@
-use api::{create_app, AppState};
+use api::{create_app, AppState, Claims};
+use jsonwebtoken::{encode, EncodingKey, Header};
+
+/// Create a JWT token for testing, signed with the secret of the test apps
+#[allow(dead_code)]
+pub fn create_test_token(email: &str, user_uuid: &str) -> String {
+    use chrono::Utc;
+
+    let now = Utc::now();
+    let exp = (now + chrono::Duration::hours(24)).timestamp() as usize;
+    let iat = now.timestamp() as usize;
+
+    let claims = Claims {
+        sub: email.to_string(),
+        user_uuid: user_uuid.to_string(),
+        exp,
+        iat,
+        is_server_admin: false,
+    };
+
+    let jwt_secret = "test-secret-key";
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(jwt_secret.as_ref()),
+    )
+    .unwrap()
+}
 
 #[allow(dead_code)]
 pub async fn create_test_app() -> axum::Router {
@@ -21,7 +48,8 @@ pub async fn create_test_app() -> axum::Router {
             created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
             updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
             mail_verified INTEGER NOT NULL DEFAULT 0,
-            activated INTEGER NOT NULL DEFAULT 1
+            activated INTEGER NOT NULL DEFAULT 1,
+            is_server_admin INTEGER NOT NULL DEFAULT 0
         )"
     )
     .execute(match &db_pool {
@@ -123,6 +151,46 @@ pub async fn create_test_app() -> axum::Router {
     .await
     .expect("Failed to create user_permissions table");
     
+    // Create permission_audit_log table for tests
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS permission_audit_log (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            organization_uuid CHAR(36) NOT NULL,
+            actor_user_id CHAR(36) NOT NULL,
+            target_user_id CHAR(36) NOT NULL,
+            permission_name VARCHAR(255) NOT NULL,
+            action VARCHAR(20) NOT NULL CHECK (action IN ('grant', 'revoke')),
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (organization_uuid) REFERENCES organizations(uuid) ON DELETE CASCADE
+        )"
+    )
+    .execute(match &db_pool {
+        flextide_core::database::DatabasePool::Sqlite(p) => p,
+        _ => unreachable!("Test pool should be SQLite"),
+    })
+    .await
+    .expect("Failed to create permission_audit_log table");
+    
+    // Create auth_events table for tests
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS auth_events (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            event_type VARCHAR(20) NOT NULL CHECK (event_type IN ('login', 'logout')),
+            outcome VARCHAR(20) NOT NULL CHECK (outcome IN ('success', 'failure')),
+            user_uuid CHAR(36),
+            email VARCHAR(255),
+            ip_address VARCHAR(45),
+            failure_reason VARCHAR(50),
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )"
+    )
+    .execute(match &db_pool {
+        flextide_core::database::DatabasePool::Sqlite(p) => p,
+        _ => unreachable!("Test pool should be SQLite"),
+    })
+    .await
+    .expect("Failed to create auth_events table");
+    
     // Insert super_admin permission group and permission for tests
     sqlx::query(
         "INSERT OR IGNORE INTO permission_groups (id, name, title, description, visible, sort_order)
@@ -169,7 +237,8 @@ pub async fn create_test_app() -> axum::Router {
             website_url VARCHAR(500),
             gender VARCHAR(20),
             created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
-            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            archived_at TIMESTAMP NULL
         )"
     )
     .execute(match &db_pool {
@@ -224,8 +293,14 @@ pub async fn create_test_app() -> axum::Router {
     
     let app_state = AppState {
         jwt_secret,
+        jwt_config: flextide_core::jwt::JwtConfig::default(),
         db_pool: db_pool.clone(),
+        db_read_pool: db_pool.clone(),
         event_dispatcher,
+        node_registry: std::sync::Arc::new(api::default_node_registry()),
+        pagination: std::sync::Arc::new(flextide_core::pagination::PaginationConfig::default()),
+        cors_allowed_origins: Vec::new(),
+        trusted_proxies: api::TrustedProxies::default(),
     };
     create_app(app_state)
 }
@@ -235,6 +310,15 @@ pub async fn create_test_app() -> axum::Router {
 /// This ensures the organization is set up in the same database as the app
 #[allow(dead_code)]
 pub async fn create_test_app_with_org() -> (axum::Router, String, String, String) {
+    let (app_state, org_uuid, user_uuid, email) = create_test_state_with_org().await;
+    let app = create_app(app_state);
+
+    (app, org_uuid, user_uuid, email)
+}
+
+/// Same as `create_test_app_with_org`, but returns the app state so tests can seed the database
+#[allow(dead_code)]
+pub async fn create_test_state_with_org() -> (AppState, String, String, String) {
     let jwt_secret = "test-secret-key".to_string();
     
     // Use in-memory SQLite database for tests - no real database needed!
@@ -254,7 +338,8 @@ pub async fn create_test_app_with_org() -> (axum::Router, String, String, String
             created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
             updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
             mail_verified INTEGER NOT NULL DEFAULT 0,
-            activated INTEGER NOT NULL DEFAULT 1
+            activated INTEGER NOT NULL DEFAULT 1,
+            is_server_admin INTEGER NOT NULL DEFAULT 0
         )"
     )
     .execute(match &db_pool {
@@ -356,6 +441,46 @@ pub async fn create_test_app_with_org() -> (axum::Router, String, String, String
     .await
     .expect("Failed to create user_permissions table");
     
+    // Create permission_audit_log table for tests
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS permission_audit_log (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            organization_uuid CHAR(36) NOT NULL,
+            actor_user_id CHAR(36) NOT NULL,
+            target_user_id CHAR(36) NOT NULL,
+            permission_name VARCHAR(255) NOT NULL,
+            action VARCHAR(20) NOT NULL CHECK (action IN ('grant', 'revoke')),
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (organization_uuid) REFERENCES organizations(uuid) ON DELETE CASCADE
+        )"
+    )
+    .execute(match &db_pool {
+        flextide_core::database::DatabasePool::Sqlite(p) => p,
+        _ => unreachable!("Test pool should be SQLite"),
+    })
+    .await
+    .expect("Failed to create permission_audit_log table");
+    
+    // Create auth_events table for tests
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS auth_events (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            event_type VARCHAR(20) NOT NULL CHECK (event_type IN ('login', 'logout')),
+            outcome VARCHAR(20) NOT NULL CHECK (outcome IN ('success', 'failure')),
+            user_uuid CHAR(36),
+            email VARCHAR(255),
+            ip_address VARCHAR(45),
+            failure_reason VARCHAR(50),
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )"
+    )
+    .execute(match &db_pool {
+        flextide_core::database::DatabasePool::Sqlite(p) => p,
+        _ => unreachable!("Test pool should be SQLite"),
+    })
+    .await
+    .expect("Failed to create auth_events table");
+    
     // Insert super_admin permission group and permission for tests
     sqlx::query(
         "INSERT OR IGNORE INTO permission_groups (id, name, title, description, visible, sort_order)
@@ -402,7 +527,8 @@ pub async fn create_test_app_with_org() -> (axum::Router, String, String, String
             website_url VARCHAR(500),
             gender VARCHAR(20),
             created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
-            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            archived_at TIMESTAMP NULL
         )"
     )
     .execute(match &db_pool {
@@ -460,12 +586,17 @@ pub async fn create_test_app_with_org() -> (axum::Router, String, String, String
     
     let app_state = AppState {
         jwt_secret,
-        db_pool,
+        jwt_config: flextide_core::jwt::JwtConfig::default(),
+        db_pool: db_pool.clone(),
+        db_read_pool: db_pool,
         event_dispatcher,
+        node_registry: std::sync::Arc::new(api::default_node_registry()),
+        pagination: std::sync::Arc::new(flextide_core::pagination::PaginationConfig::default()),
+        cors_allowed_origins: Vec::new(),
+        trusted_proxies: api::TrustedProxies::default(),
     };
-    let app = create_app(app_state);
     
-    (app, org_uuid, user_uuid, email)
+    (app_state, org_uuid, user_uuid, email)
 }
 
 /// Helper function to set up test organization and user membership in the test app's database
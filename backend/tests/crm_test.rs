@@ -1,36 +1,10 @@
+use axum_test::multipart::{MultipartForm, Part};
 use axum_test::TestServer;
-use jsonwebtoken::{encode, EncodingKey, Header};
 use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
 mod common;
-use api::Claims;
-
-/// Helper function to create a JWT token for testing
-fn create_test_token(email: &str, user_uuid: &str) -> String {
-    use chrono::Utc;
-    
-    let now = Utc::now();
-    let exp = (now + chrono::Duration::hours(24)).timestamp() as usize;
-    let iat = now.timestamp() as usize;
-    
-    let claims = Claims {
-        sub: email.to_string(),
-        user_uuid: user_uuid.to_string(),
-        exp,
-        iat,
-        is_server_admin: false,
-    };
-    
-    let jwt_secret = "test-secret-key";
-    encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(jwt_secret.as_ref()),
-    )
-    .unwrap()
-}
-
 
 // Customer Creation Tests
 
@@ -39,7 +13,7 @@ async fn test_create_customer_success() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     let response = server
         .post("/api/modules/crm/customers")
@@ -66,7 +40,7 @@ async fn test_create_customer_missing_required_fields() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     // Missing last_name - Axum returns 422 (Unprocessable Entity) for JSON deserialization failures
     let response = server
@@ -87,7 +61,7 @@ async fn test_create_customer_empty_first_name() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     let response = server
         .post("/api/modules/crm/customers")
@@ -122,14 +96,120 @@ async fn test_create_customer_without_auth() {
     response.assert_status_unauthorized();
 }
 
+// Customer Import Tests
+
+#[tokio::test]
+async fn test_import_customers_partial_success() {
+    let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
+    let server = TestServer::new(app).unwrap();
+    
+    let token = common::create_test_token(&email, &user_uuid);
+    
+    let csv = "first_name,last_name,email\nJohn,Doe,john.doe@example.com\nJane,,jane@example.com\n";
+    let response = server
+        .post("/api/modules/crm/customers/import")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .multipart(MultipartForm::new().add_part(
+            "file",
+            Part::bytes(csv.as_bytes().to_vec()).file_name("customers.csv").mime_type("text/csv"),
+        ))
+        .await;
+    
+    response.assert_status_ok();
+    
+    let body: Value = response.json();
+    let created = body.get("created").unwrap().as_array().unwrap();
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].get("line").unwrap().as_u64().unwrap(), 2);
+    assert_eq!(created[0].get("first_name").unwrap().as_str().unwrap(), "John");
+    assert!(body.get("skipped").unwrap().as_array().unwrap().is_empty());
+    assert_eq!(
+        body.get("errors").unwrap(),
+        &json!([{ "line": 3, "message": "Last name cannot be empty" }])
+    );
+}
+
+#[tokio::test]
+async fn test_import_customers_missing_file() {
+    let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
+    let server = TestServer::new(app).unwrap();
+    
+    let token = common::create_test_token(&email, &user_uuid);
+    
+    let response = server
+        .post("/api/modules/crm/customers/import")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .multipart(MultipartForm::new().add_text("comment", "no file"))
+        .await;
+    
+    response.assert_status_bad_request();
+}
+
 // Customer Search Tests
 
+#[tokio::test]
+async fn test_update_customer_event_contains_changes() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let recorded = received.clone();
+    state
+        .event_dispatcher
+        .subscribe_fn("crm-test-recorder", "module_crm_customer_updated", move |event| {
+            let recorded = recorded.clone();
+            async move {
+                recorded.lock().unwrap().push(event);
+                Ok(())
+            }
+        });
+    let server = TestServer::new(api::create_app(state)).unwrap();
+    let token = common::create_test_token(&email, &user_uuid);
+
+    let response = server
+        .post("/api/modules/crm/customers")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .json(&json!({
+            "first_name": "John",
+            "last_name": "Doe",
+            "email": "john.doe@example.com",
+            "company_name": "Example Corp"
+        }))
+        .await;
+    response.assert_status_ok();
+    let customer_uuid = response.json::<Value>()["uuid"].as_str().unwrap().to_string();
+
+    let response = server
+        .put(&format!("/api/modules/crm/customers/{}", customer_uuid))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .json(&json!({
+            "first_name": "John",
+            "email": "john@example.org",
+            "job_title": "CTO"
+        }))
+        .await;
+    response.assert_status_ok();
+
+    let events = received.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    // Fields set to their current value are not listed
+    assert_eq!(
+        events[0].payload.data["changes"],
+        json!({
+            "email": { "old": "john.doe@example.com", "new": "john@example.org" },
+            "job_title": { "old": null, "new": "CTO" }
+        })
+    );
+}
+
 #[tokio::test]
 async fn test_search_customers_success() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     // First, create a customer
     let create_response = server
@@ -166,7 +246,7 @@ async fn test_search_customers_empty_query() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     let response = server
         .get("/api/modules/crm/customers/search?q=")
@@ -185,7 +265,7 @@ async fn test_search_customers_no_results() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     let response = server
         .get("/api/modules/crm/customers/search?q=Nonexistent")
@@ -207,7 +287,7 @@ async fn test_delete_customer_success() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     // Create a customer
     let create_response = server
@@ -234,7 +314,111 @@ async fn test_delete_customer_success() {
     delete_response.assert_status_ok();
     
     let body: Value = delete_response.json();
-    assert_eq!(body.get("message").unwrap().as_str().unwrap(), "Customer deleted successfully");
+    assert_eq!(body.get("message").unwrap().as_str().unwrap(), "Customer archived successfully");
+}
+
+/// UUIDs of the customers returned by the customer list
+async fn list_customer_uuids(server: &TestServer, token: &str, org_uuid: &str, query: &str) -> Vec<String> {
+    let response = server
+        .get(&format!("/api/modules/crm/customers{}", query))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", org_uuid)
+        .await;
+
+    response.assert_status_ok();
+    let body: Value = response.json();
+    body["customers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|customer| customer["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_archived_customer_can_be_restored() {
+    let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
+    let server = TestServer::new(app).unwrap();
+    let token = common::create_test_token(&email, &user_uuid);
+
+    let create_response = server
+        .post("/api/modules/crm/customers")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .json(&json!({
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .await;
+    create_response.assert_status_ok();
+    let customer_uuid = create_response.json::<Value>()["uuid"].as_str().unwrap().to_string();
+
+    server
+        .delete(&format!("/api/modules/crm/customers/{}", customer_uuid))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await
+        .assert_status_ok();
+
+    // Archived customers are hidden unless requested
+    assert!(list_customer_uuids(&server, &token, &org_uuid, "").await.is_empty());
+    assert_eq!(
+        list_customer_uuids(&server, &token, &org_uuid, "?include_archived=true").await,
+        vec![customer_uuid.clone()]
+    );
+
+    let restore_response = server
+        .post(&format!("/api/modules/crm/customers/{}/restore", customer_uuid))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    restore_response.assert_status_ok();
+    assert_eq!(restore_response.json::<Value>()["message"], "Customer restored successfully");
+
+    assert_eq!(list_customer_uuids(&server, &token, &org_uuid, "").await, vec![customer_uuid.clone()]);
+
+    // Restoring an active customer is rejected
+    server
+        .post(&format!("/api/modules/crm/customers/{}/restore", customer_uuid))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await
+        .assert_status(axum::http::StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_delete_customer_permanently() {
+    let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
+    let server = TestServer::new(app).unwrap();
+    let token = common::create_test_token(&email, &user_uuid);
+
+    let create_response = server
+        .post("/api/modules/crm/customers")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .json(&json!({
+            "first_name": "John",
+            "last_name": "Doe"
+        }))
+        .await;
+    create_response.assert_status_ok();
+    let customer_uuid = create_response.json::<Value>()["uuid"].as_str().unwrap().to_string();
+
+    let delete_response = server
+        .delete(&format!("/api/modules/crm/customers/{}?permanent=true", customer_uuid))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    delete_response.assert_status_ok();
+    assert_eq!(delete_response.json::<Value>()["message"], "Customer deleted successfully");
+
+    assert!(list_customer_uuids(&server, &token, &org_uuid, "?include_archived=true").await.is_empty());
+    server
+        .post(&format!("/api/modules/crm/customers/{}/restore", customer_uuid))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await
+        .assert_status_not_found();
 }
 
 #[tokio::test]
@@ -242,7 +426,7 @@ async fn test_delete_customer_not_found() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     let fake_uuid = Uuid::new_v4().to_string();
     
@@ -262,7 +446,7 @@ async fn test_add_customer_note_success() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     // Create a customer
     let create_response = server
@@ -302,7 +486,7 @@ async fn test_add_customer_note_invalid_text() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     // Create a customer
     let create_response = server
@@ -337,7 +521,7 @@ async fn test_delete_customer_note_success() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     // Create a customer
     let create_response = server
@@ -388,7 +572,7 @@ async fn test_add_customer_address_success() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     // Create a customer
     let create_response = server
@@ -433,7 +617,7 @@ async fn test_add_customer_address_empty_type() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     // Create a customer
     let create_response = server
@@ -468,7 +652,7 @@ async fn test_delete_customer_address_success() {
     let (app, org_uuid, user_uuid, email) = common::create_test_app_with_org().await;
     let server = TestServer::new(app).unwrap();
     
-    let token = create_test_token(&email, &user_uuid);
+    let token = common::create_test_token(&email, &user_uuid);
     
     // Create a customer
     let create_response = server
@@ -0,0 +1,112 @@
+use axum_test::TestServer;
+use flextide_core::database::DatabasePool;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+mod common;
+use api::create_app;
+
+fn sqlite(pool: &DatabasePool) -> &sqlx::SqlitePool {
+    match pool {
+        DatabasePool::Sqlite(p) => p,
+        _ => unreachable!("Test pool should be SQLite"),
+    }
+}
+
+/// Create the workflows and runs tables and a workflow with one run, returns (workflow_uuid, run_uuid)
+async fn create_workflow_with_run(pool: &DatabasePool, org_uuid: &str) -> (String, String) {
+    for statement in [
+        "CREATE TABLE IF NOT EXISTS workflows (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            organization_uuid CHAR(36) NOT NULL,
+            name VARCHAR(255) NOT NULL
+        )",
+        "CREATE TABLE IF NOT EXISTS runs (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            workflow_id CHAR(36) NOT NULL,
+            organization_uuid CHAR(36) NOT NULL,
+            status VARCHAR(20) NOT NULL DEFAULT 'not_started',
+            trigger_type VARCHAR(255) NOT NULL DEFAULT 'manual',
+            started_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            finished_at TIMESTAMP NULL,
+            metadata JSON,
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+    ] {
+        sqlx::query(statement)
+            .execute(sqlite(pool))
+            .await
+            .expect("Failed to create table");
+    }
+
+    let workflow_uuid = Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO workflows (uuid, organization_uuid, name) VALUES (?1, ?2, 'Nightly sync')")
+        .bind(&workflow_uuid)
+        .bind(org_uuid)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert workflow");
+
+    let run_uuid = Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO runs (uuid, workflow_id, organization_uuid, status) VALUES (?1, ?2, ?3, 'completed')")
+        .bind(&run_uuid)
+        .bind(&workflow_uuid)
+        .bind(org_uuid)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert run");
+
+    (workflow_uuid, run_uuid)
+}
+
+#[tokio::test]
+async fn test_execution_of_other_organization_is_not_found() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    let (_, own_run) = create_workflow_with_run(&state.db_pool, &org_uuid).await;
+    let (_, foreign_run) = create_workflow_with_run(&state.db_pool, &Uuid::new_v4().to_string()).await;
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let token = common::create_test_token(&email, &user_uuid);
+    let get = |run_uuid: String| {
+        server
+            .get(&format!("/api/executions/{}", run_uuid))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .add_header("X-Organization-UUID", &org_uuid)
+    };
+
+    let response = get(own_run.clone()).await;
+    response.assert_status_ok();
+    let body: Value = response.json();
+    assert_eq!(body["uuid"], own_run.as_str());
+    assert_eq!(body["workflow_name"], "Nightly sync");
+
+    // Foreign and missing executions yield the same 404 response
+    let foreign = get(foreign_run).await;
+    foreign.assert_status_not_found();
+    let missing = get(Uuid::new_v4().to_string()).await;
+    missing.assert_status_not_found();
+    assert_eq!(foreign.json::<Value>(), missing.json::<Value>());
+}
+
+#[tokio::test]
+async fn test_workflow_of_other_organization_is_not_found() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    let (own_workflow, _) = create_workflow_with_run(&state.db_pool, &org_uuid).await;
+    let (foreign_workflow, _) = create_workflow_with_run(&state.db_pool, &Uuid::new_v4().to_string()).await;
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let token = common::create_test_token(&email, &user_uuid);
+    let edit_title = |workflow_uuid: String| {
+        server
+            .post(&format!("/api/workflows/{}/edit-title", workflow_uuid))
+            .add_header("Authorization", format!("Bearer {}", token))
+            .add_header("X-Organization-UUID", &org_uuid)
+            .json(&json!({ "title": "Renamed" }))
+    };
+
+    edit_title(own_workflow).await.assert_status_ok();
+
+    let foreign = edit_title(foreign_workflow).await;
+    foreign.assert_status_not_found();
+    assert_eq!(foreign.json::<Value>(), json!({ "error": "Workflow not found" }));
+}
@@ -0,0 +1,93 @@
+use axum_test::TestServer;
+use flextide_core::database::DatabasePool;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+mod common;
+use api::create_app;
+
+fn sqlite(pool: &DatabasePool) -> &sqlx::SqlitePool {
+    match pool {
+        DatabasePool::Sqlite(p) => p,
+        _ => unreachable!("Test pool should be SQLite"),
+    }
+}
+
+/// Create the workflows and runs tables and a run with metadata
+async fn create_run_with_metadata(pool: &DatabasePool, org_uuid: &str, metadata: &Value) {
+    for statement in [
+        "CREATE TABLE IF NOT EXISTS workflows (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            organization_uuid CHAR(36) NOT NULL,
+            name VARCHAR(255) NOT NULL
+        )",
+        "CREATE TABLE IF NOT EXISTS runs (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            workflow_id CHAR(36) NOT NULL,
+            organization_uuid CHAR(36) NOT NULL,
+            status VARCHAR(20) NOT NULL DEFAULT 'not_started',
+            trigger_type VARCHAR(255) NOT NULL DEFAULT 'manual',
+            started_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            finished_at TIMESTAMP NULL,
+            metadata JSON,
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+    ] {
+        sqlx::query(statement)
+            .execute(sqlite(pool))
+            .await
+            .expect("Failed to create table");
+    }
+
+    let workflow_uuid = Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO workflows (uuid, organization_uuid, name) VALUES (?1, ?2, 'Nightly sync')")
+        .bind(&workflow_uuid)
+        .bind(org_uuid)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert workflow");
+
+    sqlx::query(
+        "INSERT INTO runs (uuid, workflow_id, organization_uuid, status, metadata) VALUES (?1, ?2, ?3, 'completed', ?4)",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(&workflow_uuid)
+    .bind(org_uuid)
+    .bind(metadata.to_string())
+    .execute(sqlite(pool))
+    .await
+    .expect("Failed to insert run");
+}
+
+async fn get_last_executions(query: &str) -> Value {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    create_run_with_metadata(&state.db_pool, &org_uuid, &json!({ "input": { "rows": 1200 } })).await;
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let response = server
+        .get(&format!("/api/executions/last-executions{}", query))
+        .add_header("Authorization", format!("Bearer {}", common::create_test_token(&email, &user_uuid)))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_ok();
+
+    let body: Value = response.json();
+    assert_eq!(body["total"], 1);
+    assert_eq!(body["executions"][0]["workflow_name"], "Nightly sync");
+    body
+}
+
+#[tokio::test]
+async fn test_last_executions_omit_metadata_by_default() {
+    let body = get_last_executions("").await;
+    assert_eq!(body["executions"][0]["metadata"], Value::Null);
+
+    let body = get_last_executions("?include_metadata=false").await;
+    assert_eq!(body["executions"][0]["metadata"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_last_executions_include_metadata() {
+    let body = get_last_executions("?include_metadata=true").await;
+    assert_eq!(body["executions"][0]["metadata"], json!({ "input": { "rows": 1200 } }));
+}
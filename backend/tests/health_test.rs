@@ -0,0 +1,113 @@
+use axum_test::TestServer;
+use flextide_core::database::DatabasePool;
+use serde_json::Value;
+
+mod common;
+use api::create_app;
+
+/// Configure the credentials master key so the integration credentials check passes
+fn set_credentials_master_key() {
+    // SAFETY: test-only environment setup, every test sets the same value
+    unsafe { std::env::set_var("CREDENTIALS_MASTER_KEY", "0".repeat(64)) };
+}
+
+#[tokio::test]
+async fn test_full_health_all_healthy() {
+    set_credentials_master_key();
+    let (state, _org_uuid, _user_uuid, _email) = common::create_test_state_with_org().await;
+    let server = TestServer::new(create_app(state)).unwrap();
+
+    // No authentication or organization required
+    let response = server.get("/api/health/full").await;
+    response.assert_status_ok();
+
+    let body: Value = response.json();
+    assert_eq!(body["status"], "ok");
+    for subsystem in ["database", "event_dispatcher", "integration_credentials"] {
+        assert_eq!(body["subsystems"][subsystem]["status"], "ok", "{} should be ok", subsystem);
+    }
+    assert_eq!(body["subsystems"]["database"]["critical"], true);
+    assert_eq!(body["subsystems"]["integration_credentials"]["critical"], false);
+}
+
+#[tokio::test]
+async fn test_full_health_database_down() {
+    set_credentials_master_key();
+    let (state, _org_uuid, _user_uuid, _email) = common::create_test_state_with_org().await;
+    match &state.db_pool {
+        DatabasePool::Sqlite(p) => p.close().await,
+        _ => unreachable!("Test pool should be SQLite"),
+    }
+    let server = TestServer::new(create_app(state)).unwrap();
+
+    let response = server.get("/api/health/full").await;
+    response.assert_status_service_unavailable();
+
+    let body: Value = response.json();
+    assert_eq!(body["status"], "down");
+    assert_eq!(body["subsystems"]["database"]["status"], "down");
+    assert_eq!(body["subsystems"]["event_dispatcher"]["status"], "ok");
+}
+
+#[tokio::test]
+async fn test_full_health_event_dispatcher_shut_down() {
+    set_credentials_master_key();
+    let (state, _org_uuid, _user_uuid, _email) = common::create_test_state_with_org().await;
+    state.event_dispatcher.shutdown(std::time::Duration::from_millis(10)).await;
+    let server = TestServer::new(create_app(state)).unwrap();
+
+    let response = server.get("/api/health/full").await;
+    response.assert_status_service_unavailable();
+
+    let body: Value = response.json();
+    assert_eq!(body["status"], "down");
+    assert_eq!(body["subsystems"]["database"]["status"], "ok");
+    assert_eq!(body["subsystems"]["event_dispatcher"]["status"], "down");
+}
+
+#[tokio::test]
+async fn test_livez_does_not_touch_database() {
+    let (state, _org_uuid, _user_uuid, _email) = common::create_test_state_with_org().await;
+    // A closed pool fails every query, so a passing probe cannot have used the database
+    match &state.db_pool {
+        DatabasePool::Sqlite(p) => p.close().await,
+        _ => unreachable!("Test pool should be SQLite"),
+    }
+    state.event_dispatcher.shutdown(std::time::Duration::from_millis(10)).await;
+    let server = TestServer::new(create_app(state)).unwrap();
+
+    // No authentication or organization required
+    let response = server.get("/api/livez").await;
+    response.assert_status_ok();
+
+    let body: Value = response.json();
+    assert_eq!(body["status"], "ok");
+}
+
+#[tokio::test]
+async fn test_readyz_reflects_database_status() {
+    let (state, _org_uuid, _user_uuid, _email) = common::create_test_state_with_org().await;
+    let server = TestServer::new(create_app(state.clone())).unwrap();
+
+    let response = server.get("/api/readyz").await;
+    response.assert_status_ok();
+
+    let body: Value = response.json();
+    assert_eq!(body["status"], "ok");
+    assert_eq!(body["subsystems"]["database"]["status"], "ok");
+    assert_eq!(body["subsystems"]["event_dispatcher"]["status"], "ok");
+    // Non-critical subsystems do not affect readiness
+    assert!(body["subsystems"].get("integration_credentials").is_none());
+
+    match &state.db_pool {
+        DatabasePool::Sqlite(p) => p.close().await,
+        _ => unreachable!("Test pool should be SQLite"),
+    }
+
+    let response = server.get("/api/readyz").await;
+    response.assert_status_service_unavailable();
+
+    let body: Value = response.json();
+    assert_eq!(body["status"], "down");
+    assert_eq!(body["subsystems"]["database"]["status"], "down");
+}
@@ -0,0 +1,79 @@
+use axum_test::TestServer;
+use jsonwebtoken::{encode, EncodingKey, Header};
+
+mod common;
+use api::Claims;
+
+/// Helper function to create a JWT token for testing
+fn create_test_token(email: &str, is_server_admin: bool) -> String {
+    use chrono::Utc;
+
+    let now = Utc::now();
+    let exp = (now + chrono::Duration::hours(24)).timestamp() as usize;
+    let iat = now.timestamp() as usize;
+
+    let claims = Claims {
+        sub: email.to_string(),
+        user_uuid: uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_DNS, email.as_bytes()).to_string(),
+        exp,
+        iat,
+        is_server_admin,
+    };
+
+    let jwt_secret = "test-secret-key";
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(jwt_secret.as_ref()),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_metrics_count_requests_per_route() {
+    let app = common::create_test_app().await;
+    let server = TestServer::new(app).unwrap();
+
+    server.get("/api/health").await.assert_status_ok();
+    server.get("/api/health").await.assert_status_ok();
+    // Unauthenticated request is counted as error
+    server.get("/api/organizations/list-own").await.assert_status_unauthorized();
+
+    let response = server
+        .get("/api/metrics")
+        .add_header("Authorization", format!("Bearer {}", create_test_token("admin@example.com", true)))
+        .await;
+    response.assert_status_ok();
+
+    let body = response.text();
+    assert!(
+        body.contains("flextide_http_requests_total{method=\"GET\",route=\"/api/health\"} 2"),
+        "{}",
+        body
+    );
+    assert!(
+        body.contains("flextide_http_request_errors_total{method=\"GET\",route=\"/api/organizations/list-own\"} 1"),
+        "{}",
+        body
+    );
+    assert!(
+        body.contains("flextide_http_request_duration_seconds_count{method=\"GET\",route=\"/api/health\"} 2"),
+        "{}",
+        body
+    );
+}
+
+#[tokio::test]
+async fn test_metrics_require_server_admin() {
+    let app = common::create_test_app().await;
+    let server = TestServer::new(app).unwrap();
+
+    let response = server
+        .get("/api/metrics")
+        .add_header("Authorization", format!("Bearer {}", create_test_token("user@example.com", false)))
+        .await;
+    response.assert_status_forbidden();
+
+    let response = server.get("/api/metrics").await;
+    response.assert_status_unauthorized();
+}
@@ -0,0 +1,147 @@
+use api::create_app;
+use axum_test::TestServer;
+use flextide_core::database::DatabasePool;
+use serde_json::Value;
+use uuid::Uuid;
+
+mod common;
+
+/// Insert a user and add them to the organization with the given role
+async fn add_member(pool: &DatabasePool, org_uuid: &str, email: &str, role: &str) -> String {
+    let p = match pool {
+        DatabasePool::Sqlite(p) => p,
+        _ => unreachable!("Test pool should be SQLite"),
+    };
+
+    let user_uuid = Uuid::new_v4().to_string();
+    sqlx::query(
+        "INSERT INTO users (uuid, email, password_hash, prename) VALUES (?1, ?2, 'hash', 'Test')",
+    )
+    .bind(&user_uuid)
+    .bind(email)
+    .execute(p)
+    .await
+    .expect("Failed to create user");
+
+    sqlx::query("INSERT INTO organization_members (org_id, user_id, role) VALUES (?1, ?2, ?3)")
+        .bind(org_uuid)
+        .bind(&user_uuid)
+        .bind(role)
+        .execute(p)
+        .await
+        .expect("Failed to add user to organization");
+
+    user_uuid
+}
+
+/// Insert test permissions: two visible ones and a hidden one
+async fn seed_permissions(pool: &DatabasePool) {
+    let p = match pool {
+        DatabasePool::Sqlite(p) => p,
+        _ => unreachable!("Test pool should be SQLite"),
+    };
+
+    for (id, name, visible, sort_order) in [
+        ("30000000-0000-0000-0000-000000000001", "test_can_read", 1, 1),
+        ("30000000-0000-0000-0000-000000000002", "test_can_write", 1, 2),
+        ("30000000-0000-0000-0000-000000000003", "test_hidden", 0, 3),
+    ] {
+        sqlx::query(
+            "INSERT INTO permissions (id, permission_group_name, name, title, visible, sort_order)
+             VALUES (?1, 'super_admin', ?2, ?2, ?3, ?4)",
+        )
+        .bind(id)
+        .bind(name)
+        .bind(visible)
+        .bind(sort_order)
+        .execute(p)
+        .await
+        .expect("Failed to insert permission");
+    }
+}
+
+fn granted_flags(body: &Value) -> Vec<(String, bool)> {
+    body["permissions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| {
+            (
+                p["name"].as_str().unwrap().to_string(),
+                p["granted"].as_bool().unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_user_permissions_merged_view() {
+    let (state, org_uuid, admin_uuid, admin_email) = common::create_test_state_with_org().await;
+    seed_permissions(&state.db_pool).await;
+    let member_uuid = add_member(&state.db_pool, &org_uuid, "member@example.com", "member").await;
+    flextide_core::permissions::add_user_permission(
+        &state.db_pool,
+        &admin_uuid,
+        &member_uuid,
+        &org_uuid,
+        "test_can_read",
+    )
+    .await
+    .expect("Failed to grant permission");
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let token = common::create_test_token(&admin_email, &admin_uuid);
+
+    let response = server
+        .get(&format!("/api/organizations/{}/users/{}/permissions", org_uuid, member_uuid))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+
+    response.assert_status_ok();
+    let body: Value = response.json();
+    assert_eq!(body["user_uuid"], member_uuid.as_str());
+
+    let flags = granted_flags(&body);
+    assert!(flags.contains(&("test_can_read".to_string(), true)));
+    assert!(flags.contains(&("test_can_write".to_string(), false)));
+    assert!(flags.contains(&("super_admin".to_string(), false)));
+    // Hidden permissions are not assignable
+    assert!(!flags.iter().any(|(name, _)| name == "test_hidden"));
+}
+
+#[tokio::test]
+async fn test_user_permissions_requires_org_admin() {
+    let (state, org_uuid, admin_uuid, _) = common::create_test_state_with_org().await;
+    let member_uuid = add_member(&state.db_pool, &org_uuid, "member@example.com", "member").await;
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let token = common::create_test_token("member@example.com", &member_uuid);
+
+    let response = server
+        .get(&format!("/api/organizations/{}/users/{}/permissions", org_uuid, admin_uuid))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+
+    response.assert_status(axum::http::StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_user_permissions_unknown_member() {
+    let (app, org_uuid, admin_uuid, admin_email) = common::create_test_app_with_org().await;
+    let server = TestServer::new(app).unwrap();
+    let token = common::create_test_token(&admin_email, &admin_uuid);
+
+    let response = server
+        .get(&format!(
+            "/api/organizations/{}/users/{}/permissions",
+            org_uuid,
+            Uuid::new_v4()
+        ))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+
+    response.assert_status(axum::http::StatusCode::NOT_FOUND);
+}
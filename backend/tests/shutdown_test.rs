@@ -0,0 +1,54 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use api::{serve, AppState};
+
+#[tokio::test]
+async fn test_shutdown_flushes_pending_event_deliveries() {
+    let db_pool = flextide_core::database::create_test_pool()
+        .await
+        .expect("Failed to create test database pool");
+    let event_dispatcher = flextide_core::events::EventDispatcher::new();
+
+    let app_state = AppState {
+        jwt_secret: "test-secret-key".to_string(),
+        jwt_config: flextide_core::jwt::JwtConfig::default(),
+        db_pool: db_pool.clone(),
+        db_read_pool: db_pool,
+        event_dispatcher: event_dispatcher.clone(),
+        node_registry: std::sync::Arc::new(api::default_node_registry()),
+        pagination: std::sync::Arc::new(flextide_core::pagination::PaginationConfig::default()),
+        cors_allowed_origins: Vec::new(),
+        trusted_proxies: api::TrustedProxies::default(),
+    };
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind test listener");
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+
+    let server = tokio::spawn(serve(listener, app_state, async move {
+        let _ = shutdown_rx.await;
+    }));
+
+    // Simulate a slow webhook delivery that is still running when shutdown starts
+    let delivered = Arc::new(AtomicBool::new(false));
+    let delivered_clone = delivered.clone();
+    event_dispatcher.spawn_background(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        delivered_clone.store(true, Ordering::SeqCst);
+    });
+
+    shutdown_tx.send(()).expect("Server should still be running");
+    server
+        .await
+        .expect("Server task panicked")
+        .expect("Server returned an error");
+
+    assert!(
+        delivered.load(Ordering::SeqCst),
+        "Pending delivery should complete before serve() returns"
+    );
+    assert_eq!(event_dispatcher.pending_task_count(), 0);
+}
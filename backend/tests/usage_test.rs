@@ -0,0 +1,149 @@
+use axum_test::TestServer;
+use flextide_core::database::DatabasePool;
+use serde_json::Value;
+use uuid::Uuid;
+
+mod common;
+use api::create_app;
+
+fn sqlite(pool: &DatabasePool) -> &sqlx::SqlitePool {
+    match pool {
+        DatabasePool::Sqlite(p) => p,
+        _ => unreachable!("Test pool should be SQLite"),
+    }
+}
+
+/// Create the tables counted by the usage endpoint that the common test app does not create
+async fn create_usage_tables(pool: &DatabasePool) {
+    for statement in [
+        "CREATE TABLE IF NOT EXISTS module_docs_areas (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            organization_uuid CHAR(36) NOT NULL
+        )",
+        "CREATE TABLE IF NOT EXISTS module_docs_pages (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            organization_uuid CHAR(36) NOT NULL,
+            short_summary TEXT,
+            auto_sync_to_vector_db INTEGER NOT NULL DEFAULT 0
+        )",
+        "CREATE TABLE IF NOT EXISTS event_webhooks (
+            id CHAR(36) NOT NULL PRIMARY KEY,
+            organization_uuid CHAR(36) NOT NULL,
+            event_name VARCHAR(255) NOT NULL,
+            url VARCHAR(2048) NOT NULL,
+            secret VARCHAR(255) NULL,
+            headers JSON NULL,
+            payload_template JSON NULL,
+            content_type VARCHAR(50) NOT NULL DEFAULT 'application/json',
+            active INTEGER NOT NULL DEFAULT 1,
+            created_by CHAR(36) NOT NULL,
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+        "CREATE TABLE IF NOT EXISTS runs (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            workflow_id CHAR(36) NOT NULL,
+            organization_uuid CHAR(36) NOT NULL,
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+    ] {
+        sqlx::query(statement)
+            .execute(sqlite(pool))
+            .await
+            .expect("Failed to create usage table");
+    }
+}
+
+/// Run an insert `count` times, binding a new UUID and the organization UUID
+async fn insert_rows(pool: &DatabasePool, count: usize, sql: &str, org_uuid: &str) {
+    for _ in 0..count {
+        sqlx::query(sql)
+            .bind(Uuid::new_v4().to_string())
+            .bind(org_uuid)
+            .execute(sqlite(pool))
+            .await
+            .expect("Failed to insert row");
+    }
+}
+
+async fn seed_usage(pool: &DatabasePool, org_uuid: &str, user_uuid: &str) {
+    insert_rows(
+        pool,
+        3,
+        "INSERT INTO module_crm_customers (uuid, organization_uuid, first_name, last_name) VALUES (?1, ?2, 'Jane', 'Doe')",
+        org_uuid,
+    )
+    .await;
+    insert_rows(pool, 2, "INSERT INTO module_docs_pages (uuid, organization_uuid) VALUES (?1, ?2)", org_uuid).await;
+    insert_rows(pool, 4, "INSERT INTO runs (uuid, workflow_id, organization_uuid) VALUES (?1, 'wf', ?2)", org_uuid).await;
+    // Executions older than the recent window are not counted
+    insert_rows(
+        pool,
+        1,
+        "INSERT INTO runs (uuid, workflow_id, organization_uuid, created_at) VALUES (?1, 'wf', ?2, '2020-01-01 00:00:00')",
+        org_uuid,
+    )
+    .await;
+    for active in [1, 1, 0] {
+        sqlx::query(
+            "INSERT INTO event_webhooks (id, organization_uuid, event_name, url, active, created_by)
+             VALUES (?1, ?2, 'module_crm_customer_created', 'https://example.com/hook', ?3, ?4)",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(org_uuid)
+        .bind(active)
+        .bind(user_uuid)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert webhook");
+    }
+}
+
+#[tokio::test]
+async fn test_organization_usage() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    create_usage_tables(&state.db_pool).await;
+    seed_usage(&state.db_pool, &org_uuid, &user_uuid).await;
+
+    // Data of other organizations is not counted
+    let other_org_uuid = Uuid::new_v4().to_string();
+    seed_usage(&state.db_pool, &other_org_uuid, &user_uuid).await;
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let response = server
+        .get(&format!("/api/organizations/{}/usage", org_uuid))
+        .add_header("Authorization", format!("Bearer {}", common::create_test_token(&email, &user_uuid)))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_ok();
+
+    let body: Value = response.json();
+    assert_eq!(body["organization_uuid"], org_uuid.as_str());
+    assert_eq!(body["customers"], 3);
+    assert_eq!(body["docs_pages"], 2);
+    assert_eq!(body["webhooks"], 2);
+    assert_eq!(body["recent_executions"], 4);
+}
+
+#[tokio::test]
+async fn test_organization_usage_requires_admin() {
+    let (state, org_uuid, _user_uuid, _email) = common::create_test_state_with_org().await;
+    create_usage_tables(&state.db_pool).await;
+
+    // Regular member of the organization
+    let member_uuid = Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO organization_members (org_id, user_id, role) VALUES (?1, ?2, 'member')")
+        .bind(&org_uuid)
+        .bind(&member_uuid)
+        .execute(sqlite(&state.db_pool))
+        .await
+        .expect("Failed to add member");
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let response = server
+        .get(&format!("/api/organizations/{}/usage", org_uuid))
+        .add_header("Authorization", format!("Bearer {}", common::create_test_token("member@example.com", &member_uuid)))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_forbidden();
+}
@@ -0,0 +1,488 @@
+use axum_test::TestServer;
+use flextide_core::database::DatabasePool;
+use flextide_core::pagination::{PageSizeLimits, PaginatedResource, PaginationConfig};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use uuid::Uuid;
+
+mod common;
+use api::create_app;
+
+fn sqlite(pool: &DatabasePool) -> &sqlx::SqlitePool {
+    match pool {
+        DatabasePool::Sqlite(p) => p,
+        _ => unreachable!("Test pool should be SQLite"),
+    }
+}
+
+async fn create_workflow_tables(pool: &DatabasePool) {
+    for statement in [
+        "CREATE TABLE IF NOT EXISTS workflows (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            organization_uuid CHAR(36) NOT NULL,
+            name VARCHAR(255) NOT NULL,
+            description TEXT,
+            definition JSON NOT NULL DEFAULT '{}',
+            status VARCHAR(20) NOT NULL DEFAULT 'draft',
+            created_by CHAR(36),
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            archived_at TIMESTAMP NULL
+        )",
+        "CREATE TABLE IF NOT EXISTS runs (
+            uuid CHAR(36) NOT NULL PRIMARY KEY,
+            workflow_id CHAR(36) NOT NULL,
+            organization_uuid CHAR(36) NOT NULL,
+            status VARCHAR(20) NOT NULL DEFAULT 'not_started',
+            trigger_type VARCHAR(255) NOT NULL DEFAULT 'manual',
+            started_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            finished_at TIMESTAMP NULL,
+            metadata JSON,
+            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+    ] {
+        sqlx::query(statement)
+            .execute(sqlite(pool))
+            .await
+            .expect("Failed to create table");
+    }
+}
+
+async fn create_workflow(pool: &DatabasePool, org_uuid: &str, name: &str, created_at: &str) -> String {
+    let uuid = Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO workflows (uuid, organization_uuid, name, created_at) VALUES (?1, ?2, ?3, ?4)")
+        .bind(&uuid)
+        .bind(org_uuid)
+        .bind(name)
+        .bind(created_at)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert workflow");
+    uuid
+}
+
+async fn create_run(pool: &DatabasePool, org_uuid: &str, workflow_uuid: &str, status: &str, created_at: &str) -> String {
+    let uuid = Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO runs (uuid, workflow_id, organization_uuid, status, created_at) VALUES (?1, ?2, ?3, ?4, ?5)")
+        .bind(&uuid)
+        .bind(workflow_uuid)
+        .bind(org_uuid)
+        .bind(status)
+        .bind(created_at)
+        .execute(sqlite(pool))
+        .await
+        .expect("Failed to insert run");
+    uuid
+}
+
+#[tokio::test]
+async fn test_list_workflows_with_last_run_status() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    let pool = &state.db_pool;
+    create_workflow_tables(pool).await;
+
+    let sync = create_workflow(pool, &org_uuid, "Nightly sync", "2025-01-01 00:00:00").await;
+    create_run(pool, &org_uuid, &sync, "completed", "2025-01-02 00:00:00").await;
+    create_run(pool, &org_uuid, &sync, "failed", "2025-01-03 00:00:00").await;
+    create_run(pool, &org_uuid, &sync, "completed", "2025-01-01 12:00:00").await;
+    let draft = create_workflow(pool, &org_uuid, "Draft", "2025-02-01 00:00:00").await;
+    // Workflows of other organizations are not listed
+    create_workflow(pool, &Uuid::new_v4().to_string(), "Foreign", "2025-03-01 00:00:00").await;
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let response = server
+        .get("/api/workflows")
+        .add_header("Authorization", format!("Bearer {}", common::create_test_token(&email, &user_uuid)))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_ok();
+
+    let body: Value = response.json();
+    assert_eq!(body["total"], 2);
+    assert_eq!(body["total_pages"], 1);
+
+    // Newest workflow first
+    let workflows = body["workflows"].as_array().unwrap();
+    assert_eq!(workflows.len(), 2);
+    assert_eq!(workflows[0]["uuid"], draft.as_str());
+    assert_eq!(workflows[0]["last_run_status"], Value::Null);
+    assert_eq!(workflows[1]["uuid"], sync.as_str());
+    assert_eq!(workflows[1]["name"], "Nightly sync");
+    assert_eq!(workflows[1]["created_at"], "2025-01-01 00:00:00");
+    assert_eq!(workflows[1]["last_run_status"], "failed");
+}
+
+#[tokio::test]
+async fn test_list_workflows_pagination() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    create_workflow_tables(&state.db_pool).await;
+    for day in 1..=3 {
+        create_workflow(&state.db_pool, &org_uuid, &format!("Workflow {}", day), &format!("2025-01-0{} 00:00:00", day)).await;
+    }
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let response = server
+        .get("/api/workflows?page=2&limit=2")
+        .add_header("Authorization", format!("Bearer {}", common::create_test_token(&email, &user_uuid)))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_ok();
+
+    let body: Value = response.json();
+    assert_eq!(body["total"], 3);
+    assert_eq!(body["total_pages"], 2);
+    assert_eq!(body["workflows"].as_array().unwrap().len(), 1);
+    assert_eq!(body["workflows"][0]["name"], "Workflow 1");
+}
+
+#[tokio::test]
+async fn test_list_workflows_uses_configured_default_limit() {
+    let (mut state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    state.pagination = Arc::new(
+        PaginationConfig::default().with_limits(PaginatedResource::Workflows, PageSizeLimits { default: 2, max: 10 }),
+    );
+    create_workflow_tables(&state.db_pool).await;
+    for day in 1..=3 {
+        create_workflow(&state.db_pool, &org_uuid, &format!("Workflow {}", day), &format!("2025-01-0{} 00:00:00", day)).await;
+    }
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let token = common::create_test_token(&email, &user_uuid);
+
+    // Without `limit`, the configured default page size is used
+    let response = server
+        .get("/api/workflows")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_ok();
+    let body: Value = response.json();
+    assert_eq!(body["total"], 3);
+    assert_eq!(body["total_pages"], 2);
+    assert_eq!(body["workflows"].as_array().unwrap().len(), 2);
+
+    // An explicit limit still takes precedence
+    let response = server
+        .get("/api/workflows?limit=3")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_ok();
+    let body: Value = response.json();
+    assert_eq!(body["workflows"].as_array().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_list_workflows_requires_permission() {
+    let (state, org_uuid, _user_uuid, _email) = common::create_test_state_with_org().await;
+    create_workflow_tables(&state.db_pool).await;
+
+    // Member of the organization without any permissions
+    let member_uuid = Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO organization_members (org_id, user_id, role) VALUES (?1, ?2, 'member')")
+        .bind(&org_uuid)
+        .bind(&member_uuid)
+        .execute(sqlite(&state.db_pool))
+        .await
+        .expect("Failed to add member");
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let response = server
+        .get("/api/workflows")
+        .add_header("Authorization", format!("Bearer {}", common::create_test_token("member@example.com", &member_uuid)))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_forbidden();
+}
+
+#[tokio::test]
+async fn test_create_workflow() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    create_workflow_tables(&state.db_pool).await;
+    let pool = state.db_pool.clone();
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let token = common::create_test_token(&email, &user_uuid);
+    let response = server
+        .post("/api/workflows")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .json(&json!({ "title": "Invoice reminders" }))
+        .await;
+    response.assert_status_ok();
+
+    let body: Value = response.json();
+    let workflow_uuid = body["uuid"].as_str().unwrap();
+
+    let (organization_uuid, name, definition, status, created_by): (String, String, String, String, String) =
+        sqlx::query_as("SELECT organization_uuid, name, definition, status, created_by FROM workflows WHERE uuid = ?1")
+            .bind(workflow_uuid)
+            .fetch_one(sqlite(&pool))
+            .await
+            .expect("Workflow should have been inserted");
+    assert_eq!(organization_uuid, org_uuid);
+    assert_eq!(name, "Invoice reminders");
+    assert_eq!(serde_json::from_str::<Value>(&definition).unwrap(), json!({ "nodes": [], "edges": [] }));
+    assert_eq!(status, "draft");
+    assert_eq!(created_by, user_uuid);
+
+    // The new workflow is listed
+    let response = server
+        .get("/api/workflows")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    let body: Value = response.json();
+    assert_eq!(body["workflows"][0]["uuid"], workflow_uuid);
+}
+
+#[tokio::test]
+async fn test_create_workflow_rejects_invalid_title() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    create_workflow_tables(&state.db_pool).await;
+    let pool = state.db_pool.clone();
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let token = common::create_test_token(&email, &user_uuid);
+    for title in ["   ", "Invoice\u{200B}reminders", &"x".repeat(51)] {
+        let response = server
+            .post("/api/workflows")
+            .add_header("Authorization", format!("Bearer {}", token))
+            .add_header("X-Organization-UUID", &org_uuid)
+            .json(&json!({ "title": title }))
+            .await;
+        response.assert_status_bad_request();
+    }
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM workflows")
+        .fetch_one(sqlite(&pool))
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[tokio::test]
+async fn test_delete_workflow_archives_and_keeps_executions() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    create_workflow_tables(&state.db_pool).await;
+    let pool = state.db_pool.clone();
+    let workflow_uuid = create_workflow(&pool, &org_uuid, "Nightly sync", "2025-01-01 00:00:00").await;
+    let run_uuid = create_run(&pool, &org_uuid, &workflow_uuid, "completed", "2025-01-02 00:00:00").await;
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let token = common::create_test_token(&email, &user_uuid);
+    let get = |path: String| {
+        server
+            .get(&path)
+            .add_header("Authorization", format!("Bearer {}", token))
+            .add_header("X-Organization-UUID", &org_uuid)
+    };
+
+    let response = server
+        .delete(&format!("/api/workflows/{}", workflow_uuid))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_ok();
+
+    // The workflow row is kept, only archived
+    let archived_at: Option<String> = sqlx::query_scalar("SELECT archived_at FROM workflows WHERE uuid = ?1")
+        .bind(&workflow_uuid)
+        .fetch_one(sqlite(&pool))
+        .await
+        .unwrap();
+    assert!(archived_at.is_some());
+
+    let body: Value = get("/api/workflows".to_string()).await.json();
+    assert_eq!(body["total"], 0);
+    assert_eq!(body["workflows"].as_array().unwrap().len(), 0);
+
+    // Its executions remain queryable
+    let response = get(format!("/api/executions/{}", run_uuid)).await;
+    response.assert_status_ok();
+    assert_eq!(response.json::<Value>()["workflow_name"], "Nightly sync");
+
+    let body: Value = get("/api/executions/last-executions".to_string()).await.json();
+    assert_eq!(body["total"], 1);
+    assert_eq!(body["executions"][0]["uuid"], run_uuid.as_str());
+}
+
+#[tokio::test]
+async fn test_delete_workflow_of_other_organization_is_not_found() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    create_workflow_tables(&state.db_pool).await;
+    let pool = state.db_pool.clone();
+    let foreign_workflow = create_workflow(&pool, &Uuid::new_v4().to_string(), "Foreign", "2025-01-01 00:00:00").await;
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let response = server
+        .delete(&format!("/api/workflows/{}", foreign_workflow))
+        .add_header("Authorization", format!("Bearer {}", common::create_test_token(&email, &user_uuid)))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_not_found();
+
+    let archived_at: Option<String> = sqlx::query_scalar("SELECT archived_at FROM workflows WHERE uuid = ?1")
+        .bind(&foreign_workflow)
+        .fetch_one(sqlite(&pool))
+        .await
+        .unwrap();
+    assert!(archived_at.is_none());
+}
+
+#[tokio::test]
+async fn test_export_workflow() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    create_workflow_tables(&state.db_pool).await;
+
+    let workflow_uuid = Uuid::new_v4().to_string();
+    let definition = json!({
+        "nodes": [
+            { "id": "trigger", "type": "webhook", "position": { "x": 0, "y": 0 }, "data": {}, "config": {} },
+            { "id": "notify", "type": "slack", "position": { "x": 200, "y": 0 }, "data": {}, "config": { "channel": "#ops" } }
+        ],
+        "edges": [
+            { "id": "e1", "source": "trigger", "target": "notify", "sourceHandle": "out", "targetHandle": "in" }
+        ]
+    });
+    sqlx::query(
+        "INSERT INTO workflows (uuid, organization_uuid, name, description, definition, status)
+         VALUES (?1, ?2, 'Notify ops', 'Posts incoming webhooks to Slack', ?3, 'active')",
+    )
+    .bind(&workflow_uuid)
+    .bind(&org_uuid)
+    .bind(definition.to_string())
+    .execute(sqlite(&state.db_pool))
+    .await
+    .expect("Failed to insert workflow");
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let response = server
+        .get(&format!("/api/workflows/{}/export", workflow_uuid))
+        .add_header("Authorization", format!("Bearer {}", common::create_test_token(&email, &user_uuid)))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_ok();
+
+    let body: Value = response.json();
+    assert_eq!(body["export_version"], 1);
+    assert!(body["exported_at"].is_string());
+    let workflow = &body["workflow"];
+    assert_eq!(workflow["uuid"], workflow_uuid.as_str());
+    assert_eq!(workflow["name"], "Notify ops");
+    assert_eq!(workflow["description"], "Posts incoming webhooks to Slack");
+    assert_eq!(workflow["status"], "active");
+    assert_eq!(workflow["nodes"], definition["nodes"]);
+    assert_eq!(workflow["edges"], definition["edges"]);
+}
+
+#[tokio::test]
+async fn test_export_workflow_of_other_organization_is_not_found() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    create_workflow_tables(&state.db_pool).await;
+    let foreign_workflow =
+        create_workflow(&state.db_pool, &Uuid::new_v4().to_string(), "Foreign", "2025-01-01 00:00:00").await;
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let response = server
+        .get(&format!("/api/workflows/{}/export", foreign_workflow))
+        .add_header("Authorization", format!("Bearer {}", common::create_test_token(&email, &user_uuid)))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_not_found();
+}
+
+/// Export document with two HTTP request nodes connected by an edge
+fn http_workflow_export(node_type: &str) -> Value {
+    json!({
+        "export_version": 1,
+        "exported_at": "2025-01-01T00:00:00Z",
+        "workflow": {
+            "uuid": Uuid::new_v4().to_string(),
+            "name": "Ping services",
+            "description": "Checks that both services respond",
+            "status": "active",
+            "nodes": [
+                { "id": "first", "type": "http-request", "position": { "x": 0, "y": 0 }, "config": { "method": "GET" } },
+                { "id": "second", "type": node_type, "position": { "x": 200, "y": 0 }, "config": { "method": "HEAD" } }
+            ],
+            "edges": [
+                { "id": "e1", "source": "first", "target": "second", "sourceHandle": "status", "targetHandle": "url" }
+            ]
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_import_workflow() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    create_workflow_tables(&state.db_pool).await;
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let token = common::create_test_token(&email, &user_uuid);
+    let document = http_workflow_export("http-request");
+    let response = server
+        .post("/api/workflows/import")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .json(&document)
+        .await;
+    response.assert_status_ok();
+
+    // Imported with a fresh UUID
+    let workflow_uuid = response.json::<Value>()["uuid"].as_str().unwrap().to_string();
+    assert_ne!(json!(workflow_uuid), document["workflow"]["uuid"]);
+
+    // Exporting the imported workflow yields the same graph
+    let response = server
+        .get(&format!("/api/workflows/{}/export", workflow_uuid))
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .await;
+    response.assert_status_ok();
+
+    let exported = &response.json::<Value>()["workflow"];
+    assert_eq!(exported["name"], "Ping services");
+    assert_eq!(exported["description"], "Checks that both services respond");
+    assert_eq!(exported["status"], "draft");
+    assert_eq!(exported["nodes"], document["workflow"]["nodes"]);
+    assert_eq!(exported["edges"], document["workflow"]["edges"]);
+}
+
+#[tokio::test]
+async fn test_import_workflow_rejects_unknown_node_type() {
+    let (state, org_uuid, user_uuid, email) = common::create_test_state_with_org().await;
+    create_workflow_tables(&state.db_pool).await;
+    let pool = state.db_pool.clone();
+
+    let server = TestServer::new(create_app(state)).unwrap();
+    let token = common::create_test_token(&email, &user_uuid);
+    let response = server
+        .post("/api/workflows/import")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .json(&http_workflow_export("teleport"))
+        .await;
+    response.assert_status_bad_request();
+    assert_eq!(
+        response.json::<Value>()["error"],
+        "Node 'second' has unknown type 'teleport'"
+    );
+
+    // Unsupported export versions are rejected as well
+    let mut document = http_workflow_export("http-request");
+    document["export_version"] = json!(99);
+    let response = server
+        .post("/api/workflows/import")
+        .add_header("Authorization", format!("Bearer {}", token))
+        .add_header("X-Organization-UUID", &org_uuid)
+        .json(&document)
+        .await;
+    response.assert_status_bad_request();
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM workflows")
+        .fetch_one(sqlite(&pool))
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}